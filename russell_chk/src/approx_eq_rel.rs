@@ -0,0 +1,99 @@
+use num_traits::{Num, NumCast};
+
+/// Panics if two numbers are not approximately equal to each other (relative tolerance)
+///
+/// # Input
+///
+/// `a` -- Left value
+/// `b` -- Right value
+/// `rel_tol: f64` -- Relative error tolerance: panic occurs if `|a - b| > rel_tol · max(|a|, |b|)`
+///
+/// # Note
+///
+/// Contrary to [crate::approx_eq], the tolerance scales with the magnitude of the numbers; thus,
+/// the same tolerance can be used to compare values spanning many orders of magnitude.
+/// However, a relative tolerance is meaningless near zero: `approx_eq_rel(0.0, 1e-300, 1e-6)` panics.
+///
+/// # Examples
+///
+/// ## Accepts small relative error
+///
+/// ```
+/// use russell_chk::approx_eq_rel;
+///
+/// fn main() {
+///     approx_eq_rel(1.0000001e-20, 1e-20, 1e-6);
+///     approx_eq_rel(1.0000001e20, 1e20, 1e-6);
+/// }
+/// ```
+///
+/// ## Panics on different value
+///
+/// ```should_panic
+/// use russell_chk::approx_eq_rel;
+///
+/// fn main() {
+///     approx_eq_rel(1e-20, 2e-20, 1e-6);
+/// }
+/// ```
+pub fn approx_eq_rel<T>(a: T, b: T, rel_tol: f64)
+where
+    T: Num + NumCast + Copy,
+{
+    let a = a.to_f64().unwrap();
+    let b = b.to_f64().unwrap();
+    if a == b {
+        return; // handles infinities
+    }
+    let diff = f64::abs(a - b);
+    let rel = diff / f64::max(f64::abs(a), f64::abs(b));
+    if rel.is_nan() || rel > rel_tol {
+        panic!("numbers are not approximately equal. relative diff = {:?}", rel);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::approx_eq_rel;
+
+    #[test]
+    #[should_panic(expected = "numbers are not approximately equal. relative diff = 0.5")]
+    fn panics_on_different_values() {
+        approx_eq_rel(2e-30, 1e-30, 1e-1);
+    }
+
+    #[test]
+    #[should_panic(expected = "numbers are not approximately equal. relative diff = 0.5")]
+    fn panics_on_different_values_f32() {
+        approx_eq_rel(2f32, 1f32, 1e-1);
+    }
+
+    #[test]
+    #[should_panic(expected = "numbers are not approximately equal. relative diff = 1.0")]
+    fn panics_near_zero() {
+        approx_eq_rel(0.0, 1e-300, 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "numbers are not approximately equal. relative diff = NaN")]
+    fn panics_on_nan() {
+        approx_eq_rel(f64::NAN, f64::NAN, 1e-6);
+    }
+
+    #[test]
+    fn accepts_approx_equal_values() {
+        approx_eq_rel(0.0, 0.0, 0.0);
+        approx_eq_rel(-3.0, -3.0, 0.0);
+        approx_eq_rel(f64::INFINITY, f64::INFINITY, 0.0);
+        approx_eq_rel(1.01e-100, 1e-100, 0.01);
+        approx_eq_rel(1.01e100, 1e100, 0.01);
+        approx_eq_rel(-1.01e100, -1e100, 0.01);
+    }
+
+    #[test]
+    fn accepts_approx_equal_values_f32() {
+        approx_eq_rel(2f32, 2.02f32, 0.01);
+    }
+}
@@ -0,0 +1,181 @@
+use num_complex::{Complex32, Complex64};
+
+/// Defines values that can be compared approximately by [approx_eq_slices]
+///
+/// The comparison of composite values (tuples, arrays, complex numbers) is based on
+/// the largest absolute difference among all their components. Thus, a single
+/// tolerance applies to all components.
+pub trait ApproxEq {
+    /// Returns the maximum absolute difference between the components of two values
+    ///
+    /// Returns NaN if any component is NaN.
+    fn max_abs_diff(&self, other: &Self) -> f64;
+}
+
+impl ApproxEq for f64 {
+    fn max_abs_diff(&self, other: &Self) -> f64 {
+        f64::abs(self - other)
+    }
+}
+
+impl ApproxEq for f32 {
+    fn max_abs_diff(&self, other: &Self) -> f64 {
+        f64::abs((*self as f64) - (*other as f64))
+    }
+}
+
+impl ApproxEq for Complex64 {
+    fn max_abs_diff(&self, other: &Self) -> f64 {
+        max_nan(self.re.max_abs_diff(&other.re), self.im.max_abs_diff(&other.im))
+    }
+}
+
+impl ApproxEq for Complex32 {
+    fn max_abs_diff(&self, other: &Self) -> f64 {
+        max_nan(self.re.max_abs_diff(&other.re), self.im.max_abs_diff(&other.im))
+    }
+}
+
+impl<T: ApproxEq, const N: usize> ApproxEq for [T; N] {
+    fn max_abs_diff(&self, other: &Self) -> f64 {
+        self.iter()
+            .zip(other)
+            .fold(0.0, |acc, (a, b)| max_nan(acc, a.max_abs_diff(b)))
+    }
+}
+
+macro_rules! impl_approx_eq_for_tuple {
+    ($($name:ident $index:tt),+) => {
+        impl<$($name: ApproxEq),+> ApproxEq for ($($name,)+) {
+            fn max_abs_diff(&self, other: &Self) -> f64 {
+                let mut res = 0.0;
+                $(res = max_nan(res, self.$index.max_abs_diff(&other.$index));)+
+                res
+            }
+        }
+    };
+}
+
+impl_approx_eq_for_tuple!(A 0);
+impl_approx_eq_for_tuple!(A 0, B 1);
+impl_approx_eq_for_tuple!(A 0, B 1, C 2);
+impl_approx_eq_for_tuple!(A 0, B 1, C 2, D 3);
+impl_approx_eq_for_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_approx_eq_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+
+/// Returns the maximum of two numbers or NaN if any of them is NaN
+fn max_nan(a: f64, b: f64) -> f64 {
+    if a.is_nan() || b.is_nan() {
+        f64::NAN
+    } else {
+        f64::max(a, b)
+    }
+}
+
+/// Panics if two slices of (composite) values are not approximately equal to each other
+///
+/// The values may be numbers, complex numbers, fixed-size arrays, or tuples (up to six entries),
+/// including nested combinations such as `(f64, [Complex64; 2])`. A single tolerance applies to
+/// all components: panic occurs if `max |aᵢ - bᵢ| > tol` considering all components of all entries.
+///
+/// Panics also if the slice lengths differ. The panic message reports the entry with the largest difference.
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use russell_chk::approx_eq_slices;
+///
+/// fn main() {
+///     // tabulated data: (x, f(x), [df/dx, d²f/dx²])
+///     let a = [(0.0, 1.0, [0.0, 2.0]), (1.0, 2.0, [2.0, 2.0])];
+///     let b = [(0.0, 1.0, [0.0, 2.0]), (1.0, 2.0000001, [2.0, 2.0])];
+///     approx_eq_slices(&a, &b, 1e-6);
+///
+///     // complex numbers
+///     let u = [Complex64::new(1.0, 2.0)];
+///     let v = [Complex64::new(1.0, 2.0000001)];
+///     approx_eq_slices(&u, &v, 1e-6);
+/// }
+/// ```
+pub fn approx_eq_slices<T: ApproxEq>(a: &[T], b: &[T], tol: f64) {
+    if a.len() != b.len() {
+        panic!("slice lengths differ. {} != {}", a.len(), b.len());
+    }
+    let mut worst = 0;
+    let mut max_diff = 0.0;
+    let mut count = 0;
+    for (i, (x, y)) in a.iter().zip(b).enumerate() {
+        let diff = x.max_abs_diff(y);
+        if diff > tol || diff.is_nan() {
+            count += 1;
+            if diff > max_diff || (diff.is_nan() && !f64::is_nan(max_diff)) {
+                max_diff = diff;
+                worst = i;
+            }
+        }
+    }
+    if count > 0 {
+        panic!(
+            "slices are not approximately equal. @ {} diff = {:?} ({} of {} entries exceed tol = {:?})",
+            worst,
+            max_diff,
+            count,
+            a.len(),
+            tol
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{approx_eq_slices, ApproxEq};
+    use num_complex::{Complex32, Complex64};
+
+    #[test]
+    fn max_abs_diff_works() {
+        assert_eq!(1.0_f64.max_abs_diff(&3.0), 2.0);
+        assert_eq!(1.0_f32.max_abs_diff(&0.5), 0.5);
+        assert_eq!(Complex64::new(1.0, 2.0).max_abs_diff(&Complex64::new(1.5, 4.0)), 2.0);
+        assert_eq!(Complex32::new(1.0, 2.0).max_abs_diff(&Complex32::new(1.5, 2.0)), 0.5);
+        assert_eq!([1.0, 2.0, 3.0].max_abs_diff(&[1.0, 2.5, 3.0]), 0.5);
+        assert_eq!((1.0,).max_abs_diff(&(1.5,)), 0.5);
+        assert_eq!((1.0, [2.0, 3.0]).max_abs_diff(&(1.0, [2.0, 6.0])), 3.0);
+        assert_eq!(
+            (1.0, 2.0, 3.0, 4.0, 5.0, 6.0).max_abs_diff(&(1.0, 2.0, 3.0, 4.0, 5.0, 7.0)),
+            1.0
+        );
+        assert!((f64::NAN, 1.0).max_abs_diff(&(0.0, 1.0)).is_nan());
+        assert!((1.0, f64::NAN).max_abs_diff(&(1.0, 0.0)).is_nan());
+    }
+
+    #[test]
+    #[should_panic(expected = "slice lengths differ. 1 != 2")]
+    fn panics_on_different_lengths() {
+        approx_eq_slices(&[1.0], &[1.0, 2.0], 1e-15);
+    }
+
+    #[test]
+    #[should_panic(expected = "slices are not approximately equal. @ 2 diff = 1.5 (2 of 3 entries exceed tol = 0.1)")]
+    fn panics_on_different_values() {
+        let a = [(1, 1.0), (2, 2.0), (3, 3.0)];
+        let b = [(1, 1.0), (2, 2.5), (3, 4.5)];
+        approx_eq_slices(&a.map(|(i, x)| (i as f64, x)), &b.map(|(i, x)| (i as f64, x)), 0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "slices are not approximately equal. @ 1 diff = NaN (1 of 2 entries exceed tol = 0.1)")]
+    fn panics_on_nan() {
+        approx_eq_slices(&[[1.0, 2.0], [3.0, f64::NAN]], &[[1.0, 2.0], [3.0, 4.0]], 0.1);
+    }
+
+    #[test]
+    fn accepts_approx_equal_values() {
+        let a = [(1.0, Complex64::new(1.0, -1.0)), (2.0, Complex64::new(0.0, 1.0))];
+        let b = [(1.0, Complex64::new(1.0, -1.01)), (2.01, Complex64::new(0.0, 1.0))];
+        approx_eq_slices(&a, &b, 0.011);
+        approx_eq_slices::<f64>(&[], &[], 0.0);
+    }
+}
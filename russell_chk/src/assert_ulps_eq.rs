@@ -0,0 +1,118 @@
+/// Panics if two numbers differ by more than a given number of units in the last place (ULPs)
+///
+/// The distance in ULPs is the number of representable f64 values between `a` and `b`.
+/// For instance, `a` and `f64::from_bits(a.to_bits() + 1)` are 1 ULP apart.
+///
+/// # Input
+///
+/// `a` -- Left value
+/// `b` -- Right value
+/// `max_ulps` -- Maximum distance in ULPs: panic occurs if `ulps(a, b) > max_ulps`
+///
+/// # Note
+///
+/// +0.0 and -0.0 are considered equal; NaN is not equal to any number (including NaN).
+///
+/// # Examples
+///
+/// ## Accepts results differing by round-off only
+///
+/// ```
+/// use russell_chk::assert_ulps_eq;
+///
+/// fn main() {
+///     assert_ulps_eq(0.1 + 0.2, 0.3, 1);
+///     assert_ulps_eq(1e-300 * 3.0, 3e-300, 1);
+/// }
+/// ```
+///
+/// ## Panics on different value
+///
+/// ```should_panic
+/// use russell_chk::assert_ulps_eq;
+///
+/// fn main() {
+///     assert_ulps_eq(1.0, 1.0 + 1e-12, 4);
+/// }
+/// ```
+pub fn assert_ulps_eq(a: f64, b: f64, max_ulps: u64) {
+    if a.is_nan() || b.is_nan() {
+        panic!(
+            "numbers are not equal within {} ulps. a = {:?}, b = {:?}",
+            max_ulps, a, b
+        );
+    }
+    let ulps = ordered_bits(a).abs_diff(ordered_bits(b));
+    if ulps > max_ulps {
+        panic!("numbers are not equal within {} ulps. diff = {} ulps", max_ulps, ulps);
+    }
+}
+
+/// Maps the bits of a float to an integer such that the order of the integers follows the order of the floats
+///
+/// The positive floats are mapped to non-negative integers and the negative floats to negative integers.
+/// Both +0.0 and -0.0 are mapped to zero and adjacent floats are mapped to consecutive integers.
+fn ordered_bits(x: f64) -> i64 {
+    let bits = x.to_bits() as i64;
+    if bits < 0 {
+        i64::MIN - bits
+    } else {
+        bits
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{assert_ulps_eq, ordered_bits};
+
+    #[test]
+    fn ordered_bits_works() {
+        assert_eq!(ordered_bits(0.0), 0);
+        assert_eq!(ordered_bits(-0.0), 0);
+        assert_eq!(ordered_bits(f64::from_bits(1)), 1);
+        assert_eq!(ordered_bits(-f64::from_bits(1)), -1);
+        assert!(ordered_bits(-2.0) < ordered_bits(-1.0));
+        assert!(ordered_bits(1.0) < ordered_bits(2.0));
+        assert_eq!(ordered_bits(f64::MAX) + 1, ordered_bits(f64::INFINITY));
+    }
+
+    #[test]
+    #[should_panic(expected = "numbers are not equal within 1 ulps. diff = 2 ulps")]
+    fn panics_on_different_values() {
+        let a: f64 = 1.0;
+        let b = f64::from_bits(a.to_bits() + 2);
+        assert_ulps_eq(a, b, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "numbers are not equal within 0 ulps. diff = 2 ulps")]
+    fn panics_across_zero() {
+        assert_ulps_eq(-f64::from_bits(1), f64::from_bits(1), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "numbers are not equal within 10 ulps. a = NaN, b = NaN")]
+    fn panics_on_nan() {
+        assert_ulps_eq(f64::NAN, f64::NAN, 10);
+    }
+
+    #[test]
+    fn accepts_equal_values() {
+        assert_ulps_eq(0.0, -0.0, 0);
+        assert_ulps_eq(123.0, 123.0, 0);
+        assert_ulps_eq(f64::INFINITY, f64::INFINITY, 0);
+        assert_ulps_eq(f64::NEG_INFINITY, f64::NEG_INFINITY, 0);
+    }
+
+    #[test]
+    fn accepts_values_within_max_ulps() {
+        let a: f64 = 1e-200;
+        let b = f64::from_bits(a.to_bits() + 3);
+        assert_ulps_eq(a, b, 3);
+        assert_ulps_eq(-a, -b, 3);
+        assert_ulps_eq(-f64::from_bits(1), f64::from_bits(1), 2);
+        assert_ulps_eq(0.1 + 0.2, 0.3, 1);
+    }
+}
@@ -0,0 +1,42 @@
+use crate::deriv2_richardson;
+
+/// Panics if the second derivative is not approximately equal to the numerical second derivative
+///
+/// The numerical second derivative is computed by [deriv2_richardson].
+pub fn deriv2_approx_eq<F, A>(d2fdx2: f64, at_x: f64, args: &mut A, tol: f64, f: F)
+where
+    F: FnMut(f64, &mut A) -> f64,
+{
+    let d2fdx2_num = deriv2_richardson(at_x, args, f);
+    let diff = f64::abs(d2fdx2 - d2fdx2_num);
+    if diff > tol || diff.is_nan() {
+        panic!(
+            "second derivative is not approximately equal to numerical value. diff = {:?}",
+            diff
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::deriv2_approx_eq;
+
+    struct Arguments {}
+
+    #[test]
+    #[should_panic(expected = "second derivative is not approximately equal to numerical value. diff = ")]
+    fn panics_on_different_deriv2() {
+        let f = |x: f64, _: &mut Arguments| x * x * x / 6.0;
+        let args = &mut Arguments {};
+        deriv2_approx_eq(1.51, 1.5, args, 1e-3, f);
+    }
+
+    #[test]
+    fn accepts_approx_equal_deriv2() {
+        let f = |x: f64, _: &mut Arguments| x * x * x / 6.0;
+        let args = &mut Arguments {};
+        deriv2_approx_eq(1.5, 1.5, args, 1e-10, f);
+    }
+}
@@ -1,4 +1,5 @@
-use crate::deriv_central5;
+use crate::{deriv_central5, deriv_complex_step};
+use num_complex::Complex64;
 
 /// Panics if derivative is not approximately equal to numerical derivative
 pub fn deriv_approx_eq<F, A>(dfdx: f64, at_x: f64, args: &mut A, tol: f64, f: F)
@@ -15,11 +16,30 @@ where
     }
 }
 
+/// Panics if derivative is not approximately equal to the complex-step derivative
+///
+/// Since the complex-step derivative is accurate to machine precision, a very small `tol` can be used.
+/// The function must support complex arguments (see [deriv_complex_step]).
+pub fn deriv_approx_eq_complex_step<F, A>(dfdx: f64, at_x: f64, args: &mut A, tol: f64, f: F)
+where
+    F: FnMut(Complex64, &mut A) -> Complex64,
+{
+    let dfdx_num = deriv_complex_step(at_x, args, f);
+    let diff = f64::abs(dfdx - dfdx_num);
+    if diff > tol || diff.is_nan() {
+        panic!(
+            "derivative is not approximately equal to complex-step value. diff = {:?}",
+            diff
+        );
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::deriv_approx_eq;
+    use super::{deriv_approx_eq, deriv_approx_eq_complex_step};
+    use num_complex::Complex64;
 
     struct Arguments {}
 
@@ -42,4 +62,20 @@ mod tests {
         deriv_approx_eq(dfdx, at_x, args, 1e-2, f);
         deriv_approx_eq(dfdx, at_x, args, 1e-2, f);
     }
+
+    #[test]
+    #[should_panic(expected = "derivative is not approximately equal to complex-step value. diff = ")]
+    fn panics_on_different_deriv_complex_step() {
+        let f = |x: Complex64, _: &mut Arguments| x * x / 2.0;
+        let args = &mut Arguments {};
+        deriv_approx_eq_complex_step(1.5 + 1e-12, 1.5, args, 1e-14, f);
+    }
+
+    #[test]
+    fn accepts_approx_equal_deriv_complex_step() {
+        let f = |x: Complex64, _: &mut Arguments| x.cos() * x;
+        let args = &mut Arguments {};
+        let x: f64 = 1.5;
+        deriv_approx_eq_complex_step(f64::cos(x) - x * f64::sin(x), x, args, 1e-15, f);
+    }
 }
@@ -0,0 +1,132 @@
+use crate::deriv_check_jacobian::derivative_report;
+use crate::deriv_hessian_richardson;
+
+/// Panics if an analytical Hessian matrix is not approximately equal to the numerical Hessian matrix
+///
+/// The entries are compared component-wise with the mixed (absolute/relative) criterion:
+///
+/// ```text
+/// |Hᵢⱼ - Hᵢⱼ(num)| ≤ tol · max(1, |Hᵢⱼ(num)|)
+/// ```
+///
+/// The numerical Hessian is computed by [deriv_hessian_richardson].
+///
+/// # Input
+///
+/// * `hess` -- the analytical Hessian matrix as a list of rows (ndim × ndim)
+/// * `at_x` -- location for the second derivatives of f(x, {arguments}) w.r.t x
+/// * `args` -- extra arguments for f(x, {arguments})
+/// * `tol` -- the tolerance
+/// * `f` -- scalar function f(x, {arguments})
+///
+/// # Report
+///
+/// The panic message lists the violating entries (up to ten), sorted from the largest error.
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::deriv_check_hessian;
+/// struct Arguments {}
+/// // Rosenbrock function
+/// let f = |x: &[f64], _: &mut Arguments| {
+///     (1.0 - x[0]) * (1.0 - x[0]) + 100.0 * (x[1] - x[0] * x[0]) * (x[1] - x[0] * x[0])
+/// };
+/// let args = &mut Arguments {};
+/// let x = [0.5, 1.0];
+/// let hess = [
+///     [2.0 - 400.0 * (x[1] - 3.0 * x[0] * x[0]), -400.0 * x[0]],
+///     [-400.0 * x[0], 200.0],
+/// ];
+/// deriv_check_hessian(&hess, &x, args, 1e-8, f);
+/// ```
+pub fn deriv_check_hessian<H, F, A>(hess: &[H], at_x: &[f64], args: &mut A, tol: f64, f: F)
+where
+    H: AsRef<[f64]>,
+    F: FnMut(&[f64], &mut A) -> f64,
+{
+    let n = at_x.len();
+    if hess.len() != n {
+        panic!("hessian dimensions differ. {} rows but x.len() = {}", hess.len(), n);
+    }
+    for (i, row) in hess.iter().enumerate() {
+        if row.as_ref().len() != n {
+            panic!(
+                "hessian dimensions differ. row {} has {} columns but x.len() = {}",
+                i,
+                row.as_ref().len(),
+                n
+            );
+        }
+    }
+    let hess_num = deriv_hessian_richardson(at_x, args, f);
+    let mut violations = Vec::new();
+    for (i, (row, row_num)) in hess.iter().zip(&hess_num).enumerate() {
+        for (j, (ana, num)) in row.as_ref().iter().zip(row_num).enumerate() {
+            let scaled = f64::abs(ana - num) / f64::max(1.0, f64::abs(*num));
+            if scaled > tol || scaled.is_nan() {
+                violations.push((i, j, *ana, *num, scaled));
+            }
+        }
+    }
+    if violations.is_empty() {
+        return;
+    }
+    panic!("{}", derivative_report("hessian", violations, n * n, tol));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::deriv_check_hessian;
+
+    struct Arguments {
+        a: f64,
+    }
+
+    // f = a x₀² x₁ + exp(x₁)
+    fn f(x: &[f64], args: &mut Arguments) -> f64 {
+        args.a * x[0] * x[0] * x[1] + f64::exp(x[1])
+    }
+
+    fn hessian(x: &[f64], a: f64) -> Vec<Vec<f64>> {
+        vec![
+            vec![2.0 * a * x[1], 2.0 * a * x[0]],
+            vec![2.0 * a * x[0], f64::exp(x[1])],
+        ]
+    }
+
+    #[test]
+    fn accepts_correct_hessian() {
+        let args = &mut Arguments { a: 3.0 };
+        let x = [1.5, 0.5];
+        deriv_check_hessian(&hessian(&x, 3.0), &x, args, 1e-9, f);
+    }
+
+    #[test]
+    #[should_panic(expected = "hessian dimensions differ. 1 rows but x.len() = 2")]
+    fn panics_on_wrong_nrow() {
+        let args = &mut Arguments { a: 3.0 };
+        deriv_check_hessian(&[[0.0, 0.0]], &[1.0, 2.0], args, 1e-9, f);
+    }
+
+    #[test]
+    #[should_panic(expected = "hessian dimensions differ. row 0 has 1 columns but x.len() = 2")]
+    fn panics_on_wrong_ncol() {
+        let args = &mut Arguments { a: 3.0 };
+        let hess = vec![vec![0.0], vec![0.0, 0.0]];
+        deriv_check_hessian(&hess, &[1.0, 2.0], args, 1e-9, f);
+    }
+
+    #[test]
+    #[should_panic(expected = "hessian is not approximately equal to numerical value. @ (1,0) scaled diff = ")]
+    fn panics_on_wrong_hessian() {
+        // non-symmetric (inconsistent) tangent
+        let args = &mut Arguments { a: 3.0 };
+        let x = [1.5, 0.5];
+        let mut hess = hessian(&x, 3.0);
+        hess[1][0] = 0.0;
+        deriv_check_hessian(&hess, &x, args, 1e-9, f);
+    }
+}
@@ -0,0 +1,316 @@
+use crate::{deriv_central5, deriv_jacobian_complex_step};
+use num_complex::Complex64;
+
+/// Defines the maximum number of violating entries listed in the report
+const REPORT_MAX_ENTRIES: usize = 10;
+
+/// Computes the Jacobian matrix J = ∂f/∂x numerically using central differences with 5 points
+///
+/// # Input
+///
+/// * `ndim_f` -- the dimension of f
+/// * `at_x` -- location for the derivatives of f(x, {arguments}) w.r.t x
+/// * `args` -- extra arguments for f(x, {arguments})
+/// * `f` -- function f(x, {arguments}) writing the result into its first argument (with length `ndim_f`)
+///
+/// # Output
+///
+/// Returns the (ndim_f × at_x.len()) Jacobian matrix as a vector of rows
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::{deriv_jacobian_central5, vec_approx_eq};
+/// struct Arguments {}
+/// let f = |fx: &mut [f64], x: &[f64], _: &mut Arguments| {
+///     fx[0] = x[0] * x[1];
+///     fx[1] = x[0] + 2.0 * x[1];
+/// };
+/// let args = &mut Arguments {};
+/// let jac = deriv_jacobian_central5(2, &[3.0, 4.0], args, f);
+/// vec_approx_eq(&jac[0], &[4.0, 3.0], 1e-10);
+/// vec_approx_eq(&jac[1], &[1.0, 2.0], 1e-10);
+/// ```
+pub fn deriv_jacobian_central5<F, A>(ndim_f: usize, at_x: &[f64], args: &mut A, mut f: F) -> Vec<Vec<f64>>
+where
+    F: FnMut(&mut [f64], &[f64], &mut A),
+{
+    let ndim_x = at_x.len();
+    let mut jac = vec![vec![0.0; ndim_x]; ndim_f];
+    let mut x = at_x.to_vec();
+    let mut fx = vec![0.0; ndim_f];
+    for j in 0..ndim_x {
+        for (i, row) in jac.iter_mut().enumerate() {
+            row[j] = deriv_central5(at_x[j], args, |xj, a| {
+                x[j] = xj;
+                f(&mut fx, &x, a);
+                fx[i]
+            });
+        }
+        x[j] = at_x[j];
+    }
+    jac
+}
+
+/// Panics if an analytical Jacobian matrix is not approximately equal to the numerical Jacobian matrix
+///
+/// The entries are compared component-wise with the mixed (absolute/relative) criterion:
+///
+/// ```text
+/// |Jᵢⱼ - Jᵢⱼ(num)| ≤ tol · max(1, |Jᵢⱼ(num)|)
+/// ```
+///
+/// The numerical Jacobian is computed by [deriv_jacobian_central5].
+///
+/// # Input
+///
+/// * `jac` -- the analytical Jacobian matrix as a list of rows (ndim_f × ndim_x)
+/// * `at_x` -- location for the derivatives of f(x, {arguments}) w.r.t x
+/// * `args` -- extra arguments for f(x, {arguments})
+/// * `tol` -- the tolerance
+/// * `f` -- function f(x, {arguments}) writing the result into its first argument (with length `ndim_f`)
+///
+/// # Report
+///
+/// The panic message lists the violating entries (up to ten), sorted from the largest error.
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::deriv_check_jacobian;
+/// struct Arguments {}
+/// let f = |fx: &mut [f64], x: &[f64], _: &mut Arguments| {
+///     fx[0] = f64::sin(x[0]) * x[1];
+///     fx[1] = x[0] * x[0] * x[0];
+/// };
+/// let args = &mut Arguments {};
+/// let x = [0.5, 2.0];
+/// let jac = [
+///     [f64::cos(x[0]) * x[1], f64::sin(x[0])],
+///     [3.0 * x[0] * x[0], 0.0],
+/// ];
+/// deriv_check_jacobian(&jac, &x, args, 1e-9, f);
+/// ```
+pub fn deriv_check_jacobian<J, F, A>(jac: &[J], at_x: &[f64], args: &mut A, tol: f64, f: F)
+where
+    J: AsRef<[f64]>,
+    F: FnMut(&mut [f64], &[f64], &mut A),
+{
+    check_dims(jac, at_x.len());
+    let jac_num = deriv_jacobian_central5(jac.len(), at_x, args, f);
+    compare_jacobians(jac, &jac_num, tol);
+}
+
+/// Panics if an analytical Jacobian matrix is not approximately equal to the complex-step Jacobian matrix
+///
+/// The entries are compared as in [deriv_check_jacobian]; however, the numerical Jacobian is computed
+/// by [deriv_jacobian_complex_step]. Thus, the reference values are accurate to machine precision
+/// and a very small `tol` can be used. The function must support complex arguments.
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use russell_chk::deriv_check_jacobian_complex_step;
+/// struct Arguments {}
+/// let f = |fx: &mut [Complex64], x: &[Complex64], _: &mut Arguments| {
+///     fx[0] = x[0].sin() * x[1];
+///     fx[1] = x[0] * x[0] * x[0];
+/// };
+/// let args = &mut Arguments {};
+/// let x = [0.5, 2.0];
+/// let jac = [
+///     [f64::cos(x[0]) * x[1], f64::sin(x[0])],
+///     [3.0 * x[0] * x[0], 0.0],
+/// ];
+/// deriv_check_jacobian_complex_step(&jac, &x, args, 1e-15, f);
+/// ```
+pub fn deriv_check_jacobian_complex_step<J, F, A>(jac: &[J], at_x: &[f64], args: &mut A, tol: f64, f: F)
+where
+    J: AsRef<[f64]>,
+    F: FnMut(&mut [Complex64], &[Complex64], &mut A),
+{
+    check_dims(jac, at_x.len());
+    let jac_num = deriv_jacobian_complex_step(jac.len(), at_x, args, f);
+    compare_jacobians(jac, &jac_num, tol);
+}
+
+/// Panics if the number of columns of the Jacobian matrix differs from the dimension of x
+fn check_dims<J: AsRef<[f64]>>(jac: &[J], ndim_x: usize) {
+    for (i, row) in jac.iter().enumerate() {
+        if row.as_ref().len() != ndim_x {
+            panic!(
+                "jacobian dimensions differ. row {} has {} columns but x.len() = {}",
+                i,
+                row.as_ref().len(),
+                ndim_x
+            );
+        }
+    }
+}
+
+/// Panics if the analytical and numerical Jacobian matrices are not approximately equal
+fn compare_jacobians<J: AsRef<[f64]>>(jac: &[J], jac_num: &[Vec<f64>], tol: f64) {
+    let mut violations = Vec::new();
+    for (i, (row, row_num)) in jac.iter().zip(jac_num).enumerate() {
+        for (j, (ana, num)) in row.as_ref().iter().zip(row_num).enumerate() {
+            let scaled = f64::abs(ana - num) / f64::max(1.0, f64::abs(*num));
+            if scaled > tol || scaled.is_nan() {
+                violations.push((i, j, *ana, *num, scaled));
+            }
+        }
+    }
+    if violations.is_empty() {
+        return;
+    }
+    let nentry = jac_num.len() * jac_num.first().map_or(0, |row| row.len());
+    panic!("{}", derivative_report("jacobian", violations, nentry, tol));
+}
+
+/// Formats the report of the violating entries of a derivative check
+///
+/// Each violation is given by (i, j, analytical, numerical, scaled diff).
+pub(crate) fn derivative_report(
+    name: &str,
+    mut violations: Vec<(usize, usize, f64, f64, f64)>,
+    nentry: usize,
+    tol: f64,
+) -> String {
+    violations.sort_by(|a, b| b.4.total_cmp(&a.4));
+    let (i, j, _, _, worst) = violations[0];
+    let mut buffer = format!(
+        "{} is not approximately equal to numerical value. @ ({},{}) scaled diff = {:?}\n\
+         {} of {} entries exceed tol = {:?}\n",
+        name,
+        i,
+        j,
+        worst,
+        violations.len(),
+        nentry,
+        tol
+    );
+    buffer.push_str(&format!(
+        "{:>10} {:>24} {:>24} {:>12}\n",
+        "(i,j)", "analytical", "numerical", "scaled diff"
+    ));
+    for (i, j, ana, num, scaled) in violations.iter().take(REPORT_MAX_ENTRIES) {
+        buffer.push_str(&format!(
+            "{:>10} {:>24?} {:>24?} {:>12.3e}\n",
+            format!("({},{})", i, j),
+            ana,
+            num,
+            scaled
+        ));
+    }
+    if violations.len() > REPORT_MAX_ENTRIES {
+        buffer.push_str(&format!("{:>10}\n", "..."));
+    }
+    buffer
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{deriv_check_jacobian, deriv_check_jacobian_complex_step, deriv_jacobian_central5};
+    use crate::vec_approx_eq;
+    use num_complex::Complex64;
+
+    struct Arguments {
+        count: usize,
+    }
+
+    // f₀ = x₀² x₁ and f₁ = 5 x₀ + sin(x₁) and f₂ = exp(x₀ x₁)
+    fn f(fx: &mut [f64], x: &[f64], args: &mut Arguments) {
+        args.count += 1;
+        fx[0] = x[0] * x[0] * x[1];
+        fx[1] = 5.0 * x[0] + f64::sin(x[1]);
+        fx[2] = f64::exp(x[0] * x[1]);
+    }
+
+    fn jacobian(x: &[f64]) -> Vec<Vec<f64>> {
+        let e = f64::exp(x[0] * x[1]);
+        vec![
+            vec![2.0 * x[0] * x[1], x[0] * x[0]],
+            vec![5.0, f64::cos(x[1])],
+            vec![x[1] * e, x[0] * e],
+        ]
+    }
+
+    #[test]
+    fn deriv_jacobian_central5_works() {
+        let args = &mut Arguments { count: 0 };
+        let x = [1.5, -0.5];
+        let jac_num = deriv_jacobian_central5(3, &x, args, f);
+        let jac = jacobian(&x);
+        assert!(args.count > 0);
+        for i in 0..3 {
+            vec_approx_eq(&jac_num[i], &jac[i], 1e-10);
+        }
+    }
+
+    #[test]
+    fn accepts_correct_jacobian() {
+        let args = &mut Arguments { count: 0 };
+        let x = [1.5, -0.5];
+        deriv_check_jacobian(&jacobian(&x), &x, args, 1e-10, f);
+        // arrays are accepted as well
+        let g = |gx: &mut [f64], x: &[f64], _: &mut Arguments| gx[0] = 2.0 * x[0];
+        deriv_check_jacobian(&[[2.0]], &[3.0], args, 1e-10, g);
+    }
+
+    #[test]
+    #[should_panic(expected = "jacobian dimensions differ. row 1 has 1 columns but x.len() = 2")]
+    fn panics_on_wrong_dimensions() {
+        let args = &mut Arguments { count: 0 };
+        let jac = vec![vec![0.0, 0.0], vec![0.0], vec![0.0, 0.0]];
+        deriv_check_jacobian(&jac, &[1.0, 2.0], args, 1e-10, f);
+    }
+
+    #[test]
+    #[should_panic(expected = "jacobian is not approximately equal to numerical value. @ (1,0) scaled diff = ")]
+    fn panics_on_wrong_jacobian() {
+        let args = &mut Arguments { count: 0 };
+        let x = [1.5, -0.5];
+        let mut jac = jacobian(&x);
+        jac[1][0] = 5.5;
+        jac[2][1] *= 1.01;
+        deriv_check_jacobian(&jac, &x, args, 1e-8, f);
+    }
+
+    #[test]
+    #[should_panic(expected = "2 of 6 entries exceed tol = 1e-8")]
+    fn reports_all_violations() {
+        let args = &mut Arguments { count: 0 };
+        let x = [1.5, -0.5];
+        let mut jac = jacobian(&x);
+        jac[1][0] = 5.5;
+        jac[2][1] *= 1.01;
+        deriv_check_jacobian(&jac, &x, args, 1e-8, f);
+    }
+
+    #[test]
+    fn accepts_correct_jacobian_complex_step() {
+        let g = |gx: &mut [Complex64], x: &[Complex64], _: &mut Arguments| {
+            gx[0] = x[0] * x[0] * x[1];
+            gx[1] = x[0].exp();
+        };
+        let args = &mut Arguments { count: 0 };
+        let x = [1.5, -0.5];
+        let jac = [[2.0 * x[0] * x[1], x[0] * x[0]], [f64::exp(x[0]), 0.0]];
+        deriv_check_jacobian_complex_step(&jac, &x, args, 1e-15, g);
+    }
+
+    #[test]
+    #[should_panic(expected = "jacobian is not approximately equal to numerical value. @ (1,0) scaled diff = ")]
+    fn panics_on_wrong_jacobian_complex_step() {
+        let g = |gx: &mut [Complex64], x: &[Complex64], _: &mut Arguments| {
+            gx[0] = x[0] * x[0] * x[1];
+            gx[1] = x[0].exp();
+        };
+        let args = &mut Arguments { count: 0 };
+        let x = [1.5, -0.5];
+        let jac = [[2.0 * x[0] * x[1], x[0] * x[0]], [f64::exp(x[0]) * (1.0 + 1e-12), 0.0]];
+        deriv_check_jacobian_complex_step(&jac, &x, args, 1e-14, g);
+    }
+}
@@ -0,0 +1,111 @@
+/// Accumulates the differences between the entries of two arrays
+///
+/// This structure is shared by the reports of [crate::vec_approx_eq] and of the matrix comparisons
+/// in other crates (e.g., `russell_lab::mat_approx_eq`); thus, all reports have the same summary.
+///
+/// `I` is the type of the index of an entry (e.g., `usize` for vectors or `(usize, usize)` for matrices).
+#[doc(hidden)]
+pub struct DiffReport<I> {
+    /// Tolerance
+    pub tol: f64,
+
+    /// Index of the worst violation (largest absolute difference exceeding `tol`)
+    pub worst: I,
+
+    /// Largest absolute difference exceeding `tol`
+    pub max_abs: f64,
+
+    /// Largest relative difference (see [relative_diff])
+    pub max_rel: f64,
+
+    /// Number of entries exceeding `tol`
+    pub count: usize,
+
+    /// Total number of entries
+    pub total: usize,
+}
+
+impl<I: Copy + Default> DiffReport<I> {
+    /// Allocates a new instance
+    pub fn new(tol: f64) -> Self {
+        DiffReport {
+            tol,
+            worst: I::default(),
+            max_abs: 0.0,
+            max_rel: 0.0,
+            count: 0,
+            total: 0,
+        }
+    }
+
+    /// Returns whether the difference between `a` and `b` exceeds the tolerance or not
+    pub fn exceeds(&self, a: f64, b: f64) -> bool {
+        f64::abs(a - b) > self.tol
+    }
+
+    /// Adds the entries `a` and `b` at `index` to the statistics
+    pub fn add(&mut self, index: I, a: f64, b: f64) {
+        let diff = f64::abs(a - b);
+        if diff > self.tol {
+            self.count += 1;
+            if diff > self.max_abs {
+                self.max_abs = diff;
+                self.worst = index;
+            }
+        }
+        self.max_rel = f64::max(self.max_rel, relative_diff(a, b));
+        self.total += 1;
+    }
+
+    /// Returns the summary line (with the trailing newline)
+    pub fn summary(&self) -> String {
+        format!(
+            "max abs diff = {:?}, max rel diff = {:?}, {} of {} entries exceed tol = {:?}\n",
+            self.max_abs, self.max_rel, self.count, self.total, self.tol
+        )
+    }
+}
+
+/// Returns |a - b| / max(|a|, |b|) or zero if a = b
+#[doc(hidden)]
+pub fn relative_diff(a: f64, b: f64) -> f64 {
+    if a == b {
+        0.0
+    } else {
+        f64::abs(a - b) / f64::max(f64::abs(a), f64::abs(b))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{relative_diff, DiffReport};
+
+    #[test]
+    fn relative_diff_works() {
+        assert_eq!(relative_diff(0.0, 0.0), 0.0);
+        assert_eq!(relative_diff(2.0, 1.0), 0.5);
+        assert_eq!(relative_diff(-1.0, 1.0), 2.0);
+    }
+
+    #[test]
+    fn diff_report_works() {
+        let mut report: DiffReport<(usize, usize)> = DiffReport::new(0.1);
+        report.add((0, 0), 1.0, 1.0);
+        report.add((0, 1), 1.0, 1.05);
+        report.add((1, 0), 2.0, 1.0);
+        report.add((1, 1), 4.0, 4.5);
+        assert!(!report.exceeds(1.0, 1.05));
+        assert!(report.exceeds(2.0, 1.0));
+        assert_eq!(report.worst, (1, 0));
+        assert_eq!(report.max_abs, 1.0);
+        assert_eq!(report.max_rel, 0.5);
+        assert_eq!(report.count, 2);
+        assert_eq!(report.total, 4);
+        assert_eq!(
+            report.summary(),
+            "max abs diff = 1.0, max rel diff = 0.5, 2 of 4 entries exceed tol = 0.1\n"
+        );
+    }
+}
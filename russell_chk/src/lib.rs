@@ -6,12 +6,16 @@
 //!
 //! ```
 //! use num_complex::Complex64;
-//! use russell_chk::{approx_eq, complex_approx_eq, deriv_approx_eq, vec_approx_eq};
+//! use russell_chk::{approx_eq, approx_eq_rel, assert_ulps_eq, complex_approx_eq, deriv_approx_eq, vec_approx_eq};
 //!
 //! fn main() {
 //!     // check float point number
 //!     approx_eq(0.0000123, 0.000012, 1e-6);
 //!
+//!     // check float point numbers spanning many orders of magnitude
+//!     approx_eq_rel(1.0000001e-20, 1e-20, 1e-6);
+//!     assert_ulps_eq(0.1 + 0.2, 0.3, 1);
+//!
 //!     // check vector of float point numbers
 //!     vec_approx_eq(&[0.01, 0.012], &[0.012, 0.01], 1e-2);
 //!
@@ -32,16 +36,36 @@
 pub type StrError = &'static str;
 
 mod approx_eq;
+mod approx_eq_rel;
+mod approx_eq_slices;
+mod assert_ulps_eq;
 mod complex_approx_eq;
 mod complex_vec_approx_eq;
+mod deriv2_approx_eq;
 mod deriv_approx_eq;
+mod deriv_check_hessian;
+mod deriv_check_jacobian;
+mod diff_report;
 mod num_deriv;
+mod num_deriv2;
+mod num_deriv_complex_step;
+mod test_data_generator;
 mod vec_approx_eq;
 pub use crate::approx_eq::*;
+pub use crate::approx_eq_rel::*;
+pub use crate::approx_eq_slices::*;
+pub use crate::assert_ulps_eq::*;
 pub use crate::complex_approx_eq::*;
 pub use crate::complex_vec_approx_eq::*;
+pub use crate::deriv2_approx_eq::*;
 pub use crate::deriv_approx_eq::*;
+pub use crate::deriv_check_hessian::*;
+pub use crate::deriv_check_jacobian::*;
+pub use crate::diff_report::*;
 pub use crate::num_deriv::*;
+pub use crate::num_deriv2::*;
+pub use crate::num_deriv_complex_step::*;
+pub use crate::test_data_generator::*;
 pub use crate::vec_approx_eq::*;
 
 // run code from README file
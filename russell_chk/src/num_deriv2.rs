@@ -0,0 +1,224 @@
+/// Initial stepsize h for the derivatives computed with Richardson extrapolation
+pub const STEPSIZE_RICHARDSON: f64 = 0.05;
+
+/// Defines the number of levels (halvings of h) of the Richardson extrapolation
+const RICHARDSON_NLEVEL: usize = 4;
+
+/// Computes the numerical second derivative using central differences with Richardson extrapolation
+///
+/// # Input
+///
+/// * `at_x` -- location for the second derivative of f(x, {arguments}) w.r.t x
+/// * `args` -- extra arguments for f(x, {arguments})
+/// * `f` -- function f(x, {arguments})
+///
+/// **IMPORTANT:** The function is evaluated in [at_x-h, at_x+h] with h = [STEPSIZE_RICHARDSON].
+///
+/// # Output
+///
+/// * `d2fdx2` -- numerical second derivative of f(x) w.r.t x @ x
+///
+/// # Notes
+///
+/// * The three-point formula `(f(x+h) - 2 f(x) + f(x-h)) / h²` (with error O(h²)) is evaluated
+///   with h, h/2, h/4, h/8 and the results are combined by Richardson extrapolation (error O(h⁸))
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::deriv2_richardson;
+/// struct Arguments {}
+/// let f = |x: f64, _: &mut Arguments| f64::exp(-2.0 * x);
+/// let args = &mut Arguments {};
+/// let at_x = 1.0;
+/// let d2 = deriv2_richardson(at_x, args, f);
+/// let d2_correct = 4.0 * f64::exp(-2.0 * at_x);
+/// assert!(f64::abs(d2 - d2_correct) < 1e-10);
+/// ```
+pub fn deriv2_richardson<F, A>(at_x: f64, args: &mut A, mut f: F) -> f64
+where
+    F: FnMut(f64, &mut A) -> f64,
+{
+    let fx = f(at_x, args);
+    richardson(STEPSIZE_RICHARDSON, |h| {
+        (f(at_x + h, args) - 2.0 * fx + f(at_x - h, args)) / (h * h)
+    })
+}
+
+/// Computes the Hessian matrix H = ∂²f/∂x∂x numerically using central differences with Richardson extrapolation
+///
+/// # Input
+///
+/// * `at_x` -- location for the second derivatives of the scalar function f(x, {arguments}) w.r.t x
+/// * `args` -- extra arguments for f(x, {arguments})
+/// * `f` -- scalar function f(x, {arguments})
+///
+/// # Output
+///
+/// Returns the (symmetric) Hessian matrix as a vector of rows
+///
+/// # Notes
+///
+/// * The diagonal entries are computed as in [deriv2_richardson]
+/// * The off-diagonal entries are computed by the four-point formula
+///   `(f(x+hᵢ+hⱼ) - f(x+hᵢ-hⱼ) - f(x-hᵢ+hⱼ) + f(x-hᵢ-hⱼ)) / (4 h²)` followed by Richardson extrapolation
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::{deriv_hessian_richardson, vec_approx_eq};
+/// struct Arguments {}
+/// let f = |x: &[f64], _: &mut Arguments| x[0] * x[0] * x[1];
+/// let args = &mut Arguments {};
+/// let hess = deriv_hessian_richardson(&[3.0, 2.0], args, f);
+/// vec_approx_eq(&hess[0], &[4.0, 6.0], 1e-9);
+/// vec_approx_eq(&hess[1], &[6.0, 0.0], 1e-9);
+/// ```
+pub fn deriv_hessian_richardson<F, A>(at_x: &[f64], args: &mut A, mut f: F) -> Vec<Vec<f64>>
+where
+    F: FnMut(&[f64], &mut A) -> f64,
+{
+    let n = at_x.len();
+    let mut hess = vec![vec![0.0; n]; n];
+    let mut x = at_x.to_vec();
+    let fx = f(&x, args);
+    for i in 0..n {
+        hess[i][i] = richardson(STEPSIZE_RICHARDSON, |h| {
+            x[i] = at_x[i] + h;
+            let fp = f(&x, args);
+            x[i] = at_x[i] - h;
+            let fm = f(&x, args);
+            x[i] = at_x[i];
+            (fp - 2.0 * fx + fm) / (h * h)
+        });
+        for j in 0..i {
+            let hij = richardson(STEPSIZE_RICHARDSON, |h| {
+                let mut eval = |si: f64, sj: f64| {
+                    x[i] = at_x[i] + si * h;
+                    x[j] = at_x[j] + sj * h;
+                    f(&x, args)
+                };
+                let res = eval(1.0, 1.0) - eval(1.0, -1.0) - eval(-1.0, 1.0) + eval(-1.0, -1.0);
+                x[i] = at_x[i];
+                x[j] = at_x[j];
+                res / (4.0 * h * h)
+            });
+            hess[i][j] = hij;
+            hess[j][i] = hij;
+        }
+    }
+    hess
+}
+
+/// Performs the Richardson extrapolation of a difference formula with error O(h²) (even powers of h)
+///
+/// Returns the most accurate entry of the Neville-like table computed with h, h/2, h/4, ...
+fn richardson<D>(h: f64, mut d: D) -> f64
+where
+    D: FnMut(f64) -> f64,
+{
+    let mut table = [[0.0; RICHARDSON_NLEVEL]; RICHARDSON_NLEVEL];
+    let mut hk = h;
+    for k in 0..RICHARDSON_NLEVEL {
+        table[k][0] = d(hk);
+        let mut factor = 1.0;
+        for m in 1..(k + 1) {
+            factor *= 4.0;
+            table[k][m] = (factor * table[k][m - 1] - table[k - 1][m - 1]) / (factor - 1.0);
+        }
+        hk /= 2.0;
+    }
+    table[RICHARDSON_NLEVEL - 1][RICHARDSON_NLEVEL - 1]
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{deriv2_richardson, deriv_hessian_richardson, richardson};
+    use crate::{approx_eq, vec_approx_eq};
+    use std::f64::consts::PI;
+
+    struct Arguments {}
+
+    #[test]
+    fn richardson_works() {
+        // d(h) = 1 + h² + h⁴ + h⁶ is extrapolated exactly
+        let d = |h: f64| 1.0 + h * h + f64::powi(h, 4) + f64::powi(h, 6);
+        approx_eq(richardson(0.1, d), 1.0, 1e-15);
+    }
+
+    struct TestFunction {
+        pub name: &'static str,                // name
+        pub f: fn(f64, &mut Arguments) -> f64, // f(x)
+        pub h: fn(f64) -> f64,                 // h=d²f/dx²
+        pub at_x: f64,                         // @x value
+        pub tol_diff: f64,                     // tolerance for |num - ana|
+    }
+
+    #[test]
+    fn deriv2_richardson_works() {
+        let tests = [
+            TestFunction {
+                name: "x²",
+                f: |x, _| x * x,
+                h: |_| 2.0,
+                at_x: 1.0,
+                tol_diff: 1e-10,
+            },
+            TestFunction {
+                name: "exp(x)",
+                f: |x, _| f64::exp(x),
+                h: |x| f64::exp(x),
+                at_x: 2.0,
+                tol_diff: 1e-10,
+            },
+            TestFunction {
+                name: "exp(-x²)",
+                f: |x, _| f64::exp(-x * x),
+                h: |x| (4.0 * x * x - 2.0) * f64::exp(-x * x),
+                at_x: 2.0,
+                tol_diff: 1e-10,
+            },
+            TestFunction {
+                name: "1/x",
+                f: |x, _| 1.0 / x,
+                h: |x| 2.0 / (x * x * x),
+                at_x: 0.2,
+                tol_diff: 1e-5,
+            },
+            TestFunction {
+                name: "cos(π⋅x/2)",
+                f: |x, _| f64::cos(PI * x / 2.0),
+                h: |x| -f64::cos(PI * x / 2.0) * PI * PI / 4.0,
+                at_x: 0.5,
+                tol_diff: 1e-10,
+            },
+        ];
+        let args = &mut Arguments {};
+        for test in &tests {
+            let num = deriv2_richardson(test.at_x, args, test.f);
+            let ana = (test.h)(test.at_x);
+            println!("{:>10}: |num - ana| = {:.2e}", test.name, f64::abs(num - ana));
+            approx_eq(num, ana, test.tol_diff);
+        }
+    }
+
+    #[test]
+    fn deriv_hessian_richardson_works() {
+        // f = sin(x₀) x₁² + x₁ x₂³
+        let f = |x: &[f64], _: &mut Arguments| f64::sin(x[0]) * x[1] * x[1] + x[1] * x[2] * x[2] * x[2];
+        let args = &mut Arguments {};
+        let x = [0.5, 1.5, -1.0];
+        let hess = deriv_hessian_richardson(&x, args, f);
+        let (s, c) = (f64::sin(x[0]), f64::cos(x[0]));
+        let correct = [
+            [-s * x[1] * x[1], 2.0 * c * x[1], 0.0],
+            [2.0 * c * x[1], 2.0 * s, 3.0 * x[2] * x[2]],
+            [0.0, 3.0 * x[2] * x[2], 6.0 * x[1] * x[2]],
+        ];
+        for i in 0..3 {
+            vec_approx_eq(&hess[i], &correct[i], 1e-9);
+        }
+    }
+}
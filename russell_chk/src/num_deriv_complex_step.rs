@@ -0,0 +1,132 @@
+use num_complex::Complex64;
+
+/// Stepsize h for the complex-step derivative
+///
+/// Since there is no subtractive cancellation, h can be made tiny.
+pub const STEPSIZE_COMPLEX_STEP: f64 = 1e-100;
+
+/// Computes the numerical derivative using the complex-step method
+///
+/// ```text
+/// df/dx ≈ Im(f(x + i h)) / h
+/// ```
+///
+/// # Input
+///
+/// * `at_x` -- location for the derivative of f(x, {arguments}) w.r.t x
+/// * `args` -- extra arguments for f(x, {arguments})
+/// * `f` -- function f(x, {arguments}) evaluated with complex numbers
+///
+/// # Output
+///
+/// * `dfdx` -- numerical derivative of f(x) w.r.t x @ x
+///
+/// # Notes
+///
+/// * The error is O(h²) without any cancellation error; hence, the result is accurate to machine precision
+/// * The function must be analytic (e.g., no `abs`, no comparisons on the imaginary part, no conjugation)
+///   and must be implemented with the complex counterparts of the real functions
+///
+/// Reference: Squire W and Trapp G (1998) Using complex variables to estimate derivatives of real functions,
+/// SIAM Review, 40(1):110-112
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use russell_chk::deriv_complex_step;
+/// struct Arguments {}
+/// let f = |x: Complex64, _: &mut Arguments| (-2.0 * x).exp();
+/// let args = &mut Arguments {};
+/// let at_x = 1.0;
+/// let d = deriv_complex_step(at_x, args, f);
+/// let d_correct = -2.0 * f64::exp(-2.0 * at_x);
+/// assert!(f64::abs(d - d_correct) < 1e-16);
+/// ```
+pub fn deriv_complex_step<F, A>(at_x: f64, args: &mut A, mut f: F) -> f64
+where
+    F: FnMut(Complex64, &mut A) -> Complex64,
+{
+    f(Complex64::new(at_x, STEPSIZE_COMPLEX_STEP), args).im / STEPSIZE_COMPLEX_STEP
+}
+
+/// Computes the Jacobian matrix J = ∂f/∂x numerically using the complex-step method
+///
+/// # Input
+///
+/// * `ndim_f` -- the dimension of f
+/// * `at_x` -- location for the derivatives of f(x, {arguments}) w.r.t x
+/// * `args` -- extra arguments for f(x, {arguments})
+/// * `f` -- function f(x, {arguments}) evaluated with complex numbers and
+///   writing the result into its first argument (with length `ndim_f`)
+///
+/// # Output
+///
+/// Returns the (ndim_f × at_x.len()) Jacobian matrix as a vector of rows
+///
+/// # Note
+///
+/// Only one evaluation of f is required per column of the Jacobian matrix.
+pub fn deriv_jacobian_complex_step<F, A>(ndim_f: usize, at_x: &[f64], args: &mut A, mut f: F) -> Vec<Vec<f64>>
+where
+    F: FnMut(&mut [Complex64], &[Complex64], &mut A),
+{
+    let ndim_x = at_x.len();
+    let mut jac = vec![vec![0.0; ndim_x]; ndim_f];
+    let mut x: Vec<Complex64> = at_x.iter().map(|v| Complex64::new(*v, 0.0)).collect();
+    let mut fx = vec![Complex64::new(0.0, 0.0); ndim_f];
+    for j in 0..ndim_x {
+        x[j].im = STEPSIZE_COMPLEX_STEP;
+        f(&mut fx, &x, args);
+        x[j].im = 0.0;
+        for (row, fxi) in jac.iter_mut().zip(&fx) {
+            row[j] = fxi.im / STEPSIZE_COMPLEX_STEP;
+        }
+    }
+    jac
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{deriv_complex_step, deriv_jacobian_complex_step};
+    use crate::{approx_eq, assert_ulps_eq, vec_approx_eq};
+    use num_complex::Complex64;
+
+    struct Arguments {}
+
+    #[test]
+    fn deriv_complex_step_works() {
+        let args = &mut Arguments {};
+
+        // x⋅√x
+        let f = |x: Complex64, _: &mut Arguments| x * x.sqrt();
+        assert_ulps_eq(deriv_complex_step(25.0, args, f), 7.5, 1);
+
+        // sin(1/x)
+        let f = |x: Complex64, _: &mut Arguments| (1.0 / x).sin();
+        let x: f64 = 0.5;
+        approx_eq(deriv_complex_step(x, args, f), -f64::cos(1.0 / x) / (x * x), 1e-15);
+
+        // exp(x) / √(sin³x + cos³x) (Squire and Trapp)
+        let f = |x: Complex64, _: &mut Arguments| x.exp() / (x.sin().powi(3) + x.cos().powi(3)).sqrt();
+        approx_eq(deriv_complex_step(1.5, args, f), 4.053427893898621, 1e-14);
+    }
+
+    #[test]
+    fn deriv_jacobian_complex_step_works() {
+        let f = |fx: &mut [Complex64], x: &[Complex64], _: &mut Arguments| {
+            fx[0] = x[0] * x[0] * x[1];
+            fx[1] = 5.0 * x[0] + x[1].sin();
+            fx[2] = (x[0] * x[1]).exp();
+        };
+        let args = &mut Arguments {};
+        let x = [1.5, -0.5];
+        let jac = deriv_jacobian_complex_step(3, &x, args, f);
+        let e = f64::exp(x[0] * x[1]);
+        vec_approx_eq(&jac[0], &[2.0 * x[0] * x[1], x[0] * x[0]], 1e-15);
+        vec_approx_eq(&jac[1], &[5.0, f64::cos(x[1])], 1e-15);
+        vec_approx_eq(&jac[2], &[x[1] * e, x[0] * e], 1e-15);
+    }
+}
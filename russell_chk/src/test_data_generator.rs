@@ -0,0 +1,355 @@
+/// Generates reproducible pseudo-random vectors and matrices for tests
+///
+/// The generator is self-contained (xoshiro256** seeded by SplitMix64); thus, the same seed
+/// yields exactly the same data on every platform and with any version of external crates.
+/// Therefore, property-based tests produce reproducible failures: print the seed on failure
+/// and rerun with it.
+///
+/// The matrices are returned as a list of rows (`Vec<Vec<f64>>`), which can be converted
+/// into the matrix types of the other crates (e.g., `Matrix::from(&rows)` in russell_lab).
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::TestDataGenerator;
+///
+/// let mut gen = TestDataGenerator::new(1234);
+/// let u = gen.vector(3, -1.0, 1.0);
+/// let a = gen.matrix_with_condition(4, 3, 1e6);
+/// assert_eq!(a.len(), 4);
+/// assert_eq!(a[0].len(), 3);
+///
+/// // the same seed yields the same data
+/// let mut gen = TestDataGenerator::new(1234);
+/// assert_eq!(gen.vector(3, -1.0, 1.0), u);
+/// ```
+pub struct TestDataGenerator {
+    state: [u64; 4], // state of xoshiro256**
+}
+
+impl TestDataGenerator {
+    /// Creates a new generator with the given seed
+    pub fn new(seed: u64) -> Self {
+        let mut x = seed;
+        let mut state = [0; 4];
+        for s in &mut state {
+            *s = splitmix64(&mut x);
+        }
+        TestDataGenerator { state }
+    }
+
+    /// Returns the next pseudo-random 64-bit integer
+    pub fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+
+    /// Returns a pseudo-random number uniformly distributed in [min, max)
+    pub fn uniform(&mut self, min: f64, max: f64) -> f64 {
+        let u = ((self.next_u64() >> 11) as f64) * (1.0 / ((1_u64 << 53) as f64));
+        min + (max - min) * u
+    }
+
+    /// Returns a pseudo-random number with standard normal distribution (Box-Muller method)
+    pub fn normal(&mut self) -> f64 {
+        let u1 = 1.0 - self.uniform(0.0, 1.0); // in (0, 1]
+        let u2 = self.uniform(0.0, 1.0);
+        f64::sqrt(-2.0 * f64::ln(u1)) * f64::cos(2.0 * std::f64::consts::PI * u2)
+    }
+
+    /// Returns a vector with components uniformly distributed in [min, max)
+    pub fn vector(&mut self, n: usize, min: f64, max: f64) -> Vec<f64> {
+        (0..n).map(|_| self.uniform(min, max)).collect()
+    }
+
+    /// Returns an (m,n) matrix with components uniformly distributed in [min, max)
+    pub fn matrix(&mut self, m: usize, n: usize, min: f64, max: f64) -> Vec<Vec<f64>> {
+        (0..m).map(|_| self.vector(n, min, max)).collect()
+    }
+
+    /// Returns an (n,n) symmetric matrix with components uniformly distributed in [min, max)
+    pub fn symmetric(&mut self, n: usize, min: f64, max: f64) -> Vec<Vec<f64>> {
+        let upper: Vec<Vec<f64>> = (0..n).map(|i| self.vector(n - i, min, max)).collect();
+        (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| if j >= i { upper[i][j - i] } else { upper[j][i - j] })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns an (n,n) orthogonal matrix
+    ///
+    /// The matrix is computed by the QR decomposition (modified Gram-Schmidt) of a matrix
+    /// with standard normal components, with the signs fixed such that diag(R) > 0.
+    /// Thus, the result is uniformly distributed (Haar measure) over the orthogonal matrices.
+    pub fn orthogonal(&mut self, n: usize) -> Vec<Vec<f64>> {
+        // columns of the Gaussian matrix
+        let mut q: Vec<Vec<f64>> = (0..n).map(|_| (0..n).map(|_| self.normal()).collect()).collect();
+        for j in 0..n {
+            for k in 0..j {
+                let dot: f64 = q[k].iter().zip(&q[j]).map(|(a, b)| a * b).sum();
+                let qk = q[k].clone();
+                for (x, y) in q[j].iter_mut().zip(&qk) {
+                    *x -= dot * y;
+                }
+            }
+            let norm = f64::sqrt(q[j].iter().map(|x| x * x).sum());
+            for x in q[j].iter_mut() {
+                *x /= norm;
+            }
+        }
+        // transpose (list of columns → list of rows)
+        (0..n).map(|i| (0..n).map(|j| q[j][i]).collect()).collect()
+    }
+
+    /// Returns an (m,n) matrix with given (2-norm) condition number
+    ///
+    /// Computes `a = u⋅Σ⋅vᵀ` where u and v are random orthogonal matrices and the
+    /// singular values are geometrically distributed from 1 to 1/cond.
+    ///
+    /// # Input
+    ///
+    /// * `cond` -- the condition number σmax/σmin (≥ 1)
+    pub fn matrix_with_condition(&mut self, m: usize, n: usize, cond: f64) -> Vec<Vec<f64>> {
+        let k = usize::min(m, n);
+        let sigma = geometric_sequence(k, cond);
+        let u = self.orthogonal(m);
+        let v = self.orthogonal(n);
+        let mut a = vec![vec![0.0; n]; m];
+        for i in 0..m {
+            for j in 0..n {
+                for (l, s) in sigma.iter().enumerate() {
+                    a[i][j] += u[i][l] * s * v[j][l];
+                }
+            }
+        }
+        a
+    }
+
+    /// Returns an (n,n) symmetric positive-definite matrix with given (2-norm) condition number
+    ///
+    /// Computes `a = q⋅Λ⋅qᵀ` where q is a random orthogonal matrix and the eigenvalues are
+    /// geometrically distributed from 1 to 1/cond.
+    ///
+    /// # Input
+    ///
+    /// * `cond` -- the condition number λmax/λmin (≥ 1)
+    pub fn spd_with_condition(&mut self, n: usize, cond: f64) -> Vec<Vec<f64>> {
+        let lambda = geometric_sequence(n, cond);
+        let q = self.orthogonal(n);
+        let mut a = vec![vec![0.0; n]; n];
+        for i in 0..n {
+            for j in i..n {
+                let v: f64 = lambda.iter().enumerate().map(|(l, s)| q[i][l] * s * q[j][l]).sum();
+                a[i][j] = v;
+                a[j][i] = v;
+            }
+        }
+        a
+    }
+
+    /// Returns a sparse (m,n) matrix in triplet (coordinate) format
+    ///
+    /// Each entry is non-zero with probability `density`; the values are uniformly distributed in [-1, 1).
+    /// The triplets (i, j, aᵢⱼ) are sorted by row and then by column.
+    ///
+    /// # Input
+    ///
+    /// * `density` -- the probability of an entry being non-zero (in [0, 1])
+    /// * `diagonally_dominant` -- (square matrices only) sets all diagonal entries to `1 + Σⱼ|aᵢⱼ|`;
+    ///   thus, the matrix is non-singular
+    pub fn sparse(&mut self, m: usize, n: usize, density: f64, diagonally_dominant: bool) -> Vec<(usize, usize, f64)> {
+        if diagonally_dominant && m != n {
+            panic!("a diagonally dominant matrix must be square. {} != {}", m, n);
+        }
+        let mut triplets = Vec::new();
+        for i in 0..m {
+            let mut row_sum = 0.0;
+            let mut diag_position = None;
+            for j in 0..n {
+                let is_diag = diagonally_dominant && i == j;
+                if self.uniform(0.0, 1.0) < density || is_diag {
+                    let v = self.uniform(-1.0, 1.0);
+                    if is_diag {
+                        diag_position = Some(triplets.len());
+                    } else {
+                        row_sum += f64::abs(v);
+                    }
+                    triplets.push((i, j, v));
+                }
+            }
+            if let Some(p) = diag_position {
+                triplets[p].2 = 1.0 + row_sum;
+            }
+        }
+        triplets
+    }
+}
+
+/// Returns the sequence 1, r, r², ..., r⁽ⁿ⁻¹⁾ with r⁽ⁿ⁻¹⁾ = 1/cond
+fn geometric_sequence(n: usize, cond: f64) -> Vec<f64> {
+    if cond < 1.0 {
+        panic!("the condition number must be ≥ 1. cond = {:?}", cond);
+    }
+    if n < 2 {
+        return vec![1.0; n];
+    }
+    (0..n)
+        .map(|i| f64::powf(cond, -(i as f64) / ((n - 1) as f64)))
+        .collect()
+}
+
+/// Implements the SplitMix64 generator (used to initialize the state)
+fn splitmix64(x: &mut u64) -> u64 {
+    *x = x.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = *x;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{geometric_sequence, TestDataGenerator};
+    use crate::{approx_eq, vec_approx_eq};
+
+    // Returns the extreme eigenvalues of a symmetric 2x2 matrix
+    fn eigenvalues_2x2(a: &[Vec<f64>]) -> (f64, f64) {
+        let mean = (a[0][0] + a[1][1]) / 2.0;
+        let r = f64::sqrt(f64::powi((a[0][0] - a[1][1]) / 2.0, 2) + a[0][1] * a[1][0]);
+        (mean - r, mean + r)
+    }
+
+    #[test]
+    fn generator_is_reproducible() {
+        let mut a = TestDataGenerator::new(1234);
+        let mut b = TestDataGenerator::new(1234);
+        let mut c = TestDataGenerator::new(4321);
+        let xa: Vec<_> = (0..5).map(|_| a.next_u64()).collect();
+        let xb: Vec<_> = (0..5).map(|_| b.next_u64()).collect();
+        let xc: Vec<_> = (0..5).map(|_| c.next_u64()).collect();
+        assert_eq!(xa, xb);
+        assert_ne!(xa, xc);
+        assert_eq!(a.matrix(3, 2, 0.0, 1.0), b.matrix(3, 2, 0.0, 1.0));
+    }
+
+    #[test]
+    fn uniform_and_normal_work() {
+        let mut gen = TestDataGenerator::new(1234);
+        let n = 20_000;
+        let u = gen.vector(n, -1.0, 3.0);
+        assert!(u.iter().all(|x| *x >= -1.0 && *x < 3.0));
+        approx_eq(u.iter().sum::<f64>() / (n as f64), 1.0, 0.05);
+        let z: Vec<_> = (0..n).map(|_| gen.normal()).collect();
+        let mean = z.iter().sum::<f64>() / (n as f64);
+        let variance = z.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / ((n - 1) as f64);
+        approx_eq(mean, 0.0, 0.03);
+        approx_eq(variance, 1.0, 0.03);
+    }
+
+    #[test]
+    fn symmetric_works() {
+        let mut gen = TestDataGenerator::new(1234);
+        let a = gen.symmetric(4, -2.0, 2.0);
+        for (i, row) in a.iter().enumerate() {
+            for (j, v) in row.iter().enumerate() {
+                assert_eq!(*v, a[j][i]);
+                assert!(*v >= -2.0 && *v < 2.0);
+            }
+        }
+    }
+
+    #[test]
+    fn orthogonal_works() {
+        let mut gen = TestDataGenerator::new(1234);
+        let n = 5;
+        let q = gen.orthogonal(n);
+        for i in 0..n {
+            for j in 0..n {
+                let qtq: f64 = (0..n).map(|k| q[k][i] * q[k][j]).sum();
+                approx_eq(qtq, if i == j { 1.0 } else { 0.0 }, 1e-14);
+            }
+        }
+    }
+
+    #[test]
+    fn geometric_sequence_works() {
+        assert_eq!(geometric_sequence(0, 10.0), &[] as &[f64]);
+        assert_eq!(geometric_sequence(1, 10.0), &[1.0]);
+        vec_approx_eq(&geometric_sequence(3, 100.0), &[1.0, 0.1, 0.01], 1e-15);
+    }
+
+    #[test]
+    #[should_panic(expected = "the condition number must be ≥ 1. cond = 0.5")]
+    fn geometric_sequence_panics_on_wrong_cond() {
+        geometric_sequence(3, 0.5);
+    }
+
+    #[test]
+    fn matrix_with_condition_works() {
+        let mut gen = TestDataGenerator::new(1234);
+        // the singular values of a (3,2) matrix are the square roots of the eigenvalues of aᵀ⋅a
+        let a = gen.matrix_with_condition(3, 2, 1e3);
+        let ata: Vec<Vec<f64>> = (0..2)
+            .map(|i| (0..2).map(|j| (0..3).map(|k| a[k][i] * a[k][j]).sum()).collect())
+            .collect();
+        let (l_min, l_max) = eigenvalues_2x2(&ata);
+        approx_eq(f64::sqrt(l_max), 1.0, 1e-14);
+        approx_eq(f64::sqrt(l_max / l_min), 1e3, 1e-7);
+    }
+
+    #[test]
+    fn spd_with_condition_works() {
+        let mut gen = TestDataGenerator::new(1234);
+        let a = gen.spd_with_condition(2, 1e4);
+        assert_eq!(a[0][1], a[1][0]);
+        let (l_min, l_max) = eigenvalues_2x2(&a);
+        approx_eq(l_max, 1.0, 1e-14);
+        approx_eq(l_min, 1e-4, 1e-14);
+        // trace = sum of eigenvalues
+        let a = gen.spd_with_condition(4, 1e3);
+        let trace: f64 = (0..4).map(|i| a[i][i]).sum();
+        approx_eq(trace, 1.0 + 0.1 + 0.01 + 0.001, 1e-14);
+    }
+
+    #[test]
+    fn sparse_works() {
+        let mut gen = TestDataGenerator::new(1234);
+        let (m, n) = (50, 40);
+        let triplets = gen.sparse(m, n, 0.1, false);
+        let density = (triplets.len() as f64) / ((m * n) as f64);
+        approx_eq(density, 0.1, 0.02);
+        for w in triplets.windows(2) {
+            assert!((w[0].0, w[0].1) < (w[1].0, w[1].1));
+        }
+        let triplets = gen.sparse(10, 10, 0.3, true);
+        for i in 0..10 {
+            let diag = triplets.iter().find(|t| t.0 == i && t.1 == i).unwrap().2;
+            let off: f64 = triplets
+                .iter()
+                .filter(|t| t.0 == i && t.1 != i)
+                .map(|t| f64::abs(t.2))
+                .sum();
+            approx_eq(diag, 1.0 + off, 1e-15);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "a diagonally dominant matrix must be square. 2 != 3")]
+    fn sparse_panics_on_rectangular_diagonally_dominant() {
+        let mut gen = TestDataGenerator::new(1234);
+        gen.sparse(2, 3, 0.5, true);
+    }
+}
@@ -1,8 +1,18 @@
+use crate::DiffReport;
 use num_traits::{Num, NumCast};
 
+/// Defines the number of neighbours (on each side of the worst entry) shown in the report
+const REPORT_NEIGHBOURS: usize = 3;
+
 /// Panics if two vectors are not approximately equal to each other
 ///
 /// Panics also if the vector dimensions differ
+///
+/// # Report
+///
+/// The panic message contains the index of the worst violation (largest absolute difference),
+/// the maximum absolute and relative differences, the number of entries exceeding the tolerance,
+/// and a side-by-side dump of the entries around the worst violation (marked with `*` if exceeding `tol`).
 pub fn vec_approx_eq<T>(u: &[T], v: &[T], tol: f64)
 where
     T: Num + NumCast + Copy,
@@ -11,12 +21,50 @@ where
     if m != v.len() {
         panic!("vector dimensions differ. {} != {}", m, v.len());
     }
-    for i in 0..m {
-        let diff = f64::abs(u[i].to_f64().unwrap() - v[i].to_f64().unwrap());
-        if diff > tol {
-            panic!("vectors are not approximately equal. @ {} diff = {:?}", i, diff);
-        }
+    let uu: Vec<f64> = u.iter().map(|x| x.to_f64().unwrap()).collect();
+    let vv: Vec<f64> = v.iter().map(|x| x.to_f64().unwrap()).collect();
+    let mut report = DiffReport::new(tol);
+    for (i, (a, b)) in uu.iter().zip(&vv).enumerate() {
+        report.add(i, *a, *b);
+    }
+    if report.count > 0 {
+        panic!(
+            "vectors are not approximately equal. @ {} diff = {:?}\n{}",
+            report.worst,
+            report.max_abs,
+            vec_report(&uu, &vv, &report)
+        );
+    }
+}
+
+/// Formats the report of the vector comparison
+fn vec_report(u: &[f64], v: &[f64], report: &DiffReport<usize>) -> String {
+    let m = u.len();
+    let start = report.worst.saturating_sub(REPORT_NEIGHBOURS);
+    let end = usize::min(report.worst + REPORT_NEIGHBOURS + 1, m);
+    let mut buffer = report.summary();
+    buffer.push_str(&format!(
+        "{:>8} {:>24} {:>24} {:>12}\n",
+        "index", "left", "right", "diff"
+    ));
+    if start > 0 {
+        buffer.push_str(&format!("{:>8}\n", "..."));
+    }
+    for i in start..end {
+        let mark = if report.exceeds(u[i], v[i]) { "*" } else { " " };
+        buffer.push_str(&format!(
+            "{:>7}{} {:>24?} {:>24?} {:>12.3e}\n",
+            i,
+            mark,
+            u[i],
+            v[i],
+            f64::abs(u[i] - v[i])
+        ));
+    }
+    if end < m {
+        buffer.push_str(&format!("{:>8}\n", "..."));
     }
+    buffer
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -24,6 +72,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::vec_approx_eq;
+    use crate::DiffReport;
 
     #[test]
     #[should_panic(expected = "vector dimensions differ. 2 != 3")]
@@ -34,7 +83,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "vectors are not approximately equal. @ 0 diff = 1.5")]
+    #[should_panic(expected = "vectors are not approximately equal. @ 3 diff = 2.0")]
     fn vec_approx_eq_works_2() {
         let u = &[1.0, 2.0, 3.0, 4.0];
         let v = &[2.5, 1.0, 1.5, 2.0];
@@ -55,4 +104,40 @@ mod tests {
         let v = &[0.0, 0.0, 1e-15];
         vec_approx_eq(u, v, 1e-15);
     }
+
+    #[test]
+    #[should_panic(expected = "max abs diff = 2.0, max rel diff = 0.6, 4 of 4 entries exceed tol = 1e-15")]
+    fn vec_approx_eq_reports_max_differences() {
+        let u = &[1.0, 2.0, 3.0, 4.0];
+        let v = &[2.5, 1.0, 1.5, 2.0];
+        vec_approx_eq(u, v, 1e-15);
+    }
+
+    #[test]
+    fn vec_report_works() {
+        let u: Vec<f64> = (0..10).map(|i| i as f64).collect();
+        let mut v = u.clone();
+        v[5] = 5.5;
+        let mut stats = DiffReport::new(1e-15);
+        for (i, (a, b)) in u.iter().zip(&v).enumerate() {
+            stats.add(i, *a, *b);
+        }
+        let report = super::vec_report(&u, &v, &stats);
+        let lines: Vec<_> = report.lines().collect();
+        assert_eq!(lines.len(), 11);
+        assert_eq!(
+            lines[0],
+            "max abs diff = 0.5, max rel diff = 0.09090909090909091, 1 of 10 entries exceed tol = 1e-15"
+        );
+        assert_eq!(lines[2], "     ...");
+        assert_eq!(
+            lines[3],
+            "      2                       2.0                      2.0      0.000e0"
+        );
+        assert_eq!(
+            lines[6],
+            "      5*                      5.0                      5.5     5.000e-1"
+        );
+        assert_eq!(lines[10], "     ...");
+    }
 }
@@ -2,7 +2,7 @@ use criterion::BenchmarkId;
 use criterion::Criterion;
 use criterion::Throughput;
 use criterion::{criterion_group, criterion_main};
-use russell_lab::{mat_eigen_sym, mat_eigen_sym_jacobi, vec_add, Matrix, Vector};
+use russell_lab::{mat_eigen_sym, mat_eigen_sym_jacobi, mat_mat_mul, vec_add, Matrix, Vector};
 
 fn _bench_vec_add(c: &mut Criterion) {
     let sizes = &[1, 4, 16, 32, 64, 128];
@@ -39,6 +39,32 @@ fn bench_mat_eigen_sym(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_mat_mat_mul(c: &mut Criterion) {
+    let sizes = &[32, 64, 128, 256, 512];
+    let mut group = c.benchmark_group("lab_mat_mat_mul");
+    for size in sizes {
+        group.throughput(Throughput::Elements((size * size * size) as u64));
+        group.bench_with_input(BenchmarkId::new("BLAS", size), size, |b, &size| {
+            let a = Matrix::filled(size, size, 2.0);
+            let bb = Matrix::filled(size, size, 3.0);
+            let mut cc = Matrix::new(size, size);
+            b.iter(|| mat_mat_mul(&mut cc, 1.0, &a, &bb).unwrap());
+        });
+        #[cfg(feature = "rayon")]
+        for n_threads in [1, 2, 4, 8] {
+            let pool = rayon::ThreadPoolBuilder::new().num_threads(n_threads).build().unwrap();
+            let id = BenchmarkId::new(format!("Parallel{}", n_threads), size);
+            group.bench_with_input(id, size, |b, &size| {
+                let a = Matrix::filled(size, size, 2.0);
+                let bb = Matrix::filled(size, size, 3.0);
+                let mut cc = Matrix::new(size, size);
+                b.iter(|| pool.install(|| russell_lab::mat_mat_mul_par(&mut cc, 1.0, &a, &bb).unwrap()));
+            });
+        }
+    }
+    group.finish();
+}
+
 // criterion_group!(benches, bench_vec_add, bench_mat_eigen_sym);
-criterion_group!(benches, bench_mat_eigen_sym);
+criterion_group!(benches, bench_mat_eigen_sym, bench_mat_mat_mul);
 criterion_main!(benches);
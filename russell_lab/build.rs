@@ -1,3 +1,6 @@
 fn main() {
-    cc::Build::new().file("c_code/math_functions.c").compile("c_code");
+    // the pure_rust feature uses the libm crate instead of the C math library
+    if std::env::var("CARGO_FEATURE_PURE_RUST").is_err() {
+        cc::Build::new().file("c_code/math_functions.c").compile("c_code");
+    }
 }
@@ -3,3 +3,4 @@
 double c_erf(double x) { return erf(x); }
 double c_erfc(double x) { return erfc(x); }
 double c_gamma(double x) { return tgamma(x); }
+double c_ln_gamma(double x) { return lgamma(x); }
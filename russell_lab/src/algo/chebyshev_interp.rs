@@ -0,0 +1,295 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::StrError;
+use std::f64::consts::PI;
+
+/// Implements the Chebyshev interpolation on Chebyshev-Gauss-Lobatto points
+///
+/// The function f(x) is sampled at the N+1 Chebyshev-Gauss-Lobatto points
+/// (in ascending order) mapped to the interval [xa, xb]:
+///
+/// ```text
+/// ξⱼ = -cos(π j / N)       x = (xb - xa) ξ / 2 + (xa + xb) / 2       j = 0, …, N
+/// ```
+///
+/// and approximated by the truncated Chebyshev series:
+///
+/// ```text
+///         N
+/// I(x) =  Σ  aₖ Tₖ(ξ(x))
+///        k=0
+/// ```
+///
+/// The coefficients are used to evaluate, differentiate, and integrate the interpolant.
+/// The spectral differentiation matrix D (such that D ⋅ f ≈ f' at the points) is also available.
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::approx_eq;
+/// use russell_lab::{ChebyshevInterp, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let interp = ChebyshevInterp::new(16, 0.0, 1.0, f64::exp)?;
+///     approx_eq(interp.eval(0.5), f64::exp(0.5), 1e-14);
+///     approx_eq(interp.eval_deriv(0.5), f64::exp(0.5), 1e-13);
+///     approx_eq(interp.integrate(), f64::exp(1.0) - 1.0, 1e-14);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ChebyshevInterp {
+    /// Holds the degree N of the interpolant
+    nn: usize,
+
+    /// Holds the lower bound of the interval
+    xa: f64,
+
+    /// Holds the upper bound of the interval
+    xb: f64,
+
+    /// Holds the N+1 points in [xa, xb] (ascending order)
+    points: Vector,
+
+    /// Holds the N+1 function values at the points
+    values: Vector,
+
+    /// Holds the N+1 coefficients of the Chebyshev series
+    coef: Vector,
+
+    /// Holds the N+1 coefficients of the derivative (with respect to ξ)
+    coef_deriv: Vector,
+}
+
+impl ChebyshevInterp {
+    /// Allocates a new instance by sampling the function f(x) at the Chebyshev-Gauss-Lobatto points
+    ///
+    /// # Input
+    ///
+    /// * `nn` -- the degree N ≥ 1 of the interpolant (there will be N+1 points)
+    /// * `xa` -- the lower bound of the interval
+    /// * `xb` -- the upper bound of the interval (must be greater than xa)
+    /// * `f` -- the function f(x)
+    pub fn new<F>(nn: usize, xa: f64, xb: f64, mut f: F) -> Result<Self, StrError>
+    where
+        F: FnMut(f64) -> f64,
+    {
+        if nn < 1 {
+            return Err("the degree N must be at least 1");
+        }
+        if xb <= xa {
+            return Err("xb must be greater than xa");
+        }
+        let np = nn + 1;
+        let mut points = Vector::new(np);
+        let mut values = Vector::new(np);
+        for j in 0..np {
+            let xi = -f64::cos(PI * (j as f64) / (nn as f64));
+            points[j] = (xb - xa) * xi / 2.0 + (xa + xb) / 2.0;
+            values[j] = f(points[j]);
+        }
+        // the end points must be exact
+        points[0] = xa;
+        points[nn] = xb;
+        let mut interp = ChebyshevInterp {
+            nn,
+            xa,
+            xb,
+            points,
+            values,
+            coef: Vector::new(np),
+            coef_deriv: Vector::new(np),
+        };
+        interp.compute_coefficients();
+        Ok(interp)
+    }
+
+    /// Computes the coefficients of the series and of its derivative
+    fn compute_coefficients(&mut self) {
+        let nn = self.nn;
+        let den = nn as f64;
+        // aₖ = (2/N) Σ'' fⱼ Tₖ(ξⱼ) with Tₖ(ξⱼ) = (-1)ᵏ cos(π k j / N)
+        for k in 0..(nn + 1) {
+            let mut sum = 0.0;
+            for j in 0..(nn + 1) {
+                let w = if j == 0 || j == nn { 0.5 } else { 1.0 };
+                sum += w * self.values[j] * f64::cos(PI * ((k * j) as f64) / den);
+            }
+            let sign = if k % 2 == 0 { 1.0 } else { -1.0 };
+            self.coef[k] = sign * 2.0 * sum / den;
+        }
+        self.coef[0] /= 2.0;
+        self.coef[nn] /= 2.0;
+        // a'ₖ₋₁ = a'ₖ₊₁ + 2 k aₖ
+        self.coef_deriv.fill(0.0);
+        for k in (1..(nn + 1)).rev() {
+            let next = if k < nn { self.coef_deriv[k + 1] } else { 0.0 };
+            self.coef_deriv[k - 1] = next + 2.0 * (k as f64) * self.coef[k];
+        }
+        self.coef_deriv[0] /= 2.0;
+    }
+
+    /// Returns the degree N of the interpolant
+    pub fn degree(&self) -> usize {
+        self.nn
+    }
+
+    /// Returns the N+1 Chebyshev-Gauss-Lobatto points in [xa, xb] (ascending order)
+    pub fn points(&self) -> &Vector {
+        &self.points
+    }
+
+    /// Returns the N+1 function values at the points
+    pub fn values(&self) -> &Vector {
+        &self.values
+    }
+
+    /// Returns the N+1 coefficients of the Chebyshev series
+    pub fn coefficients(&self) -> &Vector {
+        &self.coef
+    }
+
+    /// Evaluates the interpolant at x
+    ///
+    /// **Note:** x should be in [xa, xb]; otherwise, the result is an extrapolation.
+    pub fn eval(&self, x: f64) -> f64 {
+        clenshaw(&self.coef, self.xi(x))
+    }
+
+    /// Evaluates the first derivative of the interpolant at x
+    pub fn eval_deriv(&self, x: f64) -> f64 {
+        clenshaw(&self.coef_deriv, self.xi(x)) * 2.0 / (self.xb - self.xa)
+    }
+
+    /// Computes the integral of the interpolant over [xa, xb] (Clenshaw-Curtis quadrature)
+    pub fn integrate(&self) -> f64 {
+        // ∫ Tₖ(ξ) dξ over [-1, 1] equals 2 / (1 - k²) for even k and zero for odd k
+        let mut sum = 0.0;
+        for k in (0..(self.nn + 1)).step_by(2) {
+            let kf = k as f64;
+            sum += self.coef[k] * 2.0 / (1.0 - kf * kf);
+        }
+        sum * (self.xb - self.xa) / 2.0
+    }
+
+    /// Returns the (N+1, N+1) spectral differentiation matrix
+    ///
+    /// The derivatives at the points are approximated by:
+    ///
+    /// ```text
+    /// f'ᵢ ≈ Σⱼ Dᵢⱼ fⱼ
+    /// ```
+    pub fn differentiation_matrix(&self) -> Matrix {
+        let np = self.nn + 1;
+        let scale = 2.0 / (self.xb - self.xa);
+        let xi: Vec<f64> = (0..np).map(|j| -f64::cos(PI * (j as f64) / (self.nn as f64))).collect();
+        let c = |i: usize| if i == 0 || i == self.nn { 2.0 } else { 1.0 };
+        let mut dd = Matrix::new(np, np);
+        for i in 0..np {
+            let mut sum = 0.0;
+            for j in 0..np {
+                if i != j {
+                    let sign = if (i + j) % 2 == 0 { 1.0 } else { -1.0 };
+                    let dij = sign * c(i) / (c(j) * (xi[i] - xi[j]));
+                    dd.set(i, j, dij * scale);
+                    sum += dij;
+                }
+            }
+            // negative sum trick (improves the accuracy)
+            dd.set(i, i, -sum * scale);
+        }
+        dd
+    }
+
+    /// Maps x in [xa, xb] to ξ in [-1, 1]
+    fn xi(&self, x: f64) -> f64 {
+        (2.0 * x - self.xa - self.xb) / (self.xb - self.xa)
+    }
+}
+
+/// Evaluates the Chebyshev series Σ aₖ Tₖ(ξ) using Clenshaw's algorithm
+fn clenshaw(a: &Vector, xi: f64) -> f64 {
+    let n = a.dim();
+    let (mut b1, mut b2) = (0.0, 0.0);
+    for k in (1..n).rev() {
+        let b0 = 2.0 * xi * b1 - b2 + a[k];
+        b2 = b1;
+        b1 = b0;
+    }
+    xi * b1 - b2 + a[0]
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::ChebyshevInterp;
+    use crate::{mat_vec_mul, Vector};
+    use russell_chk::{approx_eq, vec_approx_eq};
+
+    #[test]
+    fn new_captures_errors() {
+        assert_eq!(
+            ChebyshevInterp::new(0, 0.0, 1.0, |x| x).err(),
+            Some("the degree N must be at least 1")
+        );
+        assert_eq!(
+            ChebyshevInterp::new(2, 1.0, 1.0, |x| x).err(),
+            Some("xb must be greater than xa")
+        );
+    }
+
+    #[test]
+    fn points_and_coefficients_work() {
+        let interp = ChebyshevInterp::new(2, -1.0, 1.0, |x| x * x).unwrap();
+        assert_eq!(interp.degree(), 2);
+        vec_approx_eq(interp.points().as_data(), &[-1.0, 0.0, 1.0], 1e-15);
+        vec_approx_eq(interp.values().as_data(), &[1.0, 0.0, 1.0], 1e-15);
+        // x² = (T₀ + T₂) / 2
+        vec_approx_eq(interp.coefficients().as_data(), &[0.5, 0.0, 0.5], 1e-15);
+    }
+
+    #[test]
+    fn polynomial_is_reproduced() {
+        // f(x) = x³ - 2x on [-1, 2]
+        let f = |x: f64| x * x * x - 2.0 * x;
+        let df = |x: f64| 3.0 * x * x - 2.0;
+        let interp = ChebyshevInterp::new(3, -1.0, 2.0, f).unwrap();
+        for x in [-1.0, -0.3, 0.0, 0.7, 1.5, 2.0] {
+            approx_eq(interp.eval(x), f(x), 1e-14);
+            approx_eq(interp.eval_deriv(x), df(x), 1e-13);
+        }
+        // ∫ (x³ - 2x) dx from -1 to 2 = (16 - 1)/4 - (4 - 1) = 0.75
+        approx_eq(interp.integrate(), 0.75, 1e-14);
+    }
+
+    #[test]
+    fn smooth_function_converges() {
+        let interp = ChebyshevInterp::new(20, 0.0, 3.0, f64::sin).unwrap();
+        for i in 0..11 {
+            let x = 0.3 * (i as f64);
+            approx_eq(interp.eval(x), f64::sin(x), 1e-14);
+            approx_eq(interp.eval_deriv(x), f64::cos(x), 1e-12);
+        }
+        approx_eq(interp.integrate(), 1.0 - f64::cos(3.0), 1e-14);
+    }
+
+    #[test]
+    fn differentiation_matrix_works() {
+        // N = 1 on [-1, 1]: D = [[-1/2, 1/2], [-1/2, 1/2]]
+        let interp = ChebyshevInterp::new(1, -1.0, 1.0, |x| x).unwrap();
+        let dd = interp.differentiation_matrix();
+        vec_approx_eq(dd.as_data(), &[-0.5, -0.5, 0.5, 0.5], 1e-15);
+
+        // smooth function
+        let interp = ChebyshevInterp::new(16, -2.0, 1.0, f64::exp).unwrap();
+        let dd = interp.differentiation_matrix();
+        let mut df = Vector::new(17);
+        mat_vec_mul(&mut df, 1.0, &dd, interp.values()).unwrap();
+        let correct: Vec<f64> = interp.points().iter().map(|x| f64::exp(*x)).collect();
+        vec_approx_eq(df.as_data(), &correct, 1e-11);
+        for i in 0..17 {
+            approx_eq(interp.eval_deriv(interp.points()[i]), df[i], 1e-11);
+        }
+    }
+}
@@ -0,0 +1,324 @@
+use crate::StrError;
+
+/// Holds the maximum number of iterations of the minimization algorithms
+const N_MAX_ITERATIONS: usize = 500;
+
+/// Holds the golden ratio (1 + √5) / 2
+const GOLDEN_RATIO: f64 = 1.618033988749895;
+
+/// Holds the golden section (3 - √5) / 2
+const GOLDEN_SECTION: f64 = 0.3819660112501051;
+
+/// Brackets a minimum of a function by expanding an initial interval downhill
+///
+/// Starting with the points `a` and `b`, finds three points `xa < xb < xc` such that
+/// `f(xb) ≤ f(xa)` and `f(xb) ≤ f(xc)`; hence, [xa, xc] contains a (local) minimum.
+/// The interval is expanded by the golden ratio at each step.
+///
+/// # Output
+///
+/// Returns `(xa, xb, xc)` in ascending order.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{min_bracket, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let f = |x: f64| (x - 10.0) * (x - 10.0);
+///     let (xa, xb, xc) = min_bracket(f, 0.0, 1.0)?;
+///     assert!(xa < 10.0 && 10.0 < xc);
+///     assert!(f(xb) <= f(xa) && f(xb) <= f(xc));
+///     Ok(())
+/// }
+/// ```
+pub fn min_bracket<F>(mut f: F, a: f64, b: f64) -> Result<(f64, f64, f64), StrError>
+where
+    F: FnMut(f64) -> f64,
+{
+    if a == b {
+        return Err("a and b must be different");
+    }
+    let (mut a, mut b) = (a, b);
+    let (mut fa, mut fb) = (f(a), f(b));
+    if fb > fa {
+        std::mem::swap(&mut a, &mut b);
+        std::mem::swap(&mut fa, &mut fb);
+    }
+    let mut c = b + GOLDEN_RATIO * (b - a);
+    let mut fc = f(c);
+    let mut count = 0;
+    while fb > fc {
+        if count == N_MAX_ITERATIONS || !c.is_finite() {
+            return Err("cannot bracket a minimum");
+        }
+        a = b;
+        b = c;
+        fb = fc;
+        c = b + GOLDEN_RATIO * (b - a);
+        fc = f(c);
+        count += 1;
+    }
+    if a < c {
+        Ok((a, b, c))
+    } else {
+        Ok((c, b, a))
+    }
+}
+
+/// Finds the minimum of a function in [a, b] using the golden-section search
+///
+/// The function should be unimodal in [a, b]; otherwise, a local minimum is found.
+///
+/// # Input
+///
+/// * `f` -- the function f(x)
+/// * `a` -- the lower bound
+/// * `b` -- the upper bound (must be greater than a)
+/// * `tol` -- the absolute tolerance on x (must be positive)
+///
+/// # Output
+///
+/// Returns `(x_min, f(x_min))`
+pub fn min_golden<F>(mut f: F, a: f64, b: f64, tol: f64) -> Result<(f64, f64), StrError>
+where
+    F: FnMut(f64) -> f64,
+{
+    check_input(a, b, tol)?;
+    let (mut a, mut b) = (a, b);
+    let mut x1 = b - (1.0 - GOLDEN_SECTION) * (b - a);
+    let mut x2 = a + (1.0 - GOLDEN_SECTION) * (b - a);
+    let (mut f1, mut f2) = (f(x1), f(x2));
+    for _ in 0..N_MAX_ITERATIONS {
+        if b - a <= tol {
+            return Ok(if f1 < f2 { (x1, f1) } else { (x2, f2) });
+        }
+        if f1 < f2 {
+            b = x2;
+            x2 = x1;
+            f2 = f1;
+            x1 = b - (1.0 - GOLDEN_SECTION) * (b - a);
+            f1 = f(x1);
+        } else {
+            a = x1;
+            x1 = x2;
+            f1 = f2;
+            x2 = a + (1.0 - GOLDEN_SECTION) * (b - a);
+            f2 = f(x2);
+        }
+    }
+    Err("min_golden failed to converge")
+}
+
+/// Finds the minimum of a function in [a, b] using Brent's method
+///
+/// Brent's method combines the golden-section search with successive parabolic interpolation;
+/// thus, it converges superlinearly for smooth functions and never worse than the golden-section
+/// search. The function should be unimodal in [a, b]; otherwise, a local minimum is found.
+/// [min_bracket] may be used to find an appropriate interval.
+///
+/// # Input
+///
+/// * `f` -- the function f(x)
+/// * `a` -- the lower bound
+/// * `b` -- the upper bound (must be greater than a)
+/// * `tol` -- the absolute tolerance on x (must be positive); note that a relative tolerance
+///   equal to √ε (ε = machine epsilon) is also considered
+///
+/// # Output
+///
+/// Returns `(x_min, f(x_min))`
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::approx_eq;
+/// use russell_lab::{min_brent, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let (x, fx) = min_brent(f64::cos, 0.0, 6.0, 1e-10)?;
+///     approx_eq(x, std::f64::consts::PI, 1e-7);
+///     approx_eq(fx, -1.0, 1e-14);
+///     Ok(())
+/// }
+/// ```
+///
+/// # Reference
+///
+/// * Brent RP (1973) Algorithms for Minimization without Derivatives, Prentice-Hall, Chapter 5
+pub fn min_brent<F>(mut f: F, a: f64, b: f64, tol: f64) -> Result<(f64, f64), StrError>
+where
+    F: FnMut(f64) -> f64,
+{
+    check_input(a, b, tol)?;
+    let eps = f64::sqrt(f64::EPSILON);
+    let (mut a, mut b) = (a, b);
+    let mut x = a + GOLDEN_SECTION * (b - a);
+    let (mut w, mut v) = (x, x);
+    let mut fx = f(x);
+    let (mut fw, mut fv) = (fx, fx);
+    let (mut d, mut e): (f64, f64) = (0.0, 0.0);
+    for _ in 0..N_MAX_ITERATIONS {
+        let xm = (a + b) / 2.0;
+        let tol1 = eps * f64::abs(x) + tol / 3.0;
+        let tol2 = 2.0 * tol1;
+        if f64::abs(x - xm) <= tol2 - (b - a) / 2.0 {
+            return Ok((x, fx));
+        }
+        let mut golden = true;
+        if f64::abs(e) > tol1 {
+            // trial parabolic fit
+            let mut r = (x - w) * (fx - fv);
+            let mut q = (x - v) * (fx - fw);
+            let mut p = (x - v) * q - (x - w) * r;
+            q = 2.0 * (q - r);
+            if q > 0.0 {
+                p = -p;
+            } else {
+                q = -q;
+            }
+            r = e;
+            e = d;
+            if f64::abs(p) < f64::abs(q * r / 2.0) && p > q * (a - x) && p < q * (b - x) {
+                // parabolic interpolation step
+                d = p / q;
+                let u = x + d;
+                // f must not be evaluated too close to a or b
+                if u - a < tol2 || b - u < tol2 {
+                    d = if x < xm { tol1 } else { -tol1 };
+                }
+                golden = false;
+            }
+        }
+        if golden {
+            e = if x < xm { b - x } else { a - x };
+            d = GOLDEN_SECTION * e;
+        }
+        // f must not be evaluated too close to x
+        let u = if f64::abs(d) >= tol1 {
+            x + d
+        } else if d > 0.0 {
+            x + tol1
+        } else {
+            x - tol1
+        };
+        let fu = f(u);
+        if fu <= fx {
+            if u < x {
+                b = x;
+            } else {
+                a = x;
+            }
+            v = w;
+            fv = fw;
+            w = x;
+            fw = fx;
+            x = u;
+            fx = fu;
+        } else {
+            if u < x {
+                a = u;
+            } else {
+                b = u;
+            }
+            if fu <= fw || w == x {
+                v = w;
+                fv = fw;
+                w = u;
+                fw = fu;
+            } else if fu <= fv || v == x || v == w {
+                v = u;
+                fv = fu;
+            }
+        }
+    }
+    Err("min_brent failed to converge")
+}
+
+/// Checks the input of min_golden and min_brent
+fn check_input(a: f64, b: f64, tol: f64) -> Result<(), StrError> {
+    if b <= a {
+        return Err("b must be greater than a");
+    }
+    if tol <= 0.0 {
+        return Err("the tolerance must be positive");
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{min_bracket, min_brent, min_golden};
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn functions_capture_errors() {
+        assert_eq!(min_bracket(|x| x, 1.0, 1.0).err(), Some("a and b must be different"));
+        assert_eq!(min_bracket(|x| x, 0.0, 1.0).err(), Some("cannot bracket a minimum"));
+        assert_eq!(
+            min_golden(|x| x, 1.0, 0.0, 1e-8).err(),
+            Some("b must be greater than a")
+        );
+        assert_eq!(min_brent(|x| x, 0.0, 0.0, 1e-8).err(), Some("b must be greater than a"));
+        assert_eq!(
+            min_brent(|x| x, 0.0, 1.0, 0.0).err(),
+            Some("the tolerance must be positive")
+        );
+    }
+
+    #[test]
+    fn min_bracket_works() {
+        let f = |x: f64| (x - 2.0) * (x - 2.0);
+        for (a, b) in [(0.0, 0.1), (0.1, 0.0), (10.0, 9.0), (-3.0, 5.0)] {
+            let (xa, xb, xc) = min_bracket(f, a, b).unwrap();
+            assert!(xa < xb && xb < xc);
+            assert!(xa <= 2.0 && 2.0 <= xc);
+            assert!(f(xb) <= f(xa) && f(xb) <= f(xc));
+        }
+    }
+
+    #[test]
+    fn min_golden_works() {
+        let (x, fx) = min_golden(|x| (x - 2.0) * (x - 2.0) + 1.0, -1.0, 5.0, 1e-8).unwrap();
+        approx_eq(x, 2.0, 1e-8);
+        approx_eq(fx, 1.0, 1e-15);
+        // minimum at the boundary
+        let (x, _) = min_golden(|x| x, 0.0, 1.0, 1e-8).unwrap();
+        approx_eq(x, 0.0, 1e-8);
+    }
+
+    #[test]
+    fn min_brent_works() {
+        // smooth
+        let mut count = 0;
+        let (x, fx) = min_brent(
+            |x| {
+                count += 1;
+                (x - 2.0) * (x - 2.0) + 1.0
+            },
+            -1.0,
+            5.0,
+            1e-10,
+        )
+        .unwrap();
+        approx_eq(x, 2.0, 1e-8);
+        approx_eq(fx, 1.0, 1e-15);
+        assert!(count < 10); // the parabola is found immediately
+
+        // non-smooth
+        let (x, _) = min_brent(|x| f64::abs(x - 0.3), 0.0, 1.0, 1e-10).unwrap();
+        approx_eq(x, 0.3, 1e-9);
+
+        // minimum at the boundary
+        let (x, _) = min_brent(|x| x, 0.0, 1.0, 1e-10).unwrap();
+        approx_eq(x, 0.0, 1e-9);
+
+        // with bracketing
+        let f = |x: f64| f64::exp(x) - 4.0 * x;
+        let (xa, _, xc) = min_bracket(f, 0.0, 0.1).unwrap();
+        let (x, _) = min_brent(f, xa, xc, 1e-10).unwrap();
+        approx_eq(x, f64::ln(4.0), 1e-8);
+    }
+}
@@ -0,0 +1,12 @@
+//! This module contains numerical algorithms such as interpolation, quadrature, and root finding
+
+mod chebyshev_interp;
+mod minimize;
+mod newton_solver;
+mod quad_adaptive;
+mod quadrature;
+pub use crate::algo::chebyshev_interp::*;
+pub use crate::algo::minimize::*;
+pub use crate::algo::newton_solver::*;
+pub use crate::algo::quad_adaptive::*;
+pub use crate::algo::quadrature::*;
@@ -0,0 +1,377 @@
+use crate::matrix::Matrix;
+use crate::matvec::solve_lin_sys;
+use crate::vector::{vec_norm, Vector};
+use crate::{Norm, StrError};
+
+/// Defines the function F(x) as used internally by NewtonSolver
+type FnResidual<'a> = dyn FnMut(&mut Vector, &Vector) -> Result<(), StrError> + 'a;
+
+/// Defines the Jacobian function J(x) as used internally by NewtonSolver
+type FnJacobian<'a> = dyn FnMut(&mut Matrix, &Vector) -> Result<(), StrError> + 'a;
+
+/// Holds the statistics of a run of the NewtonSolver
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NewtonStats {
+    /// Holds the number of iterations
+    pub n_iterations: usize,
+
+    /// Holds the number of calls to F(x), including the ones to approximate the Jacobian
+    pub n_function_evals: usize,
+
+    /// Holds the number of Jacobian evaluations (analytical or by finite differences)
+    pub n_jacobian_evals: usize,
+
+    /// Holds the max norm of F(x) at the solution
+    pub norm_f: f64,
+}
+
+/// Solves nonlinear systems of equations F(x) = 0 using the Newton-Raphson method
+///
+/// Each iteration solves the linear system:
+///
+/// ```text
+/// J(xₖ) ⋅ δx = -F(xₖ)       xₖ₊₁ = xₖ + λ δx
+/// ```
+///
+/// where `J = ∂F/∂x` is the Jacobian matrix and `λ ∈ (0, 1]` is selected by backtracking
+/// (halving λ) until the merit function `½ ‖F‖²` decreases sufficiently (Armijo's rule).
+/// The iterations stop when `‖F‖∞ ≤ tol_f` or `‖δx‖∞ ≤ tol_dx (1 + ‖x‖∞)`.
+///
+/// The Jacobian is given by a closure ([NewtonSolver::solve_with_jacobian]) or approximated
+/// by forward finite differences ([NewtonSolver::solve]).
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::vec_approx_eq;
+/// use russell_lab::{NewtonSolver, StrError, Vector};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     // x² + y² = 4 and x = y
+///     let mut x = Vector::from(&[1.0, 0.5]);
+///     let solver = NewtonSolver::new();
+///     let stats = solver.solve(&mut x, |f, x| {
+///         f[0] = x[0] * x[0] + x[1] * x[1] - 4.0;
+///         f[1] = x[0] - x[1];
+///         Ok(())
+///     })?;
+///     let a = f64::sqrt(2.0);
+///     vec_approx_eq(x.as_data(), &[a, a], 1e-10);
+///     assert!(stats.norm_f < 1e-10);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct NewtonSolver {
+    tol_f: f64,            // tolerance on the max norm of F(x)
+    tol_dx: f64,           // (relative) tolerance on the max norm of the Newton step
+    max_iterations: usize, // maximum number of iterations
+    line_search: bool,     // use backtracking line search
+    fd_delta: f64,         // (relative) perturbation for the finite differences
+}
+
+impl NewtonSolver {
+    /// Allocates a new instance with default options
+    pub fn new() -> Self {
+        NewtonSolver {
+            tol_f: 1e-10,
+            tol_dx: 1e-14,
+            max_iterations: 50,
+            line_search: true,
+            fd_delta: 1e-8,
+        }
+    }
+
+    /// Sets the tolerance on the max norm of F(x)
+    pub fn tol_f(&mut self, value: f64) -> &mut Self {
+        self.tol_f = value;
+        self
+    }
+
+    /// Sets the relative tolerance on the max norm of the Newton step
+    pub fn tol_dx(&mut self, value: f64) -> &mut Self {
+        self.tol_dx = value;
+        self
+    }
+
+    /// Sets the maximum number of iterations
+    pub fn max_iterations(&mut self, value: usize) -> &mut Self {
+        self.max_iterations = value;
+        self
+    }
+
+    /// Enables or disables the backtracking line search
+    pub fn line_search(&mut self, enabled: bool) -> &mut Self {
+        self.line_search = enabled;
+        self
+    }
+
+    /// Sets the relative perturbation used to approximate the Jacobian by finite differences
+    pub fn fd_delta(&mut self, value: f64) -> &mut Self {
+        self.fd_delta = value;
+        self
+    }
+
+    /// Solves F(x) = 0 approximating the Jacobian by forward finite differences
+    ///
+    /// # Input
+    ///
+    /// * `x` -- (n) the initial guess; will hold the solution
+    /// * `f` -- the function `f(fx, x)` that computes `fx := F(x)`
+    pub fn solve<F>(&self, x: &mut Vector, mut f: F) -> Result<NewtonStats, StrError>
+    where
+        F: FnMut(&mut Vector, &Vector) -> Result<(), StrError>,
+    {
+        self.run(x, &mut f, None)
+    }
+
+    /// Solves F(x) = 0 using the analytical Jacobian
+    ///
+    /// # Input
+    ///
+    /// * `x` -- (n) the initial guess; will hold the solution
+    /// * `f` -- the function `f(fx, x)` that computes `fx := F(x)`
+    /// * `jac` -- the function `jac(jj, x)` that computes `jj := ∂F/∂x (x)`, an (n,n) matrix
+    pub fn solve_with_jacobian<F, J>(&self, x: &mut Vector, mut f: F, mut jac: J) -> Result<NewtonStats, StrError>
+    where
+        F: FnMut(&mut Vector, &Vector) -> Result<(), StrError>,
+        J: FnMut(&mut Matrix, &Vector) -> Result<(), StrError>,
+    {
+        self.run(x, &mut f, Some(&mut jac))
+    }
+
+    /// Runs the iterations
+    fn run(
+        &self,
+        x: &mut Vector,
+        f: &mut FnResidual,
+        mut jac: Option<&mut FnJacobian>,
+    ) -> Result<NewtonStats, StrError> {
+        let n = x.dim();
+        let mut stats = NewtonStats {
+            n_iterations: 0,
+            n_function_evals: 1,
+            n_jacobian_evals: 0,
+            norm_f: 0.0,
+        };
+        let mut fx = Vector::new(n);
+        f(&mut fx, x)?;
+        stats.norm_f = vec_norm(&fx, Norm::Max);
+        if !stats.norm_f.is_finite() {
+            return Err("F(x) is not finite at the initial guess");
+        }
+        if stats.norm_f <= self.tol_f {
+            return Ok(stats);
+        }
+        let mut jj = Matrix::new(n, n);
+        let mut dx = Vector::new(n);
+        let mut x_trial = Vector::new(n);
+        let mut f_trial = Vector::new(n);
+        while stats.n_iterations < self.max_iterations {
+            stats.n_iterations += 1;
+
+            // Jacobian
+            match jac.as_mut() {
+                Some(jac) => jac(&mut jj, x)?,
+                None => {
+                    for j in 0..n {
+                        let xj = x[j];
+                        let h = self.fd_delta * f64::max(1.0, f64::abs(xj));
+                        x[j] = xj + h;
+                        let res = f(&mut f_trial, x);
+                        x[j] = xj;
+                        res?;
+                        for i in 0..n {
+                            jj.set(i, j, (f_trial[i] - fx[i]) / h);
+                        }
+                    }
+                    stats.n_function_evals += n;
+                }
+            }
+            stats.n_jacobian_evals += 1;
+
+            // Newton step: J ⋅ δx = -F
+            for i in 0..n {
+                dx[i] = -fx[i];
+            }
+            solve_lin_sys(&mut dx, &mut jj)?;
+
+            // backtracking line search
+            let phi = 0.5 * vec_norm(&fx, Norm::Euc).powi(2);
+            let mut lambda = 1.0;
+            loop {
+                for i in 0..n {
+                    x_trial[i] = x[i] + lambda * dx[i];
+                }
+                f(&mut f_trial, &x_trial)?;
+                stats.n_function_evals += 1;
+                if !self.line_search {
+                    break;
+                }
+                let phi_trial = 0.5 * vec_norm(&f_trial, Norm::Euc).powi(2);
+                // the directional derivative of φ along δx is -‖F‖² = -2φ
+                if phi_trial.is_finite() && phi_trial <= (1.0 - 2.0 * 1e-4 * lambda) * phi {
+                    break;
+                }
+                lambda /= 2.0;
+                if lambda < 1e-10 {
+                    return Err("the line search failed to decrease the norm of F(x)");
+                }
+            }
+
+            // update
+            x.as_mut_data().copy_from_slice(x_trial.as_data());
+            fx.as_mut_data().copy_from_slice(f_trial.as_data());
+            stats.norm_f = vec_norm(&fx, Norm::Max);
+            if !stats.norm_f.is_finite() {
+                return Err("F(x) is not finite");
+            }
+            if stats.norm_f <= self.tol_f {
+                return Ok(stats);
+            }
+            if vec_norm(&dx, Norm::Max) <= self.tol_dx * (1.0 + vec_norm(x, Norm::Max)) {
+                return Ok(stats);
+            }
+        }
+        Err("the Newton solver did not converge")
+    }
+}
+
+impl Default for NewtonSolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(dead_code, unused_imports))]
+mod tests {
+    use super::NewtonSolver;
+    use crate::{Matrix, StrError, Vector};
+    use russell_chk::{approx_eq, vec_approx_eq};
+
+    /// Rosenbrock's function gradient system: F = (10 (x₁ - x₀²), 1 - x₀)
+    fn rosenbrock(f: &mut Vector, x: &Vector) -> Result<(), StrError> {
+        f[0] = 10.0 * (x[1] - x[0] * x[0]);
+        f[1] = 1.0 - x[0];
+        Ok(())
+    }
+
+    fn rosenbrock_jac(jj: &mut Matrix, x: &Vector) -> Result<(), StrError> {
+        jj.set(0, 0, -20.0 * x[0]);
+        jj.set(0, 1, 10.0);
+        jj.set(1, 0, -1.0);
+        jj.set(1, 1, 0.0);
+        Ok(())
+    }
+
+    #[test]
+    fn setters_work() {
+        let mut solver = NewtonSolver::new();
+        solver
+            .tol_f(1e-6)
+            .tol_dx(1e-8)
+            .max_iterations(3)
+            .line_search(false)
+            .fd_delta(1e-6);
+        assert_eq!(solver.tol_f, 1e-6);
+        assert_eq!(solver.tol_dx, 1e-8);
+        assert_eq!(solver.max_iterations, 3);
+        assert!(!solver.line_search);
+        assert_eq!(solver.fd_delta, 1e-6);
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn solve_captures_errors() {
+        let solver = NewtonSolver::new();
+        let mut x = Vector::from(&[1.0]);
+        assert_eq!(solver.solve(&mut x, |_, _| Err("stop")).err(), Some("stop"));
+        assert_eq!(
+            solver
+                .solve(&mut x, |f, x| {
+                    f[0] = 1.0 / (x[0] - 1.0);
+                    Ok(())
+                })
+                .err(),
+            Some("F(x) is not finite at the initial guess")
+        );
+        // no real root
+        let mut x = Vector::from(&[0.5]);
+        assert_eq!(
+            solver
+                .solve_with_jacobian(
+                    &mut x,
+                    |f, x| {
+                        f[0] = x[0] * x[0] + 1.0;
+                        Ok(())
+                    },
+                    |jj, x| {
+                        jj.set(0, 0, 2.0 * x[0]);
+                        Ok(())
+                    }
+                )
+                .err(),
+            Some("the line search failed to decrease the norm of F(x)")
+        );
+        // too few iterations
+        let mut solver = NewtonSolver::new();
+        solver.max_iterations(1);
+        let mut x = Vector::from(&[-1.2, 1.0]);
+        assert_eq!(
+            solver.solve(&mut x, rosenbrock).err(),
+            Some("the Newton solver did not converge")
+        );
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn solve_with_jacobian_works() {
+        let solver = NewtonSolver::new();
+        let mut x = Vector::from(&[-1.2, 1.0]);
+        let stats = solver.solve_with_jacobian(&mut x, rosenbrock, rosenbrock_jac).unwrap();
+        vec_approx_eq(x.as_data(), &[1.0, 1.0], 1e-14);
+        assert!(stats.norm_f <= 1e-10);
+        assert_eq!(stats.n_jacobian_evals, stats.n_iterations);
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn solve_with_finite_differences_works() {
+        let solver = NewtonSolver::new();
+        let mut x = Vector::from(&[-1.2, 1.0]);
+        let stats = solver.solve(&mut x, rosenbrock).unwrap();
+        vec_approx_eq(x.as_data(), &[1.0, 1.0], 1e-10);
+        assert!(stats.n_function_evals >= 3 * stats.n_iterations);
+
+        // already converged
+        let stats = solver.solve(&mut x, rosenbrock).unwrap();
+        assert_eq!(stats.n_iterations, 0);
+        assert_eq!(stats.n_function_evals, 1);
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn line_search_helps() {
+        // f(x) = atan(x) diverges from x₀ = 2 without the line search
+        let f = |f: &mut Vector, x: &Vector| {
+            f[0] = f64::atan(x[0]);
+            Ok(())
+        };
+        let mut solver = NewtonSolver::new();
+        let mut x = Vector::from(&[2.0]);
+        solver.solve(&mut x, f).unwrap();
+        approx_eq(x[0], 0.0, 1e-10);
+
+        solver.line_search(false).max_iterations(3);
+        let mut x = Vector::from(&[2.0]);
+        assert_eq!(
+            solver.solve(&mut x, f).err(),
+            Some("the Newton solver did not converge")
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use crate::StrError;
+
+/// Holds the maximum number of subintervals in quad_adaptive
+const N_MAX_SUBINTERVALS: usize = 1000;
+
+/// Holds the abscissae of the 15-point Kronrod rule (the ones with odd index are also the 7-point Gauss abscissae)
+const XGK: [f64; 8] = [
+    0.9914553711208126,
+    0.9491079123427585,
+    0.8648644233597691,
+    0.7415311855993945,
+    0.5860872354676911,
+    0.4058451513773972,
+    0.20778495500789848,
+    0.0,
+];
+
+/// Holds the weights of the 15-point Kronrod rule
+const WGK: [f64; 8] = [
+    0.022935322010529224,
+    0.06309209262997856,
+    0.10479001032225019,
+    0.14065325971552592,
+    0.1690047266392679,
+    0.19035057806478542,
+    0.20443294007529889,
+    0.20948214108472782,
+];
+
+/// Holds the weights of the 7-point Gauss rule
+const WG: [f64; 4] = [
+    0.1294849661688697,
+    0.27970539148927664,
+    0.3818300505051189,
+    0.4179591836734694,
+];
+
+/// Integrates a function over [a, b] using the adaptive Gauss-Kronrod (G7-K15) quadrature
+///
+/// ```text
+/// b
+/// ∫ f(x) dx
+/// a
+/// ```
+///
+/// The subinterval with the largest error estimate (the difference between the 15-point Kronrod
+/// and the 7-point Gauss results) is bisected until the total error estimate is smaller than
+/// `tol` (absolute) or `tol ⋅ |result|` (relative). Because the end points are never evaluated,
+/// integrable singularities at the end points (e.g., 1/√x at x = 0) are handled, although
+/// more subintervals are needed.
+///
+/// # Input
+///
+/// * `f` -- the function f(x)
+/// * `a` -- the lower limit
+/// * `b` -- the upper limit
+/// * `tol` -- the tolerance (must be positive)
+///
+/// # Output
+///
+/// Returns `(result, error_estimate)`
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::approx_eq;
+/// use russell_lab::{quad_adaptive, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // singularity at x = 0
+///     let (res, err) = quad_adaptive(|x| 1.0 / f64::sqrt(x), 0.0, 1.0, 1e-10)?;
+///     approx_eq(res, 2.0, 1e-9);
+///     assert!(err < 1e-9);
+///     Ok(())
+/// }
+/// ```
+pub fn quad_adaptive<F>(mut f: F, a: f64, b: f64, tol: f64) -> Result<(f64, f64), StrError>
+where
+    F: FnMut(f64) -> f64,
+{
+    if tol <= 0.0 {
+        return Err("the tolerance must be positive");
+    }
+    if a == b {
+        return Ok((0.0, 0.0));
+    }
+    // subintervals: (a, b, result, error)
+    let (res, err) = gauss_kronrod(&mut f, a, b)?;
+    let mut intervals = vec![(a, b, res, err)];
+    let (mut total, mut total_err) = (res, err);
+    while total_err > f64::max(tol, tol * f64::abs(total)) {
+        if intervals.len() >= N_MAX_SUBINTERVALS {
+            return Err("quad_adaptive failed to converge");
+        }
+        // bisect the subinterval with the largest error
+        let mut k = 0;
+        for i in 1..intervals.len() {
+            if intervals[i].3 > intervals[k].3 {
+                k = i;
+            }
+        }
+        let (xa, xb, res_old, err_old) = intervals[k];
+        let xm = (xa + xb) / 2.0;
+        if xm <= f64::min(xa, xb) || xm >= f64::max(xa, xb) {
+            return Err("quad_adaptive failed because the subinterval is too small");
+        }
+        let (res_left, err_left) = gauss_kronrod(&mut f, xa, xm)?;
+        let (res_right, err_right) = gauss_kronrod(&mut f, xm, xb)?;
+        total += res_left + res_right - res_old;
+        total_err += err_left + err_right - err_old;
+        intervals[k] = (xa, xm, res_left, err_left);
+        intervals.push((xm, xb, res_right, err_right));
+    }
+    // sum again to reduce the accumulated round-off errors
+    total = intervals.iter().map(|s| s.2).sum();
+    total_err = intervals.iter().map(|s| s.3).sum();
+    Ok((total, total_err))
+}
+
+/// Applies the G7-K15 rule over [a, b] and returns the result and the error estimate
+///
+/// The error estimate follows QUADPACK (qk15), i.e., the difference between the Kronrod and
+/// Gauss results is scaled by the mean deviation of f over the interval.
+fn gauss_kronrod<F>(f: &mut F, a: f64, b: f64) -> Result<(f64, f64), StrError>
+where
+    F: FnMut(f64) -> f64,
+{
+    let half = (b - a) / 2.0;
+    let mid = (a + b) / 2.0;
+    let mut fv1 = [0.0; 7];
+    let mut fv2 = [0.0; 7];
+    let fc = f(mid);
+    if !fc.is_finite() {
+        return Err("the function returned a non-finite value");
+    }
+    let mut res_k = WGK[7] * fc;
+    let mut res_g = WG[3] * fc;
+    let mut res_abs = f64::abs(res_k);
+    for i in 0..7 {
+        let dx = half * XGK[i];
+        fv1[i] = f(mid - dx);
+        fv2[i] = f(mid + dx);
+        if !fv1[i].is_finite() || !fv2[i].is_finite() {
+            return Err("the function returned a non-finite value");
+        }
+        res_k += WGK[i] * (fv1[i] + fv2[i]);
+        res_abs += WGK[i] * (f64::abs(fv1[i]) + f64::abs(fv2[i]));
+        if i % 2 == 1 {
+            res_g += WG[i / 2] * (fv1[i] + fv2[i]);
+        }
+    }
+    // mean deviation: ∫ |f - mean(f)| dx
+    let mean = res_k / 2.0;
+    let mut res_asc = WGK[7] * f64::abs(fc - mean);
+    for i in 0..7 {
+        res_asc += WGK[i] * (f64::abs(fv1[i] - mean) + f64::abs(fv2[i] - mean));
+    }
+    let scale = f64::abs(half);
+    res_abs *= scale;
+    res_asc *= scale;
+    let mut err = f64::abs((res_k - res_g) * half);
+    if res_asc != 0.0 && err != 0.0 {
+        err = res_asc * f64::min(1.0, f64::powf(200.0 * err / res_asc, 1.5));
+    }
+    if res_abs > f64::MIN_POSITIVE / (50.0 * f64::EPSILON) {
+        err = f64::max(50.0 * f64::EPSILON * res_abs, err);
+    }
+    Ok((res_k * half, err))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{gauss_kronrod, quad_adaptive};
+    use russell_chk::approx_eq;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn quad_adaptive_captures_errors() {
+        assert_eq!(
+            quad_adaptive(|x| x, 0.0, 1.0, 0.0),
+            Err("the tolerance must be positive")
+        );
+        assert_eq!(
+            quad_adaptive(|x| 1.0 / x, -1.0, 1.0, 1e-8),
+            Err("the function returned a non-finite value")
+        );
+        assert_eq!(
+            quad_adaptive(|x| f64::sin(1.0 / x) / x, 0.0, 1.0, 1e-14),
+            Err("quad_adaptive failed to converge")
+        );
+    }
+
+    #[test]
+    fn gauss_kronrod_works() {
+        // exact for polynomials of degree ≤ 13 (Gauss: ≤ 13 as well; thus, no error)
+        let mut f = |x: f64| f64::powi(x, 12);
+        let (res, err) = gauss_kronrod(&mut f, -1.0, 1.0).unwrap();
+        approx_eq(res, 2.0 / 13.0, 1e-15);
+        assert!(err < 1e-13);
+        // the Gauss rule is not exact for degree 14; however, the Kronrod rule is
+        let mut f = |x: f64| f64::powi(x, 14);
+        let (res, err) = gauss_kronrod(&mut f, -1.0, 1.0).unwrap();
+        approx_eq(res, 2.0 / 15.0, 1e-15);
+        assert!(err > 1e-6);
+    }
+
+    #[test]
+    fn quad_adaptive_works() {
+        let (res, err) = quad_adaptive(f64::sin, 0.0, PI, 1e-12).unwrap();
+        approx_eq(res, 2.0, 1e-14);
+        assert!(err < 2e-12);
+
+        // reversed limits
+        let (res, _) = quad_adaptive(f64::sin, PI, 0.0, 1e-12).unwrap();
+        approx_eq(res, -2.0, 1e-14);
+
+        // empty interval
+        assert_eq!(quad_adaptive(f64::sin, 1.0, 1.0, 1e-12).unwrap(), (0.0, 0.0));
+
+        // sharp peak
+        let (res, _) = quad_adaptive(|x| 1.0 / (1e-4 + x * x), -1.0, 1.0, 1e-10).unwrap();
+        approx_eq(res, 2.0 * f64::atan(100.0) * 100.0, 1e-8);
+    }
+
+    #[test]
+    fn quad_adaptive_handles_endpoint_singularities() {
+        let (res, _) = quad_adaptive(f64::ln, 0.0, 1.0, 1e-10).unwrap();
+        approx_eq(res, -1.0, 1e-10);
+        let (res, _) = quad_adaptive(|x| f64::ln(x) / f64::sqrt(x), 0.0, 1.0, 1e-10).unwrap();
+        approx_eq(res, -4.0, 1e-9);
+    }
+}
@@ -0,0 +1,297 @@
+use crate::math::gamma;
+use crate::matrix::{mat_eigen_sym, Matrix};
+use crate::vector::Vector;
+use crate::StrError;
+
+/// Computes the points and weights of the Gauss-Jacobi quadrature
+///
+/// The rule integrates exactly (up to round-off) polynomials of degree 2n-1 multiplied by the weight function:
+///
+/// ```text
+///  1
+///  ∫ (1-x)ᵅ (1+x)ᵝ f(x) dx  ≈  Σᵢ wᵢ f(xᵢ)
+/// -1
+/// ```
+///
+/// The points are the eigenvalues of the symmetric tridiagonal Jacobi matrix built from the
+/// three-term recurrence of the Jacobi polynomials; the weights are obtained from the first
+/// components of the eigenvectors (Golub-Welsch algorithm).
+///
+/// # Input
+///
+/// * `n` -- the number of points (n ≥ 1)
+/// * `alpha` -- the α parameter (α > -1)
+/// * `beta` -- the β parameter (β > -1)
+///
+/// # Output
+///
+/// Returns `(points, weights)` with the points in ascending order.
+///
+/// # Reference
+///
+/// * Golub GH and Welsch JH (1969) Calculation of Gauss quadrature rules,
+///   Mathematics of Computation, 23(106):221-230
+pub fn gauss_jacobi(n: usize, alpha: f64, beta: f64) -> Result<(Vector, Vector), StrError> {
+    if n < 1 {
+        return Err("the number of points must be at least 1");
+    }
+    if alpha <= -1.0 || beta <= -1.0 {
+        return Err("alpha and beta must be greater than -1");
+    }
+    let ab = alpha + beta;
+    let mut jj = Matrix::new(n, n);
+    for k in 0..n {
+        let kf = k as f64;
+        let diag = if k == 0 {
+            (beta - alpha) / (ab + 2.0)
+        } else {
+            (beta * beta - alpha * alpha) / ((2.0 * kf + ab) * (2.0 * kf + ab + 2.0))
+        };
+        jj.set(k, k, diag);
+        if k > 0 {
+            let b2 = if k == 1 {
+                4.0 * (1.0 + alpha) * (1.0 + beta) / ((2.0 + ab) * (2.0 + ab) * (3.0 + ab))
+            } else {
+                let d = 2.0 * kf + ab;
+                4.0 * kf * (kf + alpha) * (kf + beta) * (kf + ab) / (d * d * (d + 1.0) * (d - 1.0))
+            };
+            let b = f64::sqrt(b2);
+            jj.set(k - 1, k, b);
+            jj.set(k, k - 1, b);
+        }
+    }
+    let mu0 = f64::powf(2.0, ab + 1.0) * gamma(alpha + 1.0) * gamma(beta + 1.0) / gamma(ab + 2.0);
+    let mut points = Vector::new(n);
+    mat_eigen_sym(&mut points, &mut jj)?;
+    let mut weights = Vector::new(n);
+    for i in 0..n {
+        let v0 = jj.get(0, i);
+        weights[i] = mu0 * v0 * v0;
+    }
+    Ok((points, weights))
+}
+
+/// Computes the points and weights of the Gauss-Legendre quadrature
+///
+/// ```text
+///  1
+///  ∫ f(x) dx  ≈  Σᵢ wᵢ f(xᵢ)
+/// -1
+/// ```
+///
+/// The rule with n points integrates exactly (up to round-off) polynomials of degree 2n-1.
+///
+/// # Output
+///
+/// Returns `(points, weights)` with the points in ascending order.
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::vec_approx_eq;
+/// use russell_lab::{gauss_legendre, StrError};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     let (x, w) = gauss_legendre(2)?;
+///     let a = 1.0 / f64::sqrt(3.0);
+///     vec_approx_eq(x.as_data(), &[-a, a], 1e-14);
+///     vec_approx_eq(w.as_data(), &[1.0, 1.0], 1e-14);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+pub fn gauss_legendre(n: usize) -> Result<(Vector, Vector), StrError> {
+    gauss_jacobi(n, 0.0, 0.0)
+}
+
+/// Computes the points and weights of the Gauss-Lobatto(-Legendre) quadrature
+///
+/// The rule includes the end points -1 and 1; with n points, it integrates exactly
+/// (up to round-off) polynomials of degree 2n-3.
+///
+/// The interior points are the roots of P'ₙ₋₁(x) (the derivative of the Legendre polynomial),
+/// computed as Gauss-Jacobi points with α = β = 1. The weights are:
+///
+/// ```text
+/// wᵢ = 2 / (n (n-1) Pₙ₋₁(xᵢ)²)
+/// ```
+///
+/// # Input
+///
+/// * `n` -- the number of points (n ≥ 2)
+///
+/// # Output
+///
+/// Returns `(points, weights)` with the points in ascending order.
+pub fn gauss_lobatto(n: usize) -> Result<(Vector, Vector), StrError> {
+    if n < 2 {
+        return Err("the number of points must be at least 2");
+    }
+    let mut points = Vector::new(n);
+    points[0] = -1.0;
+    points[n - 1] = 1.0;
+    if n > 2 {
+        let (interior, _) = gauss_jacobi(n - 2, 1.0, 1.0)?;
+        for i in 0..(n - 2) {
+            points[1 + i] = interior[i];
+        }
+    }
+    let den = (n * (n - 1)) as f64;
+    let mut weights = Vector::new(n);
+    for i in 0..n {
+        let p = legendre(n - 1, points[i]);
+        weights[i] = 2.0 / (den * p * p);
+    }
+    Ok((points, weights))
+}
+
+/// Integrates a function over [a, b] using the Gauss-Legendre quadrature with n points
+///
+/// ```text
+/// b
+/// ∫ f(x) dx  ≈  (b - a) / 2  Σᵢ wᵢ f(xᵢ)
+/// a
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::approx_eq;
+/// use russell_lab::{quad, StrError};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     let res = quad(|x| x * x, 0.0, 3.0, 2)?;
+///     approx_eq(res, 9.0, 1e-14);
+///     let res = quad(f64::sin, 0.0, std::f64::consts::PI, 10)?;
+///     approx_eq(res, 2.0, 1e-14);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+pub fn quad<F>(mut f: F, a: f64, b: f64, n: usize) -> Result<f64, StrError>
+where
+    F: FnMut(f64) -> f64,
+{
+    let (points, weights) = gauss_legendre(n)?;
+    let half = (b - a) / 2.0;
+    let mid = (a + b) / 2.0;
+    let mut sum = 0.0;
+    for i in 0..n {
+        sum += weights[i] * f(half * points[i] + mid);
+    }
+    Ok(sum * half)
+}
+
+/// Evaluates the Legendre polynomial Pₙ(x) using the three-term recurrence
+fn legendre(n: usize, x: f64) -> f64 {
+    let (mut p0, mut p1) = (1.0, x);
+    if n == 0 {
+        return p0;
+    }
+    for k in 1..n {
+        let kf = k as f64;
+        let p2 = ((2.0 * kf + 1.0) * x * p1 - kf * p0) / (kf + 1.0);
+        p0 = p1;
+        p1 = p2;
+    }
+    p1
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(unused_imports))]
+mod tests {
+    use super::{gauss_jacobi, gauss_legendre, gauss_lobatto, legendre, quad};
+    use russell_chk::{approx_eq, vec_approx_eq};
+
+    #[test]
+    fn functions_capture_errors() {
+        assert_eq!(
+            gauss_jacobi(0, 0.0, 0.0).err(),
+            Some("the number of points must be at least 1")
+        );
+        assert_eq!(
+            gauss_jacobi(2, -1.0, 0.0).err(),
+            Some("alpha and beta must be greater than -1")
+        );
+        assert_eq!(gauss_legendre(0).err(), Some("the number of points must be at least 1"));
+        assert_eq!(gauss_lobatto(1).err(), Some("the number of points must be at least 2"));
+        assert_eq!(quad(|x| x, 0.0, 1.0, 0), Err("the number of points must be at least 1"));
+    }
+
+    #[test]
+    fn legendre_works() {
+        approx_eq(legendre(0, 0.3), 1.0, 1e-15);
+        approx_eq(legendre(1, 0.3), 0.3, 1e-15);
+        approx_eq(legendre(2, 0.3), (3.0 * 0.09 - 1.0) / 2.0, 1e-15);
+        approx_eq(legendre(3, 0.3), (5.0 * 0.027 - 3.0 * 0.3) / 2.0, 1e-15);
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn gauss_legendre_works() {
+        let (x, w) = gauss_legendre(1).unwrap();
+        assert_eq!(x.as_data(), &[0.0]);
+        approx_eq(w[0], 2.0, 1e-15);
+
+        let (x, w) = gauss_legendre(3).unwrap();
+        let a = f64::sqrt(3.0 / 5.0);
+        vec_approx_eq(x.as_data(), &[-a, 0.0, a], 1e-14);
+        vec_approx_eq(w.as_data(), &[5.0 / 9.0, 8.0 / 9.0, 5.0 / 9.0], 1e-14);
+
+        // high order: sum of weights and exactness for degree 2n-1
+        let n = 40;
+        let (x, w) = gauss_legendre(n).unwrap();
+        approx_eq(w.as_data().iter().sum(), 2.0, 1e-13);
+        let deg = 2 * n - 2; // even degree (the odd ones vanish)
+        let res: f64 = (0..n).map(|i| w[i] * f64::powi(x[i], deg as i32)).sum();
+        approx_eq(res, 2.0 / ((deg + 1) as f64), 1e-13);
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn gauss_lobatto_works() {
+        let (x, w) = gauss_lobatto(2).unwrap();
+        assert_eq!(x.as_data(), &[-1.0, 1.0]);
+        vec_approx_eq(w.as_data(), &[1.0, 1.0], 1e-15);
+
+        let (x, w) = gauss_lobatto(4).unwrap();
+        let a = f64::sqrt(1.0 / 5.0);
+        vec_approx_eq(x.as_data(), &[-1.0, -a, a, 1.0], 1e-14);
+        vec_approx_eq(w.as_data(), &[1.0 / 6.0, 5.0 / 6.0, 5.0 / 6.0, 1.0 / 6.0], 1e-14);
+
+        // exactness for degree 2n-3
+        let n = 12;
+        let (x, w) = gauss_lobatto(n).unwrap();
+        let deg = 2 * n - 4; // even degree
+        let res: f64 = (0..n).map(|i| w[i] * f64::powi(x[i], deg as i32)).sum();
+        approx_eq(res, 2.0 / ((deg + 1) as f64), 1e-14);
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn gauss_jacobi_works() {
+        // α = β = 0.5 (Chebyshev of the second kind): ∫ √(1-x²) dx = π/2
+        let (_, w) = gauss_jacobi(5, 0.5, 0.5).unwrap();
+        approx_eq(w.as_data().iter().sum(), std::f64::consts::PI / 2.0, 1e-14);
+
+        // α = 1, β = 0: ∫ (1-x) x² dx = 2/3
+        let (x, w) = gauss_jacobi(2, 1.0, 0.0).unwrap();
+        let res: f64 = (0..2).map(|i| w[i] * x[i] * x[i]).sum();
+        approx_eq(res, 2.0 / 3.0, 1e-14);
+
+        // α = -0.5, β = 0.5 (α + β = 0): ∫ √((1+x)/(1-x)) dx = π
+        let (_, w) = gauss_jacobi(3, -0.5, 0.5).unwrap();
+        approx_eq(w.as_data().iter().sum(), std::f64::consts::PI, 1e-14);
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn quad_works() {
+        approx_eq(quad(|x| 3.0 * x * x + 1.0, -1.0, 2.0, 2).unwrap(), 12.0, 1e-14);
+        approx_eq(quad(f64::exp, 0.0, 1.0, 8).unwrap(), f64::exp(1.0) - 1.0, 1e-15);
+    }
+}
@@ -0,0 +1,218 @@
+use std::cmp;
+use std::fmt;
+
+/// Defines the function returning the (i,j) component as used internally by DisplayOpts
+type FnGet<'a, T> = dyn Fn(usize, usize) -> T + 'a;
+
+/// Holds options to display a NumMatrix or a NumVector
+///
+/// This structure is returned by [crate::NumMatrix::display_opts] and [crate::NumVector::display_opts]
+/// and implements [fmt::Display]. With the default options, the output is the same as the one of the
+/// `Display` trait implemented by NumMatrix and NumVector.
+///
+/// Large matrices may be truncated with [DisplayOpts::max_rows] and [DisplayOpts::max_cols];
+/// in this case, the first and last rows (or columns) are shown with ellipses in between.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::Matrix;
+///
+/// let a = Matrix::from(&[
+///     [1.0, 2.0, 3.0, 4.0],
+///     [5.0, 6.0, 7.0, 8.0],
+///     [9.0, 10.0, 11.0, 12.0],
+/// ]);
+/// let text = format!("{}", a.display_opts().scientific(true).precision(1).max_rows(2).max_cols(2));
+/// assert_eq!(
+///     text,
+///     "┌                   ┐\n\
+///      │ 1.0e0     … 4.0e0 │\n\
+///      │     ⋮     ⋱     ⋮ │\n\
+///      │ 9.0e0     … 1.2e1 │\n\
+///      └                   ┘"
+/// );
+/// ```
+pub struct DisplayOpts<'a, T> {
+    nrow: usize,              // number of rows
+    ncol: usize,              // number of columns
+    get: Box<FnGet<'a, T>>,   // returns the (i,j) component
+    precision: Option<usize>, // number of digits after the decimal point
+    scientific: bool,         // use the scientific notation
+    width: usize,             // minimum width of each column
+    max_rows: Option<usize>,  // maximum number of rows to be shown
+    max_cols: Option<usize>,  // maximum number of columns to be shown
+}
+
+impl<'a, T> DisplayOpts<'a, T> {
+    /// Allocates a new instance with default options
+    pub(crate) fn new(nrow: usize, ncol: usize, get: Box<FnGet<'a, T>>) -> Self {
+        DisplayOpts {
+            nrow,
+            ncol,
+            get,
+            precision: None,
+            scientific: false,
+            width: 0,
+            max_rows: None,
+            max_cols: None,
+        }
+    }
+
+    /// Sets the number of digits after the decimal point
+    ///
+    /// **Note:** If not set, the precision given to the formatter (e.g., `{:.3}`) is used, if any.
+    pub fn precision(mut self, value: usize) -> Self {
+        self.precision = Some(value);
+        self
+    }
+
+    /// Enables or disables the scientific notation (e.g., `1.5e3`)
+    pub fn scientific(mut self, enabled: bool) -> Self {
+        self.scientific = enabled;
+        self
+    }
+
+    /// Sets the minimum width of each column (not counting the separating space)
+    pub fn width(mut self, value: usize) -> Self {
+        self.width = value;
+        self
+    }
+
+    /// Sets the maximum number of rows to be shown
+    pub fn max_rows(mut self, value: usize) -> Self {
+        self.max_rows = Some(value);
+        self
+    }
+
+    /// Sets the maximum number of columns to be shown
+    pub fn max_cols(mut self, value: usize) -> Self {
+        self.max_cols = Some(value);
+        self
+    }
+}
+
+/// Returns the indices to be shown; None indicates the position of the ellipsis
+fn shown_indices(n: usize, max: Option<usize>) -> Vec<Option<usize>> {
+    match max {
+        Some(max) if n > max => {
+            let (head, tail) = (max.div_ceil(2), max / 2);
+            let mut indices: Vec<_> = (0..head).map(Some).collect();
+            indices.push(None);
+            indices.extend((n - tail..n).map(Some));
+            indices
+        }
+        _ => (0..n).map(Some).collect(),
+    }
+}
+
+impl<'a, T> fmt::Display for DisplayOpts<'a, T>
+where
+    T: fmt::Display + fmt::LowerExp,
+{
+    /// Generates a string representation of the NumMatrix or NumVector with the selected options
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // handle empty matrix
+        if self.nrow == 0 || self.ncol == 0 {
+            return write!(f, "[]");
+        }
+        // format the shown values and find the largest width
+        let rows = shown_indices(self.nrow, self.max_rows);
+        let cols = shown_indices(self.ncol, self.max_cols);
+        let precision = self.precision.or(f.precision());
+        let mut width = self.width;
+        let mut cells = Vec::with_capacity(rows.len() * cols.len());
+        for i in &rows {
+            for j in &cols {
+                let cell = match (i, j) {
+                    (Some(i), Some(j)) => {
+                        let val = (self.get)(*i, *j);
+                        match (self.scientific, precision) {
+                            (true, Some(p)) => format!("{:.1$e}", val, p),
+                            (true, None) => format!("{:e}", val),
+                            (false, Some(p)) => format!("{:.1$}", val, p),
+                            (false, None) => format!("{}", val),
+                        }
+                    }
+                    (None, None) => "⋱".to_string(),
+                    (None, Some(_)) => "⋮".to_string(),
+                    (Some(_), None) => "…".to_string(),
+                };
+                width = cmp::max(cell.chars().count(), width);
+                cells.push(cell);
+            }
+        }
+        // draw matrix
+        width += 1;
+        let ncol = cols.len();
+        writeln!(f, "┌{:1$}┐", " ", width * ncol + 1)?;
+        for row in cells.chunks(ncol) {
+            write!(f, "│")?;
+            for cell in row {
+                write!(f, "{:>1$}", cell, width)?;
+            }
+            writeln!(f, " │")?;
+        }
+        write!(f, "└{:1$}┘", " ", width * ncol + 1)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::shown_indices;
+    use crate::{Matrix, NumMatrix, Vector};
+
+    #[test]
+    fn shown_indices_works() {
+        assert_eq!(shown_indices(3, None), &[Some(0), Some(1), Some(2)]);
+        assert_eq!(shown_indices(3, Some(3)), &[Some(0), Some(1), Some(2)]);
+        assert_eq!(shown_indices(5, Some(3)), &[Some(0), Some(1), None, Some(4)]);
+        assert_eq!(shown_indices(5, Some(2)), &[Some(0), None, Some(4)]);
+        assert_eq!(shown_indices(5, Some(1)), &[Some(0), None]);
+        assert_eq!(shown_indices(5, Some(0)), &[None]);
+    }
+
+    #[test]
+    fn display_opts_defaults_match_display() {
+        let a = Matrix::from(&[[1.0, 0.0, -1.0, 8.0], [4.0, 3.0, 1.0, -4.04]]);
+        assert_eq!(format!("{}", a.display_opts()), format!("{}", a));
+        assert_eq!(format!("{:.2}", a.display_opts()), format!("{:.2}", a));
+        let u = Vector::from(&[4.0, 3.0, 1.0, 0.0, -4.04]);
+        assert_eq!(format!("{}", u.display_opts()), format!("{}", u));
+        assert_eq!(format!("{:.3}", u.display_opts()), format!("{:.3}", u));
+        let e = Matrix::new(0, 0);
+        assert_eq!(format!("{}", e.display_opts()), "[]");
+    }
+
+    #[test]
+    fn display_opts_works() {
+        let a = NumMatrix::<i32>::from(&[[1, 2, 3], [4, 5, 6], [7, 8, 9], [10, 11, 12]]);
+        assert_eq!(
+            format!("{}", a.display_opts().max_rows(2).width(3)),
+            "┌             ┐\n\
+             │   1   2   3 │\n\
+             │   ⋮   ⋮   ⋮ │\n\
+             │  10  11  12 │\n\
+             └             ┘"
+        );
+        let u = Vector::from(&[1500.0, -0.25, 2.0, 3.0]);
+        assert_eq!(
+            format!("{}", u.display_opts().scientific(true).max_rows(3)),
+            "┌         ┐\n\
+             │   1.5e3 │\n\
+             │ -2.5e-1 │\n\
+             │       ⋮ │\n\
+             │     3e0 │\n\
+             └         ┘"
+        );
+        assert_eq!(
+            format!("{:.1}", u.display_opts().scientific(true).precision(2).max_rows(1)),
+            "┌        ┐\n\
+             │ 1.50e3 │\n\
+             │      ⋮ │\n\
+             └        ┘"
+        );
+    }
+}
@@ -68,4 +68,83 @@ pub enum Norm {
     /// ‖u‖_1 := sum_i |uᵢ|
     /// ```
     One,
+
+    /// Specifies the p-norm (with p ≥ 1)
+    ///
+    /// **matrix** (entry-wise; i.e., not the induced p-norm)
+    ///
+    /// ```text
+    /// ‖a‖_p = (Σ_i Σ_j |aᵢⱼ|ᵖ)^(1/p)
+    /// ```
+    ///
+    /// **vector**
+    ///
+    /// ```text
+    /// ‖u‖_p = (Σ_i |uᵢ|ᵖ)^(1/p)
+    /// ```
+    P(f64),
+
+    /// Specifies the root-mean-square norm
+    ///
+    /// **matrix**
+    ///
+    /// ```text
+    /// ‖a‖_rms = sqrt(Σ_i Σ_j |aᵢⱼ|⋅|aᵢⱼ| / (m⋅n))
+    /// ```
+    ///
+    /// **vector**
+    ///
+    /// ```text
+    /// ‖u‖_rms = sqrt(Σ_i |uᵢ|⋅|uᵢ| / n)
+    /// ```
+    Rms,
+}
+
+/// Options to select the factorization of a dense matrix
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Factorization {
+    /// Specifies the LU factorization with partial pivoting (general square matrix)
+    ///
+    /// ```text
+    /// p ⋅ a = l ⋅ u
+    /// ```
+    Lu,
+
+    /// Specifies the Cholesky factorization (symmetric positive-definite matrix)
+    ///
+    /// ```text
+    /// a = l ⋅ lᵀ
+    /// ```
+    Cholesky,
+}
+
+/// Options to select which singular vectors are computed by the SVD
+///
+/// With `k = min(m,n)` for an (m,n) matrix:
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SvdMode {
+    /// Computes all singular vectors: `u` is (m,m) and `vt` is (n,n)
+    Full,
+
+    /// Computes only the first `k` singular vectors (economy size): `u` is (m,k) and `vt` is (k,n)
+    Thin,
+
+    /// Computes only the singular values: `u` and `vt` are not accessed
+    ValuesOnly,
+}
+
+/// Options to sort a sequence of values such as eigenvalues
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SortOrder {
+    /// Sorts from the smallest to the largest value
+    Ascending,
+
+    /// Sorts from the largest to the smallest value
+    Descending,
+
+    /// Sorts from the smallest to the largest absolute value
+    MagnitudeAscending,
+
+    /// Sorts from the largest to the smallest absolute value
+    MagnitudeDescending,
 }
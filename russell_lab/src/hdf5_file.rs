@@ -0,0 +1,514 @@
+use crate::{Matrix, StrError, Vector};
+use hdf5_metno_sys::h5::{hsize_t, H5open};
+use hdf5_metno_sys::h5d::{H5Dclose, H5Dcreate2, H5Dget_space, H5Dopen2, H5Dread, H5Dwrite};
+use hdf5_metno_sys::h5e::{H5Eset_auto2, H5E_DEFAULT};
+use hdf5_metno_sys::h5f::{H5F_scope_t, H5Fclose, H5Fcreate, H5Fflush, H5Fopen, H5F_ACC_RDWR, H5F_ACC_TRUNC};
+use hdf5_metno_sys::h5g::{H5Gclose, H5Gcreate2};
+use hdf5_metno_sys::h5i::hid_t;
+use hdf5_metno_sys::h5l::{H5Ldelete, H5Lexists};
+use hdf5_metno_sys::h5p::H5P_DEFAULT;
+use hdf5_metno_sys::h5s::{
+    H5S_seloper_t, H5Sclose, H5Screate_simple, H5Sget_simple_extent_dims, H5Sget_simple_extent_ndims,
+    H5Sselect_hyperslab,
+};
+use hdf5_metno_sys::h5t::H5T_NATIVE_DOUBLE;
+use hdf5_metno_sys::LOCK;
+use std::ffi::{CString, OsStr};
+use std::fs;
+use std::ops::Range;
+use std::path::Path;
+use std::ptr;
+
+/// Implements a file in the HDF5 format holding named matrices and vectors
+///
+/// Each matrix or vector is stored as a dataset of `float64` values. The name of the dataset may
+/// include groups separated by slashes (e.g., `"results/step_001/stress"`); the groups are created
+/// as needed. The matrices are stored as two-dimensional datasets in the **row-major** order (as
+/// HDF5, NumPy, and h5py do); thus, `h5py.File(path)["results/step_001/stress"][...]` recovers the
+/// matrix in Python.
+///
+/// Blocks of a (very large) matrix or vector may be read with [Hdf5File::read_matrix_hyperslab] and
+/// [Hdf5File::read_vector_hyperslab]; only the selected entries are then read from the file.
+///
+/// **Note:** This structure is only available with the `hdf5` feature, which requires the HDF5
+/// library (e.g., `libhdf5-dev` on Debian/Ubuntu).
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{Hdf5File, Matrix, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     let path = "/tmp/russell_lab/doc_hdf5_file.h5";
+///     let mut file = Hdf5File::create(path)?;
+///     file.write_matrix("step_1/a", &Matrix::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]))?;
+///     file.write_vector("step_1/x", &Vector::from(&[7.0, 8.0]))?;
+///     drop(file);
+///
+///     // open the file again
+///     let file = Hdf5File::open(path)?;
+///     assert_eq!(file.read_vector("step_1/x")?.as_data(), &[7.0, 8.0]);
+///     let block = file.read_matrix_hyperslab("step_1/a", 0..2, 1..3)?;
+///     let correct = "┌     ┐\n\
+///                    │ 2 3 │\n\
+///                    │ 5 6 │\n\
+///                    └     ┘";
+///     assert_eq!(format!("{}", block), correct);
+///     Ok(())
+/// }
+/// ```
+pub struct Hdf5File {
+    id: hid_t, // identifier of the open file
+}
+
+impl Hdf5File {
+    /// Creates a new file (or truncates an existing one)
+    ///
+    /// # Input
+    ///
+    /// * `full_path` -- may be a String, &str, or Path
+    pub fn create<P>(full_path: &P) -> Result<Self, StrError>
+    where
+        P: AsRef<OsStr> + ?Sized,
+    {
+        let path = Path::new(full_path);
+        if let Some(p) = path.parent() {
+            fs::create_dir_all(p).map_err(|_| "cannot create directory")?;
+        }
+        let c_path = to_c_path(path)?;
+        let _guard = LOCK.lock();
+        initialize()?;
+        let id = unsafe { H5Fcreate(c_path.as_ptr(), H5F_ACC_TRUNC, H5P_DEFAULT, H5P_DEFAULT) };
+        if id < 0 {
+            return Err("cannot create hdf5 file");
+        }
+        Ok(Hdf5File { id })
+    }
+
+    /// Opens an existing file for reading and writing
+    ///
+    /// # Input
+    ///
+    /// * `full_path` -- may be a String, &str, or Path
+    pub fn open<P>(full_path: &P) -> Result<Self, StrError>
+    where
+        P: AsRef<OsStr> + ?Sized,
+    {
+        let c_path = to_c_path(Path::new(full_path))?;
+        let _guard = LOCK.lock();
+        initialize()?;
+        let id = unsafe { H5Fopen(c_path.as_ptr(), H5F_ACC_RDWR, H5P_DEFAULT) };
+        if id < 0 {
+            return Err("cannot open hdf5 file");
+        }
+        Ok(Hdf5File { id })
+    }
+
+    /// Writes a matrix to a (two-dimensional) dataset
+    ///
+    /// An existing dataset with the same name is replaced.
+    pub fn write_matrix(&mut self, name: &str, a: &Matrix) -> Result<(), StrError> {
+        let (m, n) = a.dims();
+        let mut data = Vec::with_capacity(m * n);
+        for i in 0..m {
+            for j in 0..n {
+                data.push(a.get(i, j));
+            }
+        }
+        self.write_dataset(name, &[m as hsize_t, n as hsize_t], &data)
+    }
+
+    /// Writes a vector to a (one-dimensional) dataset
+    ///
+    /// An existing dataset with the same name is replaced.
+    pub fn write_vector(&mut self, name: &str, u: &Vector) -> Result<(), StrError> {
+        self.write_dataset(name, &[u.dim() as hsize_t], u.as_data())
+    }
+
+    /// Reads a matrix from a (two-dimensional) dataset
+    pub fn read_matrix(&self, name: &str) -> Result<Matrix, StrError> {
+        let dims = self.dataset_dims(name)?;
+        if dims.len() != 2 {
+            return Err("hdf5 dataset must be two-dimensional");
+        }
+        self.read_matrix_hyperslab(name, 0..dims[0], 0..dims[1])
+    }
+
+    /// Reads a vector from a (one-dimensional) dataset
+    pub fn read_vector(&self, name: &str) -> Result<Vector, StrError> {
+        let dims = self.dataset_dims(name)?;
+        if dims.len() != 1 {
+            return Err("hdf5 dataset must be one-dimensional");
+        }
+        self.read_vector_hyperslab(name, 0..dims[0])
+    }
+
+    /// Reads a block (hyperslab) of a matrix stored in a (two-dimensional) dataset
+    ///
+    /// # Input
+    ///
+    /// * `name` -- the name of the dataset
+    /// * `rows` -- the range of rows of the block
+    /// * `cols` -- the range of columns of the block
+    pub fn read_matrix_hyperslab(
+        &self,
+        name: &str,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> Result<Matrix, StrError> {
+        let (m, n) = (rows.len(), cols.len());
+        let data = self.read_dataset(
+            name,
+            &[rows.start as hsize_t, cols.start as hsize_t],
+            &[m as hsize_t, n as hsize_t],
+        )?;
+        let mut a = Matrix::new(m, n);
+        for i in 0..m {
+            for j in 0..n {
+                a.set(i, j, data[i * n + j]);
+            }
+        }
+        Ok(a)
+    }
+
+    /// Reads a segment (hyperslab) of a vector stored in a (one-dimensional) dataset
+    ///
+    /// # Input
+    ///
+    /// * `name` -- the name of the dataset
+    /// * `range` -- the range of entries of the segment
+    pub fn read_vector_hyperslab(&self, name: &str, range: Range<usize>) -> Result<Vector, StrError> {
+        let data = self.read_dataset(name, &[range.start as hsize_t], &[range.len() as hsize_t])?;
+        Ok(Vector::from(&data))
+    }
+
+    /// Returns the dimensions of a dataset
+    pub fn dataset_dims(&self, name: &str) -> Result<Vec<usize>, StrError> {
+        let c_name = to_c_name(name)?;
+        let _guard = LOCK.lock();
+        let dataset = Handle::new(
+            unsafe { H5Dopen2(self.id, c_name.as_ptr(), H5P_DEFAULT) },
+            H5Dclose,
+            "cannot open hdf5 dataset",
+        )?;
+        let space = Handle::new(
+            unsafe { H5Dget_space(dataset.id) },
+            H5Sclose,
+            "cannot get the dataspace of hdf5 dataset",
+        )?;
+        Ok(get_dims(space.id)?.iter().map(|d| *d as usize).collect())
+    }
+
+    /// Returns whether a dataset (or group) exists or not
+    pub fn exists(&self, name: &str) -> Result<bool, StrError> {
+        let _guard = LOCK.lock();
+        self.link_exists(name)
+    }
+
+    /// Flushes the buffers of the file to the disk
+    pub fn flush(&mut self) -> Result<(), StrError> {
+        let _guard = LOCK.lock();
+        if unsafe { H5Fflush(self.id, H5F_scope_t::H5F_SCOPE_LOCAL) } < 0 {
+            return Err("cannot flush hdf5 file");
+        }
+        Ok(())
+    }
+
+    /// Writes the data (row-major) to a dataset, creating the groups as needed
+    fn write_dataset(&mut self, name: &str, dims: &[hsize_t], data: &[f64]) -> Result<(), StrError> {
+        let c_name = to_c_name(name)?;
+        let _guard = LOCK.lock();
+
+        // create the groups (and remove an existing dataset)
+        let mut position = 0;
+        while let Some(p) = name[position..].find('/') {
+            let group = &name[..(position + p)];
+            position += p + 1;
+            if group.is_empty() || self.link_exists(group)? {
+                continue;
+            }
+            let c_group = to_c_name(group)?;
+            Handle::new(
+                unsafe { H5Gcreate2(self.id, c_group.as_ptr(), H5P_DEFAULT, H5P_DEFAULT, H5P_DEFAULT) },
+                H5Gclose,
+                "cannot create hdf5 group",
+            )?;
+        }
+        if self.link_exists(name)? && unsafe { H5Ldelete(self.id, c_name.as_ptr(), H5P_DEFAULT) } < 0 {
+            return Err("cannot replace hdf5 dataset");
+        }
+
+        // create and write the dataset
+        let space = Handle::new(
+            unsafe { H5Screate_simple(dims.len() as i32, dims.as_ptr(), ptr::null()) },
+            H5Sclose,
+            "cannot create hdf5 dataspace",
+        )?;
+        let dataset = Handle::new(
+            unsafe {
+                H5Dcreate2(
+                    self.id,
+                    c_name.as_ptr(),
+                    *H5T_NATIVE_DOUBLE,
+                    space.id,
+                    H5P_DEFAULT,
+                    H5P_DEFAULT,
+                    H5P_DEFAULT,
+                )
+            },
+            H5Dclose,
+            "cannot create hdf5 dataset",
+        )?;
+        if data.is_empty() {
+            return Ok(());
+        }
+        let status = unsafe {
+            H5Dwrite(
+                dataset.id,
+                *H5T_NATIVE_DOUBLE,
+                space.id,
+                space.id,
+                H5P_DEFAULT,
+                data.as_ptr() as *const _,
+            )
+        };
+        if status < 0 {
+            return Err("cannot write hdf5 dataset");
+        }
+        Ok(())
+    }
+
+    /// Reads the data (row-major) of the hyperslab defined by start and count
+    fn read_dataset(&self, name: &str, start: &[hsize_t], count: &[hsize_t]) -> Result<Vec<f64>, StrError> {
+        let c_name = to_c_name(name)?;
+        let _guard = LOCK.lock();
+        let dataset = Handle::new(
+            unsafe { H5Dopen2(self.id, c_name.as_ptr(), H5P_DEFAULT) },
+            H5Dclose,
+            "cannot open hdf5 dataset",
+        )?;
+        let file_space = Handle::new(
+            unsafe { H5Dget_space(dataset.id) },
+            H5Sclose,
+            "cannot get the dataspace of hdf5 dataset",
+        )?;
+        let dims = get_dims(file_space.id)?;
+        if dims.len() != start.len() {
+            return Err("hdf5 dataset has incompatible number of dimensions");
+        }
+        for k in 0..dims.len() {
+            if start[k] + count[k] > dims[k] {
+                return Err("ranges are out of bounds");
+            }
+        }
+        let len = count.iter().product::<hsize_t>() as usize;
+        let mut data = vec![0.0; len];
+        if len == 0 {
+            return Ok(data);
+        }
+        let status = unsafe {
+            H5Sselect_hyperslab(
+                file_space.id,
+                H5S_seloper_t::H5S_SELECT_SET,
+                start.as_ptr(),
+                ptr::null(),
+                count.as_ptr(),
+                ptr::null(),
+            )
+        };
+        if status < 0 {
+            return Err("cannot select hdf5 hyperslab");
+        }
+        let memory_space = Handle::new(
+            unsafe { H5Screate_simple(count.len() as i32, count.as_ptr(), ptr::null()) },
+            H5Sclose,
+            "cannot create hdf5 dataspace",
+        )?;
+        let status = unsafe {
+            H5Dread(
+                dataset.id,
+                *H5T_NATIVE_DOUBLE,
+                memory_space.id,
+                file_space.id,
+                H5P_DEFAULT,
+                data.as_mut_ptr() as *mut _,
+            )
+        };
+        if status < 0 {
+            return Err("cannot read hdf5 dataset");
+        }
+        Ok(data)
+    }
+
+    /// Returns whether a link exists or not (the lock must be held)
+    fn link_exists(&self, name: &str) -> Result<bool, StrError> {
+        let c_name = to_c_name(name)?;
+        let status = unsafe { H5Lexists(self.id, c_name.as_ptr(), H5P_DEFAULT) };
+        if status < 0 {
+            return Err("cannot check whether the hdf5 link exists");
+        }
+        Ok(status > 0)
+    }
+}
+
+impl Drop for Hdf5File {
+    /// Closes the file
+    fn drop(&mut self) {
+        let _guard = LOCK.lock();
+        unsafe {
+            H5Fclose(self.id);
+        }
+    }
+}
+
+/// Holds an identifier that is closed when dropped
+struct Handle {
+    id: hid_t,
+    close: unsafe extern "C" fn(hid_t) -> i32,
+}
+
+impl Handle {
+    /// Returns a new handle or the error if the identifier is negative
+    fn new(id: hid_t, close: unsafe extern "C" fn(hid_t) -> i32, error: StrError) -> Result<Self, StrError> {
+        if id < 0 {
+            Err(error)
+        } else {
+            Ok(Handle { id, close })
+        }
+    }
+}
+
+impl Drop for Handle {
+    fn drop(&mut self) {
+        unsafe {
+            (self.close)(self.id);
+        }
+    }
+}
+
+/// Initializes the library and disables the automatic printing of errors (the lock must be held)
+fn initialize() -> Result<(), StrError> {
+    unsafe {
+        if H5open() < 0 {
+            return Err("cannot initialize the hdf5 library");
+        }
+        H5Eset_auto2(H5E_DEFAULT, None, ptr::null_mut());
+    }
+    Ok(())
+}
+
+/// Returns the dimensions of a dataspace (the lock must be held)
+fn get_dims(space: hid_t) -> Result<Vec<hsize_t>, StrError> {
+    let ndim = unsafe { H5Sget_simple_extent_ndims(space) };
+    if ndim < 0 {
+        return Err("cannot get the dimensions of hdf5 dataspace");
+    }
+    let mut dims = vec![0; ndim as usize];
+    if unsafe { H5Sget_simple_extent_dims(space, dims.as_mut_ptr(), ptr::null_mut()) } < 0 {
+        return Err("cannot get the dimensions of hdf5 dataspace");
+    }
+    Ok(dims)
+}
+
+/// Converts the path to a C string
+fn to_c_path(path: &Path) -> Result<CString, StrError> {
+    let path = path.to_str().ok_or("the path must be valid unicode")?;
+    CString::new(path).map_err(|_| "the path must not contain nul characters")
+}
+
+/// Converts the name of a dataset or group to a C string
+fn to_c_name(name: &str) -> Result<CString, StrError> {
+    if name.is_empty() || name.ends_with('/') {
+        return Err("hdf5 dataset name is invalid");
+    }
+    CString::new(name).map_err(|_| "hdf5 dataset name is invalid")
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Hdf5File;
+    use crate::{Matrix, Vector};
+
+    #[test]
+    fn hdf5_file_captures_errors() {
+        assert_eq!(
+            Hdf5File::open("/tmp/russell_lab/not-found.h5").err(),
+            Some("cannot open hdf5 file")
+        );
+        let path = "/tmp/russell_lab/test_hdf5_file_captures_errors.h5";
+        let mut file = Hdf5File::create(path).unwrap();
+        let a = Matrix::from(&[[1.0, 2.0], [3.0, 4.0]]);
+        assert_eq!(file.write_matrix("", &a).err(), Some("hdf5 dataset name is invalid"));
+        assert_eq!(file.write_matrix("a/", &a).err(), Some("hdf5 dataset name is invalid"));
+        assert_eq!(file.read_matrix("a").err(), Some("cannot open hdf5 dataset"));
+        file.write_matrix("a", &a).unwrap();
+        file.write_vector("u", &Vector::from(&[1.0, 2.0])).unwrap();
+        assert_eq!(
+            file.read_vector("a").err(),
+            Some("hdf5 dataset must be one-dimensional")
+        );
+        assert_eq!(
+            file.read_matrix("u").err(),
+            Some("hdf5 dataset must be two-dimensional")
+        );
+        assert_eq!(
+            file.read_matrix_hyperslab("a", 1..3, 0..2).err(),
+            Some("ranges are out of bounds")
+        );
+        assert_eq!(
+            file.read_vector_hyperslab("a", 0..1).err(),
+            Some("hdf5 dataset has incompatible number of dimensions")
+        );
+    }
+
+    #[test]
+    fn hdf5_file_works() {
+        let path = "/tmp/russell_lab/test_hdf5_file_works.h5";
+        let mut file = Hdf5File::create(path).unwrap();
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 10.0, 11.0, 12.0],
+        ]);
+        let u = Vector::from(&[-1.0, -2.0, -3.0, -4.0, -5.0]);
+        file.write_matrix("results/step_1/a", &a).unwrap();
+        file.write_vector("results/step_1/u", &u).unwrap();
+        file.write_vector("results/step_2/u", &Vector::from(&[0.5])).unwrap();
+        file.write_matrix("empty", &Matrix::new(0, 3)).unwrap();
+        file.flush().unwrap();
+        assert!(file.exists("results/step_1").unwrap());
+        assert!(!file.exists("results/step_3").unwrap());
+        drop(file);
+
+        // open the file again
+        let mut file = Hdf5File::open(path).unwrap();
+        assert_eq!(file.dataset_dims("results/step_1/a").unwrap(), &[3, 4]);
+        let b = file.read_matrix("results/step_1/a").unwrap();
+        assert_eq!(b.dims(), (3, 4));
+        assert_eq!(b.as_data(), a.as_data());
+        assert_eq!(file.read_vector("results/step_1/u").unwrap().as_data(), u.as_data());
+        assert_eq!(file.read_vector("results/step_2/u").unwrap().as_data(), &[0.5]);
+        assert_eq!(file.read_matrix("empty").unwrap().dims(), (0, 3));
+
+        // hyperslabs
+        let block = file.read_matrix_hyperslab("results/step_1/a", 1..3, 1..4).unwrap();
+        assert_eq!(
+            format!("{}", block),
+            "┌          ┐\n\
+             │  6  7  8 │\n\
+             │ 10 11 12 │\n\
+             └          ┘"
+        );
+        let segment = file.read_vector_hyperslab("results/step_1/u", 2..4).unwrap();
+        assert_eq!(segment.as_data(), &[-3.0, -4.0]);
+        assert_eq!(file.read_vector_hyperslab("results/step_1/u", 1..1).unwrap().dim(), 0);
+
+        // replace a dataset
+        file.write_vector("results/step_2/u", &Vector::from(&[1.0, 2.0]))
+            .unwrap();
+        assert_eq!(file.read_vector("results/step_2/u").unwrap().as_data(), &[1.0, 2.0]);
+    }
+}
@@ -7,6 +7,7 @@
 //! ```
 //! use russell_lab::{mat_cholesky, Matrix, StrError};
 //!
+//! # #[cfg(not(feature = "pure_rust"))]
 //! fn main() -> Result<(), StrError> {
 //!     // set matrix
 //!     let a = Matrix::from(&[
@@ -29,19 +30,29 @@
 //!     assert_eq!(format!("{}", l), l_correct);
 //!     Ok(())
 //! }
+//! # #[cfg(feature = "pure_rust")] fn main() {}
 //! ```
 
 /// Defines a type alias for the error type as a static string
 pub type StrError = &'static str;
 
+mod algo;
 mod as_array;
 mod constants;
+mod display_opts;
 mod enums;
 mod formatters;
 mod generators;
+#[cfg(feature = "hdf5")]
+mod hdf5_file;
 pub mod math;
 mod matrix;
 mod matvec;
+mod npy;
+#[cfg(feature = "npz")]
+mod npz;
+mod ode;
+mod perf_report;
 pub mod prelude;
 mod read_table;
 mod sort;
@@ -49,21 +60,38 @@ mod sort_vec_mat;
 mod stopwatch;
 mod testing;
 mod vector;
+mod workspace;
+pub use crate::algo::*;
 pub use crate::as_array::*;
 use crate::constants::*;
+pub use crate::display_opts::*;
 pub use crate::enums::*;
 pub use crate::formatters::*;
 pub use crate::generators::*;
+#[cfg(feature = "hdf5")]
+pub use crate::hdf5_file::*;
 pub use crate::matrix::*;
 pub use crate::matvec::*;
+pub use crate::npy::*;
+#[cfg(feature = "npz")]
+pub use crate::npz::*;
+pub use crate::ode::*;
+pub use crate::perf_report::*;
 pub use crate::read_table::*;
 pub use crate::sort::*;
 pub use crate::sort_vec_mat::*;
 pub use crate::stopwatch::*;
 pub use crate::vector::*;
+pub use crate::workspace::*;
 
-// run code from README file
-#[cfg(doctest)]
+// thread control of the BLAS/LAPACK backend
+pub use russell_openblas::{get_num_threads, set_num_threads, SingleThreadGuard};
+
+// structured error returned by the linear solvers, decompositions, and eigen functions
+pub use russell_openblas::Error;
+
+// run code from README file (the examples require LAPACK)
+#[cfg(all(doctest, not(feature = "pure_rust")))]
 mod test_readme {
     macro_rules! external_doc_test {
         ($x:expr) => {
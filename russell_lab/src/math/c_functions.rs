@@ -1,7 +1,9 @@
+#[cfg(not(feature = "pure_rust"))]
 extern "C" {
     fn c_erf(x: f64) -> f64;
     fn c_erfc(x: f64) -> f64;
     fn c_gamma(x: f64) -> f64;
+    fn c_ln_gamma(x: f64) -> f64;
 }
 
 /// Returns the error function (wraps C-code: erf)
@@ -11,7 +13,12 @@ extern "C" {
 /// Reference: <https://en.wikipedia.org/wiki/Error_function>
 #[inline]
 pub fn erf(x: f64) -> f64 {
-    unsafe { c_erf(x) }
+    #[cfg(not(feature = "pure_rust"))]
+    unsafe {
+        c_erf(x)
+    }
+    #[cfg(feature = "pure_rust")]
+    libm::erf(x)
 }
 
 /// Returns the complementary error function (wraps C-code: erfc)
@@ -21,7 +28,12 @@ pub fn erf(x: f64) -> f64 {
 /// Reference: <https://en.wikipedia.org/wiki/Error_function>
 #[inline]
 pub fn erfc(x: f64) -> f64 {
-    unsafe { c_erfc(x) }
+    #[cfg(not(feature = "pure_rust"))]
+    unsafe {
+        c_erfc(x)
+    }
+    #[cfg(feature = "pure_rust")]
+    libm::erfc(x)
 }
 
 /// Returns the Gamma function Γ (wraps C-code: tgamma)
@@ -29,14 +41,32 @@ pub fn erfc(x: f64) -> f64 {
 /// Code from: <https://www.cplusplus.com/reference/cmath/tgamma/>
 #[inline]
 pub fn gamma(x: f64) -> f64 {
-    unsafe { c_gamma(x) }
+    #[cfg(not(feature = "pure_rust"))]
+    unsafe {
+        c_gamma(x)
+    }
+    #[cfg(feature = "pure_rust")]
+    libm::tgamma(x)
+}
+
+/// Returns the natural logarithm of the absolute value of the Gamma function ln|Γ| (wraps C-code: lgamma)
+///
+/// Code from: <https://www.cplusplus.com/reference/cmath/lgamma/>
+#[inline]
+pub fn ln_gamma(x: f64) -> f64 {
+    #[cfg(not(feature = "pure_rust"))]
+    unsafe {
+        c_ln_gamma(x)
+    }
+    #[cfg(feature = "pure_rust")]
+    libm::lgamma(x)
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::{erf, erfc, gamma};
+    use super::{erf, erfc, gamma, ln_gamma};
     use crate::math::PI;
     use russell_chk::approx_eq;
 
@@ -118,7 +148,10 @@ mod tests {
         approx_eq(gamma(-0.5), -3.54490770181103205459633496668229036559509891224477425642761, 1e-13);
         approx_eq(gamma(1.0e-5 + 1.0e-16), 99999.42279322556767360213300482199406241771308740302819426480, 1e-9);
         approx_eq(gamma(0.1), 9.513507698668731836292487177265402192550578626088377343050000, 1e-14);
+        #[cfg(not(feature = "pure_rust"))]
         assert_eq!(gamma(1.0 - 1.0e-14), 1.000000000000005772156649015427511664653698987042926067639529);
+        #[cfg(feature = "pure_rust")]
+        approx_eq(gamma(1.0 - 1.0e-14), 1.000000000000005772156649015427511664653698987042926067639529, 1e-15);
         approx_eq(gamma(1.0), 1.0, 1e-15);
         approx_eq(gamma(1.0 + 1.0e-14), 0.99999999999999422784335098477029953441189552403615306268023, 1e-15);
         approx_eq(gamma(1.5), 0.886226925452758013649083741670572591398774728061193564106903, 1e-14);
@@ -137,4 +170,16 @@ mod tests {
         approx_eq(gamma(10.1), 454760.7514415859508673358368319076190405047458218916492282448, 1e-7);
         approx_eq(gamma(150.0 + 1.0e-12), 3.8089226376496421386707466577615064443807882167327097140e+260, 1e248);
     }
+
+    #[test]
+    fn ln_gamma_works() {
+        assert!(ln_gamma(f64::NAN).is_nan());
+        assert_eq!(ln_gamma(1.0), 0.0);
+        assert_eq!(ln_gamma(2.0), 0.0);
+        approx_eq(ln_gamma(0.5), 0.5723649429247001, 1e-15);
+        approx_eq(ln_gamma(1e-3), 6.907178885383853, 1e-14);
+        approx_eq(ln_gamma(5.0), f64::ln(24.0), 1e-14);
+        approx_eq(ln_gamma(200.5), 860.5822035097825, 1e-12);
+        assert_eq!(ln_gamma(0.0), f64::INFINITY);
+    }
 }
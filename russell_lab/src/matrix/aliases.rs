@@ -4,5 +4,8 @@ use num_complex::Complex64;
 /// Matrix is an alias to NumMatrix&lt;f64&gt; and is used in most functions that call OpenBLAS
 pub type Matrix = NumMatrix<f64>;
 
+/// MatrixF32 is an alias to NumMatrix&lt;f32&gt; and is used in the single precision functions
+pub type MatrixF32 = NumMatrix<f32>;
+
 /// ComplexMatrix is an alias to NumMatrix&lt;Complex64&gt; and is used in most functions that call OpenBLAS
 pub type ComplexMatrix = NumMatrix<Complex64>;
@@ -0,0 +1,105 @@
+use super::ComplexMatrix;
+
+/// Panics if a complex matrix is not (approximately) Hermitian
+///
+/// The matrix must be square and satisfy:
+///
+/// ```text
+/// |aᵢⱼ - conj(aⱼᵢ)| ≤ tol · max(1, max|a|)
+/// ```
+///
+/// In particular, the diagonal must be (approximately) real.
+/// The panic message reports the largest violation.
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use russell_lab::{check_hermitian, ComplexMatrix};
+///
+/// let a = ComplexMatrix::from(&[
+///     [Complex64::new(2.0, 0.0), Complex64::new(1.0, -1.0)],
+///     [Complex64::new(1.0, 1.0), Complex64::new(3.0, 0.0)],
+/// ]);
+/// check_hermitian(&a, 1e-15);
+/// ```
+pub fn check_hermitian(a: &ComplexMatrix, tol: f64) {
+    let (m, n) = a.dims();
+    if m != n {
+        panic!("matrix must be square. {} != {}", m, n);
+    }
+    let scale = a.as_data().iter().fold(1.0, |acc, x| f64::max(acc, x.norm()));
+    let mut worst = (0, 0);
+    let mut max_diff = 0.0;
+    for i in 0..m {
+        for j in i..m {
+            let diff = (a.get(i, j) - a.get(j, i).conj()).norm();
+            if diff > max_diff {
+                max_diff = diff;
+                worst = (i, j);
+            }
+        }
+    }
+    if max_diff > tol * scale {
+        panic!(
+            "matrix is not Hermitian. @ ({},{}) |aᵢⱼ - conj(aⱼᵢ)| = {:?} (a[{},{}] = {} and a[{},{}] = {})",
+            worst.0,
+            worst.1,
+            max_diff,
+            worst.0,
+            worst.1,
+            a.get(worst.0, worst.1),
+            worst.1,
+            worst.0,
+            a.get(worst.1, worst.0)
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{check_hermitian, ComplexMatrix};
+    use num_complex::Complex64;
+
+    #[test]
+    #[should_panic(expected = "matrix must be square. 2 != 3")]
+    fn check_hermitian_panics_on_rectangular_matrix() {
+        check_hermitian(&ComplexMatrix::new(2, 3), 1e-15);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "matrix is not Hermitian. @ (0,1) |aᵢⱼ - conj(aⱼᵢ)| = 2.0 (a[0,1] = 1+1i and a[1,0] = 1+1i)"
+    )]
+    fn check_hermitian_panics_on_symmetric_matrix() {
+        let a = ComplexMatrix::from(&[
+            [Complex64::new(2.0, 0.0), Complex64::new(1.0, 1.0)],
+            [Complex64::new(1.0, 1.0), Complex64::new(3.0, 0.0)],
+        ]);
+        check_hermitian(&a, 1e-15);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "matrix is not Hermitian. @ (1,1) |aᵢⱼ - conj(aⱼᵢ)| = 1.0 (a[1,1] = 3+0.5i and a[1,1] = 3+0.5i)"
+    )]
+    fn check_hermitian_panics_on_complex_diagonal() {
+        let a = ComplexMatrix::from(&[
+            [Complex64::new(2.0, 0.0), Complex64::new(1.0, -1.0)],
+            [Complex64::new(1.0, 1.0), Complex64::new(3.0, 0.5)],
+        ]);
+        check_hermitian(&a, 1e-15);
+    }
+
+    #[test]
+    fn check_hermitian_works() {
+        check_hermitian(&ComplexMatrix::new(0, 0), 0.0);
+        let a = ComplexMatrix::from(&[
+            [Complex64::new(1e6, 0.0), Complex64::new(2.0, -1.0)],
+            [Complex64::new(2.0 + 1e-6, 1.0), Complex64::new(1.0, 0.0)],
+        ]);
+        check_hermitian(&a, 1e-11);
+    }
+}
@@ -0,0 +1,86 @@
+use super::Matrix;
+
+/// Panics if the columns of a matrix are not (approximately) orthonormal
+///
+/// The (m,n) matrix q with m ≥ n must satisfy:
+///
+/// ```text
+/// |(qᵀ⋅q - I)ᵢⱼ| ≤ tol
+/// ```
+///
+/// Thus, for a square matrix, q is orthogonal. The panic message reports the largest violation.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{check_orthogonal, Matrix};
+///
+/// let (c, s) = (f64::cos(0.3), f64::sin(0.3));
+/// let q = Matrix::from(&[[c, -s], [s, c]]);
+/// check_orthogonal(&q, 1e-15);
+/// ```
+pub fn check_orthogonal(q: &Matrix, tol: f64) {
+    let (m, n) = q.dims();
+    if m < n {
+        panic!("matrix must have at least as many rows as columns. {} < {}", m, n);
+    }
+    let mut worst = (0, 0);
+    let mut max_diff = 0.0;
+    for i in 0..n {
+        for j in i..n {
+            let mut qtq = 0.0;
+            for k in 0..m {
+                qtq += q.get(k, i) * q.get(k, j);
+            }
+            let delta = if i == j { 1.0 } else { 0.0 };
+            let diff = f64::abs(qtq - delta);
+            if diff > max_diff {
+                max_diff = diff;
+                worst = (i, j);
+            }
+        }
+    }
+    if max_diff > tol {
+        panic!(
+            "matrix is not orthogonal. @ ({},{}) |(qᵀ⋅q - I)ᵢⱼ| = {:?}",
+            worst.0, worst.1, max_diff
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{check_orthogonal, Matrix};
+
+    #[test]
+    #[should_panic(expected = "matrix must have at least as many rows as columns. 2 < 3")]
+    fn check_orthogonal_panics_on_wide_matrix() {
+        check_orthogonal(&Matrix::new(2, 3), 1e-15);
+    }
+
+    #[test]
+    #[should_panic(expected = "matrix is not orthogonal. @ (1,1) |(qᵀ⋅q - I)ᵢⱼ| = 3.0")]
+    fn check_orthogonal_panics_on_non_unit_columns() {
+        let q = Matrix::from(&[[1.0, 0.0], [0.0, 2.0]]);
+        check_orthogonal(&q, 1e-15);
+    }
+
+    #[test]
+    #[should_panic(expected = "matrix is not orthogonal. @ (0,1) |(qᵀ⋅q - I)ᵢⱼ| = 0.7071067811865476")]
+    fn check_orthogonal_panics_on_non_orthogonal_columns() {
+        let s = f64::sqrt(0.5);
+        let q = Matrix::from(&[[1.0, s], [0.0, s]]);
+        check_orthogonal(&q, 1e-10);
+    }
+
+    #[test]
+    fn check_orthogonal_works() {
+        check_orthogonal(&Matrix::identity(3), 0.0);
+        // (3,2) matrix with orthonormal columns
+        let (a, b) = (1.0 / f64::sqrt(3.0), 1.0 / f64::sqrt(2.0));
+        let q = Matrix::from(&[[a, b], [a, -b], [a, 0.0]]);
+        check_orthogonal(&q, 1e-15);
+    }
+}
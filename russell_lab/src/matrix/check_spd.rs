@@ -0,0 +1,122 @@
+use super::check_symmetric::max_abs;
+use super::{check_symmetric, Matrix};
+
+/// Panics if a matrix is not (approximately) symmetric positive-definite (SPD)
+///
+/// First, the symmetry is checked by [check_symmetric]. Then, a Cholesky factorization
+/// `a = l⋅lᵀ` is computed and all pivots (the squares of the diagonal of l) must satisfy:
+///
+/// ```text
+/// pivotᵢ > tol · max(1, max|a|)
+/// ```
+///
+/// The panic message reports the smallest pivot (the largest violation).
+///
+/// # Note
+///
+/// The factorization is computed without LAPACK; thus, this function is meant for tests with small matrices.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{check_spd, Matrix};
+///
+/// let a = Matrix::from(&[[4.0, 2.0], [2.0, 3.0]]);
+/// check_spd(&a, 1e-15);
+/// ```
+pub fn check_spd(a: &Matrix, tol: f64) {
+    check_symmetric(a, tol);
+    let n = a.nrow();
+    let threshold = tol * f64::max(1.0, max_abs(a));
+    let mut l = Matrix::new(n, n);
+    let mut min_pivot = f64::INFINITY;
+    let mut worst = 0;
+    for j in 0..n {
+        let mut pivot = a.get(j, j);
+        for k in 0..j {
+            pivot -= l.get(j, k) * l.get(j, k);
+        }
+        if pivot < min_pivot {
+            min_pivot = pivot;
+            worst = j;
+        }
+        if pivot <= threshold {
+            // the factorization cannot proceed
+            break;
+        }
+        let ljj = f64::sqrt(pivot);
+        l.set(j, j, ljj);
+        for i in (j + 1)..n {
+            let mut sum = a.get(i, j);
+            for k in 0..j {
+                sum -= l.get(i, k) * l.get(j, k);
+            }
+            l.set(i, j, sum / ljj);
+        }
+    }
+    if min_pivot <= threshold {
+        panic!(
+            "matrix is not positive-definite. @ {} Cholesky pivot = {:?} (threshold = {:?})",
+            worst, min_pivot, threshold
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{check_spd, Matrix};
+    use russell_chk::TestDataGenerator;
+
+    #[test]
+    #[should_panic(expected = "matrix is not symmetric. @ (0,1)")]
+    fn check_spd_panics_on_non_symmetric_matrix() {
+        let a = Matrix::from(&[[2.0, 1.0], [0.0, 2.0]]);
+        check_spd(&a, 1e-15);
+    }
+
+    #[test]
+    #[should_panic(expected = "matrix is not positive-definite. @ 1 Cholesky pivot = -3.0")]
+    fn check_spd_panics_on_indefinite_matrix() {
+        let a = Matrix::from(&[[1.0, 2.0], [2.0, 1.0]]);
+        check_spd(&a, 1e-15);
+    }
+
+    #[test]
+    #[should_panic(expected = "matrix is not positive-definite. @ 2 Cholesky pivot = ")]
+    fn check_spd_panics_on_singular_matrix() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [ 2.0, -1.0, -1.0],
+            [-1.0,  2.0, -1.0],
+            [-1.0, -1.0,  2.0],
+        ]);
+        check_spd(&a, 1e-12);
+    }
+
+    #[test]
+    fn check_spd_works() {
+        check_spd(&Matrix::identity(3), 1e-15);
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [  3.0,   0.0,  -3.0,   0.0,   0.0],
+            [  0.0,   3.0,   1.0,   3.0,   0.0],
+            [ -3.0,   1.0,   4.0,   1.0,   0.0],
+            [  0.0,   3.0,   1.0,   3.0,   0.0],
+            [  0.0,   0.0,   0.0,   0.0,   3.0],
+        ]);
+        let mut b = a.clone();
+        b.set(3, 3, 4.0); // make it positive-definite
+        check_spd(&b, 1e-14);
+    }
+
+    #[test]
+    fn check_spd_works_with_random_matrices() {
+        let mut gen = TestDataGenerator::new(1234);
+        for n in 1..8 {
+            let a = Matrix::from(&gen.spd_with_condition(n, 1e6));
+            check_spd(&a, 1e-12);
+        }
+    }
+}
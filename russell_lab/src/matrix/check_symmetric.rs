@@ -0,0 +1,91 @@
+use super::Matrix;
+
+/// Panics if a matrix is not (approximately) symmetric
+///
+/// The matrix must be square and satisfy:
+///
+/// ```text
+/// |aᵢⱼ - aⱼᵢ| ≤ tol · max(1, max|a|)
+/// ```
+///
+/// The panic message reports the largest violation.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{check_symmetric, Matrix};
+///
+/// let a = Matrix::from(&[[2.0, 1.0], [1.0 + 1e-16, 2.0]]);
+/// check_symmetric(&a, 1e-15);
+/// ```
+pub fn check_symmetric(a: &Matrix, tol: f64) {
+    let (m, n) = a.dims();
+    if m != n {
+        panic!("matrix must be square. {} != {}", m, n);
+    }
+    let scale = f64::max(1.0, max_abs(a));
+    let mut worst = (0, 0);
+    let mut max_diff = 0.0;
+    for i in 0..m {
+        for j in (i + 1)..m {
+            let diff = f64::abs(a.get(i, j) - a.get(j, i));
+            if diff > max_diff {
+                max_diff = diff;
+                worst = (i, j);
+            }
+        }
+    }
+    if max_diff > tol * scale {
+        panic!(
+            "matrix is not symmetric. @ ({},{}) |aᵢⱼ - aⱼᵢ| = {:?} (a[{},{}] = {:?} and a[{},{}] = {:?})",
+            worst.0,
+            worst.1,
+            max_diff,
+            worst.0,
+            worst.1,
+            a.get(worst.0, worst.1),
+            worst.1,
+            worst.0,
+            a.get(worst.1, worst.0)
+        );
+    }
+}
+
+/// Returns the maximum absolute value of the components of a matrix
+pub(super) fn max_abs(a: &Matrix) -> f64 {
+    a.as_data().iter().fold(0.0, |acc, x| f64::max(acc, f64::abs(*x)))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{check_symmetric, max_abs, Matrix};
+
+    #[test]
+    #[should_panic(expected = "matrix must be square. 2 != 3")]
+    fn check_symmetric_panics_on_rectangular_matrix() {
+        check_symmetric(&Matrix::new(2, 3), 1e-15);
+    }
+
+    #[test]
+    #[should_panic(expected = "matrix is not symmetric. @ (0,2) |aᵢⱼ - aⱼᵢ| = 0.5 (a[0,2] = 3.0 and a[2,0] = 2.5)")]
+    fn check_symmetric_panics_on_non_symmetric_matrix() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0, 2.0, 3.0],
+            [2.1, 1.0, 4.0],
+            [2.5, 4.0, 1.0],
+        ]);
+        check_symmetric(&a, 1e-2);
+    }
+
+    #[test]
+    fn check_symmetric_works() {
+        assert_eq!(max_abs(&Matrix::from(&[[1.0, -3.0], [2.0, 0.0]])), 3.0);
+        check_symmetric(&Matrix::new(0, 0), 0.0);
+        // the tolerance is scaled by the largest component
+        let a = Matrix::from(&[[1e6, 2.0], [2.0 + 1e-6, 1.0]]);
+        check_symmetric(&a, 1e-11);
+    }
+}
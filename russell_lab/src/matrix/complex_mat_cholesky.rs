@@ -0,0 +1,148 @@
+use super::ComplexMatrix;
+use crate::Error;
+use num_complex::Complex64;
+use russell_openblas::{to_i32, zpotrf};
+
+/// Performs the Cholesky factorization of a Hermitian positive-definite matrix (complex version)
+///
+/// Finds `l` such that:
+///
+/// ```text
+/// a = l⋅lᴴ
+/// ```
+///
+/// where `l` is a lower-triangular matrix and `lᴴ` is the conjugate transpose of `l`
+///
+/// **Note:** Only the lower triangle (and diagonal) of `a` is accessed.
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use russell_lab::{complex_mat_approx_eq, complex_mat_cholesky, ComplexMatrix, StrError};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     // set matrix
+///     let a = ComplexMatrix::from(&[
+///         [Complex64::new(4.0, 0.0), Complex64::new(2.0, -2.0)],
+///         [Complex64::new(2.0, 2.0), Complex64::new(6.0,  0.0)],
+///     ]);
+///
+///     // perform factorization
+///     let mut l = ComplexMatrix::new(2, 2);
+///     complex_mat_cholesky(&mut l, &a)?;
+///
+///     // compare with solution
+///     let l_correct = &[
+///         [Complex64::new(2.0, 0.0), Complex64::new(0.0, 0.0)],
+///         [Complex64::new(1.0, 1.0), Complex64::new(2.0, 0.0)],
+///     ];
+///     complex_mat_approx_eq(&l, l_correct, 1e-15);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+pub fn complex_mat_cholesky(l: &mut ComplexMatrix, a: &ComplexMatrix) -> Result<(), Error> {
+    // check
+    let (m, n) = a.dims();
+    if m != n {
+        return Err(Error::BadDimensions("matrix must be square"));
+    }
+    if l.nrow() != m || l.ncol() != n {
+        return Err(Error::BadDimensions("matrices are incompatible"));
+    }
+
+    // copy lower+diagonal part and set upper part to zero
+    for i in 0..m {
+        for j in 0..n {
+            if i >= j {
+                l.set(i, j, a.get(i, j));
+            } else {
+                l.set(i, j, Complex64::new(0.0, 0.0));
+            }
+        }
+    }
+
+    // perform factorization
+    if m > 0 {
+        let m_i32 = to_i32(m);
+        zpotrf(false, m_i32, l.as_mut_data())?;
+    }
+
+    // done
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(unused_imports))]
+mod tests {
+    use super::{complex_mat_cholesky, ComplexMatrix};
+    use crate::{complex_mat_approx_eq, complex_mat_mat_mul, Error};
+    use num_complex::Complex64;
+
+    #[test]
+    fn complex_mat_cholesky_fails_on_wrong_dims() {
+        let a = ComplexMatrix::new(2, 2);
+        let a_wrong = ComplexMatrix::new(2, 3);
+        let mut l = ComplexMatrix::new(2, 2);
+        let mut l_wrong = ComplexMatrix::new(3, 2);
+        assert_eq!(
+            complex_mat_cholesky(&mut l, &a_wrong),
+            Err(Error::BadDimensions("matrix must be square"))
+        );
+        assert_eq!(
+            complex_mat_cholesky(&mut l_wrong, &a),
+            Err(Error::BadDimensions("matrices are incompatible"))
+        );
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn complex_mat_cholesky_fails_on_non_posdef() {
+        let a = ComplexMatrix::from(&[
+            [Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0)],
+            [Complex64::new(2.0, 0.0), Complex64::new(1.0, 0.0)],
+        ]);
+        let mut l = ComplexMatrix::new(2, 2);
+        assert_eq!(
+            complex_mat_cholesky(&mut l, &a),
+            Err(Error::Singular("LAPACK zpotrf failed"))
+        );
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn complex_mat_cholesky_works() {
+        #[rustfmt::skip]
+        let a = ComplexMatrix::from(&[
+            [Complex64::new( 4.0, 0.0), Complex64::new(2.0, -2.0), Complex64::new(0.0,  2.0)],
+            [Complex64::new( 2.0, 2.0), Complex64::new(6.0,  0.0), Complex64::new(1.0, -1.0)],
+            [Complex64::new( 0.0,-2.0), Complex64::new(1.0,  1.0), Complex64::new(5.0,  0.0)],
+        ]);
+        let mut l = ComplexMatrix::new(3, 3);
+        complex_mat_cholesky(&mut l, &a).unwrap();
+
+        // check that l is lower triangular with a real positive diagonal
+        for i in 0..3 {
+            assert_eq!(l.get(i, i).im, 0.0);
+            assert!(l.get(i, i).re > 0.0);
+            for j in (i + 1)..3 {
+                assert_eq!(l.get(i, j), Complex64::new(0.0, 0.0));
+            }
+        }
+
+        // check that l⋅lᴴ == a
+        let mut lh = ComplexMatrix::new(3, 3);
+        for i in 0..3 {
+            for j in 0..3 {
+                lh.set(i, j, l.get(j, i).conj());
+            }
+        }
+        let mut l_lh = ComplexMatrix::new(3, 3);
+        complex_mat_mat_mul(&mut l_lh, Complex64::new(1.0, 0.0), &l, &lh).unwrap();
+        complex_mat_approx_eq(&l_lh, &a, 1e-14);
+    }
+}
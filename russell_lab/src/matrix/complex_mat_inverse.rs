@@ -0,0 +1,252 @@
+use super::ComplexMatrix;
+use crate::Error;
+use num_complex::Complex64;
+use russell_openblas::{to_i32, zcopy, zgetrf, zgetri, BlasInt};
+
+/// Computes the inverse of a square matrix and returns its determinant (complex version)
+///
+/// ```text
+/// ai := a⁻¹
+/// ```
+///
+/// # Output
+///
+/// * `ai` -- (m,m) inverse matrix
+/// * Returns the matrix determinant
+///
+/// # Input
+///
+/// * `a` -- (m,m) matrix, symmetric or not
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use russell_lab::{complex_mat_approx_eq, complex_mat_inverse, ComplexMatrix, StrError};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     // set matrix
+///     let a = ComplexMatrix::from(&[
+///         [Complex64::new(0.0, 1.0), Complex64::new(1.0, 0.0)],
+///         [Complex64::new(1.0, 0.0), Complex64::new(0.0, 1.0)],
+///     ]);
+///
+///     // compute inverse matrix
+///     let mut ai = ComplexMatrix::new(2, 2);
+///     let det = complex_mat_inverse(&mut ai, &a)?;
+///
+///     // compare with solution
+///     assert_eq!(det, Complex64::new(-2.0, 0.0));
+///     let ai_correct = &[
+///         [Complex64::new(0.0, -0.5), Complex64::new(0.5, 0.0)],
+///         [Complex64::new(0.5, 0.0), Complex64::new(0.0, -0.5)],
+///     ];
+///     complex_mat_approx_eq(&ai, ai_correct, 1e-15);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+pub fn complex_mat_inverse(ai: &mut ComplexMatrix, a: &ComplexMatrix) -> Result<Complex64, Error> {
+    // check
+    let (m, n) = a.dims();
+    if m != n {
+        return Err(Error::BadDimensions("matrix must be square"));
+    }
+    if ai.nrow() != m || ai.ncol() != n {
+        return Err(Error::BadDimensions("matrices are incompatible"));
+    }
+
+    // handle zero-sized matrix
+    if m == 0 {
+        return Ok(Complex64::new(0.0, 0.0));
+    }
+
+    // copy a into ai
+    let m_i32 = to_i32(m);
+    zcopy(m_i32 * m_i32, a.as_data(), 1, ai.as_mut_data(), 1);
+
+    // perform the factorization
+    let mut ipiv = vec![0; m];
+    match zgetrf(m_i32, m_i32, ai.as_mut_data(), &mut ipiv) {
+        Err(Error::Singular(_)) => return Err(Error::Singular("cannot compute inverse due to zero determinant")),
+        Err(e) => return Err(e),
+        Ok(_) => (),
+    }
+
+    // first, compute the determinant
+    let det = lu_determinant(ai, &ipiv);
+
+    // second, perform the inversion
+    zgetri(m_i32, ai.as_mut_data(), &ipiv)?;
+    Ok(det)
+}
+
+/// Computes the determinant of a square matrix (complex version)
+///
+/// The determinant is computed via the LU factorization (with partial pivoting) of a copy of `a`.
+/// A singular matrix yields a zero determinant; moreover, the determinant of a zero-sized matrix is one.
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use russell_lab::{complex_mat_det, ComplexMatrix, StrError};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     let a = ComplexMatrix::from(&[
+///         [Complex64::new(1.0, 1.0), Complex64::new(2.0, 0.0)],
+///         [Complex64::new(0.0, 3.0), Complex64::new(1.0, -1.0)],
+///     ]);
+///     // (1+i)(1-i) - 2(3i) = 2 - 6i
+///     let det = complex_mat_det(&a)?;
+///     assert_eq!(det, Complex64::new(2.0, -6.0));
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+pub fn complex_mat_det(a: &ComplexMatrix) -> Result<Complex64, Error> {
+    let (m, n) = a.dims();
+    if m != n {
+        return Err(Error::BadDimensions("matrix must be square"));
+    }
+    if m == 0 {
+        return Ok(Complex64::new(1.0, 0.0));
+    }
+    let m_i32 = to_i32(m);
+    let mut lu = a.clone();
+    let mut ipiv = vec![0; m];
+    match zgetrf(m_i32, m_i32, lu.as_mut_data(), &mut ipiv) {
+        Err(Error::Singular(_)) => return Ok(Complex64::new(0.0, 0.0)),
+        Err(e) => return Err(e),
+        Ok(_) => (),
+    }
+    Ok(lu_determinant(&lu, &ipiv))
+}
+
+/// Computes the determinant from the LU factors computed by zgetrf
+fn lu_determinant(lu: &ComplexMatrix, ipiv: &[BlasInt]) -> Complex64 {
+    let mut det = Complex64::new(1.0, 0.0);
+    for (k, p) in ipiv.iter().enumerate() {
+        // NOTE: ipiv are 1-based indices
+        if (p - 1) as usize == k {
+            det *= lu.get(k, k);
+        } else {
+            det *= -lu.get(k, k);
+        }
+    }
+    det
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(dead_code, unused_imports))]
+mod tests {
+    use super::{complex_mat_det, complex_mat_inverse, ComplexMatrix};
+    use crate::{complex_mat_approx_eq, Error};
+    use num_complex::Complex64;
+    use russell_chk::complex_approx_eq;
+
+    /// Computes a⋅ai that should equal I for a square matrix
+    fn get_a_times_ai(a: &ComplexMatrix, ai: &ComplexMatrix) -> ComplexMatrix {
+        let (m, n) = a.dims();
+        let mut a_ai = ComplexMatrix::new(m, m);
+        for i in 0..m {
+            for j in 0..m {
+                for k in 0..n {
+                    a_ai.add(i, j, a.get(i, k) * ai.get(k, j));
+                }
+            }
+        }
+        a_ai
+    }
+
+    #[test]
+    fn complex_mat_inverse_fails_on_wrong_dims() {
+        let a_2x3 = ComplexMatrix::new(2, 3);
+        let a_2x2 = ComplexMatrix::new(2, 2);
+        let mut ai_1x2 = ComplexMatrix::new(1, 2);
+        let mut ai_2x1 = ComplexMatrix::new(2, 1);
+        assert_eq!(
+            complex_mat_inverse(&mut ai_1x2, &a_2x3),
+            Err(Error::BadDimensions("matrix must be square"))
+        );
+        assert_eq!(
+            complex_mat_inverse(&mut ai_1x2, &a_2x2),
+            Err(Error::BadDimensions("matrices are incompatible"))
+        );
+        assert_eq!(
+            complex_mat_inverse(&mut ai_2x1, &a_2x2),
+            Err(Error::BadDimensions("matrices are incompatible"))
+        );
+        assert_eq!(
+            complex_mat_det(&a_2x3),
+            Err(Error::BadDimensions("matrix must be square"))
+        );
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn complex_mat_inverse_fails_on_singular_matrix() {
+        let a = ComplexMatrix::from(&[
+            [Complex64::new(1.0, 1.0), Complex64::new(2.0, 2.0)],
+            [Complex64::new(0.0, 1.0), Complex64::new(0.0, 2.0)],
+        ]);
+        let mut ai = ComplexMatrix::new(2, 2);
+        assert_eq!(
+            complex_mat_inverse(&mut ai, &a),
+            Err(Error::Singular("cannot compute inverse due to zero determinant"))
+        );
+        assert_eq!(complex_mat_det(&a), Ok(Complex64::new(0.0, 0.0)));
+    }
+
+    #[test]
+    fn complex_mat_inverse_0x0_works() {
+        let a = ComplexMatrix::new(0, 0);
+        let mut ai = ComplexMatrix::new(0, 0);
+        let det = complex_mat_inverse(&mut ai, &a).unwrap();
+        assert_eq!(det, Complex64::new(0.0, 0.0));
+        assert_eq!(ai.as_data().len(), 0);
+        assert_eq!(complex_mat_det(&a), Ok(Complex64::new(1.0, 0.0)));
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn complex_mat_inverse_works() {
+        #[rustfmt::skip]
+        let a = ComplexMatrix::from(&[
+            [Complex64::new(2.0, 1.0), Complex64::new(0.0,  1.0), Complex64::new(1.0,  0.0)],
+            [Complex64::new(1.0, 0.0), Complex64::new(3.0, -1.0), Complex64::new(0.0,  2.0)],
+            [Complex64::new(0.0, 0.0), Complex64::new(1.0,  1.0), Complex64::new(4.0,  0.0)],
+        ]);
+        let mut ai = ComplexMatrix::new(3, 3);
+        let det = complex_mat_inverse(&mut ai, &a).unwrap();
+        // det = (2+i)[(3-i)4 - 2i(1+i)] - i[4 - 0] + 1[(1+i) - 0]
+        //     = (2+i)(14-6i) - 4i + 1 + i = (34+2i) + 1 - 3i = 35 - i
+        complex_approx_eq(det, Complex64::new(35.0, -1.0), 1e-14);
+        let identity = ComplexMatrix::identity(3);
+        let a_ai = get_a_times_ai(&a, &ai);
+        complex_mat_approx_eq(&a_ai, &identity, 1e-15);
+        complex_approx_eq(complex_mat_det(&a).unwrap(), Complex64::new(35.0, -1.0), 1e-14);
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn complex_mat_det_handles_pivoting() {
+        // row swap: det([[0, 1], [i, 0]]) = -i
+        let a = ComplexMatrix::from(&[
+            [Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0)],
+            [Complex64::new(0.0, 1.0), Complex64::new(0.0, 0.0)],
+        ]);
+        assert_eq!(complex_mat_det(&a), Ok(Complex64::new(0.0, -1.0)));
+        let mut ai = ComplexMatrix::new(2, 2);
+        complex_mat_inverse(&mut ai, &a).unwrap();
+        let ai_correct = &[
+            [Complex64::new(0.0, 0.0), Complex64::new(0.0, -1.0)],
+            [Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)],
+        ];
+        complex_mat_approx_eq(&ai, ai_correct, 1e-15);
+    }
+}
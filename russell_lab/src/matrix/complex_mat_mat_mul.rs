@@ -49,9 +49,9 @@ pub fn complex_mat_mat_mul(
     if a.nrow() != m || b.nrow() != k || b.ncol() != n {
         return Err("matrices are incompatible");
     }
-    let m_i32: i32 = to_i32(m);
-    let n_i32: i32 = to_i32(n);
-    let k_i32: i32 = to_i32(k);
+    let m_i32 = to_i32(m);
+    let n_i32 = to_i32(n);
+    let k_i32 = to_i32(k);
     let zero = Complex64::new(0.0, 0.0);
     zgemm(
         false,
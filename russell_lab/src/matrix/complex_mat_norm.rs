@@ -26,13 +26,18 @@ pub fn complex_mat_norm(a: &ComplexMatrix, kind: Norm) -> f64 {
     if m == 0 || n == 0 {
         return 0.0;
     }
+    let (m_i32, n_i32) = (to_i32(m), to_i32(n));
     let norm = match kind {
         Norm::Euc | Norm::Fro => b'F',
         Norm::Inf => b'I',
         Norm::Max => b'M',
         Norm::One => b'1',
+        Norm::P(p) => {
+            let sum: f64 = a.as_data().iter().map(|x| f64::powf(x.norm(), p)).sum();
+            return f64::powf(sum, 1.0 / p);
+        }
+        Norm::Rms => return zlange(b'F', m_i32, n_i32, a.as_data()) / f64::sqrt((m * n) as f64),
     };
-    let (m_i32, n_i32) = (to_i32(m), to_i32(n));
     zlange(norm, m_i32, n_i32, &a.as_data())
 }
 
@@ -85,5 +90,7 @@ mod tests {
         fro = f64::sqrt(fro);
         approx_eq(complex_mat_norm(&a, Norm::Fro), fro, 1e-15);
         approx_eq(complex_mat_norm(&a, Norm::Max), Complex64::new(5.0, 1.0).abs(), 1e-15);
+        approx_eq(complex_mat_norm(&a, Norm::P(2.0)), fro, 1e-14);
+        approx_eq(complex_mat_norm(&a, Norm::Rms), fro / 3.0, 1e-15);
     }
 }
@@ -40,12 +40,104 @@ pub fn complex_mat_zip(real: &Matrix, imag: &Matrix) -> Result<ComplexMatrix, St
     Ok(a)
 }
 
+/// Unzips a ComplexMatrix into two new arrays (real and imag)
+///
+/// This function is the inverse of [complex_mat_zip].
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use russell_lab::{complex_mat_unzip, ComplexMatrix};
+///
+/// let a = ComplexMatrix::from(&[
+///     [Complex64::new(1.0, 0.1), Complex64::new(2.0, 0.2)],
+///     [Complex64::new(3.0, -0.3), Complex64::new(4.0, -0.4)],
+/// ]);
+/// let (real, imag) = complex_mat_unzip(&a);
+/// assert_eq!(real.as_data(), &[1.0, 3.0, 2.0, 4.0]);
+/// assert_eq!(imag.as_data(), &[0.1, -0.3, 0.2, -0.4]);
+/// ```
+pub fn complex_mat_unzip(a: &ComplexMatrix) -> (Matrix, Matrix) {
+    let (m, n) = a.dims();
+    let mut real = Matrix::new(m, n);
+    let mut imag = Matrix::new(m, n);
+    complex_mat_real_imag_parts(&mut real, &mut imag, a).unwrap();
+    (real, imag)
+}
+
+/// Extracts the real and imaginary parts of a ComplexMatrix into existing matrices
+///
+/// ```text
+/// real := Re(a)
+/// imag := Im(a)
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use russell_lab::{complex_mat_real_imag_parts, ComplexMatrix, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = ComplexMatrix::from(&[
+///         [Complex64::new(1.0, 0.1), Complex64::new(2.0, 0.2)],
+///     ]);
+///     let mut real = Matrix::new(1, 2);
+///     let mut imag = Matrix::new(1, 2);
+///     complex_mat_real_imag_parts(&mut real, &mut imag, &a)?;
+///     assert_eq!(real.as_data(), &[1.0, 2.0]);
+///     assert_eq!(imag.as_data(), &[0.1, 0.2]);
+///     Ok(())
+/// }
+/// ```
+pub fn complex_mat_real_imag_parts(real: &mut Matrix, imag: &mut Matrix, a: &ComplexMatrix) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if real.nrow() != m || real.ncol() != n || imag.nrow() != m || imag.ncol() != n {
+        return Err("matrices are incompatible");
+    }
+    let re = real.as_mut_data();
+    let im = imag.as_mut_data();
+    for (k, z) in a.as_data().iter().enumerate() {
+        re[k] = z.re;
+        im[k] = z.im;
+    }
+    Ok(())
+}
+
+/// Converts a real matrix into a ComplexMatrix with zero imaginary parts
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{complex_mat_from_real, Matrix};
+///
+/// let a = Matrix::from(&[[1.0, 2.0], [3.0, 4.0]]);
+/// let b = complex_mat_from_real(&a);
+/// assert_eq!(
+///     format!("{}", b),
+///     "┌           ┐\n\
+///      │ 1+0i 2+0i │\n\
+///      │ 3+0i 4+0i │\n\
+///      └           ┘"
+/// );
+/// ```
+pub fn complex_mat_from_real(a: &Matrix) -> ComplexMatrix {
+    let (m, n) = a.dims();
+    let mut b = ComplexMatrix::new(m, n);
+    for (z, x) in b.as_mut_data().iter_mut().zip(a.as_data()) {
+        z.re = *x;
+    }
+    b
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::complex_mat_zip;
-    use crate::Matrix;
+    use super::{complex_mat_from_real, complex_mat_real_imag_parts, complex_mat_unzip, complex_mat_zip};
+    use crate::{ComplexMatrix, Matrix};
+    use num_complex::Complex64;
 
     #[test]
     fn complex_mat_zip_handles_errors() {
@@ -67,4 +159,38 @@ mod tests {
              └                      ┘"
         );
     }
+
+    #[test]
+    fn complex_mat_real_imag_parts_handles_errors() {
+        let a = ComplexMatrix::new(2, 2);
+        let mut ok = Matrix::new(2, 2);
+        let mut wrong = Matrix::new(2, 1);
+        assert_eq!(
+            complex_mat_real_imag_parts(&mut wrong, &mut ok, &a).err(),
+            Some("matrices are incompatible")
+        );
+        let mut ok = Matrix::new(2, 2);
+        let mut wrong = Matrix::new(1, 2);
+        assert_eq!(
+            complex_mat_real_imag_parts(&mut ok, &mut wrong, &a).err(),
+            Some("matrices are incompatible")
+        );
+    }
+
+    #[test]
+    fn complex_mat_unzip_and_from_real_work() {
+        let real = Matrix::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let imag = Matrix::from(&[[0.1, 0.2, 0.3], [0.4, 0.5, 0.6]]);
+        let a = complex_mat_zip(&real, &imag).unwrap();
+        assert_eq!(a.get(1, 2), Complex64::new(6.0, 0.6));
+        let (re, im) = complex_mat_unzip(&a);
+        assert_eq!(re.as_data(), real.as_data());
+        assert_eq!(im.as_data(), imag.as_data());
+        let b = complex_mat_from_real(&real);
+        assert_eq!(b.dims(), (2, 3));
+        assert_eq!(b.get(1, 0), Complex64::new(4.0, 0.0));
+        let (re, im) = complex_mat_unzip(&b);
+        assert_eq!(re.as_data(), real.as_data());
+        assert_eq!(im.as_data(), &[0.0; 6]);
+    }
 }
@@ -1,9 +1,19 @@
 use super::Matrix;
 use crate::AsArray2D;
+use russell_chk::DiffReport;
 
-/// Panics if two vectors are not approximately equal to each other
+/// Defines the number of neighbouring rows and columns (on each side of the worst entry) shown in the report
+const REPORT_NEIGHBOURS: usize = 2;
+
+/// Panics if two matrices are not approximately equal to each other
+///
+/// Panics also if the matrix dimensions differ
 ///
-/// Panics also if the vector dimensions differ
+/// # Report
+///
+/// The panic message contains the indices of the worst violation (largest absolute difference),
+/// the maximum absolute and relative differences, the number of entries exceeding the tolerance,
+/// and a side-by-side dump of the submatrices around the worst violation (entries exceeding `tol` are marked with `*`).
 pub fn mat_approx_eq<'a, T>(a: &Matrix, b: &'a T, tol: f64)
 where
     T: AsArray2D<'a, f64>,
@@ -16,17 +26,41 @@ where
     if n != nn {
         panic!("matrix dimensions differ. columns: {} != {}", n, nn);
     }
+    let mut report = DiffReport::new(tol);
     for i in 0..m {
         for j in 0..n {
-            let diff = f64::abs(a.get(i, j) - b.at(i, j));
-            if diff > tol {
-                panic!(
-                    "matrices are not approximately equal. @ ({},{}) diff = {:?}",
-                    i, j, diff
-                );
-            }
+            report.add((i, j), a.get(i, j), b.at(i, j));
         }
     }
+    if report.count > 0 {
+        let (wi, wj) = report.worst;
+        let mut buffer = report.summary();
+        let rows = wi.saturating_sub(REPORT_NEIGHBOURS)..usize::min(wi + REPORT_NEIGHBOURS + 1, m);
+        let cols = wj.saturating_sub(REPORT_NEIGHBOURS)..usize::min(wj + REPORT_NEIGHBOURS + 1, n);
+        buffer.push_str(&format!(
+            "rows {}..{} and columns {}..{} of (left | right):\n",
+            rows.start, rows.end, cols.start, cols.end
+        ));
+        for i in rows {
+            let left: Vec<String> = cols.clone().map(|j| format!("{:>12.5e}", a.get(i, j))).collect();
+            let right: Vec<String> = cols
+                .clone()
+                .map(|j| {
+                    let mark = if report.exceeds(a.get(i, j), b.at(i, j)) {
+                        "*"
+                    } else {
+                        " "
+                    };
+                    format!("{:>12.5e}{}", b.at(i, j), mark)
+                })
+                .collect();
+            buffer.push_str(&format!("{} | {}\n", left.join(" "), right.join("")));
+        }
+        panic!(
+            "matrices are not approximately equal. @ ({},{}) diff = {:?}\n{}",
+            wi, wj, report.max_abs, buffer
+        );
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -52,7 +86,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "matrices are not approximately equal. @ (0,0) diff = 1.5")]
+    #[should_panic(expected = "matrices are not approximately equal. @ (1,1) diff = 2.0")]
     fn mat_approx_eq_works_3() {
         let a = Matrix::from(&[[1.0, 2.0], [3.0, 4.0]]);
         let b = &[[2.5, 1.0], [1.5, 2.0]];
@@ -73,4 +107,29 @@ mod tests {
         let b = &[[0.0], [1e-15]];
         mat_approx_eq(&a, b, 1e-15);
     }
+
+    #[test]
+    #[should_panic(expected = "max abs diff = 2.0, max rel diff = 0.6, 4 of 4 entries exceed tol = 1e-15")]
+    fn mat_approx_eq_reports_max_differences() {
+        let a = Matrix::from(&[[1.0, 2.0], [3.0, 4.0]]);
+        let b = &[[2.5, 1.0], [1.5, 2.0]];
+        mat_approx_eq(&a, b, 1e-15);
+    }
+
+    #[test]
+    fn mat_approx_eq_reports_region() {
+        let a = Matrix::new(10, 10);
+        let mut b = vec![vec![0.0; 10]; 10];
+        b[5][0] = 1.0;
+        let err = std::panic::catch_unwind(|| mat_approx_eq(&a, &b, 1e-15)).unwrap_err();
+        let message = err.downcast_ref::<String>().unwrap();
+        let lines: Vec<_> = message.lines().collect();
+        assert_eq!(lines.len(), 8);
+        assert_eq!(lines[0], "matrices are not approximately equal. @ (5,0) diff = 1.0");
+        assert_eq!(lines[2], "rows 3..8 and columns 0..3 of (left | right):");
+        assert_eq!(
+            lines[5],
+            "   0.00000e0    0.00000e0    0.00000e0 |    1.00000e0*   0.00000e0    0.00000e0 "
+        );
+    }
 }
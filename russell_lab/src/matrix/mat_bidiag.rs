@@ -0,0 +1,256 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::Error;
+use russell_openblas::{dgebrd, dgebrd_lwork, dorgbr, dorgbr_lwork, to_i32};
+
+/// Reduces a matrix to bidiagonal form by orthogonal transformations
+///
+/// Finds `q`, `b`, and `pt` such that:
+///
+/// ```text
+///   a  =   q   ⋅   b   ⋅   pᵀ
+/// (m,n)  (m,m)   (m,n)   (n,n)
+/// ```
+///
+/// where `q` and `p` are orthogonal matrices and `b` is a bidiagonal matrix, defined by the diagonal `d`
+/// and the off-diagonal `e`. If m ≥ n, `b` is upper bidiagonal (`e` is on the superdiagonal);
+/// otherwise, `b` is lower bidiagonal (`e` is on the subdiagonal). The singular values of `b` are
+/// the same as the singular values of `a`; thus, this reduction is the first step of SVD algorithms.
+///
+/// # Output
+///
+/// * `q` -- (m,m) orthogonal matrix
+/// * `d` -- (k) the diagonal of `b`, with k = min(m,n)
+/// * `e` -- (k-1) the off-diagonal of `b` (or an empty vector if k = 0)
+/// * `pt` -- (n,n) orthogonal matrix with the transpose of p
+///
+/// # Input
+///
+/// * `a` -- (m,n) matrix
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_approx_eq, mat_bidiag, Matrix, StrError, Vector};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     // set matrix
+///     let a = Matrix::from(&[
+///         [1.0, 2.0, 3.0],
+///         [4.0, 5.0, 6.0],
+///         [7.0, 8.0, 9.0],
+///         [1.0, 0.0, 1.0],
+///     ]);
+///
+///     // reduce to upper bidiagonal form
+///     let (m, n) = a.dims();
+///     let mut q = Matrix::new(m, m);
+///     let mut d = Vector::new(n);
+///     let mut e = Vector::new(n - 1);
+///     let mut pt = Matrix::new(n, n);
+///     mat_bidiag(&mut q, &mut d, &mut e, &mut pt, &a)?;
+///
+///     // check that a = q⋅b⋅pᵀ
+///     let mut qbpt = Matrix::new(m, n);
+///     for i in 0..m {
+///         for j in 0..n {
+///             for k in 0..n {
+///                 let mut qb = q.get(i, k) * d[k];
+///                 if k > 0 {
+///                     qb += q.get(i, k - 1) * e[k - 1];
+///                 }
+///                 qbpt.add(i, j, qb * pt.get(k, j));
+///             }
+///         }
+///     }
+///     mat_approx_eq(&qbpt, &a, 1e-13);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+pub fn mat_bidiag(q: &mut Matrix, d: &mut Vector, e: &mut Vector, pt: &mut Matrix, a: &Matrix) -> Result<(), Error> {
+    // check
+    let (m, n) = a.dims();
+    let k = usize::min(m, n);
+    if q.nrow() != m || q.ncol() != m || pt.nrow() != n || pt.ncol() != n {
+        return Err(Error::BadDimensions("matrices are incompatible"));
+    }
+    if d.dim() != k || e.dim() != usize::max(1, k) - 1 {
+        return Err(Error::BadDimensions("vectors are incompatible"));
+    }
+    if k == 0 {
+        q.fill(0.0);
+        pt.fill(0.0);
+        for i in 0..m {
+            q.set(i, i, 1.0);
+        }
+        for i in 0..n {
+            pt.set(i, i, 1.0);
+        }
+        return Ok(());
+    }
+
+    // reduce a copy of a
+    let (m_i32, n_i32) = (to_i32(m), to_i32(n));
+    let mut br = a.as_data().clone();
+    let mut tauq = vec![0.0; k];
+    let mut taup = vec![0.0; k];
+    let mut work = vec![0.0; dgebrd_lwork(m_i32, n_i32)?];
+    dgebrd(
+        m_i32,
+        n_i32,
+        &mut br,
+        d.as_mut_data(),
+        e.as_mut_data(),
+        &mut tauq,
+        &mut taup,
+        &mut work,
+    )?;
+
+    // generate q from the reflectors (in the first k columns)
+    let q_data = q.as_mut_data();
+    q_data.fill(0.0);
+    q_data[..(m * k)].copy_from_slice(&br[..(m * k)]);
+    let mut work = vec![0.0; dorgbr_lwork(true, m_i32, m_i32, n_i32)?];
+    dorgbr(true, m_i32, m_i32, n_i32, q_data, &tauq, &mut work)?;
+
+    // generate pᵀ from the reflectors (in the first k rows)
+    pt.fill(0.0);
+    for i in 0..k {
+        for j in 0..n {
+            pt.set(i, j, br[i + j * m]);
+        }
+    }
+    let mut work = vec![0.0; dorgbr_lwork(false, n_i32, n_i32, m_i32)?];
+    dorgbr(false, n_i32, n_i32, m_i32, pt.as_mut_data(), &taup, &mut work)?;
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(dead_code, unused_imports))]
+mod tests {
+    use super::mat_bidiag;
+    use crate::{check_orthogonal, mat_approx_eq, mat_mat_mul, mat_svd, Error, Matrix, SvdMode, Vector};
+    use russell_chk::vec_approx_eq;
+
+    // computes the bidiagonal form and checks that a = q⋅b⋅pᵀ; returns b
+    fn check_bidiag(a: &Matrix, tol: f64) -> Matrix {
+        let (m, n) = a.dims();
+        let k = usize::min(m, n);
+        let mut q = Matrix::new(m, m);
+        let mut d = Vector::new(k);
+        let mut e = Vector::new(usize::max(1, k) - 1);
+        let mut pt = Matrix::new(n, n);
+        mat_bidiag(&mut q, &mut d, &mut e, &mut pt, a).unwrap();
+        let mut b = Matrix::new(m, n);
+        for i in 0..k {
+            b.set(i, i, d[i]);
+            if i + 1 < k {
+                if m >= n {
+                    b.set(i, i + 1, e[i]);
+                } else {
+                    b.set(i + 1, i, e[i]);
+                }
+            }
+        }
+        let mut qb = Matrix::new(m, n);
+        let mut qbpt = Matrix::new(m, n);
+        mat_mat_mul(&mut qb, 1.0, &q, &b).unwrap();
+        mat_mat_mul(&mut qbpt, 1.0, &qb, &pt).unwrap();
+        mat_approx_eq(&qbpt, a, tol);
+        check_orthogonal(&q, tol);
+        check_orthogonal(&pt, tol);
+        b
+    }
+
+    // returns the singular values
+    fn singular_values(a: &Matrix) -> Vec<f64> {
+        let (m, n) = a.dims();
+        let mut s = Vector::new(usize::min(m, n));
+        let mut u = Matrix::new(m, m);
+        let mut vt = Matrix::new(n, n);
+        let mut acopy = a.clone();
+        mat_svd(&mut s, &mut u, &mut vt, &mut acopy, SvdMode::ValuesOnly).unwrap();
+        s.as_data().clone()
+    }
+
+    #[test]
+    fn mat_bidiag_fails_on_wrong_dims() {
+        let a = Matrix::new(3, 2);
+        let mut q = Matrix::new(3, 3);
+        let mut d = Vector::new(2);
+        let mut e = Vector::new(1);
+        let mut pt = Matrix::new(2, 2);
+        let mut q_wrong = Matrix::new(3, 2);
+        let mut pt_wrong = Matrix::new(3, 3);
+        let mut d_wrong = Vector::new(3);
+        let mut e_wrong = Vector::new(2);
+        assert_eq!(
+            mat_bidiag(&mut q_wrong, &mut d, &mut e, &mut pt, &a),
+            Err(Error::BadDimensions("matrices are incompatible"))
+        );
+        assert_eq!(
+            mat_bidiag(&mut q, &mut d, &mut e, &mut pt_wrong, &a),
+            Err(Error::BadDimensions("matrices are incompatible"))
+        );
+        assert_eq!(
+            mat_bidiag(&mut q, &mut d_wrong, &mut e, &mut pt, &a),
+            Err(Error::BadDimensions("vectors are incompatible"))
+        );
+        assert_eq!(
+            mat_bidiag(&mut q, &mut d, &mut e_wrong, &mut pt, &a),
+            Err(Error::BadDimensions("vectors are incompatible"))
+        );
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn mat_bidiag_works() {
+        // tall matrix: upper bidiagonal
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0, 2.0,  3.0],
+            [4.0, 5.0,  6.0],
+            [7.0, 8.0, 10.0],
+            [1.0, 0.0,  1.0],
+        ]);
+        let b = check_bidiag(&a, 1e-13);
+        assert_eq!(b.get(1, 0), 0.0);
+        vec_approx_eq(&singular_values(&b), &singular_values(&a), 1e-13);
+
+        // wide matrix: lower bidiagonal
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0, 4.0,  7.0, 1.0],
+            [2.0, 5.0,  8.0, 0.0],
+            [3.0, 6.0, 10.0, 1.0],
+        ]);
+        let b = check_bidiag(&a, 1e-13);
+        assert_eq!(b.get(0, 1), 0.0);
+        vec_approx_eq(&singular_values(&b), &singular_values(&a), 1e-13);
+
+        // square and single-column matrices
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [ 2.0, -1.0,  0.0],
+            [-1.0,  2.0, -1.0],
+            [ 0.0, -1.0,  2.0],
+        ]);
+        check_bidiag(&a, 1e-14);
+        check_bidiag(&Matrix::from(&[[3.0], [4.0]]), 1e-15);
+    }
+
+    #[test]
+    fn mat_bidiag_handles_empty_matrix() {
+        let a = Matrix::new(2, 0);
+        let mut q = Matrix::new(2, 2);
+        let mut d = Vector::new(0);
+        let mut e = Vector::new(0);
+        let mut pt = Matrix::new(0, 0);
+        mat_bidiag(&mut q, &mut d, &mut e, &mut pt, &a).unwrap();
+        mat_approx_eq(&q, &Matrix::identity(2), 1e-15);
+    }
+}
@@ -1,5 +1,5 @@
 use super::Matrix;
-use crate::StrError;
+use crate::Error;
 use russell_openblas::{dpotrf, to_i32};
 
 /// Performs the Cholesky factorization of a symmetric positive-definite matrix
@@ -17,6 +17,7 @@ use russell_openblas::{dpotrf, to_i32};
 /// ```
 /// use russell_lab::{mat_cholesky, Matrix, StrError};
 ///
+/// # #[cfg(not(feature = "pure_rust"))]
 /// fn main() -> Result<(), StrError> {
 ///     // set matrix
 ///     let a = Matrix::from(&[
@@ -55,15 +56,16 @@ use russell_openblas::{dpotrf, to_i32};
 ///     assert_eq!(format!("{}", l), l_correct);
 ///     Ok(())
 /// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
 /// ```
-pub fn mat_cholesky(l: &mut Matrix, a: &Matrix) -> Result<(), StrError> {
+pub fn mat_cholesky(l: &mut Matrix, a: &Matrix) -> Result<(), Error> {
     // check
     let (m, n) = a.dims();
     if m != n {
-        return Err("matrix must be square");
+        return Err(Error::BadDimensions("matrix must be square"));
     }
     if l.nrow() != m || l.ncol() != n {
-        return Err("matrices are incompatible");
+        return Err(Error::BadDimensions("matrices are incompatible"));
     }
 
     // copy lower+diagonal part and set upper part to zero
@@ -88,9 +90,10 @@ pub fn mat_cholesky(l: &mut Matrix, a: &Matrix) -> Result<(), StrError> {
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(unused_imports))]
 mod tests {
     use super::{mat_cholesky, Matrix};
-    use crate::mat_approx_eq;
+    use crate::{mat_approx_eq, Error};
 
     #[test]
     fn mat_cholesky_fails_on_wrong_dims() {
@@ -99,11 +102,21 @@ mod tests {
         let mut l = Matrix::new(2, 2);
         let mut l_wrong1 = Matrix::new(3, 2);
         let mut l_wrong2 = Matrix::new(2, 3);
-        assert_eq!(mat_cholesky(&mut l, &a_wrong), Err("matrix must be square"));
-        assert_eq!(mat_cholesky(&mut l_wrong1, &a), Err("matrices are incompatible"));
-        assert_eq!(mat_cholesky(&mut l_wrong2, &a), Err("matrices are incompatible"));
+        assert_eq!(
+            mat_cholesky(&mut l, &a_wrong),
+            Err(Error::BadDimensions("matrix must be square"))
+        );
+        assert_eq!(
+            mat_cholesky(&mut l_wrong1, &a),
+            Err(Error::BadDimensions("matrices are incompatible"))
+        );
+        assert_eq!(
+            mat_cholesky(&mut l_wrong2, &a),
+            Err(Error::BadDimensions("matrices are incompatible"))
+        );
     }
 
+    #[cfg(not(feature = "pure_rust"))]
     #[test]
     fn mat_cholesky_3x3_works() {
         #[rustfmt::skip]
@@ -133,6 +146,7 @@ mod tests {
         mat_approx_eq(&l_lt, &a, 1e-15);
     }
 
+    #[cfg(not(feature = "pure_rust"))]
     #[test]
     fn mat_cholesky_5x5_works() {
         #[rustfmt::skip]
@@ -166,4 +180,17 @@ mod tests {
         }
         mat_approx_eq(&l_lt, &a, 1e-15);
     }
+
+    #[cfg(feature = "pure_rust")]
+    #[test]
+    fn mat_cholesky_returns_backend_error() {
+        let a = Matrix::from(&[[4.0, 2.0], [2.0, 3.0]]);
+        let mut l = Matrix::new(2, 2);
+        assert_eq!(
+            mat_cholesky(&mut l, &a),
+            Err(Error::Backend(
+                "LAPACK dpotrf is not available with the pure_rust backend"
+            ))
+        );
+    }
 }
@@ -36,7 +36,7 @@ pub fn mat_copy(b: &mut Matrix, a: &Matrix) -> Result<(), StrError> {
     if a.nrow() != m || a.ncol() != n {
         return Err("matrices are incompatible");
     }
-    let n_i32: i32 = to_i32(m * n);
+    let n_i32 = to_i32(m * n);
     dcopy(n_i32, a.as_data(), 1, b.as_mut_data(), 1);
     Ok(())
 }
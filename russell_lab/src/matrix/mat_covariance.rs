@@ -0,0 +1,226 @@
+use super::{mat_update_sym, Matrix};
+use crate::StrError;
+
+/// Computes the covariance matrix of a data matrix
+///
+/// The rows of `data` are the observations and the columns are the variables. Thus:
+///
+/// ```text
+/// covᵢⱼ := Σ_k (dataₖᵢ - meanᵢ) ⋅ (dataₖⱼ - meanⱼ) / (m - 1)     (sample = true)
+///
+/// covᵢⱼ := Σ_k (dataₖᵢ - meanᵢ) ⋅ (dataₖⱼ - meanⱼ) / m           (sample = false)
+/// ```
+///
+/// where `m` is the number of observations and `meanᵢ` is the mean of the i-th column.
+/// The centered data is multiplied by its transpose with the symmetric rank-k update (dsyrk).
+///
+/// # Output
+///
+/// * `cov` -- (n,n) the covariance matrix (symmetric)
+///
+/// # Input
+///
+/// * `data` -- (m,n) matrix with m observations (rows) of n variables (columns)
+/// * `sample` -- uses the sample (unbiased) normalization `1/(m-1)`; otherwise,
+///   uses the population normalization `1/m`
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_covariance, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let data = Matrix::from(&[
+///         [1.0, 2.0],
+///         [2.0, 1.0],
+///         [3.0, 6.0],
+///     ]);
+///     let mut cov = Matrix::new(2, 2);
+///     mat_covariance(&mut cov, &data, true)?;
+///     let correct = "┌     ┐\n\
+///                    │ 1 2 │\n\
+///                    │ 2 7 │\n\
+///                    └     ┘";
+///     assert_eq!(format!("{}", cov), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_covariance(cov: &mut Matrix, data: &Matrix, sample: bool) -> Result<(), StrError> {
+    // check
+    let (m, n) = data.dims();
+    if cov.nrow() != n || cov.ncol() != n {
+        return Err("matrices are incompatible");
+    }
+    if m == 0 {
+        return Err("data must have at least one observation");
+    }
+    if sample && m < 2 {
+        return Err("data must have at least two observations for the sample covariance");
+    }
+
+    // centered data
+    let mut centered = data.clone();
+    for j in 0..n {
+        let mut mean = 0.0;
+        for i in 0..m {
+            mean += data.get(i, j);
+        }
+        mean /= m as f64;
+        for i in 0..m {
+            centered.add(i, j, -mean);
+        }
+    }
+
+    // cov := centeredᵀ ⋅ centered / den
+    let den = if sample { (m - 1) as f64 } else { m as f64 };
+    mat_update_sym(cov, 1.0 / den, &centered, true, 0.0)
+}
+
+/// Computes the (Pearson) correlation matrix of a data matrix
+///
+/// The rows of `data` are the observations and the columns are the variables. Thus:
+///
+/// ```text
+/// corrᵢⱼ := covᵢⱼ / sqrt(covᵢᵢ ⋅ covⱼⱼ)
+/// ```
+///
+/// where `cov` is the covariance matrix (see [mat_covariance]). The normalization of the
+/// covariance cancels out; thus, the sample and population definitions yield the same result.
+///
+/// # Output
+///
+/// * `corr` -- (n,n) the correlation matrix (symmetric, with unit diagonal)
+///
+/// # Input
+///
+/// * `data` -- (m,n) matrix with m observations (rows) of n variables (columns)
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_approx_eq, mat_correlation, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let data = Matrix::from(&[
+///         [1.0,  2.0, 3.0],
+///         [2.0,  4.0, 1.0],
+///         [3.0,  6.0, 2.0],
+///     ]);
+///     let mut corr = Matrix::new(3, 3);
+///     mat_correlation(&mut corr, &data)?;
+///     let r = -0.5;
+///     mat_approx_eq(&corr, &[[1.0, 1.0, r], [1.0, 1.0, r], [r, r, 1.0]], 1e-15);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_correlation(corr: &mut Matrix, data: &Matrix) -> Result<(), StrError> {
+    mat_covariance(corr, data, false)?;
+    let n = corr.nrow();
+    let mut std = vec![0.0; n];
+    for (i, s) in std.iter_mut().enumerate() {
+        let var = corr.get(i, i);
+        if var <= 0.0 {
+            return Err("the variance of each variable must be positive");
+        }
+        *s = f64::sqrt(var);
+    }
+    for i in 0..n {
+        for j in 0..n {
+            let value = if i == j {
+                1.0
+            } else {
+                corr.get(i, j) / (std[i] * std[j])
+            };
+            corr.set(i, j, value);
+        }
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_correlation, mat_covariance};
+    use crate::{check_symmetric, mat_approx_eq, Matrix};
+
+    #[test]
+    fn mat_covariance_captures_errors() {
+        let data = Matrix::new(3, 2);
+        let mut cov = Matrix::new(3, 3);
+        assert_eq!(mat_covariance(&mut cov, &data, true), Err("matrices are incompatible"));
+        let mut cov = Matrix::new(2, 2);
+        assert_eq!(
+            mat_covariance(&mut cov, &Matrix::new(0, 2), false),
+            Err("data must have at least one observation")
+        );
+        assert_eq!(
+            mat_covariance(&mut cov, &Matrix::new(1, 2), true),
+            Err("data must have at least two observations for the sample covariance")
+        );
+        assert_eq!(
+            mat_correlation(&mut cov, &Matrix::from(&[[1.0, 2.0], [1.0, 3.0]])),
+            Err("the variance of each variable must be positive")
+        );
+    }
+
+    #[test]
+    fn mat_covariance_works() {
+        #[rustfmt::skip]
+        let data = Matrix::from(&[
+            [ 4.0, 2.0, 0.60],
+            [ 4.2, 2.1, 0.59],
+            [ 3.9, 2.0, 0.58],
+            [ 4.3, 2.1, 0.62],
+            [ 4.1, 2.2, 0.63],
+        ]);
+        let mut cov = Matrix::new(3, 3);
+
+        // sample
+        mat_covariance(&mut cov, &data, true).unwrap();
+        #[rustfmt::skip]
+        let correct = &[
+            [0.02500, 0.00750, 0.00175],
+            [0.00750, 0.00700, 0.00135],
+            [0.00175, 0.00135, 0.00043],
+        ];
+        mat_approx_eq(&cov, correct, 1e-15);
+        check_symmetric(&cov, 0.0);
+
+        // population
+        let mut cov_pop = Matrix::new(3, 3);
+        mat_covariance(&mut cov_pop, &data, false).unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!(f64::abs(cov_pop.get(i, j) - cov.get(i, j) * 4.0 / 5.0) < 1e-15);
+            }
+        }
+
+        // single observation (population)
+        let mut cov = Matrix::new(2, 2);
+        mat_covariance(&mut cov, &Matrix::from(&[[1.0, 2.0]]), false).unwrap();
+        mat_approx_eq(&cov, &[[0.0, 0.0], [0.0, 0.0]], 1e-15);
+    }
+
+    #[test]
+    fn mat_correlation_works() {
+        #[rustfmt::skip]
+        let data = Matrix::from(&[
+            [1.0, 10.0, -1.0],
+            [2.0,  8.0, -2.0],
+            [3.0,  6.0, -2.0],
+            [4.0,  4.0, -5.0],
+        ]);
+        let mut corr = Matrix::new(3, 3);
+        mat_correlation(&mut corr, &data).unwrap();
+        // the first and second variables are perfectly anti-correlated
+        let r = -2.0 / f64::sqrt(5.0);
+        #[rustfmt::skip]
+        let correct = &[
+            [1.0, -1.0,  r ],
+            [-1.0, 1.0, -r ],
+            [ r,  -r,   1.0],
+        ];
+        mat_approx_eq(&corr, correct, 1e-15);
+    }
+}
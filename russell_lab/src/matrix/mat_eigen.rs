@@ -1,5 +1,6 @@
-use super::Matrix;
-use crate::{StrError, Vector};
+use super::{ComplexMatrix, Matrix};
+use crate::{ComplexVector, Error, Vector, Workspace};
+use num_complex::Complex64;
 use russell_openblas::{dgeev, dgeev_data, dgeev_data_lr, to_i32};
 
 /// Performs the eigen-decomposition of a square matrix
@@ -54,6 +55,7 @@ use russell_openblas::{dgeev, dgeev_data, dgeev_data_lr, to_i32};
 /// use russell_chk::approx_eq;
 /// use russell_lab::{mat_add, mat_eigen, mat_mat_mul, mat_norm, Matrix, Norm, StrError, Vector};
 ///
+/// # #[cfg(not(feature = "pure_rust"))]
 /// fn main() -> Result<(), StrError> {
 ///     // set matrix
 ///     let data = [[2.0, 0.0, 0.0], [0.0, 3.0, 4.0], [0.0, 4.0, 9.0]];
@@ -100,6 +102,7 @@ use russell_openblas::{dgeev, dgeev_data, dgeev_data_lr, to_i32};
 ///     approx_eq(mat_norm(&err, Norm::Max), 0.0, 1e-15);
 ///     Ok(())
 /// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
 /// ```
 pub fn mat_eigen(
     l_real: &mut Vector,
@@ -107,19 +110,35 @@ pub fn mat_eigen(
     v_real: &mut Matrix,
     v_imag: &mut Matrix,
     a: &mut Matrix,
-) -> Result<(), StrError> {
+) -> Result<(), Error> {
+    mat_eigen_with_workspace(l_real, l_imag, v_real, v_imag, a, &mut Workspace::new())
+}
+
+/// Performs the eigen-decomposition of a square matrix reusing a workspace
+///
+/// This function is the same as [mat_eigen]; however, the auxiliary (m,m) array with the
+/// eigenvectors computed by LAPACK is stored in the given [Workspace]. Thus, no allocation
+/// happens if the workspace is large enough.
+pub fn mat_eigen_with_workspace(
+    l_real: &mut Vector,
+    l_imag: &mut Vector,
+    v_real: &mut Matrix,
+    v_imag: &mut Matrix,
+    a: &mut Matrix,
+    workspace: &mut Workspace,
+) -> Result<(), Error> {
     let (m, n) = a.dims();
     if m != n {
-        return Err("matrix must be square");
+        return Err(Error::BadDimensions("matrix must be square"));
     }
     if l_real.dim() != m || l_imag.dim() != m {
-        return Err("vectors are incompatible");
+        return Err(Error::BadDimensions("vectors are incompatible"));
     }
     if v_real.nrow() != m || v_real.ncol() != m || v_imag.nrow() != m || v_imag.ncol() != m {
-        return Err("matrices are incompatible");
+        return Err(Error::BadDimensions("matrices are incompatible"));
     }
     let m_i32 = to_i32(m);
-    let mut v = vec![0.0; m * m];
+    let v = workspace.real(m * m);
     let mut empty: Vec<f64> = Vec::new();
     dgeev(
         false,
@@ -129,9 +148,9 @@ pub fn mat_eigen(
         l_real.as_mut_data(),
         l_imag.as_mut_data(),
         &mut empty,
-        &mut v,
+        v,
     )?;
-    dgeev_data(v_real.as_mut_data(), v_imag.as_mut_data(), l_imag.as_data(), &v)?;
+    dgeev_data(v_real.as_mut_data(), v_imag.as_mut_data(), l_imag.as_data(), v)?;
     Ok(())
 }
 
@@ -182,6 +201,7 @@ pub fn mat_eigen(
 /// };
 /// use russell_lab::{mat_eigen_lr, Matrix, StrError, Vector};
 ///
+/// # #[cfg(not(feature = "pure_rust"))]
 /// fn main() -> Result<(), StrError> {
 ///     // set matrix
 ///     let data = [[0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [1.0, 0.0, 0.0]];
@@ -245,6 +265,7 @@ pub fn mat_eigen(
 ///     approx_eq(complex_mat_norm(&err, Norm::Max), 0.0, 1e-15);
 ///     Ok(())
 /// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
 /// ```
 pub fn mat_eigen_lr(
     l_real: &mut Vector,
@@ -254,13 +275,13 @@ pub fn mat_eigen_lr(
     v_real: &mut Matrix,
     v_imag: &mut Matrix,
     a: &mut Matrix,
-) -> Result<(), StrError> {
+) -> Result<(), Error> {
     let (m, n) = a.dims();
     if m != n {
-        return Err("matrix must be square");
+        return Err(Error::BadDimensions("matrix must be square"));
     }
     if l_real.dim() != m || l_imag.dim() != m {
-        return Err("vectors are incompatible");
+        return Err(Error::BadDimensions("vectors are incompatible"));
     }
     if u_real.nrow() != m
         || u_real.ncol() != m
@@ -271,7 +292,7 @@ pub fn mat_eigen_lr(
         || v_imag.nrow() != m
         || v_imag.ncol() != m
     {
-        return Err("matrices are incompatible");
+        return Err(Error::BadDimensions("matrices are incompatible"));
     }
     let m_i32 = to_i32(m);
     let mut u = vec![0.0; m * m];
@@ -298,15 +319,81 @@ pub fn mat_eigen_lr(
     Ok(())
 }
 
+/// Performs the eigen-decomposition of a square matrix (complex output)
+///
+/// Computes the eigenvalues `l` and right eigenvectors `v`, such that:
+///
+/// ```text
+/// a ⋅ vj = lj ⋅ vj
+/// ```
+///
+/// This function is equivalent to [mat_eigen], but the results are stored in
+/// complex arrays instead of pairs of real and imaginary parts.
+///
+/// # Output
+///
+/// * `l` -- (m) eigenvalues
+/// * `v` -- (m,m) **right** eigenvectors (as columns)
+///
+/// # Input
+///
+/// * `a` -- (m,m) general matrix [will be modified]
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use russell_chk::complex_vec_approx_eq;
+/// use russell_lab::{mat_eigen_complex, ComplexMatrix, ComplexVector, Matrix, StrError};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     // rotation by 90 degrees
+///     let mut a = Matrix::from(&[[0.0, -1.0], [1.0, 0.0]]);
+///     let mut l = ComplexVector::new(2);
+///     let mut v = ComplexMatrix::new(2, 2);
+///     mat_eigen_complex(&mut l, &mut v, &mut a)?;
+///     let correct = &[Complex64::new(0.0, 1.0), Complex64::new(0.0, -1.0)];
+///     complex_vec_approx_eq(l.as_data(), correct, 1e-15);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+pub fn mat_eigen_complex(l: &mut ComplexVector, v: &mut ComplexMatrix, a: &mut Matrix) -> Result<(), Error> {
+    let (m, n) = a.dims();
+    if m != n {
+        return Err(Error::BadDimensions("matrix must be square"));
+    }
+    if l.dim() != m {
+        return Err(Error::BadDimensions("vectors are incompatible"));
+    }
+    if v.nrow() != m || v.ncol() != m {
+        return Err(Error::BadDimensions("matrices are incompatible"));
+    }
+    let mut l_real = Vector::new(m);
+    let mut l_imag = Vector::new(m);
+    let mut v_real = Matrix::new(m, m);
+    let mut v_imag = Matrix::new(m, m);
+    mat_eigen(&mut l_real, &mut l_imag, &mut v_real, &mut v_imag, a)?;
+    for i in 0..m {
+        l[i] = Complex64::new(l_real[i], l_imag[i]);
+        for j in 0..m {
+            v.set(i, j, Complex64::new(v_real.get(i, j), v_imag.get(i, j)));
+        }
+    }
+    Ok(())
+}
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(unused_imports))]
 mod tests {
-    use super::{mat_eigen, mat_eigen_lr};
+    use super::{mat_eigen, mat_eigen_complex, mat_eigen_lr, mat_eigen_with_workspace};
     use crate::mat_approx_eq;
     use crate::testing::{check_eigen_general, check_eigen_real};
-    use crate::{Matrix, Vector};
-    use russell_chk::vec_approx_eq;
+    use crate::{ComplexMatrix, ComplexVector, Error, Matrix, Vector, Workspace};
+    use num_complex::Complex64;
+    use russell_chk::{complex_vec_approx_eq, vec_approx_eq};
 
     #[test]
     fn mat_eigen_fails_on_non_square() {
@@ -318,7 +405,7 @@ mod tests {
         let mut v_imag = Matrix::new(m, m);
         assert_eq!(
             mat_eigen(&mut l_real, &mut l_imag, &mut v_real, &mut v_imag, &mut a),
-            Err("matrix must be square")
+            Err(Error::BadDimensions("matrix must be square"))
         );
     }
 
@@ -336,19 +423,19 @@ mod tests {
         let mut v_imag_wrong = Matrix::new(m, m + 1);
         assert_eq!(
             mat_eigen(&mut l_real_wrong, &mut l_imag, &mut v_real, &mut v_imag, &mut a),
-            Err("vectors are incompatible")
+            Err(Error::BadDimensions("vectors are incompatible"))
         );
         assert_eq!(
             mat_eigen(&mut l_real, &mut l_imag_wrong, &mut v_real, &mut v_imag, &mut a),
-            Err("vectors are incompatible")
+            Err(Error::BadDimensions("vectors are incompatible"))
         );
         assert_eq!(
             mat_eigen(&mut l_real, &mut l_imag, &mut v_real_wrong, &mut v_imag, &mut a),
-            Err("matrices are incompatible")
+            Err(Error::BadDimensions("matrices are incompatible"))
         );
         assert_eq!(
             mat_eigen(&mut l_real, &mut l_imag, &mut v_real, &mut v_imag_wrong, &mut a),
-            Err("matrices are incompatible")
+            Err(Error::BadDimensions("matrices are incompatible"))
         );
     }
 
@@ -372,7 +459,7 @@ mod tests {
                 &mut v_imag,
                 &mut a,
             ),
-            Err("matrix must be square"),
+            Err(Error::BadDimensions("matrix must be square")),
         );
     }
 
@@ -402,7 +489,7 @@ mod tests {
                 &mut v_imag,
                 &mut a,
             ),
-            Err("vectors are incompatible"),
+            Err(Error::BadDimensions("vectors are incompatible")),
         );
         assert_eq!(
             mat_eigen_lr(
@@ -414,7 +501,7 @@ mod tests {
                 &mut v_imag,
                 &mut a,
             ),
-            Err("vectors are incompatible"),
+            Err(Error::BadDimensions("vectors are incompatible")),
         );
         assert_eq!(
             mat_eigen_lr(
@@ -426,7 +513,7 @@ mod tests {
                 &mut v_imag,
                 &mut a,
             ),
-            Err("matrices are incompatible"),
+            Err(Error::BadDimensions("matrices are incompatible")),
         );
         assert_eq!(
             mat_eigen_lr(
@@ -438,7 +525,7 @@ mod tests {
                 &mut v_imag,
                 &mut a,
             ),
-            Err("matrices are incompatible"),
+            Err(Error::BadDimensions("matrices are incompatible")),
         );
         assert_eq!(
             mat_eigen_lr(
@@ -450,7 +537,7 @@ mod tests {
                 &mut v_imag,
                 &mut a,
             ),
-            Err("matrices are incompatible"),
+            Err(Error::BadDimensions("matrices are incompatible")),
         );
         assert_eq!(
             mat_eigen_lr(
@@ -462,10 +549,11 @@ mod tests {
                 &mut v_imag_wrong,
                 &mut a,
             ),
-            Err("matrices are incompatible"),
+            Err(Error::BadDimensions("matrices are incompatible")),
         );
     }
 
+    #[cfg(not(feature = "pure_rust"))]
     #[test]
     fn mat_eigen_works() {
         #[rustfmt::skip]
@@ -489,6 +577,33 @@ mod tests {
         check_eigen_general(&data, &v_real, &l_real, &v_imag, &l_imag, 1e-15);
     }
 
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn mat_eigen_with_workspace_works() {
+        let mut workspace = Workspace::new();
+        for m in [3, 2] {
+            let data = Matrix::diagonal(&vec![2.0; m]);
+            let mut a = data.clone();
+            let mut l_real = Vector::new(m);
+            let mut l_imag = Vector::new(m);
+            let mut v_real = Matrix::new(m, m);
+            let mut v_imag = Matrix::new(m, m);
+            mat_eigen_with_workspace(
+                &mut l_real,
+                &mut l_imag,
+                &mut v_real,
+                &mut v_imag,
+                &mut a,
+                &mut workspace,
+            )
+            .unwrap();
+            vec_approx_eq(l_real.as_data(), &vec![2.0; m], 1e-15);
+            vec_approx_eq(l_imag.as_data(), &vec![0.0; m], 1e-15);
+            mat_approx_eq(&v_real, &Matrix::identity(m), 1e-15);
+        }
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
     #[test]
     fn mat_eigen_repeated_eval_works() {
         // rep: repeated eigenvalues
@@ -524,6 +639,7 @@ mod tests {
         check_eigen_real(&data, &v_real, &l_real, 1e-15);
     }
 
+    #[cfg(not(feature = "pure_rust"))]
     #[test]
     fn mat_eigen_lr_works() {
         #[rustfmt::skip]
@@ -557,4 +673,74 @@ mod tests {
         vec_approx_eq(l_imag.as_data(), l_imag_correct, 1e-15);
         check_eigen_general(&data, &v_real, &l_real, &v_imag, &l_imag, 1e-15);
     }
+
+    #[test]
+    fn mat_eigen_complex_captures_errors() {
+        let mut a = Matrix::new(3, 4);
+        let mut l = ComplexVector::new(3);
+        let mut v = ComplexMatrix::new(3, 3);
+        assert_eq!(
+            mat_eigen_complex(&mut l, &mut v, &mut a),
+            Err(Error::BadDimensions("matrix must be square"))
+        );
+        let mut a = Matrix::new(2, 2);
+        assert_eq!(
+            mat_eigen_complex(&mut l, &mut v, &mut a),
+            Err(Error::BadDimensions("vectors are incompatible"))
+        );
+        let mut l = ComplexVector::new(2);
+        assert_eq!(
+            mat_eigen_complex(&mut l, &mut v, &mut a),
+            Err(Error::BadDimensions("matrices are incompatible"))
+        );
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn mat_eigen_complex_works() {
+        #[rustfmt::skip]
+        let data = [
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0],
+            [1.0, 0.0, 0.0],
+        ];
+        let mut a = Matrix::from(&data);
+        let mut l = ComplexVector::new(3);
+        let mut v = ComplexMatrix::new(3, 3);
+        mat_eigen_complex(&mut l, &mut v, &mut a).unwrap();
+        let s3 = f64::sqrt(3.0);
+        let l_correct = &[
+            Complex64::new(-0.5, s3 / 2.0),
+            Complex64::new(-0.5, -s3 / 2.0),
+            Complex64::new(1.0, 0.0),
+        ];
+        complex_vec_approx_eq(l.as_data(), l_correct, 1e-15);
+        // check a⋅vj = lj⋅vj
+        for j in 0..3 {
+            for (i, row) in data.iter().enumerate() {
+                let mut a_v = Complex64::new(0.0, 0.0);
+                for (k, aik) in row.iter().enumerate() {
+                    a_v += aik * v.get(k, j);
+                }
+                let diff = a_v - l[j] * v.get(i, j);
+                assert!(diff.norm() < 1e-15);
+            }
+        }
+    }
+
+    #[cfg(feature = "pure_rust")]
+    #[test]
+    fn mat_eigen_returns_backend_error() {
+        let mut a = Matrix::from(&[[2.0, 1.0], [1.0, 2.0]]);
+        let mut l_real = Vector::new(2);
+        let mut l_imag = Vector::new(2);
+        let mut v_real = Matrix::new(2, 2);
+        let mut v_imag = Matrix::new(2, 2);
+        assert_eq!(
+            mat_eigen(&mut l_real, &mut l_imag, &mut v_real, &mut v_imag, &mut a),
+            Err(Error::Backend(
+                "LAPACK dgeev is not available with the pure_rust backend"
+            ))
+        );
+    }
 }
@@ -1,5 +1,5 @@
 use super::Matrix;
-use crate::{StrError, Vector};
+use crate::{Error, Vector};
 use russell_openblas::{dsyev, to_i32};
 
 /// Calculates the eigenvalues and eigenvectors of a symmetric matrix
@@ -20,16 +20,16 @@ use russell_openblas::{dsyev, to_i32};
 ///
 /// * `l` -- the eigenvalues
 /// * `a` -- will hold the eigenvectors as columns
-pub fn mat_eigen_sym(l: &mut Vector, a: &mut Matrix) -> Result<(), StrError> {
+pub fn mat_eigen_sym(l: &mut Vector, a: &mut Matrix) -> Result<(), Error> {
     let (m, n) = a.dims();
     if m != n {
-        return Err("matrix must be square");
+        return Err(Error::BadDimensions("matrix must be square"));
     }
     if m == 0 {
-        return Err("matrix dimension must be ≥ 1");
+        return Err(Error::BadDimensions("matrix dimension must be ≥ 1"));
     }
     if l.dim() != n {
-        return Err("l vector has incompatible dimension");
+        return Err(Error::BadDimensions("l vector has incompatible dimension"));
     }
     let n_i32 = to_i32(n);
     dsyev(true, true, n_i32, a.as_mut_data(), l.as_mut_data())?;
@@ -39,11 +39,12 @@ pub fn mat_eigen_sym(l: &mut Vector, a: &mut Matrix) -> Result<(), StrError> {
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(dead_code, unused_imports))]
 mod tests {
     use super::{mat_eigen_sym, Matrix};
     use crate::math::SQRT_2;
     use crate::testing::check_eigen_real;
-    use crate::{mat_approx_eq, AsArray2D, Vector};
+    use crate::{mat_approx_eq, AsArray2D, Error, Vector};
     use russell_chk::vec_approx_eq;
 
     fn calc_eigen<'a, T>(data: &'a T) -> (Vector, Matrix)
@@ -61,19 +62,23 @@ mod tests {
     fn mat_eigen_sym_handles_errors() {
         let mut a = Matrix::new(0, 1);
         let mut l = Vector::new(0);
-        assert_eq!(mat_eigen_sym(&mut l, &mut a).err(), Some("matrix must be square"));
+        assert_eq!(
+            mat_eigen_sym(&mut l, &mut a).err(),
+            Some(Error::BadDimensions("matrix must be square"))
+        );
         let mut a = Matrix::new(0, 0);
         assert_eq!(
             mat_eigen_sym(&mut l, &mut a).err(),
-            Some("matrix dimension must be ≥ 1")
+            Some(Error::BadDimensions("matrix dimension must be ≥ 1"))
         );
         let mut a = Matrix::new(1, 1);
         assert_eq!(
             mat_eigen_sym(&mut l, &mut a).err(),
-            Some("l vector has incompatible dimension")
+            Some(Error::BadDimensions("l vector has incompatible dimension"))
         );
     }
 
+    #[cfg(not(feature = "pure_rust"))]
     #[test]
     fn mat_eigen_sym_works_0() {
         // 1x1 matrix
@@ -93,6 +98,7 @@ mod tests {
         vec_approx_eq(l.as_data(), &[1.0, 3.0], 1e-15);
     }
 
+    #[cfg(not(feature = "pure_rust"))]
     #[test]
     fn mat_eigen_sym_works_1() {
         // all zero
@@ -149,6 +155,7 @@ mod tests {
         check_eigen_real(data, &v, &l, 1e-15);
     }
 
+    #[cfg(not(feature = "pure_rust"))]
     #[test]
     fn mat_eigen_sym_works_2() {
         #[rustfmt::skip]
@@ -170,6 +177,7 @@ mod tests {
         check_eigen_real(data, &v, &l, 1e-15);
     }
 
+    #[cfg(not(feature = "pure_rust"))]
     #[test]
     fn mat_eigen_sym_works_3() {
         #[rustfmt::skip]
@@ -182,6 +190,7 @@ mod tests {
         check_eigen_real(data, &v, &l, 1e-14);
     }
 
+    #[cfg(not(feature = "pure_rust"))]
     #[test]
     fn mat_eigen_sym_works_4() {
         #[rustfmt::skip]
@@ -196,6 +205,7 @@ mod tests {
         check_eigen_real(data, &v, &l, 1e-14);
     }
 
+    #[cfg(not(feature = "pure_rust"))]
     #[test]
     fn mat_eigen_sym_works_5() {
         let samples = &[
@@ -258,4 +268,17 @@ mod tests {
             test_id += 1;
         }
     }
+
+    #[cfg(feature = "pure_rust")]
+    #[test]
+    fn mat_eigen_sym_returns_backend_error() {
+        let mut a = Matrix::from(&[[2.0, 1.0], [1.0, 2.0]]);
+        let mut l = Vector::new(2);
+        assert_eq!(
+            mat_eigen_sym(&mut l, &mut a),
+            Err(Error::Backend(
+                "LAPACK dsyev is not available with the pure_rust backend"
+            ))
+        );
+    }
 }
@@ -1,5 +1,18 @@
 use super::Matrix;
-use crate::{StrError, Vector};
+use crate::{Error, PerfReport, Vector};
+
+/// Holds the convergence diagnostics of the Jacobi eigen solver
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct JacobiStats {
+    /// Holds the number of iterations
+    pub n_iterations: usize,
+
+    /// Holds the final sum of the absolute value of the upper off-diagonal elements
+    pub off_diagonal_sum: f64,
+
+    /// Indicates whether the off-diagonal sum became smaller than the tolerance or not
+    pub converged: bool,
+}
 
 /// Performs the Jacobi transformation of a symmetric matrix to find its eigenvectors and eigenvalues
 ///
@@ -32,6 +45,7 @@ use crate::{StrError, Vector};
 /// 1. The tolerance is fixed at `1e-15`
 ///    (for the sum of the absolute value of the upper off-diagonal elements)
 /// 2. The maximum number of iterations is fixed at `20`
+///    (use [mat_eigen_sym_jacobi_with_params] to change these values)
 /// 3. For matrices of order greater than about 10, say, the algorithm is slower,
 ///    by a significant constant factor, than the QR method.
 /// 4. This function is recommended for small matrices only, e.g., dim ≤ 32
@@ -42,25 +56,81 @@ use crate::{StrError, Vector};
 ///
 /// * Press WH, Teukolsky SA, Vetterling WT and Flannery BP (2007),
 ///   Numerical Recipes in C: The Art of Scientific Computing, 3rd Edition
-pub fn mat_eigen_sym_jacobi(l: &mut Vector, v: &mut Matrix, a: &mut Matrix) -> Result<usize, StrError> {
-    // constants
-    const TOLERANCE: f64 = 1e-15;
-    const N_MAX_ITERATIONS: usize = 20;
+pub fn mat_eigen_sym_jacobi(l: &mut Vector, v: &mut Matrix, a: &mut Matrix) -> Result<usize, Error> {
+    let stats = mat_eigen_sym_jacobi_with_params(l, v, a, 1e-15, 20, None)?;
+    if !stats.converged {
+        return Err(Error::NotConverged("Jacobi rotation did not converge"));
+    }
+    Ok(stats.n_iterations)
+}
 
+/// Performs the Jacobi transformation of a symmetric matrix with the given tolerance and iteration limit
+///
+/// This function is similar to [mat_eigen_sym_jacobi]; however, the tolerance and maximum number
+/// of iterations are given, and the lack of convergence is not an error. Instead, the convergence
+/// diagnostics are returned, and `l` and `v` hold the approximations of the last iteration. For
+/// instance, near-degenerate matrices may need a larger tolerance than the default `1e-15`.
+///
+/// # Input
+///
+/// * `a` -- matrix to compute eigenvalues (SYMMETRIC and SQUARE)
+/// * `tolerance` -- the tolerance for the sum of the absolute value of the upper off-diagonal elements
+/// * `n_max_iterations` -- the maximum number of iterations
+/// * `perf` -- (optional) collects the elapsed times of each sweep of rotations (section "sweep")
+///
+/// # Output
+///
+/// * `l` -- the eigenvalues (unsorted)
+/// * `v` -- matrix which columns are the eigenvectors (unsorted)
+/// * `a` -- will be modified
+/// * Returns the convergence diagnostics
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_eigen_sym_jacobi_with_params, Matrix, PerfReport, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut a = Matrix::from(&[
+///         [2.0, 1.0],
+///         [1.0, 2.0],
+///     ]);
+///     let mut l = Vector::new(2);
+///     let mut v = Matrix::new(2, 2);
+///     let mut perf = PerfReport::new();
+///     let stats = mat_eigen_sym_jacobi_with_params(&mut l, &mut v, &mut a, 1e-10, 50, Some(&mut perf))?;
+///     assert!(stats.converged);
+///     assert_eq!(stats.n_iterations, 2);
+///     assert_eq!(perf.get("sweep").unwrap().count, 1);
+///     assert_eq!(l.as_data(), &[1.0, 3.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_eigen_sym_jacobi_with_params(
+    l: &mut Vector,
+    v: &mut Matrix,
+    a: &mut Matrix,
+    tolerance: f64,
+    n_max_iterations: usize,
+    mut perf: Option<&mut PerfReport>,
+) -> Result<JacobiStats, Error> {
     // check
+    if tolerance < 0.0 {
+        return Err(Error::InvalidArgument("the tolerance must be non-negative"));
+    }
     let (m, n) = a.dims();
     if m != n {
-        return Err("matrix must be square");
+        return Err(Error::BadDimensions("matrix must be square"));
     }
     if m == 0 {
-        return Err("matrix dimension must be ≥ 1");
+        return Err(Error::BadDimensions("matrix dimension must be ≥ 1"));
     }
     let (mm, nn) = v.dims();
     if mm != m || nn != n {
-        return Err("v and a matrices must have the same dimensions");
+        return Err(Error::BadDimensions("v and a matrices must have the same dimensions"));
     }
     if l.dim() != n {
-        return Err("l vector has incompatible dimension");
+        return Err(Error::BadDimensions("l vector has incompatible dimension"));
     }
 
     // auxiliary arrays
@@ -92,7 +162,7 @@ pub fn mat_eigen_sym_jacobi(l: &mut Vector, v: &mut Matrix, a: &mut Matrix) -> R
     let mut g: f64;
 
     // perform iterations
-    for iteration in 0..N_MAX_ITERATIONS {
+    for iteration in 0..n_max_iterations {
         // sum magnitude of upper off-diagonal elements
         sm = 0.0;
         for p in 0..(n - 1) {
@@ -102,15 +172,22 @@ pub fn mat_eigen_sym_jacobi(l: &mut Vector, v: &mut Matrix, a: &mut Matrix) -> R
         }
 
         // exit point
-        if sm < TOLERANCE {
-            return Ok(iteration + 1);
+        if sm < tolerance {
+            return Ok(JacobiStats {
+                n_iterations: iteration + 1,
+                off_diagonal_sum: sm,
+                converged: true,
+            });
         }
 
         // rotations
+        if let Some(perf) = perf.as_mut() {
+            perf.start("sweep");
+        }
         for p in 0..(n - 1) {
             for q in (p + 1)..n {
                 h = l[q] - l[p];
-                if f64::abs(h) <= TOLERANCE {
+                if f64::abs(h) <= tolerance {
                     t = 1.0;
                 } else {
                     theta = 0.5 * h / (a.get(p, q));
@@ -163,19 +240,33 @@ pub fn mat_eigen_sym_jacobi(l: &mut Vector, v: &mut Matrix, a: &mut Matrix) -> R
             l[p] = b[p];
             z[p] = 0.0;
         }
+        if let Some(perf) = perf.as_mut() {
+            perf.stop("sweep");
+        }
     }
 
-    Err("Jacobi rotation did not converge")
+    // final sum of the upper off-diagonal elements
+    sm = 0.0;
+    for p in 0..(n - 1) {
+        for q in (p + 1)..n {
+            sm += f64::abs(a.get(p, q));
+        }
+    }
+    Ok(JacobiStats {
+        n_iterations: n_max_iterations,
+        off_diagonal_sum: sm,
+        converged: false,
+    })
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::{mat_eigen_sym_jacobi, Matrix};
+    use super::{mat_eigen_sym_jacobi, mat_eigen_sym_jacobi_with_params, Matrix};
     use crate::math::SQRT_2;
     use crate::testing::check_eigen_real;
-    use crate::{mat_approx_eq, AsArray2D, Vector};
+    use crate::{check_orthogonal, mat_approx_eq, AsArray2D, Error, PerfReport, Vector};
     use russell_chk::vec_approx_eq;
 
     fn calc_eigen<'a, T>(data: &'a T) -> (usize, Vector, Matrix)
@@ -197,22 +288,22 @@ mod tests {
         let mut l = Vector::new(0);
         assert_eq!(
             mat_eigen_sym_jacobi(&mut l, &mut v, &mut a).err(),
-            Some("matrix must be square")
+            Some(Error::BadDimensions("matrix must be square"))
         );
         let mut a = Matrix::new(0, 0);
         assert_eq!(
             mat_eigen_sym_jacobi(&mut l, &mut v, &mut a).err(),
-            Some("matrix dimension must be ≥ 1")
+            Some(Error::BadDimensions("matrix dimension must be ≥ 1"))
         );
         let mut a = Matrix::new(2, 2);
         assert_eq!(
             mat_eigen_sym_jacobi(&mut l, &mut v, &mut a).err(),
-            Some("v and a matrices must have the same dimensions")
+            Some(Error::BadDimensions("v and a matrices must have the same dimensions"))
         );
         let mut a = Matrix::new(1, 1);
         assert_eq!(
             mat_eigen_sym_jacobi(&mut l, &mut v, &mut a).err(),
-            Some("l vector has incompatible dimension")
+            Some(Error::BadDimensions("l vector has incompatible dimension"))
         );
     }
 
@@ -334,6 +425,7 @@ mod tests {
             1e-15,
         );
         check_eigen_real(data, &v, &l, 1e-14);
+        check_orthogonal(&v, 1e-14);
     }
 
     #[test]
@@ -369,6 +461,7 @@ mod tests {
             1e-12,
         );
         check_eigen_real(data, &v, &l, 1e-14);
+        check_orthogonal(&v, 1e-14);
     }
 
     #[test]
@@ -460,6 +553,7 @@ mod tests {
         // println!("l =\n{}", l);
         // println!("v =\n{}", v);
         check_eigen_real(&a_copy, &v, &l, 1e-14);
+        check_orthogonal(&v, 1e-14);
 
         let mut a = Matrix::filled(size, size, (size + 1) as f64);
         for i in 0..(size - 1) {
@@ -479,4 +573,47 @@ mod tests {
         // println!("v =\n{}", v);
         check_eigen_real(&a_copy, &v, &l, 1e-12);
     }
+
+    #[test]
+    fn mat_eigen_sym_jacobi_with_params_works() {
+        #[rustfmt::skip]
+        let data = &[
+            [1.0, 2.0, 3.0],
+            [2.0, 3.0, 2.0],
+            [3.0, 2.0, 2.0],
+        ];
+        let mut v = Matrix::new(3, 3);
+        let mut l = Vector::new(3);
+
+        // negative tolerance
+        let mut a = Matrix::from(data);
+        assert_eq!(
+            mat_eigen_sym_jacobi_with_params(&mut l, &mut v, &mut a, -1.0, 20, None).err(),
+            Some(Error::InvalidArgument("the tolerance must be non-negative"))
+        );
+
+        // not enough iterations
+        let mut a = Matrix::from(data);
+        let stats = mat_eigen_sym_jacobi_with_params(&mut l, &mut v, &mut a, 1e-15, 2, None).unwrap();
+        assert!(!stats.converged);
+        assert_eq!(stats.n_iterations, 2);
+        assert!(stats.off_diagonal_sum > 1e-15);
+
+        // same as the default parameters (with performance report)
+        let mut a = Matrix::from(data);
+        let mut perf = PerfReport::new();
+        let stats = mat_eigen_sym_jacobi_with_params(&mut l, &mut v, &mut a, 1e-15, 20, Some(&mut perf)).unwrap();
+        assert!(stats.converged);
+        assert_eq!(stats.n_iterations, 5);
+        assert_eq!(perf.get("sweep").unwrap().count, 4);
+        assert!(stats.off_diagonal_sum < 1e-15);
+        check_eigen_real(data, &v, &l, 1e-14);
+
+        // looser tolerance
+        let mut a = Matrix::from(data);
+        let stats = mat_eigen_sym_jacobi_with_params(&mut l, &mut v, &mut a, 1e-6, 20, None).unwrap();
+        assert!(stats.converged);
+        assert!(stats.n_iterations < 5);
+        check_eigen_real(data, &v, &l, 1e-5);
+    }
 }
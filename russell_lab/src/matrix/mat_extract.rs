@@ -0,0 +1,94 @@
+use super::Matrix;
+use crate::StrError;
+use std::ops::Range;
+
+/// Extracts a block (submatrix) of a matrix
+///
+/// ```text
+/// sub := a[rows, cols]
+/// ```
+///
+/// # Output
+///
+/// * `sub` -- (rows.len(), cols.len()) matrix
+///
+/// # Input
+///
+/// * `a` -- (m,n) matrix
+/// * `rows` -- range of row indices with `rows.end ≤ m`
+/// * `cols` -- range of column indices with `cols.end ≤ n`
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_extract, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [1.0, 2.0, 3.0],
+///         [4.0, 5.0, 6.0],
+///         [7.0, 8.0, 9.0],
+///     ]);
+///     let mut sub = Matrix::new(2, 2);
+///     mat_extract(&mut sub, &a, 1..3, 0..2)?;
+///     let correct = "┌     ┐\n\
+///                    │ 4 5 │\n\
+///                    │ 7 8 │\n\
+///                    └     ┘";
+///     assert_eq!(format!("{}", sub), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_extract(sub: &mut Matrix, a: &Matrix, rows: Range<usize>, cols: Range<usize>) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if rows.start > rows.end || cols.start > cols.end || rows.end > m || cols.end > n {
+        return Err("ranges are out of bounds");
+    }
+    let (p, q) = (rows.len(), cols.len());
+    if sub.nrow() != p || sub.ncol() != q {
+        return Err("matrices are incompatible");
+    }
+    // copy column by column (col-major)
+    let a_data = a.as_data();
+    let sub_data = sub.as_mut_data();
+    for (k, j) in cols.enumerate() {
+        let start = rows.start + j * m;
+        sub_data[(k * p)..((k + 1) * p)].copy_from_slice(&a_data[start..(start + p)]);
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::mat_extract;
+    use crate::Matrix;
+
+    #[test]
+    fn mat_extract_fails_on_wrong_input() {
+        let a = Matrix::new(3, 3);
+        let mut sub = Matrix::new(2, 2);
+        assert_eq!(mat_extract(&mut sub, &a, 2..4, 0..2), Err("ranges are out of bounds"));
+        assert_eq!(mat_extract(&mut sub, &a, 0..2, 2..4), Err("ranges are out of bounds"));
+        assert_eq!(mat_extract(&mut sub, &a, 0..3, 0..2), Err("matrices are incompatible"));
+    }
+
+    #[test]
+    fn mat_extract_works() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0,  2.0,  3.0,  4.0],
+            [5.0,  6.0,  7.0,  8.0],
+            [9.0, 10.0, 11.0, 12.0],
+        ]);
+        let mut sub = Matrix::new(2, 3);
+        mat_extract(&mut sub, &a, 1..3, 1..4).unwrap();
+        assert_eq!(sub.as_data(), &[6.0, 10.0, 7.0, 11.0, 8.0, 12.0]);
+        let mut all = Matrix::new(3, 4);
+        mat_extract(&mut all, &a, 0..3, 0..4).unwrap();
+        assert_eq!(all.as_data(), a.as_data());
+        let mut empty = Matrix::new(0, 2);
+        mat_extract(&mut empty, &a, 1..1, 0..2).unwrap();
+    }
+}
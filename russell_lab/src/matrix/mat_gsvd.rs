@@ -0,0 +1,255 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::Error;
+use russell_openblas::{dggsvd3, to_i32};
+
+/// Computes the generalized singular value decomposition (GSVD) of a pair of matrices
+///
+/// Finds `u`, `v`, `q`, and the upper triangular matrix `R` such that:
+///
+/// ```text
+///   a  =   u   ⋅  d1  ⋅ [0 R] ⋅  qᵀ
+/// (m,n)  (m,m)  (m,r)   (r,n)  (n,n)
+///
+///   b  =   v   ⋅  d2  ⋅ [0 R] ⋅  qᵀ
+/// (p,n)  (p,p)  (p,r)   (r,n)  (n,n)
+/// ```
+///
+/// where `u`, `v`, and `q` are orthogonal matrices and `r = k + l` is the effective numerical
+/// rank of the stacked matrix `[aᵀ bᵀ]ᵀ`. The "diagonal" matrices `d1` and `d2` are defined by
+/// `alpha` and `beta`; e.g., if `m ≥ r`:
+///
+/// ```text
+///                 k  l                      k  l
+///     d1 =   k [  I  0 ]       d2 =    l [  0  S ]
+///            l [  0  C ]             p-l [  0  0 ]
+///          m-r [  0  0 ]
+///
+/// C = diag(alpha[k..r])    S = diag(beta[k..r])    C² + S² = I
+/// ```
+///
+/// The ratios `alpha[i] / beta[i]` for `i` in `k..r` are the generalized singular values of
+/// the pair (a, b). The GSVD is needed, for instance, to solve constrained least-squares
+/// problems and to analyze Tikhonov regularization with a general regularization matrix.
+///
+/// # Output
+///
+/// * `alpha` -- (n) the first components of the generalized singular value pairs
+/// * `beta` -- (n) the second components of the generalized singular value pairs
+/// * `u` -- (m,m) orthogonal matrix
+/// * `v` -- (p,p) orthogonal matrix
+/// * `q` -- (n,n) orthogonal matrix
+/// * `a` -- on exit, `a[0..r, n-r..n]` contains the (r,r) upper triangular matrix R if `m ≥ r`;
+///   otherwise, R is split between `a` and `b` (see the LAPACK dggsvd3 documentation)
+/// * Returns `(k, l)`
+///
+/// # Input
+///
+/// * `a` -- (m,n) matrix; it will be modified
+/// * `b` -- (p,n) matrix; it will be modified
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::vec_approx_eq;
+/// use russell_lab::{mat_gsvd, Matrix, StrError, Vector};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     // with b = I, the generalized singular values are the singular values of a
+///     let mut a = Matrix::from(&[
+///         [3.0, 0.0],
+///         [0.0, 4.0],
+///         [0.0, 0.0],
+///     ]);
+///     let mut b = Matrix::identity(2);
+///     let mut alpha = Vector::new(2);
+///     let mut beta = Vector::new(2);
+///     let mut u = Matrix::new(3, 3);
+///     let mut v = Matrix::new(2, 2);
+///     let mut q = Matrix::new(2, 2);
+///     let (k, l) = mat_gsvd(&mut alpha, &mut beta, &mut u, &mut v, &mut q, &mut a, &mut b)?;
+///     assert_eq!((k, l), (0, 2));
+///
+///     // sorted generalized singular values
+///     let mut sigma: Vec<_> = (k..k + l).map(|i| alpha[i] / beta[i]).collect();
+///     sigma.sort_by(|x, y| x.total_cmp(y));
+///     vec_approx_eq(&sigma, &[3.0, 4.0], 1e-14);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+pub fn mat_gsvd(
+    alpha: &mut Vector,
+    beta: &mut Vector,
+    u: &mut Matrix,
+    v: &mut Matrix,
+    q: &mut Matrix,
+    a: &mut Matrix,
+    b: &mut Matrix,
+) -> Result<(usize, usize), Error> {
+    let (m, n) = a.dims();
+    let p = b.nrow();
+    if b.ncol() != n {
+        return Err(Error::BadDimensions(
+            "matrices [a] and [b] must have the same number of columns",
+        ));
+    }
+    if alpha.dim() != n || beta.dim() != n {
+        return Err(Error::BadDimensions("[alpha] and [beta] must be n vectors"));
+    }
+    if u.nrow() != m || u.ncol() != m {
+        return Err(Error::BadDimensions("[u] must be an m-by-m square matrix"));
+    }
+    if v.nrow() != p || v.ncol() != p {
+        return Err(Error::BadDimensions("[v] must be a p-by-p square matrix"));
+    }
+    if q.nrow() != n || q.ncol() != n {
+        return Err(Error::BadDimensions("[q] must be an n-by-n square matrix"));
+    }
+    let mut iwork = vec![0; n];
+    let (k, l) = dggsvd3(
+        b'U',
+        b'V',
+        b'Q',
+        to_i32(m),
+        to_i32(n),
+        to_i32(p),
+        a.as_mut_data(),
+        b.as_mut_data(),
+        alpha.as_mut_data(),
+        beta.as_mut_data(),
+        u.as_mut_data(),
+        v.as_mut_data(),
+        q.as_mut_data(),
+        &mut iwork,
+    )?;
+    Ok((k as usize, l as usize))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(dead_code))]
+mod tests {
+    use super::mat_gsvd;
+    use crate::{check_orthogonal, mat_approx_eq, mat_mat_mul, mat_transpose, Error, Matrix, Vector};
+    use russell_chk::approx_eq;
+
+    // computes the GSVD and checks the decomposition (assuming m ≥ k + l)
+    fn check_gsvd(a: &Matrix, b: &Matrix, tol: f64) -> (usize, usize, Vector, Vector) {
+        let (m, n) = a.dims();
+        let p = b.nrow();
+        let mut alpha = Vector::new(n);
+        let mut beta = Vector::new(n);
+        let mut u = Matrix::new(m, m);
+        let mut v = Matrix::new(p, p);
+        let mut q = Matrix::new(n, n);
+        let mut r_data = a.clone();
+        let mut b_copy = b.clone();
+        let (k, l) = mat_gsvd(&mut alpha, &mut beta, &mut u, &mut v, &mut q, &mut r_data, &mut b_copy).unwrap();
+        let r = k + l;
+        check_orthogonal(&u, tol);
+        check_orthogonal(&v, tol);
+        check_orthogonal(&q, tol);
+
+        // zr = [0 R] (r,n)
+        let mut zr = Matrix::new(r, n);
+        for i in 0..r {
+            for j in (n - r + i)..n {
+                zr.set(i, j, r_data.get(i, j));
+            }
+        }
+        let mut d1 = Matrix::new(m, r);
+        let mut d2 = Matrix::new(p, r);
+        for i in 0..k {
+            d1.set(i, i, 1.0);
+        }
+        for i in 0..l {
+            d1.set(k + i, k + i, alpha[k + i]);
+            d2.set(i, k + i, beta[k + i]);
+            approx_eq(alpha[k + i] * alpha[k + i] + beta[k + i] * beta[k + i], 1.0, 1e-14);
+        }
+
+        // check a = u ⋅ d1 ⋅ [0 R] ⋅ qᵀ and b = v ⋅ d2 ⋅ [0 R] ⋅ qᵀ
+        let mut zrqt = Matrix::new(r, n);
+        let mut qt = Matrix::new(n, n);
+        mat_transpose(&mut qt, &q).unwrap();
+        mat_mat_mul(&mut zrqt, 1.0, &zr, &qt).unwrap();
+        for (x, w, d) in [(a, &u, &d1), (b, &v, &d2)] {
+            let mut wd = Matrix::new(w.nrow(), r);
+            let mut wdzrqt = Matrix::new(w.nrow(), n);
+            mat_mat_mul(&mut wd, 1.0, w, d).unwrap();
+            mat_mat_mul(&mut wdzrqt, 1.0, &wd, &zrqt).unwrap();
+            mat_approx_eq(&wdzrqt, x, tol);
+        }
+        (k, l, alpha, beta)
+    }
+
+    #[test]
+    fn mat_gsvd_fails_on_wrong_dims() {
+        let mut a = Matrix::new(3, 2);
+        let mut b = Matrix::new(2, 2);
+        let mut alpha = Vector::new(2);
+        let mut beta = Vector::new(2);
+        let mut u = Matrix::new(3, 3);
+        let mut v = Matrix::new(2, 2);
+        let mut q = Matrix::new(2, 2);
+        let mut b_wrong = Matrix::new(2, 3);
+        let mut alpha_wrong = Vector::new(3);
+        let mut u_wrong = Matrix::new(2, 2);
+        let mut v_wrong = Matrix::new(3, 3);
+        let mut q_wrong = Matrix::new(3, 3);
+        assert_eq!(
+            mat_gsvd(&mut alpha, &mut beta, &mut u, &mut v, &mut q, &mut a, &mut b_wrong),
+            Err(Error::BadDimensions(
+                "matrices [a] and [b] must have the same number of columns"
+            ))
+        );
+        assert_eq!(
+            mat_gsvd(&mut alpha_wrong, &mut beta, &mut u, &mut v, &mut q, &mut a, &mut b),
+            Err(Error::BadDimensions("[alpha] and [beta] must be n vectors"))
+        );
+        assert_eq!(
+            mat_gsvd(&mut alpha, &mut beta, &mut u_wrong, &mut v, &mut q, &mut a, &mut b),
+            Err(Error::BadDimensions("[u] must be an m-by-m square matrix"))
+        );
+        assert_eq!(
+            mat_gsvd(&mut alpha, &mut beta, &mut u, &mut v_wrong, &mut q, &mut a, &mut b),
+            Err(Error::BadDimensions("[v] must be a p-by-p square matrix"))
+        );
+        assert_eq!(
+            mat_gsvd(&mut alpha, &mut beta, &mut u, &mut v, &mut q_wrong, &mut a, &mut b),
+            Err(Error::BadDimensions("[q] must be an n-by-n square matrix"))
+        );
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn mat_gsvd_works() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0, 2.0,  3.0],
+            [4.0, 5.0,  6.0],
+            [7.0, 8.0, 10.0],
+            [1.0, 0.0,  1.0],
+        ]);
+
+        // regularization with the second-difference operator
+        #[rustfmt::skip]
+        let b = Matrix::from(&[
+            [ 2.0, -1.0,  0.0],
+            [-1.0,  2.0, -1.0],
+            [ 0.0, -1.0,  2.0],
+        ]);
+        let (k, l, _, _) = check_gsvd(&a, &b, 1e-13);
+        assert_eq!((k, l), (0, 3));
+
+        // rank-deficient b
+        let b = Matrix::from(&[[1.0, 1.0, 1.0]]);
+        let (k, l, alpha, beta) = check_gsvd(&a, &b, 1e-13);
+        assert_eq!((k, l), (2, 1));
+        assert_eq!((alpha[0], beta[0]), (1.0, 0.0));
+        assert_eq!((alpha[1], beta[1]), (1.0, 0.0));
+    }
+}
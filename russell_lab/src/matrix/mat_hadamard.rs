@@ -0,0 +1,159 @@
+use super::Matrix;
+use crate::StrError;
+
+/// Performs the element-wise (Hadamard) product of two matrices
+///
+/// ```text
+/// c := a ⊙ b    i.e.,  cᵢⱼ := aᵢⱼ ⋅ bᵢⱼ
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_hadamard, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[[1.0, 2.0], [3.0, 4.0]]);
+///     let b = Matrix::from(&[[5.0, 6.0], [7.0, 8.0]]);
+///     let mut c = Matrix::new(2, 2);
+///     mat_hadamard(&mut c, &a, &b)?;
+///     let correct = "┌       ┐\n\
+///                    │  5 12 │\n\
+///                    │ 21 32 │\n\
+///                    └       ┘";
+///     assert_eq!(format!("{}", c), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_hadamard(c: &mut Matrix, a: &Matrix, b: &Matrix) -> Result<(), StrError> {
+    let dims = c.dims();
+    if a.dims() != dims || b.dims() != dims {
+        return Err("matrices are incompatible");
+    }
+    let (a_data, b_data) = (a.as_data(), b.as_data());
+    for (k, cc) in c.as_mut_data().iter_mut().enumerate() {
+        *cc = a_data[k] * b_data[k];
+    }
+    Ok(())
+}
+
+/// Performs the element-wise division of two matrices
+///
+/// ```text
+/// c := a ⊘ b    i.e.,  cᵢⱼ := aᵢⱼ / bᵢⱼ
+/// ```
+///
+/// **Note:** No check is made for zero components of `b` (the IEEE-754 rules apply).
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_hadamard_div, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[[5.0, 12.0], [21.0, 32.0]]);
+///     let b = Matrix::from(&[[5.0, 6.0], [7.0, 8.0]]);
+///     let mut c = Matrix::new(2, 2);
+///     mat_hadamard_div(&mut c, &a, &b)?;
+///     let correct = "┌     ┐\n\
+///                    │ 1 2 │\n\
+///                    │ 3 4 │\n\
+///                    └     ┘";
+///     assert_eq!(format!("{}", c), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_hadamard_div(c: &mut Matrix, a: &Matrix, b: &Matrix) -> Result<(), StrError> {
+    let dims = c.dims();
+    if a.dims() != dims || b.dims() != dims {
+        return Err("matrices are incompatible");
+    }
+    let (a_data, b_data) = (a.as_data(), b.as_data());
+    for (k, cc) in c.as_mut_data().iter_mut().enumerate() {
+        *cc = a_data[k] / b_data[k];
+    }
+    Ok(())
+}
+
+/// Updates a matrix by the element-wise (Hadamard) product with another matrix
+///
+/// ```text
+/// b := b ⊙ a    i.e.,  bᵢⱼ := bᵢⱼ ⋅ aᵢⱼ
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_hadamard_update, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[[1.0, 2.0], [3.0, 4.0]]);
+///     let mut b = Matrix::from(&[[5.0, 6.0], [7.0, 8.0]]);
+///     mat_hadamard_update(&mut b, &a)?;
+///     let correct = "┌       ┐\n\
+///                    │  5 12 │\n\
+///                    │ 21 32 │\n\
+///                    └       ┘";
+///     assert_eq!(format!("{}", b), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_hadamard_update(b: &mut Matrix, a: &Matrix) -> Result<(), StrError> {
+    if a.dims() != b.dims() {
+        return Err("matrices are incompatible");
+    }
+    let a_data = a.as_data();
+    for (k, bb) in b.as_mut_data().iter_mut().enumerate() {
+        *bb *= a_data[k];
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_hadamard, mat_hadamard_div, mat_hadamard_update, Matrix};
+    use crate::mat_approx_eq;
+
+    #[test]
+    fn mat_hadamard_functions_fail_on_wrong_dims() {
+        let a_2x2 = Matrix::new(2, 2);
+        let a_2x3 = Matrix::new(2, 3);
+        let mut c_2x2 = Matrix::new(2, 2);
+        assert_eq!(
+            mat_hadamard(&mut c_2x2, &a_2x3, &a_2x2),
+            Err("matrices are incompatible")
+        );
+        assert_eq!(
+            mat_hadamard(&mut c_2x2, &a_2x2, &a_2x3),
+            Err("matrices are incompatible")
+        );
+        assert_eq!(
+            mat_hadamard_div(&mut c_2x2, &a_2x3, &a_2x2),
+            Err("matrices are incompatible")
+        );
+        assert_eq!(
+            mat_hadamard_div(&mut c_2x2, &a_2x2, &a_2x3),
+            Err("matrices are incompatible")
+        );
+        assert_eq!(
+            mat_hadamard_update(&mut c_2x2, &a_2x3),
+            Err("matrices are incompatible")
+        );
+    }
+
+    #[test]
+    fn mat_hadamard_functions_work() {
+        let a = Matrix::from(&[[1.0, -2.0, 0.5], [0.0, 3.0, 1.0]]);
+        let b = Matrix::from(&[[3.0, 4.0, -2.0], [1.0, 2.0, 8.0]]);
+        let mut c = Matrix::new(2, 3);
+        mat_hadamard(&mut c, &a, &b).unwrap();
+        mat_approx_eq(&c, &[[3.0, -8.0, -1.0], [0.0, 6.0, 8.0]], 1e-15);
+        mat_hadamard_div(&mut c, &a, &b).unwrap();
+        mat_approx_eq(&c, &[[1.0 / 3.0, -0.5, -0.25], [0.0, 1.5, 0.125]], 1e-15);
+        let mut d = b.clone();
+        mat_hadamard_update(&mut d, &a).unwrap();
+        mat_approx_eq(&d, &[[3.0, -8.0, -1.0], [0.0, 6.0, 8.0]], 1e-15);
+    }
+}
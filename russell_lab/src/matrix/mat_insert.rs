@@ -0,0 +1,118 @@
+use super::Matrix;
+use crate::StrError;
+
+/// Inserts (or adds) a block into a matrix
+///
+/// ```text
+/// a[i0 + i, j0 + j] := α ⋅ sub[i, j]                   (if accumulate is false)
+/// a[i0 + i, j0 + j] := a[i0 + i, j0 + j] + α ⋅ sub[i, j]   (if accumulate is true)
+/// ```
+///
+/// The `accumulate` option is useful to assemble element matrices into a larger matrix.
+///
+/// # Input
+///
+/// * `a` -- (m,n) matrix
+/// * `i0`, `j0` -- indices of the top-left corner of the block in `a`
+/// * `alpha` -- multiplier
+/// * `sub` -- (p,q) block with `i0 + p ≤ m` and `j0 + q ≤ n`
+/// * `accumulate` -- adds to the current values instead of overwriting them
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_insert, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut a = Matrix::filled(3, 3, 1.0);
+///     let sub = Matrix::from(&[
+///         [2.0, 3.0],
+///         [4.0, 5.0],
+///     ]);
+///     mat_insert(&mut a, 1, 1, 1.0, &sub, true)?;
+///     let correct = "┌       ┐\n\
+///                    │ 1 1 1 │\n\
+///                    │ 1 3 4 │\n\
+///                    │ 1 5 6 │\n\
+///                    └       ┘";
+///     assert_eq!(format!("{}", a), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_insert(
+    a: &mut Matrix,
+    i0: usize,
+    j0: usize,
+    alpha: f64,
+    sub: &Matrix,
+    accumulate: bool,
+) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    let (p, q) = sub.dims();
+    if i0 + p > m || j0 + q > n {
+        return Err("block is out of bounds");
+    }
+    // process column by column (col-major)
+    let sub_data = sub.as_data();
+    let a_data = a.as_mut_data();
+    for j in 0..q {
+        let start = i0 + (j0 + j) * m;
+        let target = &mut a_data[start..(start + p)];
+        let source = &sub_data[(j * p)..((j + 1) * p)];
+        if accumulate {
+            target.iter_mut().zip(source).for_each(|(t, s)| *t += alpha * s);
+        } else {
+            target.iter_mut().zip(source).for_each(|(t, s)| *t = alpha * s);
+        }
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::mat_insert;
+    use crate::{mat_approx_eq, Matrix};
+
+    #[test]
+    fn mat_insert_fails_on_wrong_input() {
+        let mut a = Matrix::new(3, 3);
+        let sub = Matrix::new(2, 2);
+        assert_eq!(
+            mat_insert(&mut a, 2, 0, 1.0, &sub, false),
+            Err("block is out of bounds")
+        );
+        assert_eq!(
+            mat_insert(&mut a, 0, 2, 1.0, &sub, false),
+            Err("block is out of bounds")
+        );
+    }
+
+    #[test]
+    fn mat_insert_works() {
+        let mut a = Matrix::filled(3, 4, 1.0);
+        let sub = Matrix::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        mat_insert(&mut a, 1, 1, 2.0, &sub, false).unwrap();
+        #[rustfmt::skip]
+        let correct = &[
+            [1.0, 1.0,  1.0,  1.0],
+            [1.0, 2.0,  4.0,  6.0],
+            [1.0, 8.0, 10.0, 12.0],
+        ];
+        mat_approx_eq(&a, correct, 1e-15);
+
+        // assemble two overlapping element matrices
+        let mut k = Matrix::new(3, 3);
+        let ke = Matrix::from(&[[1.0, -1.0], [-1.0, 1.0]]);
+        mat_insert(&mut k, 0, 0, 1.0, &ke, true).unwrap();
+        mat_insert(&mut k, 1, 1, 1.0, &ke, true).unwrap();
+        #[rustfmt::skip]
+        let correct = &[
+            [ 1.0, -1.0,  0.0],
+            [-1.0,  2.0, -1.0],
+            [ 0.0, -1.0,  1.0],
+        ];
+        mat_approx_eq(&k, correct, 1e-15);
+    }
+}
@@ -0,0 +1,363 @@
+use super::Matrix;
+use crate::{Error, Vector};
+use russell_openblas::{dgetrf, dgetrs, to_i32, BlasInt};
+
+/// Holds the LU factorization (with partial pivoting) of a square matrix
+///
+/// The factorization reads:
+///
+/// ```text
+/// p ⋅ a = l ⋅ u
+/// ```
+///
+/// where `p` is a permutation matrix, `l` is unit lower triangular, and `u` is upper triangular.
+/// The factors are computed once and can be reused to solve linear systems with many
+/// right-hand sides and to compute the determinant.
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::vec_approx_eq;
+/// use russell_lab::{LuFactors, Matrix, StrError, Vector};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     // set matrix
+///     let a = Matrix::from(&[
+///         [2.0, 1.0, 1.0],
+///         [4.0, 3.0, 3.0],
+///         [8.0, 7.0, 9.0],
+///     ]);
+///
+///     // factorize
+///     let lu = LuFactors::new(&a)?;
+///     assert!(f64::abs(lu.determinant() - 4.0) < 1e-14);
+///
+///     // solve two systems with the same factors
+///     let mut b = Vector::from(&[4.0, 10.0, 24.0]);
+///     lu.solve(&mut b)?;
+///     vec_approx_eq(b.as_data(), &[1.0, 1.0, 1.0], 1e-14);
+///     let mut b = Vector::from(&[2.0, 4.0, 8.0]);
+///     lu.solve(&mut b)?;
+///     vec_approx_eq(b.as_data(), &[1.0, 0.0, 0.0], 1e-14);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+#[derive(Clone, Debug)]
+pub struct LuFactors {
+    /// Holds l (below the diagonal) and u (on and above the diagonal)
+    lu: Matrix,
+
+    /// Holds the (1-based) pivot indices computed by LAPACK
+    ipiv: Vec<BlasInt>,
+
+    /// Indicates that u has a zero on the diagonal
+    singular: bool,
+}
+
+impl LuFactors {
+    /// Computes the LU factorization of a square matrix
+    ///
+    /// **Note:** a singular matrix is factorized as well; in this case, the determinant is zero
+    /// and `solve` returns an error.
+    pub fn new(a: &Matrix) -> Result<Self, Error> {
+        let (m, n) = a.dims();
+        if m != n {
+            return Err(Error::BadDimensions("matrix must be square"));
+        }
+        let mut lu = a.clone();
+        let mut ipiv = vec![0; m];
+        let mut singular = false;
+        if m > 0 {
+            let m_i32 = to_i32(m);
+            match dgetrf(m_i32, m_i32, lu.as_mut_data(), &mut ipiv) {
+                Err(Error::Singular(_)) => singular = true,
+                Err(e) => return Err(e),
+                Ok(()) => (),
+            }
+        }
+        Ok(LuFactors { lu, ipiv, singular })
+    }
+
+    /// Returns the dimension of the factorized matrix
+    pub fn dim(&self) -> usize {
+        self.lu.nrow()
+    }
+
+    /// Indicates whether the factorized matrix is singular (exactly, i.e., u has a zero on the diagonal)
+    pub fn is_singular(&self) -> bool {
+        self.singular
+    }
+
+    /// Returns the unit lower triangular factor
+    pub fn l(&self) -> Matrix {
+        let m = self.dim();
+        let mut l = Matrix::new(m, m);
+        for i in 0..m {
+            l.set(i, i, 1.0);
+            for j in 0..i {
+                l.set(i, j, self.lu.get(i, j));
+            }
+        }
+        l
+    }
+
+    /// Returns the upper triangular factor
+    pub fn u(&self) -> Matrix {
+        let m = self.dim();
+        let mut u = Matrix::new(m, m);
+        for i in 0..m {
+            for j in i..m {
+                u.set(i, j, self.lu.get(i, j));
+            }
+        }
+        u
+    }
+
+    /// Returns the row permutation such that row `i` of `p ⋅ a` is row `perm[i]` of `a`
+    pub fn perm(&self) -> Vec<usize> {
+        let m = self.dim();
+        let mut perm: Vec<usize> = (0..m).collect();
+        for k in 0..m {
+            // NOTE: ipiv are 1-based indices
+            perm.swap(k, (self.ipiv[k] - 1) as usize);
+        }
+        perm
+    }
+
+    /// Returns the determinant of the factorized matrix
+    ///
+    /// Returns 1.0 for a zero-sized matrix.
+    pub fn determinant(&self) -> f64 {
+        let mut det = 1.0;
+        for k in 0..self.dim() {
+            // NOTE: ipiv are 1-based indices
+            if (self.ipiv[k] - 1) as usize == k {
+                det *= self.lu.get(k, k);
+            } else {
+                det *= -self.lu.get(k, k);
+            }
+        }
+        det
+    }
+
+    /// Solves the linear system `a ⋅ x = b` using the factors
+    ///
+    /// # Input
+    ///
+    /// * `b` -- (m) on input, the right-hand side; on output, the solution `x`
+    pub fn solve(&self, b: &mut Vector) -> Result<(), Error> {
+        let m = self.dim();
+        if b.dim() != m {
+            return Err(Error::BadDimensions("vector has wrong dimension"));
+        }
+        if self.singular {
+            return Err(Error::Singular("cannot solve with a singular matrix"));
+        }
+        if m > 0 {
+            dgetrs(false, to_i32(m), 1, self.lu.as_data(), &self.ipiv, b.as_mut_data())?;
+        }
+        Ok(())
+    }
+}
+
+/// Computes the LU factorization (with partial pivoting) of a square matrix
+///
+/// ```text
+/// p ⋅ a = l ⋅ u
+/// ```
+///
+/// # Output
+///
+/// * `l` -- (m,m) unit lower triangular matrix
+/// * `u` -- (m,m) upper triangular matrix
+/// * `perm` -- (m) row permutation such that row `i` of `p ⋅ a` is row `perm[i]` of `a`
+///
+/// # Input
+///
+/// * `a` -- (m,m) matrix
+///
+/// **Note:** Use [LuFactors] directly to reuse the factorization for solving linear systems.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_approx_eq, mat_lu, mat_mat_mul, Matrix, StrError};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     // set matrix
+///     let a = Matrix::from(&[
+///         [1.0, 2.0],
+///         [3.0, 4.0],
+///     ]);
+///
+///     // factorize
+///     let mut l = Matrix::new(2, 2);
+///     let mut u = Matrix::new(2, 2);
+///     let mut perm = vec![0; 2];
+///     mat_lu(&mut l, &mut u, &mut perm, &a)?;
+///     assert_eq!(perm, &[1, 0]);
+///
+///     // check that p⋅a = l⋅u
+///     let pa = Matrix::from(&[
+///         [3.0, 4.0],
+///         [1.0, 2.0],
+///     ]);
+///     let mut lu = Matrix::new(2, 2);
+///     mat_mat_mul(&mut lu, 1.0, &l, &u)?;
+///     mat_approx_eq(&lu, &pa, 1e-15);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+pub fn mat_lu(l: &mut Matrix, u: &mut Matrix, perm: &mut [usize], a: &Matrix) -> Result<(), Error> {
+    let (m, n) = a.dims();
+    if m != n {
+        return Err(Error::BadDimensions("matrix must be square"));
+    }
+    if l.nrow() != m || l.ncol() != m || u.nrow() != m || u.ncol() != m {
+        return Err(Error::BadDimensions("matrices are incompatible"));
+    }
+    if perm.len() != m {
+        return Err(Error::BadDimensions("permutation has wrong dimension"));
+    }
+    let factors = LuFactors::new(a)?;
+    *l = factors.l();
+    *u = factors.u();
+    perm.copy_from_slice(&factors.perm());
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(dead_code, unused_imports))]
+mod tests {
+    use super::{mat_lu, LuFactors};
+    use crate::{mat_approx_eq, mat_mat_mul, Error, Matrix, Vector};
+    use russell_chk::vec_approx_eq;
+
+    // checks that p⋅a = l⋅u
+    fn check_lu(a: &Matrix, l: &Matrix, u: &Matrix, perm: &[usize], tol: f64) {
+        let m = a.nrow();
+        let mut pa = Matrix::new(m, m);
+        for (i, p) in perm.iter().enumerate() {
+            for j in 0..m {
+                pa.set(i, j, a.get(*p, j));
+            }
+        }
+        let mut lu = Matrix::new(m, m);
+        mat_mat_mul(&mut lu, 1.0, l, u).unwrap();
+        mat_approx_eq(&lu, &pa, tol);
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn lu_factors_handle_errors() {
+        let a = Matrix::new(2, 3);
+        assert_eq!(
+            LuFactors::new(&a).err(),
+            Some(Error::BadDimensions("matrix must be square"))
+        );
+        let a = Matrix::from(&[[1.0, 2.0], [3.0, 4.0]]);
+        let lu = LuFactors::new(&a).unwrap();
+        let mut b = Vector::new(3);
+        assert_eq!(
+            lu.solve(&mut b),
+            Err(Error::BadDimensions("vector has wrong dimension"))
+        );
+        let singular = Matrix::from(&[[1.0, 2.0], [2.0, 4.0]]);
+        let lu = LuFactors::new(&singular).unwrap();
+        assert!(lu.is_singular());
+        assert_eq!(lu.determinant(), 0.0);
+        let mut b = Vector::new(2);
+        assert_eq!(
+            lu.solve(&mut b),
+            Err(Error::Singular("cannot solve with a singular matrix"))
+        );
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn lu_factors_work() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0, 2.0, 3.0],
+            [0.0, 1.0, 4.0],
+            [5.0, 6.0, 0.0],
+        ]);
+        let lu = LuFactors::new(&a).unwrap();
+        assert_eq!(lu.dim(), 3);
+        assert!(!lu.is_singular());
+        assert!(f64::abs(lu.determinant() - 1.0) < 1e-14);
+        check_lu(&a, &lu.l(), &lu.u(), &lu.perm(), 1e-15);
+
+        // many right-hand sides
+        let mut b = Vector::from(&[6.0, 5.0, 11.0]);
+        lu.solve(&mut b).unwrap();
+        vec_approx_eq(b.as_data(), &[1.0, 1.0, 1.0], 1e-13);
+        let mut b = Vector::from(&[1.0, 0.0, 0.0]);
+        lu.solve(&mut b).unwrap();
+        vec_approx_eq(b.as_data(), &[-24.0, 20.0, -5.0], 1e-13);
+
+        // zero-sized matrix
+        let lu = LuFactors::new(&Matrix::new(0, 0)).unwrap();
+        assert_eq!(lu.determinant(), 1.0);
+        let mut b = Vector::new(0);
+        lu.solve(&mut b).unwrap();
+    }
+
+    #[test]
+    fn mat_lu_handles_errors() {
+        let a = Matrix::new(2, 2);
+        let mut l = Matrix::new(2, 2);
+        let mut u = Matrix::new(2, 2);
+        let mut perm = vec![0; 2];
+        assert_eq!(
+            mat_lu(&mut l, &mut u, &mut perm, &Matrix::new(2, 1)),
+            Err(Error::BadDimensions("matrix must be square"))
+        );
+        let mut wrong = Matrix::new(3, 3);
+        assert_eq!(
+            mat_lu(&mut wrong, &mut u, &mut perm, &a),
+            Err(Error::BadDimensions("matrices are incompatible"))
+        );
+        assert_eq!(
+            mat_lu(&mut l, &mut wrong, &mut perm, &a),
+            Err(Error::BadDimensions("matrices are incompatible"))
+        );
+        let mut perm_wrong = vec![0; 1];
+        assert_eq!(
+            mat_lu(&mut l, &mut u, &mut perm_wrong, &a),
+            Err(Error::BadDimensions("permutation has wrong dimension"))
+        );
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn mat_lu_works() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [ 2.0,  1.0, 1.0, 0.0],
+            [ 4.0,  3.0, 3.0, 1.0],
+            [ 8.0,  7.0, 9.0, 5.0],
+            [ 6.0,  7.0, 9.0, 8.0],
+        ]);
+        let mut l = Matrix::new(4, 4);
+        let mut u = Matrix::new(4, 4);
+        let mut perm = vec![0; 4];
+        mat_lu(&mut l, &mut u, &mut perm, &a).unwrap();
+        check_lu(&a, &l, &u, &perm, 1e-14);
+        for i in 0..4 {
+            assert_eq!(l.get(i, i), 1.0);
+            for j in (i + 1)..4 {
+                assert_eq!(l.get(i, j), 0.0);
+                assert_eq!(u.get(j, i), 0.0);
+            }
+        }
+        // partial pivoting selects the largest entry of the first column
+        assert_eq!(perm[0], 2);
+    }
+}
@@ -1,6 +1,6 @@
-use super::Matrix;
+use super::{Matrix, MatrixF32};
 use crate::StrError;
-use russell_openblas::{dgemm, to_i32};
+use russell_openblas::{dgemm, sgemm, to_i32};
 
 /// Performs the matrix-matrix multiplication resulting in a matrix
 ///
@@ -44,9 +44,9 @@ pub fn mat_mat_mul(c: &mut Matrix, alpha: f64, a: &Matrix, b: &Matrix) -> Result
     if m == 0 || n == 0 {
         return Ok(());
     }
-    let m_i32: i32 = to_i32(m);
-    let n_i32: i32 = to_i32(n);
-    let k_i32: i32 = to_i32(k);
+    let m_i32 = to_i32(m);
+    let n_i32 = to_i32(n);
+    let k_i32 = to_i32(k);
     dgemm(
         false,
         false,
@@ -62,11 +62,59 @@ pub fn mat_mat_mul(c: &mut Matrix, alpha: f64, a: &Matrix, b: &Matrix) -> Result
     Ok(())
 }
 
+/// Performs the matrix-matrix multiplication resulting in a matrix (single precision version)
+///
+/// ```text
+///   c  :=  α ⋅  a   ⋅   b
+/// (m,n)       (m,k)   (k,n)
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_mat_mul_f32, MatrixF32, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = MatrixF32::from(&[[1.0, 2.0], [3.0, 4.0]]);
+///     let b = MatrixF32::from(&[[-1.0, 0.0], [0.0, 2.0]]);
+///     let mut c = MatrixF32::new(2, 2);
+///     mat_mat_mul_f32(&mut c, 1.0, &a, &b)?;
+///     assert_eq!(c.as_data(), &[-1.0, -3.0, 4.0, 8.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_mat_mul_f32(c: &mut MatrixF32, alpha: f32, a: &MatrixF32, b: &MatrixF32) -> Result<(), StrError> {
+    let (m, n) = c.dims();
+    let k = a.ncol();
+    if a.nrow() != m || b.nrow() != k || b.ncol() != n {
+        return Err("matrices are incompatible");
+    }
+    if m == 0 || n == 0 {
+        return Ok(());
+    }
+    let m_i32 = to_i32(m);
+    let n_i32 = to_i32(n);
+    let k_i32 = to_i32(k);
+    sgemm(
+        false,
+        false,
+        m_i32,
+        n_i32,
+        k_i32,
+        alpha,
+        a.as_data(),
+        b.as_data(),
+        0.0,
+        c.as_mut_data(),
+    );
+    Ok(())
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::{mat_mat_mul, Matrix};
+    use super::{mat_mat_mul, mat_mat_mul_f32, Matrix, MatrixF32};
     use crate::mat_approx_eq;
 
     #[test]
@@ -121,4 +169,21 @@ mod tests {
         ];
         mat_approx_eq(&c, correct, 1e-15);
     }
+
+    #[test]
+    fn mat_mat_mul_f32_works() {
+        let a = MatrixF32::from(&[[1.0, 2.0, 3.0], [0.5, 0.75, 1.5]]);
+        let b = MatrixF32::from(&[[0.1, 0.5], [0.2, 2.0], [0.3, 0.5]]);
+        let mut c = MatrixF32::new(2, 2);
+        let mut c_wrong = MatrixF32::new(2, 3);
+        assert_eq!(
+            mat_mat_mul_f32(&mut c_wrong, 1.0, &a, &b),
+            Err("matrices are incompatible")
+        );
+        mat_mat_mul_f32(&mut c, 2.0, &a, &b).unwrap();
+        let correct = [2.8, 1.3, 12.0, 5.0];
+        for (x, y) in c.as_data().iter().zip(correct) {
+            assert!(f32::abs(x - y) < 1e-6);
+        }
+    }
 }
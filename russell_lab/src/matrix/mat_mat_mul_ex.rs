@@ -0,0 +1,162 @@
+use super::Matrix;
+use crate::StrError;
+use russell_openblas::{dgemm, to_i32};
+
+/// Performs the general matrix-matrix multiplication with optional transposition and accumulation
+///
+/// ```text
+///   c  :=  α ⋅ op(a) ⋅ op(b)  +  β ⋅  c
+/// (m,n)        (m,k)   (k,n)        (m,n)
+/// ```
+///
+/// where `op(a) = aᵀ` if `trans_a` is true and `op(a) = a` otherwise (the same for `b`).
+///
+/// # Input
+///
+/// * `a` -- (m,k) matrix or (k,m) matrix if `trans_a`
+/// * `b` -- (k,n) matrix or (n,k) matrix if `trans_b`
+/// * `beta` -- coefficient of the previous `c`; if zero, the previous contents of `c` are ignored
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_mat_mul_ex, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [1.0, 2.0],
+///         [3.0, 4.0],
+///     ]);
+///     let mut c = Matrix::from(&[
+///         [1.0, 0.0],
+///         [0.0, 1.0],
+///     ]);
+///     // c := aᵀ⋅a + c
+///     mat_mat_mul_ex(&mut c, 1.0, &a, true, &a, false, 1.0)?;
+///     let correct = "┌       ┐\n\
+///                    │ 11 14 │\n\
+///                    │ 14 21 │\n\
+///                    └       ┘";
+///     assert_eq!(format!("{}", c), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_mat_mul_ex(
+    c: &mut Matrix,
+    alpha: f64,
+    a: &Matrix,
+    trans_a: bool,
+    b: &Matrix,
+    trans_b: bool,
+    beta: f64,
+) -> Result<(), StrError> {
+    let (m, n) = c.dims();
+    let (a_m, k) = if trans_a { (a.ncol(), a.nrow()) } else { a.dims() };
+    let (b_k, b_n) = if trans_b { (b.ncol(), b.nrow()) } else { b.dims() };
+    if a_m != m || b_k != k || b_n != n {
+        return Err("matrices are incompatible");
+    }
+    if m == 0 || n == 0 {
+        return Ok(());
+    }
+    if k == 0 {
+        // nothing to multiply; only scale c
+        for x in c.as_mut_data().iter_mut() {
+            *x = if beta == 0.0 { 0.0 } else { beta * *x };
+        }
+        return Ok(());
+    }
+    let m_i32 = to_i32(m);
+    let n_i32 = to_i32(n);
+    let k_i32 = to_i32(k);
+    dgemm(
+        trans_a,
+        trans_b,
+        m_i32,
+        n_i32,
+        k_i32,
+        alpha,
+        a.as_data(),
+        b.as_data(),
+        beta,
+        c.as_mut_data(),
+    );
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_mat_mul_ex, Matrix};
+    use crate::mat_approx_eq;
+
+    #[test]
+    fn mat_mat_mul_ex_fails_on_wrong_dims() {
+        let a_2x3 = Matrix::new(2, 3);
+        let b_3x4 = Matrix::new(3, 4);
+        let b_4x3 = Matrix::new(4, 3);
+        let mut c_2x4 = Matrix::new(2, 4);
+        let mut c_3x4 = Matrix::new(3, 4);
+        assert_eq!(
+            mat_mat_mul_ex(&mut c_2x4, 1.0, &a_2x3, true, &b_3x4, false, 0.0),
+            Err("matrices are incompatible")
+        );
+        assert_eq!(
+            mat_mat_mul_ex(&mut c_2x4, 1.0, &a_2x3, false, &b_3x4, true, 0.0),
+            Err("matrices are incompatible")
+        );
+        assert_eq!(
+            mat_mat_mul_ex(&mut c_3x4, 1.0, &a_2x3, false, &b_3x4, false, 0.0),
+            Err("matrices are incompatible")
+        );
+        mat_mat_mul_ex(&mut c_2x4, 1.0, &a_2x3, false, &b_3x4, false, 0.0).unwrap();
+        mat_mat_mul_ex(&mut c_2x4, 1.0, &a_2x3, false, &b_4x3, true, 0.0).unwrap();
+    }
+
+    #[test]
+    fn mat_mat_mul_ex_works() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0, 2.0],
+            [3.0, 4.0],
+            [5.0, 6.0],
+        ]);
+        #[rustfmt::skip]
+        let b = Matrix::from(&[
+            [-1.0, -2.0, -3.0],
+            [-4.0, -5.0, -6.0],
+        ]);
+        let at = Matrix::from(&[[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]]);
+        let bt = Matrix::from(&[[-1.0, -4.0], [-2.0, -5.0], [-3.0, -6.0]]);
+        #[rustfmt::skip]
+        let a_b = [
+            [ -9.0, -12.0, -15.0],
+            [-19.0, -26.0, -33.0],
+            [-29.0, -40.0, -51.0],
+        ];
+        for (aa, ta) in [(&a, false), (&at, true)] {
+            for (bb, tb) in [(&b, false), (&bt, true)] {
+                let mut c = Matrix::filled(3, 3, 123.0);
+                mat_mat_mul_ex(&mut c, 1.0, aa, ta, bb, tb, 0.0).unwrap();
+                mat_approx_eq(&c, &a_b, 1e-15);
+            }
+        }
+
+        // accumulation: c := 2⋅a⋅b - c
+        let mut c = Matrix::filled(3, 3, 1.0);
+        mat_mat_mul_ex(&mut c, 2.0, &a, false, &b, false, -1.0).unwrap();
+        #[rustfmt::skip]
+        let correct = [
+            [-19.0, -25.0, -31.0],
+            [-39.0, -53.0, -67.0],
+            [-59.0, -81.0, -103.0],
+        ];
+        mat_approx_eq(&c, &correct, 1e-15);
+
+        // k = 0 (only scales c)
+        let mut c = Matrix::filled(2, 2, 3.0);
+        mat_mat_mul_ex(&mut c, 1.0, &Matrix::new(0, 2), true, &Matrix::new(0, 2), false, 2.0).unwrap();
+        mat_approx_eq(&c, &[[6.0, 6.0], [6.0, 6.0]], 1e-15);
+    }
+}
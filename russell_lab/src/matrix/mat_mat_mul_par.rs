@@ -0,0 +1,177 @@
+use super::Matrix;
+use crate::StrError;
+use rayon::prelude::*;
+
+/// Number of columns of c computed by each parallel task
+const BLOCK_COLS: usize = 16;
+
+/// Number of rows of a (and c) in a cache block
+const BLOCK_ROWS: usize = 256;
+
+/// Number of columns of a (and rows of b) in a cache block
+const BLOCK_INNER: usize = 128;
+
+/// Performs the matrix-matrix multiplication resulting in a matrix (parallel, pure Rust version)
+///
+/// ```text
+///   c  :=  α ⋅  a   ⋅   b
+/// (m,n)       (m,k)   (k,n)
+/// ```
+///
+/// This function implements a cache-blocked multiplication in Rust; the columns of `c` are split
+/// into blocks which are computed in parallel by the rayon thread pool. It does not call BLAS;
+/// thus, it is useful when the BLAS library is single-threaded, untuned, or unavailable
+/// (e.g., with the `pure_rust` feature). Otherwise, [crate::mat_mat_mul] is usually faster.
+///
+/// The number of threads is selected at runtime as in any rayon code; e.g., by setting the
+/// `RAYON_NUM_THREADS` environment variable or by calling this function within `ThreadPool::install`.
+///
+/// **Note:** This function is only available with the `rayon` feature.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_mat_mul_par, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [1.0, 2.0],
+///         [3.0, 4.0],
+///         [5.0, 6.0],
+///     ]);
+///     let b = Matrix::from(&[
+///         [-1.0, -2.0, -3.0],
+///         [-4.0, -5.0, -6.0],
+///     ]);
+///     let mut c = Matrix::new(3, 3);
+///     mat_mat_mul_par(&mut c, 1.0, &a, &b)?;
+///     let correct = "┌             ┐\n\
+///                    │  -9 -12 -15 │\n\
+///                    │ -19 -26 -33 │\n\
+///                    │ -29 -40 -51 │\n\
+///                    └             ┘";
+///     assert_eq!(format!("{}", c), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_mat_mul_par(c: &mut Matrix, alpha: f64, a: &Matrix, b: &Matrix) -> Result<(), StrError> {
+    let (m, n) = c.dims();
+    let k = a.ncol();
+    if a.nrow() != m || b.nrow() != k || b.ncol() != n {
+        return Err("matrices are incompatible");
+    }
+    if m == 0 || n == 0 {
+        return Ok(());
+    }
+    let a_data = a.as_data();
+    let b_data = b.as_data();
+    c.as_mut_data()
+        .par_chunks_mut(m * BLOCK_COLS)
+        .enumerate()
+        .for_each(|(block, c_block)| {
+            c_block.fill(0.0);
+            let j0 = block * BLOCK_COLS;
+            for l0 in (0..k).step_by(BLOCK_INNER) {
+                let l1 = usize::min(l0 + BLOCK_INNER, k);
+                for i0 in (0..m).step_by(BLOCK_ROWS) {
+                    let i1 = usize::min(i0 + BLOCK_ROWS, m);
+                    for (jj, c_col) in c_block.chunks_mut(m).enumerate() {
+                        let j = j0 + jj;
+                        let c_col = &mut c_col[i0..i1];
+                        for l in l0..l1 {
+                            let blj = alpha * b_data[l + j * k];
+                            if blj == 0.0 {
+                                continue;
+                            }
+                            let a_col = &a_data[(i0 + l * m)..(i1 + l * m)];
+                            for (cij, ail) in c_col.iter_mut().zip(a_col) {
+                                *cij += ail * blj;
+                            }
+                        }
+                    }
+                }
+            }
+        });
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::mat_mat_mul_par;
+    use crate::{mat_approx_eq, mat_mat_mul, Matrix};
+
+    #[test]
+    fn mat_mat_mul_par_fails_on_wrong_dims() {
+        let a_2x1 = Matrix::new(2, 1);
+        let a_1x2 = Matrix::new(1, 2);
+        let b_2x1 = Matrix::new(2, 1);
+        let b_1x3 = Matrix::new(1, 3);
+        let mut c_2x2 = Matrix::new(2, 2);
+        assert_eq!(
+            mat_mat_mul_par(&mut c_2x2, 1.0, &a_2x1, &b_2x1),
+            Err("matrices are incompatible")
+        );
+        assert_eq!(
+            mat_mat_mul_par(&mut c_2x2, 1.0, &a_1x2, &b_2x1),
+            Err("matrices are incompatible")
+        );
+        assert_eq!(
+            mat_mat_mul_par(&mut c_2x2, 1.0, &a_2x1, &b_1x3),
+            Err("matrices are incompatible")
+        );
+    }
+
+    #[test]
+    fn mat_mat_mul_par_works() {
+        let a = Matrix::from(&[
+            //
+            [1.0, 2.0],
+            [3.0, 4.0],
+            [5.0, 6.0],
+        ]);
+        let b = Matrix::from(&[
+            //
+            [-1.0, -2.0, -3.0],
+            [-4.0, -5.0, -6.0],
+        ]);
+        let mut c = Matrix::filled(3, 3, 123.0);
+        mat_mat_mul_par(&mut c, 2.0, &a, &b).unwrap();
+        #[rustfmt::skip]
+        let correct = &[
+            [-18.0, -24.0, -30.0],
+            [-38.0, -52.0, -66.0],
+            [-58.0, -80.0, -102.0],
+        ];
+        mat_approx_eq(&c, correct, 1e-15);
+
+        // k = 0 yields a zero matrix
+        let mut c = Matrix::filled(2, 2, 1.0);
+        mat_mat_mul_par(&mut c, 1.0, &Matrix::new(2, 0), &Matrix::new(0, 2)).unwrap();
+        assert_eq!(c.as_data(), &[0.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn mat_mat_mul_par_matches_blas() {
+        // dimensions not multiple of the block sizes
+        let (m, k, n) = (300, 150, 37);
+        let mut a = Matrix::new(m, k);
+        let mut b = Matrix::new(k, n);
+        for i in 0..m {
+            for j in 0..k {
+                a.set(i, j, f64::sin((i * k + j) as f64));
+            }
+        }
+        for i in 0..k {
+            for j in 0..n {
+                b.set(i, j, f64::cos((i * n + j) as f64));
+            }
+        }
+        let mut c = Matrix::new(m, n);
+        let mut c_ref = Matrix::new(m, n);
+        mat_mat_mul_par(&mut c, 0.5, &a, &b).unwrap();
+        mat_mat_mul(&mut c_ref, 0.5, &a, &b).unwrap();
+        mat_approx_eq(&c, &c_ref, 1e-12);
+    }
+}
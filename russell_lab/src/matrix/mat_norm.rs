@@ -18,6 +18,7 @@ use russell_openblas::{dlange, to_i32};
 ///     assert_eq!(mat_norm(&a, Norm::Inf), 5.0);
 ///     assert_eq!(mat_norm(&a, Norm::Fro), 5.0);
 ///     assert_eq!(mat_norm(&a, Norm::Max), 4.0);
+///     assert_eq!(mat_norm(&a, Norm::P(1.0)), 9.0);
 /// }
 /// ```
 pub fn mat_norm(a: &Matrix, kind: Norm) -> f64 {
@@ -25,13 +26,18 @@ pub fn mat_norm(a: &Matrix, kind: Norm) -> f64 {
     if m == 0 || n == 0 {
         return 0.0;
     }
+    let (m_i32, n_i32) = (to_i32(m), to_i32(n));
     let norm = match kind {
         Norm::Euc | Norm::Fro => b'F',
         Norm::Inf => b'I',
         Norm::Max => b'M',
         Norm::One => b'1',
+        Norm::P(p) => {
+            let sum: f64 = a.as_data().iter().map(|x| f64::powf(f64::abs(*x), p)).sum();
+            return f64::powf(sum, 1.0 / p);
+        }
+        Norm::Rms => return dlange(b'F', m_i32, n_i32, a.as_data()) / f64::sqrt((m * n) as f64),
     };
-    let (m_i32, n_i32) = (to_i32(m), to_i32(n));
     dlange(norm, m_i32, n_i32, &a.as_data())
 }
 
@@ -53,6 +59,8 @@ mod tests {
         assert_eq!(mat_norm(&a_0x0, Norm::Euc), 0.0);
         assert_eq!(mat_norm(&a_0x0, Norm::Fro), 0.0);
         assert_eq!(mat_norm(&a_0x0, Norm::Max), 0.0);
+        assert_eq!(mat_norm(&a_0x0, Norm::P(3.0)), 0.0);
+        assert_eq!(mat_norm(&a_0x0, Norm::Rms), 0.0);
         assert_eq!(mat_norm(&a_0x1, Norm::One), 0.0);
         assert_eq!(mat_norm(&a_0x1, Norm::Inf), 0.0);
         assert_eq!(mat_norm(&a_0x1, Norm::Fro), 0.0);
@@ -72,6 +80,10 @@ mod tests {
         assert_eq!(mat_norm(&a, Norm::Euc), 8.0);
         assert_eq!(mat_norm(&a, Norm::Fro), 8.0);
         assert_eq!(mat_norm(&a, Norm::Max), 5.0);
+        approx_eq(mat_norm(&a, Norm::P(1.0)), 20.0, 1e-15);
+        approx_eq(mat_norm(&a, Norm::P(2.0)), 8.0, 1e-15);
+        approx_eq(mat_norm(&a, Norm::P(3.0)), f64::cbrt(242.0), 1e-14);
+        approx_eq(mat_norm(&a, Norm::Rms), 8.0 / 3.0, 1e-15);
 
         // example from https://netlib.org/lapack/lug/node75.html
         #[rustfmt::skip]
@@ -0,0 +1,113 @@
+use super::mat_rank::rank_from_singular_values;
+use super::{mat_svd, Matrix};
+use crate::{StrError, SvdMode, Vector};
+
+/// Computes an orthonormal basis of the null space (kernel) of a matrix
+///
+/// Returns the (n,n-r) matrix `z` whose columns are orthonormal and satisfy:
+///
+/// ```text
+/// a ⋅ z = 0
+/// ```
+///
+/// where `r` is the numerical rank of `a` (see [crate::mat_rank]). The basis is given by the
+/// right singular vectors of `a` corresponding to the negligible singular values.
+///
+/// # Input
+///
+/// * `a` -- (m,n) matrix
+/// * `tol` -- tolerance for the singular values; if None, uses `max(m,n) ⋅ ε ⋅ σ_max` where
+///   `ε` is the machine epsilon and `σ_max` is the largest singular value
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_mat_mul, mat_norm, mat_null_space, Matrix, Norm, StrError};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [1.0, 1.0, 0.0],
+///         [0.0, 0.0, 1.0],
+///     ]);
+///     let z = mat_null_space(&a, None)?;
+///     assert_eq!(z.dims(), (3, 1));
+///
+///     // check that a ⋅ z = 0
+///     let mut az = Matrix::new(2, 1);
+///     mat_mat_mul(&mut az, 1.0, &a, &z)?;
+///     assert!(mat_norm(&az, Norm::Max) < 1e-15);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+pub fn mat_null_space(a: &Matrix, tol: Option<f64>) -> Result<Matrix, StrError> {
+    let (m, n) = a.dims();
+    let min_mn = usize::min(m, n);
+    if min_mn == 0 {
+        let mut z = Matrix::new(n, n);
+        for i in 0..n {
+            z.set(i, i, 1.0);
+        }
+        return Ok(z);
+    }
+    let mut s = Vector::new(min_mn);
+    let mut u = Matrix::new(m, m);
+    let mut vt = Matrix::new(n, n);
+    let mut a_copy = a.clone();
+    mat_svd(&mut s, &mut u, &mut vt, &mut a_copy, SvdMode::Full)?;
+    let rank = rank_from_singular_values(&s, m, n, tol);
+    let mut z = Matrix::new(n, n - rank);
+    for j in 0..(n - rank) {
+        for i in 0..n {
+            z.set(i, j, vt.get(rank + j, i));
+        }
+    }
+    Ok(z)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(unused_imports))]
+mod tests {
+    use super::mat_null_space;
+    use crate::{check_orthogonal, mat_approx_eq, mat_mat_mul, mat_norm, Matrix, Norm};
+
+    #[test]
+    fn mat_null_space_handles_empty_matrices() {
+        let z = mat_null_space(&Matrix::new(0, 2), None).unwrap();
+        mat_approx_eq(&z, &[[1.0, 0.0], [0.0, 1.0]], 1e-15);
+        let z = mat_null_space(&Matrix::new(2, 0), None).unwrap();
+        assert_eq!(z.dims(), (0, 0));
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn mat_null_space_works() {
+        // rank 2
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0, 2.0, 3.0, 4.0],
+            [2.0, 4.0, 6.0, 8.0],
+            [0.0, 1.0, 0.0, 1.0],
+        ]);
+        let z = mat_null_space(&a, None).unwrap();
+        assert_eq!(z.dims(), (4, 2));
+        check_orthogonal(&z, 1e-14);
+        let mut az = Matrix::new(3, 2);
+        mat_mat_mul(&mut az, 1.0, &a, &z).unwrap();
+        assert!(mat_norm(&az, Norm::Max) < 1e-14);
+
+        // full rank
+        let a = Matrix::from(&[[2.0, 1.0], [1.0, 3.0]]);
+        let z = mat_null_space(&a, None).unwrap();
+        assert_eq!(z.dims(), (2, 0));
+
+        // tolerance
+        let a = Matrix::from(&[[1.0, 0.0], [0.0, 1e-10]]);
+        let z = mat_null_space(&a, Some(1e-8)).unwrap();
+        assert_eq!(z.dims(), (2, 1));
+        assert!(f64::abs(f64::abs(z.get(1, 0)) - 1.0) < 1e-15);
+    }
+}
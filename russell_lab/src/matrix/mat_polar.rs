@@ -0,0 +1,160 @@
+use super::{mat_svd, Matrix};
+use crate::{Error, SvdMode, Vector};
+
+/// Computes the (right) polar decomposition of a square matrix
+///
+/// Finds `r` and `u` such that:
+///
+/// ```text
+/// a = r ⋅ u
+/// ```
+///
+/// where `r` is orthogonal and `u` is symmetric positive semi-definite. The decomposition
+/// is computed via the SVD `a = w ⋅ s ⋅ vᵀ`, giving:
+///
+/// ```text
+/// r = w ⋅ vᵀ
+/// u = v ⋅ s ⋅ vᵀ
+/// ```
+///
+/// In continuum mechanics, with `a` being the deformation gradient, `r` is the rotation
+/// and `u` is the right stretch tensor.
+///
+/// **Note:** If det(a) < 0, then det(r) = -1 (i.e., `r` includes a reflection).
+///
+/// # Output
+///
+/// * `r` -- (m,m) orthogonal matrix
+/// * `u` -- (m,m) symmetric positive semi-definite matrix
+///
+/// # Input
+///
+/// * `a` -- (m,m) matrix
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_approx_eq, mat_polar, Matrix, StrError};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     // deformation gradient: stretch along x followed by a rotation of 90 degrees
+///     let a = Matrix::from(&[
+///         [0.0, -1.0],
+///         [2.0,  0.0],
+///     ]);
+///
+///     // compute the polar decomposition
+///     let mut r = Matrix::new(2, 2);
+///     let mut u = Matrix::new(2, 2);
+///     mat_polar(&mut r, &mut u, &a)?;
+///
+///     // check
+///     mat_approx_eq(&r, &[[0.0, -1.0], [1.0, 0.0]], 1e-15);
+///     mat_approx_eq(&u, &[[2.0, 0.0], [0.0, 1.0]], 1e-15);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+pub fn mat_polar(r: &mut Matrix, u: &mut Matrix, a: &Matrix) -> Result<(), Error> {
+    // check
+    let (m, n) = a.dims();
+    if m != n {
+        return Err(Error::BadDimensions("matrix must be square"));
+    }
+    if r.nrow() != m || r.ncol() != m || u.nrow() != m || u.ncol() != m {
+        return Err(Error::BadDimensions("matrices are incompatible"));
+    }
+    if m == 0 {
+        return Ok(());
+    }
+
+    // compute the SVD: a = w ⋅ s ⋅ vᵀ
+    let mut s = Vector::new(m);
+    let mut w = Matrix::new(m, m);
+    let mut vt = Matrix::new(m, m);
+    let mut a_copy = a.clone();
+    mat_svd(&mut s, &mut w, &mut vt, &mut a_copy, SvdMode::Full)?;
+
+    // r = w ⋅ vᵀ and u = v ⋅ s ⋅ vᵀ
+    for i in 0..m {
+        for j in 0..m {
+            let mut rij = 0.0;
+            let mut uij = 0.0;
+            for k in 0..m {
+                rij += w.get(i, k) * vt.get(k, j);
+                uij += vt.get(k, i) * s[k] * vt.get(k, j);
+            }
+            r.set(i, j, rij);
+            u.set(i, j, uij);
+        }
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(unused_imports))]
+mod tests {
+    use super::mat_polar;
+    use crate::{check_orthogonal, check_symmetric, mat_approx_eq, mat_cholesky, mat_mat_mul, Error, Matrix};
+
+    #[test]
+    fn mat_polar_fails_on_wrong_dims() {
+        let mut r = Matrix::new(2, 2);
+        let mut u = Matrix::new(2, 2);
+        let mut wrong = Matrix::new(3, 3);
+        assert_eq!(
+            mat_polar(&mut r, &mut u, &Matrix::new(2, 3)),
+            Err(Error::BadDimensions("matrix must be square"))
+        );
+        let a = Matrix::new(2, 2);
+        assert_eq!(
+            mat_polar(&mut wrong, &mut u, &a),
+            Err(Error::BadDimensions("matrices are incompatible"))
+        );
+        assert_eq!(
+            mat_polar(&mut r, &mut wrong, &a),
+            Err(Error::BadDimensions("matrices are incompatible"))
+        );
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn mat_polar_works() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.2, 0.3, 0.1],
+            [0.2, 0.9, 0.4],
+            [0.0, 0.1, 1.1],
+        ]);
+        let mut r = Matrix::new(3, 3);
+        let mut u = Matrix::new(3, 3);
+        mat_polar(&mut r, &mut u, &a).unwrap();
+
+        // check that a = r ⋅ u
+        let mut ru = Matrix::new(3, 3);
+        mat_mat_mul(&mut ru, 1.0, &r, &u).unwrap();
+        mat_approx_eq(&ru, &a, 1e-14);
+
+        // check that r is orthogonal and u is symmetric positive-definite (a is not singular)
+        check_orthogonal(&r, 1e-14);
+        check_symmetric(&u, 1e-14);
+        let mut l = Matrix::new(3, 3);
+        mat_cholesky(&mut l, &u).unwrap();
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn mat_polar_rotation_works() {
+        // pure rotation: r = a and u = identity
+        let (c, s) = (f64::cos(0.3), f64::sin(0.3));
+        let a = Matrix::from(&[[c, -s], [s, c]]);
+        let mut r = Matrix::new(2, 2);
+        let mut u = Matrix::new(2, 2);
+        mat_polar(&mut r, &mut u, &a).unwrap();
+        mat_approx_eq(&r, &a, 1e-15);
+        mat_approx_eq(&u, &[[1.0, 0.0], [0.0, 1.0]], 1e-15);
+    }
+}
@@ -0,0 +1,291 @@
+use super::{mat_copy, mat_eigen_sym, mat_inverse, mat_mat_mul, Matrix};
+use crate::{StrError, Vector};
+
+/// Computes the integer power of a square matrix
+///
+/// ```text
+/// c := aⁿ
+/// ```
+///
+/// The power is computed by binary exponentiation (repeated squaring); thus, about `2⋅log₂(n)`
+/// matrix-matrix multiplications are performed. Two (m,m) work matrices are allocated and
+/// reused throughout the computation.
+///
+/// **Note:** If `n` is negative, the power of the inverse matrix is computed; moreover, `a⁰ = I`.
+///
+/// # Output
+///
+/// * `c` -- (m,m) the resulting matrix
+///
+/// # Input
+///
+/// * `a` -- (m,m) matrix
+/// * `n` -- the exponent
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_powi, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // Fibonacci numbers
+///     let a = Matrix::from(&[
+///         [1.0, 1.0],
+///         [1.0, 0.0],
+///     ]);
+///     let mut c = Matrix::new(2, 2);
+///     mat_powi(&mut c, &a, 10)?;
+///     let correct = "┌       ┐\n\
+///                    │ 89 55 │\n\
+///                    │ 55 34 │\n\
+///                    └       ┘";
+///     assert_eq!(format!("{}", c), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_powi(c: &mut Matrix, a: &Matrix, n: i32) -> Result<(), StrError> {
+    // check
+    let (m, mm) = a.dims();
+    if m != mm {
+        return Err("matrix must be square");
+    }
+    if c.nrow() != m || c.ncol() != m {
+        return Err("matrices are incompatible");
+    }
+    if m == 0 {
+        return Ok(());
+    }
+
+    // base := a or a⁻¹
+    let mut base = Matrix::new(m, m);
+    if n < 0 {
+        mat_inverse(&mut base, a)?;
+    } else {
+        mat_copy(&mut base, a)?;
+    }
+
+    // c := I
+    c.fill(0.0);
+    for i in 0..m {
+        c.set(i, i, 1.0);
+    }
+
+    // repeated squaring
+    let mut work = Matrix::new(m, m);
+    let mut e = n.unsigned_abs();
+    while e > 0 {
+        if e & 1 == 1 {
+            mat_mat_mul(&mut work, 1.0, c, &base)?;
+            std::mem::swap(c, &mut work);
+        }
+        e >>= 1;
+        if e > 0 {
+            mat_mat_mul(&mut work, 1.0, &base, &base)?;
+            std::mem::swap(&mut base, &mut work);
+        }
+    }
+    Ok(())
+}
+
+/// Computes the real power of a symmetric matrix
+///
+/// ```text
+/// c := aᵖ = v ⋅ diag(λᵢᵖ) ⋅ vᵀ
+/// ```
+///
+/// where `λᵢ` are the eigenvalues and `v` holds the eigenvectors (as columns) of `a`.
+///
+/// **Important:** The matrix must be symmetric (this is not checked). Furthermore, if `p`
+/// is not an integer, the eigenvalues must be non-negative; and if `p` is negative,
+/// the eigenvalues must be non-zero.
+///
+/// # Output
+///
+/// * `c` -- (m,m) the resulting matrix (symmetric)
+///
+/// # Input
+///
+/// * `a` -- (m,m) symmetric matrix
+/// * `p` -- the exponent
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_approx_eq, mat_powf, Matrix, StrError};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     // square root of a symmetric positive-definite matrix
+///     let a = Matrix::from(&[
+///         [5.0, 4.0],
+///         [4.0, 5.0],
+///     ]);
+///     let mut c = Matrix::new(2, 2);
+///     mat_powf(&mut c, &a, 0.5)?;
+///     mat_approx_eq(&c, &[[2.0, 1.0], [1.0, 2.0]], 1e-14);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+pub fn mat_powf(c: &mut Matrix, a: &Matrix, p: f64) -> Result<(), StrError> {
+    // check
+    let (m, mm) = a.dims();
+    if m != mm {
+        return Err("matrix must be square");
+    }
+    if c.nrow() != m || c.ncol() != m {
+        return Err("matrices are incompatible");
+    }
+    if m == 0 {
+        return Ok(());
+    }
+
+    // eigen-decomposition: a = v ⋅ diag(λ) ⋅ vᵀ
+    let mut l = Vector::new(m);
+    let mut v = a.clone();
+    mat_eigen_sym(&mut l, &mut v)?;
+
+    // powers of the eigenvalues
+    let integer = p == f64::trunc(p);
+    for k in 0..m {
+        if l[k] < 0.0 && !integer {
+            return Err("the eigenvalues must be non-negative for a non-integer exponent");
+        }
+        if l[k] == 0.0 && p < 0.0 {
+            return Err("the matrix must be non-singular for a negative exponent");
+        }
+        l[k] = f64::powf(l[k], p);
+    }
+
+    // c := v ⋅ diag(λᵖ) ⋅ vᵀ
+    for i in 0..m {
+        for j in 0..m {
+            let mut cij = 0.0;
+            for k in 0..m {
+                cij += v.get(i, k) * l[k] * v.get(j, k);
+            }
+            c.set(i, j, cij);
+        }
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_powf, mat_powi};
+    use crate::{mat_approx_eq, mat_mat_mul, Matrix};
+
+    #[test]
+    fn mat_powi_fails_on_wrong_dims() {
+        let mut c = Matrix::new(2, 2);
+        assert_eq!(mat_powi(&mut c, &Matrix::new(2, 3), 2), Err("matrix must be square"));
+        assert_eq!(
+            mat_powi(&mut c, &Matrix::new(3, 3), 2),
+            Err("matrices are incompatible")
+        );
+        assert_eq!(mat_powf(&mut c, &Matrix::new(2, 3), 2.0), Err("matrix must be square"));
+        assert_eq!(
+            mat_powf(&mut c, &Matrix::new(3, 3), 2.0),
+            Err("matrices are incompatible")
+        );
+    }
+
+    #[test]
+    fn mat_powi_works() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0, 2.0, 0.0],
+            [0.0, 1.0, 3.0],
+            [1.0, 0.0, 1.0],
+        ]);
+        let mut c = Matrix::new(3, 3);
+
+        // zero and one
+        mat_powi(&mut c, &a, 0).unwrap();
+        mat_approx_eq(&c, &Matrix::identity(3), 1e-15);
+        mat_powi(&mut c, &a, 1).unwrap();
+        mat_approx_eq(&c, &a, 1e-15);
+
+        // compare with repeated multiplication
+        let mut correct = Matrix::identity(3);
+        let mut work = Matrix::new(3, 3);
+        for n in 1..=7 {
+            mat_mat_mul(&mut work, 1.0, &correct, &a).unwrap();
+            std::mem::swap(&mut correct, &mut work);
+            mat_powi(&mut c, &a, n).unwrap();
+            mat_approx_eq(&c, &correct, 1e-12);
+        }
+
+        // negative power: a⁻² ⋅ a² = I
+        let mut ai2 = Matrix::new(3, 3);
+        let mut a2 = Matrix::new(3, 3);
+        mat_powi(&mut ai2, &a, -2).unwrap();
+        mat_powi(&mut a2, &a, 2).unwrap();
+        mat_mat_mul(&mut c, 1.0, &ai2, &a2).unwrap();
+        mat_approx_eq(&c, &Matrix::identity(3), 1e-14);
+    }
+
+    #[test]
+    fn mat_powi_markov_chain_works() {
+        // the rows of the transition matrix converge to the stationary distribution [2/3, 1/3]
+        #[rustfmt::skip]
+        let p = Matrix::from(&[
+            [0.9, 0.1],
+            [0.2, 0.8],
+        ]);
+        let mut c = Matrix::new(2, 2);
+        mat_powi(&mut c, &p, 200).unwrap();
+        let r = 2.0 / 3.0;
+        mat_approx_eq(&c, &[[r, 1.0 - r], [r, 1.0 - r]], 1e-14);
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn mat_powf_works() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [2.0, 1.0, 0.0],
+            [1.0, 2.0, 1.0],
+            [0.0, 1.0, 2.0],
+        ]);
+        let mut c = Matrix::new(3, 3);
+
+        // integer power equals mat_powi
+        let mut correct = Matrix::new(3, 3);
+        mat_powf(&mut c, &a, 3.0).unwrap();
+        mat_powi(&mut correct, &a, 3).unwrap();
+        mat_approx_eq(&c, &correct, 1e-13);
+
+        // square root squared equals a
+        let mut sqrt_a = Matrix::new(3, 3);
+        mat_powf(&mut sqrt_a, &a, 0.5).unwrap();
+        mat_mat_mul(&mut c, 1.0, &sqrt_a, &sqrt_a).unwrap();
+        mat_approx_eq(&c, &a, 1e-14);
+
+        // inverse
+        let mut ai = Matrix::new(3, 3);
+        mat_powf(&mut ai, &a, -1.0).unwrap();
+        mat_mat_mul(&mut c, 1.0, &a, &ai).unwrap();
+        mat_approx_eq(&c, &Matrix::identity(3), 1e-14);
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn mat_powf_captures_errors() {
+        let mut c = Matrix::new(2, 2);
+        let a = Matrix::from(&[[1.0, 0.0], [0.0, -1.0]]);
+        assert_eq!(
+            mat_powf(&mut c, &a, 0.5),
+            Err("the eigenvalues must be non-negative for a non-integer exponent")
+        );
+        mat_powf(&mut c, &a, 2.0).unwrap();
+        mat_approx_eq(&c, &Matrix::identity(2), 1e-15);
+        let a = Matrix::from(&[[1.0, 0.0], [0.0, 0.0]]);
+        assert_eq!(
+            mat_powf(&mut c, &a, -1.0),
+            Err("the matrix must be non-singular for a negative exponent")
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use super::Matrix;
+use crate::Error;
+use russell_openblas::{dgeqrf, dgeqrf_lwork, dorgqr, dorgqr_lwork, to_i32};
+
+/// Computes the QR decomposition of a matrix
+///
+/// Finds `q` and `r` such that:
+///
+/// ```text
+///   a  =   q   ⋅   r
+/// (m,n)  (m,m)   (m,n)     full
+///
+///   a  =   q   ⋅   r
+/// (m,n)  (m,k)   (k,n)     economy, with k = min(m,n)
+/// ```
+///
+/// where `q` has orthonormal columns and `r` is upper triangular (trapezoidal if m < n).
+/// The economy-size decomposition is smaller than the full one if m > n.
+///
+/// # Output
+///
+/// * `q` -- (m,m) orthogonal matrix or (m,k) matrix with orthonormal columns if `economy`
+/// * `r` -- (m,n) upper triangular matrix or (k,n) matrix if `economy`
+///
+/// # Input
+///
+/// * `a` -- (m,n) matrix
+/// * `economy` -- computes the economy-size decomposition
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_approx_eq, mat_mat_mul, mat_qr, Matrix, StrError};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     // set matrix
+///     let a = Matrix::from(&[
+///         [12.0, -51.0,   4.0],
+///         [ 6.0, 167.0, -68.0],
+///         [-4.0,  24.0, -41.0],
+///         [ 1.0,   1.0,   1.0],
+///     ]);
+///
+///     // economy-size decomposition
+///     let (m, n) = a.dims();
+///     let mut q = Matrix::new(m, n);
+///     let mut r = Matrix::new(n, n);
+///     mat_qr(&mut q, &mut r, &a, true)?;
+///
+///     // check that a = q⋅r
+///     let mut q_r = Matrix::new(m, n);
+///     mat_mat_mul(&mut q_r, 1.0, &q, &r)?;
+///     mat_approx_eq(&q_r, &a, 1e-13);
+///
+///     // r is upper triangular
+///     assert_eq!(r.get(1, 0), 0.0);
+///     assert_eq!(r.get(2, 0), 0.0);
+///     assert_eq!(r.get(2, 1), 0.0);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+pub fn mat_qr(q: &mut Matrix, r: &mut Matrix, a: &Matrix, economy: bool) -> Result<(), Error> {
+    // check
+    let (m, n) = a.dims();
+    let k = usize::min(m, n);
+    let (q_ncol, r_nrow) = if economy { (k, k) } else { (m, m) };
+    if q.nrow() != m || q.ncol() != q_ncol || r.nrow() != r_nrow || r.ncol() != n {
+        return Err(Error::BadDimensions("matrices are incompatible"));
+    }
+
+    // factorize a copy of a
+    let (m_i32, n_i32, k_i32) = (to_i32(m), to_i32(n), to_i32(k));
+    let mut qr = a.as_data().clone();
+    let mut tau = vec![0.0; k];
+    let mut work = vec![0.0; dgeqrf_lwork(m_i32, n_i32)?];
+    dgeqrf(m_i32, n_i32, &mut qr, &mut tau, &mut work)?;
+
+    // extract r (on and above the diagonal)
+    for i in 0..r_nrow {
+        for j in 0..n {
+            r.set(i, j, if i <= j { qr[i + j * m] } else { 0.0 });
+        }
+    }
+
+    // generate q from the reflectors (in the first k columns)
+    let q_data = q.as_mut_data();
+    q_data.fill(0.0);
+    q_data[..(m * k)].copy_from_slice(&qr[..(m * k)]);
+    let q_ncol_i32 = to_i32(q_ncol);
+    let mut work = vec![0.0; dorgqr_lwork(m_i32, q_ncol_i32, k_i32)?];
+    dorgqr(m_i32, q_ncol_i32, k_i32, q_data, &tau, &mut work)?;
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(dead_code))]
+mod tests {
+    use super::mat_qr;
+    use crate::{check_orthogonal, mat_approx_eq, mat_mat_mul, Error, Matrix};
+
+    // checks that a = q⋅r, that r is upper triangular, and that q has orthonormal columns
+    fn check_qr(a: &Matrix, q: &Matrix, r: &Matrix, tol: f64) {
+        let mut q_r = Matrix::new(a.nrow(), a.ncol());
+        mat_mat_mul(&mut q_r, 1.0, q, r).unwrap();
+        mat_approx_eq(&q_r, a, tol);
+        for i in 0..r.nrow() {
+            for j in 0..usize::min(i, r.ncol()) {
+                assert_eq!(r.get(i, j), 0.0);
+            }
+        }
+        check_orthogonal(q, tol);
+    }
+
+    #[test]
+    fn mat_qr_fails_on_wrong_dims() {
+        let a = Matrix::new(3, 2);
+        let mut q = Matrix::new(3, 3);
+        let mut r = Matrix::new(3, 2);
+        let mut q_wrong = Matrix::new(3, 2);
+        let mut r_wrong = Matrix::new(2, 2);
+        assert_eq!(
+            mat_qr(&mut q_wrong, &mut r, &a, false),
+            Err(Error::BadDimensions("matrices are incompatible"))
+        );
+        assert_eq!(
+            mat_qr(&mut q, &mut r_wrong, &a, false),
+            Err(Error::BadDimensions("matrices are incompatible"))
+        );
+        assert_eq!(
+            mat_qr(&mut q, &mut r_wrong, &a, true),
+            Err(Error::BadDimensions("matrices are incompatible"))
+        );
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn mat_qr_works() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [12.0, -51.0,   4.0],
+            [ 6.0, 167.0, -68.0],
+            [-4.0,  24.0, -41.0],
+        ]);
+        let mut q = Matrix::new(3, 3);
+        let mut r = Matrix::new(3, 3);
+        mat_qr(&mut q, &mut r, &a, false).unwrap();
+        check_qr(&a, &q, &r, 1e-13);
+        // the factorization is unique up to the signs of the rows of r
+        let r_correct = [[14.0, 21.0, -14.0], [0.0, 175.0, -70.0], [0.0, 0.0, 35.0]];
+        for (i, row) in r_correct.iter().enumerate() {
+            let sign = f64::signum(r.get(i, i));
+            for (j, value) in row.iter().enumerate() {
+                assert!(f64::abs(sign * r.get(i, j) - value) < 1e-13);
+            }
+        }
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn mat_qr_rectangular_works() {
+        #[rustfmt::skip]
+        let tall = Matrix::from(&[
+            [1.0, 2.0],
+            [3.0, 4.0],
+            [5.0, 6.0],
+            [7.0, 8.0],
+        ]);
+        let wide = Matrix::from(&[[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]]);
+
+        // full
+        let (mut q, mut r) = (Matrix::new(4, 4), Matrix::new(4, 2));
+        mat_qr(&mut q, &mut r, &tall, false).unwrap();
+        check_qr(&tall, &q, &r, 1e-14);
+        let (mut q, mut r) = (Matrix::new(2, 2), Matrix::new(2, 4));
+        mat_qr(&mut q, &mut r, &wide, false).unwrap();
+        check_qr(&wide, &q, &r, 1e-14);
+
+        // economy
+        let (mut q, mut r) = (Matrix::new(4, 2), Matrix::new(2, 2));
+        mat_qr(&mut q, &mut r, &tall, true).unwrap();
+        check_qr(&tall, &q, &r, 1e-14);
+        let (mut q, mut r) = (Matrix::new(2, 2), Matrix::new(2, 4));
+        mat_qr(&mut q, &mut r, &wide, true).unwrap();
+        check_qr(&wide, &q, &r, 1e-14);
+    }
+}
@@ -0,0 +1,89 @@
+use super::{mat_svd, Matrix};
+use crate::{StrError, SvdMode, Vector};
+
+/// Computes the numerical rank of a matrix
+///
+/// The rank is the number of singular values greater than the tolerance.
+///
+/// # Input
+///
+/// * `a` -- (m,n) matrix
+/// * `tol` -- tolerance; if None, uses `max(m,n) ⋅ ε ⋅ σ_max` where `ε` is the machine
+///   epsilon and `σ_max` is the largest singular value
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_rank, Matrix, StrError};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [1.0, 2.0, 3.0],
+///         [2.0, 4.0, 6.0],
+///         [1.0, 0.0, 1.0],
+///     ]);
+///     assert_eq!(mat_rank(&a, None)?, 2);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+pub fn mat_rank(a: &Matrix, tol: Option<f64>) -> Result<usize, StrError> {
+    let (m, n) = a.dims();
+    let min_mn = usize::min(m, n);
+    if min_mn == 0 {
+        return Ok(0);
+    }
+    let mut s = Vector::new(min_mn);
+    let mut u = Matrix::new(0, 0);
+    let mut vt = Matrix::new(0, 0);
+    let mut a_copy = a.clone();
+    mat_svd(&mut s, &mut u, &mut vt, &mut a_copy, SvdMode::ValuesOnly)?;
+    Ok(rank_from_singular_values(&s, m, n, tol))
+}
+
+/// Returns the number of singular values (sorted in descending order) greater than the tolerance
+pub(super) fn rank_from_singular_values(s: &Vector, m: usize, n: usize, tol: Option<f64>) -> usize {
+    if s.dim() == 0 {
+        return 0;
+    }
+    let tol = match tol {
+        Some(v) => v,
+        None => (usize::max(m, n) as f64) * f64::EPSILON * s[0],
+    };
+    s.as_data().iter().filter(|&&v| v > tol).count()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(unused_imports))]
+mod tests {
+    use super::{mat_rank, rank_from_singular_values};
+    use crate::{Matrix, Vector};
+
+    #[test]
+    fn rank_from_singular_values_works() {
+        let s = Vector::from(&[3.0, 1e-3, 1e-17]);
+        assert_eq!(rank_from_singular_values(&s, 3, 3, None), 2);
+        assert_eq!(rank_from_singular_values(&s, 3, 3, Some(1e-2)), 1);
+        assert_eq!(rank_from_singular_values(&Vector::new(0), 0, 3, None), 0);
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn mat_rank_works() {
+        assert_eq!(mat_rank(&Matrix::new(0, 3), None), Ok(0));
+        assert_eq!(mat_rank(&Matrix::new(2, 3), None), Ok(0));
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0, 2.0, 3.0, 4.0],
+            [2.0, 4.0, 6.0, 8.0],
+            [0.0, 1.0, 0.0, 1.0],
+        ]);
+        assert_eq!(mat_rank(&a, None), Ok(2));
+        let identity = Matrix::from(&[[1.0, 0.0], [0.0, 1e-10]]);
+        assert_eq!(mat_rank(&identity, None), Ok(2));
+        assert_eq!(mat_rank(&identity, Some(1e-8)), Ok(1));
+    }
+}
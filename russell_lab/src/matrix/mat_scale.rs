@@ -30,7 +30,7 @@ use russell_openblas::{dscal, to_i32};
 /// ```
 pub fn mat_scale(a: &mut Matrix, alpha: f64) {
     let mut data = a.as_mut_data();
-    let n: i32 = to_i32(data.len());
+    let n = to_i32(data.len());
     dscal(n, alpha, &mut data, 1);
 }
 
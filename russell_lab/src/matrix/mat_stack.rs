@@ -0,0 +1,220 @@
+use super::{mat_insert, Matrix};
+use crate::StrError;
+
+/// Stacks matrices horizontally (side by side)
+///
+/// ```text
+/// c := [a₀ a₁ ⋯]
+/// ```
+///
+/// All matrices must have the same number of rows.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_hstack, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[[1.0], [2.0]]);
+///     let b = Matrix::from(&[[3.0, 4.0], [5.0, 6.0]]);
+///     let c = mat_hstack(&[&a, &b])?;
+///     let correct = "┌       ┐\n\
+///                    │ 1 3 4 │\n\
+///                    │ 2 5 6 │\n\
+///                    └       ┘";
+///     assert_eq!(format!("{}", c), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_hstack(matrices: &[&Matrix]) -> Result<Matrix, StrError> {
+    let m = match matrices.first() {
+        Some(a) => a.nrow(),
+        None => return Ok(Matrix::new(0, 0)),
+    };
+    if matrices.iter().any(|a| a.nrow() != m) {
+        return Err("all matrices must have the same number of rows");
+    }
+    let n = matrices.iter().map(|a| a.ncol()).sum();
+    let mut c = Matrix::new(m, n);
+    let mut j0 = 0;
+    for a in matrices {
+        mat_insert(&mut c, 0, j0, 1.0, a, false)?;
+        j0 += a.ncol();
+    }
+    Ok(c)
+}
+
+/// Stacks matrices vertically (one on top of the other)
+///
+/// ```text
+///      ┌    ┐
+///      │ a₀ │
+/// c := │ a₁ │
+///      │ ⋮  │
+///      └    ┘
+/// ```
+///
+/// All matrices must have the same number of columns.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_vstack, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[[1.0, 2.0]]);
+///     let b = Matrix::from(&[[3.0, 4.0], [5.0, 6.0]]);
+///     let c = mat_vstack(&[&a, &b])?;
+///     let correct = "┌     ┐\n\
+///                    │ 1 2 │\n\
+///                    │ 3 4 │\n\
+///                    │ 5 6 │\n\
+///                    └     ┘";
+///     assert_eq!(format!("{}", c), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_vstack(matrices: &[&Matrix]) -> Result<Matrix, StrError> {
+    let n = match matrices.first() {
+        Some(a) => a.ncol(),
+        None => return Ok(Matrix::new(0, 0)),
+    };
+    if matrices.iter().any(|a| a.ncol() != n) {
+        return Err("all matrices must have the same number of columns");
+    }
+    let m = matrices.iter().map(|a| a.nrow()).sum();
+    let mut c = Matrix::new(m, n);
+    let mut i0 = 0;
+    for a in matrices {
+        mat_insert(&mut c, i0, 0, 1.0, a, false)?;
+        i0 += a.nrow();
+    }
+    Ok(c)
+}
+
+/// Assembles a matrix from a grid of blocks
+///
+/// ```text
+///      ┌                ┐
+///      │ a₀₀ a₀₁ ⋯ a₀ₙ  │
+/// c := │ a₁₀ a₁₁ ⋯ a₁ₙ  │
+///      │  ⋮   ⋮  ⋱  ⋮   │
+///      └                ┘
+/// ```
+///
+/// All blocks in the same block-row must have the same number of rows, and all blocks in
+/// the same block-column must have the same number of columns. The result is allocated once.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_block, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // saddle-point matrix [[k, g], [gᵀ, 0]]
+///     let k = Matrix::from(&[[2.0, -1.0], [-1.0, 2.0]]);
+///     let g = Matrix::from(&[[1.0], [1.0]]);
+///     let gt = Matrix::from(&[[1.0, 1.0]]);
+///     let zero = Matrix::new(1, 1);
+///     let c = mat_block(&[[&k, &g], [&gt, &zero]])?;
+///     let correct = "┌          ┐\n\
+///                    │  2 -1  1 │\n\
+///                    │ -1  2  1 │\n\
+///                    │  1  1  0 │\n\
+///                    └          ┘";
+///     assert_eq!(format!("{}", c), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_block<const M: usize, const N: usize>(blocks: &[[&Matrix; N]; M]) -> Result<Matrix, StrError> {
+    if M == 0 || N == 0 {
+        return Ok(Matrix::new(0, 0));
+    }
+    let heights: Vec<usize> = blocks.iter().map(|row| row[0].nrow()).collect();
+    let widths: Vec<usize> = blocks[0].iter().map(|a| a.ncol()).collect();
+    for (row, height) in blocks.iter().zip(&heights) {
+        for (a, width) in row.iter().zip(&widths) {
+            if a.nrow() != *height || a.ncol() != *width {
+                return Err("blocks have incompatible dimensions");
+            }
+        }
+    }
+    let mut c = Matrix::new(heights.iter().sum(), widths.iter().sum());
+    let mut i0 = 0;
+    for (row, height) in blocks.iter().zip(&heights) {
+        let mut j0 = 0;
+        for (a, width) in row.iter().zip(&widths) {
+            mat_insert(&mut c, i0, j0, 1.0, a, false)?;
+            j0 += width;
+        }
+        i0 += height;
+    }
+    Ok(c)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_block, mat_hstack, mat_vstack};
+    use crate::{mat_approx_eq, Matrix};
+
+    #[test]
+    fn stack_functions_handle_errors() {
+        let a = Matrix::new(2, 2);
+        let b = Matrix::new(3, 3);
+        assert_eq!(
+            mat_hstack(&[&a, &b]).err(),
+            Some("all matrices must have the same number of rows")
+        );
+        assert_eq!(
+            mat_vstack(&[&a, &b]).err(),
+            Some("all matrices must have the same number of columns")
+        );
+        assert_eq!(
+            mat_block(&[[&a, &a], [&a, &b]]).err(),
+            Some("blocks have incompatible dimensions")
+        );
+    }
+
+    #[test]
+    fn mat_hstack_and_vstack_work() {
+        assert_eq!(mat_hstack(&[]).unwrap().dims(), (0, 0));
+        assert_eq!(mat_vstack(&[]).unwrap().dims(), (0, 0));
+        let a = Matrix::from(&[[1.0, 2.0], [3.0, 4.0]]);
+        let b = Matrix::from(&[[5.0], [6.0]]);
+        let empty = Matrix::new(2, 0);
+        let c = mat_hstack(&[&a, &empty, &b]).unwrap();
+        mat_approx_eq(&c, &[[1.0, 2.0, 5.0], [3.0, 4.0, 6.0]], 1e-15);
+        let d = Matrix::from(&[[7.0, 8.0]]);
+        let c = mat_vstack(&[&d, &a, &d]).unwrap();
+        #[rustfmt::skip]
+        let correct = &[
+            [7.0, 8.0],
+            [1.0, 2.0],
+            [3.0, 4.0],
+            [7.0, 8.0],
+        ];
+        mat_approx_eq(&c, correct, 1e-15);
+    }
+
+    #[test]
+    fn mat_block_works() {
+        let a = Matrix::from(&[[1.0, 2.0], [3.0, 4.0]]);
+        let b = Matrix::from(&[[5.0], [6.0]]);
+        let c = Matrix::from(&[[7.0, 8.0]]);
+        let d = Matrix::from(&[[9.0]]);
+        let res = mat_block(&[[&a, &b], [&c, &d]]).unwrap();
+        #[rustfmt::skip]
+        let correct = &[
+            [1.0, 2.0, 5.0],
+            [3.0, 4.0, 6.0],
+            [7.0, 8.0, 9.0],
+        ];
+        mat_approx_eq(&res, correct, 1e-15);
+        let res = mat_block(&[[&a, &a, &b]]).unwrap();
+        assert_eq!(res.dims(), (2, 5));
+        let none: [[&Matrix; 0]; 0] = [];
+        assert_eq!(mat_block(&none).unwrap().dims(), (0, 0));
+    }
+}
@@ -1,6 +1,6 @@
 use crate::matrix::Matrix;
 use crate::vector::Vector;
-use crate::StrError;
+use crate::{Error, SvdMode, Workspace};
 use russell_openblas::{dgesvd, to_i32};
 
 /// Computes the singular value decomposition (SVD) of a matrix
@@ -9,18 +9,25 @@ use russell_openblas::{dgesvd, to_i32};
 ///
 /// ```text
 ///   a  :=  u   ⋅   s   ⋅   vᵀ
-/// (m,n)  (m,m)   (m,n)   (n,n)
+/// (m,n)  (m,m)   (m,n)   (n,n)     SvdMode::Full
+///
+///   a  :=  u   ⋅   s   ⋅   vᵀ
+/// (m,n)  (m,k)   (k,k)   (k,n)     SvdMode::Thin, with k = min(m,n)
 /// ```
 ///
+/// For tall (m ≫ n) or wide (m ≪ n) matrices, the thin SVD avoids computing (and storing)
+/// the large orthogonal matrix `u` or `vt`. With `SvdMode::ValuesOnly`, only `s` is computed.
+///
 /// # Output
 ///
 /// * `s` -- min(m,n) vector with the diagonal elements
-/// * `u` -- (m,m) orthogonal matrix
-/// * `vt` -- (n,n) orthogonal matrix with the transpose of v
+/// * `u` -- (m,m) orthogonal matrix (Full); (m,k) matrix with orthonormal columns (Thin); or not accessed (ValuesOnly)
+/// * `vt` -- (n,n) orthogonal matrix with the transpose of v (Full); (k,n) matrix with orthonormal rows (Thin); or not accessed (ValuesOnly)
 ///
 /// # Input
 ///
 /// * `a` -- (m,n) matrix, symmetric or not [will be modified]
+/// * `mode` -- selects which singular vectors are computed
 ///
 /// # Note
 ///
@@ -31,8 +38,9 @@ use russell_openblas::{dgesvd, to_i32};
 /// ## First - 2 x 3 rectangular matrix
 ///
 /// ```
-/// use russell_lab::{mat_svd, Matrix, Vector, StrError};
+/// use russell_lab::{mat_svd, Matrix, SvdMode, Vector, StrError};
 ///
+/// # #[cfg(not(feature = "pure_rust"))]
 /// fn main() -> Result<(), StrError> {
 ///     // set matrix
 ///     let mut a = Matrix::from(&[
@@ -48,7 +56,7 @@ use russell_openblas::{dgesvd, to_i32};
 ///     let mut vt = Matrix::new(n, n);
 ///
 ///     // perform SVD
-///     mat_svd(&mut s, &mut u, &mut vt, &mut a)?;
+///     mat_svd(&mut s, &mut u, &mut vt, &mut a, SvdMode::Full)?;
 ///
 ///     // define correct data
 ///     let s_correct = "┌       ┐\n\
@@ -75,13 +83,15 @@ use russell_openblas::{dgesvd, to_i32};
 ///     assert_eq!(format!("{:.6}", usv), usv_correct);
 ///     Ok(())
 /// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
 /// ```
 ///
 /// ## Second - 4 x 2 rectangular matrix
 ///
 /// ```
-/// use russell_lab::{mat_svd, Matrix, Vector, StrError};
+/// use russell_lab::{mat_svd, Matrix, SvdMode, Vector, StrError};
 ///
+/// # #[cfg(not(feature = "pure_rust"))]
 /// fn main() -> Result<(), StrError> {
 ///     // set matrix
 ///     let mut a = Matrix::from(&[
@@ -99,7 +109,7 @@ use russell_openblas::{dgesvd, to_i32};
 ///     let mut vt = Matrix::new(n, n);
 ///
 ///     // perform SVD
-///     mat_svd(&mut s, &mut u, &mut vt, &mut a)?;
+///     mat_svd(&mut s, &mut u, &mut vt, &mut a, SvdMode::Full)?;
 ///
 ///     // define correct data
 ///     let s_correct = "┌      ┐\n\
@@ -140,43 +150,79 @@ use russell_openblas::{dgesvd, to_i32};
 ///     assert_eq!(format!("{}", usv), usv_correct);
 ///     Ok(())
 /// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
 /// ```
-pub fn mat_svd(s: &mut Vector, u: &mut Matrix, vt: &mut Matrix, a: &mut Matrix) -> Result<(), StrError> {
+pub fn mat_svd(s: &mut Vector, u: &mut Matrix, vt: &mut Matrix, a: &mut Matrix, mode: SvdMode) -> Result<(), Error> {
+    mat_svd_with_workspace(s, u, vt, a, mode, &mut Workspace::new())
+}
+
+/// Computes the singular value decomposition (SVD) of a matrix reusing a workspace
+///
+/// This function is the same as [mat_svd]; however, the auxiliary `superb` array needed by LAPACK
+/// is stored in the given [Workspace]. Thus, no allocation happens if the workspace is large enough.
+pub fn mat_svd_with_workspace(
+    s: &mut Vector,
+    u: &mut Matrix,
+    vt: &mut Matrix,
+    a: &mut Matrix,
+    mode: SvdMode,
+    workspace: &mut Workspace,
+) -> Result<(), Error> {
     let (m, n) = a.dims();
     let min_mn = if m < n { m } else { n };
     if s.dim() != min_mn {
-        return Err("[s] must be an min(m,n) vector");
-    }
-    if u.nrow() != m || u.ncol() != m {
-        return Err("[u] must be an m-by-m square matrix");
+        return Err(Error::BadDimensions("[s] must be an min(m,n) vector"));
     }
-    if vt.nrow() != n || vt.ncol() != n {
-        return Err("[vt] must be an n-by-n square matrix");
+    let (jobu, jobvt) = match mode {
+        SvdMode::Full => {
+            if u.nrow() != m || u.ncol() != m {
+                return Err(Error::BadDimensions("[u] must be an m-by-m square matrix"));
+            }
+            if vt.nrow() != n || vt.ncol() != n {
+                return Err(Error::BadDimensions("[vt] must be an n-by-n square matrix"));
+            }
+            (b'A', b'A')
+        }
+        SvdMode::Thin => {
+            if u.nrow() != m || u.ncol() != min_mn {
+                return Err(Error::BadDimensions("[u] must be an m-by-min(m,n) matrix"));
+            }
+            if vt.nrow() != min_mn || vt.ncol() != n {
+                return Err(Error::BadDimensions("[vt] must be a min(m,n)-by-n matrix"));
+            }
+            (b'S', b'S')
+        }
+        SvdMode::ValuesOnly => (b'N', b'N'),
+    };
+    if min_mn == 0 {
+        return Ok(());
     }
     let m_i32 = to_i32(m);
     let n_i32 = to_i32(n);
-    let mut superb = vec![0.0; min_mn];
+    let superb = workspace.real(min_mn);
     dgesvd(
-        b'A',
-        b'A',
+        jobu,
+        jobvt,
         m_i32,
         n_i32,
         a.as_mut_data(),
         s.as_mut_data(),
         u.as_mut_data(),
         vt.as_mut_data(),
-        &mut superb,
-    )
+        superb,
+    )?;
+    Ok(())
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(unused_imports))]
 mod tests {
     use russell_chk::vec_approx_eq;
 
-    use super::{mat_svd, Matrix, Vector};
-    use crate::mat_approx_eq;
+    use super::{mat_svd, mat_svd_with_workspace, Matrix, SvdMode, Vector};
+    use crate::{mat_approx_eq, Error, Workspace};
 
     #[test]
     fn mat_svd_fails_on_wrong_dims() {
@@ -190,27 +236,44 @@ mod tests {
         let mut vt_3x3 = Matrix::new(3, 3);
         let mut vt_2x3 = Matrix::new(2, 3);
         assert_eq!(
-            mat_svd(&mut s_3, &mut u, &mut vt, &mut a),
-            Err("[s] must be an min(m,n) vector")
+            mat_svd(&mut s_3, &mut u, &mut vt, &mut a, SvdMode::Full),
+            Err(Error::BadDimensions("[s] must be an min(m,n) vector"))
         );
         assert_eq!(
-            mat_svd(&mut s, &mut u_2x2, &mut vt, &mut a),
-            Err("[u] must be an m-by-m square matrix")
+            mat_svd(&mut s, &mut u_2x2, &mut vt, &mut a, SvdMode::Full),
+            Err(Error::BadDimensions("[u] must be an m-by-m square matrix"))
         );
         assert_eq!(
-            mat_svd(&mut s, &mut u_3x2, &mut vt, &mut a),
-            Err("[u] must be an m-by-m square matrix")
+            mat_svd(&mut s, &mut u_3x2, &mut vt, &mut a, SvdMode::Full),
+            Err(Error::BadDimensions("[u] must be an m-by-m square matrix"))
         );
         assert_eq!(
-            mat_svd(&mut s, &mut u, &mut vt_3x3, &mut a),
-            Err("[vt] must be an n-by-n square matrix")
+            mat_svd(&mut s, &mut u, &mut vt_3x3, &mut a, SvdMode::Full),
+            Err(Error::BadDimensions("[vt] must be an n-by-n square matrix"))
         );
         assert_eq!(
-            mat_svd(&mut s, &mut u, &mut vt_2x3, &mut a),
-            Err("[vt] must be an n-by-n square matrix")
+            mat_svd(&mut s, &mut u, &mut vt_2x3, &mut a, SvdMode::Full),
+            Err(Error::BadDimensions("[vt] must be an n-by-n square matrix"))
         );
     }
 
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn mat_svd_with_workspace_works() {
+        let mut workspace = Workspace::new();
+        for (m, n) in [(3, 2), (2, 2)] {
+            let mut a = Matrix::new(m, n);
+            a.set(0, 0, 3.0);
+            a.set(1, 1, -4.0);
+            let mut s = Vector::new(2);
+            let mut u = Matrix::new(m, m);
+            let mut vt = Matrix::new(n, n);
+            mat_svd_with_workspace(&mut s, &mut u, &mut vt, &mut a, SvdMode::Full, &mut workspace).unwrap();
+            vec_approx_eq(s.as_data(), &[4.0, 3.0], 1e-15);
+        }
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
     #[test]
     fn mat_svd_works() {
         // matrix
@@ -233,7 +296,7 @@ mod tests {
         let mut vt = Matrix::new(n, n);
 
         // calculate SVD
-        mat_svd(&mut s, &mut u, &mut vt, &mut a).unwrap();
+        mat_svd(&mut s, &mut u, &mut vt, &mut a, SvdMode::Full).unwrap();
 
         // check
         #[rustfmt::skip]
@@ -271,6 +334,7 @@ mod tests {
         mat_approx_eq(&usv, &a_copy, 1e-15);
     }
 
+    #[cfg(not(feature = "pure_rust"))]
     #[test]
     fn mat_svd_1_works() {
         // matrix
@@ -290,7 +354,7 @@ mod tests {
         let mut vt = Matrix::new(n, n);
 
         // calculate SVD
-        mat_svd(&mut s, &mut u, &mut vt, &mut a).unwrap();
+        mat_svd(&mut s, &mut u, &mut vt, &mut a, SvdMode::Full).unwrap();
 
         // check
         let sqrt2 = std::f64::consts::SQRT_2;
@@ -326,4 +390,77 @@ mod tests {
         }
         mat_approx_eq(&usv, &a_copy, 1e-15);
     }
+
+    #[test]
+    fn mat_svd_thin_fails_on_wrong_dims() {
+        let mut a = Matrix::new(3, 2);
+        let mut s = Vector::new(2);
+        let mut u = Matrix::new(3, 2);
+        let mut vt = Matrix::new(2, 2);
+        let mut u_3x3 = Matrix::new(3, 3);
+        let mut vt_2x3 = Matrix::new(2, 3);
+        assert_eq!(
+            mat_svd(&mut s, &mut u_3x3, &mut vt, &mut a, SvdMode::Thin),
+            Err(Error::BadDimensions("[u] must be an m-by-min(m,n) matrix"))
+        );
+        assert_eq!(
+            mat_svd(&mut s, &mut u, &mut vt_2x3, &mut a, SvdMode::Thin),
+            Err(Error::BadDimensions("[vt] must be a min(m,n)-by-n matrix"))
+        );
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn mat_svd_thin_and_values_only_work() {
+        // tall matrix
+        #[rustfmt::skip]
+        let data = [
+            [2.0, 4.0],
+            [1.0, 3.0],
+            [0.0, 0.0],
+            [0.0, 0.0],
+            [0.0, 0.0],
+        ];
+        let mut a = Matrix::from(&data);
+        let a_copy = Matrix::from(&data);
+        let mut s = Vector::new(2);
+        let mut u = Matrix::new(5, 2);
+        let mut vt = Matrix::new(2, 2);
+        mat_svd(&mut s, &mut u, &mut vt, &mut a, SvdMode::Thin).unwrap();
+        let mut usv = Matrix::new(5, 2);
+        for i in 0..5 {
+            for j in 0..2 {
+                for k in 0..2 {
+                    usv.add(i, j, u.get(i, k) * s[k] * vt.get(k, j));
+                }
+            }
+        }
+        mat_approx_eq(&usv, &a_copy, 1e-14);
+
+        // singular values only (u and vt may have any dimension)
+        let mut a = Matrix::from(&data);
+        let mut s_only = Vector::new(2);
+        let mut empty = Matrix::new(0, 0);
+        let mut empty_too = Matrix::new(0, 0);
+        mat_svd(&mut s_only, &mut empty, &mut empty_too, &mut a, SvdMode::ValuesOnly).unwrap();
+        vec_approx_eq(s_only.as_data(), s.as_data(), 1e-14);
+        // s² are the eigenvalues of aᵀ⋅a = [[5, 11], [11, 25]]
+        let sum_sq = s_only[0] * s_only[0] + s_only[1] * s_only[1];
+        assert!(f64::abs(sum_sq - 30.0) < 1e-13);
+    }
+
+    #[cfg(feature = "pure_rust")]
+    #[test]
+    fn mat_svd_returns_backend_error() {
+        let mut a = Matrix::from(&[[2.0, 0.0], [0.0, 1.0]]);
+        let mut s = Vector::new(2);
+        let mut u = Matrix::new(2, 2);
+        let mut vt = Matrix::new(2, 2);
+        assert_eq!(
+            mat_svd(&mut s, &mut u, &mut vt, &mut a, SvdMode::Full),
+            Err(Error::Backend(
+                "LAPACK dgesvd is not available with the pure_rust backend"
+            ))
+        );
+    }
 }
@@ -44,9 +44,9 @@ pub fn mat_t_mat_mul(c: &mut Matrix, alpha: f64, a: &Matrix, b: &Matrix) -> Resu
     if m == 0 || n == 0 {
         return Ok(());
     }
-    let m_i32: i32 = to_i32(m);
-    let n_i32: i32 = to_i32(n);
-    let k_i32: i32 = to_i32(k);
+    let m_i32 = to_i32(m);
+    let n_i32 = to_i32(n);
+    let k_i32 = to_i32(k);
     dgemm(
         true,
         false,
@@ -0,0 +1,165 @@
+use super::Matrix;
+use crate::StrError;
+
+// block size of the cache-blocked algorithm
+const BLOCK_SIZE: usize = 32;
+
+/// Computes the transpose of a matrix
+///
+/// ```text
+///   at  :=  aᵀ
+/// (n,m)    (m,n)
+/// ```
+///
+/// Large matrices are transposed in square blocks to improve the use of the cache.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_transpose, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [1.0, 2.0, 3.0],
+///         [4.0, 5.0, 6.0],
+///     ]);
+///     let mut at = Matrix::new(3, 2);
+///     mat_transpose(&mut at, &a)?;
+///     let correct = "┌     ┐\n\
+///                    │ 1 4 │\n\
+///                    │ 2 5 │\n\
+///                    │ 3 6 │\n\
+///                    └     ┘";
+///     assert_eq!(format!("{}", at), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_transpose(at: &mut Matrix, a: &Matrix) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if at.nrow() != n || at.ncol() != m {
+        return Err("matrices are incompatible");
+    }
+    // col-major: a[i,j] = a_data[i + j⋅m] and at[j,i] = at_data[j + i⋅n]
+    let a_data = a.as_data();
+    let at_data = at.as_mut_data();
+    for jb in (0..n).step_by(BLOCK_SIZE) {
+        let j_end = usize::min(jb + BLOCK_SIZE, n);
+        for ib in (0..m).step_by(BLOCK_SIZE) {
+            let i_end = usize::min(ib + BLOCK_SIZE, m);
+            for j in jb..j_end {
+                for i in ib..i_end {
+                    at_data[j + i * n] = a_data[i + j * m];
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Transposes a square matrix in place
+///
+/// ```text
+/// a := aᵀ
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_transpose_in_place, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut a = Matrix::from(&[
+///         [1.0, 2.0],
+///         [3.0, 4.0],
+///     ]);
+///     mat_transpose_in_place(&mut a)?;
+///     let correct = "┌     ┐\n\
+///                    │ 1 3 │\n\
+///                    │ 2 4 │\n\
+///                    └     ┘";
+///     assert_eq!(format!("{}", a), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_transpose_in_place(a: &mut Matrix) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if m != n {
+        return Err("matrix must be square");
+    }
+    let data = a.as_mut_data();
+    for jb in (0..m).step_by(BLOCK_SIZE) {
+        let j_end = usize::min(jb + BLOCK_SIZE, m);
+        // swap the blocks below the diagonal (and the lower part of the diagonal blocks)
+        for ib in (jb..m).step_by(BLOCK_SIZE) {
+            let i_end = usize::min(ib + BLOCK_SIZE, m);
+            for j in jb..j_end {
+                for i in usize::max(ib, j + 1)..i_end {
+                    data.swap(i + j * m, j + i * m);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_transpose, mat_transpose_in_place, BLOCK_SIZE};
+    use crate::Matrix;
+
+    #[test]
+    fn mat_transpose_fails_on_wrong_dims() {
+        let a = Matrix::new(2, 3);
+        let mut at = Matrix::new(2, 3);
+        assert_eq!(mat_transpose(&mut at, &a), Err("matrices are incompatible"));
+        let mut a = Matrix::new(2, 3);
+        assert_eq!(mat_transpose_in_place(&mut a), Err("matrix must be square"));
+    }
+
+    #[test]
+    fn mat_transpose_works() {
+        let a = Matrix::new(0, 3);
+        let mut at = Matrix::new(3, 0);
+        mat_transpose(&mut at, &a).unwrap();
+
+        // larger than the block size (with incomplete blocks)
+        let (m, n) = (BLOCK_SIZE + 5, 2 * BLOCK_SIZE + 3);
+        let mut a = Matrix::new(m, n);
+        for i in 0..m {
+            for j in 0..n {
+                a.set(i, j, (i * n + j) as f64);
+            }
+        }
+        let mut at = Matrix::new(n, m);
+        mat_transpose(&mut at, &a).unwrap();
+        for i in 0..m {
+            for j in 0..n {
+                assert_eq!(at.get(j, i), a.get(i, j));
+            }
+        }
+    }
+
+    #[test]
+    fn mat_transpose_in_place_works() {
+        let mut a = Matrix::new(0, 0);
+        mat_transpose_in_place(&mut a).unwrap();
+
+        for m in [1, 3, BLOCK_SIZE, 2 * BLOCK_SIZE + 7] {
+            let mut a = Matrix::new(m, m);
+            for i in 0..m {
+                for j in 0..m {
+                    a.set(i, j, (i * m + j) as f64);
+                }
+            }
+            let a_copy = a.clone();
+            mat_transpose_in_place(&mut a).unwrap();
+            for i in 0..m {
+                for j in 0..m {
+                    assert_eq!(a.get(j, i), a_copy.get(i, j));
+                }
+            }
+        }
+    }
+}
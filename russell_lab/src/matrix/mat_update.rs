@@ -36,7 +36,7 @@ pub fn mat_update(b: &mut Matrix, alpha: f64, a: &Matrix) -> Result<(), StrError
     if a.nrow() != m || a.ncol() != n {
         return Err("matrices are incompatible");
     }
-    let mn_i32: i32 = to_i32(m * n);
+    let mn_i32 = to_i32(m * n);
     daxpy(mn_i32, alpha, a.as_data(), 1, b.as_mut_data(), 1);
     Ok(())
 }
@@ -0,0 +1,135 @@
+use super::Matrix;
+use crate::StrError;
+use russell_openblas::{dsyrk, to_i32};
+
+/// Performs the symmetric rank-k update of a matrix
+///
+/// ```text
+///   c  :=  α ⋅  a  ⋅  aᵀ  +  β ⋅  c      (trans = false)
+/// (n,n)       (n,k) (k,n)        (n,n)
+///
+/// or
+///
+///   c  :=  α ⋅  aᵀ  ⋅  a  +  β ⋅  c      (trans = true)
+/// (n,n)       (n,k)  (k,n)       (n,n)
+/// ```
+///
+/// Only the upper triangle is computed (with about half the work of the general
+/// matrix-matrix multiplication); afterwards, the lower triangle is filled by symmetry.
+///
+/// # Note
+///
+/// Because only the upper triangle of the previous `c` is used, `c` must be symmetric
+/// on input if `beta` is non-zero.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_update_sym, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [1.0, 2.0],
+///         [3.0, 4.0],
+///         [5.0, 6.0],
+///     ]);
+///     let mut c = Matrix::new(2, 2);
+///     // c := aᵀ⋅a (e.g., normal equations)
+///     mat_update_sym(&mut c, 1.0, &a, true, 0.0)?;
+///     let correct = "┌       ┐\n\
+///                    │ 35 44 │\n\
+///                    │ 44 56 │\n\
+///                    └       ┘";
+///     assert_eq!(format!("{}", c), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_update_sym(c: &mut Matrix, alpha: f64, a: &Matrix, trans: bool, beta: f64) -> Result<(), StrError> {
+    let (m, n) = c.dims();
+    if m != n {
+        return Err("matrix c must be square");
+    }
+    let (a_n, k) = if trans { (a.ncol(), a.nrow()) } else { a.dims() };
+    if a_n != n {
+        return Err("matrices are incompatible");
+    }
+    if n == 0 {
+        return Ok(());
+    }
+    if k == 0 {
+        // nothing to multiply; only scale c
+        for x in c.as_mut_data().iter_mut() {
+            *x = if beta == 0.0 { 0.0 } else { beta * *x };
+        }
+        return Ok(());
+    }
+    let n_i32 = to_i32(n);
+    let k_i32 = to_i32(k);
+    dsyrk(true, trans, n_i32, k_i32, alpha, a.as_data(), beta, c.as_mut_data());
+    for j in 0..n {
+        for i in (j + 1)..n {
+            c.set(i, j, c.get(j, i));
+        }
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_update_sym, Matrix};
+    use crate::{mat_approx_eq, mat_mat_mul_ex};
+
+    #[test]
+    fn mat_update_sym_fails_on_wrong_dims() {
+        let a_2x3 = Matrix::new(2, 3);
+        let mut c_2x3 = Matrix::new(2, 3);
+        let mut c_2x2 = Matrix::new(2, 2);
+        let mut c_3x3 = Matrix::new(3, 3);
+        assert_eq!(
+            mat_update_sym(&mut c_2x3, 1.0, &a_2x3, false, 0.0),
+            Err("matrix c must be square")
+        );
+        assert_eq!(
+            mat_update_sym(&mut c_2x2, 1.0, &a_2x3, true, 0.0),
+            Err("matrices are incompatible")
+        );
+        assert_eq!(
+            mat_update_sym(&mut c_3x3, 1.0, &a_2x3, false, 0.0),
+            Err("matrices are incompatible")
+        );
+    }
+
+    #[test]
+    fn mat_update_sym_works() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0, -2.0,  0.5],
+            [3.0,  4.0, -1.0],
+        ]);
+
+        // c := a⋅aᵀ
+        let mut c = Matrix::filled(2, 2, 123.0);
+        mat_update_sym(&mut c, 1.0, &a, false, 0.0).unwrap();
+        let mut correct = Matrix::new(2, 2);
+        mat_mat_mul_ex(&mut correct, 1.0, &a, false, &a, true, 0.0).unwrap();
+        mat_approx_eq(&c, &[[5.25, -5.5], [-5.5, 26.0]], 1e-15);
+        mat_approx_eq(&c, &correct, 1e-15);
+
+        // c := 2⋅aᵀ⋅a - c
+        let mut c = Matrix::from(&[[1.0, 2.0, 3.0], [2.0, 4.0, 5.0], [3.0, 5.0, 6.0]]);
+        let mut correct = c.clone();
+        mat_update_sym(&mut c, 2.0, &a, true, -1.0).unwrap();
+        mat_mat_mul_ex(&mut correct, 2.0, &a, true, &a, false, -1.0).unwrap();
+        mat_approx_eq(&c, &correct, 1e-15);
+        assert_eq!(c.get(1, 0), c.get(0, 1));
+        assert_eq!(c.get(2, 0), c.get(0, 2));
+        assert_eq!(c.get(2, 1), c.get(1, 2));
+
+        // k = 0 (only scales c)
+        let mut c = Matrix::filled(2, 2, 3.0);
+        mat_update_sym(&mut c, 1.0, &Matrix::new(0, 2), true, 0.5).unwrap();
+        mat_approx_eq(&c, &[[1.5, 1.5], [1.5, 1.5]], 1e-15);
+    }
+}
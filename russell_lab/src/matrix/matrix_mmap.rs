@@ -0,0 +1,377 @@
+use super::Matrix;
+use crate::StrError;
+use memmap2::MmapMut;
+use std::ffi::OsStr;
+use std::fs::{File, OpenOptions};
+use std::mem::size_of;
+use std::ops::Range;
+use std::path::Path;
+
+/// Implements a dense matrix stored in a memory-mapped file
+///
+/// The data is stored in the **col-major** order (as in [Matrix]) as raw `f64` values with the
+/// native endianness of the machine; there is no header in the file. Since the operating system
+/// loads (and writes back) the pages of the file on demand, the matrix may be larger than the
+/// available RAM.
+///
+/// `MatrixMmap` is a storage container: the routines of this crate (e.g., [crate::mat_vec_mul])
+/// take a [Matrix] and do not accept a `MatrixMmap`. There are two ways to operate on the data:
+///
+/// 1. Copy blocks of the matrix to (or from) a regular [Matrix] with [MatrixMmap::extract] and
+///    [MatrixMmap::insert]; then, call the routines block-wise (see the first example).
+/// 2. Pass the borrowed col-major view given by [MatrixMmap::as_data] (or [MatrixMmap::as_mut_data])
+///    together with [MatrixMmap::dims] directly to the BLAS/LAPACK wrappers of russell_openblas;
+///    the leading dimension is `nrow` (see the second example). No data is copied in this case.
+///
+/// **Note:** This structure is only available with the `mmap` feature.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{Matrix, MatrixMmap, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let path = std::env::temp_dir().join("russell_lab_matrix_mmap_doc.bin");
+///     let mut a = MatrixMmap::create(&path, 3, 3)?;
+///     a.set(0, 0, 1.0);
+///     a.insert(1, 1, &Matrix::from(&[[2.0, 3.0], [4.0, 5.0]]))?;
+///     a.flush()?;
+///
+///     // open the file again
+///     let b = MatrixMmap::open(&path, 3, 3)?;
+///     let mut sub = Matrix::new(2, 2);
+///     b.extract(&mut sub, 0..2, 0..2)?;
+///     let correct = "┌     ┐\n\
+///                    │ 1 0 │\n\
+///                    │ 0 2 │\n\
+///                    └     ┘";
+///     assert_eq!(format!("{}", sub), correct);
+///     Ok(())
+/// }
+/// ```
+///
+/// Calling a BLAS wrapper with the data in the file:
+///
+/// ```
+/// use russell_lab::{MatrixMmap, StrError};
+/// use russell_openblas::{dgemv, to_i32};
+///
+/// fn main() -> Result<(), StrError> {
+///     let path = std::env::temp_dir().join("russell_lab_matrix_mmap_blas_doc.bin");
+///     let mut a = MatrixMmap::create(&path, 2, 3)?;
+///     a.as_mut_data().copy_from_slice(&[1.0, 4.0, 2.0, 5.0, 3.0, 6.0]); // col-major
+///
+///     // v := a ⋅ u
+///     let (m, n) = a.dims();
+///     let u = [1.0, 1.0, 1.0];
+///     let mut v = [0.0; 2];
+///     dgemv(false, to_i32(m), to_i32(n), 1.0, a.as_data(), &u, 1, 0.0, &mut v, 1);
+///     assert_eq!(v, [6.0, 15.0]);
+///     Ok(())
+/// }
+/// ```
+pub struct MatrixMmap {
+    nrow: usize, // number of rows
+    ncol: usize, // number of columns
+    mmap: MmapMut,
+}
+
+impl MatrixMmap {
+    /// Creates a new file (or truncates an existing one) and maps a zeroed (nrow,ncol) matrix
+    ///
+    /// # Input
+    ///
+    /// * `full_path` -- may be a String, &str, or Path
+    /// * `nrow`, `ncol` -- the dimensions of the matrix
+    pub fn create<P>(full_path: &P, nrow: usize, ncol: usize) -> Result<Self, StrError>
+    where
+        P: AsRef<OsStr> + ?Sized,
+    {
+        let len = nrow * ncol * size_of::<f64>();
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(Path::new(full_path))
+            .map_err(|_| "cannot create file")?;
+        file.set_len(len as u64)
+            .map_err(|_| "cannot set the length of the file")?;
+        MatrixMmap::map(file, nrow, ncol)
+    }
+
+    /// Opens an existing file with the data of a (nrow,ncol) matrix
+    ///
+    /// # Input
+    ///
+    /// * `full_path` -- may be a String, &str, or Path
+    /// * `nrow`, `ncol` -- the dimensions of the matrix; the length of the file must be `nrow ⋅ ncol ⋅ 8` bytes
+    pub fn open<P>(full_path: &P, nrow: usize, ncol: usize) -> Result<Self, StrError>
+    where
+        P: AsRef<OsStr> + ?Sized,
+    {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(Path::new(full_path))
+            .map_err(|_| "cannot open file")?;
+        let len = file.metadata().map_err(|_| "cannot read the length of the file")?.len();
+        if len != (nrow * ncol * size_of::<f64>()) as u64 {
+            return Err("the length of the file is incompatible with the dimensions");
+        }
+        MatrixMmap::map(file, nrow, ncol)
+    }
+
+    /// Maps the file into memory
+    fn map(file: File, nrow: usize, ncol: usize) -> Result<Self, StrError> {
+        // SAFETY: the file must not be modified by other processes while mapped
+        let mmap = unsafe { MmapMut::map_mut(&file).map_err(|_| "cannot map file into memory")? };
+        Ok(MatrixMmap { nrow, ncol, mmap })
+    }
+
+    /// Returns the number of rows
+    pub fn nrow(&self) -> usize {
+        self.nrow
+    }
+
+    /// Returns the number of columns
+    pub fn ncol(&self) -> usize {
+        self.ncol
+    }
+
+    /// Returns the dimensions (nrow, ncol) of this matrix
+    pub fn dims(&self) -> (usize, usize) {
+        (self.nrow, self.ncol)
+    }
+
+    /// Returns an access to the underlying data (col-major)
+    ///
+    /// The slice (with the leading dimension equal to `nrow`) may be given to the BLAS/LAPACK
+    /// wrappers of russell_openblas that take col-major data.
+    pub fn as_data(&self) -> &[f64] {
+        let n = self.nrow * self.ncol;
+        if n == 0 {
+            return &[];
+        }
+        // SAFETY: the mapping is page-aligned and holds exactly n values
+        unsafe { std::slice::from_raw_parts(self.mmap.as_ptr() as *const f64, n) }
+    }
+
+    /// Returns a mutable access to the underlying data (col-major)
+    pub fn as_mut_data(&mut self) -> &mut [f64] {
+        let n = self.nrow * self.ncol;
+        if n == 0 {
+            return &mut [];
+        }
+        // SAFETY: the mapping is page-aligned and holds exactly n values
+        unsafe { std::slice::from_raw_parts_mut(self.mmap.as_mut_ptr() as *mut f64, n) }
+    }
+
+    /// Returns the (i,j) component
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the indices are out of range.
+    #[inline]
+    pub fn get(&self, i: usize, j: usize) -> f64 {
+        assert!(i < self.nrow);
+        assert!(j < self.ncol);
+        self.as_data()[i + j * self.nrow]
+    }
+
+    /// Changes the (i,j) component
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the indices are out of range.
+    #[inline]
+    pub fn set(&mut self, i: usize, j: usize, value: f64) {
+        assert!(i < self.nrow);
+        assert!(j < self.ncol);
+        let m = self.nrow;
+        self.as_mut_data()[i + j * m] = value;
+    }
+
+    /// Adds a value to the (i,j) component
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the indices are out of range.
+    #[inline]
+    pub fn add(&mut self, i: usize, j: usize, value: f64) {
+        assert!(i < self.nrow);
+        assert!(j < self.ncol);
+        let m = self.nrow;
+        self.as_mut_data()[i + j * m] += value;
+    }
+
+    /// Copies a block of this matrix into a regular matrix
+    ///
+    /// ```text
+    /// sub := a[rows, cols]
+    /// ```
+    ///
+    /// See [crate::mat_extract]
+    pub fn extract(&self, sub: &mut Matrix, rows: Range<usize>, cols: Range<usize>) -> Result<(), StrError> {
+        let (m, n) = self.dims();
+        if rows.start > rows.end || cols.start > cols.end || rows.end > m || cols.end > n {
+            return Err("ranges are out of bounds");
+        }
+        let (p, q) = (rows.len(), cols.len());
+        if sub.nrow() != p || sub.ncol() != q {
+            return Err("matrices are incompatible");
+        }
+        let a_data = self.as_data();
+        let sub_data = sub.as_mut_data();
+        for (k, j) in cols.enumerate() {
+            let start = rows.start + j * m;
+            sub_data[(k * p)..((k + 1) * p)].copy_from_slice(&a_data[start..(start + p)]);
+        }
+        Ok(())
+    }
+
+    /// Copies a regular matrix into a block of this matrix
+    ///
+    /// ```text
+    /// a[i0 + i, j0 + j] := sub[i, j]
+    /// ```
+    ///
+    /// See [crate::mat_insert]
+    pub fn insert(&mut self, i0: usize, j0: usize, sub: &Matrix) -> Result<(), StrError> {
+        let (m, n) = self.dims();
+        let (p, q) = sub.dims();
+        if i0 + p > m || j0 + q > n {
+            return Err("block is out of bounds");
+        }
+        let sub_data = sub.as_data();
+        let a_data = self.as_mut_data();
+        for j in 0..q {
+            let start = i0 + (j0 + j) * m;
+            a_data[start..(start + p)].copy_from_slice(&sub_data[(j * p)..((j + 1) * p)]);
+        }
+        Ok(())
+    }
+
+    /// Writes the modified data to the file
+    pub fn flush(&self) -> Result<(), StrError> {
+        self.mmap.flush().map_err(|_| "cannot flush the data to the file")
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::MatrixMmap;
+    use crate::{mat_approx_eq, mat_mat_mul, Matrix};
+    use russell_openblas::{dgemm, to_i32};
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(name)
+    }
+
+    #[test]
+    fn matrix_mmap_captures_errors() {
+        assert_eq!(
+            MatrixMmap::create("/tmp/russell_lab/not-found/a.bin", 2, 2).err(),
+            Some("cannot create file")
+        );
+        assert_eq!(MatrixMmap::open("not-found", 2, 2).err(), Some("cannot open file"));
+        let path = temp_path("russell_lab_matrix_mmap_errors.bin");
+        MatrixMmap::create(&path, 2, 2).unwrap();
+        assert_eq!(
+            MatrixMmap::open(&path, 3, 2).err(),
+            Some("the length of the file is incompatible with the dimensions")
+        );
+        let mut a = MatrixMmap::open(&path, 2, 2).unwrap();
+        let mut sub = Matrix::new(2, 2);
+        assert_eq!(a.extract(&mut sub, 1..3, 0..2).err(), Some("ranges are out of bounds"));
+        assert_eq!(a.extract(&mut sub, 0..1, 0..2).err(), Some("matrices are incompatible"));
+        assert_eq!(a.insert(1, 0, &sub).err(), Some("block is out of bounds"));
+    }
+
+    #[test]
+    fn matrix_mmap_works() {
+        let path = temp_path("russell_lab_matrix_mmap_works.bin");
+        let mut a = MatrixMmap::create(&path, 3, 2).unwrap();
+        assert_eq!(a.dims(), (3, 2));
+        assert_eq!((a.nrow(), a.ncol()), (3, 2));
+        assert_eq!(a.as_data(), &[0.0; 6]);
+        a.set(0, 0, 1.0);
+        a.set(2, 1, 6.0);
+        a.add(2, 1, 0.5);
+        a.as_mut_data()[1] = 2.0;
+        assert_eq!(a.get(1, 0), 2.0);
+        assert_eq!(a.as_data(), &[1.0, 2.0, 0.0, 0.0, 0.0, 6.5]);
+        a.flush().unwrap();
+        drop(a);
+
+        // data persists in the file
+        let b = MatrixMmap::open(&path, 3, 2).unwrap();
+        assert_eq!(b.get(2, 1), 6.5);
+        assert_eq!(b.as_data(), &[1.0, 2.0, 0.0, 0.0, 0.0, 6.5]);
+
+        // empty matrix
+        let e = MatrixMmap::create(&temp_path("russell_lab_matrix_mmap_empty.bin"), 0, 3).unwrap();
+        assert_eq!(e.as_data().len(), 0);
+    }
+
+    #[test]
+    fn matrix_mmap_block_processing_works() {
+        // c := a ⋅ b computed by (2,2) blocks, with a and c in files
+        #[rustfmt::skip]
+        let a_ram = Matrix::from(&[
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0, 7.0],
+        ]);
+        let b = Matrix::from(&[[1.0, 0.0], [0.0, 1.0], [1.0, 1.0], [2.0, -1.0]]);
+        let mut a = MatrixMmap::create(&temp_path("russell_lab_matrix_mmap_a.bin"), 4, 4).unwrap();
+        a.insert(0, 0, &a_ram).unwrap();
+        let mut c = MatrixMmap::create(&temp_path("russell_lab_matrix_mmap_c.bin"), 4, 2).unwrap();
+        let mut block = Matrix::new(2, 4);
+        let mut c_block = Matrix::new(2, 2);
+        for i0 in [0, 2] {
+            a.extract(&mut block, i0..(i0 + 2), 0..4).unwrap();
+            mat_mat_mul(&mut c_block, 1.0, &block, &b).unwrap();
+            c.insert(i0, 0, &c_block).unwrap();
+        }
+        let mut c_ram = Matrix::new(4, 2);
+        let mut correct = Matrix::new(4, 2);
+        c.extract(&mut c_ram, 0..4, 0..2).unwrap();
+        mat_mat_mul(&mut correct, 1.0, &a_ram, &b).unwrap();
+        mat_approx_eq(&c_ram, &correct, 1e-15);
+    }
+
+    #[test]
+    fn matrix_mmap_works_with_blas() {
+        // c := a ⋅ b computed by dgemm with a, b, and c in files
+        let mut a = MatrixMmap::create(&temp_path("russell_lab_matrix_mmap_blas_a.bin"), 2, 3).unwrap();
+        let mut b = MatrixMmap::create(&temp_path("russell_lab_matrix_mmap_blas_b.bin"), 3, 2).unwrap();
+        let mut c = MatrixMmap::create(&temp_path("russell_lab_matrix_mmap_blas_c.bin"), 2, 2).unwrap();
+        let a_ram = Matrix::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b_ram = Matrix::from(&[[1.0, 0.0], [0.0, 1.0], [1.0, -1.0]]);
+        a.insert(0, 0, &a_ram).unwrap();
+        b.insert(0, 0, &b_ram).unwrap();
+        let ((m, k), n) = (a.dims(), b.ncol());
+        let (m, n, k) = (to_i32(m), to_i32(n), to_i32(k));
+        dgemm(
+            false,
+            false,
+            m,
+            n,
+            k,
+            1.0,
+            a.as_data(),
+            b.as_data(),
+            0.0,
+            c.as_mut_data(),
+        );
+        let mut c_ram = Matrix::new(2, 2);
+        let mut correct = Matrix::new(2, 2);
+        c.extract(&mut c_ram, 0..2, 0..2).unwrap();
+        mat_mat_mul(&mut correct, 1.0, &a_ram, &b_ram).unwrap();
+        mat_approx_eq(&c_ram, &correct, 1e-15);
+    }
+}
@@ -1,50 +1,102 @@
 //! This module contains functions for calculations with matrices
 
 mod aliases;
+mod check_hermitian;
+mod check_orthogonal;
+mod check_spd;
+mod check_symmetric;
 mod complex_mat_add;
 mod complex_mat_approx_eq;
+mod complex_mat_cholesky;
+mod complex_mat_inverse;
 mod complex_mat_mat_mul;
 mod complex_mat_norm;
 mod complex_mat_zip;
 mod mat_add;
 mod mat_approx_eq;
+mod mat_bidiag;
 mod mat_cholesky;
 mod mat_copy;
+mod mat_covariance;
 mod mat_eigen;
 mod mat_eigen_sym;
 mod mat_eigen_sym_jacobi;
+mod mat_extract;
+mod mat_gsvd;
+mod mat_hadamard;
+mod mat_insert;
 mod mat_inverse;
+mod mat_lu;
 mod mat_mat_mul;
+mod mat_mat_mul_ex;
+#[cfg(feature = "rayon")]
+mod mat_mat_mul_par;
 mod mat_max_abs_diff;
 mod mat_norm;
+mod mat_null_space;
+mod mat_polar;
+mod mat_pow;
 mod mat_pseudo_inverse;
+mod mat_qr;
+mod mat_rank;
 mod mat_scale;
+mod mat_stack;
 mod mat_svd;
 mod mat_t_mat_mul;
+mod mat_transpose;
 mod mat_update;
+mod mat_update_sym;
 mod mat_write_vismatrix;
+#[cfg(feature = "mmap")]
+mod matrix_mmap;
 mod num_matrix;
 pub use crate::matrix::aliases::*;
+pub use crate::matrix::check_hermitian::*;
+pub use crate::matrix::check_orthogonal::*;
+pub use crate::matrix::check_spd::*;
+pub use crate::matrix::check_symmetric::*;
 pub use crate::matrix::complex_mat_add::*;
 pub use crate::matrix::complex_mat_approx_eq::*;
+pub use crate::matrix::complex_mat_cholesky::*;
+pub use crate::matrix::complex_mat_inverse::*;
 pub use crate::matrix::complex_mat_mat_mul::*;
 pub use crate::matrix::complex_mat_norm::*;
 pub use crate::matrix::complex_mat_zip::*;
 pub use crate::matrix::mat_add::*;
 pub use crate::matrix::mat_approx_eq::*;
+pub use crate::matrix::mat_bidiag::*;
 pub use crate::matrix::mat_cholesky::*;
 pub use crate::matrix::mat_copy::*;
+pub use crate::matrix::mat_covariance::*;
 pub use crate::matrix::mat_eigen::*;
 pub use crate::matrix::mat_eigen_sym::*;
 pub use crate::matrix::mat_eigen_sym_jacobi::*;
+pub use crate::matrix::mat_extract::*;
+pub use crate::matrix::mat_gsvd::*;
+pub use crate::matrix::mat_hadamard::*;
+pub use crate::matrix::mat_insert::*;
 pub use crate::matrix::mat_inverse::*;
+pub use crate::matrix::mat_lu::*;
 pub use crate::matrix::mat_mat_mul::*;
+pub use crate::matrix::mat_mat_mul_ex::*;
+#[cfg(feature = "rayon")]
+pub use crate::matrix::mat_mat_mul_par::*;
 pub use crate::matrix::mat_max_abs_diff::*;
 pub use crate::matrix::mat_norm::*;
+pub use crate::matrix::mat_null_space::*;
+pub use crate::matrix::mat_polar::*;
+pub use crate::matrix::mat_pow::*;
 pub use crate::matrix::mat_pseudo_inverse::*;
+pub use crate::matrix::mat_qr::*;
+pub use crate::matrix::mat_rank::*;
 pub use crate::matrix::mat_scale::*;
+pub use crate::matrix::mat_stack::*;
 pub use crate::matrix::mat_svd::*;
 pub use crate::matrix::mat_t_mat_mul::*;
+pub use crate::matrix::mat_transpose::*;
 pub use crate::matrix::mat_update::*;
+pub use crate::matrix::mat_update_sym::*;
 pub use crate::matrix::mat_write_vismatrix::*;
+#[cfg(feature = "mmap")]
+pub use crate::matrix::matrix_mmap::*;
 pub use crate::matrix::num_matrix::*;
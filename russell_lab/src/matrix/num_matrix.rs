@@ -1,5 +1,6 @@
-use crate::{AsArray2D, StrError};
-use num_traits::Num;
+use crate::{AsArray2D, DisplayOpts, StrError};
+use num_traits::{cast, Num, NumCast};
+use russell_chk::TestDataGenerator;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::cmp;
@@ -163,6 +164,26 @@ where
     data: Vec<T>, // col-major
 }
 
+/// Holds the error returned by [NumMatrix::from_text_file_ex]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextFileError {
+    /// Holds the error message
+    pub message: StrError,
+
+    /// Holds the line number (1-based) where the error occurred or zero if the error is not related to a line
+    pub line: usize,
+}
+
+impl fmt::Display for TextFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line == 0 {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{} (line {})", self.message, self.line)
+        }
+    }
+}
+
 impl<T> NumMatrix<T>
 where
     T: AddAssign + MulAssign + Num + Copy + DeserializeOwned + Serialize,
@@ -347,6 +368,85 @@ where
         matrix
     }
 
+    /// Creates new Toeplitz matrix (constant along each diagonal)
+    ///
+    /// ```text
+    /// aᵢⱼ = col[i-j]  if i ≥ j
+    /// aᵢⱼ = row[j-i]  if i < j
+    /// ```
+    ///
+    /// # Input
+    ///
+    /// * `col` -- (m) the first column
+    /// * `row` -- (n) the first row; `row[0]` is ignored because the diagonal is given by `col[0]`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::NumMatrix;
+    /// let a = NumMatrix::<f64>::toeplitz(&[1.0, 2.0, 3.0], &[1.0, 4.0, 5.0, 6.0]);
+    /// let correct = "┌         ┐\n\
+    ///                │ 1 4 5 6 │\n\
+    ///                │ 2 1 4 5 │\n\
+    ///                │ 3 2 1 4 │\n\
+    ///                └         ┘";
+    /// assert_eq!(format!("{}", a), correct);
+    /// ```
+    pub fn toeplitz(col: &[T], row: &[T]) -> Self {
+        let (nrow, ncol) = (col.len(), row.len());
+        let mut matrix = NumMatrix {
+            nrow,
+            ncol,
+            data: vec![T::zero(); nrow * ncol],
+        };
+        for i in 0..nrow {
+            for j in 0..ncol {
+                matrix.data[i + j * nrow] = if i >= j { col[i - j] } else { row[j - i] };
+            }
+        }
+        matrix
+    }
+
+    /// Creates new Vandermonde matrix with increasing powers
+    ///
+    /// ```text
+    /// aᵢⱼ = xᵢʲ
+    /// ```
+    ///
+    /// # Input
+    ///
+    /// * `x` -- (m) the nodes
+    /// * `n` -- the number of columns (i.e., the polynomial degree plus one)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::NumMatrix;
+    /// let a = NumMatrix::<f64>::vandermonde(&[1.0, 2.0, 3.0], 3);
+    /// let correct = "┌       ┐\n\
+    ///                │ 1 1 1 │\n\
+    ///                │ 1 2 4 │\n\
+    ///                │ 1 3 9 │\n\
+    ///                └       ┘";
+    /// assert_eq!(format!("{}", a), correct);
+    /// ```
+    pub fn vandermonde(x: &[T], n: usize) -> Self {
+        let nrow = x.len();
+        let mut matrix = NumMatrix {
+            nrow,
+            ncol: n,
+            data: vec![T::zero(); nrow * n],
+        };
+        for (i, xi) in x.iter().enumerate() {
+            let mut power = T::one();
+            for j in 0..n {
+                matrix.data[i + j * nrow] = power;
+                power *= *xi;
+            }
+        }
+        matrix
+    }
+
     /// Creates matrix from text file
     ///
     /// # Input
@@ -391,62 +491,91 @@ where
     /// * Lines starting with '#' or empty lines are ignored
     /// * The end of the row (line) may contain comments too and will cause to stop reading data,
     ///   thus, the '#' marker in a row (line) must be at the end of the line.
+    /// * Values in the Fortran double precision notation (e.g., `1.5D+03`) are accepted
+    /// * Use [NumMatrix::from_text_file_ex] to obtain the line number where an error occurred
     pub fn from_text_file<P>(full_path: &P) -> Result<Self, StrError>
+    where
+        P: AsRef<OsStr> + ?Sized,
+    {
+        NumMatrix::from_text_file_ex(full_path).map_err(|e| e.message)
+    }
+
+    /// Creates matrix from text file and reports the line number of errors
+    ///
+    /// This function is similar to [NumMatrix::from_text_file]; however, the returned error
+    /// contains the line number (1-based) where the problem was found.
+    ///
+    /// # Input
+    ///
+    /// * `full_path` -- may be a String, &str, or Path
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_lab::NumMatrix;
+    ///
+    /// let res = NumMatrix::<f64>::from_text_file_ex("./data/matrices/bad_missing_data.txt");
+    /// let err = res.err().unwrap();
+    /// assert_eq!(err.line, 4);
+    /// assert_eq!(format!("{}", err), "column data is missing (line 4)");
+    /// ```
+    pub fn from_text_file_ex<P>(full_path: &P) -> Result<Self, TextFileError>
     where
         P: AsRef<OsStr> + ?Sized,
     {
         // read file
         let path = Path::new(full_path).to_path_buf();
-        let input = File::open(path).map_err(|_| "cannot open file")?;
+        let input = File::open(path).map_err(|_| TextFileError {
+            message: "cannot open file",
+            line: 0,
+        })?;
         let buffered = BufReader::new(input);
-        let mut lines_iter = buffered.lines();
 
         // parse rows, ignoring comments and empty lines
         let mut current_row_index = 0;
         let mut number_of_columns = 0;
         let mut data_row_major = Vec::<T>::new();
-        loop {
-            match lines_iter.next() {
-                Some(v) => {
-                    // extract line
-                    let line = v.unwrap(); // must panic because no error expected here
-
-                    // ignore comments or empty lines
-                    let maybe_data = line.trim_start().trim_end_matches("\n");
-                    if maybe_data.starts_with("#") || maybe_data == "" {
-                        continue; // nothing to parse
-                    }
+        for (line_index, v) in buffered.lines().enumerate() {
+            // extract line
+            let line = v.unwrap(); // must panic because no error expected here
+            let fail = |message| TextFileError {
+                message,
+                line: line_index + 1,
+            };
+
+            // ignore comments or empty lines
+            let maybe_data = line.trim_start().trim_end_matches("\n");
+            if maybe_data.starts_with("#") || maybe_data == "" {
+                continue; // nothing to parse
+            }
 
-                    // remove whitespace
-                    let mut row_values = maybe_data.split_whitespace();
-
-                    // loop over columns
-                    let mut column_index = 0;
-                    loop {
-                        match row_values.next() {
-                            Some(s) => {
-                                if s.starts_with("#") {
-                                    break; // ignore comments at the end of the row
-                                }
-                                data_row_major.push(T::from_str_radix(s, 10).map_err(|_| "cannot parse value")?);
-                                column_index += 1;
-                            }
-                            None => break,
-                        }
+            // loop over columns (separated by whitespace)
+            let mut column_index = 0;
+            for s in maybe_data.split_whitespace() {
+                if s.starts_with("#") {
+                    break; // ignore comments at the end of the row
+                }
+                let value = match T::from_str_radix(s, 10) {
+                    Ok(v) => v,
+                    Err(_) => {
+                        // try the Fortran double precision notation
+                        let fortran = s.replace(['D', 'd'], "e");
+                        T::from_str_radix(&fortran, 10).map_err(|_| fail("cannot parse value"))?
                     }
+                };
+                data_row_major.push(value);
+                column_index += 1;
+            }
 
-                    // set or check the number of columns
-                    if current_row_index == 0 {
-                        number_of_columns = column_index; // the first row determines the number of columns
-                    } else {
-                        if column_index != number_of_columns {
-                            return Err("column data is missing");
-                        }
-                    }
-                    current_row_index += 1;
-                }
-                None => break,
+            // set or check the number of columns
+            if current_row_index == 0 {
+                number_of_columns = column_index; // the first row determines the number of columns
+            } else if column_index < number_of_columns {
+                return Err(fail("column data is missing"));
+            } else if column_index > number_of_columns {
+                return Err(fail("row has more columns than the first row"));
             }
+            current_row_index += 1;
         }
         let (nrow, ncol) = (current_row_index, number_of_columns);
         let mut data = vec![T::zero(); nrow * ncol];
@@ -521,6 +650,78 @@ where
         self.data.iter_mut().map(|x| *x = value).count();
     }
 
+    /// Changes the dimensions of this matrix without changing its data
+    ///
+    /// The components are kept in the same **col-major** order; thus, the new (m,n) matrix is filled
+    /// column by column with the components of the old matrix taken column by column.
+    ///
+    /// # Input
+    ///
+    /// * `m` -- the new number of rows
+    /// * `n` -- the new number of columns (m⋅n must equal nrow⋅ncol)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::{NumMatrix, StrError};
+    /// # fn main() -> Result<(), StrError> {
+    /// let mut a = NumMatrix::<i32>::from(&[[1, 2, 3], [4, 5, 6]]);
+    /// a.reshape(3, 2)?;
+    /// let correct = "┌     ┐\n\
+    ///                │ 1 5 │\n\
+    ///                │ 4 3 │\n\
+    ///                │ 2 6 │\n\
+    ///                └     ┘";
+    /// assert_eq!(format!("{}", a), correct);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reshape(&mut self, m: usize, n: usize) -> Result<(), StrError> {
+        if m * n != self.data.len() {
+            return Err("the new dimensions must yield the same number of components");
+        }
+        self.nrow = m;
+        self.ncol = n;
+        Ok(())
+    }
+
+    /// Changes the dimensions of this matrix, keeping the existing components
+    ///
+    /// The component (i,j) is preserved if i < min(nrow,m) and j < min(ncol,n);
+    /// the new components are set to `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::NumMatrix;
+    /// let mut a = NumMatrix::<i32>::from(&[[1, 2], [3, 4]]);
+    /// a.resize(3, 3, 0);
+    /// let correct = "┌       ┐\n\
+    ///                │ 1 2 0 │\n\
+    ///                │ 3 4 0 │\n\
+    ///                │ 0 0 0 │\n\
+    ///                └       ┘";
+    /// assert_eq!(format!("{}", a), correct);
+    /// a.resize(1, 2, 0);
+    /// assert_eq!(format!("{}", a), "┌     ┐\n│ 1 2 │\n└     ┘");
+    /// ```
+    pub fn resize(&mut self, m: usize, n: usize, value: T) {
+        if m == self.nrow {
+            // the columns are contiguous; thus, truncating or appending is enough
+            self.data.resize(m * n, value);
+        } else {
+            let mut data = vec![value; m * n];
+            let mm = cmp::min(m, self.nrow);
+            for j in 0..cmp::min(n, self.ncol) {
+                let start = j * self.nrow;
+                data[(j * m)..(j * m + mm)].copy_from_slice(&self.data[start..(start + mm)]);
+            }
+            self.data = data;
+        }
+        self.nrow = m;
+        self.ncol = n;
+    }
+
     /// Returns an access to the underlying data
     ///
     /// # Note
@@ -759,6 +960,330 @@ where
         }
         res
     }
+
+    /// Returns an iterator over the components of a row
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::NumMatrix;
+    /// let a = NumMatrix::<f64>::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// let row: Vec<_> = a.row(1).copied().collect();
+    /// assert_eq!(row, [4.0, 5.0, 6.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the row index is out-of-bounds.
+    pub fn row(&self, i: usize) -> std::iter::StepBy<std::slice::Iter<'_, T>> {
+        assert!(i < self.nrow);
+        self.data[i..].iter().step_by(self.nrow)
+    }
+
+    /// Returns an iterator over the components of a column
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::NumMatrix;
+    /// let a = NumMatrix::<f64>::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// let column: Vec<_> = a.col(2).copied().collect();
+    /// assert_eq!(column, [3.0, 6.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the column index is out-of-bounds.
+    pub fn col(&self, j: usize) -> std::slice::Iter<'_, T> {
+        assert!(j < self.ncol);
+        self.data[(j * self.nrow)..((j + 1) * self.nrow)].iter()
+    }
+
+    /// Returns an iterator over the rows, each one given as an iterator over its components
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::NumMatrix;
+    /// let a = NumMatrix::<f64>::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// let sums: Vec<f64> = a.rows().map(|row| row.sum()).collect();
+    /// assert_eq!(sums, [6.0, 15.0]);
+    /// ```
+    pub fn rows(&self) -> impl Iterator<Item = std::iter::StepBy<std::slice::Iter<'_, T>>> {
+        (0..self.nrow).map(move |i| self.row(i))
+    }
+
+    /// Returns an iterator over the columns, each one given as a slice
+    ///
+    /// The columns are contiguous because the data is stored in col-major order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::NumMatrix;
+    /// let a = NumMatrix::<f64>::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// let sums: Vec<f64> = a.cols().map(|col| col.iter().sum()).collect();
+    /// assert_eq!(sums, [5.0, 7.0, 9.0]);
+    /// ```
+    pub fn cols(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.ncol).map(move |j| &self.data[(j * self.nrow)..((j + 1) * self.nrow)])
+    }
+
+    /// Applies a function over all components of this matrix
+    ///
+    /// ```text
+    /// a := map(function(aij))
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::NumMatrix;
+    /// let mut a = NumMatrix::<f64>::from(&[[1.0, 2.0], [3.0, 4.0]]);
+    /// a.map(|x| x * x);
+    /// let correct = "┌       ┐\n\
+    ///                │  1  4 │\n\
+    ///                │  9 16 │\n\
+    ///                └       ┘";
+    /// assert_eq!(format!("{}", a), correct);
+    /// ```
+    pub fn map<F>(&mut self, function: F)
+    where
+        F: Fn(T) -> T,
+    {
+        for elem in self.data.iter_mut() {
+            *elem = function(*elem);
+        }
+    }
+
+    /// Applies a function (with indices) over all components of this matrix
+    ///
+    /// ```text
+    /// a := map(function(i, j, aij))
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::NumMatrix;
+    /// let mut a = NumMatrix::<f64>::from(&[[1.0, 2.0], [3.0, 4.0]]);
+    /// a.map_with_indices(|i, j, x| x + (10 * i + j) as f64);
+    /// let correct = "┌       ┐\n\
+    ///                │  1  3 │\n\
+    ///                │ 13 15 │\n\
+    ///                └       ┘";
+    /// assert_eq!(format!("{}", a), correct);
+    /// ```
+    pub fn map_with_indices<F>(&mut self, function: F)
+    where
+        F: Fn(usize, usize, T) -> T,
+    {
+        for (index, elem) in self.data.iter_mut().enumerate() {
+            *elem = function(index % self.nrow, index / self.nrow, *elem);
+        }
+    }
+
+    /// Returns a mapped version of this matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::NumMatrix;
+    /// let a = NumMatrix::<f64>::from(&[[1.0, 2.0], [3.0, 4.0]]);
+    /// let b = a.get_mapped(|x| 5.0 - x);
+    /// assert_eq!(
+    ///     format!("{}", b),
+    ///     "┌     ┐\n\
+    ///      │ 4 3 │\n\
+    ///      │ 2 1 │\n\
+    ///      └     ┘"
+    /// );
+    /// ```
+    pub fn get_mapped<F>(&self, function: F) -> Self
+    where
+        F: Fn(T) -> T,
+    {
+        let mut res = self.clone();
+        res.map(function);
+        res
+    }
+
+    /// Returns a structure to display this matrix with options such as precision and truncation
+    ///
+    /// See [DisplayOpts] for the available options; the structure implements [fmt::Display].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::NumMatrix;
+    /// let a = NumMatrix::<f64>::filled(1000, 1000, 0.5);
+    /// assert_eq!(
+    ///     format!("{}", a.display_opts().max_rows(3).max_cols(3)),
+    ///     "┌                 ┐\n\
+    ///      │ 0.5 0.5   … 0.5 │\n\
+    ///      │ 0.5 0.5   … 0.5 │\n\
+    ///      │   ⋮   ⋮   ⋱   ⋮ │\n\
+    ///      │ 0.5 0.5   … 0.5 │\n\
+    ///      └                 ┘"
+    /// );
+    /// ```
+    pub fn display_opts(&self) -> DisplayOpts<'_, T> {
+        DisplayOpts::new(self.nrow, self.ncol, Box::new(move |i, j| self.get(i, j)))
+    }
+}
+
+impl<T> NumMatrix<T>
+where
+    T: AddAssign + MulAssign + Num + NumCast + Copy + DeserializeOwned + Serialize,
+{
+    /// Creates new Hilbert matrix (a classical ill-conditioned test matrix)
+    ///
+    /// ```text
+    /// aᵢⱼ = 1 / (i + j + 1)
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::NumMatrix;
+    /// let a = NumMatrix::<f64>::hilbert(3);
+    /// assert_eq!(
+    ///     format!("{:.4}", a),
+    ///     "┌                      ┐\n\
+    ///      │ 1.0000 0.5000 0.3333 │\n\
+    ///      │ 0.5000 0.3333 0.2500 │\n\
+    ///      │ 0.3333 0.2500 0.2000 │\n\
+    ///      └                      ┘"
+    /// );
+    /// ```
+    pub fn hilbert(m: usize) -> Self {
+        let mut matrix = NumMatrix::new(m, m);
+        for i in 0..m {
+            for j in 0..m {
+                let den: T = cast(i + j + 1).unwrap();
+                matrix.data[i + j * m] = T::one() / den;
+            }
+        }
+        matrix
+    }
+}
+
+impl NumMatrix<f64> {
+    /// Creates new matrix with pseudo-random components uniformly distributed in [lo, hi)
+    ///
+    /// The same seed yields exactly the same matrix on every platform.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::Matrix;
+    /// let a = Matrix::random(2, 3, -1.0, 1.0, 1234);
+    /// assert_eq!(a.dims(), (2, 3));
+    /// assert!(a.as_data().iter().all(|x| *x >= -1.0 && *x < 1.0));
+    /// assert_eq!(Matrix::random(2, 3, -1.0, 1.0, 1234).as_data(), a.as_data());
+    /// ```
+    pub fn random(m: usize, n: usize, lo: f64, hi: f64, seed: u64) -> Self {
+        let mut gen = TestDataGenerator::new(seed);
+        NumMatrix::from(&gen.matrix(m, n, lo, hi))
+    }
+
+    /// Creates new pseudo-random symmetric positive-definite matrix with given (2-norm) condition number
+    ///
+    /// The eigenvalues are geometrically distributed from 1 to 1/cond.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::{check_spd, Matrix};
+    /// let a = Matrix::random_spd(4, 100.0, 1234);
+    /// check_spd(&a, 1e-14);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if `cond` < 1.
+    pub fn random_spd(n: usize, cond: f64, seed: u64) -> Self {
+        let mut gen = TestDataGenerator::new(seed);
+        NumMatrix::from(&gen.spd_with_condition(n, cond))
+    }
+
+    /// Creates new pseudo-random orthogonal matrix (uniformly distributed over the orthogonal matrices)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::{check_orthogonal, Matrix};
+    /// let q = Matrix::random_orthogonal(4, 1234);
+    /// check_orthogonal(&q, 1e-14);
+    /// ```
+    pub fn random_orthogonal(n: usize, seed: u64) -> Self {
+        let mut gen = TestDataGenerator::new(seed);
+        NumMatrix::from(&gen.orthogonal(n))
+    }
+
+    /// Returns true if the components of two matrices differ by at most `tol` (absolute difference)
+    ///
+    /// Unlike [crate::mat_approx_eq], this function does not panic. It returns false if
+    /// the dimensions differ or any difference is not a number (NaN).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::Matrix;
+    /// let a = Matrix::from(&[[1.0, 2.0], [3.0, 4.0]]);
+    /// let b = Matrix::from(&[[1.0, 2.0], [3.0, 4.0 + 1e-10]]);
+    /// assert!(a.approx_eq(&b, 1e-8));
+    /// assert!(!a.approx_eq(&b, 1e-12));
+    /// assert!(!a.approx_eq(&Matrix::new(2, 3), 1e8));
+    /// ```
+    pub fn approx_eq(&self, other: &Self, tol: f64) -> bool {
+        if self.nrow != other.nrow || self.ncol != other.ncol {
+            return false;
+        }
+        self.data
+            .iter()
+            .zip(other.data.iter())
+            .all(|(a, b)| f64::abs(a - b) <= tol)
+    }
+
+    /// Finds the maximum absolute difference between the components of two matrices
+    ///
+    /// Returns `(i, j, max_abs_diff)` or an error if the dimensions differ. See [crate::mat_max_abs_diff].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::Matrix;
+    /// let a = Matrix::from(&[[1.0, 2.0], [3.0, 4.0]]);
+    /// let b = Matrix::from(&[[1.0, 2.0], [3.5, 4.0]]);
+    /// let (i, j, diff) = a.max_abs_diff(&b).unwrap();
+    /// assert_eq!((i, j), (1, 0));
+    /// assert_eq!(diff, 0.5);
+    /// ```
+    pub fn max_abs_diff(&self, other: &Self) -> Result<(usize, usize, f64), StrError> {
+        crate::mat_max_abs_diff(self, other)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T> NumMatrix<T>
+where
+    T: AddAssign + MulAssign + Num + Copy + DeserializeOwned + Serialize + Send + Sync,
+{
+    /// Applies a function over all components of this matrix in parallel
+    ///
+    /// ```text
+    /// a := map(function(aij))
+    /// ```
+    ///
+    /// **Note:** This function is only available with the `rayon` feature.
+    pub fn par_map<F>(&mut self, function: F)
+    where
+        F: Fn(T) -> T + Send + Sync,
+    {
+        use rayon::prelude::*;
+        self.data.par_iter_mut().for_each(|elem| *elem = function(*elem));
+    }
 }
 
 impl<T> fmt::Display for NumMatrix<T>
@@ -847,7 +1372,8 @@ where
 #[cfg(test)]
 mod tests {
     use super::NumMatrix;
-    use crate::AsArray2D;
+    use crate::{check_orthogonal, check_spd, AsArray2D};
+    use num_complex::Complex64;
     use serde::{Deserialize, Serialize};
 
     #[test]
@@ -919,6 +1445,70 @@ mod tests {
         assert_eq!(a.data, [-8.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 1.0]);
     }
 
+    #[test]
+    fn toeplitz_works() {
+        let a = NumMatrix::<f64>::toeplitz(&[1.0, 2.0], &[100.0, 3.0, 4.0]);
+        assert_eq!(a.dims(), (2, 3));
+        assert_eq!(a.data, [1.0, 2.0, 3.0, 1.0, 4.0, 3.0]);
+        let a = NumMatrix::<f64>::toeplitz(&[1.0, 2.0, 3.0], &[1.0]);
+        assert_eq!(a.data, [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn vandermonde_works() {
+        let a = NumMatrix::<f64>::vandermonde(&[2.0, -1.0], 4);
+        assert_eq!(a.dims(), (2, 4));
+        assert_eq!(a.data, [1.0, 1.0, 2.0, -1.0, 4.0, 1.0, 8.0, -1.0]);
+        let a = NumMatrix::<f64>::vandermonde(&[2.0, -1.0], 0);
+        assert_eq!(a.dims(), (2, 0));
+    }
+
+    #[test]
+    fn approx_eq_and_max_abs_diff_work() {
+        let a = NumMatrix::<f64>::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b = NumMatrix::<f64>::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.001]]);
+        assert!(a.approx_eq(&a, 0.0));
+        assert!(a.approx_eq(&b, 1e-2));
+        assert!(!a.approx_eq(&b, 1e-4));
+        assert!(!a.approx_eq(&NumMatrix::<f64>::new(3, 2), 1e10));
+        let mut c = a.clone();
+        c.set(0, 0, f64::NAN);
+        assert!(!a.approx_eq(&c, 1e10));
+        let (i, j, diff) = a.max_abs_diff(&b).unwrap();
+        assert_eq!((i, j), (1, 2));
+        assert!(f64::abs(diff - 0.001) < 1e-14);
+        assert_eq!(
+            a.max_abs_diff(&NumMatrix::<f64>::new(3, 2)).err(),
+            Some("matrices are incompatible")
+        );
+    }
+
+    #[test]
+    fn random_works() {
+        let a = NumMatrix::<f64>::random(3, 2, 2.0, 5.0, 7);
+        assert_eq!(a.dims(), (3, 2));
+        assert!(a.data.iter().all(|x| *x >= 2.0 && *x < 5.0));
+        let b = NumMatrix::<f64>::random(3, 2, 2.0, 5.0, 7);
+        assert_eq!(a.data, b.data);
+        let c = NumMatrix::<f64>::random(3, 2, 2.0, 5.0, 8);
+        assert_ne!(a.data, c.data);
+
+        let a = NumMatrix::<f64>::random_spd(3, 10.0, 7);
+        assert_eq!(a.dims(), (3, 3));
+        check_spd(&a, 1e-14);
+        let q = NumMatrix::<f64>::random_orthogonal(3, 7);
+        assert_eq!(q.dims(), (3, 3));
+        check_orthogonal(&q, 1e-14);
+    }
+
+    #[test]
+    fn hilbert_works() {
+        let a = NumMatrix::<f64>::hilbert(2);
+        assert_eq!(a.data, [1.0, 0.5, 0.5, 1.0 / 3.0]);
+        let a = NumMatrix::<f64>::hilbert(0);
+        assert_eq!(a.dims(), (0, 0));
+    }
+
     #[test]
     fn from_text_file_handles_problems() {
         assert_eq!(NumMatrix::<f64>::from_text_file("").err(), Some("cannot open file"),);
@@ -934,6 +1524,30 @@ mod tests {
             NumMatrix::<f64>::from_text_file("./data/matrices/bad_wrong_data.txt").err(),
             Some("cannot parse value"),
         );
+        assert_eq!(
+            NumMatrix::<f64>::from_text_file("./data/matrices/bad_too_many_columns.txt").err(),
+            Some("row has more columns than the first row"),
+        );
+    }
+
+    #[test]
+    fn from_text_file_ex_reports_line_numbers() {
+        let err = NumMatrix::<f64>::from_text_file_ex("not-found").err().unwrap();
+        assert_eq!(err.line, 0);
+        assert_eq!(format!("{}", err), "cannot open file");
+        let err = NumMatrix::<f64>::from_text_file_ex("./data/matrices/bad_missing_data.txt")
+            .err()
+            .unwrap();
+        assert_eq!(err.message, "column data is missing");
+        assert_eq!(err.line, 4);
+        let err = NumMatrix::<f64>::from_text_file_ex("./data/matrices/bad_wrong_data.txt")
+            .err()
+            .unwrap();
+        assert_eq!(format!("{}", err), "cannot parse value (line 1)");
+        let err = NumMatrix::<f64>::from_text_file_ex("./data/matrices/bad_too_many_columns.txt")
+            .err()
+            .unwrap();
+        assert_eq!(format!("{}", err), "row has more columns than the first row (line 5)");
     }
 
     #[test]
@@ -972,6 +1586,10 @@ mod tests {
              │ 7 8 9 │\n\
              └       ┘"
         );
+
+        let a = NumMatrix::<f64>::from_text_file("./data/matrices/ok_fortran.txt").unwrap();
+        assert_eq!(a.dims(), (2, 3));
+        assert_eq!(a.as_data(), &[1500.0, -0.25, 0.001, 2.0, 1e10, 3.0]);
     }
 
     #[test]
@@ -1046,6 +1664,37 @@ mod tests {
         assert_eq!(a.data, correct);
     }
 
+    #[test]
+    fn reshape_works() {
+        let mut a = NumMatrix::<f64>::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert_eq!(
+            a.reshape(2, 2),
+            Err("the new dimensions must yield the same number of components")
+        );
+        a.reshape(1, 6).unwrap();
+        assert_eq!(a.dims(), (1, 6));
+        assert_eq!(a.data, &[1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+        a.reshape(6, 1).unwrap();
+        assert_eq!(a.get(5, 0), 6.0);
+    }
+
+    #[test]
+    fn resize_works() {
+        let mut a = NumMatrix::<f64>::from(&[[1.0, 2.0], [3.0, 4.0]]);
+        a.resize(2, 3, 9.0);
+        assert_eq!(a.dims(), (2, 3));
+        assert_eq!(a.data, &[1.0, 3.0, 2.0, 4.0, 9.0, 9.0]);
+        a.resize(3, 2, 8.0);
+        assert_eq!(a.dims(), (3, 2));
+        assert_eq!(a.data, &[1.0, 3.0, 8.0, 2.0, 4.0, 8.0]);
+        a.resize(1, 1, 0.0);
+        assert_eq!(a.data, &[1.0]);
+        a.resize(0, 0, 0.0);
+        assert_eq!(a.dims(), (0, 0));
+        a.resize(1, 2, 5.0);
+        assert_eq!(a.data, &[5.0, 5.0]);
+    }
+
     #[test]
     #[should_panic]
     fn get_panics_on_wrong_indices() {
@@ -1159,6 +1808,59 @@ mod tests {
         assert_eq!(second_column, [5.0, 6.0, 7.0, 8.0]);
     }
 
+    #[test]
+    fn row_col_iterators_work() {
+        #[rustfmt::skip]
+        let a = NumMatrix::<f64>::from(&[
+            [1.0, 2.0],
+            [3.0, 4.0],
+            [5.0, 6.0],
+        ]);
+        assert_eq!(a.row(0).copied().collect::<Vec<_>>(), [1.0, 2.0]);
+        assert_eq!(a.row(2).copied().collect::<Vec<_>>(), [5.0, 6.0]);
+        assert_eq!(a.col(1).copied().collect::<Vec<_>>(), [2.0, 4.0, 6.0]);
+        let rows: Vec<Vec<f64>> = a.rows().map(|r| r.copied().collect()).collect();
+        assert_eq!(rows, [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let cols: Vec<&[f64]> = a.cols().collect();
+        assert_eq!(cols, [[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]]);
+
+        // empty
+        let b = NumMatrix::<f64>::new(0, 2);
+        assert_eq!(b.rows().count(), 0);
+        assert_eq!(b.cols().map(|c| c.len()).collect::<Vec<_>>(), [0, 0]);
+    }
+
+    #[test]
+    fn map_works() {
+        let mut a = NumMatrix::<f64>::from(&[[1.0, -2.0], [3.0, -4.0], [5.0, -6.0]]);
+        a.map(|x| 2.0 * x);
+        assert_eq!(a.data, &[2.0, 6.0, 10.0, -4.0, -8.0, -12.0]);
+    }
+
+    #[test]
+    fn map_with_indices_works() {
+        let mut a = NumMatrix::<f64>::new(3, 2);
+        a.map_with_indices(|i, j, x| x + (10 * i + j) as f64);
+        assert_eq!(a.data, &[0.0, 10.0, 20.0, 1.0, 11.0, 21.0]);
+    }
+
+    #[test]
+    fn get_mapped_works() {
+        let mut a = NumMatrix::<f64>::from(&[[1.0, 2.0], [3.0, 4.0]]);
+        let b = a.get_mapped(|x| x * x);
+        a.set(0, 0, 0.5);
+        assert_eq!(a.data, &[0.5, 3.0, 2.0, 4.0]);
+        assert_eq!(b.data, &[1.0, 9.0, 4.0, 16.0]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_map_works() {
+        let mut a = NumMatrix::<f64>::from(&[[1.0, -2.0], [3.0, -4.0]]);
+        a.par_map(|x| x * x);
+        assert_eq!(a.data, &[1.0, 9.0, 4.0, 16.0]);
+    }
+
     #[test]
     fn clone_and_serialize_work() {
         #[rustfmt::skip]
@@ -1246,6 +1948,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn complex_serialize_works() {
+        let a = NumMatrix::<Complex64>::from(&[
+            [Complex64::new(1.0, -1.0), Complex64::new(2.0, 0.5)],
+            [Complex64::new(3.0, 0.0), Complex64::new(4.0, 2.0)],
+        ]);
+        let json = serde_json::to_string(&a)
+            .map_err(|_| "serde_json::to_string failed")
+            .unwrap();
+        assert_eq!(
+            json,
+            r#"{"nrow":2,"ncol":2,"data":[[1.0,-1.0],[3.0,0.0],[2.0,0.5],[4.0,2.0]]}"#
+        );
+        let from_json: NumMatrix<Complex64> = serde_json::from_str(&json)
+            .map_err(|_| "serde_json::from_str failed")
+            .unwrap();
+        assert_eq!(from_json.dims(), (2, 2));
+        assert_eq!(from_json.as_data(), a.as_data());
+    }
+
     fn array_2d_test<'a, T, U>(array: &'a T) -> String
     where
         T: AsArray2D<'a, U>,
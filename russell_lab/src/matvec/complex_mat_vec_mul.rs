@@ -0,0 +1,142 @@
+use crate::matrix::ComplexMatrix;
+use crate::vector::ComplexVector;
+use crate::StrError;
+use num_complex::Complex64;
+use russell_openblas::{to_i32, zgemv};
+
+/// Performs the matrix-vector multiplication resulting in a vector (complex version)
+///
+/// ```text
+///  v  :=  α ⋅  a   ⋅  u
+/// (m)        (m,n)   (n)
+/// ```
+///
+/// # Note
+///
+/// The length of vector `u` must equal the number of columns of matrix `a` and
+/// the length of vector `v` must equal the number of rows of matrix `a`.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{complex_mat_vec_mul, ComplexMatrix, ComplexVector, StrError};
+/// use num_complex::Complex64;
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = ComplexMatrix::from(&[
+///         [ 5.0, -2.0, 1.0],
+///         [-4.0,  0.0, 2.0],
+///         [15.0, -6.0, 0.0],
+///         [ 3.0,  5.0, 1.0],
+///     ]);
+///     let u = ComplexVector::from(&[1.0, 2.0, 3.0]);
+///     let mut v = ComplexVector::new(a.nrow());
+///     complex_mat_vec_mul(&mut v, Complex64::new(0.5, 0.0), &a, &u)?;
+///     let correct = "┌        ┐\n\
+///                    │   2+0i │\n\
+///                    │   1+0i │\n\
+///                    │ 1.5+0i │\n\
+///                    │   8+0i │\n\
+///                    └        ┘";
+///     assert_eq!(format!("{}", v), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn complex_mat_vec_mul(
+    v: &mut ComplexVector,
+    alpha: Complex64,
+    a: &ComplexMatrix,
+    u: &ComplexVector,
+) -> Result<(), StrError> {
+    let m = v.dim();
+    let n = u.dim();
+    if m != a.nrow() || n != a.ncol() {
+        return Err("matrix and vectors are incompatible");
+    }
+    if m == 0 || n == 0 {
+        return Ok(());
+    }
+    let m_i32 = to_i32(m);
+    let n_i32 = to_i32(n);
+    let zero = Complex64::new(0.0, 0.0);
+    zgemv(
+        false,
+        m_i32,
+        n_i32,
+        alpha,
+        a.as_data(),
+        u.as_data(),
+        1,
+        zero,
+        v.as_mut_data(),
+        1,
+    );
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{complex_mat_vec_mul, ComplexMatrix, ComplexVector};
+    use num_complex::Complex64;
+    use russell_chk::complex_vec_approx_eq;
+
+    #[test]
+    fn complex_mat_vec_mul_fails_on_wrong_dims() {
+        let u = ComplexVector::new(2);
+        let a_1x2 = ComplexMatrix::new(1, 2);
+        let a_3x1 = ComplexMatrix::new(3, 1);
+        let mut v = ComplexVector::new(3);
+        let one = Complex64::new(1.0, 0.0);
+        assert_eq!(
+            complex_mat_vec_mul(&mut v, one, &a_1x2, &u),
+            Err("matrix and vectors are incompatible")
+        );
+        assert_eq!(
+            complex_mat_vec_mul(&mut v, one, &a_3x1, &u),
+            Err("matrix and vectors are incompatible")
+        );
+    }
+
+    #[test]
+    fn complex_mat_vec_mul_works() {
+        let a = ComplexMatrix::from(&[
+            [
+                Complex64::new(1.0, 1.0),
+                Complex64::new(0.0, 2.0),
+                Complex64::new(3.0, 0.0),
+            ],
+            [
+                Complex64::new(-1.0, 0.0),
+                Complex64::new(2.0, -1.0),
+                Complex64::new(0.0, 0.0),
+            ],
+        ]);
+        let u = ComplexVector::from(&[
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 1.0),
+            Complex64::new(1.0, -1.0),
+        ]);
+        let mut v = ComplexVector::new(a.nrow());
+        // row 0: (1+i) + (2i)(i) + 3(1-i) = (1+i) - 2 + (3-3i) = 2-2i
+        // row 1: -1 + (2-i)(i) = -1 + (1+2i) = 2i
+        complex_mat_vec_mul(&mut v, Complex64::new(0.0, 1.0), &a, &u).unwrap();
+        let correct = &[Complex64::new(2.0, 2.0), Complex64::new(-2.0, 0.0)];
+        complex_vec_approx_eq(v.as_data(), correct, 1e-15);
+    }
+
+    #[test]
+    fn complex_mat_vec_mul_zero_works() {
+        let a_0x0 = ComplexMatrix::new(0, 0);
+        let a_1x0 = ComplexMatrix::new(1, 0);
+        let u0 = ComplexVector::new(0);
+        let mut v0 = ComplexVector::new(0);
+        let mut v1 = ComplexVector::new(1);
+        let one = Complex64::new(1.0, 0.0);
+        complex_mat_vec_mul(&mut v0, one, &a_0x0, &u0).unwrap();
+        assert_eq!(v0.dim(), 0);
+        complex_mat_vec_mul(&mut v1, one, &a_1x0, &u0).unwrap();
+        assert_eq!(v1.as_data(), &[Complex64::new(0.0, 0.0)]);
+    }
+}
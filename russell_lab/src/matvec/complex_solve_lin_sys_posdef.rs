@@ -0,0 +1,130 @@
+use crate::matrix::ComplexMatrix;
+use crate::vector::ComplexVector;
+use crate::Error;
+use russell_openblas::{to_i32, zposv};
+
+/// Solves a linear system with a Hermitian positive-definite matrix (complex version)
+///
+/// For a Hermitian positive-definite matrix `a`, find `x` such that:
+///
+/// ```text
+///   a   ⋅  x  =  b
+/// (m,m)   (m)   (m)
+/// ```
+///
+/// However, the right-hand-side will hold the solution:
+///
+/// ```text
+/// b := a⁻¹⋅b == x
+/// ```
+///
+/// The solution is obtained via Cholesky decomposition using Lapack zposv routine.
+///
+/// # Note
+///
+/// 1. Only the lower triangle (and diagonal) of `a` is accessed
+/// 2. The matrix `a` will be modified
+/// 3. The right-hand-side `b` will contain the solution `x`
+///
+/// ```
+/// use num_complex::Complex64;
+/// use russell_chk::complex_vec_approx_eq;
+/// use russell_lab::{complex_solve_lin_sys_posdef, ComplexMatrix, ComplexVector, StrError};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     // set matrix and right-hand side
+///     let mut a = ComplexMatrix::from(&[
+///         [Complex64::new(2.0, 0.0), Complex64::new(1.0, -1.0)],
+///         [Complex64::new(1.0, 1.0), Complex64::new(3.0,  0.0)],
+///     ]);
+///     let mut b = ComplexVector::from(&[Complex64::new(4.0, 0.0), Complex64::new(6.0, 2.0)]);
+///
+///     // solve linear system b := a⁻¹⋅b
+///     complex_solve_lin_sys_posdef(&mut b, &mut a)?;
+///
+///     // check
+///     let x_correct = &[Complex64::new(1.0, 1.0), Complex64::new(2.0, 0.0)];
+///     complex_vec_approx_eq(b.as_data(), x_correct, 1e-14);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+pub fn complex_solve_lin_sys_posdef(b: &mut ComplexVector, a: &mut ComplexMatrix) -> Result<(), Error> {
+    let (m, n) = a.dims();
+    if m != n {
+        return Err(Error::BadDimensions("matrix must be square"));
+    }
+    if b.dim() != m {
+        return Err(Error::BadDimensions("vector has wrong dimension"));
+    }
+    if m == 0 {
+        return Ok(());
+    }
+    let m_i32 = to_i32(m);
+    zposv(false, m_i32, 1, a.as_mut_data(), b.as_mut_data())?;
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(unused_imports))]
+mod tests {
+    use super::{complex_solve_lin_sys_posdef, ComplexMatrix, ComplexVector};
+    use crate::Error;
+    use num_complex::Complex64;
+    use russell_chk::complex_vec_approx_eq;
+
+    #[test]
+    fn complex_solve_lin_sys_posdef_fails_on_wrong_dims() {
+        let mut a = ComplexMatrix::new(2, 3);
+        let mut b = ComplexVector::new(2);
+        assert_eq!(
+            complex_solve_lin_sys_posdef(&mut b, &mut a),
+            Err(Error::BadDimensions("matrix must be square"))
+        );
+        let mut a = ComplexMatrix::new(2, 2);
+        let mut b = ComplexVector::new(3);
+        assert_eq!(
+            complex_solve_lin_sys_posdef(&mut b, &mut a),
+            Err(Error::BadDimensions("vector has wrong dimension"))
+        );
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn complex_solve_lin_sys_posdef_fails_on_non_posdef() {
+        let mut a = ComplexMatrix::new(2, 2);
+        let mut b = ComplexVector::new(2);
+        assert_eq!(
+            complex_solve_lin_sys_posdef(&mut b, &mut a),
+            Err(Error::Singular("LAPACK zposv failed"))
+        );
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn complex_solve_lin_sys_posdef_works() {
+        // the upper triangle is not accessed
+        #[rustfmt::skip]
+        let mut a = ComplexMatrix::from(&[
+            [Complex64::new( 4.0, 0.0), Complex64::new(9.0, 9.0), Complex64::new(9.0, 9.0)],
+            [Complex64::new( 2.0, 2.0), Complex64::new(6.0, 0.0), Complex64::new(9.0, 9.0)],
+            [Complex64::new( 0.0,-2.0), Complex64::new(1.0, 1.0), Complex64::new(5.0, 0.0)],
+        ]);
+        // x = [1, i, 1 - i]
+        let mut b = ComplexVector::from(&[
+            Complex64::new(8.0, 4.0),
+            Complex64::new(2.0, 6.0),
+            Complex64::new(4.0, -6.0),
+        ]);
+        complex_solve_lin_sys_posdef(&mut b, &mut a).unwrap();
+        let x_correct = &[
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 1.0),
+            Complex64::new(1.0, -1.0),
+        ];
+        complex_vec_approx_eq(b.as_data(), x_correct, 1e-14);
+    }
+}
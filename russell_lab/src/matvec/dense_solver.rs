@@ -0,0 +1,219 @@
+use crate::matrix::{LuFactors, Matrix};
+use crate::vector::Vector;
+use crate::{Error, Factorization};
+use russell_openblas::{dpotrf, dpotrs, to_i32};
+
+/// Solves dense linear systems by factorizing the matrix once and reusing the factors
+///
+/// Iterative algorithms (e.g., Newton's method or time-stepping) often solve many systems
+/// with the same matrix. With `DenseSolver`, the matrix is factorized once by `factorize`
+/// and each call to `solve` performs only the (cheap) forward and backward substitutions.
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::vec_approx_eq;
+/// use russell_lab::{DenseSolver, Factorization, Matrix, StrError, Vector};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     // set matrix
+///     let a = Matrix::from(&[
+///         [  4.0,  12.0, -16.0],
+///         [ 12.0,  37.0, -43.0],
+///         [-16.0, -43.0,  98.0],
+///     ]);
+///
+///     // factorize once
+///     let mut solver = DenseSolver::new(Factorization::Cholesky);
+///     solver.factorize(&a)?;
+///
+///     // solve many times
+///     let mut x = Vector::new(3);
+///     solver.solve(&mut x, &Vector::from(&[0.0, 6.0, 39.0]))?;
+///     vec_approx_eq(x.as_data(), &[1.0, 1.0, 1.0], 1e-13);
+///     solver.solve(&mut x, &Vector::from(&[4.0, 12.0, -16.0]))?;
+///     vec_approx_eq(x.as_data(), &[1.0, 0.0, 0.0], 1e-13);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+#[derive(Clone, Debug)]
+pub struct DenseSolver {
+    /// Holds the kind of factorization
+    kind: Factorization,
+
+    /// Holds the LU factors (if kind is Lu and factorize has been called)
+    lu: Option<LuFactors>,
+
+    /// Holds the lower triangular Cholesky factor (if kind is Cholesky and factorize has been called)
+    l: Option<Matrix>,
+}
+
+impl DenseSolver {
+    /// Allocates a new instance
+    pub fn new(kind: Factorization) -> Self {
+        DenseSolver {
+            kind,
+            lu: None,
+            l: None,
+        }
+    }
+
+    /// Returns the kind of factorization
+    pub fn kind(&self) -> Factorization {
+        self.kind
+    }
+
+    /// Returns the dimension of the factorized matrix (None if factorize has not been called)
+    pub fn dim(&self) -> Option<usize> {
+        match self.kind {
+            Factorization::Lu => self.lu.as_ref().map(|lu| lu.dim()),
+            Factorization::Cholesky => self.l.as_ref().map(|l| l.nrow()),
+        }
+    }
+
+    /// Factorizes a square matrix, replacing any previous factorization
+    ///
+    /// **Note:** With [Factorization::Cholesky], only the lower triangle (and diagonal) of `a` is accessed.
+    pub fn factorize(&mut self, a: &Matrix) -> Result<(), Error> {
+        self.lu = None;
+        self.l = None;
+        let (m, n) = a.dims();
+        if m != n {
+            return Err(Error::BadDimensions("matrix must be square"));
+        }
+        match self.kind {
+            Factorization::Lu => {
+                let lu = LuFactors::new(a)?;
+                if lu.is_singular() {
+                    return Err(Error::Singular("cannot factorize a singular matrix"));
+                }
+                self.lu = Some(lu);
+            }
+            Factorization::Cholesky => {
+                let mut l = Matrix::new(m, m);
+                for i in 0..m {
+                    for j in 0..(i + 1) {
+                        l.set(i, j, a.get(i, j));
+                    }
+                }
+                if m > 0 {
+                    let m_i32 = to_i32(m);
+                    dpotrf(false, m_i32, l.as_mut_data())?;
+                }
+                self.l = Some(l);
+            }
+        }
+        Ok(())
+    }
+
+    /// Solves the linear system `a ⋅ x = b` using the factors computed by `factorize`
+    ///
+    /// # Output
+    ///
+    /// * `x` -- (m) the solution
+    ///
+    /// # Input
+    ///
+    /// * `b` -- (m) the right-hand side
+    pub fn solve(&self, x: &mut Vector, b: &Vector) -> Result<(), Error> {
+        let m = match self.dim() {
+            Some(m) => m,
+            None => return Err(Error::Other("factorize must be called first")),
+        };
+        if x.dim() != m || b.dim() != m {
+            return Err(Error::BadDimensions("vectors are incompatible"));
+        }
+        x.as_mut_data().copy_from_slice(b.as_data());
+        match self.kind {
+            Factorization::Lu => self.lu.as_ref().unwrap().solve(x)?,
+            Factorization::Cholesky => {
+                if m > 0 {
+                    let l = self.l.as_ref().unwrap();
+                    dpotrs(false, to_i32(m), 1, l.as_data(), x.as_mut_data())?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(all(test, not(feature = "pure_rust")))]
+mod tests {
+    use super::DenseSolver;
+    use crate::{Error, Factorization, Matrix, Vector};
+    use russell_chk::vec_approx_eq;
+
+    #[test]
+    fn dense_solver_handles_errors() {
+        let mut solver = DenseSolver::new(Factorization::Lu);
+        let mut x = Vector::new(2);
+        let b = Vector::new(2);
+        assert_eq!(solver.dim(), None);
+        assert_eq!(
+            solver.solve(&mut x, &b),
+            Err(Error::Other("factorize must be called first"))
+        );
+        assert_eq!(
+            solver.factorize(&Matrix::new(2, 3)),
+            Err(Error::BadDimensions("matrix must be square"))
+        );
+        assert_eq!(
+            solver.factorize(&Matrix::new(2, 2)),
+            Err(Error::Singular("cannot factorize a singular matrix"))
+        );
+        assert_eq!(solver.dim(), None);
+        solver.factorize(&Matrix::from(&[[1.0, 0.0], [0.0, 1.0]])).unwrap();
+        let mut x_wrong = Vector::new(3);
+        assert_eq!(
+            solver.solve(&mut x_wrong, &b),
+            Err(Error::BadDimensions("vectors are incompatible"))
+        );
+        let b_wrong = Vector::new(3);
+        assert_eq!(
+            solver.solve(&mut x, &b_wrong),
+            Err(Error::BadDimensions("vectors are incompatible"))
+        );
+
+        let mut solver = DenseSolver::new(Factorization::Cholesky);
+        assert_eq!(
+            solver.factorize(&Matrix::from(&[[1.0, 2.0], [2.0, 1.0]])),
+            Err(Error::Singular("LAPACK dpotrf failed"))
+        );
+        assert_eq!(
+            solver.solve(&mut x, &b),
+            Err(Error::Other("factorize must be called first"))
+        );
+    }
+
+    #[test]
+    fn dense_solver_works() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [ 2.0, -1.0,  0.0,  0.0],
+            [-1.0,  2.0, -1.0,  0.0],
+            [ 0.0, -1.0,  2.0, -1.0],
+            [ 0.0,  0.0, -1.0,  2.0],
+        ]);
+        let mut x = Vector::new(4);
+        for kind in [Factorization::Lu, Factorization::Cholesky] {
+            let mut solver = DenseSolver::new(kind);
+            assert_eq!(solver.kind(), kind);
+            solver.factorize(&a).unwrap();
+            assert_eq!(solver.dim(), Some(4));
+            solver.solve(&mut x, &Vector::from(&[1.0, 0.0, 0.0, 1.0])).unwrap();
+            vec_approx_eq(x.as_data(), &[1.0, 1.0, 1.0, 1.0], 1e-14);
+            solver.solve(&mut x, &Vector::from(&[0.0, 0.0, 0.0, 5.0])).unwrap();
+            vec_approx_eq(x.as_data(), &[1.0, 2.0, 3.0, 4.0], 1e-14);
+        }
+
+        // zero-sized matrix
+        let mut solver = DenseSolver::new(Factorization::Cholesky);
+        solver.factorize(&Matrix::new(0, 0)).unwrap();
+        let mut x = Vector::new(0);
+        solver.solve(&mut x, &Vector::new(0)).unwrap();
+    }
+}
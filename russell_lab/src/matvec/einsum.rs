@@ -0,0 +1,497 @@
+use crate::matrix::{mat_mat_mul, mat_t_mat_mul, Matrix};
+use crate::vector::{vec_inner, Vector};
+use crate::{mat_vec_mul, vec_mat_mul, vec_outer, StrError};
+use russell_openblas::{ddot, to_i32};
+
+/// Holds an operand of [einsum] (a vector or a matrix)
+#[derive(Clone, Copy, Debug)]
+pub enum EinsumOperand<'a> {
+    /// A vector (one label)
+    Vector(&'a Vector),
+
+    /// A matrix (two labels)
+    Matrix(&'a Matrix),
+}
+
+impl<'a> From<&'a Vector> for EinsumOperand<'a> {
+    fn from(v: &'a Vector) -> Self {
+        EinsumOperand::Vector(v)
+    }
+}
+
+impl<'a> From<&'a Matrix> for EinsumOperand<'a> {
+    fn from(a: &'a Matrix) -> Self {
+        EinsumOperand::Matrix(a)
+    }
+}
+
+impl<'a> EinsumOperand<'a> {
+    /// Returns the number of labels (indices) of the operand
+    fn rank(&self) -> usize {
+        match self {
+            EinsumOperand::Vector(_) => 1,
+            EinsumOperand::Matrix(_) => 2,
+        }
+    }
+
+    /// Returns the dimension corresponding to the k-th label
+    fn dim(&self, k: usize) -> usize {
+        match self {
+            EinsumOperand::Vector(v) => v.dim(),
+            EinsumOperand::Matrix(a) => {
+                if k == 0 {
+                    a.nrow()
+                } else {
+                    a.ncol()
+                }
+            }
+        }
+    }
+
+    /// Returns the component given the values of the labels
+    fn get(&self, idx: &[usize]) -> f64 {
+        match self {
+            EinsumOperand::Vector(v) => v[idx[0]],
+            EinsumOperand::Matrix(a) => a.get(idx[0], idx[1]),
+        }
+    }
+}
+
+/// Holds the result of [einsum]
+#[derive(Clone, Debug)]
+pub enum EinsumOutput {
+    /// The result of a full contraction (no output labels)
+    Scalar(f64),
+
+    /// The result with one output label
+    Vector(Vector),
+
+    /// The result with two output labels
+    Matrix(Matrix),
+}
+
+impl EinsumOutput {
+    /// Returns the scalar result
+    pub fn scalar(&self) -> Result<f64, StrError> {
+        match self {
+            EinsumOutput::Scalar(s) => Ok(*s),
+            _ => Err("the einsum output is not a scalar"),
+        }
+    }
+
+    /// Returns the vector result
+    pub fn into_vector(self) -> Result<Vector, StrError> {
+        match self {
+            EinsumOutput::Vector(v) => Ok(v),
+            _ => Err("the einsum output is not a vector"),
+        }
+    }
+
+    /// Returns the matrix result
+    pub fn into_matrix(self) -> Result<Matrix, StrError> {
+        match self {
+            EinsumOutput::Matrix(a) => Ok(a),
+            _ => Err("the einsum output is not a matrix"),
+        }
+    }
+}
+
+/// Evaluates an Einstein summation (contraction) of two operands
+///
+/// The specification string holds the labels of the indices of each operand, separated by
+/// a comma, followed by `->` and the labels of the output. Labels that do not appear in the
+/// output are summed over. For example:
+///
+/// ```text
+/// "ij,jk->ik"  c[i][k] = Σ_j a[i][j] ⋅ b[j][k]   (matrix-matrix multiplication)
+/// "ji,jk->ik"  c[i][k] = Σ_j a[j][i] ⋅ b[j][k]   (aᵀ⋅b)
+/// "ij,j->i"    v[i]    = Σ_j a[i][j] ⋅ u[j]      (matrix-vector multiplication)
+/// "i,ij->j"    v[j]    = Σ_i u[i] ⋅ a[i][j]      (vector-matrix multiplication)
+/// "i,i->"      s       = Σ_i u[i] ⋅ v[i]         (inner product)
+/// "i,j->ij"    c[i][j] = u[i] ⋅ v[j]             (outer product)
+/// "ij,ij->"    s       = Σ_ij a[i][j] ⋅ b[i][j]  (double-dot product)
+/// "ij,ij->ij"  c[i][j] = a[i][j] ⋅ b[i][j]       (Hadamard product)
+/// "ii,jj->"    s       = tr(a) ⋅ tr(b)
+/// ```
+///
+/// The patterns listed above, except the last two, are computed by BLAS; the other patterns
+/// are computed by explicit loops over all labels. If `->` is omitted, the output labels are
+/// the labels appearing only once, in alphabetical order (e.g., `"ij,jk"` means `"ij,jk->ik"`).
+///
+/// # Input
+///
+/// * `spec` -- the specification string; labels must be lowercase letters and whitespace is ignored
+/// * `a` -- the first operand (a `&Vector` with one label or a `&Matrix` with two labels)
+/// * `b` -- the second operand (a `&Vector` with one label or a `&Matrix` with two labels)
+///
+/// # Output
+///
+/// Returns a scalar, a vector, or a matrix depending on the number of output labels (0, 1, or 2)
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{einsum, Matrix, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [1.0, 2.0],
+///         [3.0, 4.0],
+///     ]);
+///     let u = Vector::from(&[1.0, -1.0]);
+///
+///     // matrix-vector multiplication
+///     let v = einsum("ij,j->i", &a, &u)?.into_vector()?;
+///     assert_eq!(v.as_data(), &[-1.0, -1.0]);
+///
+///     // transposed matrix-matrix multiplication
+///     let c = einsum("ji,jk->ik", &a, &a)?.into_matrix()?;
+///     let correct = "┌       ┐\n\
+///                    │ 10 14 │\n\
+///                    │ 14 20 │\n\
+///                    └       ┘";
+///     assert_eq!(format!("{}", c), correct);
+///
+///     // double-dot product
+///     let s = einsum("ij,ij->", &a, &a)?.scalar()?;
+///     assert_eq!(s, 30.0);
+///     Ok(())
+/// }
+/// ```
+pub fn einsum<'a, A, B>(spec: &str, a: A, b: B) -> Result<EinsumOutput, StrError>
+where
+    A: Into<EinsumOperand<'a>>,
+    B: Into<EinsumOperand<'a>>,
+{
+    let (a, b) = (a.into(), b.into());
+
+    // parse the specification
+    let (la, lb, lo) = parse_spec(spec)?;
+    if la.len() != a.rank() || lb.len() != b.rank() {
+        return Err("einsum labels do not match the ranks of the operands");
+    }
+    if lo.len() > 2 {
+        return Err("einsum output must have at most two labels");
+    }
+    for (k, c) in lo.iter().enumerate() {
+        if lo[..k].contains(c) || !(la.contains(c) || lb.contains(c)) {
+            return Err("einsum output labels are invalid");
+        }
+    }
+
+    // find the distinct labels and their dimensions
+    let mut labels: Vec<u8> = Vec::new();
+    let mut dims: Vec<usize> = Vec::new();
+    for (op, ls) in [(&a, &la), (&b, &lb)] {
+        for (k, c) in ls.iter().enumerate() {
+            match labels.iter().position(|x| x == c) {
+                Some(p) => {
+                    if dims[p] != op.dim(k) {
+                        return Err("einsum dimensions are incompatible");
+                    }
+                }
+                None => {
+                    labels.push(*c);
+                    dims.push(op.dim(k));
+                }
+            }
+        }
+    }
+    let position = |c: &u8| labels.iter().position(|x| x == c).unwrap();
+    let pa: Vec<usize> = la.iter().map(position).collect();
+    let pb: Vec<usize> = lb.iter().map(position).collect();
+    let po: Vec<usize> = lo.iter().map(position).collect();
+
+    // allocate the output
+    let mut out = match po.len() {
+        0 => EinsumOutput::Scalar(0.0),
+        1 => EinsumOutput::Vector(Vector::new(dims[po[0]])),
+        _ => EinsumOutput::Matrix(Matrix::new(dims[po[0]], dims[po[1]])),
+    };
+    if dims.contains(&0) {
+        return Ok(out);
+    }
+
+    // use BLAS for the recognized patterns (labels renamed in order of appearance)
+    let pattern = canonical_pattern(&pa, &pb, &po);
+    match (pattern.as_str(), &a, &b, &mut out) {
+        ("ab,bc->ac", EinsumOperand::Matrix(a), EinsumOperand::Matrix(b), EinsumOutput::Matrix(c)) => {
+            mat_mat_mul(c, 1.0, a, b)?;
+            return Ok(out);
+        }
+        ("ba,bc->ac", EinsumOperand::Matrix(a), EinsumOperand::Matrix(b), EinsumOutput::Matrix(c)) => {
+            mat_t_mat_mul(c, 1.0, a, b)?;
+            return Ok(out);
+        }
+        ("ab,b->a", EinsumOperand::Matrix(a), EinsumOperand::Vector(u), EinsumOutput::Vector(v)) => {
+            mat_vec_mul(v, 1.0, a, u)?;
+            return Ok(out);
+        }
+        ("a,ab->b", EinsumOperand::Vector(u), EinsumOperand::Matrix(a), EinsumOutput::Vector(v)) => {
+            vec_mat_mul(v, 1.0, u, a)?;
+            return Ok(out);
+        }
+        ("a,a->", EinsumOperand::Vector(u), EinsumOperand::Vector(v), EinsumOutput::Scalar(s)) => {
+            *s = vec_inner(u, v);
+            return Ok(out);
+        }
+        ("a,b->ab", EinsumOperand::Vector(u), EinsumOperand::Vector(v), EinsumOutput::Matrix(c)) => {
+            vec_outer(c, 1.0, u, v)?;
+            return Ok(out);
+        }
+        ("ab,ab->", EinsumOperand::Matrix(a), EinsumOperand::Matrix(b), EinsumOutput::Scalar(s)) => {
+            let n_i32 = to_i32(a.as_data().len());
+            *s = ddot(n_i32, a.as_data(), 1, b.as_data(), 1);
+            return Ok(out);
+        }
+        _ => (),
+    }
+
+    // loop over all combinations of the label values
+    let mut counter = vec![0; labels.len()];
+    let mut ia = vec![0; pa.len()];
+    let mut ib = vec![0; pb.len()];
+    loop {
+        for (k, p) in pa.iter().enumerate() {
+            ia[k] = counter[*p];
+        }
+        for (k, p) in pb.iter().enumerate() {
+            ib[k] = counter[*p];
+        }
+        let value = a.get(&ia) * b.get(&ib);
+        match &mut out {
+            EinsumOutput::Scalar(s) => *s += value,
+            EinsumOutput::Vector(v) => v[counter[po[0]]] += value,
+            EinsumOutput::Matrix(c) => c.add(counter[po[0]], counter[po[1]], value),
+        }
+        // next combination
+        let mut k = 0;
+        loop {
+            if k == counter.len() {
+                return Ok(out);
+            }
+            counter[k] += 1;
+            if counter[k] < dims[k] {
+                break;
+            }
+            counter[k] = 0;
+            k += 1;
+        }
+    }
+}
+
+/// Holds the labels of the two operands and the output
+type Labels = (Vec<u8>, Vec<u8>, Vec<u8>);
+
+/// Parses the specification string into the labels of the two operands and the output
+fn parse_spec(spec: &str) -> Result<Labels, StrError> {
+    let spec: Vec<u8> = spec.bytes().filter(|c| !c.is_ascii_whitespace()).collect();
+    let (inputs, output) = match spec.windows(2).position(|w| w == b"->") {
+        Some(p) => (&spec[..p], Some(&spec[p + 2..])),
+        None => (&spec[..], None),
+    };
+    let parts: Vec<&[u8]> = inputs.split(|c| *c == b',').collect();
+    if parts.len() != 2 {
+        return Err("einsum requires exactly two operands");
+    }
+    let all_labels = parts[0]
+        .iter()
+        .chain(parts[1].iter())
+        .chain(output.unwrap_or(&[]).iter());
+    for c in all_labels {
+        if !c.is_ascii_lowercase() {
+            return Err("einsum labels must be lowercase letters");
+        }
+    }
+    let (la, lb) = (parts[0].to_vec(), parts[1].to_vec());
+    let lo = match output {
+        Some(o) => o.to_vec(),
+        None => {
+            // implicit mode: labels appearing only once, in alphabetical order
+            let mut lo: Vec<u8> = Vec::new();
+            for c in la.iter().chain(lb.iter()) {
+                let count = la.iter().chain(lb.iter()).filter(|x| *x == c).count();
+                if count == 1 {
+                    lo.push(*c);
+                }
+            }
+            lo.sort();
+            lo
+        }
+    };
+    Ok((la, lb, lo))
+}
+
+/// Returns the pattern with the labels renamed in order of first appearance (e.g., "ik,kj->ij" becomes "ab,bc->ac")
+fn canonical_pattern(pa: &[usize], pb: &[usize], po: &[usize]) -> String {
+    let name = |p: &usize| (b'a' + *p as u8) as char;
+    let a: String = pa.iter().map(name).collect();
+    let b: String = pb.iter().map(name).collect();
+    let o: String = po.iter().map(name).collect();
+    format!("{},{}->{}", a, b, o)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{canonical_pattern, einsum, parse_spec, EinsumOutput};
+    use crate::{mat_approx_eq, Matrix, Vector};
+    use russell_chk::{approx_eq, vec_approx_eq};
+
+    #[test]
+    fn einsum_captures_errors() {
+        let a = Matrix::new(2, 3);
+        let u = Vector::new(3);
+        assert_eq!(einsum("ij", &a, &u).err(), Some("einsum requires exactly two operands"));
+        assert_eq!(
+            einsum("ij,j,k->", &a, &u).err(),
+            Some("einsum requires exactly two operands")
+        );
+        assert_eq!(
+            einsum("iJ,J->i", &a, &u).err(),
+            Some("einsum labels must be lowercase letters")
+        );
+        assert_eq!(
+            einsum("ijk,k->ij", &a, &u).err(),
+            Some("einsum labels do not match the ranks of the operands")
+        );
+        assert_eq!(
+            einsum("ij,k->ijk", &a, &u).err(),
+            Some("einsum output must have at most two labels")
+        );
+        assert_eq!(
+            einsum("ij,j->k", &a, &u).err(),
+            Some("einsum output labels are invalid")
+        );
+        assert_eq!(
+            einsum("ij,j->ii", &a, &u).err(),
+            Some("einsum output labels are invalid")
+        );
+        assert_eq!(
+            einsum("ij,i->j", &a, &u).err(),
+            Some("einsum dimensions are incompatible")
+        );
+        let out = einsum("ij,j->i", &a, &u).unwrap();
+        assert_eq!(out.scalar().err(), Some("the einsum output is not a scalar"));
+        assert_eq!(
+            out.clone().into_matrix().err(),
+            Some("the einsum output is not a matrix")
+        );
+        let out = einsum("ij,j->", &a, &u).unwrap();
+        assert_eq!(out.into_vector().err(), Some("the einsum output is not a vector"));
+    }
+
+    #[test]
+    fn parse_spec_and_canonical_pattern_work() {
+        let (la, lb, lo) = parse_spec(" ik , kj -> ij ").unwrap();
+        assert_eq!(
+            (la.as_slice(), lb.as_slice(), lo.as_slice()),
+            (&b"ik"[..], &b"kj"[..], &b"ij"[..])
+        );
+        let (_, _, lo) = parse_spec("kj,ik").unwrap();
+        assert_eq!(lo, b"ij");
+        let (_, _, lo) = parse_spec("i,i").unwrap();
+        assert_eq!(lo, b"");
+        assert_eq!(canonical_pattern(&[0, 1], &[1, 2], &[0, 2]), "ab,bc->ac");
+        assert_eq!(canonical_pattern(&[0], &[0], &[]), "a,a->");
+    }
+
+    #[test]
+    fn einsum_blas_patterns_work() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+        ]);
+        #[rustfmt::skip]
+        let b = Matrix::from(&[
+            [1.0, -1.0],
+            [0.0,  2.0],
+            [3.0,  1.0],
+        ]);
+        let u = Vector::from(&[1.0, 0.0, -1.0]);
+        let w = Vector::from(&[2.0, 3.0]);
+
+        let c = einsum("ik,kj->ij", &a, &b).unwrap().into_matrix().unwrap();
+        mat_approx_eq(&c, &[[10.0, 6.0], [22.0, 12.0]], 1e-15);
+
+        let c = einsum("ji,jk->ik", &a, &a).unwrap().into_matrix().unwrap();
+        #[rustfmt::skip]
+        mat_approx_eq(&c, &[
+            [17.0, 22.0, 27.0],
+            [22.0, 29.0, 36.0],
+            [27.0, 36.0, 45.0],
+        ], 1e-15);
+
+        let v = einsum("ij,j->i", &a, &u).unwrap().into_vector().unwrap();
+        vec_approx_eq(v.as_data(), &[-2.0, -2.0], 1e-15);
+
+        let v = einsum("i,ij->j", &w, &a).unwrap().into_vector().unwrap();
+        vec_approx_eq(v.as_data(), &[14.0, 19.0, 24.0], 1e-15);
+
+        let s = einsum("i,i->", &u, &u).unwrap().scalar().unwrap();
+        assert_eq!(s, 2.0);
+
+        let c = einsum("i,j->ij", &w, &u).unwrap().into_matrix().unwrap();
+        mat_approx_eq(&c, &[[2.0, 0.0, -2.0], [3.0, 0.0, -3.0]], 1e-15);
+
+        let s = einsum("ij,ij->", &a, &a).unwrap().scalar().unwrap();
+        assert_eq!(s, 91.0);
+    }
+
+    #[test]
+    fn einsum_general_patterns_work() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0, 2.0],
+            [3.0, 4.0],
+        ]);
+        #[rustfmt::skip]
+        let b = Matrix::from(&[
+            [0.0, 1.0],
+            [2.0, 3.0],
+        ]);
+        let u = Vector::from(&[1.0, -1.0]);
+
+        // Hadamard product
+        let c = einsum("ij,ij->ij", &a, &b).unwrap().into_matrix().unwrap();
+        mat_approx_eq(&c, &[[0.0, 2.0], [6.0, 12.0]], 1e-15);
+
+        // transposed output: (a⋅b)ᵀ
+        let c = einsum("ij,jk->ki", &a, &b).unwrap().into_matrix().unwrap();
+        mat_approx_eq(&c, &[[4.0, 8.0], [7.0, 15.0]], 1e-15);
+
+        // aᵀ⋅u
+        let v = einsum("ji,j->i", &a, &u).unwrap().into_vector().unwrap();
+        vec_approx_eq(v.as_data(), &[-2.0, -2.0], 1e-15);
+
+        // product of traces
+        let s = einsum("ii,jj->", &a, &b).unwrap().scalar().unwrap();
+        approx_eq(s, 15.0, 1e-15);
+
+        // diagonal scaled by u
+        let v = einsum("ii,i->i", &a, &u).unwrap().into_vector().unwrap();
+        vec_approx_eq(v.as_data(), &[1.0, -4.0], 1e-15);
+
+        // sum over the columns of a times the sum of u
+        let v = einsum("ij,k->i", &a, &u).unwrap().into_vector().unwrap();
+        vec_approx_eq(v.as_data(), &[0.0, 0.0], 1e-15);
+
+        // implicit output
+        let c = einsum("ij,jk", &a, &b).unwrap().into_matrix().unwrap();
+        mat_approx_eq(&c, &[[4.0, 7.0], [8.0, 15.0]], 1e-15);
+    }
+
+    #[test]
+    fn einsum_zero_sized_works() {
+        let a = Matrix::new(2, 0);
+        let u = Vector::new(0);
+        let v = einsum("ij,j->i", &a, &u).unwrap().into_vector().unwrap();
+        assert_eq!(v.as_data(), &[0.0, 0.0]);
+        match einsum("i,i->", &u, &u).unwrap() {
+            EinsumOutput::Scalar(s) => assert_eq!(s, 0.0),
+            _ => panic!("the output should be a scalar"),
+        }
+    }
+}
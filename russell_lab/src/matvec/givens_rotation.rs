@@ -0,0 +1,224 @@
+use crate::matrix::Matrix;
+use crate::StrError;
+
+/// Computes a Givens rotation that annihilates the second component of a 2-vector
+///
+/// Finds `c`, `s`, and `r` such that:
+///
+/// ```text
+/// ┌       ┐ ┌   ┐   ┌   ┐
+/// │  c  s │ │ f │   │ r │
+/// │ -s  c │ │ g │ = │ 0 │       with    c² + s² = 1
+/// └       ┘ └   ┘   └   ┘
+/// ```
+///
+/// The norm `r = sqrt(f² + g²)` is computed without unnecessary overflow or underflow;
+/// moreover, `r ≥ 0`. If `f = g = 0`, then `c = 1`, `s = 0`, and `r = 0`.
+///
+/// # Output
+///
+/// Returns `(c, s, r)`
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::approx_eq;
+/// use russell_lab::givens_rotation;
+///
+/// let (c, s, r) = givens_rotation(3.0, 4.0);
+/// approx_eq(c, 0.6, 1e-15);
+/// approx_eq(s, 0.8, 1e-15);
+/// approx_eq(r, 5.0, 1e-15);
+/// ```
+pub fn givens_rotation(f: f64, g: f64) -> (f64, f64, f64) {
+    if g == 0.0 && f == 0.0 {
+        return (1.0, 0.0, 0.0);
+    }
+    let r = f64::hypot(f, g);
+    (f / r, g / r, r)
+}
+
+/// Applies a Givens rotation to two rows of a matrix
+///
+/// For every column `j`:
+///
+/// ```text
+/// ┌      ┐    ┌       ┐ ┌      ┐
+/// │ a_ij │    │  c  s │ │ a_ij │
+/// │ a_kj │ := │ -s  c │ │ a_kj │
+/// └      ┘    └       ┘ └      ┘
+/// ```
+///
+/// # Input
+///
+/// * `a` -- the matrix
+/// * `i` -- the index of the first row
+/// * `k` -- the index of the second row (must be different from `i`)
+/// * `c`, `s` -- the rotation coefficients computed by [givens_rotation]
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::approx_eq;
+/// use russell_lab::{givens_apply_rows, givens_rotation, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut a = Matrix::from(&[
+///         [3.0, 1.0],
+///         [4.0, 2.0],
+///     ]);
+///     let (c, s, r) = givens_rotation(a.get(0, 0), a.get(1, 0));
+///     givens_apply_rows(&mut a, 0, 1, c, s)?;
+///     approx_eq(a.get(0, 0), r, 1e-15);
+///     approx_eq(a.get(1, 0), 0.0, 1e-15);
+///     Ok(())
+/// }
+/// ```
+pub fn givens_apply_rows(a: &mut Matrix, i: usize, k: usize, c: f64, s: f64) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if i >= m || k >= m || i == k {
+        return Err("the row indices are invalid");
+    }
+    for j in 0..n {
+        let (x, y) = (a.get(i, j), a.get(k, j));
+        a.set(i, j, c * x + s * y);
+        a.set(k, j, -s * x + c * y);
+    }
+    Ok(())
+}
+
+/// Applies a Givens rotation to two columns of a matrix
+///
+/// For every row `j`:
+///
+/// ```text
+/// ┌           ┐    ┌           ┐ ┌       ┐
+/// │ a_ji a_jk │ := │ a_ji a_jk │ │ c -s  │
+/// └           ┘    └           ┘ │ s  c  │
+///                                └       ┘
+/// ```
+///
+/// i.e., the columns are multiplied from the right by the transpose of the rotation matrix used
+/// in [givens_apply_rows]; thus, applying the same rotation to the rows and columns of a
+/// symmetric matrix yields a symmetric matrix.
+///
+/// # Input
+///
+/// * `a` -- the matrix
+/// * `i` -- the index of the first column
+/// * `k` -- the index of the second column (must be different from `i`)
+/// * `c`, `s` -- the rotation coefficients computed by [givens_rotation]
+pub fn givens_apply_cols(a: &mut Matrix, i: usize, k: usize, c: f64, s: f64) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if i >= n || k >= n || i == k {
+        return Err("the column indices are invalid");
+    }
+    for j in 0..m {
+        let (x, y) = (a.get(j, i), a.get(j, k));
+        a.set(j, i, c * x + s * y);
+        a.set(j, k, -s * x + c * y);
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{givens_apply_cols, givens_apply_rows, givens_rotation};
+    use crate::{mat_approx_eq, mat_mat_mul, mat_t_mat_mul, Matrix};
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn givens_rotation_works() {
+        for (f, g) in [
+            (3.0, 4.0),
+            (-3.0, 4.0),
+            (0.0, -2.0),
+            (5.0, 0.0),
+            (-5.0, 0.0),
+            (1e300, 1e300),
+            (1e-300, -1e-300),
+        ] {
+            let (c, s, r) = givens_rotation(f, g);
+            approx_eq(c * c + s * s, 1.0, 1e-15);
+            assert!(r >= 0.0);
+            approx_eq((c * f + s * g) / r, 1.0, 1e-15);
+            approx_eq((-s * f + c * g) / r, 0.0, 1e-15);
+        }
+        assert_eq!(givens_rotation(0.0, 0.0), (1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn givens_apply_functions_capture_errors() {
+        let mut a = Matrix::new(2, 3);
+        assert_eq!(
+            givens_apply_rows(&mut a, 0, 2, 1.0, 0.0).err(),
+            Some("the row indices are invalid")
+        );
+        assert_eq!(
+            givens_apply_rows(&mut a, 1, 1, 1.0, 0.0).err(),
+            Some("the row indices are invalid")
+        );
+        assert_eq!(
+            givens_apply_cols(&mut a, 3, 0, 1.0, 0.0).err(),
+            Some("the column indices are invalid")
+        );
+        assert_eq!(
+            givens_apply_cols(&mut a, 2, 2, 1.0, 0.0).err(),
+            Some("the column indices are invalid")
+        );
+    }
+
+    #[test]
+    fn givens_qr_works() {
+        // QR factorization of a (3,3) matrix with Givens rotations
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [6.0, 5.0, 0.0],
+            [5.0, 1.0, 4.0],
+            [0.0, 4.0, 3.0],
+        ]);
+        let mut r = a.clone();
+        let mut qt = Matrix::identity(3);
+        for j in 0..2 {
+            for i in ((j + 1)..3).rev() {
+                let (c, s, _) = givens_rotation(r.get(i - 1, j), r.get(i, j));
+                givens_apply_rows(&mut r, i - 1, i, c, s).unwrap();
+                givens_apply_rows(&mut qt, i - 1, i, c, s).unwrap();
+            }
+        }
+        for j in 0..3 {
+            for i in (j + 1)..3 {
+                approx_eq(r.get(i, j), 0.0, 1e-15);
+            }
+        }
+        // qᵀ ⋅ q = I and q ⋅ r = a
+        let mut q = Matrix::new(3, 3);
+        for i in 0..3 {
+            for j in 0..3 {
+                q.set(i, j, qt.get(j, i));
+            }
+        }
+        let mut qtq = Matrix::new(3, 3);
+        mat_t_mat_mul(&mut qtq, 1.0, &q, &q).unwrap();
+        mat_approx_eq(&qtq, &Matrix::identity(3), 1e-15);
+        let mut qr = Matrix::new(3, 3);
+        mat_mat_mul(&mut qr, 1.0, &q, &r).unwrap();
+        mat_approx_eq(&qr, &a, 1e-14);
+    }
+
+    #[test]
+    fn givens_apply_cols_works() {
+        // rotating the rows and columns of a symmetric matrix (similarity transformation)
+        #[rustfmt::skip]
+        let mut a = Matrix::from(&[
+            [2.0, 1.0],
+            [1.0, 2.0],
+        ]);
+        let s = f64::sqrt(0.5);
+        givens_apply_rows(&mut a, 0, 1, s, s).unwrap();
+        givens_apply_cols(&mut a, 0, 1, s, s).unwrap();
+        mat_approx_eq(&a, &[[3.0, 0.0], [0.0, 1.0]], 1e-15);
+    }
+}
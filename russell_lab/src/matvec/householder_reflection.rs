@@ -0,0 +1,271 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::StrError;
+use russell_openblas::{dnrm2, to_i32};
+
+/// Computes a Householder reflection that annihilates all but the first component of a vector
+///
+/// Finds `v`, `τ`, and `β` such that:
+///
+/// ```text
+/// H ⋅ x = β ⋅ e₀    with    H = I - τ ⋅ v ⋅ vᵀ
+/// ```
+///
+/// where `e₀ = [1, 0, …, 0]ᵀ`, `v₀ = 1`, and `|β| = ‖x‖₂`. The matrix `H` is symmetric and orthogonal.
+/// The sign of `β` is opposite to the sign of `x₀` to avoid cancellation. If `x₁ = … = xₙ₋₁ = 0`,
+/// then `τ = 0` and `H = I`. This is the same convention as LAPACK's dlarfg.
+///
+/// # Output
+///
+/// * `v` -- (n) the Householder vector (with `v₀ = 1`)
+/// * Returns `(τ, β)`
+///
+/// # Input
+///
+/// * `x` -- (n) the vector to be reflected (n ≥ 1)
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::{approx_eq, vec_approx_eq};
+/// use russell_lab::{householder_apply_left, householder_reflection, Matrix, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     let x = Vector::from(&[3.0, 4.0]);
+///     let mut v = Vector::new(2);
+///     let (tau, beta) = householder_reflection(&mut v, &x)?;
+///     approx_eq(beta, -5.0, 1e-15);
+///
+///     // apply H to a matrix whose first column is x
+///     let mut a = Matrix::from(&[
+///         [3.0, 1.0],
+///         [4.0, 2.0],
+///     ]);
+///     householder_apply_left(&mut a, &v, tau, 0)?;
+///     vec_approx_eq(&a.as_data()[0..2], &[-5.0, 0.0], 1e-15);
+///     Ok(())
+/// }
+/// ```
+pub fn householder_reflection(v: &mut Vector, x: &Vector) -> Result<(f64, f64), StrError> {
+    let n = x.dim();
+    if n == 0 {
+        return Err("the vector must have at least one component");
+    }
+    if v.dim() != n {
+        return Err("vectors are incompatible");
+    }
+    let alpha = x[0];
+    let xnorm = if n > 1 {
+        dnrm2(to_i32(n - 1), &x.as_data()[1..], 1)
+    } else {
+        0.0
+    };
+    v.fill(0.0);
+    v[0] = 1.0;
+    if xnorm == 0.0 {
+        return Ok((0.0, alpha));
+    }
+    let beta = -f64::copysign(f64::hypot(alpha, xnorm), alpha);
+    let tau = (beta - alpha) / beta;
+    let scale = 1.0 / (alpha - beta);
+    for i in 1..n {
+        v[i] = x[i] * scale;
+    }
+    Ok((tau, beta))
+}
+
+/// Applies a Householder reflection to a matrix from the left
+///
+/// ```text
+/// a := H ⋅ a    with    H = I - τ ⋅ v ⋅ vᵀ
+/// ```
+///
+/// The reflection acts on the rows `start..start+n` of `a`, where `n` is the dimension of `v`;
+/// thus, the trailing rows of a matrix can be updated, as needed by QR-like factorizations.
+///
+/// # Input
+///
+/// * `a` -- (m,k) the matrix
+/// * `v` -- (n) the Householder vector computed by [householder_reflection]
+/// * `tau` -- the τ coefficient computed by [householder_reflection]
+/// * `start` -- the index of the first row to be updated (start + n ≤ m)
+pub fn householder_apply_left(a: &mut Matrix, v: &Vector, tau: f64, start: usize) -> Result<(), StrError> {
+    let (m, k) = a.dims();
+    let n = v.dim();
+    if start + n > m {
+        return Err("the vector is incompatible with the matrix");
+    }
+    if tau == 0.0 {
+        return Ok(());
+    }
+    for j in 0..k {
+        let mut w = 0.0;
+        for i in 0..n {
+            w += v[i] * a.get(start + i, j);
+        }
+        for i in 0..n {
+            a.add(start + i, j, -tau * w * v[i]);
+        }
+    }
+    Ok(())
+}
+
+/// Applies a Householder reflection to a matrix from the right
+///
+/// ```text
+/// a := a ⋅ H    with    H = I - τ ⋅ v ⋅ vᵀ
+/// ```
+///
+/// The reflection acts on the columns `start..start+n` of `a`, where `n` is the dimension of `v`.
+///
+/// # Input
+///
+/// * `a` -- (k,m) the matrix
+/// * `v` -- (n) the Householder vector computed by [householder_reflection]
+/// * `tau` -- the τ coefficient computed by [householder_reflection]
+/// * `start` -- the index of the first column to be updated (start + n ≤ m)
+pub fn householder_apply_right(a: &mut Matrix, v: &Vector, tau: f64, start: usize) -> Result<(), StrError> {
+    let (k, m) = a.dims();
+    let n = v.dim();
+    if start + n > m {
+        return Err("the vector is incompatible with the matrix");
+    }
+    if tau == 0.0 {
+        return Ok(());
+    }
+    for i in 0..k {
+        let mut w = 0.0;
+        for j in 0..n {
+            w += a.get(i, start + j) * v[j];
+        }
+        for j in 0..n {
+            a.add(i, start + j, -tau * w * v[j]);
+        }
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{householder_apply_left, householder_apply_right, householder_reflection};
+    use crate::{check_orthogonal, mat_approx_eq, mat_mat_mul, mat_t_mat_mul, Matrix, Vector};
+    use russell_chk::{approx_eq, vec_approx_eq};
+
+    /// Returns H = I - τ ⋅ v ⋅ vᵀ
+    fn get_h(v: &Vector, tau: f64) -> Matrix {
+        let n = v.dim();
+        let mut h = Matrix::identity(n);
+        for i in 0..n {
+            for j in 0..n {
+                h.add(i, j, -tau * v[i] * v[j]);
+            }
+        }
+        h
+    }
+
+    #[test]
+    fn householder_functions_capture_errors() {
+        let mut v = Vector::new(0);
+        assert_eq!(
+            householder_reflection(&mut v, &Vector::new(0)).err(),
+            Some("the vector must have at least one component")
+        );
+        assert_eq!(
+            householder_reflection(&mut v, &Vector::new(1)).err(),
+            Some("vectors are incompatible")
+        );
+        let mut a = Matrix::new(3, 3);
+        let v = Vector::new(2);
+        assert_eq!(
+            householder_apply_left(&mut a, &v, 1.0, 2).err(),
+            Some("the vector is incompatible with the matrix")
+        );
+        assert_eq!(
+            householder_apply_right(&mut a, &v, 1.0, 2).err(),
+            Some("the vector is incompatible with the matrix")
+        );
+    }
+
+    #[test]
+    fn householder_reflection_works() {
+        for x_data in [
+            vec![2.0, 1.0, -2.0],
+            vec![-2.0, 1.0, -2.0],
+            vec![0.0, 3.0, 4.0],
+            vec![1e-200, 1e-200, 1e-200],
+            vec![1e200, -1e200],
+        ] {
+            let x = Vector::from(&x_data);
+            let n = x.dim();
+            let mut v = Vector::new(n);
+            let (tau, beta) = householder_reflection(&mut v, &x).unwrap();
+            assert_eq!(v[0], 1.0);
+            assert!((1.0..=2.0).contains(&tau));
+            let h = get_h(&v, tau);
+            check_orthogonal(&h, 1e-15);
+            let mut hx = Vector::new(n);
+            for i in 0..n {
+                for j in 0..n {
+                    hx[i] += h.get(i, j) * x[j];
+                }
+            }
+            let scale = f64::abs(beta);
+            let mut correct = vec![0.0; n];
+            correct[0] = beta / scale;
+            hx.as_mut_data().iter_mut().for_each(|y| *y /= scale);
+            vec_approx_eq(hx.as_data(), &correct, 1e-15);
+        }
+    }
+
+    #[test]
+    fn householder_reflection_handles_trivial_cases() {
+        let mut v = Vector::new(3);
+        let (tau, beta) = householder_reflection(&mut v, &Vector::from(&[-7.0, 0.0, 0.0])).unwrap();
+        assert_eq!((tau, beta), (0.0, -7.0));
+        assert_eq!(v.as_data(), &[1.0, 0.0, 0.0]);
+        let mut v = Vector::new(1);
+        let (tau, beta) = householder_reflection(&mut v, &Vector::from(&[3.0])).unwrap();
+        assert_eq!((tau, beta), (0.0, 3.0));
+    }
+
+    #[test]
+    fn householder_qr_works() {
+        // QR factorization of a (4,3) matrix with Householder reflections
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 10.0],
+            [1.0, 0.0, 1.0],
+        ]);
+        let (m, n) = a.dims();
+        let mut r = a.clone();
+        let mut q = Matrix::identity(m);
+        for k in 0..n {
+            let mut x = Vector::new(m - k);
+            for i in 0..(m - k) {
+                x[i] = r.get(k + i, k);
+            }
+            let mut v = Vector::new(m - k);
+            let (tau, beta) = householder_reflection(&mut v, &x).unwrap();
+            householder_apply_left(&mut r, &v, tau, k).unwrap();
+            householder_apply_right(&mut q, &v, tau, k).unwrap();
+            approx_eq(r.get(k, k), beta, 1e-14);
+        }
+        // r is upper triangular
+        for j in 0..n {
+            for i in (j + 1)..m {
+                approx_eq(r.get(i, j), 0.0, 1e-14);
+            }
+        }
+        // q is orthogonal and q ⋅ r = a
+        let mut qtq = Matrix::new(m, m);
+        mat_t_mat_mul(&mut qtq, 1.0, &q, &q).unwrap();
+        mat_approx_eq(&qtq, &Matrix::identity(m), 1e-14);
+        let mut qr = Matrix::new(m, n);
+        mat_mat_mul(&mut qr, 1.0, &q, &r).unwrap();
+        mat_approx_eq(&qr, &a, 1e-14);
+    }
+}
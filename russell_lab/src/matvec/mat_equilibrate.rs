@@ -0,0 +1,169 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::StrError;
+use russell_openblas::{dgeequ, dlaqge, to_i32};
+
+/// Equilibrates a matrix by scaling its rows and columns
+///
+/// Computes the row and column scale factors `r` and `c` (using LAPACK dgeequ) and
+/// applies them (using LAPACK dlaqge) such that
+///
+/// ```text
+/// a := diag(r) ⋅ a ⋅ diag(c)
+/// ```
+///
+/// has its largest element in each row and column with absolute value close to 1.
+/// The scaling is only applied if worthwhile (see dlaqge); the factors that are not
+/// applied are set to one. Thus, on exit, the above relation always holds.
+///
+/// To solve `a ⋅ x = b` with the equilibrated matrix, solve `(diag(r) ⋅ a ⋅ diag(c)) ⋅ y = diag(r) ⋅ b`
+/// and then unscale the solution using `x = diag(c) ⋅ y`.
+///
+/// # Output
+///
+/// * `r` -- vector with dim = m (the row scale factors)
+/// * `c` -- vector with dim = n (the column scale factors)
+/// * `a` -- (m, n) matrix; it will be overwritten by the equilibrated matrix
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::vec_approx_eq;
+/// use russell_lab::{mat_equilibrate, solve_lin_sys, Matrix, Vector, StrError};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     // badly scaled system with solution x = [1, 2]
+///     let mut a = Matrix::from(&[
+///         [1e6,  2e6],
+///         [3e-3, 4e-3],
+///     ]);
+///     let mut b = Vector::from(&[5e6, 11e-3]);
+///
+///     // equilibrate
+///     let mut r = Vector::new(2);
+///     let mut c = Vector::new(2);
+///     mat_equilibrate(&mut r, &mut c, &mut a)?;
+///     assert_eq!(format!("{}", a), "┌           ┐\n\
+///                                   │  0.5    1 │\n\
+///                                   │ 0.75    1 │\n\
+///                                   └           ┘");
+///
+///     // scale the right-hand side, solve, and unscale the solution
+///     for i in 0..2 {
+///         b[i] *= r[i];
+///     }
+///     solve_lin_sys(&mut b, &mut a)?;
+///     for j in 0..2 {
+///         b[j] *= c[j];
+///     }
+///     vec_approx_eq(b.as_data(), &[1.0, 2.0], 1e-12);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+pub fn mat_equilibrate(r: &mut Vector, c: &mut Vector, a: &mut Matrix) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if r.dim() != m || c.dim() != n {
+        return Err("vectors are incompatible");
+    }
+    if m == 0 || n == 0 {
+        r.fill(1.0);
+        c.fill(1.0);
+        return Ok(());
+    }
+    let m_i32 = to_i32(m);
+    let n_i32 = to_i32(n);
+    let (rowcnd, colcnd, amax) = dgeequ(m_i32, n_i32, a.as_data(), r.as_mut_data(), c.as_mut_data())?;
+    let equed = dlaqge(
+        m_i32,
+        n_i32,
+        a.as_mut_data(),
+        r.as_data(),
+        c.as_data(),
+        rowcnd,
+        colcnd,
+        amax,
+    )?;
+    if equed == b'N' || equed == b'C' {
+        r.fill(1.0);
+    }
+    if equed == b'N' || equed == b'R' {
+        c.fill(1.0);
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(unused_imports))]
+mod tests {
+    use super::{mat_equilibrate, Matrix, Vector};
+    use crate::mat_approx_eq;
+    use russell_chk::{approx_eq, vec_approx_eq};
+
+    #[test]
+    fn mat_equilibrate_captures_errors() {
+        let mut a = Matrix::new(2, 3);
+        let mut r = Vector::new(2);
+        let mut c = Vector::new(3);
+        let mut wrong = Vector::new(1);
+        assert_eq!(
+            mat_equilibrate(&mut wrong, &mut c, &mut a),
+            Err("vectors are incompatible")
+        );
+        assert_eq!(
+            mat_equilibrate(&mut r, &mut wrong, &mut a),
+            Err("vectors are incompatible")
+        );
+        #[cfg(not(feature = "pure_rust"))]
+        assert_eq!(mat_equilibrate(&mut r, &mut c, &mut a), Err("LAPACK dgeequ failed"));
+        #[cfg(feature = "pure_rust")]
+        assert_eq!(
+            mat_equilibrate(&mut r, &mut c, &mut a),
+            Err("LAPACK dgeequ is not available with the pure_rust backend")
+        );
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn mat_equilibrate_works() {
+        // well scaled: nothing happens
+        #[rustfmt::skip]
+        let mut a = Matrix::from(&[
+            [1.0, 0.5],
+            [0.5, 1.0],
+        ]);
+        let a_copy = a.clone();
+        let mut r = Vector::new(2);
+        let mut c = Vector::new(2);
+        mat_equilibrate(&mut r, &mut c, &mut a).unwrap();
+        assert_eq!(r.as_data(), &[1.0, 1.0]);
+        assert_eq!(c.as_data(), &[1.0, 1.0]);
+        mat_approx_eq(&a, &a_copy, 1e-15);
+
+        // badly scaled rows and columns
+        #[rustfmt::skip]
+        let mut a = Matrix::from(&[
+            [1.0, 1e-8],
+            [1e8, 2.0],
+        ]);
+        let a_copy = a.clone();
+        mat_equilibrate(&mut r, &mut c, &mut a).unwrap();
+        vec_approx_eq(r.as_data(), &[1.0, 1e-8], 1e-15);
+        vec_approx_eq(c.as_data(), &[1.0, 5e7], 1e-15);
+        mat_approx_eq(&a, &[[1.0, 0.5], [1.0, 1.0]], 1e-15);
+        for i in 0..2 {
+            for j in 0..2 {
+                approx_eq(a.get(i, j), r[i] * a_copy.get(i, j) * c[j], 1e-15);
+            }
+        }
+
+        // empty matrix
+        let mut r = Vector::new(0);
+        let mut c = Vector::from(&[5.0]);
+        mat_equilibrate(&mut r, &mut c, &mut Matrix::new(0, 1)).unwrap();
+        assert_eq!(c.as_data(), &[1.0]);
+    }
+}
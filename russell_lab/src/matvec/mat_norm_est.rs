@@ -0,0 +1,137 @@
+use super::{mat_vec_mul, vec_mat_mul};
+use crate::matrix::Matrix;
+use crate::vector::{vec_norm, vec_scale, Vector};
+use crate::{Norm, StrError};
+
+/// Estimates the spectral norm (2-norm) of a matrix using the power iteration on aᵀ⋅a
+///
+/// ```text
+/// ‖a‖₂ = σ_max(a) = sqrt(λ_max(aᵀ⋅a))
+/// ```
+///
+/// Each iteration computes `y = a⋅x` and `x = aᵀ⋅y / ‖aᵀ⋅y‖`; thus, only two matrix-vector
+/// products are needed per iteration and `aᵀ⋅a` is never formed. The estimate `‖a⋅x‖` (with `‖x‖ = 1`)
+/// is a lower bound of `‖a‖₂` which increases monotonically. The iterations stop when the relative
+/// change of the estimate is smaller than or equal to `tolerance`. This function is much cheaper
+/// than the SVD for large matrices; however, the convergence may be slow if the two largest
+/// singular values are close to each other.
+///
+/// # Input
+///
+/// * `a` -- (m,n) matrix
+/// * `tolerance` -- the tolerance for the relative change of the estimate (e.g., 1e-6)
+/// * `n_max_iterations` -- the maximum number of iterations
+///
+/// # Output
+///
+/// Returns `(estimate, n_iterations)`. If the maximum number of iterations is reached, the current
+/// (lower bound) estimate is returned anyway.
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::approx_eq;
+/// use russell_lab::{mat_norm_est_2, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // the singular values are 5 and 3
+///     let a = Matrix::from(&[
+///         [3.0, 2.0,  2.0],
+///         [2.0, 3.0, -2.0],
+///     ]);
+///     let (norm, _) = mat_norm_est_2(&a, 1e-10, 100)?;
+///     approx_eq(norm, 5.0, 1e-9);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_norm_est_2(a: &Matrix, tolerance: f64, n_max_iterations: usize) -> Result<(f64, usize), StrError> {
+    if tolerance < 0.0 {
+        return Err("the tolerance must be non-negative");
+    }
+    let (m, n) = a.dims();
+    if m == 0 || n == 0 {
+        return Ok((0.0, 0));
+    }
+
+    // starting vector (slightly non-uniform to avoid symmetric cancellations)
+    let mut x = Vector::new(n);
+    x.map_with_index(|j, _| 1.0 + (j as f64) / (n as f64));
+    let norm_x = vec_norm(&x, Norm::Euc);
+    vec_scale(&mut x, 1.0 / norm_x);
+
+    // power iterations
+    let mut y = Vector::new(m);
+    let mut estimate = 0.0;
+    for iteration in 0..n_max_iterations {
+        mat_vec_mul(&mut y, 1.0, a, &x)?;
+        let previous = estimate;
+        estimate = vec_norm(&y, Norm::Euc);
+        if estimate == 0.0 {
+            return Ok((0.0, iteration + 1));
+        }
+        if f64::abs(estimate - previous) <= tolerance * estimate {
+            return Ok((estimate, iteration + 1));
+        }
+        vec_mat_mul(&mut x, 1.0, &y, a)?;
+        let norm_x = vec_norm(&x, Norm::Euc);
+        if norm_x == 0.0 {
+            return Ok((estimate, iteration + 1));
+        }
+        vec_scale(&mut x, 1.0 / norm_x);
+    }
+    Ok((estimate, n_max_iterations))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(unused_imports))]
+mod tests {
+    use super::mat_norm_est_2;
+    use crate::{mat_svd, Matrix, SvdMode, Vector};
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn mat_norm_est_2_captures_errors() {
+        let a = Matrix::new(2, 2);
+        assert_eq!(
+            mat_norm_est_2(&a, -1.0, 10).err(),
+            Some("the tolerance must be non-negative")
+        );
+    }
+
+    #[test]
+    fn mat_norm_est_2_handles_zero_and_empty() {
+        assert_eq!(mat_norm_est_2(&Matrix::new(0, 3), 1e-8, 10), Ok((0.0, 0)));
+        assert_eq!(mat_norm_est_2(&Matrix::new(3, 2), 1e-8, 10), Ok((0.0, 1)));
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn mat_norm_est_2_works() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0, 2.0,  3.0],
+            [4.0, 5.0,  6.0],
+            [7.0, 8.0, 10.0],
+            [1.0, 0.0,  1.0],
+        ]);
+        let mut s = Vector::new(3);
+        let mut u = Matrix::new(4, 4);
+        let mut vt = Matrix::new(3, 3);
+        mat_svd(&mut s, &mut u, &mut vt, &mut a.clone(), SvdMode::ValuesOnly).unwrap();
+        let (norm, n_iterations) = mat_norm_est_2(&a, 1e-14, 100).unwrap();
+        approx_eq(norm, s[0], 1e-12);
+        assert!(n_iterations < 100);
+
+        // the estimate is a lower bound even with few iterations
+        let (norm, n_iterations) = mat_norm_est_2(&a, 0.0, 1).unwrap();
+        assert_eq!(n_iterations, 1);
+        assert!(norm <= s[0]);
+
+        // diagonal matrix
+        let a = Matrix::diagonal(&[1.0, -3.0, 2.0]);
+        let (norm, _) = mat_norm_est_2(&a, 1e-15, 500).unwrap();
+        approx_eq(norm, 3.0, 1e-12);
+    }
+}
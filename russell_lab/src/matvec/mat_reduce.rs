@@ -0,0 +1,279 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::StrError;
+
+/// Computes the mean of each column of a matrix (i.e., averages the rows)
+///
+/// ```text
+/// vⱼ = Σ_i aᵢⱼ / m
+/// ```
+///
+/// # Input
+///
+/// * `v` -- vector with dim = n
+/// * `a` -- (m, n) matrix with m ≥ 1
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_mean_rows, Matrix, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [1.0, 2.0, 3.0],
+///         [3.0, 4.0, 7.0],
+///     ]);
+///     let mut v = Vector::new(3);
+///     mat_mean_rows(&mut v, &a)?;
+///     assert_eq!(v.as_data(), &[2.0, 3.0, 5.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_mean_rows(v: &mut Vector, a: &Matrix) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if v.dim() != n {
+        return Err("vector is incompatible");
+    }
+    if m == 0 && n > 0 {
+        return Err("matrix must not be empty");
+    }
+    for j in 0..n {
+        v[j] = 0.0;
+        for i in 0..m {
+            v[j] += a.get(i, j);
+        }
+        v[j] /= m as f64;
+    }
+    Ok(())
+}
+
+/// Computes the mean of each row of a matrix (i.e., averages the columns)
+///
+/// ```text
+/// vᵢ = Σ_j aᵢⱼ / n
+/// ```
+///
+/// # Input
+///
+/// * `v` -- vector with dim = m
+/// * `a` -- (m, n) matrix with n ≥ 1
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_mean_cols, Matrix, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [1.0, 2.0, 3.0],
+///         [3.0, 4.0, 8.0],
+///     ]);
+///     let mut v = Vector::new(2);
+///     mat_mean_cols(&mut v, &a)?;
+///     assert_eq!(v.as_data(), &[2.0, 5.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_mean_cols(v: &mut Vector, a: &Matrix) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if v.dim() != m {
+        return Err("vector is incompatible");
+    }
+    if n == 0 && m > 0 {
+        return Err("matrix must not be empty");
+    }
+    for i in 0..m {
+        v[i] = 0.0;
+        for j in 0..n {
+            v[i] += a.get(i, j);
+        }
+        v[i] /= n as f64;
+    }
+    Ok(())
+}
+
+/// Finds the minimum of each column of a matrix (i.e., the minimum over the rows)
+///
+/// ```text
+/// vⱼ = min_i aᵢⱼ
+/// ```
+///
+/// # Input
+///
+/// * `v` -- vector with dim = n
+/// * `a` -- (m, n) matrix with m ≥ 1
+pub fn mat_min_rows(v: &mut Vector, a: &Matrix) -> Result<(), StrError> {
+    reduce_rows(v, a, f64::min)
+}
+
+/// Finds the maximum of each column of a matrix (i.e., the maximum over the rows)
+///
+/// ```text
+/// vⱼ = max_i aᵢⱼ
+/// ```
+///
+/// # Input
+///
+/// * `v` -- vector with dim = n
+/// * `a` -- (m, n) matrix with m ≥ 1
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_max_rows, mat_min_rows, Matrix, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [1.0, 5.0, 3.0],
+///         [3.0, 4.0, 8.0],
+///     ]);
+///     let mut v = Vector::new(3);
+///     mat_min_rows(&mut v, &a)?;
+///     assert_eq!(v.as_data(), &[1.0, 4.0, 3.0]);
+///     mat_max_rows(&mut v, &a)?;
+///     assert_eq!(v.as_data(), &[3.0, 5.0, 8.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_max_rows(v: &mut Vector, a: &Matrix) -> Result<(), StrError> {
+    reduce_rows(v, a, f64::max)
+}
+
+/// Finds the minimum of each row of a matrix (i.e., the minimum over the columns)
+///
+/// ```text
+/// vᵢ = min_j aᵢⱼ
+/// ```
+///
+/// # Input
+///
+/// * `v` -- vector with dim = m
+/// * `a` -- (m, n) matrix with n ≥ 1
+pub fn mat_min_cols(v: &mut Vector, a: &Matrix) -> Result<(), StrError> {
+    reduce_cols(v, a, f64::min)
+}
+
+/// Finds the maximum of each row of a matrix (i.e., the maximum over the columns)
+///
+/// ```text
+/// vᵢ = max_j aᵢⱼ
+/// ```
+///
+/// # Input
+///
+/// * `v` -- vector with dim = m
+/// * `a` -- (m, n) matrix with n ≥ 1
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_max_cols, mat_min_cols, Matrix, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [1.0, 5.0, 3.0],
+///         [3.0, 4.0, 8.0],
+///     ]);
+///     let mut v = Vector::new(2);
+///     mat_min_cols(&mut v, &a)?;
+///     assert_eq!(v.as_data(), &[1.0, 3.0]);
+///     mat_max_cols(&mut v, &a)?;
+///     assert_eq!(v.as_data(), &[5.0, 8.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_max_cols(v: &mut Vector, a: &Matrix) -> Result<(), StrError> {
+    reduce_cols(v, a, f64::max)
+}
+
+/// Reduces each column of a matrix (over the rows) with a binary operation
+fn reduce_rows(v: &mut Vector, a: &Matrix, op: fn(f64, f64) -> f64) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if v.dim() != n {
+        return Err("vector is incompatible");
+    }
+    if m == 0 && n > 0 {
+        return Err("matrix must not be empty");
+    }
+    for j in 0..n {
+        v[j] = a.get(0, j);
+        for i in 1..m {
+            v[j] = op(v[j], a.get(i, j));
+        }
+    }
+    Ok(())
+}
+
+/// Reduces each row of a matrix (over the columns) with a binary operation
+fn reduce_cols(v: &mut Vector, a: &Matrix, op: fn(f64, f64) -> f64) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if v.dim() != m {
+        return Err("vector is incompatible");
+    }
+    if n == 0 && m > 0 {
+        return Err("matrix must not be empty");
+    }
+    for i in 0..m {
+        v[i] = a.get(i, 0);
+        for j in 1..n {
+            v[i] = op(v[i], a.get(i, j));
+        }
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_max_cols, mat_max_rows, mat_mean_cols, mat_mean_rows, mat_min_cols, mat_min_rows};
+    use crate::{Matrix, Vector};
+
+    #[test]
+    fn mat_reduce_capture_errors() {
+        let a = Matrix::new(2, 3);
+        let mut v2 = Vector::new(2);
+        let mut v3 = Vector::new(3);
+        assert_eq!(mat_mean_rows(&mut v2, &a), Err("vector is incompatible"));
+        assert_eq!(mat_min_rows(&mut v2, &a), Err("vector is incompatible"));
+        assert_eq!(mat_max_rows(&mut v2, &a), Err("vector is incompatible"));
+        assert_eq!(mat_mean_cols(&mut v3, &a), Err("vector is incompatible"));
+        assert_eq!(mat_min_cols(&mut v3, &a), Err("vector is incompatible"));
+        assert_eq!(mat_max_cols(&mut v3, &a), Err("vector is incompatible"));
+        let a_0x3 = Matrix::new(0, 3);
+        let a_2x0 = Matrix::new(2, 0);
+        assert_eq!(mat_mean_rows(&mut v3, &a_0x3), Err("matrix must not be empty"));
+        assert_eq!(mat_min_rows(&mut v3, &a_0x3), Err("matrix must not be empty"));
+        assert_eq!(mat_mean_cols(&mut v2, &a_2x0), Err("matrix must not be empty"));
+        assert_eq!(mat_max_cols(&mut v2, &a_2x0), Err("matrix must not be empty"));
+    }
+
+    #[test]
+    fn mat_reduce_works() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [ 1.0, -2.0,  9.0, 4.0],
+            [ 6.0,  0.0, -3.0, 4.0],
+            [-1.0,  5.0,  3.0, 4.0],
+        ]);
+        let mut v = Vector::new(4);
+        mat_mean_rows(&mut v, &a).unwrap();
+        assert_eq!(v.as_data(), &[2.0, 1.0, 3.0, 4.0]);
+        mat_min_rows(&mut v, &a).unwrap();
+        assert_eq!(v.as_data(), &[-1.0, -2.0, -3.0, 4.0]);
+        mat_max_rows(&mut v, &a).unwrap();
+        assert_eq!(v.as_data(), &[6.0, 5.0, 9.0, 4.0]);
+        let mut v = Vector::new(3);
+        mat_mean_cols(&mut v, &a).unwrap();
+        assert_eq!(v.as_data(), &[3.0, 1.75, 2.75]);
+        mat_min_cols(&mut v, &a).unwrap();
+        assert_eq!(v.as_data(), &[-2.0, -3.0, -1.0]);
+        mat_max_cols(&mut v, &a).unwrap();
+        assert_eq!(v.as_data(), &[9.0, 6.0, 5.0]);
+
+        // empty
+        let mut v = Vector::new(0);
+        mat_mean_rows(&mut v, &Matrix::new(0, 0)).unwrap();
+        mat_max_cols(&mut v, &Matrix::new(0, 0)).unwrap();
+    }
+}
@@ -0,0 +1,85 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::StrError;
+
+/// Scales the columns of a matrix (post-multiplication by a diagonal matrix)
+///
+/// ```text
+/// a := a ⋅ diag(d)
+///
+/// aᵢⱼ := aᵢⱼ ⋅ dⱼ
+/// ```
+///
+/// The diagonal matrix is never formed.
+///
+/// # Input
+///
+/// * `a` -- (m, n) matrix
+/// * `d` -- vector with dim = n
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_scale_cols, Matrix, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut a = Matrix::from(&[
+///         [1.0, 2.0, 3.0],
+///         [4.0, 5.0, 6.0],
+///     ]);
+///     let d = Vector::from(&[2.0, 0.5, 1.0]);
+///     mat_scale_cols(&mut a, &d)?;
+///     let correct = "┌             ┐\n\
+///                    │   2   1   3 │\n\
+///                    │   8 2.5   6 │\n\
+///                    └             ┘";
+///     assert_eq!(format!("{}", a), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_scale_cols(a: &mut Matrix, d: &Vector) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if d.dim() != n {
+        return Err("vector is incompatible");
+    }
+    for j in 0..n {
+        for i in 0..m {
+            a.mul(i, j, d[j]);
+        }
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_scale_cols, Matrix, Vector};
+    use crate::mat_approx_eq;
+
+    #[test]
+    fn mat_scale_cols_fails_on_wrong_dims() {
+        let mut a_2x3 = Matrix::new(2, 3);
+        let d = Vector::new(2);
+        assert_eq!(mat_scale_cols(&mut a_2x3, &d), Err("vector is incompatible"));
+    }
+
+    #[test]
+    fn mat_scale_cols_works() {
+        #[rustfmt::skip]
+        let mut a = Matrix::from(&[
+            [ 5.0, -2.0, 0.0, 1.0],
+            [10.0, -4.0, 0.0, 2.0],
+            [15.0, -6.0, 0.0, 3.0],
+        ]);
+        let d = Vector::from(&[0.2, -0.5, 7.0, 3.0]);
+        mat_scale_cols(&mut a, &d).unwrap();
+        #[rustfmt::skip]
+        let correct = &[
+            [1.0, 1.0, 0.0, 3.0],
+            [2.0, 2.0, 0.0, 6.0],
+            [3.0, 3.0, 0.0, 9.0],
+        ];
+        mat_approx_eq(&a, correct, 1e-15);
+    }
+}
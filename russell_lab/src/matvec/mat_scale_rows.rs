@@ -0,0 +1,85 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::StrError;
+
+/// Scales the rows of a matrix (pre-multiplication by a diagonal matrix)
+///
+/// ```text
+/// a := diag(d) ⋅ a
+///
+/// aᵢⱼ := dᵢ ⋅ aᵢⱼ
+/// ```
+///
+/// The diagonal matrix is never formed.
+///
+/// # Input
+///
+/// * `a` -- (m, n) matrix
+/// * `d` -- vector with dim = m
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_scale_rows, Matrix, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut a = Matrix::from(&[
+///         [1.0, 2.0, 3.0],
+///         [4.0, 5.0, 6.0],
+///     ]);
+///     let d = Vector::from(&[2.0, 0.5]);
+///     mat_scale_rows(&mut a, &d)?;
+///     let correct = "┌             ┐\n\
+///                    │   2   4   6 │\n\
+///                    │   2 2.5   3 │\n\
+///                    └             ┘";
+///     assert_eq!(format!("{}", a), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_scale_rows(a: &mut Matrix, d: &Vector) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if d.dim() != m {
+        return Err("vector is incompatible");
+    }
+    for j in 0..n {
+        for i in 0..m {
+            a.mul(i, j, d[i]);
+        }
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_scale_rows, Matrix, Vector};
+    use crate::mat_approx_eq;
+
+    #[test]
+    fn mat_scale_rows_fails_on_wrong_dims() {
+        let mut a_2x3 = Matrix::new(2, 3);
+        let d = Vector::new(3);
+        assert_eq!(mat_scale_rows(&mut a_2x3, &d), Err("vector is incompatible"));
+    }
+
+    #[test]
+    fn mat_scale_rows_works() {
+        #[rustfmt::skip]
+        let mut a = Matrix::from(&[
+            [ 5.0, -2.0, 0.0, 1.0],
+            [10.0, -4.0, 0.0, 2.0],
+            [15.0, -6.0, 0.0, 3.0],
+        ]);
+        let d = Vector::from(&[2.0, 0.5, -1.0]);
+        mat_scale_rows(&mut a, &d).unwrap();
+        #[rustfmt::skip]
+        let correct = &[
+            [ 10.0, -4.0, 0.0,  2.0],
+            [  5.0, -2.0, 0.0,  1.0],
+            [-15.0,  6.0, 0.0, -3.0],
+        ];
+        mat_approx_eq(&a, correct, 1e-15);
+    }
+}
@@ -1,7 +1,7 @@
-use crate::matrix::Matrix;
-use crate::vector::Vector;
+use crate::matrix::{Matrix, MatrixF32};
+use crate::vector::{Vector, VectorF32};
 use crate::StrError;
-use russell_openblas::{dgemv, to_i32};
+use russell_openblas::{dgemv, sgemv, to_i32};
 
 /// Performs the matrix-vector multiplication resulting in a vector
 ///
@@ -49,8 +49,8 @@ pub fn mat_vec_mul(v: &mut Vector, alpha: f64, a: &Matrix, u: &Vector) -> Result
     if m == 0 || n == 0 {
         return Ok(());
     }
-    let m_i32: i32 = to_i32(m);
-    let n_i32: i32 = to_i32(n);
+    let m_i32 = to_i32(m);
+    let n_i32 = to_i32(n);
     dgemv(
         false,
         m_i32,
@@ -66,11 +66,58 @@ pub fn mat_vec_mul(v: &mut Vector, alpha: f64, a: &Matrix, u: &Vector) -> Result
     Ok(())
 }
 
+/// Performs the matrix-vector multiplication resulting in a vector (single precision version)
+///
+/// ```text
+///  v  :=  α ⋅  a   ⋅  u
+/// (m)        (m,n)   (n)
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_vec_mul_f32, MatrixF32, VectorF32, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = MatrixF32::from(&[[1.0, 2.0], [3.0, 4.0]]);
+///     let u = VectorF32::from(&[1.0, -1.0]);
+///     let mut v = VectorF32::new(2);
+///     mat_vec_mul_f32(&mut v, 2.0, &a, &u)?;
+///     assert_eq!(v.as_data(), &[-2.0, -2.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_vec_mul_f32(v: &mut VectorF32, alpha: f32, a: &MatrixF32, u: &VectorF32) -> Result<(), StrError> {
+    let m = v.dim();
+    let n = u.dim();
+    if m != a.nrow() || n != a.ncol() {
+        return Err("matrix and vectors are incompatible");
+    }
+    if m == 0 || n == 0 {
+        return Ok(());
+    }
+    let m_i32 = to_i32(m);
+    let n_i32 = to_i32(n);
+    sgemv(
+        false,
+        m_i32,
+        n_i32,
+        alpha,
+        a.as_data(),
+        u.as_data(),
+        1,
+        0.0,
+        v.as_mut_data(),
+        1,
+    );
+    Ok(())
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::{mat_vec_mul, Matrix, Vector};
+    use super::{mat_vec_mul, mat_vec_mul_f32, Matrix, MatrixF32, Vector, VectorF32};
     use russell_chk::vec_approx_eq;
 
     #[test]
@@ -120,4 +167,23 @@ mod tests {
         mat_vec_mul(&mut v1, 1.0, &a_1x0, &u0).unwrap();
         assert_eq!(v1.as_data(), &[0.0]);
     }
+
+    #[test]
+    fn mat_vec_mul_f32_works() {
+        #[rustfmt::skip]
+        let a = MatrixF32::from(&[
+            [ 5.0, -2.0, 0.0, 1.0],
+            [10.0, -4.0, 0.0, 2.0],
+            [15.0, -6.0, 0.0, 3.0],
+        ]);
+        let u = VectorF32::from(&[1.0, 3.0, 8.0, 5.0]);
+        let mut v = VectorF32::new(3);
+        let mut v_wrong = VectorF32::new(2);
+        assert_eq!(
+            mat_vec_mul_f32(&mut v_wrong, 1.0, &a, &u),
+            Err("matrix and vectors are incompatible")
+        );
+        mat_vec_mul_f32(&mut v, 1.0, &a, &u).unwrap();
+        assert_eq!(v.as_data(), &[4.0, 8.0, 12.0]);
+    }
 }
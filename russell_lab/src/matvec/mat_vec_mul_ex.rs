@@ -0,0 +1,144 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::StrError;
+use russell_openblas::{dgemv, to_i32};
+
+/// Performs the general matrix-vector multiplication with optional transposition and accumulation
+///
+/// ```text
+///  v  :=  α ⋅ op(a) ⋅ u  +  β ⋅ v
+/// (m)         (m,n)  (n)        (m)
+/// ```
+///
+/// where `op(a) = aᵀ` if `trans` is true and `op(a) = a` otherwise.
+///
+/// # Input
+///
+/// * `a` -- (m,n) matrix or (n,m) matrix if `trans`
+/// * `beta` -- coefficient of the previous `v`; if zero, the previous contents of `v` are ignored
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_vec_mul_ex, Matrix, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [1.0, 2.0, 3.0],
+///         [4.0, 5.0, 6.0],
+///     ]);
+///     let u = Vector::from(&[1.0, -1.0]);
+///     let mut v = Vector::from(&[10.0, 20.0, 30.0]);
+///     // v := aᵀ⋅u + v
+///     mat_vec_mul_ex(&mut v, 1.0, &a, true, &u, 1.0)?;
+///     assert_eq!(v.as_data(), &[7.0, 17.0, 27.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_vec_mul_ex(
+    v: &mut Vector,
+    alpha: f64,
+    a: &Matrix,
+    trans: bool,
+    u: &Vector,
+    beta: f64,
+) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    let (dim_v, dim_u) = if trans { (n, m) } else { (m, n) };
+    if v.dim() != dim_v || u.dim() != dim_u {
+        return Err("matrix and vectors are incompatible");
+    }
+    if m == 0 || n == 0 {
+        // nothing to multiply; only scale v
+        for x in v.as_mut_data().iter_mut() {
+            *x = if beta == 0.0 { 0.0 } else { beta * *x };
+        }
+        return Ok(());
+    }
+    let m_i32 = to_i32(m);
+    let n_i32 = to_i32(n);
+    dgemv(
+        trans,
+        m_i32,
+        n_i32,
+        alpha,
+        a.as_data(),
+        u.as_data(),
+        1,
+        beta,
+        v.as_mut_data(),
+        1,
+    );
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_vec_mul_ex, Matrix, Vector};
+    use russell_chk::vec_approx_eq;
+
+    #[test]
+    fn mat_vec_mul_ex_fails_on_wrong_dims() {
+        let a_2x3 = Matrix::new(2, 3);
+        let u2 = Vector::new(2);
+        let u3 = Vector::new(3);
+        let mut v2 = Vector::new(2);
+        let mut v3 = Vector::new(3);
+        assert_eq!(
+            mat_vec_mul_ex(&mut v2, 1.0, &a_2x3, true, &u3, 0.0),
+            Err("matrix and vectors are incompatible")
+        );
+        assert_eq!(
+            mat_vec_mul_ex(&mut v3, 1.0, &a_2x3, false, &u3, 0.0),
+            Err("matrix and vectors are incompatible")
+        );
+        assert_eq!(
+            mat_vec_mul_ex(&mut v2, 1.0, &a_2x3, false, &u2, 0.0),
+            Err("matrix and vectors are incompatible")
+        );
+        mat_vec_mul_ex(&mut v2, 1.0, &a_2x3, false, &u3, 0.0).unwrap();
+        mat_vec_mul_ex(&mut v3, 1.0, &a_2x3, true, &u2, 0.0).unwrap();
+    }
+
+    #[test]
+    fn mat_vec_mul_ex_works() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [ 5.0, -2.0, 0.0, 1.0],
+            [10.0, -4.0, 0.0, 2.0],
+            [15.0, -6.0, 0.0, 3.0],
+        ]);
+
+        // v := a⋅u (previous v is ignored)
+        let u = Vector::from(&[1.0, 3.0, 8.0, 5.0]);
+        let mut v = Vector::filled(3, 123.0);
+        mat_vec_mul_ex(&mut v, 1.0, &a, false, &u, 0.0).unwrap();
+        vec_approx_eq(v.as_data(), &[4.0, 8.0, 12.0], 1e-15);
+
+        // v := 2⋅a⋅u - v
+        mat_vec_mul_ex(&mut v, 2.0, &a, false, &u, -1.0).unwrap();
+        vec_approx_eq(v.as_data(), &[4.0, 8.0, 12.0], 1e-15);
+
+        // w := aᵀ⋅z + w
+        let z = Vector::from(&[1.0, 0.0, -1.0]);
+        let mut w = Vector::from(&[1.0, 1.0, 1.0, 1.0]);
+        mat_vec_mul_ex(&mut w, 1.0, &a, true, &z, 1.0).unwrap();
+        vec_approx_eq(w.as_data(), &[-9.0, 5.0, 1.0, -1.0], 1e-15);
+    }
+
+    #[test]
+    fn mat_vec_mul_ex_zero_works() {
+        let a_1x0 = Matrix::new(1, 0);
+        let u0 = Vector::new(0);
+        let mut v1 = Vector::from(&[3.0]);
+        mat_vec_mul_ex(&mut v1, 1.0, &a_1x0, false, &u0, 2.0).unwrap();
+        assert_eq!(v1.as_data(), &[6.0]);
+        mat_vec_mul_ex(&mut v1, 1.0, &a_1x0, false, &u0, 0.0).unwrap();
+        assert_eq!(v1.as_data(), &[0.0]);
+        let mut v0 = Vector::new(0);
+        mat_vec_mul_ex(&mut v0, 1.0, &a_1x0, true, &Vector::new(1), 1.0).unwrap();
+        assert_eq!(v0.as_data(), &[] as &[f64]);
+    }
+}
@@ -1,14 +1,46 @@
 //! This module contains functions for calculations with matrices and vectors
 
+mod complex_mat_vec_mul;
+mod complex_solve_lin_sys_posdef;
+mod dense_solver;
+mod einsum;
+mod givens_rotation;
+mod householder_reflection;
+mod mat_equilibrate;
+mod mat_norm_est;
+mod mat_reduce;
+mod mat_scale_cols;
+mod mat_scale_rows;
 mod mat_sum_cols;
 mod mat_sum_rows;
 mod mat_vec_mul;
+mod mat_vec_mul_ex;
 mod solve_lin_sys;
+mod solve_lin_sys_multi;
+mod solve_lin_sys_posdef;
+mod solve_lin_sys_refined;
+mod solve_lin_sys_sym;
 mod vec_mat_mul;
 mod vec_outer;
+pub use crate::matvec::complex_mat_vec_mul::*;
+pub use crate::matvec::complex_solve_lin_sys_posdef::*;
+pub use crate::matvec::dense_solver::*;
+pub use crate::matvec::einsum::*;
+pub use crate::matvec::givens_rotation::*;
+pub use crate::matvec::householder_reflection::*;
+pub use crate::matvec::mat_equilibrate::*;
+pub use crate::matvec::mat_norm_est::*;
+pub use crate::matvec::mat_reduce::*;
+pub use crate::matvec::mat_scale_cols::*;
+pub use crate::matvec::mat_scale_rows::*;
 pub use crate::matvec::mat_sum_cols::*;
 pub use crate::matvec::mat_sum_rows::*;
 pub use crate::matvec::mat_vec_mul::*;
+pub use crate::matvec::mat_vec_mul_ex::*;
 pub use crate::matvec::solve_lin_sys::*;
+pub use crate::matvec::solve_lin_sys_multi::*;
+pub use crate::matvec::solve_lin_sys_posdef::*;
+pub use crate::matvec::solve_lin_sys_refined::*;
+pub use crate::matvec::solve_lin_sys_sym::*;
 pub use crate::matvec::vec_mat_mul::*;
 pub use crate::matvec::vec_outer::*;
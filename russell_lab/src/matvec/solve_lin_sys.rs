@@ -1,6 +1,6 @@
 use crate::matrix::Matrix;
 use crate::vector::Vector;
-use crate::StrError;
+use crate::{Error, Workspace};
 use russell_openblas::{dgesv, to_i32};
 
 /// Solves a general linear system (real numbers)
@@ -29,6 +29,7 @@ use russell_openblas::{dgesv, to_i32};
 /// ```
 /// use russell_lab::{solve_lin_sys, Matrix, Vector, StrError};
 ///
+/// # #[cfg(not(feature = "pure_rust"))]
 /// fn main() -> Result<(), StrError> {
 ///     // set matrix and right-hand side
 ///     let mut a = Matrix::from(&[
@@ -50,43 +51,60 @@ use russell_openblas::{dgesv, to_i32};
 ///     assert_eq!(format!("{:.3}", b), x_correct);
 ///     Ok(())
 /// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
 /// ```
-pub fn solve_lin_sys(b: &mut Vector, a: &mut Matrix) -> Result<(), StrError> {
+pub fn solve_lin_sys(b: &mut Vector, a: &mut Matrix) -> Result<(), Error> {
+    solve_lin_sys_with_workspace(b, a, &mut Workspace::new())
+}
+
+/// Solves a general linear system (real numbers) reusing a workspace
+///
+/// This function is the same as [solve_lin_sys]; however, the pivot indices are stored in
+/// the given [Workspace]. Thus, no allocation happens if the workspace is large enough.
+pub fn solve_lin_sys_with_workspace(b: &mut Vector, a: &mut Matrix, workspace: &mut Workspace) -> Result<(), Error> {
     let (m, n) = a.dims();
     if m != n {
-        return Err("matrix must be square");
+        return Err(Error::BadDimensions("matrix must be square"));
     }
     if b.dim() != m {
-        return Err("vector has wrong dimension");
+        return Err(Error::BadDimensions("vector has wrong dimension"));
     }
     if m == 0 {
         return Ok(());
     }
-    let mut ipiv = vec![0; m];
+    let ipiv = workspace.int(m);
     let m_i32 = to_i32(m);
-    dgesv(m_i32, 1, a.as_mut_data(), &mut ipiv, b.as_mut_data())?;
+    dgesv(m_i32, 1, a.as_mut_data(), ipiv, b.as_mut_data())?;
     Ok(())
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(unused_imports))]
 mod tests {
-    use super::{solve_lin_sys, Matrix, Vector};
+    use super::{solve_lin_sys, solve_lin_sys_with_workspace, Matrix, Vector};
+    use crate::{Error, Workspace};
     use russell_chk::vec_approx_eq;
 
     #[test]
     fn solve_lin_sys_fails_on_non_square() {
         let mut a = Matrix::new(2, 3);
         let mut b = Vector::new(3);
-        assert_eq!(solve_lin_sys(&mut b, &mut a), Err("matrix must be square"));
+        assert_eq!(
+            solve_lin_sys(&mut b, &mut a),
+            Err(Error::BadDimensions("matrix must be square"))
+        );
     }
 
     #[test]
     fn solve_lin_sys_fails_on_wrong_dims() {
         let mut a = Matrix::new(2, 2);
         let mut b = Vector::new(3);
-        assert_eq!(solve_lin_sys(&mut b, &mut a), Err("vector has wrong dimension"));
+        assert_eq!(
+            solve_lin_sys(&mut b, &mut a),
+            Err(Error::BadDimensions("vector has wrong dimension"))
+        );
     }
 
     #[test]
@@ -97,6 +115,7 @@ mod tests {
         assert_eq!(b.dim(), 0);
     }
 
+    #[cfg(not(feature = "pure_rust"))]
     #[test]
     fn solve_lin_sys_works() {
         #[rustfmt::skip]
@@ -127,6 +146,7 @@ mod tests {
         vec_approx_eq(b.as_data(), x_correct, 1e-13);
     }
 
+    #[cfg(not(feature = "pure_rust"))]
     #[test]
     fn solve_lin_sys_1_works() {
         // example from https://numericalalgorithmsgroup.github.io/LAPACK_Examples/examples/doc/dgesv_example.html
@@ -154,4 +174,32 @@ mod tests {
         ];
         vec_approx_eq(b.as_data(), x_correct, 1e-14);
     }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn solve_lin_sys_with_workspace_works() {
+        let mut workspace = Workspace::new();
+        for m in [3, 2] {
+            let mut a = Matrix::identity(m);
+            let mut b = Vector::filled(m, 2.0);
+            a.set(0, 1, 1.0);
+            solve_lin_sys_with_workspace(&mut b, &mut a, &mut workspace).unwrap();
+            let mut correct = vec![2.0; m];
+            correct[0] = 0.0;
+            vec_approx_eq(b.as_data(), &correct, 1e-15);
+        }
+    }
+
+    #[cfg(feature = "pure_rust")]
+    #[test]
+    fn solve_lin_sys_returns_backend_error() {
+        let mut a = Matrix::from(&[[2.0, 0.0], [0.0, 2.0]]);
+        let mut b = Vector::from(&[1.0, 1.0]);
+        assert_eq!(
+            solve_lin_sys(&mut b, &mut a),
+            Err(Error::Backend(
+                "LAPACK dgesv is not available with the pure_rust backend"
+            ))
+        );
+    }
 }
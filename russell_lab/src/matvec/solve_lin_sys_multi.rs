@@ -0,0 +1,144 @@
+use crate::matrix::Matrix;
+use crate::Error;
+use russell_openblas::{dgesv, to_i32};
+
+/// Solves a general linear system with multiple right-hand sides (real numbers)
+///
+/// For a general matrix `a` (square, symmetric, non-symmetric, dense,
+/// sparse), find `x` such that:
+///
+/// ```text
+///   a   ⋅   x   =   b
+/// (m,m)   (m,k)   (m,k)
+/// ```
+///
+/// However, the right-hand-side will hold the solution:
+///
+/// ```text
+/// b := a⁻¹⋅b == x
+/// ```
+///
+/// The solution is obtained via LU decomposition using Lapack dgesv routine.
+/// All `k` columns of `b` are solved by a single call; thus, the matrix is factorized only once.
+///
+/// # Note
+///
+/// 1. The matrix `a` will be modified
+/// 2. The right-hand-side `b` will contain the solution `x`
+///
+/// ```
+/// use russell_lab::{solve_lin_sys_multi, Matrix, StrError};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     // set matrix and right-hand sides (as columns)
+///     let mut a = Matrix::from(&[
+///         [1.0,  3.0, -2.0],
+///         [3.0,  5.0,  6.0],
+///         [2.0,  4.0,  3.0],
+///     ]);
+///     let mut b = Matrix::from(&[
+///         [5.0, 1.0],
+///         [7.0, 3.0],
+///         [8.0, 2.0],
+///     ]);
+///
+///     // solve linear systems b := a⁻¹⋅b
+///     solve_lin_sys_multi(&mut b, &mut a)?;
+///
+///     // check
+///     let x_correct = "┌                 ┐\n\
+///                      │ -15.000   1.000 │\n\
+///                      │   8.000   0.000 │\n\
+///                      │   2.000   0.000 │\n\
+///                      └                 ┘";
+///     assert_eq!(format!("{:.3}", b), x_correct);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+pub fn solve_lin_sys_multi(b: &mut Matrix, a: &mut Matrix) -> Result<(), Error> {
+    let (m, n) = a.dims();
+    if m != n {
+        return Err(Error::BadDimensions("matrix must be square"));
+    }
+    let (nrow, nrhs) = b.dims();
+    if nrow != m {
+        return Err(Error::BadDimensions("matrices are incompatible"));
+    }
+    if m == 0 || nrhs == 0 {
+        return Ok(());
+    }
+    let mut ipiv = vec![0; m];
+    let m_i32 = to_i32(m);
+    let nrhs_i32 = to_i32(nrhs);
+    dgesv(m_i32, nrhs_i32, a.as_mut_data(), &mut ipiv, b.as_mut_data())?;
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(unused_imports))]
+mod tests {
+    use super::{solve_lin_sys_multi, Matrix};
+    use crate::{mat_approx_eq, Error};
+
+    #[test]
+    fn solve_lin_sys_multi_fails_on_wrong_dims() {
+        let mut a = Matrix::new(2, 3);
+        let mut b = Matrix::new(2, 1);
+        assert_eq!(
+            solve_lin_sys_multi(&mut b, &mut a),
+            Err(Error::BadDimensions("matrix must be square"))
+        );
+        let mut a = Matrix::new(2, 2);
+        let mut b = Matrix::new(3, 1);
+        assert_eq!(
+            solve_lin_sys_multi(&mut b, &mut a),
+            Err(Error::BadDimensions("matrices are incompatible"))
+        );
+    }
+
+    #[test]
+    fn solve_lin_sys_multi_0x0_works() {
+        let mut a = Matrix::new(0, 0);
+        let mut b = Matrix::new(0, 2);
+        solve_lin_sys_multi(&mut b, &mut a).unwrap();
+        let mut a = Matrix::new(2, 2);
+        let mut b = Matrix::new(2, 0);
+        solve_lin_sys_multi(&mut b, &mut a).unwrap();
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn solve_lin_sys_multi_works() {
+        #[rustfmt::skip]
+        let mut a = Matrix::from(&[
+            [2.0, 1.0, 1.0, 3.0, 2.0],
+            [1.0, 2.0, 2.0, 1.0, 1.0],
+            [1.0, 2.0, 9.0, 1.0, 5.0],
+            [3.0, 1.0, 1.0, 7.0, 1.0],
+            [2.0, 1.0, 5.0, 1.0, 8.0],
+        ]);
+        // the columns of b are a⋅[1,1,1,1,1], a⋅[1,0,0,0,0], and a⋅[1,2,3,4,5]
+        #[rustfmt::skip]
+        let mut b = Matrix::from(&[
+            [ 9.0, 2.0, 29.0],
+            [ 7.0, 1.0, 20.0],
+            [18.0, 1.0, 61.0],
+            [13.0, 3.0, 41.0],
+            [17.0, 2.0, 63.0],
+        ]);
+        solve_lin_sys_multi(&mut b, &mut a).unwrap();
+        #[rustfmt::skip]
+        let x_correct = &[
+            [1.0, 1.0, 1.0],
+            [1.0, 0.0, 2.0],
+            [1.0, 0.0, 3.0],
+            [1.0, 0.0, 4.0],
+            [1.0, 0.0, 5.0],
+        ];
+        mat_approx_eq(&b, x_correct, 1e-13);
+    }
+}
@@ -0,0 +1,132 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::Error;
+use russell_openblas::{dposv, to_i32};
+
+/// Solves a linear system with a symmetric positive-definite matrix (real numbers)
+///
+/// For a symmetric positive-definite matrix `a`, find `x` such that:
+///
+/// ```text
+///   a   ⋅  x  =  b
+/// (m,m)   (m)   (m)
+/// ```
+///
+/// However, the right-hand-side will hold the solution:
+///
+/// ```text
+/// b := a⁻¹⋅b == x
+/// ```
+///
+/// The solution is obtained via Cholesky decomposition using Lapack dposv routine,
+/// which is about twice as fast as the LU decomposition of [crate::solve_lin_sys].
+///
+/// # Note
+///
+/// 1. Only the lower triangle (and diagonal) of `a` is accessed
+/// 2. The matrix `a` will be modified
+/// 3. The right-hand-side `b` will contain the solution `x`
+///
+/// ```
+/// use russell_lab::{solve_lin_sys_posdef, Matrix, Vector, StrError};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     // set matrix and right-hand side
+///     let mut a = Matrix::from(&[
+///         [  4.0,  12.0, -16.0],
+///         [ 12.0,  37.0, -43.0],
+///         [-16.0, -43.0,  98.0],
+///     ]);
+///     let mut b = Vector::from(&[0.0, 6.0, 39.0]);
+///
+///     // solve linear system b := a⁻¹⋅b
+///     solve_lin_sys_posdef(&mut b, &mut a)?;
+///
+///     // check
+///     let x_correct = "┌       ┐\n\
+///                      │ 1.000 │\n\
+///                      │ 1.000 │\n\
+///                      │ 1.000 │\n\
+///                      └       ┘";
+///     assert_eq!(format!("{:.3}", b), x_correct);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+pub fn solve_lin_sys_posdef(b: &mut Vector, a: &mut Matrix) -> Result<(), Error> {
+    let (m, n) = a.dims();
+    if m != n {
+        return Err(Error::BadDimensions("matrix must be square"));
+    }
+    if b.dim() != m {
+        return Err(Error::BadDimensions("vector has wrong dimension"));
+    }
+    if m == 0 {
+        return Ok(());
+    }
+    let m_i32 = to_i32(m);
+    dposv(false, m_i32, 1, a.as_mut_data(), b.as_mut_data())?;
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(unused_imports))]
+mod tests {
+    use super::{solve_lin_sys_posdef, Matrix, Vector};
+    use crate::Error;
+    use russell_chk::vec_approx_eq;
+
+    #[test]
+    fn solve_lin_sys_posdef_fails_on_wrong_dims() {
+        let mut a = Matrix::new(2, 3);
+        let mut b = Vector::new(2);
+        assert_eq!(
+            solve_lin_sys_posdef(&mut b, &mut a),
+            Err(Error::BadDimensions("matrix must be square"))
+        );
+        let mut a = Matrix::new(2, 2);
+        let mut b = Vector::new(3);
+        assert_eq!(
+            solve_lin_sys_posdef(&mut b, &mut a),
+            Err(Error::BadDimensions("vector has wrong dimension"))
+        );
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn solve_lin_sys_posdef_fails_on_non_posdef() {
+        let mut a = Matrix::from(&[[1.0, 2.0], [2.0, 1.0]]);
+        let mut b = Vector::from(&[1.0, 1.0]);
+        assert_eq!(
+            solve_lin_sys_posdef(&mut b, &mut a),
+            Err(Error::Singular("LAPACK dposv failed"))
+        );
+    }
+
+    #[test]
+    fn solve_lin_sys_posdef_0x0_works() {
+        let mut a = Matrix::new(0, 0);
+        let mut b = Vector::new(0);
+        solve_lin_sys_posdef(&mut b, &mut a).unwrap();
+        assert_eq!(b.dim(), 0);
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn solve_lin_sys_posdef_works() {
+        // the upper triangle is not accessed
+        #[rustfmt::skip]
+        let mut a = Matrix::from(&[
+            [ 2.0, -1.0, -1.0, -1.0],
+            [-1.0,  2.0, -1.0, -1.0],
+            [ 0.0, -1.0,  2.0, -1.0],
+            [ 0.0,  0.0, -1.0,  2.0],
+        ]);
+        let mut b = Vector::from(&[1.0, 0.0, 0.0, 1.0]);
+        solve_lin_sys_posdef(&mut b, &mut a).unwrap();
+        vec_approx_eq(b.as_data(), &[1.0, 1.0, 1.0, 1.0], 1e-14);
+    }
+}
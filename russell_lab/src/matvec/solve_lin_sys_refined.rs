@@ -0,0 +1,191 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::Error;
+use russell_openblas::{dgesvx, to_i32};
+
+/// Holds the error estimates computed by solve_lin_sys_refined
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SolveStats {
+    /// Holds the estimate of the reciprocal condition number of the (equilibrated) matrix
+    ///
+    /// If `rcond` is less than the machine precision, the matrix is singular to working precision.
+    pub rcond: f64,
+
+    /// Holds the estimated forward error bound, i.e., ‖x - x_true‖∞ / ‖x‖∞
+    pub forward_error: f64,
+
+    /// Holds the componentwise relative backward error, i.e., the smallest relative change
+    /// in any element of `a` or `b` that makes `x` an exact solution
+    pub backward_error: f64,
+
+    /// Indicates that the system has been equilibrated (i.e., rows and/or columns were scaled)
+    pub equilibrated: bool,
+}
+
+/// Solves a general linear system with equilibration, iterative refinement, and error bounds
+///
+/// For a general square matrix `a`, find `x` such that:
+///
+/// ```text
+///   a   ⋅  x  =  b
+/// (m,m)   (m)   (m)
+/// ```
+///
+/// The system is equilibrated (if worthwhile), the matrix is factorized by LU decomposition,
+/// and the solution is improved by iterative refinement using the Lapack dgesvx routine.
+/// Compared with [crate::solve_lin_sys], this function is more expensive but does not modify
+/// `a` or `b` and returns the estimated errors and the reciprocal condition number.
+///
+/// # Note
+///
+/// A matrix that is singular to working precision (`rcond` less than the machine precision)
+/// does not result in an error; check `rcond` in the returned [SolveStats] instead.
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::vec_approx_eq;
+/// use russell_lab::{solve_lin_sys_refined, Matrix, Vector, StrError};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [1.0,  3.0, -2.0],
+///         [3.0,  5.0,  6.0],
+///         [2.0,  4.0,  3.0],
+///     ]);
+///     let b = Vector::from(&[5.0, 7.0, 8.0]);
+///     let mut x = Vector::new(3);
+///     let stats = solve_lin_sys_refined(&mut x, &a, &b)?;
+///     vec_approx_eq(x.as_data(), &[-15.0, 8.0, 2.0], 1e-13);
+///     assert!(stats.rcond > f64::EPSILON);
+///     assert!(stats.backward_error < 1e-15);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+pub fn solve_lin_sys_refined(x: &mut Vector, a: &Matrix, b: &Vector) -> Result<SolveStats, Error> {
+    let (m, n) = a.dims();
+    if m != n {
+        return Err(Error::BadDimensions("matrix must be square"));
+    }
+    if b.dim() != m || x.dim() != m {
+        return Err(Error::BadDimensions("vectors have wrong dimensions"));
+    }
+    if m == 0 {
+        return Ok(SolveStats {
+            rcond: 1.0,
+            forward_error: 0.0,
+            backward_error: 0.0,
+            equilibrated: false,
+        });
+    }
+    let mut a_copy = a.clone();
+    let mut b_copy = b.clone();
+    let mut af = vec![0.0; m * m];
+    let mut ipiv = vec![0; m];
+    let mut r = vec![0.0; m];
+    let mut c = vec![0.0; m];
+    let mut ferr = vec![0.0; 1];
+    let mut berr = vec![0.0; 1];
+    let (rcond, equed) = dgesvx(
+        true,
+        to_i32(m),
+        1,
+        a_copy.as_mut_data(),
+        &mut af,
+        &mut ipiv,
+        &mut r,
+        &mut c,
+        b_copy.as_mut_data(),
+        x.as_mut_data(),
+        &mut ferr,
+        &mut berr,
+    )?;
+    Ok(SolveStats {
+        rcond,
+        forward_error: ferr[0],
+        backward_error: berr[0],
+        equilibrated: equed != b'N',
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(all(test, not(feature = "pure_rust")))]
+mod tests {
+    use super::{solve_lin_sys_refined, Matrix, Vector};
+    use crate::Error;
+    use russell_chk::vec_approx_eq;
+
+    #[test]
+    fn solve_lin_sys_refined_captures_errors() {
+        let a = Matrix::new(2, 3);
+        let mut x = Vector::new(2);
+        let b = Vector::new(2);
+        assert_eq!(
+            solve_lin_sys_refined(&mut x, &a, &b),
+            Err(Error::BadDimensions("matrix must be square"))
+        );
+        let a = Matrix::new(2, 2);
+        let b_wrong = Vector::new(3);
+        assert_eq!(
+            solve_lin_sys_refined(&mut x, &a, &b_wrong),
+            Err(Error::BadDimensions("vectors have wrong dimensions"))
+        );
+        assert_eq!(
+            solve_lin_sys_refined(&mut x, &a, &b),
+            Err(Error::Singular("LAPACK dgesvx failed"))
+        );
+    }
+
+    #[test]
+    fn solve_lin_sys_refined_works() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [2.0,  1.0,  1.0,   3.0,  2.0],
+            [1.0,  2.0,  2.0,   1.0,  1.0],
+            [1.0,  2.0,  9.0,   1.0,  5.0],
+            [3.0,  1.0,  1.0,   7.0,  1.0],
+            [2.0,  1.0,  5.0,   1.0,  8.0],
+        ]);
+        let b = Vector::from(&[-2.0, 4.0, 3.0, -5.0, 1.0]);
+        let a_copy = a.clone();
+        let b_copy = b.clone();
+        let mut x = Vector::new(5);
+        let stats = solve_lin_sys_refined(&mut x, &a, &b).unwrap();
+        #[rustfmt::skip]
+        let x_correct = &[
+            -629.0 / 98.0,
+             237.0 / 49.0,
+             -53.0 / 49.0,
+              62.0 / 49.0,
+              23.0 / 14.0,
+        ];
+        vec_approx_eq(x.as_data(), x_correct, 1e-13);
+        assert_eq!(a.as_data(), a_copy.as_data());
+        assert_eq!(b.as_data(), b_copy.as_data());
+        assert!(stats.rcond > 1e-3 && stats.rcond <= 1.0);
+        assert!(stats.forward_error < 1e-12);
+        assert!(stats.backward_error < 1e-15);
+
+        // empty system
+        let stats = solve_lin_sys_refined(&mut Vector::new(0), &Matrix::new(0, 0), &Vector::new(0)).unwrap();
+        assert_eq!(stats.rcond, 1.0);
+    }
+
+    #[test]
+    fn solve_lin_sys_refined_handles_near_singular() {
+        let a = Matrix::from(&[[1.0, 1.0], [1.0, 1.0 + 1e-17]]);
+        let b = Vector::from(&[2.0, 2.0]);
+        let mut x = Vector::new(2);
+        // the matrix is exactly singular in floating point
+        assert_eq!(
+            solve_lin_sys_refined(&mut x, &a, &b),
+            Err(Error::Singular("LAPACK dgesvx failed"))
+        );
+        let a = Matrix::from(&[[1.0, 1.0], [1.0, 1.0 + 1e-15]]);
+        let stats = solve_lin_sys_refined(&mut x, &a, &b).unwrap();
+        assert!(stats.rcond < 1e-14);
+    }
+}
@@ -0,0 +1,135 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::Error;
+use russell_openblas::{dsysv, to_i32};
+
+/// Solves a linear system with a symmetric (possibly indefinite) matrix (real numbers)
+///
+/// For a symmetric matrix `a`, find `x` such that:
+///
+/// ```text
+///   a   ⋅  x  =  b
+/// (m,m)   (m)   (m)
+/// ```
+///
+/// However, the right-hand-side will hold the solution:
+///
+/// ```text
+/// b := a⁻¹⋅b == x
+/// ```
+///
+/// The solution is obtained via the diagonal pivoting (Bunch-Kaufman) decomposition
+/// using Lapack dsysv routine. If the matrix is also positive-definite, prefer
+/// [crate::solve_lin_sys_posdef].
+///
+/// # Note
+///
+/// 1. Only the lower triangle (and diagonal) of `a` is accessed
+/// 2. The matrix `a` will be modified
+/// 3. The right-hand-side `b` will contain the solution `x`
+///
+/// ```
+/// use russell_lab::{solve_lin_sys_sym, Matrix, Vector, StrError};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     // set matrix and right-hand side
+///     let mut a = Matrix::from(&[
+///         [1.0,  2.0, 3.0],
+///         [2.0, -4.0, 1.0],
+///         [3.0,  1.0, 0.0],
+///     ]);
+///     let mut b = Vector::from(&[6.0, -1.0, 4.0]);
+///
+///     // solve linear system b := a⁻¹⋅b
+///     solve_lin_sys_sym(&mut b, &mut a)?;
+///
+///     // check
+///     let x_correct = "┌       ┐\n\
+///                      │ 1.000 │\n\
+///                      │ 1.000 │\n\
+///                      │ 1.000 │\n\
+///                      └       ┘";
+///     assert_eq!(format!("{:.3}", b), x_correct);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+pub fn solve_lin_sys_sym(b: &mut Vector, a: &mut Matrix) -> Result<(), Error> {
+    let (m, n) = a.dims();
+    if m != n {
+        return Err(Error::BadDimensions("matrix must be square"));
+    }
+    if b.dim() != m {
+        return Err(Error::BadDimensions("vector has wrong dimension"));
+    }
+    if m == 0 {
+        return Ok(());
+    }
+    let mut ipiv = vec![0; m];
+    let m_i32 = to_i32(m);
+    dsysv(false, m_i32, 1, a.as_mut_data(), &mut ipiv, b.as_mut_data())?;
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(unused_imports))]
+mod tests {
+    use super::{solve_lin_sys_sym, Matrix, Vector};
+    use crate::Error;
+    use russell_chk::vec_approx_eq;
+
+    #[test]
+    fn solve_lin_sys_sym_fails_on_wrong_dims() {
+        let mut a = Matrix::new(2, 3);
+        let mut b = Vector::new(2);
+        assert_eq!(
+            solve_lin_sys_sym(&mut b, &mut a),
+            Err(Error::BadDimensions("matrix must be square"))
+        );
+        let mut a = Matrix::new(2, 2);
+        let mut b = Vector::new(3);
+        assert_eq!(
+            solve_lin_sys_sym(&mut b, &mut a),
+            Err(Error::BadDimensions("vector has wrong dimension"))
+        );
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn solve_lin_sys_sym_fails_on_singular() {
+        let mut a = Matrix::new(2, 2);
+        let mut b = Vector::from(&[1.0, 1.0]);
+        assert_eq!(
+            solve_lin_sys_sym(&mut b, &mut a),
+            Err(Error::Singular("LAPACK dsysv failed"))
+        );
+    }
+
+    #[test]
+    fn solve_lin_sys_sym_0x0_works() {
+        let mut a = Matrix::new(0, 0);
+        let mut b = Vector::new(0);
+        solve_lin_sys_sym(&mut b, &mut a).unwrap();
+        assert_eq!(b.dim(), 0);
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn solve_lin_sys_sym_works() {
+        // indefinite matrix (the upper triangle is not accessed)
+        #[rustfmt::skip]
+        let mut a = Matrix::from(&[
+            [ 0.0, 0.0, 0.0, 0.0],
+            [ 1.0, 0.0, 0.0, 0.0],
+            [ 2.0, 3.0, 1.0, 0.0],
+            [-1.0, 1.0, 2.0, -2.0],
+        ]);
+        // x = [1, 2, 3, 4]
+        let mut b = Vector::from(&[4.0, 14.0, 19.0, -1.0]);
+        solve_lin_sys_sym(&mut b, &mut a).unwrap();
+        vec_approx_eq(b.as_data(), &[1.0, 2.0, 3.0, 4.0], 1e-14);
+    }
+}
@@ -55,8 +55,8 @@ pub fn vec_mat_mul(v: &mut Vector, alpha: f64, u: &Vector, a: &Matrix) -> Result
     if m == 0 || n == 0 {
         return Ok(());
     }
-    let m_i32: i32 = to_i32(m);
-    let n_i32: i32 = to_i32(n);
+    let m_i32 = to_i32(m);
+    let n_i32 = to_i32(n);
     dgemv(
         true,
         m_i32,
@@ -3,17 +3,21 @@ use crate::vector::Vector;
 use crate::StrError;
 use russell_openblas::{dger, to_i32};
 
-/// Performs the outer (tensor) product between two vectors resulting in a matrix
+/// Performs the outer (tensor) product between two vectors and adds the result to a matrix (rank-1 update)
 ///
 /// ```text
-///   a  :=   α ⋅ u  outer  v
+///   a  +=   α ⋅ u  outer  v
 /// (m,n)        (m)       (n)
 /// ```
 ///
+/// i.e., `a := a + α ⋅ u ⋅ vᵀ`
+///
 /// # Note
 ///
-/// The rows of matrix a must equal the length of vector u and
-/// the columns of matrix a must equal the length of vector v
+/// 1. The rows of matrix a must equal the length of vector u and
+///    the columns of matrix a must equal the length of vector v
+/// 2. The previous contents of `a` are **not** cleared; thus, `a` must be
+///    zeroed (e.g., with `fill(0.0)`) to obtain the outer product alone
 ///
 /// # Example
 ///
@@ -40,8 +44,8 @@ pub fn vec_outer(a: &mut Matrix, alpha: f64, u: &Vector, v: &Vector) -> Result<(
     if a.nrow() != m || a.ncol() != n {
         return Err("matrix and vectors are incompatible");
     }
-    let m_i32: i32 = to_i32(m);
-    let n_i32: i32 = to_i32(n);
+    let m_i32 = to_i32(m);
+    let n_i32 = to_i32(n);
     dger(m_i32, n_i32, alpha, u.as_data(), 1, v.as_data(), 1, a.as_mut_data());
     Ok(())
 }
@@ -101,4 +105,19 @@ mod tests {
         ];
         mat_approx_eq(&a, correct, 1e-15);
     }
+
+    #[test]
+    fn vec_outer_accumulates() {
+        let u = Vector::from(&[1.0, 2.0]);
+        let v = Vector::from(&[3.0, -1.0]);
+        let mut a = Matrix::from(&[[1.0, 0.0], [0.0, 1.0]]);
+        vec_outer(&mut a, 0.5, &u, &v).unwrap();
+        vec_outer(&mut a, 0.5, &u, &v).unwrap();
+        #[rustfmt::skip]
+        let correct = &[
+            [4.0, -1.0],
+            [6.0, -1.0],
+        ];
+        mat_approx_eq(&a, correct, 1e-15);
+    }
 }
@@ -0,0 +1,535 @@
+use crate::{ComplexMatrix, ComplexVector, Matrix, StrError, Vector};
+use num_complex::Complex64;
+use std::ffi::OsStr;
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::Path;
+
+/// Holds the magic string of the NPY format
+const NPY_MAGIC: &[u8] = b"\x93NUMPY";
+
+/// Holds the data type description of f64 (little-endian)
+pub(crate) const DESCR_F64: &str = "<f8";
+
+/// Holds the data type description of Complex64 (little-endian)
+pub(crate) const DESCR_C128: &str = "<c16";
+
+/// Writes a matrix to a NumPy binary file (.npy)
+///
+/// The data is written in column-major (Fortran) order; thus, no copy is required.
+/// The file can be read in Python with `numpy.load(path)`.
+///
+/// See also `write_npz` (with the `npz` feature) to write many arrays into a single (.npz) file.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{read_npy_matrix, write_npy_matrix, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+///     let path = "/tmp/russell_lab/doc_write_npy_matrix.npy";
+///     write_npy_matrix(path, &a)?;
+///     let b = read_npy_matrix(path)?;
+///     assert_eq!(b.dims(), (2, 3));
+///     assert_eq!(b.as_data(), a.as_data());
+///     Ok(())
+/// }
+/// ```
+pub fn write_npy_matrix<P>(full_path: &P, a: &Matrix) -> Result<(), StrError>
+where
+    P: AsRef<OsStr> + ?Sized,
+{
+    let (m, n) = a.dims();
+    let mut bytes = Vec::with_capacity(8 * m * n);
+    for x in a.as_data() {
+        bytes.extend_from_slice(&x.to_le_bytes());
+    }
+    write_npy(full_path, DESCR_F64, &[m, n], &bytes)
+}
+
+/// Reads a matrix from a NumPy binary file (.npy)
+///
+/// The file must contain a 2D array of `float64` (little-endian) in either C or Fortran order.
+pub fn read_npy_matrix<P>(full_path: &P) -> Result<Matrix, StrError>
+where
+    P: AsRef<OsStr> + ?Sized,
+{
+    let (fortran_order, shape, data) = read_npy(full_path, DESCR_F64)?;
+    to_matrix(fortran_order, &shape, &data)
+}
+
+/// Writes a vector to a NumPy binary file (.npy)
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{read_npy_vector, write_npy_vector, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let u = Vector::from(&[1.0, 2.0, 3.0]);
+///     let path = "/tmp/russell_lab/doc_write_npy_vector.npy";
+///     write_npy_vector(path, &u)?;
+///     let v = read_npy_vector(path)?;
+///     assert_eq!(v.as_data(), u.as_data());
+///     Ok(())
+/// }
+/// ```
+pub fn write_npy_vector<P>(full_path: &P, u: &Vector) -> Result<(), StrError>
+where
+    P: AsRef<OsStr> + ?Sized,
+{
+    let mut bytes = Vec::with_capacity(8 * u.dim());
+    for x in u.as_data() {
+        bytes.extend_from_slice(&x.to_le_bytes());
+    }
+    write_npy(full_path, DESCR_F64, &[u.dim()], &bytes)
+}
+
+/// Reads a vector from a NumPy binary file (.npy)
+///
+/// The file must contain a 1D array of `float64` (little-endian).
+pub fn read_npy_vector<P>(full_path: &P) -> Result<Vector, StrError>
+where
+    P: AsRef<OsStr> + ?Sized,
+{
+    let (_, shape, data) = read_npy(full_path, DESCR_F64)?;
+    to_vector(&shape, &data)
+}
+
+/// Writes a complex matrix to a NumPy binary file (.npy)
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use russell_lab::{read_npy_complex_matrix, write_npy_complex_matrix, ComplexMatrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = ComplexMatrix::from(&[[Complex64::new(1.0, -1.0), Complex64::new(2.0, 3.0)]]);
+///     let path = "/tmp/russell_lab/doc_write_npy_complex_matrix.npy";
+///     write_npy_complex_matrix(path, &a)?;
+///     let b = read_npy_complex_matrix(path)?;
+///     assert_eq!(b.as_data(), a.as_data());
+///     Ok(())
+/// }
+/// ```
+pub fn write_npy_complex_matrix<P>(full_path: &P, a: &ComplexMatrix) -> Result<(), StrError>
+where
+    P: AsRef<OsStr> + ?Sized,
+{
+    let (m, n) = a.dims();
+    write_npy(full_path, DESCR_C128, &[m, n], &from_complex(a.as_data()))
+}
+
+/// Reads a complex matrix from a NumPy binary file (.npy)
+///
+/// The file must contain a 2D array of `complex128` (little-endian) in either C or Fortran order.
+pub fn read_npy_complex_matrix<P>(full_path: &P) -> Result<ComplexMatrix, StrError>
+where
+    P: AsRef<OsStr> + ?Sized,
+{
+    let (fortran_order, shape, data) = read_npy(full_path, DESCR_C128)?;
+    to_complex_matrix(fortran_order, &shape, &data)
+}
+
+/// Writes a complex vector to a NumPy binary file (.npy)
+pub fn write_npy_complex_vector<P>(full_path: &P, u: &ComplexVector) -> Result<(), StrError>
+where
+    P: AsRef<OsStr> + ?Sized,
+{
+    write_npy(full_path, DESCR_C128, &[u.dim()], &from_complex(u.as_data()))
+}
+
+/// Reads a complex vector from a NumPy binary file (.npy)
+///
+/// The file must contain a 1D array of `complex128` (little-endian).
+pub fn read_npy_complex_vector<P>(full_path: &P) -> Result<ComplexVector, StrError>
+where
+    P: AsRef<OsStr> + ?Sized,
+{
+    let (_, shape, data) = read_npy(full_path, DESCR_C128)?;
+    to_complex_vector(&shape, &data)
+}
+
+/// Writes the NPY (version 1.0) header and data
+fn write_npy<P>(full_path: &P, descr: &str, shape: &[usize], data: &[u8]) -> Result<(), StrError>
+where
+    P: AsRef<OsStr> + ?Sized,
+{
+    write_bytes(full_path, &encode_npy(descr, shape, data)?)
+}
+
+/// Returns the bytes of an NPY (version 1.0) file with the given header and data
+pub(crate) fn encode_npy(descr: &str, shape: &[usize], data: &[u8]) -> Result<Vec<u8>, StrError> {
+    // header dictionary (the comma is required for 1D shapes)
+    let dims: Vec<String> = shape.iter().map(|d| d.to_string()).collect();
+    let shape_str = if shape.len() == 1 {
+        format!("({},)", dims[0])
+    } else {
+        format!("({})", dims.join(", "))
+    };
+    let mut header = format!(
+        "{{'descr': '{}', 'fortran_order': True, 'shape': {}, }}",
+        descr, shape_str
+    );
+
+    // pad with spaces such that the data starts at a multiple of 64 bytes
+    let prefix_len = NPY_MAGIC.len() + 4;
+    let total = prefix_len + header.len() + 1;
+    header.push_str(&" ".repeat((64 - total % 64) % 64));
+    header.push('\n');
+    if header.len() > u16::MAX as usize {
+        return Err("npy header is too long");
+    }
+
+    // magic string, version, header, and data
+    let mut bytes = Vec::with_capacity(prefix_len + header.len() + data.len());
+    bytes.extend_from_slice(NPY_MAGIC);
+    bytes.extend_from_slice(&[1, 0]);
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    bytes.extend_from_slice(data);
+    Ok(bytes)
+}
+
+/// Writes the bytes to a file (creating the directory, if needed)
+pub(crate) fn write_bytes<P>(full_path: &P, bytes: &[u8]) -> Result<(), StrError>
+where
+    P: AsRef<OsStr> + ?Sized,
+{
+    // create directory
+    let path = Path::new(full_path);
+    if let Some(p) = path.parent() {
+        fs::create_dir_all(p).map_err(|_| "cannot create directory")?;
+    }
+
+    // write file
+    let mut file = File::create(path).map_err(|_| "cannot create file")?;
+    file.write_all(bytes).map_err(|_| "cannot write file")?;
+
+    // force sync
+    file.sync_all().map_err(|_| "cannot sync file")?;
+    Ok(())
+}
+
+/// Reads the NPY header and data
+///
+/// Returns `(fortran_order, shape, data)`
+fn read_npy<P>(full_path: &P, descr: &str) -> Result<(bool, Vec<usize>, Vec<u8>), StrError>
+where
+    P: AsRef<OsStr> + ?Sized,
+{
+    // read file
+    let path = Path::new(full_path);
+    let mut file = File::open(path).map_err(|_| "cannot open file")?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes).map_err(|_| "cannot read file")?;
+
+    // decode
+    let d = decode_npy(&bytes)?;
+    if d.descr != descr {
+        return Err("npy data type is not supported");
+    }
+    Ok((d.fortran_order, d.shape, d.data.to_vec()))
+}
+
+/// Holds the header values and the data of an NPY file
+pub(crate) struct NpyData<'a> {
+    pub(crate) descr: &'static str, // either DESCR_F64 or DESCR_C128
+    pub(crate) fortran_order: bool, // the data is in column-major order
+    pub(crate) shape: Vec<usize>,   // the dimensions of the array
+    pub(crate) data: &'a [u8],      // the (little-endian) data
+}
+
+/// Decodes the bytes of an NPY file
+pub(crate) fn decode_npy(bytes: &[u8]) -> Result<NpyData<'_>, StrError> {
+    // check magic string and version
+    let n_magic = NPY_MAGIC.len();
+    if bytes.len() < n_magic + 4 || &bytes[..n_magic] != NPY_MAGIC {
+        return Err("file is not in npy format");
+    }
+    let (header_start, header_len) = match bytes[n_magic] {
+        1 => (
+            n_magic + 4,
+            u16::from_le_bytes([bytes[n_magic + 2], bytes[n_magic + 3]]) as usize,
+        ),
+        2 | 3 => {
+            if bytes.len() < n_magic + 6 {
+                return Err("file is not in npy format");
+            }
+            let mut len = [0u8; 4];
+            len.copy_from_slice(&bytes[(n_magic + 2)..(n_magic + 6)]);
+            (n_magic + 6, u32::from_le_bytes(len) as usize)
+        }
+        _ => return Err("npy version is not supported"),
+    };
+    let data_start = header_start + header_len;
+    if bytes.len() < data_start {
+        return Err("npy header is incomplete");
+    }
+    let header = std::str::from_utf8(&bytes[header_start..data_start]).map_err(|_| "npy header is invalid")?;
+
+    // parse header
+    let descr = match header_value(header, "descr")?.trim_matches(|c| c == '\'' || c == '"') {
+        DESCR_F64 => DESCR_F64,
+        DESCR_C128 => DESCR_C128,
+        _ => return Err("npy data type is not supported"),
+    };
+    let fortran_order = match header_value(header, "fortran_order")? {
+        "True" => true,
+        "False" => false,
+        _ => return Err("npy header is invalid"),
+    };
+    let shape_str = header_value(header, "shape")?;
+    let mut shape = Vec::new();
+    for dim in shape_str.trim_matches(|c| c == '(' || c == ')').split(',') {
+        let dim = dim.trim();
+        if !dim.is_empty() {
+            shape.push(dim.parse::<usize>().map_err(|_| "npy header is invalid")?);
+        }
+    }
+
+    // check data size
+    let item_size = if descr == DESCR_C128 { 16 } else { 8 };
+    let size = shape
+        .iter()
+        .try_fold(item_size, |acc: usize, dim| acc.checked_mul(*dim))
+        .ok_or("npy shape is too large")?;
+    if bytes.len() - data_start != size {
+        return Err("npy data has wrong size");
+    }
+    Ok(NpyData {
+        descr,
+        fortran_order,
+        shape,
+        data: &bytes[data_start..],
+    })
+}
+
+/// Returns the (trimmed) value corresponding to a key in the NPY header dictionary
+fn header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, StrError> {
+    let pattern_1 = format!("'{}':", key);
+    let pattern_2 = format!("\"{}\":", key);
+    let start = match header.find(&pattern_1) {
+        Some(p) => p + pattern_1.len(),
+        None => match header.find(&pattern_2) {
+            Some(p) => p + pattern_2.len(),
+            None => return Err("npy header is invalid"),
+        },
+    };
+    let rest = header[start..].trim_start();
+    let end = if rest.starts_with('(') {
+        rest.find(')').map(|p| p + 1)
+    } else {
+        rest.find([',', '}'])
+    };
+    match end {
+        Some(p) => Ok(rest[..p].trim()),
+        None => Err("npy header is invalid"),
+    }
+}
+
+/// Converts the NPY data to a matrix
+pub(crate) fn to_matrix(fortran_order: bool, shape: &[usize], data: &[u8]) -> Result<Matrix, StrError> {
+    if shape.len() != 2 {
+        return Err("npy array must be two-dimensional");
+    }
+    let (m, n) = (shape[0], shape[1]);
+    let values = to_f64(data);
+    let mut a = Matrix::new(m, n);
+    for i in 0..m {
+        for j in 0..n {
+            a.set(i, j, values[if fortran_order { i + j * m } else { i * n + j }]);
+        }
+    }
+    Ok(a)
+}
+
+/// Converts the NPY data to a vector
+pub(crate) fn to_vector(shape: &[usize], data: &[u8]) -> Result<Vector, StrError> {
+    if shape.len() != 1 {
+        return Err("npy array must be one-dimensional");
+    }
+    Ok(Vector::from(&to_f64(data)))
+}
+
+/// Converts the NPY data to a complex matrix
+pub(crate) fn to_complex_matrix(fortran_order: bool, shape: &[usize], data: &[u8]) -> Result<ComplexMatrix, StrError> {
+    if shape.len() != 2 {
+        return Err("npy array must be two-dimensional");
+    }
+    let (m, n) = (shape[0], shape[1]);
+    let values = to_complex(data);
+    let mut a = ComplexMatrix::new(m, n);
+    for i in 0..m {
+        for j in 0..n {
+            a.set(i, j, values[if fortran_order { i + j * m } else { i * n + j }]);
+        }
+    }
+    Ok(a)
+}
+
+/// Converts the NPY data to a complex vector
+pub(crate) fn to_complex_vector(shape: &[usize], data: &[u8]) -> Result<ComplexVector, StrError> {
+    if shape.len() != 1 {
+        return Err("npy array must be one-dimensional");
+    }
+    Ok(ComplexVector::from(&to_complex(data)))
+}
+
+/// Converts little-endian bytes to f64 values
+fn to_f64(data: &[u8]) -> Vec<f64> {
+    data.chunks_exact(8)
+        .map(|c| {
+            let mut b = [0u8; 8];
+            b.copy_from_slice(c);
+            f64::from_le_bytes(b)
+        })
+        .collect()
+}
+
+/// Converts little-endian bytes to Complex64 values
+fn to_complex(data: &[u8]) -> Vec<Complex64> {
+    to_f64(data)
+        .chunks_exact(2)
+        .map(|c| Complex64::new(c[0], c[1]))
+        .collect()
+}
+
+/// Converts Complex64 values to little-endian bytes
+pub(crate) fn from_complex(values: &[Complex64]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(16 * values.len());
+    for x in values {
+        bytes.extend_from_slice(&x.re.to_le_bytes());
+        bytes.extend_from_slice(&x.im.to_le_bytes());
+    }
+    bytes
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        read_npy_complex_matrix, read_npy_complex_vector, read_npy_matrix, read_npy_vector, write_npy_complex_matrix,
+        write_npy_complex_vector, write_npy_matrix, write_npy_vector,
+    };
+    use crate::{ComplexMatrix, ComplexVector, Matrix, Vector};
+    use num_complex::Complex64;
+    use std::fs;
+
+    // writes a file with the given header and data (as if it had been written by NumPy)
+    fn write_raw(path: &str, header: &str, data: &[f64]) {
+        let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        for x in data {
+            bytes.extend_from_slice(&x.to_le_bytes());
+        }
+        fs::create_dir_all("/tmp/russell_lab").unwrap();
+        fs::write(path, bytes).unwrap();
+    }
+
+    #[test]
+    fn read_npy_captures_errors() {
+        assert_eq!(
+            read_npy_matrix("/tmp/russell_lab/not-found.npy").err(),
+            Some("cannot open file")
+        );
+        let path = "/tmp/russell_lab/test_read_npy_captures_errors.npy";
+        fs::create_dir_all("/tmp/russell_lab").unwrap();
+        fs::write(path, "1 2 3\n").unwrap();
+        assert_eq!(read_npy_matrix(path).err(), Some("file is not in npy format"));
+        write_raw(path, "{'descr': '<f4', 'fortran_order': False, 'shape': (1,), }\n", &[]);
+        assert_eq!(read_npy_vector(path).err(), Some("npy data type is not supported"));
+        write_raw(
+            path,
+            "{'descr': '<f8', 'fortran_order': Maybe, 'shape': (1,), }\n",
+            &[1.0],
+        );
+        assert_eq!(read_npy_vector(path).err(), Some("npy header is invalid"));
+        write_raw(
+            path,
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (2,), }\n",
+            &[1.0],
+        );
+        assert_eq!(read_npy_vector(path).err(), Some("npy data has wrong size"));
+        write_raw(
+            path,
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (4294967296, 4294967296), }\n",
+            &[1.0],
+        );
+        assert_eq!(read_npy_matrix(path).err(), Some("npy shape is too large"));
+        write_raw(
+            path,
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (1,), }\n",
+            &[1.0],
+        );
+        assert_eq!(read_npy_matrix(path).err(), Some("npy array must be two-dimensional"));
+        write_raw(
+            path,
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (1, 1), }\n",
+            &[1.0],
+        );
+        assert_eq!(read_npy_vector(path).err(), Some("npy array must be one-dimensional"));
+    }
+
+    #[test]
+    fn write_and_read_npy_work() {
+        let a = Matrix::from(&[[1.0, -2.0, 3.5], [4.0, 5.0, -6.25]]);
+        let path = "/tmp/russell_lab/test_write_npy_matrix.npy";
+        write_npy_matrix(path, &a).unwrap();
+        let bytes = fs::read(path).unwrap();
+        let header_len = u16::from_le_bytes([bytes[8], bytes[9]]) as usize;
+        assert_eq!((10 + header_len) % 64, 0);
+        assert_eq!(bytes.len(), 10 + header_len + 6 * 8);
+        let header = std::str::from_utf8(&bytes[10..(10 + header_len)]).unwrap();
+        assert!(header.starts_with("{'descr': '<f8', 'fortran_order': True, 'shape': (2, 3), }"));
+        assert!(header.ends_with('\n'));
+        let b = read_npy_matrix(path).unwrap();
+        assert_eq!(b.dims(), (2, 3));
+        assert_eq!(b.as_data(), a.as_data());
+
+        let u = Vector::from(&[1.0, 2.0, 3.0]);
+        let path = "/tmp/russell_lab/test_write_npy_vector.npy";
+        write_npy_vector(path, &u).unwrap();
+        let bytes = fs::read(path).unwrap();
+        assert!(bytes.windows(13).any(|w| w == b"'shape': (3,)"));
+        assert_eq!(read_npy_vector(path).unwrap().as_data(), u.as_data());
+
+        let a = ComplexMatrix::from(&[
+            [Complex64::new(1.0, 2.0), Complex64::new(3.0, 4.0)],
+            [Complex64::new(-1.0, 0.0), Complex64::new(0.0, -1.0)],
+        ]);
+        let path = "/tmp/russell_lab/test_write_npy_complex_matrix.npy";
+        write_npy_complex_matrix(path, &a).unwrap();
+        assert_eq!(read_npy_complex_matrix(path).unwrap().as_data(), a.as_data());
+
+        let u = ComplexVector::from(&[Complex64::new(1.0, 2.0), Complex64::new(3.0, 4.0)]);
+        let path = "/tmp/russell_lab/test_write_npy_complex_vector.npy";
+        write_npy_complex_vector(path, &u).unwrap();
+        assert_eq!(read_npy_complex_vector(path).unwrap().as_data(), u.as_data());
+
+        // empty
+        let path = "/tmp/russell_lab/test_write_npy_empty.npy";
+        write_npy_matrix(path, &Matrix::new(0, 3)).unwrap();
+        assert_eq!(read_npy_matrix(path).unwrap().dims(), (0, 3));
+    }
+
+    #[test]
+    fn read_npy_handles_c_order() {
+        // as written by numpy.save(path, numpy.array([[1, 2, 3], [4, 5, 6]], dtype=float))
+        let path = "/tmp/russell_lab/test_read_npy_handles_c_order.npy";
+        let header = "{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }                                                          \n";
+        write_raw(path, header, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let a = read_npy_matrix(path).unwrap();
+        assert_eq!(
+            format!("{}", a),
+            "┌       ┐\n\
+             │ 1 2 3 │\n\
+             │ 4 5 6 │\n\
+             └       ┘"
+        );
+    }
+}
@@ -0,0 +1,496 @@
+use crate::npy::{
+    decode_npy, encode_npy, from_complex, to_complex_matrix, to_complex_vector, to_matrix, to_vector, write_bytes,
+    DESCR_C128, DESCR_F64,
+};
+use crate::{ComplexMatrix, ComplexVector, Matrix, StrError, Vector};
+use flate2::read::DeflateDecoder;
+use flate2::Crc;
+use std::ffi::OsStr;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+/// Holds the signature of a local file header of a zip archive
+const ZIP_LOCAL_SIGNATURE: u32 = 0x04034b50;
+
+/// Holds the signature of a central directory header of a zip archive
+const ZIP_CENTRAL_SIGNATURE: u32 = 0x02014b50;
+
+/// Holds the signature of the end of central directory record of a zip archive
+const ZIP_END_SIGNATURE: u32 = 0x06054b50;
+
+/// Holds the size of the end of central directory record (without the comment)
+const ZIP_END_SIZE: usize = 22;
+
+/// Holds the identifier of the zip64 extended information extra field
+const ZIP64_EXTRA_ID: u16 = 0x0001;
+
+/// Holds the zip version (2.0) written to the headers
+const ZIP_VERSION: u16 = 20;
+
+/// Holds the MS-DOS date written to the headers (1980-01-01)
+const ZIP_DATE: u16 = (1 << 5) | 1;
+
+/// Holds one of the arrays stored in a NumPy zip archive (.npz)
+#[derive(Clone, Debug)]
+pub enum NpyArray {
+    /// A 1D array of `float64`
+    Vector(Vector),
+
+    /// A 2D array of `float64`
+    Matrix(Matrix),
+
+    /// A 1D array of `complex128`
+    ComplexVector(ComplexVector),
+
+    /// A 2D array of `complex128`
+    ComplexMatrix(ComplexMatrix),
+}
+
+/// Writes a named collection of arrays to a NumPy zip archive (.npz)
+///
+/// Each array is stored (uncompressed) as the `<name>.npy` entry of the archive.
+/// The file can be read in Python with `numpy.load(path)`; e.g., `numpy.load(path)["a"]`.
+///
+/// **Note:** This function is only available with the `npz` feature.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{read_npz, write_npz, Matrix, NpyArray, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     let arrays = [
+///         ("a", NpyArray::Matrix(Matrix::from(&[[1.0, 2.0], [3.0, 4.0]]))),
+///         ("x", NpyArray::Vector(Vector::from(&[5.0, 6.0]))),
+///     ];
+///     let path = "/tmp/russell_lab/doc_write_npz.npz";
+///     write_npz(path, &arrays)?;
+///     let res = read_npz(path)?;
+///     assert_eq!(res.len(), 2);
+///     assert_eq!(res[0].0, "a");
+///     match &res[1] {
+///         (name, NpyArray::Vector(x)) => {
+///             assert_eq!(name, "x");
+///             assert_eq!(x.as_data(), &[5.0, 6.0]);
+///         }
+///         _ => panic!("x should be a vector"),
+///     }
+///     Ok(())
+/// }
+/// ```
+pub fn write_npz<P>(full_path: &P, arrays: &[(&str, NpyArray)]) -> Result<(), StrError>
+where
+    P: AsRef<OsStr> + ?Sized,
+{
+    let mut bytes = Vec::new();
+    let mut directory = Vec::new();
+    for (name, array) in arrays {
+        let npy = match array {
+            NpyArray::Vector(u) => {
+                let mut data = Vec::with_capacity(8 * u.dim());
+                for x in u.as_data() {
+                    data.extend_from_slice(&x.to_le_bytes());
+                }
+                encode_npy(DESCR_F64, &[u.dim()], &data)?
+            }
+            NpyArray::Matrix(a) => {
+                let (m, n) = a.dims();
+                let mut data = Vec::with_capacity(8 * m * n);
+                for x in a.as_data() {
+                    data.extend_from_slice(&x.to_le_bytes());
+                }
+                encode_npy(DESCR_F64, &[m, n], &data)?
+            }
+            NpyArray::ComplexVector(u) => encode_npy(DESCR_C128, &[u.dim()], &from_complex(u.as_data()))?,
+            NpyArray::ComplexMatrix(a) => {
+                let (m, n) = a.dims();
+                encode_npy(DESCR_C128, &[m, n], &from_complex(a.as_data()))?
+            }
+        };
+        let entry_name = format!("{}.npy", name);
+        if entry_name.len() > u16::MAX as usize {
+            return Err("npz entry name is too long");
+        }
+        let offset = to_u32(bytes.len())?;
+        let size = to_u32(npy.len())?;
+        let mut crc = Crc::new();
+        crc.update(&npy);
+        let header = ZipHeader {
+            crc: crc.sum(),
+            size,
+            offset,
+        };
+        bytes.extend_from_slice(&ZIP_LOCAL_SIGNATURE.to_le_bytes());
+        header.write(&mut bytes, entry_name.len() as u16);
+        bytes.extend_from_slice(entry_name.as_bytes());
+        bytes.extend_from_slice(&npy);
+        directory.push((entry_name, header));
+    }
+
+    // central directory
+    let directory_offset = to_u32(bytes.len())?;
+    if directory.len() > u16::MAX as usize {
+        return Err("npz archive has too many entries");
+    }
+    for (entry_name, header) in &directory {
+        bytes.extend_from_slice(&ZIP_CENTRAL_SIGNATURE.to_le_bytes());
+        bytes.extend_from_slice(&ZIP_VERSION.to_le_bytes()); // version made by
+        header.write(&mut bytes, entry_name.len() as u16);
+        bytes.extend_from_slice(&[0; 10]); // comment length, disk number, and attributes
+        bytes.extend_from_slice(&header.offset.to_le_bytes());
+        bytes.extend_from_slice(entry_name.as_bytes());
+    }
+    let directory_size = to_u32(bytes.len())? - directory_offset;
+
+    // end of central directory record
+    bytes.extend_from_slice(&ZIP_END_SIGNATURE.to_le_bytes());
+    bytes.extend_from_slice(&[0; 4]); // disk numbers
+    bytes.extend_from_slice(&(directory.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(&(directory.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(&directory_size.to_le_bytes());
+    bytes.extend_from_slice(&directory_offset.to_le_bytes());
+    bytes.extend_from_slice(&[0; 2]); // comment length
+    write_bytes(full_path, &bytes)
+}
+
+/// Reads a named collection of arrays from a NumPy zip archive (.npz)
+///
+/// The entries may be stored or deflated (e.g., written by `numpy.savez` or `numpy.savez_compressed`).
+/// Each entry must contain a 1D or 2D array of `float64` or `complex128`.
+///
+/// Returns the `(name, array)` pairs in the order of the archive, where `name` excludes the `.npy` extension.
+///
+/// **Note:** This function is only available with the `npz` feature.
+pub fn read_npz<P>(full_path: &P) -> Result<Vec<(String, NpyArray)>, StrError>
+where
+    P: AsRef<OsStr> + ?Sized,
+{
+    // read file
+    let path = Path::new(full_path);
+    let mut file = File::open(path).map_err(|_| "cannot open file")?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes).map_err(|_| "cannot read file")?;
+
+    // find the end of central directory record (followed by a comment with up to 65535 bytes)
+    let min_start = bytes.len().saturating_sub(ZIP_END_SIZE + u16::MAX as usize);
+    let end = (min_start..(bytes.len().saturating_sub(ZIP_END_SIZE - 1)))
+        .rev()
+        .find(|p| read_u32(&bytes, *p) == Ok(ZIP_END_SIGNATURE))
+        .ok_or("file is not in npz format")?;
+    let n_entries = read_u16(&bytes, end + 10)? as usize;
+    let mut p = read_u32(&bytes, end + 16)? as usize;
+    if n_entries == u16::MAX as usize || p == u32::MAX as usize {
+        return Err("npz archives in zip64 format are not supported");
+    }
+
+    // central directory
+    let mut arrays = Vec::with_capacity(n_entries);
+    for _ in 0..n_entries {
+        if read_u32(&bytes, p)? != ZIP_CENTRAL_SIGNATURE {
+            return Err("npz central directory is invalid");
+        }
+        let method = read_u16(&bytes, p + 10)?;
+        let crc = read_u32(&bytes, p + 16)?;
+        let mut compressed_size = read_u32(&bytes, p + 20)? as u64;
+        let mut size = read_u32(&bytes, p + 24)? as u64;
+        let name_len = read_u16(&bytes, p + 28)? as usize;
+        let extra_len = read_u16(&bytes, p + 30)? as usize;
+        let comment_len = read_u16(&bytes, p + 32)? as usize;
+        let mut offset = read_u32(&bytes, p + 42)? as u64;
+        let name = bytes
+            .get((p + 46)..(p + 46 + name_len))
+            .ok_or("npz central directory is invalid")?;
+        let name = std::str::from_utf8(name).map_err(|_| "npz entry name is invalid")?;
+
+        // zip64 extended information (e.g., written by numpy.savez)
+        let extra = bytes
+            .get((p + 46 + name_len)..(p + 46 + name_len + extra_len))
+            .ok_or("npz central directory is invalid")?;
+        let mut q = 0;
+        while q + 4 <= extra.len() {
+            let id = read_u16(extra, q)?;
+            let len = read_u16(extra, q + 2)? as usize;
+            if id == ZIP64_EXTRA_ID {
+                let mut r = q + 4;
+                for value in [&mut size, &mut compressed_size, &mut offset] {
+                    if *value == u32::MAX as u64 {
+                        *value = read_u64(extra, r)?;
+                        r += 8;
+                    }
+                }
+            }
+            q += 4 + len;
+        }
+        p += 46 + name_len + extra_len + comment_len;
+
+        // local header and data
+        let offset = usize::try_from(offset).map_err(|_| "npz entry is incomplete")?;
+        if read_u32(&bytes, offset)? != ZIP_LOCAL_SIGNATURE {
+            return Err("npz local header is invalid");
+        }
+        let start = offset + 30 + read_u16(&bytes, offset + 26)? as usize + read_u16(&bytes, offset + 28)? as usize;
+        let compressed = usize::try_from(compressed_size)
+            .ok()
+            .and_then(|len| start.checked_add(len))
+            .and_then(|end| bytes.get(start..end))
+            .ok_or("npz entry is incomplete")?;
+        let npy = match method {
+            0 => compressed.to_vec(),
+            8 => {
+                let mut npy = Vec::new();
+                DeflateDecoder::new(compressed)
+                    .read_to_end(&mut npy)
+                    .map_err(|_| "cannot decompress npz entry")?;
+                npy
+            }
+            _ => return Err("npz compression method is not supported"),
+        };
+        let mut checksum = Crc::new();
+        checksum.update(&npy);
+        if npy.len() as u64 != size || checksum.sum() != crc {
+            return Err("npz entry is corrupted");
+        }
+
+        // array
+        let d = decode_npy(&npy)?;
+        let array = match (d.descr, d.shape.len()) {
+            (DESCR_F64, 1) => NpyArray::Vector(to_vector(&d.shape, d.data)?),
+            (DESCR_F64, 2) => NpyArray::Matrix(to_matrix(d.fortran_order, &d.shape, d.data)?),
+            (_, 1) => NpyArray::ComplexVector(to_complex_vector(&d.shape, d.data)?),
+            (_, 2) => NpyArray::ComplexMatrix(to_complex_matrix(d.fortran_order, &d.shape, d.data)?),
+            _ => return Err("npy array must be one- or two-dimensional"),
+        };
+        arrays.push((name.strip_suffix(".npy").unwrap_or(name).to_string(), array));
+    }
+    Ok(arrays)
+}
+
+/// Holds the fields shared by the local and central headers of a (stored) zip entry
+struct ZipHeader {
+    crc: u32,    // CRC-32 of the data
+    size: u32,   // size of the data (compressed and uncompressed)
+    offset: u32, // offset of the local header
+}
+
+impl ZipHeader {
+    /// Writes the fields from "version needed to extract" to "extra field length"
+    fn write(&self, bytes: &mut Vec<u8>, name_len: u16) {
+        bytes.extend_from_slice(&ZIP_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&[0; 4]); // flags and compression method (stored)
+        bytes.extend_from_slice(&[0; 2]); // time
+        bytes.extend_from_slice(&ZIP_DATE.to_le_bytes());
+        bytes.extend_from_slice(&self.crc.to_le_bytes());
+        bytes.extend_from_slice(&self.size.to_le_bytes());
+        bytes.extend_from_slice(&self.size.to_le_bytes());
+        bytes.extend_from_slice(&name_len.to_le_bytes());
+        bytes.extend_from_slice(&[0; 2]); // extra field length
+    }
+}
+
+/// Converts a size or offset to u32 (zip64 is not supported)
+fn to_u32(value: usize) -> Result<u32, StrError> {
+    u32::try_from(value).map_err(|_| "npz archive is too large")
+}
+
+/// Reads a little-endian u16
+fn read_u16(bytes: &[u8], p: usize) -> Result<u16, StrError> {
+    match p.checked_add(2).and_then(|q| bytes.get(p..q)) {
+        Some(b) => Ok(u16::from_le_bytes([b[0], b[1]])),
+        None => Err("npz archive is incomplete"),
+    }
+}
+
+/// Reads a little-endian u32
+fn read_u32(bytes: &[u8], p: usize) -> Result<u32, StrError> {
+    match p.checked_add(4).and_then(|q| bytes.get(p..q)) {
+        Some(b) => Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]])),
+        None => Err("npz archive is incomplete"),
+    }
+}
+
+/// Reads a little-endian u64
+fn read_u64(bytes: &[u8], p: usize) -> Result<u64, StrError> {
+    match p.checked_add(8).and_then(|q| bytes.get(p..q)) {
+        Some(b) => {
+            let mut v = [0u8; 8];
+            v.copy_from_slice(b);
+            Ok(u64::from_le_bytes(v))
+        }
+        None => Err("npz archive is incomplete"),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{read_npz, write_npz, NpyArray};
+    use crate::{ComplexMatrix, ComplexVector, Matrix, Vector};
+    use flate2::write::DeflateEncoder;
+    use flate2::{Compression, Crc};
+    use num_complex::Complex64;
+    use std::fs;
+    use std::io::Write;
+
+    // writes a zip archive with a single entry (as if it had been written by numpy.savez or numpy.savez_compressed)
+    fn write_raw_zip(path: &str, name: &str, npy: &[u8], deflate: bool) {
+        let data = if deflate {
+            let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(npy).unwrap();
+            encoder.finish().unwrap()
+        } else {
+            npy.to_vec()
+        };
+        let mut crc = Crc::new();
+        crc.update(npy);
+        let method: u16 = if deflate { 8 } else { 0 };
+        // the sizes are given by the zip64 extra field
+        let mut extra = vec![1, 0, 16, 0];
+        extra.extend_from_slice(&(npy.len() as u64).to_le_bytes());
+        extra.extend_from_slice(&(data.len() as u64).to_le_bytes());
+        let mut fields = Vec::new();
+        fields.extend_from_slice(&45u16.to_le_bytes());
+        fields.extend_from_slice(&0u16.to_le_bytes());
+        fields.extend_from_slice(&method.to_le_bytes());
+        fields.extend_from_slice(&[0, 0, 0x21, 0]);
+        fields.extend_from_slice(&crc.sum().to_le_bytes());
+        fields.extend_from_slice(&[0xff; 8]);
+        fields.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        fields.extend_from_slice(&(extra.len() as u16).to_le_bytes());
+        let mut bytes = vec![0x50, 0x4b, 0x03, 0x04];
+        bytes.extend_from_slice(&fields);
+        bytes.extend_from_slice(name.as_bytes());
+        bytes.extend_from_slice(&extra);
+        bytes.extend_from_slice(&data);
+        let directory_offset = bytes.len() as u32;
+        bytes.extend_from_slice(&[0x50, 0x4b, 0x01, 0x02, 45, 0]);
+        bytes.extend_from_slice(&fields);
+        bytes.extend_from_slice(&[0; 14]);
+        bytes.extend_from_slice(name.as_bytes());
+        bytes.extend_from_slice(&extra);
+        let directory_size = bytes.len() as u32 - directory_offset;
+        bytes.extend_from_slice(&[0x50, 0x4b, 0x05, 0x06, 0, 0, 0, 0, 1, 0, 1, 0]);
+        bytes.extend_from_slice(&directory_size.to_le_bytes());
+        bytes.extend_from_slice(&directory_offset.to_le_bytes());
+        bytes.extend_from_slice(&[0, 0]);
+        fs::create_dir_all("/tmp/russell_lab").unwrap();
+        fs::write(path, bytes).unwrap();
+    }
+
+    #[test]
+    fn read_npz_captures_errors() {
+        assert_eq!(
+            read_npz("/tmp/russell_lab/not-found.npz").err(),
+            Some("cannot open file")
+        );
+        let path = "/tmp/russell_lab/test_read_npz_captures_errors.npz";
+        fs::create_dir_all("/tmp/russell_lab").unwrap();
+        fs::write(path, "1 2 3\n").unwrap();
+        assert_eq!(read_npz(path).err(), Some("file is not in npz format"));
+
+        // corrupted data
+        write_npz(path, &[("u", NpyArray::Vector(Vector::from(&[1.0, 2.0])))]).unwrap();
+        let mut bytes = fs::read(path).unwrap();
+        let p = bytes.windows(5).position(|w| w == b"u.npy").unwrap() + 5;
+        bytes[p + 100] ^= 1;
+        fs::write(path, &bytes).unwrap();
+        assert_eq!(read_npz(path).err(), Some("npz entry is corrupted"));
+
+        // unsupported compression method
+        bytes[p + 100] ^= 1;
+        let q = bytes.windows(4).position(|w| w == [0x50, 0x4b, 0x01, 0x02]).unwrap();
+        bytes[q + 10] = 12; // bzip2
+        fs::write(path, &bytes).unwrap();
+        assert_eq!(read_npz(path).err(), Some("npz compression method is not supported"));
+
+        // unsupported array
+        write_raw_zip(path, "a.npy", b"\x93NUMPY\x01\x00\x0a\x00{'descr': ", false);
+        assert_eq!(read_npz(path).err(), Some("npy header is invalid"));
+
+        // compressed size (in the zip64 extra field of the central directory) is too large
+        let mut bytes = fs::read(path).unwrap();
+        let q = bytes.windows(4).rposition(|w| w == [1, 0, 16, 0]).unwrap();
+        bytes[(q + 12)..(q + 20)].copy_from_slice(&u64::MAX.to_le_bytes());
+        fs::write(path, &bytes).unwrap();
+        assert_eq!(read_npz(path).err(), Some("npz entry is incomplete"));
+    }
+
+    #[test]
+    fn write_and_read_npz_work() {
+        let arrays = [
+            (
+                "a",
+                NpyArray::Matrix(Matrix::from(&[[1.0, -2.0, 3.5], [4.0, 5.0, -6.25]])),
+            ),
+            ("u", NpyArray::Vector(Vector::from(&[1.0, 2.0, 3.0]))),
+            (
+                "ca",
+                NpyArray::ComplexMatrix(ComplexMatrix::from(&[[
+                    Complex64::new(1.0, 2.0),
+                    Complex64::new(3.0, 4.0),
+                ]])),
+            ),
+            (
+                "cu",
+                NpyArray::ComplexVector(ComplexVector::from(&[Complex64::new(-1.0, 0.5)])),
+            ),
+            ("empty", NpyArray::Matrix(Matrix::new(0, 3))),
+        ];
+        let path = "/tmp/russell_lab/test_write_npz.npz";
+        write_npz(path, &arrays).unwrap();
+        let res = read_npz(path).unwrap();
+        let names: Vec<_> = res.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, &["a", "u", "ca", "cu", "empty"]);
+        match (&res[0].1, &res[1].1, &res[2].1, &res[3].1, &res[4].1) {
+            (
+                NpyArray::Matrix(a),
+                NpyArray::Vector(u),
+                NpyArray::ComplexMatrix(ca),
+                NpyArray::ComplexVector(cu),
+                NpyArray::Matrix(empty),
+            ) => {
+                assert_eq!(a.dims(), (2, 3));
+                assert_eq!(a.as_data(), &[1.0, 4.0, -2.0, 5.0, 3.5, -6.25]);
+                assert_eq!(u.as_data(), &[1.0, 2.0, 3.0]);
+                assert_eq!(ca.as_data(), &[Complex64::new(1.0, 2.0), Complex64::new(3.0, 4.0)]);
+                assert_eq!(cu.as_data(), &[Complex64::new(-1.0, 0.5)]);
+                assert_eq!(empty.dims(), (0, 3));
+            }
+            _ => panic!("wrong kinds of arrays"),
+        }
+
+        // no arrays
+        write_npz(path, &[]).unwrap();
+        assert_eq!(read_npz(path).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn read_npz_handles_zip64_and_deflate() {
+        // as written by numpy.savez_compressed(path, a=numpy.array([[1, 2, 3], [4, 5, 6]], dtype=float))
+        let mut npy = b"\x93NUMPY\x01\x00\x76\x00".to_vec();
+        npy.extend_from_slice(b"{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }");
+        npy.extend_from_slice(&[b' '; 58]);
+        npy.push(b'\n');
+        for x in [1.0, 2.0, 3.0, 4.0, 5.0, 6.0_f64] {
+            npy.extend_from_slice(&x.to_le_bytes());
+        }
+        let path = "/tmp/russell_lab/test_read_npz_handles_zip64_and_deflate.npz";
+        for deflate in [false, true] {
+            write_raw_zip(path, "a.npy", &npy, deflate);
+            let res = read_npz(path).unwrap();
+            assert_eq!(res.len(), 1);
+            assert_eq!(res[0].0, "a");
+            match &res[0].1 {
+                NpyArray::Matrix(a) => assert_eq!(
+                    format!("{}", a),
+                    "┌       ┐\n\
+                     │ 1 2 3 │\n\
+                     │ 4 5 6 │\n\
+                     └       ┘"
+                ),
+                _ => panic!("a should be a matrix"),
+            }
+        }
+    }
+}
@@ -0,0 +1,562 @@
+use super::OdeStats;
+use crate::matrix::Matrix;
+use crate::matvec::DenseSolver;
+use crate::vector::{vec_rms_scaled, Vector};
+use crate::{Factorization, StrError};
+
+/// Defines the function f(t, y) as used internally by Bdf2
+type FnRhs<'a> = dyn FnMut(&mut Vector, f64, &Vector) -> Result<(), StrError> + 'a;
+
+/// Defines the Jacobian function J(t, y) as used internally by Bdf2
+type FnJacobian<'a> = dyn FnMut(&mut Matrix, f64, &Vector) -> Result<(), StrError> + 'a;
+
+/// Defines the callback function as used internally by Bdf2
+type FnCallback<'a> = dyn FnMut(f64, &Vector) -> Result<(), StrError> + 'a;
+
+/// Solves stiff systems of ODEs using the adaptive (variable step) second-order BDF method
+///
+/// Integrates the system:
+///
+/// ```text
+/// dy
+/// —— = f(t, y)       with y(t0) given
+/// dt
+/// ```
+///
+/// from `t0` to `t1` using the implicit variable-step BDF2 formula (with ω = hₙ / hₙ₋₁):
+///
+/// ```text
+///          (1+ω)²          ω²              1+ω
+/// yₙ₊₁ - ——————— yₙ + ——————— yₙ₋₁ = hₙ ——————— f(tₙ₊₁, yₙ₊₁)
+///          1+2ω           1+2ω            1+2ω
+/// ```
+///
+/// The first step uses the backward Euler method. The nonlinear system of each step is solved by
+/// the simplified Newton method with the iteration matrix `M = I - α h J` where `J = ∂f/∂y`.
+/// The Jacobian is kept for as many steps as possible and `M` is factorized (by [DenseSolver])
+/// only when `α h` changes or the Jacobian is updated. The Jacobian is re-evaluated if the Newton
+/// iterations fail to converge; if they fail with a fresh Jacobian, the step size is halved.
+///
+/// The local error is estimated by comparing the solution with an explicit predictor
+/// (Milne's device) and the step size is selected such that the scaled RMS norm of the
+/// error (see [crate::vec_rms_scaled]) is smaller than one. The step size ratio is limited
+/// to `ω ≤ 2` to ensure zero-stability and the step size is kept unchanged if it would
+/// increase by less than 20% (to reuse the factorization).
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::approx_eq;
+/// use russell_lab::{Bdf2, StrError, Vector};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     // stiff problem: dy/dt = -1000 (y - cos(t)) - sin(t) with y(0) = 1 => y = cos(t)
+///     let mut y = Vector::from(&[1.0]);
+///     let mut solver = Bdf2::new();
+///     solver.tolerances(1e-8, 1e-8);
+///     let stats = solver.solve(
+///         &mut y,
+///         0.0,
+///         1.0,
+///         |dydt, t, y| {
+///             dydt[0] = -1000.0 * (y[0] - f64::cos(t)) - f64::sin(t);
+///             Ok(())
+///         },
+///         |_, _| Ok(()),
+///     )?;
+///     approx_eq(y[0], f64::cos(1.0), 1e-6);
+///     assert!(stats.n_jacobian_evals < stats.n_accepted);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+///
+/// # Reference
+///
+/// * Hairer E and Wanner G (2002) Solving Ordinary Differential Equations II
+///   Stiff and Differential-Algebraic Problems, 2nd Revision, Springer, Section III.5
+#[derive(Clone, Copy, Debug)]
+pub struct Bdf2 {
+    abs_tol: f64,                 // absolute tolerance
+    rel_tol: f64,                 // relative tolerance
+    h_ini: f64,                   // initial step size
+    h_max: f64,                   // maximum step size (0 => t1 - t0)
+    max_steps: usize,             // maximum number of steps (accepted and rejected)
+    newton_max_iterations: usize, // maximum number of Newton iterations per step
+    newton_tol: f64,              // tolerance on the scaled RMS norm of the Newton corrections
+    safety: f64,                  // safety factor of the step size control
+    fac_min: f64,                 // minimum factor to decrease the step size
+    fac_max: f64,                 // maximum factor to increase the step size
+}
+
+impl Bdf2 {
+    /// Allocates a new instance with default options
+    pub fn new() -> Self {
+        Bdf2 {
+            abs_tol: 1e-6,
+            rel_tol: 1e-6,
+            h_ini: 1e-6,
+            h_max: 0.0,
+            max_steps: 100_000,
+            newton_max_iterations: 7,
+            newton_tol: 0.01,
+            safety: 0.9,
+            fac_min: 0.2,
+            fac_max: 2.0,
+        }
+    }
+
+    /// Sets the absolute and relative tolerances (both must be positive)
+    pub fn tolerances(&mut self, abs_tol: f64, rel_tol: f64) -> &mut Self {
+        self.abs_tol = abs_tol;
+        self.rel_tol = rel_tol;
+        self
+    }
+
+    /// Sets the initial step size
+    pub fn h_ini(&mut self, value: f64) -> &mut Self {
+        self.h_ini = value;
+        self
+    }
+
+    /// Sets the maximum step size (zero means t1 - t0)
+    pub fn h_max(&mut self, value: f64) -> &mut Self {
+        self.h_max = value;
+        self
+    }
+
+    /// Sets the maximum number of steps (accepted and rejected)
+    pub fn max_steps(&mut self, value: usize) -> &mut Self {
+        self.max_steps = value;
+        self
+    }
+
+    /// Sets the maximum number of Newton iterations per step
+    pub fn newton_max_iterations(&mut self, value: usize) -> &mut Self {
+        self.newton_max_iterations = value;
+        self
+    }
+
+    /// Sets the tolerance on the scaled RMS norm of the Newton corrections
+    ///
+    /// **Note:** The norm is scaled by the absolute and relative tolerances; thus,
+    /// the value should be smaller than one.
+    pub fn newton_tol(&mut self, value: f64) -> &mut Self {
+        self.newton_tol = value;
+        self
+    }
+
+    /// Integrates the system from t0 to t1 approximating the Jacobian by finite differences
+    ///
+    /// # Input
+    ///
+    /// * `y` -- (n) the initial state y(t0); will hold the state y(t1)
+    /// * `t0` -- the initial time
+    /// * `t1` -- the final time (must be greater than t0)
+    /// * `f` -- the function `f(dydt, t, y)` that computes `dydt := f(t, y)`
+    /// * `callback` -- the function `callback(t, y)` called after each accepted step
+    pub fn solve<F, C>(&self, y: &mut Vector, t0: f64, t1: f64, mut f: F, mut callback: C) -> Result<OdeStats, StrError>
+    where
+        F: FnMut(&mut Vector, f64, &Vector) -> Result<(), StrError>,
+        C: FnMut(f64, &Vector) -> Result<(), StrError>,
+    {
+        self.run(y, t0, t1, &mut f, None, &mut callback)
+    }
+
+    /// Integrates the system from t0 to t1 using the analytical Jacobian
+    ///
+    /// # Input
+    ///
+    /// * `y` -- (n) the initial state y(t0); will hold the state y(t1)
+    /// * `t0` -- the initial time
+    /// * `t1` -- the final time (must be greater than t0)
+    /// * `f` -- the function `f(dydt, t, y)` that computes `dydt := f(t, y)`
+    /// * `jac` -- the function `jac(jj, t, y)` that computes `jj := ∂f/∂y (t, y)`, an (n,n) matrix
+    /// * `callback` -- the function `callback(t, y)` called after each accepted step
+    pub fn solve_with_jacobian<F, J, C>(
+        &self,
+        y: &mut Vector,
+        t0: f64,
+        t1: f64,
+        mut f: F,
+        mut jac: J,
+        mut callback: C,
+    ) -> Result<OdeStats, StrError>
+    where
+        F: FnMut(&mut Vector, f64, &Vector) -> Result<(), StrError>,
+        J: FnMut(&mut Matrix, f64, &Vector) -> Result<(), StrError>,
+        C: FnMut(f64, &Vector) -> Result<(), StrError>,
+    {
+        self.run(y, t0, t1, &mut f, Some(&mut jac), &mut callback)
+    }
+
+    /// Runs the time integration
+    fn run(
+        &self,
+        y: &mut Vector,
+        t0: f64,
+        t1: f64,
+        f: &mut FnRhs,
+        mut jac: Option<&mut FnJacobian>,
+        callback: &mut FnCallback,
+    ) -> Result<OdeStats, StrError> {
+        if t1 <= t0 {
+            return Err("t1 must be greater than t0");
+        }
+        if self.abs_tol <= 0.0 || self.rel_tol <= 0.0 {
+            return Err("the tolerances must be positive");
+        }
+        if self.h_ini <= 0.0 {
+            return Err("the initial step size must be positive");
+        }
+        let n = y.dim();
+        let h_max = if self.h_max > 0.0 { self.h_max } else { t1 - t0 };
+        let mut y_prev = Vector::new(n); // yₙ₋₁
+        let mut y_new = Vector::new(n); // yₙ₊₁
+        let mut y_pred = Vector::new(n); // predictor
+        let mut psi = Vector::new(n); // history part of the BDF formula
+        let mut fy = Vector::new(n); // f(tₙ, yₙ)
+        let mut w = Vector::new(n); // workspace
+        let mut delta = Vector::new(n); // Newton correction
+        let mut jj = Matrix::new(n, n);
+        let mut mm = Matrix::new(n, n);
+        let mut solver = DenseSolver::new(Factorization::Lu);
+        let mut stats = OdeStats::new();
+
+        let mut t = t0;
+        let mut h = f64::min(self.h_ini, h_max);
+        let mut h_prev = 0.0;
+        let mut has_prev = false;
+        let mut need_jacobian = true;
+        let mut fresh_jacobian = false;
+        let mut factorized_ah = f64::NAN;
+        f(&mut fy, t, y)?;
+        stats.n_function_evals += 1;
+
+        loop {
+            if stats.n_accepted + stats.n_rejected >= self.max_steps {
+                return Err("the maximum number of steps has been reached");
+            }
+            if h < 16.0 * f64::EPSILON * f64::max(1.0, f64::abs(t)) {
+                return Err("the step size became too small");
+            }
+            let last_step = t + h >= t1 - 16.0 * f64::EPSILON * f64::abs(t1);
+            if last_step {
+                h = t1 - t;
+            }
+
+            // coefficients, history, and predictor
+            let (alpha, err_factor) = if has_prev {
+                let om = h / h_prev;
+                let den = 1.0 + 2.0 * om;
+                for i in 0..n {
+                    psi[i] = (1.0 + om) * (1.0 + om) * y[i] / den - om * om * y_prev[i] / den;
+                    // quadratic extrapolation through (tₙ₋₁, yₙ₋₁) and (tₙ, yₙ, fₙ)
+                    let curv = (y_prev[i] - y[i] + fy[i] * h_prev) / (h_prev * h_prev);
+                    y_pred[i] = y[i] + fy[i] * h + curv * h * h;
+                }
+                ((1.0 + om) / den, (1.0 + om) / (2.0 + 3.0 * om))
+            } else {
+                for i in 0..n {
+                    psi[i] = y[i];
+                    y_pred[i] = y[i] + h * fy[i];
+                }
+                (1.0, 0.5)
+            };
+            let ah = alpha * h;
+
+            // Jacobian and iteration matrix
+            if need_jacobian {
+                stats.perf.start("jacobian");
+                match jac.as_mut() {
+                    Some(jac) => jac(&mut jj, t, y)?,
+                    None => {
+                        w.as_mut_data().copy_from_slice(y.as_data());
+                        for j in 0..n {
+                            let yj = y[j];
+                            let dy = f64::sqrt(f64::EPSILON * f64::max(1e-5, f64::abs(yj)));
+                            w[j] = yj + dy;
+                            f(&mut delta, t, &w)?;
+                            w[j] = yj;
+                            for i in 0..n {
+                                jj.set(i, j, (delta[i] - fy[i]) / dy);
+                            }
+                        }
+                        stats.n_function_evals += n;
+                    }
+                }
+                stats.perf.stop("jacobian");
+                stats.n_jacobian_evals += 1;
+                need_jacobian = false;
+                fresh_jacobian = true;
+                factorized_ah = f64::NAN;
+            }
+            if ah != factorized_ah {
+                for i in 0..n {
+                    for j in 0..n {
+                        let delta_ij = if i == j { 1.0 } else { 0.0 };
+                        mm.set(i, j, delta_ij - ah * jj.get(i, j));
+                    }
+                }
+                stats.n_factorizations += 1;
+                if stats.perf.measure("factorize", || solver.factorize(&mm)).is_err() {
+                    factorized_ah = f64::NAN;
+                    stats.n_rejected += 1;
+                    h /= 2.0;
+                    continue;
+                }
+                factorized_ah = ah;
+            }
+
+            // simplified Newton iterations
+            stats.perf.start("newton");
+            y_new.as_mut_data().copy_from_slice(y_pred.as_data());
+            let mut converged = false;
+            let mut norm_prev = f64::MAX;
+            for _ in 0..self.newton_max_iterations {
+                f(&mut w, t + h, &y_new)?;
+                stats.n_function_evals += 1;
+                stats.n_newton_iterations += 1;
+                for i in 0..n {
+                    w[i] = psi[i] + ah * w[i] - y_new[i]; // -G(y)
+                }
+                solver.solve(&mut delta, &w)?;
+                for i in 0..n {
+                    y_new[i] += delta[i];
+                }
+                let norm = vec_rms_scaled(&delta, y, self.abs_tol, self.rel_tol);
+                if !norm.is_finite() || norm > norm_prev {
+                    break; // diverging
+                }
+                if norm <= self.newton_tol {
+                    converged = true;
+                    break;
+                }
+                norm_prev = norm;
+            }
+            stats.perf.stop("newton");
+            if !converged {
+                stats.n_rejected += 1;
+                if fresh_jacobian {
+                    h /= 2.0;
+                } else {
+                    need_jacobian = true;
+                }
+                continue;
+            }
+
+            // error estimate
+            for i in 0..n {
+                w[i] = err_factor * (y_new[i] - y_pred[i]);
+            }
+            let rms = vec_rms_scaled(&w, y, self.abs_tol, self.rel_tol);
+            let fac = if rms > 0.0 {
+                self.safety * f64::powf(rms, -1.0 / 3.0)
+            } else {
+                self.fac_max
+            };
+            if rms <= 1.0 {
+                y_prev.as_mut_data().copy_from_slice(y.as_data());
+                y.as_mut_data().copy_from_slice(y_new.as_data());
+                h_prev = h;
+                has_prev = true;
+                t = if last_step { t1 } else { t + h };
+                f(&mut fy, t, y)?;
+                stats.n_function_evals += 1;
+                stats.n_accepted += 1;
+                stats.h_last = h;
+                stats.perf.measure("callback", || callback(t, y))?;
+                if last_step {
+                    return Ok(stats);
+                }
+                fresh_jacobian = false;
+                // keep the step size (and thus the factorization) if the increase would be small
+                let ratio = f64::min(self.fac_max, f64::max(self.fac_min, fac));
+                if !(1.0..=1.2).contains(&ratio) {
+                    h = f64::min(h * ratio, h_max);
+                }
+            } else {
+                stats.n_rejected += 1;
+                h *= f64::min(1.0, f64::max(self.fac_min, fac));
+            }
+        }
+    }
+}
+
+impl Default for Bdf2 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(dead_code, unused_imports))]
+mod tests {
+    use super::Bdf2;
+    use crate::{Matrix, StrError, Vector};
+    use russell_chk::{approx_eq, vec_approx_eq};
+
+    /// Robertson's chemical reaction problem (Hairer and Wanner 2002, page 3)
+    fn robertson(dydt: &mut Vector, _: f64, y: &Vector) -> Result<(), StrError> {
+        dydt[0] = -0.04 * y[0] + 1.0e4 * y[1] * y[2];
+        dydt[1] = 0.04 * y[0] - 1.0e4 * y[1] * y[2] - 3.0e7 * y[1] * y[1];
+        dydt[2] = 3.0e7 * y[1] * y[1];
+        Ok(())
+    }
+
+    fn robertson_jac(jj: &mut Matrix, _: f64, y: &Vector) -> Result<(), StrError> {
+        jj.set(0, 0, -0.04);
+        jj.set(0, 1, 1.0e4 * y[2]);
+        jj.set(0, 2, 1.0e4 * y[1]);
+        jj.set(1, 0, 0.04);
+        jj.set(1, 1, -1.0e4 * y[2] - 6.0e7 * y[1]);
+        jj.set(1, 2, -1.0e4 * y[1]);
+        jj.set(2, 0, 0.0);
+        jj.set(2, 1, 6.0e7 * y[1]);
+        jj.set(2, 2, 0.0);
+        Ok(())
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn solve_captures_errors() {
+        let mut y = Vector::from(&[1.0, 0.0, 0.0]);
+        let solver = Bdf2::new();
+        assert_eq!(
+            solver.solve(&mut y, 1.0, 0.0, robertson, |_, _| Ok(())).err(),
+            Some("t1 must be greater than t0")
+        );
+        let mut solver = Bdf2::new();
+        solver.tolerances(1e-6, 0.0);
+        assert_eq!(
+            solver.solve(&mut y, 0.0, 1.0, robertson, |_, _| Ok(())).err(),
+            Some("the tolerances must be positive")
+        );
+        let mut solver = Bdf2::new();
+        solver.h_ini(0.0);
+        assert_eq!(
+            solver.solve(&mut y, 0.0, 1.0, robertson, |_, _| Ok(())).err(),
+            Some("the initial step size must be positive")
+        );
+        let mut solver = Bdf2::new();
+        solver.max_steps(3);
+        assert_eq!(
+            solver.solve(&mut y, 0.0, 1.0, robertson, |_, _| Ok(())).err(),
+            Some("the maximum number of steps has been reached")
+        );
+        let solver = Bdf2::new();
+        assert_eq!(
+            solver.solve(&mut y, 0.0, 1.0, robertson, |_, _| Err("stop")).err(),
+            Some("stop")
+        );
+        assert_eq!(
+            solver
+                .solve_with_jacobian(&mut y, 0.0, 1.0, robertson, |_, _, _| Err("no jac"), |_, _| Ok(()))
+                .err(),
+            Some("no jac")
+        );
+    }
+
+    #[test]
+    fn setters_work() {
+        let mut solver = Bdf2::new();
+        solver
+            .tolerances(1e-3, 1e-4)
+            .h_ini(0.1)
+            .h_max(0.5)
+            .max_steps(10)
+            .newton_max_iterations(3)
+            .newton_tol(0.1);
+        assert_eq!(solver.abs_tol, 1e-3);
+        assert_eq!(solver.rel_tol, 1e-4);
+        assert_eq!(solver.h_ini, 0.1);
+        assert_eq!(solver.h_max, 0.5);
+        assert_eq!(solver.max_steps, 10);
+        assert_eq!(solver.newton_max_iterations, 3);
+        assert_eq!(solver.newton_tol, 0.1);
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn second_order_convergence() {
+        // dy/dt = -y with fixed step (h_max = h_ini and large tolerances)
+        let mut errors = Vec::new();
+        for h in [0.01, 0.005] {
+            let mut solver = Bdf2::new();
+            solver.tolerances(1.0, 1.0).h_ini(h).h_max(h);
+            let mut y = Vector::from(&[1.0]);
+            let stats = solver
+                .solve(
+                    &mut y,
+                    0.0,
+                    1.0,
+                    |dydt, _, y| {
+                        dydt[0] = -y[0];
+                        Ok(())
+                    },
+                    |_, _| Ok(()),
+                )
+                .unwrap();
+            assert_eq!(stats.n_rejected, 0);
+            errors.push(f64::abs(y[0] - f64::exp(-1.0)));
+        }
+        let order = f64::log2(errors[0] / errors[1]);
+        assert!(order > 1.8 && order < 2.2);
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn stiff_linear_problem_works() {
+        let f = |dydt: &mut Vector, t: f64, y: &Vector| {
+            dydt[0] = -1000.0 * (y[0] - f64::cos(t)) - f64::sin(t);
+            Ok(())
+        };
+        let mut solver = Bdf2::new();
+        solver.tolerances(1e-8, 1e-8);
+        let mut y = Vector::from(&[1.0]);
+        let mut t_prev = 0.0;
+        let stats = solver
+            .solve(&mut y, 0.0, 2.0, f, |t, y| {
+                assert!(t > t_prev);
+                t_prev = t;
+                approx_eq(y[0], f64::cos(t), 1e-5);
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(t_prev, 2.0);
+        approx_eq(y[0], f64::cos(2.0), 1e-6);
+        // the Jacobian is constant; thus, it is computed once
+        assert_eq!(stats.n_jacobian_evals, 1);
+        assert!(stats.n_factorizations < stats.n_accepted);
+        assert_eq!(stats.perf.get("jacobian").unwrap().count, 1);
+        assert_eq!(stats.perf.get("factorize").unwrap().count, stats.n_factorizations);
+        assert_eq!(stats.perf.get("callback").unwrap().count, stats.n_accepted);
+        // an explicit method would need more than 1000 steps (stability limit h < 0.002)
+        assert!(stats.n_accepted < 1000);
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn robertson_works() {
+        // reference solution at t = 40 (Hairer and Wanner 2002)
+        let y_ref = &[0.7158270687193, 9.185534764529e-6, 0.2841637457459];
+        let mut solver = Bdf2::new();
+        solver.tolerances(1e-10, 1e-6);
+        let mut y = Vector::from(&[1.0, 0.0, 0.0]);
+        let stats = solver
+            .solve_with_jacobian(&mut y, 0.0, 40.0, robertson, robertson_jac, |_, _| Ok(()))
+            .unwrap();
+        vec_approx_eq(y.as_data(), y_ref, 1e-4);
+        approx_eq(y[0] + y[1] + y[2], 1.0, 1e-12);
+        assert!(stats.n_jacobian_evals < stats.n_accepted);
+
+        // finite differences
+        let mut y_fd = Vector::from(&[1.0, 0.0, 0.0]);
+        let stats_fd = solver.solve(&mut y_fd, 0.0, 40.0, robertson, |_, _| Ok(())).unwrap();
+        vec_approx_eq(y_fd.as_data(), y.as_data(), 1e-5);
+        assert!(stats_fd.n_function_evals > stats.n_function_evals);
+    }
+}
@@ -0,0 +1,514 @@
+use super::OdeStats;
+use crate::vector::{vec_rms_scaled, Vector};
+use crate::StrError;
+
+// Butcher tableau of the Dormand-Prince 5(4) method
+const C2: f64 = 1.0 / 5.0;
+const C3: f64 = 3.0 / 10.0;
+const C4: f64 = 4.0 / 5.0;
+const C5: f64 = 8.0 / 9.0;
+const A21: f64 = 1.0 / 5.0;
+const A31: f64 = 3.0 / 40.0;
+const A32: f64 = 9.0 / 40.0;
+const A41: f64 = 44.0 / 45.0;
+const A42: f64 = -56.0 / 15.0;
+const A43: f64 = 32.0 / 9.0;
+const A51: f64 = 19372.0 / 6561.0;
+const A52: f64 = -25360.0 / 2187.0;
+const A53: f64 = 64448.0 / 6561.0;
+const A54: f64 = -212.0 / 729.0;
+const A61: f64 = 9017.0 / 3168.0;
+const A62: f64 = -355.0 / 33.0;
+const A63: f64 = 46732.0 / 5247.0;
+const A64: f64 = 49.0 / 176.0;
+const A65: f64 = -5103.0 / 18656.0;
+const A71: f64 = 35.0 / 384.0;
+const A73: f64 = 500.0 / 1113.0;
+const A74: f64 = 125.0 / 192.0;
+const A75: f64 = -2187.0 / 6784.0;
+const A76: f64 = 11.0 / 84.0;
+
+// coefficients of the error estimate (difference between the 5th and 4th order solutions)
+const E1: f64 = 71.0 / 57600.0;
+const E3: f64 = -71.0 / 16695.0;
+const E4: f64 = 71.0 / 1920.0;
+const E5: f64 = -17253.0 / 339200.0;
+const E6: f64 = 22.0 / 525.0;
+const E7: f64 = -1.0 / 40.0;
+
+// coefficients of the dense output (continuous extension of order 4)
+const D1: f64 = -12715105075.0 / 11282082432.0;
+const D3: f64 = 87487479700.0 / 32700410799.0;
+const D4: f64 = -10690763975.0 / 1880347072.0;
+const D5: f64 = 701980252875.0 / 199316789632.0;
+const D6: f64 = -1453857185.0 / 822651844.0;
+const D7: f64 = 69997945.0 / 29380423.0;
+
+/// Holds the information about the last accepted step, including the dense output
+///
+/// The dense output is a continuous extension of order 4 that interpolates the
+/// solution between the beginning and the end of the step without extra calls to f(t, y).
+pub struct DenseOutput {
+    /// Holds the time at the beginning of the step
+    t_old: f64,
+
+    /// Holds the time at the end of the step
+    t: f64,
+
+    /// Holds the state at the end of the step
+    y: Vector,
+
+    /// Holds the coefficients of the interpolating polynomial
+    rr: [Vector; 5],
+}
+
+impl DenseOutput {
+    /// Returns the time at the beginning of the step
+    pub fn t_old(&self) -> f64 {
+        self.t_old
+    }
+
+    /// Returns the time at the end of the step
+    pub fn t(&self) -> f64 {
+        self.t
+    }
+
+    /// Returns the state at the end of the step
+    pub fn y(&self) -> &Vector {
+        &self.y
+    }
+
+    /// Computes the state at time t ∈ [t_old, t] by interpolation
+    ///
+    /// # Output
+    ///
+    /// * `y_out` -- (n) the interpolated state
+    pub fn eval(&self, y_out: &mut Vector, t: f64) -> Result<(), StrError> {
+        if y_out.dim() != self.y.dim() {
+            return Err("vector has wrong dimension");
+        }
+        let h = self.t - self.t_old;
+        let theta = if h > 0.0 { (t - self.t_old) / h } else { 1.0 };
+        let theta1 = 1.0 - theta;
+        let rr = &self.rr;
+        for i in 0..y_out.dim() {
+            y_out[i] = rr[0][i] + theta * (rr[1][i] + theta1 * (rr[2][i] + theta * (rr[3][i] + theta1 * rr[4][i])));
+        }
+        Ok(())
+    }
+}
+
+/// Solves a system of ODEs using the adaptive Dormand-Prince 5(4) method (DOPRI5)
+///
+/// Integrates the system:
+///
+/// ```text
+/// dy
+/// —— = f(t, y)       with y(t0) given
+/// dt
+/// ```
+///
+/// from `t0` to `t1`. The step size is selected automatically such that the local error
+/// estimate, measured by the scaled RMS norm (see [crate::vec_rms_scaled]), is smaller than one.
+/// After each accepted step, a callback receives a [DenseOutput] that allows the solution to
+/// be evaluated at any time within the step.
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::approx_eq;
+/// use russell_lab::{DormandPrince, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     // dy/dt = -y with y(0) = 1
+///     let mut y = Vector::from(&[1.0]);
+///     let mut y_half = Vector::new(1);
+///     let mut solver = DormandPrince::new();
+///     solver.tolerances(1e-10, 1e-10);
+///     solver.solve(
+///         &mut y,
+///         0.0,
+///         1.0,
+///         |dydt, _, y| {
+///             dydt[0] = -y[0];
+///             Ok(())
+///         },
+///         |out| {
+///             if out.t_old() <= 0.5 && 0.5 <= out.t() {
+///                 out.eval(&mut y_half, 0.5)?;
+///             }
+///             Ok(())
+///         },
+///     )?;
+///     approx_eq(y[0], f64::exp(-1.0), 1e-9);
+///     approx_eq(y_half[0], f64::exp(-0.5), 1e-9);
+///     Ok(())
+/// }
+/// ```
+///
+/// # Reference
+///
+/// * Hairer E, Nørsett SP, Wanner G (1993) Solving Ordinary Differential Equations I:
+///   Nonstiff Problems, 2nd Revised Edition, Springer, Section II.4 and II.6
+#[derive(Clone, Copy, Debug)]
+pub struct DormandPrince {
+    abs_tol: f64,     // absolute tolerance
+    rel_tol: f64,     // relative tolerance
+    h_ini: f64,       // initial step size (0 => automatic)
+    h_max: f64,       // maximum step size (0 => t1 - t0)
+    max_steps: usize, // maximum number of steps (accepted and rejected)
+    safety: f64,      // safety factor of the step size control
+    fac_min: f64,     // minimum factor to decrease the step size
+    fac_max: f64,     // maximum factor to increase the step size
+}
+
+impl DormandPrince {
+    /// Allocates a new instance with default options
+    pub fn new() -> Self {
+        DormandPrince {
+            abs_tol: 1e-6,
+            rel_tol: 1e-6,
+            h_ini: 0.0,
+            h_max: 0.0,
+            max_steps: 100_000,
+            safety: 0.9,
+            fac_min: 0.2,
+            fac_max: 10.0,
+        }
+    }
+
+    /// Sets the absolute and relative tolerances (both must be positive)
+    pub fn tolerances(&mut self, abs_tol: f64, rel_tol: f64) -> &mut Self {
+        self.abs_tol = abs_tol;
+        self.rel_tol = rel_tol;
+        self
+    }
+
+    /// Sets the initial step size (zero means automatic selection)
+    pub fn h_ini(&mut self, value: f64) -> &mut Self {
+        self.h_ini = value;
+        self
+    }
+
+    /// Sets the maximum step size (zero means t1 - t0)
+    pub fn h_max(&mut self, value: f64) -> &mut Self {
+        self.h_max = value;
+        self
+    }
+
+    /// Sets the maximum number of steps (accepted and rejected)
+    pub fn max_steps(&mut self, value: usize) -> &mut Self {
+        self.max_steps = value;
+        self
+    }
+
+    /// Integrates the system from t0 to t1
+    ///
+    /// # Input
+    ///
+    /// * `y` -- (n) the initial state y(t0); will hold the state y(t1)
+    /// * `t0` -- the initial time
+    /// * `t1` -- the final time (must be greater than t0)
+    /// * `f` -- the function `f(dydt, t, y)` that computes `dydt := f(t, y)`
+    /// * `callback` -- the function called after each accepted step
+    pub fn solve<F, C>(&self, y: &mut Vector, t0: f64, t1: f64, mut f: F, mut callback: C) -> Result<OdeStats, StrError>
+    where
+        F: FnMut(&mut Vector, f64, &Vector) -> Result<(), StrError>,
+        C: FnMut(&DenseOutput) -> Result<(), StrError>,
+    {
+        if t1 <= t0 {
+            return Err("t1 must be greater than t0");
+        }
+        if self.abs_tol <= 0.0 || self.rel_tol <= 0.0 {
+            return Err("the tolerances must be positive");
+        }
+        let n = y.dim();
+        let h_max = if self.h_max > 0.0 { self.h_max } else { t1 - t0 };
+        let mut k1 = Vector::new(n);
+        let mut k2 = Vector::new(n);
+        let mut k3 = Vector::new(n);
+        let mut k4 = Vector::new(n);
+        let mut k5 = Vector::new(n);
+        let mut k6 = Vector::new(n);
+        let mut k7 = Vector::new(n);
+        let mut w = Vector::new(n);
+        let mut err = Vector::new(n);
+        let mut out = DenseOutput {
+            t_old: t0,
+            t: t0,
+            y: Vector::new(n),
+            rr: [
+                Vector::new(n),
+                Vector::new(n),
+                Vector::new(n),
+                Vector::new(n),
+                Vector::new(n),
+            ],
+        };
+        let mut stats = OdeStats::new();
+
+        // initial derivative and step size
+        let mut t = t0;
+        f(&mut k1, t, y)?;
+        stats.n_function_evals += 1;
+        let mut h = if self.h_ini > 0.0 {
+            self.h_ini
+        } else {
+            self.initial_step(&mut f, &mut w, &mut k2, t, y, &k1, &mut stats)?
+        };
+        h = f64::min(h, h_max);
+
+        let mut last_rejected = false;
+        loop {
+            if stats.n_accepted + stats.n_rejected >= self.max_steps {
+                return Err("the maximum number of steps has been reached");
+            }
+            if h < 16.0 * f64::EPSILON * f64::max(1.0, f64::abs(t)) {
+                return Err("the step size became too small");
+            }
+            let last_step = t + h >= t1 - 16.0 * f64::EPSILON * f64::abs(t1);
+            if last_step {
+                h = t1 - t;
+            }
+
+            // stages
+            stats.perf.start("step");
+            for i in 0..n {
+                w[i] = y[i] + h * A21 * k1[i];
+            }
+            f(&mut k2, t + C2 * h, &w)?;
+            for i in 0..n {
+                w[i] = y[i] + h * (A31 * k1[i] + A32 * k2[i]);
+            }
+            f(&mut k3, t + C3 * h, &w)?;
+            for i in 0..n {
+                w[i] = y[i] + h * (A41 * k1[i] + A42 * k2[i] + A43 * k3[i]);
+            }
+            f(&mut k4, t + C4 * h, &w)?;
+            for i in 0..n {
+                w[i] = y[i] + h * (A51 * k1[i] + A52 * k2[i] + A53 * k3[i] + A54 * k4[i]);
+            }
+            f(&mut k5, t + C5 * h, &w)?;
+            for i in 0..n {
+                w[i] = y[i] + h * (A61 * k1[i] + A62 * k2[i] + A63 * k3[i] + A64 * k4[i] + A65 * k5[i]);
+            }
+            f(&mut k6, t + h, &w)?;
+            for i in 0..n {
+                w[i] = y[i] + h * (A71 * k1[i] + A73 * k3[i] + A74 * k4[i] + A75 * k5[i] + A76 * k6[i]);
+            }
+            f(&mut k7, t + h, &w)?;
+            stats.n_function_evals += 6;
+
+            // error estimate
+            for i in 0..n {
+                err[i] = h * (E1 * k1[i] + E3 * k3[i] + E4 * k4[i] + E5 * k5[i] + E6 * k6[i] + E7 * k7[i]);
+            }
+            let rms = vec_rms_scaled(&err, y, self.abs_tol, self.rel_tol);
+            stats.perf.stop("step");
+            if !rms.is_finite() {
+                return Err("the error estimate is not finite");
+            }
+            let fac = if rms > 0.0 {
+                self.safety * f64::powf(rms, -0.2)
+            } else {
+                self.fac_max
+            };
+
+            if rms <= 1.0 {
+                // dense output coefficients
+                for i in 0..n {
+                    let ydiff = w[i] - y[i];
+                    let bspl = h * k1[i] - ydiff;
+                    out.rr[0][i] = y[i];
+                    out.rr[1][i] = ydiff;
+                    out.rr[2][i] = bspl;
+                    out.rr[3][i] = ydiff - h * k7[i] - bspl;
+                    out.rr[4][i] = h * (D1 * k1[i] + D3 * k3[i] + D4 * k4[i] + D5 * k5[i] + D6 * k6[i] + D7 * k7[i]);
+                }
+                // accept (first same as last: k1 := k7)
+                y.as_mut_data().copy_from_slice(w.as_data());
+                k1.as_mut_data().copy_from_slice(k7.as_data());
+                out.t_old = t;
+                t = if last_step { t1 } else { t + h };
+                out.t = t;
+                out.y.as_mut_data().copy_from_slice(y.as_data());
+                stats.n_accepted += 1;
+                stats.h_last = h;
+                stats.perf.measure("callback", || callback(&out))?;
+                if last_step {
+                    return Ok(stats);
+                }
+                // do not increase the step size right after a rejection
+                let fac_max = if last_rejected { 1.0 } else { self.fac_max };
+                h *= f64::min(fac_max, f64::max(self.fac_min, fac));
+                h = f64::min(h, h_max);
+                last_rejected = false;
+            } else {
+                stats.n_rejected += 1;
+                h *= f64::min(1.0, f64::max(self.fac_min, fac));
+                last_rejected = true;
+            }
+        }
+    }
+
+    /// Estimates the initial step size (Hairer et al. 1993, Section II.4)
+    #[allow(clippy::too_many_arguments)]
+    fn initial_step<F>(
+        &self,
+        f: &mut F,
+        w: &mut Vector,
+        f1: &mut Vector,
+        t: f64,
+        y: &Vector,
+        f0: &Vector,
+        stats: &mut OdeStats,
+    ) -> Result<f64, StrError>
+    where
+        F: FnMut(&mut Vector, f64, &Vector) -> Result<(), StrError>,
+    {
+        let n = y.dim();
+        if n == 0 {
+            return Ok(f64::MAX);
+        }
+        let d0 = vec_rms_scaled(y, y, self.abs_tol, self.rel_tol);
+        let d1 = vec_rms_scaled(f0, y, self.abs_tol, self.rel_tol);
+        let h0 = if d0 < 1e-5 || d1 < 1e-5 { 1e-6 } else { 0.01 * d0 / d1 };
+        for i in 0..n {
+            w[i] = y[i] + h0 * f0[i];
+        }
+        f(f1, t + h0, w)?;
+        stats.n_function_evals += 1;
+        for i in 0..n {
+            w[i] = (f1[i] - f0[i]) / h0;
+        }
+        let d2 = vec_rms_scaled(w, y, self.abs_tol, self.rel_tol);
+        let d12 = f64::max(d1, d2);
+        let h1 = if d12 <= 1e-15 {
+            f64::max(1e-6, h0 * 1e-3)
+        } else {
+            f64::powf(0.01 / d12, 0.2)
+        };
+        Ok(f64::min(100.0 * h0, h1))
+    }
+}
+
+impl Default for DormandPrince {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::DormandPrince;
+    use crate::{StrError, Vector};
+    use russell_chk::{approx_eq, vec_approx_eq};
+
+    fn oscillator(dydt: &mut Vector, _: f64, y: &Vector) -> Result<(), StrError> {
+        dydt[0] = y[1];
+        dydt[1] = -y[0];
+        Ok(())
+    }
+
+    #[test]
+    fn solve_captures_errors() {
+        let mut y = Vector::from(&[1.0, 0.0]);
+        let solver = DormandPrince::new();
+        assert_eq!(
+            solver.solve(&mut y, 1.0, 0.0, oscillator, |_| Ok(())).err(),
+            Some("t1 must be greater than t0")
+        );
+        let mut solver = DormandPrince::new();
+        solver.tolerances(0.0, 1e-6);
+        assert_eq!(
+            solver.solve(&mut y, 0.0, 1.0, oscillator, |_| Ok(())).err(),
+            Some("the tolerances must be positive")
+        );
+        let mut solver = DormandPrince::new();
+        solver.max_steps(2);
+        assert_eq!(
+            solver.solve(&mut y, 0.0, 100.0, oscillator, |_| Ok(())).err(),
+            Some("the maximum number of steps has been reached")
+        );
+        let solver = DormandPrince::new();
+        assert_eq!(
+            solver.solve(&mut y, 0.0, 1.0, oscillator, |_| Err("stop")).err(),
+            Some("stop")
+        );
+        // finite-time blow up: dy/dt = y² with y(0) = 1 => y = 1 / (1 - t)
+        let mut y = Vector::from(&[1.0]);
+        let res = solver.solve(
+            &mut y,
+            0.0,
+            2.0,
+            |dydt, _, y| {
+                dydt[0] = y[0] * y[0];
+                Ok(())
+            },
+            |_| Ok(()),
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn solve_works() {
+        let mut solver = DormandPrince::new();
+        solver.tolerances(1e-10, 1e-10);
+        let mut y = Vector::from(&[1.0, 0.0]);
+        let mut t_prev = 0.0;
+        let stats = solver
+            .solve(&mut y, 0.0, 10.0, oscillator, |out| {
+                assert_eq!(out.t_old(), t_prev);
+                t_prev = out.t();
+                vec_approx_eq(out.y().as_data(), &[f64::cos(out.t()), -f64::sin(out.t())], 1e-8);
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(t_prev, 10.0);
+        vec_approx_eq(y.as_data(), &[f64::cos(10.0), -f64::sin(10.0)], 1e-8);
+        assert_eq!(stats.n_function_evals, 2 + 6 * (stats.n_accepted + stats.n_rejected));
+        assert_eq!(
+            stats.perf.get("step").unwrap().count,
+            stats.n_accepted + stats.n_rejected
+        );
+        assert_eq!(stats.perf.get("callback").unwrap().count, stats.n_accepted);
+
+        // looser tolerances need fewer steps
+        solver.tolerances(1e-4, 1e-4);
+        let mut y = Vector::from(&[1.0, 0.0]);
+        let loose = solver.solve(&mut y, 0.0, 10.0, oscillator, |_| Ok(())).unwrap();
+        assert!(loose.n_accepted < stats.n_accepted);
+        vec_approx_eq(y.as_data(), &[f64::cos(10.0), -f64::sin(10.0)], 1e-2);
+    }
+
+    #[test]
+    fn dense_output_works() {
+        let mut solver = DormandPrince::new();
+        solver.tolerances(1e-10, 1e-10).h_ini(0.1).h_max(0.5);
+        let mut y = Vector::from(&[1.0, 0.0]);
+        let mut y_out = Vector::new(2);
+        let mut count = 0;
+        let stats = solver
+            .solve(&mut y, 0.0, 3.0, oscillator, |out| {
+                assert!(out.t() - out.t_old() <= 0.5);
+                assert_eq!(out.eval(&mut Vector::new(1), 0.0), Err("vector has wrong dimension"));
+                // the end points are reproduced exactly
+                out.eval(&mut y_out, out.t())?;
+                vec_approx_eq(y_out.as_data(), out.y().as_data(), 1e-15);
+                // intermediate points
+                for k in 1..4 {
+                    let t = out.t_old() + (k as f64) * (out.t() - out.t_old()) / 4.0;
+                    out.eval(&mut y_out, t)?;
+                    vec_approx_eq(y_out.as_data(), &[f64::cos(t), -f64::sin(t)], 1e-8);
+                    count += 1;
+                }
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(count, 3 * stats.n_accepted);
+        assert!(stats.h_last <= 0.5);
+        approx_eq(y[0], f64::cos(3.0), 1e-8);
+    }
+}
@@ -0,0 +1,10 @@
+//! This module contains solvers for systems of ordinary differential equations (ODEs)
+
+mod bdf2;
+mod dormand_prince;
+mod ode_rk4;
+mod ode_stats;
+pub use crate::ode::bdf2::*;
+pub use crate::ode::dormand_prince::*;
+pub use crate::ode::ode_rk4::*;
+pub use crate::ode::ode_stats::*;
@@ -0,0 +1,209 @@
+use super::OdeStats;
+use crate::vector::Vector;
+use crate::StrError;
+
+/// Solves a system of ODEs using the classical (fixed-step) fourth-order Runge-Kutta method
+///
+/// Integrates the system:
+///
+/// ```text
+/// dy
+/// —— = f(t, y)       with y(t0) given
+/// dt
+/// ```
+///
+/// from `t0` to `t1` using steps of size `h`. The last step is shortened to reach `t1` exactly.
+///
+/// # Input
+///
+/// * `y` -- (n) the initial state y(t0); will hold the state y(t1)
+/// * `t0` -- the initial time
+/// * `t1` -- the final time (must be greater than t0)
+/// * `h` -- the step size (must be positive)
+/// * `f` -- the function `f(dydt, t, y)` that computes `dydt := f(t, y)`
+/// * `callback` -- the function `callback(t, y)` called after each step
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::approx_eq;
+/// use russell_lab::{ode_rk4, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     // dy/dt = -y with y(0) = 1
+///     let mut y = Vector::from(&[1.0]);
+///     let mut n_steps = 0;
+///     ode_rk4(
+///         &mut y,
+///         0.0,
+///         1.0,
+///         0.01,
+///         |dydt, _, y| {
+///             dydt[0] = -y[0];
+///             Ok(())
+///         },
+///         |_, _| {
+///             n_steps += 1;
+///             Ok(())
+///         },
+///     )?;
+///     approx_eq(y[0], f64::exp(-1.0), 1e-10);
+///     assert_eq!(n_steps, 100);
+///     Ok(())
+/// }
+/// ```
+pub fn ode_rk4<F, C>(y: &mut Vector, t0: f64, t1: f64, h: f64, mut f: F, mut callback: C) -> Result<OdeStats, StrError>
+where
+    F: FnMut(&mut Vector, f64, &Vector) -> Result<(), StrError>,
+    C: FnMut(f64, &Vector) -> Result<(), StrError>,
+{
+    if t1 <= t0 {
+        return Err("t1 must be greater than t0");
+    }
+    if h <= 0.0 {
+        return Err("the step size must be positive");
+    }
+    let n = y.dim();
+    let mut k1 = Vector::new(n);
+    let mut k2 = Vector::new(n);
+    let mut k3 = Vector::new(n);
+    let mut k4 = Vector::new(n);
+    let mut w = Vector::new(n);
+    let mut stats = OdeStats::new();
+    let n_steps = f64::ceil((t1 - t0) / h - 10.0 * f64::EPSILON) as usize;
+    let mut t = t0;
+    for step in 0..n_steps {
+        let dt = if step + 1 == n_steps { t1 - t } else { h };
+        stats.perf.start("step");
+        f(&mut k1, t, y)?;
+        for i in 0..n {
+            w[i] = y[i] + dt * k1[i] / 2.0;
+        }
+        f(&mut k2, t + dt / 2.0, &w)?;
+        for i in 0..n {
+            w[i] = y[i] + dt * k2[i] / 2.0;
+        }
+        f(&mut k3, t + dt / 2.0, &w)?;
+        for i in 0..n {
+            w[i] = y[i] + dt * k3[i];
+        }
+        f(&mut k4, t + dt, &w)?;
+        for i in 0..n {
+            y[i] += dt * (k1[i] + 2.0 * k2[i] + 2.0 * k3[i] + k4[i]) / 6.0;
+        }
+        t = if step + 1 == n_steps {
+            t1
+        } else {
+            t0 + ((step + 1) as f64) * h
+        };
+        stats.n_function_evals += 4;
+        stats.n_accepted += 1;
+        stats.h_last = dt;
+        stats.perf.stop("step");
+        stats.perf.measure("callback", || callback(t, y))?;
+    }
+    Ok(stats)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::ode_rk4;
+    use crate::Vector;
+    use russell_chk::{approx_eq, vec_approx_eq};
+
+    #[test]
+    fn ode_rk4_captures_errors() {
+        let mut y = Vector::new(1);
+        let f = |_: &mut Vector, _: f64, _: &Vector| Ok(());
+        let cb = |_: f64, _: &Vector| Ok(());
+        assert_eq!(
+            ode_rk4(&mut y, 1.0, 1.0, 0.1, f, cb).err(),
+            Some("t1 must be greater than t0")
+        );
+        assert_eq!(
+            ode_rk4(&mut y, 0.0, 1.0, 0.0, f, cb).err(),
+            Some("the step size must be positive")
+        );
+        assert_eq!(
+            ode_rk4(&mut y, 0.0, 1.0, 0.1, |_, _, _| Err("stop"), cb).err(),
+            Some("stop")
+        );
+        assert_eq!(
+            ode_rk4(&mut y, 0.0, 1.0, 0.1, f, |_, _| Err("halt")).err(),
+            Some("halt")
+        );
+    }
+
+    #[test]
+    fn ode_rk4_is_exact_for_cubic_polynomials() {
+        // dy/dt = 3t² => y = t³
+        let mut y = Vector::from(&[0.0]);
+        let stats = ode_rk4(
+            &mut y,
+            0.0,
+            2.0,
+            0.3,
+            |dydt, t, _| {
+                dydt[0] = 3.0 * t * t;
+                Ok(())
+            },
+            |_, _| Ok(()),
+        )
+        .unwrap();
+        approx_eq(y[0], 8.0, 1e-14);
+        assert_eq!(stats.n_accepted, 7);
+        assert_eq!(stats.n_function_evals, 28);
+        assert_eq!(stats.perf.get("step").unwrap().count, 7);
+        assert_eq!(stats.perf.get("callback").unwrap().count, 7);
+        approx_eq(stats.h_last, 0.2, 1e-14);
+    }
+
+    #[test]
+    fn ode_rk4_works() {
+        // harmonic oscillator: y0'' = -y0 with y0(0) = 1 and y0'(0) = 0
+        let mut y = Vector::from(&[1.0, 0.0]);
+        let mut times = Vec::new();
+        ode_rk4(
+            &mut y,
+            0.0,
+            1.0,
+            0.25,
+            |dydt, _, y| {
+                dydt[0] = y[1];
+                dydt[1] = -y[0];
+                Ok(())
+            },
+            |t, _| {
+                times.push(t);
+                Ok(())
+            },
+        )
+        .unwrap();
+        assert_eq!(times, &[0.25, 0.5, 0.75, 1.0]);
+        vec_approx_eq(y.as_data(), &[f64::cos(1.0), -f64::sin(1.0)], 1e-4);
+
+        // fourth-order convergence
+        let mut errors = Vec::new();
+        for h in [0.1, 0.05] {
+            let mut y = Vector::from(&[1.0, 0.0]);
+            ode_rk4(
+                &mut y,
+                0.0,
+                1.0,
+                h,
+                |dydt, _, y| {
+                    dydt[0] = y[1];
+                    dydt[1] = -y[0];
+                    Ok(())
+                },
+                |_, _| Ok(()),
+            )
+            .unwrap();
+            errors.push(f64::abs(y[0] - f64::cos(1.0)));
+        }
+        let order = f64::log2(errors[0] / errors[1]);
+        assert!(order > 3.9 && order < 4.1);
+    }
+}
@@ -0,0 +1,54 @@
+use crate::PerfReport;
+
+/// Holds statistics of the ODE solvers
+#[derive(Clone, Debug)]
+pub struct OdeStats {
+    /// Holds the number of calls to the function f(t, y), including the ones to approximate the Jacobian
+    pub n_function_evals: usize,
+
+    /// Holds the number of accepted steps
+    pub n_accepted: usize,
+
+    /// Holds the number of rejected steps (adaptive solvers only)
+    pub n_rejected: usize,
+
+    /// Holds the last accepted step size
+    pub h_last: f64,
+
+    /// Holds the number of Jacobian evaluations (implicit solvers only)
+    pub n_jacobian_evals: usize,
+
+    /// Holds the number of factorizations of the iteration matrix (implicit solvers only)
+    pub n_factorizations: usize,
+
+    /// Holds the total number of Newton iterations (implicit solvers only)
+    pub n_newton_iterations: usize,
+
+    /// Holds the statistics of the elapsed times of the sections of the time integration
+    ///
+    /// The explicit solvers record "step" (each attempted step) and "callback". Bdf2 records
+    /// "jacobian", "factorize", "newton" (the iterations of each attempted step), and "callback".
+    pub perf: PerfReport,
+}
+
+impl OdeStats {
+    /// Allocates a new instance with zeroed counters
+    pub fn new() -> Self {
+        OdeStats {
+            n_function_evals: 0,
+            n_accepted: 0,
+            n_rejected: 0,
+            h_last: 0.0,
+            n_jacobian_evals: 0,
+            n_factorizations: 0,
+            n_newton_iterations: 0,
+            perf: PerfReport::new(),
+        }
+    }
+}
+
+impl Default for OdeStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
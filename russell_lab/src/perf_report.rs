@@ -0,0 +1,379 @@
+use super::format_nanoseconds;
+use std::collections::HashMap;
+use std::fmt::{self, Write};
+use std::time::Instant;
+
+/// Holds the aggregated statistics of the elapsed times of a named section
+#[derive(Clone, Debug, PartialEq)]
+pub struct PerfSection {
+    /// The name of the section
+    pub name: String,
+
+    /// The number of measurements
+    pub count: usize,
+
+    /// The sum of the elapsed times (nanoseconds)
+    pub total: u128,
+
+    /// The minimum elapsed time (nanoseconds)
+    pub min: u128,
+
+    /// The maximum elapsed time (nanoseconds)
+    pub max: u128,
+}
+
+impl PerfSection {
+    /// Returns the mean elapsed time (nanoseconds)
+    pub fn mean(&self) -> u128 {
+        if self.count == 0 {
+            0
+        } else {
+            self.total / (self.count as u128)
+        }
+    }
+}
+
+/// Collects the elapsed times of named sections of a computation
+///
+/// Each section may be measured many times (e.g., once per iteration). The report keeps the
+/// number of measurements and the total, minimum, maximum, and mean elapsed times of each
+/// section, in the order the sections were first recorded.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::PerfReport;
+///
+/// let mut perf = PerfReport::new();
+/// for _ in 0..3 {
+///     perf.start("assemble");
+///     // ... expensive calculation
+///     perf.stop("assemble");
+///     let sum = perf.measure("solve", || (0..100).sum::<i32>());
+///     assert_eq!(sum, 4950);
+/// }
+/// perf.record("output", 1_000);
+///
+/// assert_eq!(perf.get("assemble").unwrap().count, 3);
+/// assert_eq!(perf.get("output").unwrap().total, 1_000);
+/// println!("{}", perf);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PerfReport {
+    sections: Vec<PerfSection>,
+    index: HashMap<String, usize>,
+    running: HashMap<String, Instant>,
+}
+
+impl PerfReport {
+    /// Allocates a new (empty) report
+    pub fn new() -> Self {
+        PerfReport {
+            sections: Vec::new(),
+            index: HashMap::new(),
+            running: HashMap::new(),
+        }
+    }
+
+    /// Records an elapsed time (nanoseconds) of a section
+    pub fn record(&mut self, name: &str, elapsed: u128) {
+        match self.index.get(name) {
+            Some(i) => {
+                let section = &mut self.sections[*i];
+                section.count += 1;
+                section.total += elapsed;
+                section.min = u128::min(section.min, elapsed);
+                section.max = u128::max(section.max, elapsed);
+            }
+            None => {
+                self.index.insert(name.to_string(), self.sections.len());
+                self.sections.push(PerfSection {
+                    name: name.to_string(),
+                    count: 1,
+                    total: elapsed,
+                    min: elapsed,
+                    max: elapsed,
+                });
+            }
+        }
+    }
+
+    /// Starts measuring a section
+    ///
+    /// Calling `start` again before `stop` restarts the measurement.
+    pub fn start(&mut self, name: &str) {
+        self.running.insert(name.to_string(), Instant::now());
+    }
+
+    /// Stops measuring a section, records and returns the elapsed time (nanoseconds)
+    ///
+    /// Returns zero (and records nothing) if the section has not been started.
+    pub fn stop(&mut self, name: &str) -> u128 {
+        match self.running.remove(name) {
+            Some(initial_time) => {
+                let elapsed = Instant::now().duration_since(initial_time).as_nanos();
+                self.record(name, elapsed);
+                elapsed
+            }
+            None => 0,
+        }
+    }
+
+    /// Measures the elapsed time of a closure and returns its result
+    pub fn measure<F, R>(&mut self, name: &str, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        let initial_time = Instant::now();
+        let res = f();
+        self.record(name, Instant::now().duration_since(initial_time).as_nanos());
+        res
+    }
+
+    /// Returns the statistics of a section
+    pub fn get(&self, name: &str) -> Option<&PerfSection> {
+        self.index.get(name).map(|i| &self.sections[*i])
+    }
+
+    /// Returns the statistics of all sections (in the order they were first recorded)
+    pub fn sections(&self) -> &[PerfSection] {
+        &self.sections
+    }
+
+    /// Returns the sum of the total elapsed times of all sections (nanoseconds)
+    pub fn total(&self) -> u128 {
+        self.sections.iter().map(|s| s.total).sum()
+    }
+
+    /// Merges the sections of another report into this report
+    pub fn merge(&mut self, other: &PerfReport) {
+        for s in &other.sections {
+            match self.index.get(&s.name) {
+                Some(i) => {
+                    let section = &mut self.sections[*i];
+                    section.count += s.count;
+                    section.total += s.total;
+                    section.min = u128::min(section.min, s.min);
+                    section.max = u128::max(section.max, s.max);
+                }
+                None => {
+                    self.index.insert(s.name.clone(), self.sections.len());
+                    self.sections.push(s.clone());
+                }
+            }
+        }
+    }
+
+    /// Clears all sections
+    pub fn reset(&mut self) {
+        self.sections.clear();
+        self.index.clear();
+        self.running.clear();
+    }
+
+    /// Returns a JSON representation of the report (times in nanoseconds)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_lab::PerfReport;
+    /// let mut perf = PerfReport::new();
+    /// perf.record("solve", 3);
+    /// perf.record("solve", 5);
+    /// assert_eq!(
+    ///     perf.to_json(),
+    ///     "{\"totalNs\":8,\"sections\":[\
+    ///      {\"name\":\"solve\",\"count\":2,\"totalNs\":8,\"meanNs\":4,\"minNs\":3,\"maxNs\":5}]}"
+    /// );
+    /// ```
+    pub fn to_json(&self) -> String {
+        let mut buffer = String::new();
+        write!(&mut buffer, "{{\"totalNs\":{},\"sections\":[", self.total()).unwrap();
+        for (i, s) in self.sections.iter().enumerate() {
+            if i > 0 {
+                buffer.push(',');
+            }
+            write!(
+                &mut buffer,
+                "{{\"name\":\"{}\",\"count\":{},\"totalNs\":{},\"meanNs\":{},\"minNs\":{},\"maxNs\":{}}}",
+                escape_json(&s.name),
+                s.count,
+                s.total,
+                s.mean(),
+                s.min,
+                s.max
+            )
+            .unwrap();
+        }
+        buffer.push_str("]}");
+        buffer
+    }
+}
+
+impl Default for PerfReport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for PerfReport {
+    /// Writes a table with the statistics of each section and the percentage of the total time
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.sections.iter().map(|s| s.name.chars().count()).fold(7, usize::max);
+        let total = self.total();
+        writeln!(
+            f,
+            "{:width$} {:>7} {:>12} {:>12} {:>12} {:>12} {:>7}",
+            "section",
+            "count",
+            "total",
+            "mean",
+            "min",
+            "max",
+            "%",
+            width = width
+        )?;
+        for s in &self.sections {
+            let pct = if total > 0 {
+                100.0 * (s.total as f64) / (total as f64)
+            } else {
+                0.0
+            };
+            writeln!(
+                f,
+                "{:width$} {:>7} {:>12} {:>12} {:>12} {:>12} {:>7.2}",
+                s.name,
+                s.count,
+                format_nanoseconds(s.total),
+                format_nanoseconds(s.mean()),
+                format_nanoseconds(s.min),
+                format_nanoseconds(s.max),
+                pct,
+                width = width
+            )?;
+        }
+        write!(
+            f,
+            "{:width$} {:>7} {:>12}",
+            "total",
+            "",
+            format_nanoseconds(total),
+            width = width
+        )
+    }
+}
+
+/// Escapes the special characters of a JSON string
+fn escape_json(text: &str) -> String {
+    let mut res = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            '\t' => res.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(&mut res, "\\u{:04x}", c as u32).unwrap(),
+            c => res.push(c),
+        }
+    }
+    res
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{escape_json, PerfReport};
+    use std::thread::sleep;
+    use std::time::Duration;
+
+    #[test]
+    fn record_and_get_work() {
+        let mut perf = PerfReport::default();
+        assert_eq!(perf.get("solve"), None);
+        assert_eq!(perf.total(), 0);
+        perf.record("assemble", 10);
+        perf.record("solve", 4);
+        perf.record("solve", 8);
+        perf.record("solve", 3);
+        let solve = perf.get("solve").unwrap();
+        assert_eq!(solve.count, 3);
+        assert_eq!(solve.total, 15);
+        assert_eq!(solve.mean(), 5);
+        assert_eq!(solve.min, 3);
+        assert_eq!(solve.max, 8);
+        assert_eq!(perf.total(), 25);
+        let names: Vec<_> = perf.sections().iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, &["assemble", "solve"]);
+        perf.reset();
+        assert_eq!(perf.sections().len(), 0);
+    }
+
+    #[test]
+    fn start_stop_and_measure_work() {
+        let mut perf = PerfReport::new();
+        assert_eq!(perf.stop("not started"), 0);
+        assert_eq!(perf.get("not started"), None);
+        perf.start("sleep");
+        sleep(Duration::new(0, 1_000));
+        let elapsed = perf.stop("sleep");
+        assert!(elapsed > 0);
+        assert_eq!(perf.get("sleep").unwrap().total, elapsed);
+        let res = perf.measure("closure", || {
+            sleep(Duration::new(0, 1_000));
+            123
+        });
+        assert_eq!(res, 123);
+        assert_eq!(perf.get("closure").unwrap().count, 1);
+        assert!(perf.get("closure").unwrap().total > 0);
+    }
+
+    #[test]
+    fn merge_works() {
+        let mut a = PerfReport::new();
+        a.record("solve", 4);
+        let mut b = PerfReport::new();
+        b.record("assemble", 7);
+        b.record("solve", 2);
+        b.record("solve", 10);
+        a.merge(&b);
+        let solve = a.get("solve").unwrap();
+        assert_eq!((solve.count, solve.total, solve.min, solve.max), (3, 16, 2, 10));
+        assert_eq!(a.get("assemble").unwrap().count, 1);
+    }
+
+    #[test]
+    fn display_works() {
+        let mut perf = PerfReport::new();
+        perf.record("factorize", 3_000);
+        perf.record("solve", 500);
+        perf.record("solve", 1_500);
+        assert_eq!(
+            format!("{}", perf),
+            "section     count        total         mean          min          max       %\n\
+             factorize       1          3µs          3µs          3µs          3µs   60.00\n\
+             solve           2          2µs          1µs        500ns        1.5µs   40.00\n\
+             total                      5µs"
+        );
+    }
+
+    #[test]
+    fn to_json_works() {
+        let perf = PerfReport::new();
+        assert_eq!(perf.to_json(), "{\"totalNs\":0,\"sections\":[]}");
+        let mut perf = PerfReport::new();
+        perf.record("a \"b\"", 1);
+        perf.record("c", 2);
+        assert_eq!(
+            perf.to_json(),
+            "{\"totalNs\":3,\"sections\":[\
+             {\"name\":\"a \\\"b\\\"\",\"count\":1,\"totalNs\":1,\"meanNs\":1,\"minNs\":1,\"maxNs\":1},\
+             {\"name\":\"c\",\"count\":1,\"totalNs\":2,\"meanNs\":2,\"minNs\":2,\"maxNs\":2}]}"
+        );
+    }
+
+    #[test]
+    fn escape_json_works() {
+        assert_eq!(escape_json("a\\b\n\t\u{1}"), "a\\\\b\\n\\t\\u0001");
+    }
+}
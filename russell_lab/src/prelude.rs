@@ -7,6 +7,7 @@ pub use crate::enums::*;
 pub use crate::generators::*;
 pub use crate::matrix::*;
 pub use crate::matvec::*;
+pub use crate::perf_report::*;
 pub use crate::read_table::*;
 pub use crate::sort::*;
 pub use crate::stopwatch::*;
@@ -1,5 +1,4 @@
-use crate::StrError;
-use crate::{Matrix, Vector};
+use crate::{Matrix, SortOrder, StrError, Vector};
 
 /// Sorts (descending) the components of a vector and corresponding columns of a matrix
 ///
@@ -18,6 +17,49 @@ use crate::{Matrix, Vector};
 /// * Press WH, Teukolsky SA, Vetterling WT and Flannery BP (2007),
 ///   Numerical Recipes: The Art of Scientific Computing, 3rd Edition
 pub fn sort_vec_mat(l: &mut Vector, v: &mut Matrix) -> Result<(), StrError> {
+    sort_vec_mat_ordered(l, v, SortOrder::Descending)
+}
+
+/// Sorts the components of a vector and corresponding columns of a matrix in the given order
+///
+/// For example, this function is useful to sort the eigenvalues computed by
+/// [crate::mat_eigen_sym_jacobi] and, at the same time, rearrange the corresponding
+/// eigenvectors (columns). The sorting is stable; thus, the columns corresponding to
+/// equal keys keep their relative order.
+///
+/// # Input
+///
+/// * `l` -- e.g., vector of eigenvalues; dim = n
+/// * `v` -- e.g., matrix of eigenvectors; square, dims = (n, n)
+/// * `order` -- the sorting order
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_eigen_sym_jacobi, sort_vec_mat_ordered, Matrix, SortOrder, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut a = Matrix::from(&[
+///         [2.0, 0.0, 0.0],
+///         [0.0, -3.0, 0.0],
+///         [0.0, 0.0, 1.0],
+///     ]);
+///     let mut l = Vector::new(3);
+///     let mut v = Matrix::new(3, 3);
+///     mat_eigen_sym_jacobi(&mut l, &mut v, &mut a)?;
+///
+///     sort_vec_mat_ordered(&mut l, &mut v, SortOrder::MagnitudeDescending)?;
+///     assert_eq!(l.as_data(), &[-3.0, 2.0, 1.0]);
+///     let correct = "┌       ┐\n\
+///                    │ 0 1 0 │\n\
+///                    │ 1 0 0 │\n\
+///                    │ 0 0 1 │\n\
+///                    └       ┘";
+///     assert_eq!(format!("{}", v), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn sort_vec_mat_ordered(l: &mut Vector, v: &mut Matrix, order: SortOrder) -> Result<(), StrError> {
     let (m, n) = v.dims();
     if m != n {
         return Err("matrix must be square");
@@ -25,23 +67,23 @@ pub fn sort_vec_mat(l: &mut Vector, v: &mut Matrix) -> Result<(), StrError> {
     if l.dim() != n {
         return Err("vector must have the same dimension as matrix");
     }
-    for i in 0..(n - 1) {
-        let mut p = l[i];
-        let mut k = i;
-        for j in i..n {
-            if l[j] >= p {
-                p = l[j];
-                k = j;
-            }
-        }
-        if k != i {
-            l[k] = l[i];
-            l[i] = p;
-            for j in 0..n {
-                p = v.get(j, i);
-                v.set(j, i, v.get(j, k));
-                v.set(j, k, p);
-            }
+
+    // find the permutation
+    let mut perm: Vec<usize> = (0..n).collect();
+    match order {
+        SortOrder::Ascending => perm.sort_by(|&i, &j| l[i].total_cmp(&l[j])),
+        SortOrder::Descending => perm.sort_by(|&i, &j| l[j].total_cmp(&l[i])),
+        SortOrder::MagnitudeAscending => perm.sort_by(|&i, &j| f64::abs(l[i]).total_cmp(&f64::abs(l[j]))),
+        SortOrder::MagnitudeDescending => perm.sort_by(|&i, &j| f64::abs(l[j]).total_cmp(&f64::abs(l[i]))),
+    }
+
+    // apply the permutation
+    let l_old = l.clone();
+    let v_old = v.clone();
+    for (k, &p) in perm.iter().enumerate() {
+        l[k] = l_old[p];
+        for i in 0..n {
+            v.set(i, k, v_old.get(i, p));
         }
     }
     Ok(())
@@ -51,8 +93,8 @@ pub fn sort_vec_mat(l: &mut Vector, v: &mut Matrix) -> Result<(), StrError> {
 
 #[cfg(test)]
 mod tests {
-    use super::sort_vec_mat;
-    use crate::{mat_approx_eq, Matrix, Vector};
+    use super::{sort_vec_mat, sort_vec_mat_ordered};
+    use crate::{mat_approx_eq, Matrix, SortOrder, Vector};
     use russell_chk::vec_approx_eq;
 
     #[test]
@@ -95,4 +137,43 @@ mod tests {
         vec_approx_eq(l.as_data(), &[7.0, 4.0, 3.0, 1.0], 1e-15);
         mat_approx_eq(&v, v_correct, 1e-15);
     }
+
+    #[test]
+    fn sort_vec_mat_ordered_works() {
+        let l_original = Vector::from(&[3.0, -7.0, 1.0, -4.0]);
+        let v_original = Matrix::from(&[
+            [103.0, 107.0, 101.0, 104.0],
+            [203.0, 207.0, 201.0, 204.0],
+            [303.0, 307.0, 301.0, 304.0],
+            [403.0, 407.0, 401.0, 404.0],
+        ]);
+        for (order, l_correct, cols) in [
+            (SortOrder::Ascending, [-7.0, -4.0, 1.0, 3.0], [1, 3, 2, 0]),
+            (SortOrder::Descending, [3.0, 1.0, -4.0, -7.0], [0, 2, 3, 1]),
+            (SortOrder::MagnitudeAscending, [1.0, 3.0, -4.0, -7.0], [2, 0, 3, 1]),
+            (SortOrder::MagnitudeDescending, [-7.0, -4.0, 3.0, 1.0], [1, 3, 0, 2]),
+        ] {
+            let mut l = l_original.clone();
+            let mut v = v_original.clone();
+            sort_vec_mat_ordered(&mut l, &mut v, order).unwrap();
+            vec_approx_eq(l.as_data(), &l_correct, 1e-15);
+            for (k, &p) in cols.iter().enumerate() {
+                for i in 0..4 {
+                    assert_eq!(v.get(i, k), v_original.get(i, p));
+                }
+            }
+        }
+
+        // ties keep their relative order
+        let mut l = Vector::from(&[2.0, -2.0, 1.0]);
+        let mut v = Matrix::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        sort_vec_mat_ordered(&mut l, &mut v, SortOrder::MagnitudeDescending).unwrap();
+        vec_approx_eq(l.as_data(), &[2.0, -2.0, 1.0], 1e-15);
+        mat_approx_eq(&v, &[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]], 1e-15);
+
+        // empty
+        let mut l = Vector::new(0);
+        let mut v = Matrix::new(0, 0);
+        sort_vec_mat_ordered(&mut l, &mut v, SortOrder::Ascending).unwrap();
+    }
 }
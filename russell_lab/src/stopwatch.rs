@@ -59,10 +59,37 @@ use std::time::Instant;
 /// // println!("{:?}", elapsed_times); // will show something like:
 /// // [57148, 55991, 55299]
 /// ```
+///
+/// ## Recording lap times
+///
+/// ```
+/// use russell_lab::{format_nanoseconds, Stopwatch};
+/// use std::thread::sleep;
+/// use std::time::Duration;
+///
+/// fn expensive_calculation() {
+///     sleep(Duration::new(0, 1_000));
+/// }
+///
+/// let mut sw = Stopwatch::new("total = ");
+/// for _ in 0..3 {
+///     expensive_calculation();
+///     sw.lap();
+/// }
+/// sw.stop();
+/// assert_eq!(sw.laps().len(), 3);
+///
+/// let formatted: Vec<_> = sw.laps().iter().map(|t| format_nanoseconds(*t)).collect();
+/// // println!("{:?}", formatted); // will show something like:
+/// // ["60.262µs", "56.711µs", "55.811µs"]
+/// assert_eq!(formatted.len(), 3);
+/// ```
 pub struct Stopwatch {
     label: &'static str,
     initial_time: Instant,
     final_time: Instant,
+    lap_time: Instant,
+    laps: Vec<u128>,
 }
 
 impl Stopwatch {
@@ -89,6 +116,8 @@ impl Stopwatch {
             label,
             initial_time: now,
             final_time: now,
+            lap_time: now,
+            laps: Vec::new(),
         }
     }
 
@@ -116,7 +145,36 @@ impl Stopwatch {
         self.final_time.duration_since(self.initial_time).as_nanos()
     }
 
-    /// Resets the stopwatch to zero elapsed time
+    /// Records a lap and returns the time elapsed since the previous lap (or since the start)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_lab::Stopwatch;
+    /// use std::thread::sleep;
+    /// use std::time::Duration;
+    /// let mut sw = Stopwatch::new("");
+    /// sleep(Duration::new(0, 1_000));
+    /// let first = sw.lap();
+    /// sleep(Duration::new(0, 1_000));
+    /// let second = sw.lap();
+    /// assert!(first > 0 && second > 0);
+    /// assert_eq!(sw.laps(), &[first, second]);
+    /// ```
+    pub fn lap(&mut self) -> u128 {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.lap_time).as_nanos();
+        self.lap_time = now;
+        self.laps.push(elapsed);
+        elapsed
+    }
+
+    /// Returns the recorded lap times (in nanoseconds)
+    pub fn laps(&self) -> &[u128] {
+        &self.laps
+    }
+
+    /// Resets the stopwatch to zero elapsed time and clears the laps
     ///
     /// # Example
     ///
@@ -134,9 +192,11 @@ impl Stopwatch {
         let now = Instant::now();
         self.initial_time = now;
         self.final_time = now;
+        self.lap_time = now;
+        self.laps.clear();
     }
 
-    /// Stops the stopwatch and resets it to zero elapsed time
+    /// Stops the stopwatch and resets it to zero elapsed time (clearing the laps)
     ///
     /// # Output
     ///
@@ -162,9 +222,7 @@ impl Stopwatch {
         let elapsed = self.final_time.duration_since(self.initial_time).as_nanos();
 
         // reset
-        let now = Instant::now();
-        self.initial_time = now;
-        self.final_time = now;
+        self.reset();
         elapsed
     }
 }
@@ -229,4 +287,25 @@ mod tests {
         let delta = sw.final_time.duration_since(sw.initial_time);
         assert_eq!(delta.as_nanos(), 0);
     }
+
+    #[test]
+    fn lap_works() {
+        let mut sw = Stopwatch::new("");
+        assert_eq!(sw.laps().len(), 0);
+
+        sleep(Duration::new(0, 1_000));
+        let first = sw.lap();
+        sleep(Duration::new(0, 1_000));
+        let second = sw.lap();
+        let total = sw.stop();
+        assert!(first > 0 && second > 0);
+        assert!(first + second <= total);
+        assert_eq!(sw.laps(), &[first, second]);
+
+        sw.reset();
+        assert_eq!(sw.laps().len(), 0);
+        sw.lap();
+        sw.stop_and_reset();
+        assert_eq!(sw.laps().len(), 0);
+    }
 }
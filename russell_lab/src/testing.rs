@@ -71,12 +71,249 @@ pub(crate) fn check_eigen_general<'a, T>(
     approx_eq(complex_mat_norm(&err, Norm::Max), 0.0, tolerance);
 }
 
+/// Checks the solution of the generalized eigenproblem with complex eigenpairs
+///
+/// ```text
+/// a⋅vj = λj b⋅vj
+/// err := max |a⋅vj - λj b⋅vj| / max(1, max|a⋅vj|)
+/// ```
+///
+/// where `vj` is the column j of `v`. Use `b = None` for the standard eigenproblem (b = I).
+/// The eigenvectors are computed with complex numbers; thus, the complex conjugate pairs
+/// of real matrices are handled without special treatment.
+pub(crate) fn check_eigen_pairs(
+    a: &ComplexMatrix,
+    b: Option<&ComplexMatrix>,
+    l: &[Complex64],
+    v: &ComplexMatrix,
+    tolerance: f64,
+) {
+    let m = a.nrow();
+    if a.ncol() != m || v.dims() != (m, m) || l.len() != m {
+        panic!("dimensions of a, v, and l are incompatible");
+    }
+    if let Some(bb) = b {
+        if bb.dims() != (m, m) {
+            panic!("dimensions of a and b are incompatible");
+        }
+    }
+    let mut worst = (0, 0.0);
+    for (j, lj) in l.iter().enumerate() {
+        let mut max_a_v: f64 = 0.0;
+        let mut max_err: f64 = 0.0;
+        for i in 0..m {
+            let mut a_v = Complex64::new(0.0, 0.0);
+            let mut b_v = Complex64::new(0.0, 0.0);
+            for k in 0..m {
+                a_v += a.get(i, k) * v.get(k, j);
+                b_v += match b {
+                    Some(bb) => bb.get(i, k) * v.get(k, j),
+                    None => {
+                        if i == k {
+                            v.get(k, j)
+                        } else {
+                            Complex64::new(0.0, 0.0)
+                        }
+                    }
+                };
+            }
+            max_a_v = f64::max(max_a_v, a_v.norm());
+            max_err = f64::max(max_err, (a_v - lj * b_v).norm());
+        }
+        let v_norm = (0..m).fold(0.0, |acc, k| f64::max(acc, v.get(k, j).norm()));
+        if v_norm <= f64::EPSILON {
+            panic!("eigenvector {} cannot be zero", j);
+        }
+        let err = max_err / f64::max(1.0, max_a_v);
+        if err > worst.1 {
+            worst = (j, err);
+        }
+    }
+    if worst.1 > tolerance {
+        panic!(
+            "eigenpair {} does not satisfy a⋅v = λ b⋅v. err = {:?}",
+            worst.0, worst.1
+        );
+    }
+}
+
+/// Checks the solution of the generalized eigenproblem of real matrices with real eigenpairs
+///
+/// ```text
+/// a⋅vj = λj b⋅vj
+/// ```
+pub(crate) fn check_eigen_generalized_real<'a, 'b, T, U>(a: &'a T, b: &'b U, v: &Matrix, l: &Vector, tolerance: f64)
+where
+    T: AsArray2D<'a, f64>,
+    U: AsArray2D<'b, f64>,
+{
+    let ll: Vec<_> = l.as_data().iter().map(|x| Complex64::new(*x, 0.0)).collect();
+    let vv = complex_mat_zip(v, &Matrix::new(v.nrow(), v.ncol())).unwrap();
+    check_eigen_pairs(
+        &ComplexMatrix::from(a),
+        Some(&ComplexMatrix::from(b)),
+        &ll,
+        &vv,
+        tolerance,
+    );
+}
+
+/// Normalizes an eigenvector such that it has unit 2-norm and its largest component is real and positive
+///
+/// This normalization removes the arbitrary scaling (including the sign or the complex phase) of eigenvectors.
+/// Ties in the magnitude of the components are broken by taking the first one.
+pub(crate) fn normalize_eigenvector(v: &mut [Complex64]) {
+    let norm = f64::sqrt(v.iter().fold(0.0, |acc, x| acc + x.norm_sqr()));
+    if norm <= f64::EPSILON {
+        return;
+    }
+    let mut k = 0;
+    for (i, x) in v.iter().enumerate() {
+        if x.norm() > v[k].norm() * (1.0 + 1e-10) {
+            k = i;
+        }
+    }
+    let phase = v[k] / v[k].norm();
+    for x in v.iter_mut() {
+        *x /= phase * norm;
+    }
+}
+
+/// Checks that two sets of eigenpairs are equivalent
+///
+/// The eigenpairs are sorted by eigenvalue (real part, then imaginary part) before the comparison.
+/// Then, groups of (nearly) repeated eigenvalues, i.e., `|λi - λj| ≤ tolerance`, are identified:
+///
+/// * Simple eigenvalues: the eigenvectors are normalized by [normalize_eigenvector] and compared directly
+/// * Repeated eigenvalues: the eigenvectors are not unique; thus, the orthogonal projectors
+///   `P = Q⋅Qᴴ` onto the spanned subspaces are compared instead (Q is an orthonormal basis)
+///
+/// # Input
+///
+/// * `l`, `v` -- the eigenvalues and eigenvectors (as columns) being checked
+/// * `l_ref`, `v_ref` -- the reference eigenvalues and eigenvectors (as columns)
+pub(crate) fn check_eigen_match(
+    l: &[Complex64],
+    v: &ComplexMatrix,
+    l_ref: &[Complex64],
+    v_ref: &ComplexMatrix,
+    tolerance: f64,
+) {
+    let m = l.len();
+    if l_ref.len() != m || v.dims() != (m, m) || v_ref.dims() != (m, m) {
+        panic!("dimensions of the eigenpairs are incompatible");
+    }
+    let sorted = |l: &[Complex64]| {
+        let mut idx: Vec<usize> = (0..m).collect();
+        idx.sort_by(|&i, &j| l[i].re.total_cmp(&l[j].re).then(l[i].im.total_cmp(&l[j].im)));
+        idx
+    };
+    let idx = sorted(l);
+    let idx_ref = sorted(l_ref);
+    for k in 0..m {
+        let diff = (l[idx[k]] - l_ref[idx_ref[k]]).norm();
+        if diff > tolerance {
+            panic!(
+                "eigenvalues do not match. sorted position {}: {} != {} (diff = {:?})",
+                k, l[idx[k]], l_ref[idx_ref[k]], diff
+            );
+        }
+    }
+    let mut start = 0;
+    while start < m {
+        let mut end = start + 1;
+        while end < m && (l_ref[idx_ref[end]] - l_ref[idx_ref[end - 1]]).norm() <= tolerance {
+            end += 1;
+        }
+        if end - start == 1 {
+            let mut a = v.extract_column(idx[start]);
+            let mut b = v_ref.extract_column(idx_ref[start]);
+            normalize_eigenvector(&mut a);
+            normalize_eigenvector(&mut b);
+            let diff = a.iter().zip(&b).fold(0.0, |acc, (x, y)| f64::max(acc, (x - y).norm()));
+            if diff > tolerance {
+                panic!(
+                    "eigenvectors do not match. sorted position {} (λ = {}): diff = {:?}",
+                    start, l_ref[idx_ref[start]], diff
+                );
+            }
+        } else {
+            let p = projector(v, &idx[start..end]);
+            let p_ref = projector(v_ref, &idx_ref[start..end]);
+            let diff = p
+                .iter()
+                .zip(&p_ref)
+                .fold(0.0, |acc, (x, y)| f64::max(acc, (x - y).norm()));
+            if diff > tolerance {
+                panic!(
+                    "eigenspaces do not match. sorted positions {}..{} (λ = {}): projector diff = {:?}",
+                    start, end, l_ref[idx_ref[start]], diff
+                );
+            }
+        }
+        start = end;
+    }
+}
+
+/// Checks that two sets of real eigenpairs are equivalent (see [check_eigen_match])
+pub(crate) fn check_eigen_match_real(l: &Vector, v: &Matrix, l_ref: &Vector, v_ref: &Matrix, tolerance: f64) {
+    let to_complex = |l: &Vector| -> Vec<Complex64> { l.as_data().iter().map(|x| Complex64::new(*x, 0.0)).collect() };
+    let zeros = Matrix::new(v.nrow(), v.ncol());
+    check_eigen_match(
+        &to_complex(l),
+        &complex_mat_zip(v, &zeros).unwrap(),
+        &to_complex(l_ref),
+        &complex_mat_zip(v_ref, &zeros).unwrap(),
+        tolerance,
+    );
+}
+
+/// Computes the orthogonal projector P = Q⋅Qᴴ onto the subspace spanned by some columns of v
+///
+/// The orthonormal basis Q is computed by the modified Gram-Schmidt process.
+/// Returns the (m,m) projector as a row-major vector.
+fn projector(v: &ComplexMatrix, columns: &[usize]) -> Vec<Complex64> {
+    let m = v.nrow();
+    let mut q: Vec<Vec<Complex64>> = Vec::new();
+    for j in columns {
+        let mut u = v.extract_column(*j);
+        for qk in &q {
+            let dot = qk
+                .iter()
+                .zip(&u)
+                .fold(Complex64::new(0.0, 0.0), |acc, (a, b)| acc + a.conj() * b);
+            for (ui, qki) in u.iter_mut().zip(qk) {
+                *ui -= dot * qki;
+            }
+        }
+        let norm = f64::sqrt(u.iter().fold(0.0, |acc, x| acc + x.norm_sqr()));
+        if norm <= f64::EPSILON {
+            panic!("eigenvectors of a repeated eigenvalue must be linearly independent");
+        }
+        q.push(u.iter().map(|x| x / norm).collect());
+    }
+    let mut p = vec![Complex64::new(0.0, 0.0); m * m];
+    for qk in &q {
+        for i in 0..m {
+            for j in 0..m {
+                p[i * m + j] += qk[i] * qk[j].conj();
+            }
+        }
+    }
+    p
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::{check_eigen_general, check_eigen_real};
-    use crate::{Matrix, Vector};
+    use super::{
+        check_eigen_general, check_eigen_generalized_real, check_eigen_match, check_eigen_match_real,
+        check_eigen_pairs, check_eigen_real, normalize_eigenvector,
+    };
+    use crate::{ComplexMatrix, Matrix, Vector};
+    use num_complex::Complex64;
+    use russell_chk::approx_eq;
 
     #[test]
     #[should_panic]
@@ -147,4 +384,105 @@ mod tests {
         let v_imag = Matrix::from(&[[0.0, 0.0, 0.0], [0.5, -0.5, 0.0], [-0.5, 0.5, 0.0]]);
         check_eigen_general(data, &v_real, &l_real, &v_imag, &l_imag, 1e-15);
     }
+
+    #[test]
+    fn check_eigen_generalized_real_works() {
+        let a = &[[2.0, 0.0], [0.0, 3.0]];
+        let b = &[[1.0, 0.0], [0.0, 2.0]];
+        let v = Matrix::from(&[[0.0, 5.0], [1.0, 0.0]]);
+        let l = Vector::from(&[1.5, 2.0]);
+        check_eigen_generalized_real(a, b, &v, &l, 1e-15);
+    }
+
+    #[test]
+    #[should_panic(expected = "eigenpair 1 does not satisfy a⋅v = λ b⋅v. err = 0.25")]
+    fn check_eigen_generalized_real_panics_on_wrong_values() {
+        let a = &[[2.0, 0.0], [0.0, 3.0]];
+        let b = &[[1.0, 0.0], [0.0, 2.0]];
+        let v = Matrix::from(&[[0.0, 1.0], [1.0, 0.0]]);
+        let l = Vector::from(&[1.5, 2.5]);
+        check_eigen_generalized_real(a, b, &v, &l, 1e-15);
+    }
+
+    #[test]
+    fn check_eigen_pairs_works_with_complex_pairs() {
+        // rotation matrix: λ = ±i
+        let a = ComplexMatrix::from(&[[0.0, -1.0], [1.0, 0.0]]);
+        let i = Complex64::new(0.0, 1.0);
+        let one = Complex64::new(1.0, 0.0);
+        let l = [i, -i];
+        let v = ComplexMatrix::from(&[[one, one], [-i, i]]);
+        check_eigen_pairs(&a, None, &l, &v, 1e-15);
+    }
+
+    #[test]
+    #[should_panic(expected = "eigenvector 0 cannot be zero")]
+    fn check_eigen_pairs_panics_on_zero_vector() {
+        let a = ComplexMatrix::from(&[[1.0, 0.0], [0.0, 1.0]]);
+        let one = Complex64::new(1.0, 0.0);
+        let v = ComplexMatrix::from(&[[0.0, 1.0], [0.0, 0.0]]);
+        check_eigen_pairs(&a, None, &[one, one], &v, 1e-15);
+    }
+
+    #[test]
+    fn normalize_eigenvector_works() {
+        let i = Complex64::new(0.0, 1.0);
+        let mut v = [Complex64::new(0.0, -3.0), 4.0 * i];
+        normalize_eigenvector(&mut v);
+        approx_eq(v[0].re, -0.6, 1e-15);
+        approx_eq(v[0].im, 0.0, 1e-15);
+        approx_eq(v[1].re, 0.8, 1e-15);
+        approx_eq(v[1].im, 0.0, 1e-15);
+        let mut w = [Complex64::new(-2.0, 0.0), Complex64::new(1.0, 0.0)];
+        normalize_eigenvector(&mut w);
+        approx_eq(w[0].re, 2.0 / f64::sqrt(5.0), 1e-15);
+        approx_eq(w[1].re, -1.0 / f64::sqrt(5.0), 1e-15);
+    }
+
+    #[test]
+    fn check_eigen_match_real_handles_order_and_sign() {
+        let l = Vector::from(&[3.0, 1.0]);
+        let v = Matrix::from(&[[1.0, -1.0], [1.0, 1.0]]);
+        let l_ref = Vector::from(&[1.0, 3.0]);
+        let s = 1.0 / f64::sqrt(2.0);
+        let v_ref = Matrix::from(&[[s, -s], [-s, -s]]);
+        check_eigen_match_real(&l, &v, &l_ref, &v_ref, 1e-15);
+    }
+
+    #[test]
+    fn check_eigen_match_real_handles_repeated_eigenvalues() {
+        // the eigenvectors of λ = 2 differ, but span the same plane
+        let l = Vector::from(&[2.0, 5.0, 2.0]);
+        let v = Matrix::from(&[[1.0, 0.0, 1.0], [1.0, 0.0, -1.0], [0.0, 1.0, 0.0]]);
+        let l_ref = Vector::from(&[2.0, 2.0, 5.0]);
+        let v_ref = Matrix::from(&[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -1.0]]);
+        check_eigen_match_real(&l, &v, &l_ref, &v_ref, 1e-15);
+    }
+
+    #[test]
+    #[should_panic(expected = "eigenspaces do not match. sorted positions 0..2 (λ = 2+0i): projector diff = 1.0")]
+    fn check_eigen_match_real_panics_on_different_subspaces() {
+        let l = Vector::from(&[2.0, 2.0, 5.0]);
+        let v = Matrix::from(&[[1.0, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, 1.0, 0.0]]);
+        let v_ref = Matrix::identity(3);
+        check_eigen_match_real(&l, &v, &l, &v_ref, 1e-15);
+    }
+
+    #[test]
+    #[should_panic(expected = "eigenvalues do not match. sorted position 1: 3+0i != 4+0i (diff = 1.0)")]
+    fn check_eigen_match_panics_on_wrong_values() {
+        let one = Complex64::new(1.0, 0.0);
+        let v = ComplexMatrix::identity(2);
+        check_eigen_match(&[one, 3.0 * one], &v, &[4.0 * one, one], &v, 1e-15);
+    }
+
+    #[test]
+    #[should_panic(expected = "eigenvectors do not match. sorted position 1 (λ = 0+1i): diff = ")]
+    fn check_eigen_match_panics_on_wrong_vectors() {
+        let i = Complex64::new(0.0, 1.0);
+        let one = Complex64::new(1.0, 0.0);
+        let v = ComplexMatrix::from(&[[one, one], [-i, i]]);
+        let v_wrong = ComplexMatrix::from(&[[one, one], [i, i]]);
+        check_eigen_match(&[i, -i], &v, &[i, -i], &v_wrong, 1e-15);
+    }
 }
@@ -4,5 +4,8 @@ use num_complex::Complex64;
 /// Vector is an alias to NumVector&lt;f64&gt; and is used in most functions that call OpenBLAS
 pub type Vector = NumVector<f64>;
 
+/// VectorF32 is an alias to NumVector&lt;f32&gt; and is used in the single precision functions
+pub type VectorF32 = NumVector<f32>;
+
 /// ComplexVector is an alias to NumVector&lt;Complex64&gt; and is used in most functions that call OpenBLAS
 pub type ComplexVector = NumVector<Complex64>;
@@ -0,0 +1,79 @@
+use super::ComplexVector;
+use crate::StrError;
+use num_complex::Complex64;
+use russell_openblas::{to_i32, zaxpy};
+
+/// Updates vector based on another vector (complex version)
+///
+/// ```text
+/// v += α⋅u
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{complex_vec_axpy, ComplexVector, StrError};
+/// use num_complex::Complex64;
+///
+/// fn main() -> Result<(), StrError> {
+///     let u = ComplexVector::from(&[10.0, 20.0, 30.0]);
+///     let mut v = ComplexVector::from(&[10.0, 20.0, 30.0]);
+///     complex_vec_axpy(&mut v, Complex64::new(0.1, 1.0), &u)?;
+///     let correct = "┌        ┐\n\
+///                    │ 11+10i │\n\
+///                    │ 22+20i │\n\
+///                    │ 33+30i │\n\
+///                    └        ┘";
+///     assert_eq!(format!("{}", v), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn complex_vec_axpy(v: &mut ComplexVector, alpha: Complex64, u: &ComplexVector) -> Result<(), StrError> {
+    let n = v.dim();
+    if u.dim() != n {
+        return Err("vectors are incompatible");
+    }
+    let n_i32 = to_i32(n);
+    zaxpy(n_i32, alpha, u.as_data(), 1, v.as_mut_data(), 1);
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{complex_vec_axpy, ComplexVector};
+    use num_complex::Complex64;
+    use russell_chk::complex_vec_approx_eq;
+
+    #[test]
+    fn complex_vec_axpy_fails_on_wrong_dims() {
+        let u = ComplexVector::new(4);
+        let mut v = ComplexVector::new(3);
+        assert_eq!(
+            complex_vec_axpy(&mut v, Complex64::new(1.0, 0.0), &u),
+            Err("vectors are incompatible")
+        );
+    }
+
+    #[test]
+    fn complex_vec_axpy_works() {
+        let u = ComplexVector::from(&[
+            Complex64::new(1.0, 1.0),
+            Complex64::new(2.0, 0.0),
+            Complex64::new(0.0, -3.0),
+        ]);
+        let mut v = ComplexVector::from(&[
+            Complex64::new(10.0, 0.0),
+            Complex64::new(0.0, 20.0),
+            Complex64::new(30.0, 30.0),
+        ]);
+        complex_vec_axpy(&mut v, Complex64::new(2.0, -1.0), &u).unwrap();
+        let correct = &[
+            Complex64::new(13.0, 1.0),
+            Complex64::new(4.0, 18.0),
+            Complex64::new(27.0, 24.0),
+        ];
+        complex_vec_approx_eq(v.as_data(), correct, 1e-15);
+    }
+}
@@ -31,7 +31,7 @@ pub fn complex_vec_copy(v: &mut ComplexVector, u: &ComplexVector) -> Result<(),
     if u.dim() != n {
         return Err("vectors are incompatible");
     }
-    let n_i32: i32 = to_i32(n);
+    let n_i32 = to_i32(n);
     zcopy(n_i32, u.as_data(), 1, v.as_mut_data(), 1);
     Ok(())
 }
@@ -0,0 +1,90 @@
+use super::ComplexVector;
+use num_complex::Complex64;
+use russell_openblas::{to_i32, zdotc, zdotu};
+
+/// Performs the unconjugated dot product between two vectors resulting in a scalar value
+///
+/// ```text
+///  s := uᵀ ⋅ v = Σ_i uᵢ ⋅ vᵢ
+/// ```
+///
+/// # Note
+///
+/// The lengths of both vectors may be different; the smallest length will be selected.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{complex_vec_dot, ComplexVector};
+/// use num_complex::Complex64;
+///
+/// let u = ComplexVector::from(&[Complex64::new(1.0, 1.0), Complex64::new(0.0, 2.0)]);
+/// let v = ComplexVector::from(&[Complex64::new(1.0, -1.0), Complex64::new(0.0, 1.0), Complex64::new(5.0, 0.0)]);
+/// let s = complex_vec_dot(&u, &v);
+/// assert_eq!(s, Complex64::new(0.0, 0.0));
+/// ```
+pub fn complex_vec_dot(u: &ComplexVector, v: &ComplexVector) -> Complex64 {
+    let n = if u.dim() < v.dim() { u.dim() } else { v.dim() };
+    let n_i32 = to_i32(n);
+    zdotu(n_i32, u.as_data(), 1, v.as_data(), 1)
+}
+
+/// Performs the conjugated dot product between two vectors resulting in a scalar value
+///
+/// ```text
+///  s := uᴴ ⋅ v = Σ_i conj(uᵢ) ⋅ vᵢ
+/// ```
+///
+/// # Note
+///
+/// The first vector (u) is conjugated. Also, the lengths of both vectors may be different;
+/// the smallest length will be selected.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{complex_vec_dotc, ComplexVector};
+/// use num_complex::Complex64;
+///
+/// let u = ComplexVector::from(&[Complex64::new(1.0, 1.0), Complex64::new(0.0, 2.0)]);
+/// let s = complex_vec_dotc(&u, &u);
+/// assert_eq!(s, Complex64::new(6.0, 0.0));
+/// ```
+pub fn complex_vec_dotc(u: &ComplexVector, v: &ComplexVector) -> Complex64 {
+    let n = if u.dim() < v.dim() { u.dim() } else { v.dim() };
+    let n_i32 = to_i32(n);
+    zdotc(n_i32, u.as_data(), 1, v.as_data(), 1)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{complex_vec_dot, complex_vec_dotc, ComplexVector};
+    use num_complex::Complex64;
+
+    #[test]
+    fn complex_vec_dot_works() {
+        const IGNORED: Complex64 = Complex64::new(100000.0, 100000.0);
+        let x = ComplexVector::from(&[Complex64::new(1.0, 2.0), Complex64::new(3.0, -1.0), IGNORED]);
+        let y = ComplexVector::from(&[Complex64::new(2.0, 1.0), Complex64::new(-1.0, 4.0)]);
+        // (1+2i)(2+i) + (3-i)(-1+4i) = 5i + (1+13i)
+        assert_eq!(complex_vec_dot(&x, &y), Complex64::new(1.0, 18.0));
+        assert_eq!(complex_vec_dot(&y, &x), Complex64::new(1.0, 18.0));
+        let empty = ComplexVector::new(0);
+        assert_eq!(complex_vec_dot(&empty, &x), Complex64::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn complex_vec_dotc_works() {
+        const IGNORED: Complex64 = Complex64::new(100000.0, 100000.0);
+        let x = ComplexVector::from(&[Complex64::new(1.0, 2.0), Complex64::new(3.0, -1.0), IGNORED]);
+        let y = ComplexVector::from(&[Complex64::new(2.0, 1.0), Complex64::new(-1.0, 4.0)]);
+        // (1-2i)(2+i) + (3+i)(-1+4i) = (4-3i) + (-7+11i)
+        assert_eq!(complex_vec_dotc(&x, &y), Complex64::new(-3.0, 8.0));
+        // conj(x ⋅ y) = y ⋅ x
+        assert_eq!(complex_vec_dotc(&y, &x), Complex64::new(-3.0, -8.0));
+        // x ⋅ x = ‖x‖²
+        assert_eq!(complex_vec_dotc(&y, &y), Complex64::new(22.0, 0.0));
+    }
+}
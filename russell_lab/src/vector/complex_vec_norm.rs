@@ -0,0 +1,73 @@
+use super::ComplexVector;
+use crate::Norm;
+use russell_openblas::{dznrm2, to_i32};
+
+/// Returns the vector norm (complex version)
+///
+/// The norms are computed with the moduli of the components; e.g., `‖u‖₁ = Σ_i |uᵢ|`.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{complex_vec_norm, ComplexVector, Norm};
+/// use num_complex::Complex64;
+///
+/// fn main() {
+///     let u = ComplexVector::from(&[
+///         Complex64::new(3.0, 4.0),
+///         Complex64::new(0.0, 5.0),
+///         Complex64::new(-5.0, 0.0),
+///         Complex64::new(4.0, -3.0),
+///     ]);
+///     assert_eq!(complex_vec_norm(&u, Norm::One), 20.0);
+///     assert_eq!(complex_vec_norm(&u, Norm::Euc), 10.0);
+///     assert_eq!(complex_vec_norm(&u, Norm::Max), 5.0);
+///     assert_eq!(complex_vec_norm(&u, Norm::P(1.0)), 20.0);
+///     assert_eq!(complex_vec_norm(&u, Norm::Rms), 5.0);
+/// }
+/// ```
+pub fn complex_vec_norm(v: &ComplexVector, kind: Norm) -> f64 {
+    let n = to_i32(v.dim());
+    if n == 0 {
+        return 0.0;
+    }
+    match kind {
+        Norm::Euc | Norm::Fro => dznrm2(n, v.as_data(), 1),
+        Norm::Inf | Norm::Max => v.as_data().iter().fold(0.0, |acc, x| f64::max(acc, x.norm())),
+        Norm::One => v.as_data().iter().map(|x| x.norm()).sum(),
+        Norm::P(p) => {
+            let sum: f64 = v.as_data().iter().map(|x| f64::powf(x.norm(), p)).sum();
+            f64::powf(sum, 1.0 / p)
+        }
+        Norm::Rms => dznrm2(n, v.as_data(), 1) / f64::sqrt(v.dim() as f64),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{complex_vec_norm, ComplexVector};
+    use crate::Norm;
+    use num_complex::Complex64;
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn complex_vec_norm_works() {
+        let u = ComplexVector::new(0);
+        assert_eq!(complex_vec_norm(&u, Norm::Euc), 0.0);
+        let u = ComplexVector::from(&[
+            Complex64::new(0.0, -1.0),
+            Complex64::new(-3.0, 4.0),
+            Complex64::new(2.0, 0.0),
+            Complex64::new(-2.0, -2.0),
+        ]);
+        approx_eq(complex_vec_norm(&u, Norm::One), 8.0 + f64::sqrt(8.0), 1e-15);
+        approx_eq(complex_vec_norm(&u, Norm::Euc), f64::sqrt(38.0), 1e-15);
+        approx_eq(complex_vec_norm(&u, Norm::Fro), f64::sqrt(38.0), 1e-15);
+        assert_eq!(complex_vec_norm(&u, Norm::Inf), 5.0);
+        assert_eq!(complex_vec_norm(&u, Norm::Max), 5.0);
+        approx_eq(complex_vec_norm(&u, Norm::P(2.0)), f64::sqrt(38.0), 1e-14);
+        approx_eq(complex_vec_norm(&u, Norm::Rms), f64::sqrt(38.0 / 4.0), 1e-15);
+    }
+}
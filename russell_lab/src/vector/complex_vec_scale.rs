@@ -0,0 +1,56 @@
+use super::ComplexVector;
+use num_complex::Complex64;
+use russell_openblas::{to_i32, zscal};
+
+/// Scales vector (complex version)
+///
+/// ```text
+/// u := alpha * u
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{complex_vec_scale, ComplexVector};
+/// use num_complex::Complex64;
+///
+/// fn main() {
+///     let mut u = ComplexVector::from(&[1.0, 2.0, 3.0]);
+///     complex_vec_scale(&mut u, Complex64::new(0.5, 1.0));
+///     let correct = "┌        ┐\n\
+///                    │ 0.5+1i │\n\
+///                    │   1+2i │\n\
+///                    │ 1.5+3i │\n\
+///                    └        ┘";
+///     assert_eq!(format!("{}", u), correct);
+/// }
+/// ```
+pub fn complex_vec_scale(v: &mut ComplexVector, alpha: Complex64) {
+    let n_i32 = to_i32(v.dim());
+    zscal(n_i32, alpha, v.as_mut_data(), 1);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{complex_vec_scale, ComplexVector};
+    use num_complex::Complex64;
+    use russell_chk::complex_vec_approx_eq;
+
+    #[test]
+    fn complex_vec_scale_works() {
+        let mut u = ComplexVector::from(&[
+            Complex64::new(6.0, 3.0),
+            Complex64::new(9.0, 0.0),
+            Complex64::new(0.0, 12.0),
+        ]);
+        complex_vec_scale(&mut u, Complex64::new(0.0, 1.0 / 3.0));
+        let correct = &[
+            Complex64::new(-1.0, 2.0),
+            Complex64::new(0.0, 3.0),
+            Complex64::new(-4.0, 0.0),
+        ];
+        complex_vec_approx_eq(u.as_data(), correct, 1e-15);
+    }
+}
@@ -37,11 +37,58 @@ pub fn complex_vec_zip(real: &Vector, imag: &Vector) -> Result<ComplexVector, St
     Ok(v)
 }
 
+/// Unzips a ComplexVector into two new arrays (real and imag)
+///
+/// This function is the inverse of [complex_vec_zip].
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use russell_lab::{complex_vec_unzip, ComplexVector};
+///
+/// let v = ComplexVector::from(&[Complex64::new(1.0, 0.1), Complex64::new(2.0, -0.2)]);
+/// let (real, imag) = complex_vec_unzip(&v);
+/// assert_eq!(real.as_data(), &[1.0, 2.0]);
+/// assert_eq!(imag.as_data(), &[0.1, -0.2]);
+/// ```
+pub fn complex_vec_unzip(v: &ComplexVector) -> (Vector, Vector) {
+    let n = v.dim();
+    let mut real = Vector::new(n);
+    let mut imag = Vector::new(n);
+    for i in 0..n {
+        real[i] = v[i].re;
+        imag[i] = v[i].im;
+    }
+    (real, imag)
+}
+
+/// Converts a real vector into a ComplexVector with zero imaginary parts
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use russell_lab::{complex_vec_from_real, Vector};
+///
+/// let u = Vector::from(&[1.0, 2.0]);
+/// let v = complex_vec_from_real(&u);
+/// assert_eq!(v.as_data(), &[Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0)]);
+/// ```
+pub fn complex_vec_from_real(u: &Vector) -> ComplexVector {
+    let n = u.dim();
+    let mut v = ComplexVector::new(n);
+    for i in 0..n {
+        v[i].re = u[i];
+    }
+    v
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::complex_vec_zip;
+    use super::{complex_vec_from_real, complex_vec_unzip, complex_vec_zip};
     use crate::Vector;
 
     #[test]
@@ -66,4 +113,18 @@ mod tests {
              └      ┘"
         );
     }
+
+    #[test]
+    fn complex_vec_unzip_and_from_real_work() {
+        let real = Vector::from(&[1.0, 2.0, 3.0]);
+        let imag = Vector::from(&[4.0, 5.0, 6.0]);
+        let v = complex_vec_zip(&real, &imag).unwrap();
+        let (re, im) = complex_vec_unzip(&v);
+        assert_eq!(re.as_data(), real.as_data());
+        assert_eq!(im.as_data(), imag.as_data());
+        let w = complex_vec_from_real(&real);
+        let (re, im) = complex_vec_unzip(&w);
+        assert_eq!(re.as_data(), real.as_data());
+        assert_eq!(im.as_data(), &[0.0, 0.0, 0.0]);
+    }
 }
@@ -2,29 +2,43 @@
 
 mod aliases;
 mod complex_vec_add;
+mod complex_vec_axpy;
 mod complex_vec_copy;
+mod complex_vec_dot;
+mod complex_vec_norm;
+mod complex_vec_scale;
 mod complex_vec_zip;
 mod num_vector;
 mod vec_add;
 mod vec_copy;
+mod vec_cross;
+mod vec_hadamard;
 mod vec_inner;
 mod vec_max_abs_diff;
 mod vec_max_scaled;
 mod vec_norm;
 mod vec_rms_scaled;
 mod vec_scale;
+mod vec_stats;
 mod vec_update;
 pub use crate::vector::aliases::*;
 pub use crate::vector::complex_vec_add::*;
+pub use crate::vector::complex_vec_axpy::*;
 pub use crate::vector::complex_vec_copy::*;
+pub use crate::vector::complex_vec_dot::*;
+pub use crate::vector::complex_vec_norm::*;
+pub use crate::vector::complex_vec_scale::*;
 pub use crate::vector::complex_vec_zip::*;
 pub use crate::vector::num_vector::*;
 pub use crate::vector::vec_add::*;
 pub use crate::vector::vec_copy::*;
+pub use crate::vector::vec_cross::*;
+pub use crate::vector::vec_hadamard::*;
 pub use crate::vector::vec_inner::*;
 pub use crate::vector::vec_max_abs_diff::*;
 pub use crate::vector::vec_max_scaled::*;
 pub use crate::vector::vec_norm::*;
 pub use crate::vector::vec_rms_scaled::*;
 pub use crate::vector::vec_scale::*;
+pub use crate::vector::vec_stats::*;
 pub use crate::vector::vec_update::*;
@@ -1,5 +1,6 @@
-use crate::{AsArray1D, StrError};
+use crate::{AsArray1D, DisplayOpts, StrError};
 use num_traits::{cast, Num, NumCast};
+use russell_chk::TestDataGenerator;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::cmp;
@@ -321,6 +322,39 @@ where
         self.data.iter_mut().map(|x| *x = value).count();
     }
 
+    /// Changes the dimension of this vector, keeping the existing components
+    ///
+    /// If the new dimension is greater than the current one, the new components are set to `value`;
+    /// otherwise, the vector is truncated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::NumVector;
+    /// let mut u = NumVector::<f64>::from(&[1.0, 2.0]);
+    /// u.resize(3, 8.8);
+    /// assert_eq!(u.as_data(), &[1.0, 2.0, 8.8]);
+    /// u.resize(1, 0.0);
+    /// assert_eq!(u.as_data(), &[1.0]);
+    /// ```
+    pub fn resize(&mut self, dim: usize, value: T) {
+        self.data.resize(dim, value);
+    }
+
+    /// Appends components to the end of this vector
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::NumVector;
+    /// let mut u = NumVector::<f64>::from(&[1.0, 2.0]);
+    /// u.append(&[3.0, 4.0]);
+    /// assert_eq!(u.as_data(), &[1.0, 2.0, 3.0, 4.0]);
+    /// ```
+    pub fn append(&mut self, values: &[T]) {
+        self.data.extend_from_slice(values);
+    }
+
     /// Returns an access to the underlying data
     ///
     /// # Example
@@ -351,6 +385,36 @@ where
         &mut self.data
     }
 
+    /// Returns an iterator over the components
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::NumVector;
+    /// let u = NumVector::<f64>::from(&[1.0, 2.0, 3.0]);
+    /// let sum: f64 = u.iter().sum();
+    /// assert_eq!(sum, 6.0);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Returns a mutable iterator over the components
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::NumVector;
+    /// let mut u = NumVector::<f64>::from(&[1.0, 2.0, 3.0]);
+    /// u.iter_mut().for_each(|x| *x *= 2.0);
+    /// assert_eq!(u.as_data(), &[2.0, 4.0, 6.0]);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
     /// Returns the i-th component
     ///
     /// # Example
@@ -486,6 +550,113 @@ where
         }
         NumVector { data }
     }
+
+    /// Returns a structure to display this vector with options such as precision and truncation
+    ///
+    /// See [DisplayOpts] for the available options; the structure implements [fmt::Display].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::NumVector;
+    /// let u = NumVector::<f64>::from(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+    /// assert_eq!(
+    ///     format!("{}", u.display_opts().precision(2).max_rows(2)),
+    ///     "┌      ┐\n\
+    ///      │ 1.00 │\n\
+    ///      │    ⋮ │\n\
+    ///      │ 5.00 │\n\
+    ///      └      ┘"
+    /// );
+    /// ```
+    pub fn display_opts(&self) -> DisplayOpts<'_, T> {
+        DisplayOpts::new(self.data.len(), 1, Box::new(move |i, _| self.data[i]))
+    }
+}
+
+impl NumVector<f64> {
+    /// Creates new vector with pseudo-random components uniformly distributed in [lo, hi)
+    ///
+    /// The same seed yields exactly the same vector on every platform.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::Vector;
+    /// let u = Vector::random(4, 0.0, 10.0, 1234);
+    /// assert_eq!(u.dim(), 4);
+    /// assert!(u.as_data().iter().all(|x| *x >= 0.0 && *x < 10.0));
+    /// assert_eq!(Vector::random(4, 0.0, 10.0, 1234).as_data(), u.as_data());
+    /// ```
+    pub fn random(n: usize, lo: f64, hi: f64, seed: u64) -> Self {
+        let mut gen = TestDataGenerator::new(seed);
+        NumVector {
+            data: gen.vector(n, lo, hi),
+        }
+    }
+
+    /// Returns true if the components of two vectors differ by at most `tol` (absolute difference)
+    ///
+    /// Unlike [russell_chk::vec_approx_eq], this function does not panic. It returns false if
+    /// the dimensions differ or any difference is not a number (NaN).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::Vector;
+    /// let u = Vector::from(&[1.0, 2.0]);
+    /// assert!(u.approx_eq(&Vector::from(&[1.0, 2.0 + 1e-10]), 1e-8));
+    /// assert!(!u.approx_eq(&Vector::from(&[1.0, 2.1]), 1e-8));
+    /// assert!(!u.approx_eq(&Vector::from(&[1.0]), 1e-8));
+    /// ```
+    pub fn approx_eq(&self, other: &Self, tol: f64) -> bool {
+        if self.data.len() != other.data.len() {
+            return false;
+        }
+        self.data
+            .iter()
+            .zip(other.data.iter())
+            .all(|(a, b)| f64::abs(a - b) <= tol)
+    }
+
+    /// Finds the maximum absolute difference between the components of two vectors
+    ///
+    /// Returns `(i, max_abs_diff)` or an error if the dimensions differ. See [crate::vec_max_abs_diff].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use russell_lab::Vector;
+    /// let u = Vector::from(&[1.0, 2.0, 3.0]);
+    /// let v = Vector::from(&[1.0, 2.5, 3.1]);
+    /// let (i, diff) = u.max_abs_diff(&v).unwrap();
+    /// assert_eq!(i, 1);
+    /// assert_eq!(diff, 0.5);
+    /// ```
+    pub fn max_abs_diff(&self, other: &Self) -> Result<(usize, f64), StrError> {
+        crate::vec_max_abs_diff(self, other)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T> NumVector<T>
+where
+    T: Num + NumCast + Copy + DeserializeOwned + Serialize + Send + Sync,
+{
+    /// Applies a function over all components of this vector in parallel
+    ///
+    /// ```text
+    /// u := map(function(ui))
+    /// ```
+    ///
+    /// **Note:** This function is only available with the `rayon` feature.
+    pub fn par_map<F>(&mut self, function: F)
+    where
+        F: Fn(T) -> T + Send + Sync,
+    {
+        use rayon::prelude::*;
+        self.data.par_iter_mut().for_each(|elem| *elem = function(*elem));
+    }
 }
 
 impl<T> fmt::Display for NumVector<T>
@@ -673,6 +844,26 @@ where
     }
 }
 
+/// Allows collecting an iterator into a NumVector
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::NumVector;
+/// let u: NumVector<f64> = (1..4).map(|i| (i * i) as f64).collect();
+/// assert_eq!(u.as_data(), &[1.0, 4.0, 9.0]);
+/// ```
+impl<T> FromIterator<T> for NumVector<T>
+where
+    T: Num + NumCast + Copy + DeserializeOwned + Serialize,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        NumVector {
+            data: iter.into_iter().collect(),
+        }
+    }
+}
+
 /// Allows accessing NumVector as an Array1D
 impl<'a, T: 'a> AsArray1D<'a, T> for NumVector<T>
 where
@@ -802,6 +993,19 @@ mod tests {
         assert_eq!(u.data, correct);
     }
 
+    #[test]
+    fn resize_and_append_work() {
+        let mut u = NumVector::<f64>::new(0);
+        u.resize(2, 1.5);
+        assert_eq!(u.data, &[1.5, 1.5]);
+        u.append(&[2.0, 3.0]);
+        assert_eq!(u.data, &[1.5, 1.5, 2.0, 3.0]);
+        u.resize(3, 0.0);
+        assert_eq!(u.data, &[1.5, 1.5, 2.0]);
+        u.append(&[]);
+        assert_eq!(u.dim(), 3);
+    }
+
     #[test]
     fn as_data_works() {
         let u = NumVector::<f64>::from(&[1.0, 2.0, 3.0]);
@@ -845,6 +1049,47 @@ mod tests {
         assert_eq!(u.data, &[-1.0, -2.0]);
     }
 
+    #[test]
+    fn random_works() {
+        let u = NumVector::<f64>::random(5, -3.0, -1.0, 42);
+        assert_eq!(u.dim(), 5);
+        assert!(u.data.iter().all(|x| *x >= -3.0 && *x < -1.0));
+        assert_eq!(NumVector::<f64>::random(5, -3.0, -1.0, 42).data, u.data);
+        assert_ne!(NumVector::<f64>::random(5, -3.0, -1.0, 43).data, u.data);
+    }
+
+    #[test]
+    fn approx_eq_and_max_abs_diff_work() {
+        let u = NumVector::<f64>::from(&[1.0, -2.0, 3.0]);
+        let v = NumVector::<f64>::from(&[1.0, -2.001, 3.0]);
+        assert!(u.approx_eq(&u, 0.0));
+        assert!(u.approx_eq(&v, 1e-2));
+        assert!(!u.approx_eq(&v, 1e-4));
+        assert!(!u.approx_eq(&NumVector::<f64>::new(2), 1e10));
+        assert!(!u.approx_eq(&NumVector::<f64>::from(&[1.0, f64::NAN, 3.0]), 1e10));
+        let (i, diff) = u.max_abs_diff(&v).unwrap();
+        assert_eq!(i, 1);
+        assert!(f64::abs(diff - 0.001) < 1e-15);
+        assert_eq!(
+            u.max_abs_diff(&NumVector::<f64>::new(2)).err(),
+            Some("vectors are incompatible")
+        );
+    }
+
+    #[test]
+    fn iter_and_from_iter_work() {
+        let mut u = NumVector::<f64>::from(&[1.0, 2.0, 3.0]);
+        assert_eq!(u.iter().copied().collect::<Vec<_>>(), &[1.0, 2.0, 3.0]);
+        for x in u.iter_mut() {
+            *x = -*x;
+        }
+        assert_eq!(u.data, &[-1.0, -2.0, -3.0]);
+        let v: NumVector<f64> = u.iter().map(|x| x * 10.0).collect();
+        assert_eq!(v.data, &[-10.0, -20.0, -30.0]);
+        let w: NumVector<f64> = std::iter::empty().collect();
+        assert_eq!(w.dim(), 0);
+    }
+
     #[test]
     fn map_works() {
         let mut u = NumVector::<f64>::from(&[-1.0, -2.0, -3.0]);
@@ -872,6 +1117,14 @@ mod tests {
         assert_eq!(v.data, &[1.0, 4.0, 9.0]);
     }
 
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_map_works() {
+        let mut u = NumVector::<f64>::from(&[-1.0, -2.0, -3.0]);
+        u.par_map(pow3);
+        assert_eq!(u.data, &[-1.0, -8.0, -27.0]);
+    }
+
     #[test]
     fn display_works() {
         let x0 = NumVector::<f64>::new(0);
@@ -31,16 +31,77 @@ pub fn vec_copy(v: &mut Vector, u: &Vector) -> Result<(), StrError> {
     if u.dim() != n {
         return Err("vectors are incompatible");
     }
-    let n_i32: i32 = to_i32(n);
+    let n_i32 = to_i32(n);
     dcopy(n_i32, u.as_data(), 1, v.as_mut_data(), 1);
     Ok(())
 }
 
+/// Copies a strided sequence of components into another strided sequence
+///
+/// ```text
+/// v[i⋅inc_v] := u[i⋅inc_u]    for i in 0..n
+/// ```
+///
+/// The strides allow operating directly on non-contiguous data; e.g., the row `r` of a column-major
+/// (m,n) matrix `a` corresponds to `&a.as_data()[r..]` with stride `m`.
+///
+/// # Input
+///
+/// * `n` -- number of components to be copied
+/// * `v` -- the destination slice with at least `(n-1)⋅inc_v + 1` components
+/// * `inc_v` -- the stride of `v` (> 0)
+/// * `u` -- the source slice with at least `(n-1)⋅inc_u + 1` components
+/// * `inc_u` -- the stride of `u` (> 0)
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_copy_strided, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // copy the first row of a into the last row of b
+///     let a = Matrix::from(&[
+///         [1.0, 2.0, 3.0],
+///         [4.0, 5.0, 6.0],
+///     ]);
+///     let mut b = Matrix::new(3, 3);
+///     vec_copy_strided(3, &mut b.as_mut_data()[2..], 3, a.as_data(), 2)?;
+///     let correct = "┌       ┐\n\
+///                    │ 0 0 0 │\n\
+///                    │ 0 0 0 │\n\
+///                    │ 1 2 3 │\n\
+///                    └       ┘";
+///     assert_eq!(format!("{}", b), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn vec_copy_strided(n: usize, v: &mut [f64], inc_v: usize, u: &[f64], inc_u: usize) -> Result<(), StrError> {
+    check_strided(n, v.len(), inc_v)?;
+    check_strided(n, u.len(), inc_u)?;
+    if n == 0 {
+        return Ok(());
+    }
+    dcopy(to_i32(n), u, to_i32(inc_u), v, to_i32(inc_v));
+    Ok(())
+}
+
+/// Checks whether a slice with the given length holds n components with the given stride
+pub(crate) fn check_strided(n: usize, len: usize, inc: usize) -> Result<(), StrError> {
+    if inc == 0 {
+        return Err("the stride must be greater than zero");
+    }
+    if n > 0 && (n - 1) * inc + 1 > len {
+        return Err("the slice is too short for the number of components and stride");
+    }
+    Ok(())
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::{vec_copy, Vector};
+    use super::{check_strided, vec_copy, vec_copy_strided, Vector};
+    use crate::Matrix;
     use russell_chk::vec_approx_eq;
 
     #[test]
@@ -58,4 +119,31 @@ mod tests {
         let correct = &[1.0, 2.0, 3.0];
         vec_approx_eq(v.as_data(), correct, 1e-15);
     }
+
+    #[test]
+    fn check_strided_works() {
+        assert_eq!(check_strided(2, 3, 0), Err("the stride must be greater than zero"));
+        assert_eq!(
+            check_strided(3, 4, 2),
+            Err("the slice is too short for the number of components and stride")
+        );
+        assert_eq!(check_strided(3, 5, 2), Ok(()));
+        assert_eq!(check_strided(0, 0, 3), Ok(()));
+    }
+
+    #[test]
+    fn vec_copy_strided_works() {
+        let a = Matrix::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let mut u = Vector::new(3);
+        assert_eq!(
+            vec_copy_strided(3, u.as_mut_data(), 1, &a.as_data()[1..], 3),
+            Err("the slice is too short for the number of components and stride")
+        );
+        vec_copy_strided(3, u.as_mut_data(), 1, &a.as_data()[1..], 2).unwrap();
+        assert_eq!(u.as_data(), &[4.0, 5.0, 6.0]);
+        let mut b = Matrix::new(2, 3);
+        vec_copy_strided(3, b.as_mut_data(), 2, u.as_data(), 1).unwrap();
+        assert_eq!(b.as_data(), &[4.0, 0.0, 5.0, 0.0, 6.0, 0.0]);
+        vec_copy_strided(0, &mut [], 1, &[], 1).unwrap();
+    }
 }
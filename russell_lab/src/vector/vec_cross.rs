@@ -0,0 +1,162 @@
+use super::{vec_inner, vec_norm, Vector};
+use crate::{Norm, StrError};
+
+/// Performs the cross product between two 3D vectors
+///
+/// ```text
+/// w := u × v
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_cross, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let u = Vector::from(&[1.0, 0.0, 0.0]);
+///     let v = Vector::from(&[0.0, 1.0, 0.0]);
+///     let mut w = Vector::new(3);
+///     vec_cross(&mut w, &u, &v)?;
+///     assert_eq!(w.as_data(), &[0.0, 0.0, 1.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn vec_cross(w: &mut Vector, u: &Vector, v: &Vector) -> Result<(), StrError> {
+    if w.dim() != 3 || u.dim() != 3 || v.dim() != 3 {
+        return Err("vectors must have dimension 3");
+    }
+    w[0] = u[1] * v[2] - u[2] * v[1];
+    w[1] = u[2] * v[0] - u[0] * v[2];
+    w[2] = u[0] * v[1] - u[1] * v[0];
+    Ok(())
+}
+
+/// Computes the scalar triple product of three 3D vectors
+///
+/// ```text
+/// s := u · (v × w)
+/// ```
+///
+/// The result equals the (signed) volume of the parallelepiped spanned by the three vectors.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_triple_product, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let u = Vector::from(&[2.0, 0.0, 0.0]);
+///     let v = Vector::from(&[0.0, 3.0, 0.0]);
+///     let w = Vector::from(&[0.0, 0.0, 4.0]);
+///     assert_eq!(vec_triple_product(&u, &v, &w)?, 24.0);
+///     Ok(())
+/// }
+/// ```
+pub fn vec_triple_product(u: &Vector, v: &Vector, w: &Vector) -> Result<f64, StrError> {
+    if u.dim() != 3 || v.dim() != 3 || w.dim() != 3 {
+        return Err("vectors must have dimension 3");
+    }
+    Ok(u[0] * (v[1] * w[2] - v[2] * w[1]) + u[1] * (v[2] * w[0] - v[0] * w[2]) + u[2] * (v[0] * w[1] - v[1] * w[0]))
+}
+
+/// Returns the angle (in radians) between two vectors
+///
+/// ```text
+/// θ := acos(u · v / (‖u‖ ‖v‖))    with 0 ≤ θ ≤ π
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::approx_eq;
+/// use russell_lab::{vec_angle_between, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let u = Vector::from(&[1.0, 0.0]);
+///     let v = Vector::from(&[1.0, 1.0]);
+///     approx_eq(vec_angle_between(&u, &v)?, std::f64::consts::PI / 4.0, 1e-15);
+///     Ok(())
+/// }
+/// ```
+pub fn vec_angle_between(u: &Vector, v: &Vector) -> Result<f64, StrError> {
+    if u.dim() != v.dim() {
+        return Err("vectors are incompatible");
+    }
+    let den = vec_norm(u, Norm::Euc) * vec_norm(v, Norm::Euc);
+    if den == 0.0 {
+        return Err("cannot compute the angle with a zero vector");
+    }
+    // clamp to avoid NaN due to round-off errors
+    let cos = f64::clamp(vec_inner(u, v) / den, -1.0, 1.0);
+    Ok(f64::acos(cos))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{vec_angle_between, vec_cross, vec_triple_product, Vector};
+    use russell_chk::{approx_eq, vec_approx_eq};
+    use std::f64::consts::PI;
+
+    #[test]
+    fn vec_cross_fails_on_wrong_dims() {
+        let u = Vector::new(3);
+        let v = Vector::new(3);
+        let u_wrong = Vector::new(2);
+        let mut w = Vector::new(3);
+        let mut w_wrong = Vector::new(4);
+        assert_eq!(vec_cross(&mut w_wrong, &u, &v), Err("vectors must have dimension 3"));
+        assert_eq!(vec_cross(&mut w, &u_wrong, &v), Err("vectors must have dimension 3"));
+        assert_eq!(vec_cross(&mut w, &u, &u_wrong), Err("vectors must have dimension 3"));
+        assert_eq!(
+            vec_triple_product(&u_wrong, &u, &v),
+            Err("vectors must have dimension 3")
+        );
+        assert_eq!(vec_angle_between(&u_wrong, &v), Err("vectors are incompatible"));
+        assert_eq!(
+            vec_angle_between(&u, &Vector::from(&[1.0, 0.0, 0.0])),
+            Err("cannot compute the angle with a zero vector")
+        );
+    }
+
+    #[test]
+    fn vec_cross_works() {
+        let u = Vector::from(&[1.0, 2.0, 3.0]);
+        let v = Vector::from(&[4.0, 5.0, 6.0]);
+        let mut w = Vector::new(3);
+        vec_cross(&mut w, &u, &v).unwrap();
+        assert_eq!(w.as_data(), &[-3.0, 6.0, -3.0]);
+        vec_cross(&mut w, &v, &u).unwrap();
+        assert_eq!(w.as_data(), &[3.0, -6.0, 3.0]);
+        vec_cross(&mut w, &u, &u).unwrap();
+        assert_eq!(w.as_data(), &[0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn vec_triple_product_works() {
+        let u = Vector::from(&[1.0, 2.0, 3.0]);
+        let v = Vector::from(&[4.0, 5.0, 6.0]);
+        let w = Vector::from(&[7.0, 8.0, 10.0]);
+        assert_eq!(vec_triple_product(&u, &v, &w).unwrap(), -3.0);
+        assert_eq!(vec_triple_product(&v, &w, &u).unwrap(), -3.0);
+        assert_eq!(vec_triple_product(&u, &w, &v).unwrap(), 3.0);
+        assert_eq!(vec_triple_product(&u, &v, &u).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn vec_angle_between_works() {
+        let u = Vector::from(&[1.0, 0.0, 0.0]);
+        let v = Vector::from(&[0.0, 2.0, 0.0]);
+        approx_eq(vec_angle_between(&u, &v).unwrap(), PI / 2.0, 1e-15);
+        approx_eq(vec_angle_between(&u, &u).unwrap(), 0.0, 1e-15);
+        let minus_u = Vector::from(&[-3.0, 0.0, 0.0]);
+        approx_eq(vec_angle_between(&u, &minus_u).unwrap(), PI, 1e-15);
+        let w = Vector::from(&[1.0, 1.0, 0.0]);
+        let mut n = Vector::new(3);
+        vec_cross(&mut n, &u, &w).unwrap();
+        vec_approx_eq(n.as_data(), &[0.0, 0.0, 1.0], 1e-15);
+        approx_eq(vec_angle_between(&u, &w).unwrap(), PI / 4.0, 1e-15);
+        approx_eq(vec_angle_between(&n, &w).unwrap(), PI / 2.0, 1e-15);
+    }
+}
@@ -0,0 +1,131 @@
+use super::Vector;
+use crate::StrError;
+
+/// Performs the element-wise (Hadamard) product of two vectors
+///
+/// ```text
+/// w := u ⊙ v    i.e.,  wᵢ := uᵢ ⋅ vᵢ
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_hadamard, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let u = Vector::from(&[1.0, 2.0, 3.0]);
+///     let v = Vector::from(&[4.0, 5.0, 6.0]);
+///     let mut w = Vector::new(3);
+///     vec_hadamard(&mut w, &u, &v)?;
+///     assert_eq!(w.as_data(), &[4.0, 10.0, 18.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn vec_hadamard(w: &mut Vector, u: &Vector, v: &Vector) -> Result<(), StrError> {
+    let n = w.dim();
+    if u.dim() != n || v.dim() != n {
+        return Err("vectors are incompatible");
+    }
+    for i in 0..n {
+        w[i] = u[i] * v[i];
+    }
+    Ok(())
+}
+
+/// Performs the element-wise division of two vectors
+///
+/// ```text
+/// w := u ⊘ v    i.e.,  wᵢ := uᵢ / vᵢ
+/// ```
+///
+/// **Note:** No check is made for zero components of `v` (the IEEE-754 rules apply).
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_hadamard_div, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let u = Vector::from(&[4.0, 10.0, 18.0]);
+///     let v = Vector::from(&[4.0, 5.0, 6.0]);
+///     let mut w = Vector::new(3);
+///     vec_hadamard_div(&mut w, &u, &v)?;
+///     assert_eq!(w.as_data(), &[1.0, 2.0, 3.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn vec_hadamard_div(w: &mut Vector, u: &Vector, v: &Vector) -> Result<(), StrError> {
+    let n = w.dim();
+    if u.dim() != n || v.dim() != n {
+        return Err("vectors are incompatible");
+    }
+    for i in 0..n {
+        w[i] = u[i] / v[i];
+    }
+    Ok(())
+}
+
+/// Updates a vector by the element-wise (Hadamard) product with another vector
+///
+/// ```text
+/// v := v ⊙ u    i.e.,  vᵢ := vᵢ ⋅ uᵢ
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_hadamard_update, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let u = Vector::from(&[1.0, 2.0, 3.0]);
+///     let mut v = Vector::from(&[4.0, 5.0, 6.0]);
+///     vec_hadamard_update(&mut v, &u)?;
+///     assert_eq!(v.as_data(), &[4.0, 10.0, 18.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn vec_hadamard_update(v: &mut Vector, u: &Vector) -> Result<(), StrError> {
+    let n = v.dim();
+    if u.dim() != n {
+        return Err("vectors are incompatible");
+    }
+    for i in 0..n {
+        v[i] *= u[i];
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{vec_hadamard, vec_hadamard_div, vec_hadamard_update, Vector};
+
+    #[test]
+    fn vec_hadamard_functions_fail_on_wrong_dims() {
+        let u_2 = Vector::new(2);
+        let u_3 = Vector::new(3);
+        let mut w_2 = Vector::new(2);
+        assert_eq!(vec_hadamard(&mut w_2, &u_3, &u_2), Err("vectors are incompatible"));
+        assert_eq!(vec_hadamard(&mut w_2, &u_2, &u_3), Err("vectors are incompatible"));
+        assert_eq!(vec_hadamard_div(&mut w_2, &u_3, &u_2), Err("vectors are incompatible"));
+        assert_eq!(vec_hadamard_div(&mut w_2, &u_2, &u_3), Err("vectors are incompatible"));
+        assert_eq!(vec_hadamard_update(&mut w_2, &u_3), Err("vectors are incompatible"));
+    }
+
+    #[test]
+    fn vec_hadamard_functions_work() {
+        let u = Vector::from(&[1.0, -2.0, 0.5, 0.0]);
+        let v = Vector::from(&[3.0, 4.0, -2.0, 1.0]);
+        let mut w = Vector::new(4);
+        vec_hadamard(&mut w, &u, &v).unwrap();
+        assert_eq!(w.as_data(), &[3.0, -8.0, -1.0, 0.0]);
+        vec_hadamard_div(&mut w, &u, &v).unwrap();
+        assert_eq!(w.as_data(), &[1.0 / 3.0, -0.5, -0.25, 0.0]);
+        let mut x = v.clone();
+        vec_hadamard_update(&mut x, &u).unwrap();
+        assert_eq!(x.as_data(), &[3.0, -8.0, -1.0, 0.0]);
+        let mut empty = Vector::new(0);
+        vec_hadamard(&mut empty, &Vector::new(0), &Vector::new(0)).unwrap();
+    }
+}
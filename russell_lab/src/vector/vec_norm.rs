@@ -1,5 +1,5 @@
 use super::Vector;
-use crate::Norm;
+use crate::{Norm, StrError};
 use russell_openblas::{dasum, dnrm2, idamax, to_i32};
 
 /// Returns the vector norm
@@ -14,6 +14,8 @@ use russell_openblas::{dasum, dnrm2, idamax, to_i32};
 ///     assert_eq!(vec_norm(&u, Norm::One), 11.0);
 ///     assert_eq!(vec_norm(&u, Norm::Euc), 5.0);
 ///     assert_eq!(vec_norm(&u, Norm::Max), 3.0);
+///     assert_eq!(vec_norm(&u, Norm::P(1.0)), 11.0);
+///     assert_eq!(vec_norm(&u, Norm::Rms), f64::sqrt(5.0));
 /// }
 /// ```
 pub fn vec_norm(v: &Vector, kind: Norm) -> f64 {
@@ -28,14 +30,54 @@ pub fn vec_norm(v: &Vector, kind: Norm) -> f64 {
             f64::abs(v.get(idx as usize))
         }
         Norm::One => dasum(n, &v.as_data(), 1),
+        Norm::P(p) => {
+            let sum: f64 = v.as_data().iter().map(|x| f64::powf(f64::abs(*x), p)).sum();
+            f64::powf(sum, 1.0 / p)
+        }
+        Norm::Rms => dnrm2(n, v.as_data(), 1) / f64::sqrt(v.dim() as f64),
     }
 }
 
+/// Returns the weighted Euclidean norm of a vector
+///
+/// ```text
+/// ‖u‖_w = sqrt(Σ_i wᵢ⋅uᵢ⋅uᵢ)
+/// ```
+///
+/// **Note:** The weights must be non-negative. Error estimators in adaptive schemes
+/// typically use `wᵢ = 1 / (n ⋅ scᵢ²)` where `scᵢ` are scaling factors.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_norm_weighted, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let u = Vector::from(&[3.0, 2.0]);
+///     let w = Vector::from(&[1.0, 4.0]);
+///     assert_eq!(vec_norm_weighted(&u, &w)?, 5.0);
+///     Ok(())
+/// }
+/// ```
+pub fn vec_norm_weighted(u: &Vector, w: &Vector) -> Result<f64, StrError> {
+    if w.dim() != u.dim() {
+        return Err("vectors are incompatible");
+    }
+    let mut sum = 0.0;
+    for i in 0..u.dim() {
+        if w[i] < 0.0 {
+            return Err("weights must be non-negative");
+        }
+        sum += w[i] * u[i] * u[i];
+    }
+    Ok(f64::sqrt(sum))
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::{vec_norm, Vector};
+    use super::{vec_norm, vec_norm_weighted, Vector};
     use crate::Norm;
     use russell_chk::approx_eq;
 
@@ -61,4 +103,32 @@ mod tests {
         assert_eq!(vec_norm(&diff, Norm::Inf), 2.0);
         assert_eq!(vec_norm(&diff, Norm::One), 3.1);
     }
+
+    #[test]
+    fn vec_norm_p_and_rms_work() {
+        let u0 = Vector::new(0);
+        assert_eq!(vec_norm(&u0, Norm::P(3.0)), 0.0);
+        assert_eq!(vec_norm(&u0, Norm::Rms), 0.0);
+
+        let u = Vector::from(&[-3.0, 2.0, 1.0, 1.0, 1.0]);
+        approx_eq(vec_norm(&u, Norm::P(1.0)), 8.0, 1e-15);
+        approx_eq(vec_norm(&u, Norm::P(2.0)), 4.0, 1e-15);
+        approx_eq(vec_norm(&u, Norm::P(3.0)), f64::cbrt(38.0), 1e-14);
+        approx_eq(vec_norm(&u, Norm::P(100.0)), 3.0, 1e-14);
+        approx_eq(vec_norm(&u, Norm::Rms), 4.0 / f64::sqrt(5.0), 1e-15);
+    }
+
+    #[test]
+    fn vec_norm_weighted_works() {
+        let u = Vector::from(&[1.0, -2.0, 2.0]);
+        let w_wrong = Vector::new(2);
+        assert_eq!(vec_norm_weighted(&u, &w_wrong), Err("vectors are incompatible"));
+        let w_neg = Vector::from(&[1.0, -1.0, 1.0]);
+        assert_eq!(vec_norm_weighted(&u, &w_neg), Err("weights must be non-negative"));
+        let ones = Vector::from(&[1.0, 1.0, 1.0]);
+        assert_eq!(vec_norm_weighted(&u, &ones).unwrap(), 3.0);
+        let w = Vector::from(&[4.0, 0.0, 3.0]);
+        assert_eq!(vec_norm_weighted(&u, &w).unwrap(), 4.0);
+        assert_eq!(vec_norm_weighted(&Vector::new(0), &Vector::new(0)).unwrap(), 0.0);
+    }
 }
@@ -1,4 +1,5 @@
-use super::Vector;
+use super::{check_strided, Vector};
+use crate::StrError;
 use russell_openblas::{dscal, to_i32};
 
 /// Scales vector
@@ -24,15 +25,61 @@ use russell_openblas::{dscal, to_i32};
 /// }
 /// ```
 pub fn vec_scale(v: &mut Vector, alpha: f64) {
-    let n_i32: i32 = to_i32(v.dim());
+    let n_i32 = to_i32(v.dim());
     dscal(n_i32, alpha, v.as_mut_data(), 1);
 }
 
+/// Scales a strided sequence of components
+///
+/// ```text
+/// v[i⋅inc_v] := alpha * v[i⋅inc_v]    for i in 0..n
+/// ```
+///
+/// The stride allows operating directly on non-contiguous data; e.g., the row `r` of a column-major
+/// (m,n) matrix `a` corresponds to `&mut a.as_mut_data()[r..]` with stride `m`.
+///
+/// # Input
+///
+/// * `n` -- number of components to be scaled
+/// * `alpha` -- the scaling factor
+/// * `v` -- the slice with at least `(n-1)⋅inc_v + 1` components
+/// * `inc_v` -- the stride of `v` (> 0)
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_scale_strided, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // scale the second row of a
+///     let mut a = Matrix::from(&[
+///         [1.0, 2.0, 3.0],
+///         [4.0, 5.0, 6.0],
+///     ]);
+///     vec_scale_strided(3, 0.5, &mut a.as_mut_data()[1..], 2)?;
+///     let correct = "┌             ┐\n\
+///                    │   1   2   3 │\n\
+///                    │   2 2.5   3 │\n\
+///                    └             ┘";
+///     assert_eq!(format!("{}", a), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn vec_scale_strided(n: usize, alpha: f64, v: &mut [f64], inc_v: usize) -> Result<(), StrError> {
+    check_strided(n, v.len(), inc_v)?;
+    if n == 0 {
+        return Ok(());
+    }
+    dscal(to_i32(n), alpha, v, to_i32(inc_v));
+    Ok(())
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::{vec_scale, Vector};
+    use super::{vec_scale, vec_scale_strided, Vector};
+    use crate::Matrix;
     use russell_chk::vec_approx_eq;
 
     #[test]
@@ -42,4 +89,20 @@ mod tests {
         let correct = &[2.0, 3.0, 4.0];
         vec_approx_eq(u.as_data(), correct, 1e-15);
     }
+
+    #[test]
+    fn vec_scale_strided_works() {
+        let mut a = Matrix::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        assert_eq!(
+            vec_scale_strided(3, 2.0, a.as_mut_data(), 0),
+            Err("the stride must be greater than zero")
+        );
+        assert_eq!(
+            vec_scale_strided(3, 2.0, &mut a.as_mut_data()[3..], 3),
+            Err("the slice is too short for the number of components and stride")
+        );
+        vec_scale_strided(3, 2.0, &mut a.as_mut_data()[2..], 3).unwrap();
+        assert_eq!(a.as_data(), &[1.0, 4.0, 14.0, 2.0, 5.0, 16.0, 3.0, 6.0, 18.0]);
+        vec_scale_strided(0, 2.0, &mut [], 1).unwrap();
+    }
 }
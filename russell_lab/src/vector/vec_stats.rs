@@ -0,0 +1,167 @@
+use super::Vector;
+use crate::StrError;
+
+/// Returns the sum of the components of a vector
+///
+/// ```text
+/// s := Σ_i uᵢ
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_sum, Vector};
+/// let u = Vector::from(&[1.0, 2.0, 3.0]);
+/// assert_eq!(vec_sum(&u), 6.0);
+/// ```
+pub fn vec_sum(u: &Vector) -> f64 {
+    u.as_data().iter().sum()
+}
+
+/// Returns the mean (average) of the components of a vector
+///
+/// ```text
+/// mean := Σ_i uᵢ / n
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_mean, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let u = Vector::from(&[1.0, 2.0, 3.0, 6.0]);
+///     assert_eq!(vec_mean(&u)?, 3.0);
+///     Ok(())
+/// }
+/// ```
+pub fn vec_mean(u: &Vector) -> Result<f64, StrError> {
+    let n = u.dim();
+    if n == 0 {
+        return Err("vector must not be empty");
+    }
+    Ok(vec_sum(u) / (n as f64))
+}
+
+/// Returns the (sample) standard deviation of the components of a vector
+///
+/// ```text
+/// std := sqrt(Σ_i (uᵢ - mean)² / (n - 1))
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_std, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let u = Vector::from(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+///     assert_eq!(vec_std(&u)?, f64::sqrt(32.0 / 7.0));
+///     Ok(())
+/// }
+/// ```
+pub fn vec_std(u: &Vector) -> Result<f64, StrError> {
+    let n = u.dim();
+    if n < 2 {
+        return Err("vector must have at least two components");
+    }
+    let mean = vec_mean(u)?;
+    let sum: f64 = u.as_data().iter().map(|x| (x - mean) * (x - mean)).sum();
+    Ok(f64::sqrt(sum / ((n - 1) as f64)))
+}
+
+/// Finds the minimum component of a vector
+///
+/// Returns (i,min) where i is the index of the first occurrence of the minimum
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_min, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let u = Vector::from(&[3.0, -1.0, 2.0]);
+///     assert_eq!(vec_min(&u)?, (1, -1.0));
+///     Ok(())
+/// }
+/// ```
+pub fn vec_min(u: &Vector) -> Result<(usize, f64), StrError> {
+    let n = u.dim();
+    if n == 0 {
+        return Err("vector must not be empty");
+    }
+    let (mut i_found, mut min) = (0, u[0]);
+    for i in 1..n {
+        if u[i] < min {
+            i_found = i;
+            min = u[i];
+        }
+    }
+    Ok((i_found, min))
+}
+
+/// Finds the maximum component of a vector
+///
+/// Returns (i,max) where i is the index of the first occurrence of the maximum
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_max, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let u = Vector::from(&[3.0, -1.0, 2.0]);
+///     assert_eq!(vec_max(&u)?, (0, 3.0));
+///     Ok(())
+/// }
+/// ```
+pub fn vec_max(u: &Vector) -> Result<(usize, f64), StrError> {
+    let n = u.dim();
+    if n == 0 {
+        return Err("vector must not be empty");
+    }
+    let (mut i_found, mut max) = (0, u[0]);
+    for i in 1..n {
+        if u[i] > max {
+            i_found = i;
+            max = u[i];
+        }
+    }
+    Ok((i_found, max))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{vec_max, vec_mean, vec_min, vec_std, vec_sum, Vector};
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn vec_stats_capture_errors() {
+        let empty = Vector::new(0);
+        assert_eq!(vec_sum(&empty), 0.0);
+        assert_eq!(vec_mean(&empty), Err("vector must not be empty"));
+        assert_eq!(vec_min(&empty), Err("vector must not be empty"));
+        assert_eq!(vec_max(&empty), Err("vector must not be empty"));
+        assert_eq!(
+            vec_std(&Vector::from(&[1.0])),
+            Err("vector must have at least two components")
+        );
+    }
+
+    #[test]
+    fn vec_stats_work() {
+        let u = Vector::from(&[4.0, -2.0, 7.0, -2.0, 7.0, 1.0]);
+        assert_eq!(vec_sum(&u), 15.0);
+        assert_eq!(vec_mean(&u).unwrap(), 2.5);
+        approx_eq(vec_std(&u).unwrap(), f64::sqrt(85.5 / 5.0), 1e-15);
+        assert_eq!(vec_min(&u).unwrap(), (1, -2.0));
+        assert_eq!(vec_max(&u).unwrap(), (2, 7.0));
+
+        let one = Vector::from(&[-5.0]);
+        assert_eq!(vec_mean(&one).unwrap(), -5.0);
+        assert_eq!(vec_min(&one).unwrap(), (0, -5.0));
+        assert_eq!(vec_max(&one).unwrap(), (0, -5.0));
+    }
+}
@@ -1,4 +1,4 @@
-use super::Vector;
+use super::{check_strided, Vector};
 use crate::StrError;
 use russell_openblas::{daxpy, to_i32};
 
@@ -31,16 +31,73 @@ pub fn vec_update(v: &mut Vector, alpha: f64, u: &Vector) -> Result<(), StrError
     if u.dim() != n {
         return Err("vectors are incompatible");
     }
-    let n_i32: i32 = to_i32(n);
+    let n_i32 = to_i32(n);
     daxpy(n_i32, alpha, u.as_data(), 1, v.as_mut_data(), 1);
     Ok(())
 }
 
+/// Updates a strided sequence of components based on another strided sequence
+///
+/// ```text
+/// v[i⋅inc_v] += α⋅u[i⋅inc_u]    for i in 0..n
+/// ```
+///
+/// The strides allow operating directly on non-contiguous data; e.g., the row `r` of a column-major
+/// (m,n) matrix `a` corresponds to `&a.as_data()[r..]` with stride `m`.
+///
+/// # Input
+///
+/// * `n` -- number of components to be updated
+/// * `v` -- the slice to be updated with at least `(n-1)⋅inc_v + 1` components
+/// * `inc_v` -- the stride of `v` (> 0)
+/// * `alpha` -- the α coefficient
+/// * `u` -- the slice with at least `(n-1)⋅inc_u + 1` components
+/// * `inc_u` -- the stride of `u` (> 0)
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_update_strided, Matrix, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     // add 10⋅u to the first row of a
+///     let u = Vector::from(&[1.0, 2.0, 3.0]);
+///     let mut a = Matrix::from(&[
+///         [1.0, 2.0, 3.0],
+///         [4.0, 5.0, 6.0],
+///     ]);
+///     vec_update_strided(3, a.as_mut_data(), 2, 10.0, u.as_data(), 1)?;
+///     let correct = "┌          ┐\n\
+///                    │ 11 22 33 │\n\
+///                    │  4  5  6 │\n\
+///                    └          ┘";
+///     assert_eq!(format!("{}", a), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn vec_update_strided(
+    n: usize,
+    v: &mut [f64],
+    inc_v: usize,
+    alpha: f64,
+    u: &[f64],
+    inc_u: usize,
+) -> Result<(), StrError> {
+    check_strided(n, v.len(), inc_v)?;
+    check_strided(n, u.len(), inc_u)?;
+    if n == 0 {
+        return Ok(());
+    }
+    daxpy(to_i32(n), alpha, u, to_i32(inc_u), v, to_i32(inc_v));
+    Ok(())
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::{vec_update, Vector};
+    use super::{vec_update, vec_update_strided, Vector};
+    use crate::Matrix;
     use russell_chk::vec_approx_eq;
 
     #[test]
@@ -58,4 +115,21 @@ mod tests {
         let correct = &[120.0, 240.0, 360.0];
         vec_approx_eq(v.as_data(), correct, 1e-15);
     }
+
+    #[test]
+    fn vec_update_strided_works() {
+        let a = Matrix::from(&[[1.0, 2.0], [3.0, 4.0]]);
+        let mut b = Matrix::from(&[[10.0, 20.0], [30.0, 40.0]]);
+        assert_eq!(
+            vec_update_strided(2, b.as_mut_data(), 0, 1.0, a.as_data(), 2),
+            Err("the stride must be greater than zero")
+        );
+        assert_eq!(
+            vec_update_strided(2, b.as_mut_data(), 2, 1.0, &a.as_data()[1..], 3),
+            Err("the slice is too short for the number of components and stride")
+        );
+        // b[1,:] += 2⋅a[0,:]
+        vec_update_strided(2, &mut b.as_mut_data()[1..], 2, 2.0, a.as_data(), 2).unwrap();
+        assert_eq!(b.as_data(), &[10.0, 32.0, 20.0, 44.0]);
+    }
 }
@@ -0,0 +1,85 @@
+use russell_openblas::BlasInt;
+
+/// Holds work arrays that can be reused by repeated calls to the linear algebra functions
+///
+/// Some functions such as [crate::mat_svd], [crate::mat_eigen], and [crate::solve_lin_sys] need
+/// auxiliary arrays (e.g., the `superb` array of dgesvd or the pivot indices of dgesv). By default,
+/// these arrays are allocated at every call. The `*_with_workspace` variants of these functions
+/// take a Workspace instead; thus, repeated calls with the same (or smaller) dimensions, e.g., in
+/// a loop over finite elements, do not allocate these arrays again.
+///
+/// The arrays grow as needed and are never shrunk.
+///
+/// **Note:** The underlying LAPACKE routines may still allocate their own internal work arrays.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{solve_lin_sys_with_workspace, Matrix, StrError, Vector, Workspace};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     let mut workspace = Workspace::new();
+///     for i in 1..4 {
+///         let d = i as f64;
+///         let mut a = Matrix::from(&[[d, 0.0], [0.0, 2.0 * d]]);
+///         let mut b = Vector::from(&[d, d]);
+///         solve_lin_sys_with_workspace(&mut b, &mut a, &mut workspace)?;
+///         assert_eq!(b.as_data(), &[1.0, 0.5]);
+///     }
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Workspace {
+    real: Vec<f64>,    // real work array
+    int: Vec<BlasInt>, // integer work array
+}
+
+impl Workspace {
+    /// Allocates a new (empty) instance
+    pub fn new() -> Self {
+        Workspace {
+            real: Vec::new(),
+            int: Vec::new(),
+        }
+    }
+
+    /// Returns a real work array with the given length (growing the array if needed)
+    pub(crate) fn real(&mut self, len: usize) -> &mut [f64] {
+        if self.real.len() < len {
+            self.real.resize(len, 0.0);
+        }
+        &mut self.real[..len]
+    }
+
+    /// Returns an integer work array with the given length (growing the array if needed)
+    pub(crate) fn int(&mut self, len: usize) -> &mut [BlasInt] {
+        if self.int.len() < len {
+            self.int.resize(len, 0);
+        }
+        &mut self.int[..len]
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Workspace;
+
+    #[test]
+    fn workspace_works() {
+        let mut ws = Workspace::new();
+        assert_eq!(ws.real(3).len(), 3);
+        ws.real(3)[2] = 1.0;
+        assert_eq!(ws.real(2).len(), 2);
+        assert_eq!(ws.real.len(), 3);
+        assert_eq!(ws.int(4).len(), 4);
+        assert_eq!(ws.int(1).len(), 1);
+        assert_eq!(ws.int.len(), 4);
+        let ws_clone = ws.clone();
+        assert_eq!(ws_clone.real, &[0.0, 0.0, 1.0]);
+    }
+}
@@ -1,3 +1,5 @@
+#![cfg(not(feature = "pure_rust"))]
+
 use russell_lab::{mat_vec_mul, solve_lin_sys, vec_add, vec_norm, Matrix, Norm, StrError, Vector};
 
 #[test]
@@ -1,4 +1,73 @@
+use std::env;
+
+// BLAS/LAPACK backends selectable by cargo features (the first one is the default)
+const BACKENDS: [&str; 5] = ["openblas", "intel_mkl", "netlib", "accelerate", "pure_rust"];
+
 fn main() {
-    println!("cargo:rustc-link-lib=dylib=openblas");
-    println!("cargo:rustc-link-lib=dylib=lapacke");
+    // selected backends
+    let selected: Vec<&str> = BACKENDS
+        .into_iter()
+        .filter(|b| env::var(format!("CARGO_FEATURE_{}", b.to_uppercase())).is_ok())
+        .collect();
+
+    // the default backend (openblas) gives way to any alternative backend because
+    // it may have been enabled by another crate in the dependency graph
+    let alternatives: Vec<&str> = selected
+        .iter()
+        .copied()
+        .filter(|b| *b != "openblas")
+        .collect();
+    let backend = match (alternatives.len(), selected.len()) {
+        (0, 0) => panic!(
+            "one BLAS/LAPACK backend feature must be enabled: {}",
+            BACKENDS.join(", ")
+        ),
+        (0, _) => "openblas",
+        (1, _) => alternatives[0],
+        _ => panic!(
+            "only one alternative BLAS/LAPACK backend feature may be enabled; found: {}",
+            alternatives.join(", ")
+        ),
+    };
+
+    // link
+    let ilp64 = env::var("CARGO_FEATURE_ILP64").is_ok();
+    let libs: &[&str] = match (backend, ilp64) {
+        ("intel_mkl", false) => &["mkl_rt"],
+        ("intel_mkl", true) => &["mkl_intel_ilp64", "mkl_gnu_thread", "mkl_core", "gomp"],
+        ("netlib", false) => &["lapacke", "lapack", "cblas", "blas"],
+        ("netlib", true) => &["lapacke64", "lapack64", "cblas64", "blas64"],
+        ("accelerate", false) => &["lapacke"],
+        ("accelerate", true) => {
+            panic!("the ilp64 feature is not supported by the accelerate backend")
+        }
+        // the BLAS functions are implemented in Rust and LAPACK is not available
+        ("pure_rust", _) => &[],
+        (_, false) => &["openblas", "lapacke"],
+        // OpenBLAS built with INTERFACE64=1 includes LAPACKE
+        (_, true) => &["openblas64"],
+    };
+    if backend == "intel_mkl" {
+        if let Ok(root) = env::var("MKLROOT") {
+            println!("cargo:rustc-link-search=native={}/lib/intel64", root);
+            println!("cargo:rustc-link-search=native={}/lib", root);
+        }
+    }
+    if backend == "accelerate" {
+        // Accelerate does not provide LAPACKE; thus, it must be installed separately (e.g., brew install lapack)
+        println!("cargo:rustc-link-search=native=/opt/homebrew/opt/lapack/lib");
+        println!("cargo:rustc-link-search=native=/usr/local/opt/lapack/lib");
+        println!("cargo:rustc-link-lib=framework=Accelerate");
+    }
+    for lib in libs {
+        println!("cargo:rustc-link-lib=dylib={}", lib);
+    }
+
+    // configuration
+    let cfgs: Vec<String> = BACKENDS.iter().map(|b| format!("backend_{}", b)).collect();
+    println!("cargo:rustc-check-cfg=cfg({})", cfgs.join(", "));
+    println!("cargo:rustc-cfg=backend_{}", backend);
+    println!("cargo:rustc-env=RUSSELL_BLAS_BACKEND={}", backend);
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=MKLROOT");
 }
@@ -1,31 +1,229 @@
+use std::fmt;
+use std::sync::Mutex;
+#[cfg(any(backend_openblas, backend_intel_mkl))]
+use std::{ffi::CStr, os::raw::c_char};
+
+#[cfg(backend_openblas)]
 extern "C" {
     fn openblas_set_num_threads(num_threads: i32);
     fn openblas_get_num_threads() -> i32;
+    fn openblas_get_config() -> *const c_char;
+}
+
+#[cfg(backend_intel_mkl)]
+extern "C" {
+    fn MKL_Set_Num_Threads(num_threads: i32);
+    fn MKL_Get_Max_Threads() -> i32;
+    fn MKL_Get_Version_String(buffer: *mut c_char, len: i32);
+}
+
+/// Holds information about the BLAS/LAPACK backend selected at compile time
+///
+/// The backend is selected by one of the cargo features `openblas` (default), `intel_mkl`, `netlib`, `accelerate`,
+/// or `pure_rust`.
+#[derive(Clone, Debug)]
+pub struct BackendInfo {
+    /// The name of the backend (the cargo feature)
+    pub name: &'static str,
+
+    /// The configuration (e.g., version and architecture) as reported by the library, if available
+    pub config: String,
+
+    /// The number of threads used by the library (1 if the backend is single-threaded)
+    pub num_threads: i32,
+}
+
+impl fmt::Display for BackendInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "backend: {}", self.name).unwrap();
+        if !self.config.is_empty() {
+            writeln!(f, "config: {}", self.config).unwrap();
+        }
+        write!(f, "num_threads: {}", self.num_threads)
+    }
+}
+
+/// Returns information about the BLAS/LAPACK backend
+///
+/// # Example
+///
+/// ```
+/// use russell_openblas::backend_info;
+/// let info = backend_info();
+/// println!("{}", info);
+/// assert!(info.num_threads >= 1);
+/// ```
+pub fn backend_info() -> BackendInfo {
+    BackendInfo {
+        name: env!("RUSSELL_BLAS_BACKEND"),
+        config: backend_config(),
+        num_threads: get_num_threads(),
+    }
 }
 
 /// Sets the number of threads
+///
+/// **Note:** This function has no effect with the netlib, accelerate, and pure_rust backends.
 #[inline]
 pub fn set_num_threads(num_threads: i32) {
+    #[cfg(backend_openblas)]
     unsafe {
         openblas_set_num_threads(num_threads);
     }
+    #[cfg(backend_intel_mkl)]
+    unsafe {
+        MKL_Set_Num_Threads(num_threads);
+    }
+    #[cfg(any(backend_netlib, backend_accelerate, backend_pure_rust))]
+    let _ = num_threads;
 }
 
 /// Gets the number of threads
+///
+/// **Note:** This function returns 1 with the netlib, accelerate, and pure_rust backends.
 #[inline]
 pub fn get_num_threads() -> i32 {
-    unsafe { openblas_get_num_threads() }
+    #[cfg(backend_openblas)]
+    unsafe {
+        openblas_get_num_threads()
+    }
+    #[cfg(backend_intel_mkl)]
+    unsafe {
+        MKL_Get_Max_Threads()
+    }
+    #[cfg(any(backend_netlib, backend_accelerate, backend_pure_rust))]
+    1
+}
+
+/// Holds the number of active SingleThreadGuards and the number of threads to be restored
+static SINGLE_THREAD_STATE: Mutex<(usize, i32)> = Mutex::new((0, 1));
+
+/// Pins BLAS/LAPACK to a single thread while in scope (RAII guard)
+///
+/// This is useful when the application parallelizes at a higher level (e.g., rayon over elements)
+/// and the BLAS threads would otherwise compete with the application threads.
+///
+/// The number of threads of the BLAS library is a process-wide setting. Thus, the guards are reference-counted:
+/// the first guard saves the current number of threads and sets it to one; the last guard to be dropped
+/// restores the saved number. Therefore, guards may be created concurrently (e.g., one per parallel task).
+///
+/// # Example
+///
+/// ```
+/// use russell_openblas::{get_num_threads, SingleThreadGuard};
+///
+/// {
+///     let _guard = SingleThreadGuard::new();
+///     assert_eq!(get_num_threads(), 1);
+///     // ... call BLAS from many threads
+/// }
+/// // the previous number of threads is restored here
+/// ```
+pub struct SingleThreadGuard {
+    _private: (),
+}
+
+impl SingleThreadGuard {
+    /// Sets the number of threads to one and returns the guard that restores it
+    pub fn new() -> Self {
+        let mut state = SINGLE_THREAD_STATE.lock().unwrap();
+        if state.0 == 0 {
+            state.1 = get_num_threads();
+            set_num_threads(1);
+        }
+        state.0 += 1;
+        SingleThreadGuard { _private: () }
+    }
+}
+
+impl Default for SingleThreadGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for SingleThreadGuard {
+    fn drop(&mut self) {
+        let mut state = SINGLE_THREAD_STATE.lock().unwrap();
+        state.0 -= 1;
+        if state.0 == 0 {
+            set_num_threads(state.1);
+        }
+    }
+}
+
+/// Returns the configuration string reported by the library
+fn backend_config() -> String {
+    #[cfg(backend_openblas)]
+    unsafe {
+        let config = openblas_get_config();
+        if config.is_null() {
+            return String::new();
+        }
+        CStr::from_ptr(config).to_string_lossy().trim().to_string()
+    }
+    #[cfg(backend_intel_mkl)]
+    unsafe {
+        let mut buffer = vec![0 as c_char; 256];
+        MKL_Get_Version_String(buffer.as_mut_ptr(), buffer.len() as i32);
+        let last = buffer.len() - 1;
+        buffer[last] = 0;
+        CStr::from_ptr(buffer.as_ptr()).to_string_lossy().trim().to_string()
+    }
+    #[cfg(any(backend_netlib, backend_accelerate, backend_pure_rust))]
+    String::new()
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::{get_num_threads, set_num_threads};
+    use super::backend_info;
+    #[cfg(any(backend_openblas, backend_intel_mkl))]
+    use super::{get_num_threads, set_num_threads, SingleThreadGuard};
 
+    // NOTE: the number of threads is a process-wide setting; thus, a single test
+    // must handle it to avoid interference between concurrent tests
     #[test]
+    #[cfg(any(backend_openblas, backend_intel_mkl))]
     fn set_get_num_threads_work() {
         set_num_threads(2);
         assert_eq!(get_num_threads(), 2);
+
+        // single thread guard
+        {
+            let _guard = SingleThreadGuard::new();
+            assert_eq!(get_num_threads(), 1);
+            {
+                let _nested = SingleThreadGuard::default();
+                assert_eq!(get_num_threads(), 1);
+            }
+            assert_eq!(get_num_threads(), 1);
+        }
+        assert_eq!(get_num_threads(), 2);
+
+        // concurrent guards
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                std::thread::spawn(|| {
+                    let _guard = SingleThreadGuard::new();
+                    assert_eq!(get_num_threads(), 1);
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(get_num_threads(), 2);
+    }
+
+    #[test]
+    fn backend_info_works() {
+        let info = backend_info();
+        assert!(["openblas", "intel_mkl", "netlib", "accelerate", "pure_rust"].contains(&info.name));
+        assert!(info.num_threads >= 1);
+        let text = format!("{}", info);
+        assert!(text.starts_with(&format!("backend: {}\n", info.name)));
+        assert!(text.ends_with(&format!("num_threads: {}", info.num_threads)));
     }
 }
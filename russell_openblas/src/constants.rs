@@ -39,3 +39,19 @@ pub(crate) fn lapack_job_vlr(calculate: bool) -> u8 {
     }
     b'N'
 }
+
+#[inline]
+pub(crate) fn lapack_side(left: bool) -> u8 {
+    if left {
+        return b'L';
+    }
+    b'R'
+}
+
+#[inline]
+pub(crate) fn lapack_transpose(transpose: bool) -> u8 {
+    if transpose {
+        return b'T';
+    }
+    b'N'
+}
@@ -1,4 +1,4 @@
-use crate::StrError;
+use crate::Error;
 use num_complex::Complex64;
 
 /// Returns the colum-major representation of a row-major matrix
@@ -59,12 +59,12 @@ pub fn col_major_complex(m: usize, n: usize, row_major: &[Complex64]) -> Vec<Com
 /// * `w_imag` -- n, eigenvalues; imaginary part
 /// * `v` -- n*n, output of dgeev
 ///
-pub fn dgeev_data(v_real: &mut [f64], v_imag: &mut [f64], w_imag: &[f64], v: &[f64]) -> Result<(), StrError> {
+pub fn dgeev_data(v_real: &mut [f64], v_imag: &mut [f64], w_imag: &[f64], v: &[f64]) -> Result<(), Error> {
     // check
     let n = w_imag.len();
     let nn = n * n;
     if v_real.len() != nn || v_imag.len() != nn || v.len() != nn || w_imag.len() != n {
-        return Err("arrays have wrong dimensions");
+        return Err(Error::BadDimensions("arrays have wrong dimensions"));
     }
 
     // step and increment for next conjugate pair
@@ -76,7 +76,7 @@ pub fn dgeev_data(v_real: &mut [f64], v_imag: &mut [f64], w_imag: &[f64], v: &[f
         // eigenvalue is complex
         if w_imag[j].abs() > 0.0 {
             if j > n - 2 {
-                return Err("last eigenvalue cannot be complex");
+                return Err(Error::InvalidArgument("last eigenvalue cannot be complex"));
             }
             // loop over rows
             for i in 0..n {
@@ -128,7 +128,7 @@ pub fn dgeev_data_lr(
     w_imag: &[f64],
     vl: &[f64],
     vr: &[f64],
-) -> Result<(), StrError> {
+) -> Result<(), Error> {
     // check
     let n = w_imag.len();
     let nn = n * n;
@@ -140,7 +140,7 @@ pub fn dgeev_data_lr(
         || vr.len() != nn
         || w_imag.len() != n
     {
-        return Err("arrays have wrong dimensions");
+        return Err(Error::BadDimensions("arrays have wrong dimensions"));
     }
 
     // step and increment for next conjugate pair
@@ -152,7 +152,7 @@ pub fn dgeev_data_lr(
         // eigenvalue is complex
         if w_imag[j].abs() > 0.0 {
             if j > n - 2 {
-                return Err("last eigenvalue cannot be complex");
+                return Err(Error::InvalidArgument("last eigenvalue cannot be complex"));
             }
             // loop over rows
             for i in 0..n {
@@ -192,7 +192,7 @@ pub fn dgeev_data_lr(
 #[cfg(test)]
 mod tests {
     use super::{col_major, col_major_complex, dgeev_data, dgeev_data_lr};
-    use crate::StrError;
+    use crate::{Error, StrError};
     use num_complex::Complex64;
     use russell_chk::{complex_vec_approx_eq, vec_approx_eq};
 
@@ -240,19 +240,19 @@ mod tests {
         let v_wrong = vec![0.0; n * wrong];
         assert_eq!(
             dgeev_data(&mut v_real_wrong, &mut v_imag, &w_imag, &v),
-            Err("arrays have wrong dimensions")
+            Err(Error::BadDimensions("arrays have wrong dimensions"))
         );
         assert_eq!(
             dgeev_data(&mut v_real, &mut v_imag_wrong, &w_imag, &v),
-            Err("arrays have wrong dimensions")
+            Err(Error::BadDimensions("arrays have wrong dimensions"))
         );
         assert_eq!(
             dgeev_data(&mut v_real, &mut v_imag, &w_imag_wrong, &v),
-            Err("arrays have wrong dimensions")
+            Err(Error::BadDimensions("arrays have wrong dimensions"))
         );
         assert_eq!(
             dgeev_data(&mut v_real, &mut v_imag, &w_imag, &v_wrong),
-            Err("arrays have wrong dimensions")
+            Err(Error::BadDimensions("arrays have wrong dimensions"))
         );
     }
 
@@ -266,7 +266,7 @@ mod tests {
         let v = vec![0.0; n * n];
         assert_eq!(
             dgeev_data(&mut v_real, &mut v_imag, &w_imag, &v),
-            Err("last eigenvalue cannot be complex")
+            Err(Error::InvalidArgument("last eigenvalue cannot be complex"))
         );
     }
 
@@ -330,7 +330,7 @@ mod tests {
                 &vl,
                 &vr,
             ),
-            Err("arrays have wrong dimensions")
+            Err(Error::BadDimensions("arrays have wrong dimensions"))
         );
         assert_eq!(
             dgeev_data_lr(
@@ -342,7 +342,7 @@ mod tests {
                 &vl,
                 &vr,
             ),
-            Err("arrays have wrong dimensions")
+            Err(Error::BadDimensions("arrays have wrong dimensions"))
         );
         assert_eq!(
             dgeev_data_lr(
@@ -354,7 +354,7 @@ mod tests {
                 &vl,
                 &vr,
             ),
-            Err("arrays have wrong dimensions")
+            Err(Error::BadDimensions("arrays have wrong dimensions"))
         );
         assert_eq!(
             dgeev_data_lr(
@@ -366,7 +366,7 @@ mod tests {
                 &vl,
                 &vr,
             ),
-            Err("arrays have wrong dimensions")
+            Err(Error::BadDimensions("arrays have wrong dimensions"))
         );
         assert_eq!(
             dgeev_data_lr(
@@ -378,7 +378,7 @@ mod tests {
                 &vl,
                 &vr,
             ),
-            Err("arrays have wrong dimensions")
+            Err(Error::BadDimensions("arrays have wrong dimensions"))
         );
         assert_eq!(
             dgeev_data_lr(
@@ -390,7 +390,7 @@ mod tests {
                 &vl_wrong,
                 &vr,
             ),
-            Err("arrays have wrong dimensions")
+            Err(Error::BadDimensions("arrays have wrong dimensions"))
         );
         assert_eq!(
             dgeev_data_lr(
@@ -402,7 +402,7 @@ mod tests {
                 &vl,
                 &vr_wrong,
             ),
-            Err("arrays have wrong dimensions")
+            Err(Error::BadDimensions("arrays have wrong dimensions"))
         );
     }
 
@@ -427,7 +427,7 @@ mod tests {
                 &vl,
                 &vr,
             ),
-            Err("last eigenvalue cannot be complex")
+            Err(Error::InvalidArgument("last eigenvalue cannot be complex"))
         );
     }
 
@@ -0,0 +1,228 @@
+use crate::{BlasInt, StrError};
+use std::fmt;
+
+/// Defines the errors returned by the BLAS/LAPACK wrappers
+///
+/// The variants allow matching on the kind of error instead of parsing the message.
+/// The messages are the same as the ones of the former `StrError`; moreover, an error
+/// converts into a `StrError` (and vice-versa); thus, the `?` operator works in functions
+/// returning `Result<T, StrError>`.
+///
+/// # Example
+///
+/// ```
+/// use russell_openblas::{dgetrf, Error};
+///
+/// // singular matrix (col-major)
+/// let mut a = vec![1.0, 2.0, 2.0, 4.0];
+/// let mut ipiv = vec![0; 2];
+/// match dgetrf(2, 2, &mut a, &mut ipiv) {
+///     Err(Error::Singular(message)) => println!("{}", message),
+///     Err(e) => println!("another error: {}", e),
+///     Ok(_) => (),
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// The dimensions of the arrays are incompatible
+    BadDimensions(&'static str),
+
+    /// The matrix is singular or not positive-definite
+    Singular(&'static str),
+
+    /// An iterative algorithm (e.g., eigenvalues or SVD) did not converge
+    NotConverged(&'static str),
+
+    /// An argument has an invalid value
+    InvalidArgument(&'static str),
+
+    /// The backend library failed or the function is not available with the selected backend
+    Backend(&'static str),
+
+    /// Any other error (e.g., converted from a `StrError`)
+    Other(&'static str),
+
+    /// Wraps an error (the source) with a message describing the context
+    Context(&'static str, Box<Error>),
+}
+
+impl Error {
+    /// Returns the message of this error (without the messages of the sources)
+    pub fn message(&self) -> &'static str {
+        match self {
+            Error::BadDimensions(m)
+            | Error::Singular(m)
+            | Error::NotConverged(m)
+            | Error::InvalidArgument(m)
+            | Error::Backend(m)
+            | Error::Other(m)
+            | Error::Context(m, _) => m,
+        }
+    }
+
+    /// Wraps this error with a message describing the context
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_openblas::Error;
+    /// use std::error::Error as StdError;
+    ///
+    /// let err = Error::Singular("LAPACK dgetrf failed").context("cannot compute the inverse");
+    /// assert_eq!(err.to_string(), "cannot compute the inverse");
+    /// assert_eq!(err.source().unwrap().to_string(), "LAPACK dgetrf failed");
+    /// assert_eq!(err.root(), &Error::Singular("LAPACK dgetrf failed"));
+    /// ```
+    pub fn context(self, message: &'static str) -> Self {
+        Error::Context(message, Box::new(self))
+    }
+
+    /// Returns the innermost error (following the chain of sources)
+    pub fn root(&self) -> &Error {
+        match self {
+            Error::Context(_, source) => source.root(),
+            _ => self,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Context(_, source) => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<StrError> for Error {
+    fn from(message: StrError) -> Self {
+        Error::Other(message)
+    }
+}
+
+impl From<Error> for StrError {
+    fn from(error: Error) -> Self {
+        error.message()
+    }
+}
+
+/// Converts the info code returned by LAPACK into an error
+///
+/// A negative info means an illegal argument. A positive info means a numerical failure
+/// whose kind depends on the function; thus, it is given by `positive`.
+pub(crate) fn lapack_error(info: BlasInt, message: &'static str, positive: fn(&'static str) -> Error) -> Error {
+    #[cfg(backend_pure_rust)]
+    if info == crate::pure_rust::LAPACK_NOT_AVAILABLE {
+        return Error::Backend(not_available(message));
+    }
+    if info < 0 {
+        Error::InvalidArgument(message)
+    } else {
+        positive(message)
+    }
+}
+
+/// Returns the message of a LAPACK function that is not available with the pure_rust backend
+///
+/// The `message` is the one given to [lapack_error] (e.g., "LAPACK dgetrf failed").
+#[cfg(backend_pure_rust)]
+fn not_available(message: &'static str) -> &'static str {
+    macro_rules! messages {
+        ($($name:literal),*) => {
+            match message {
+                $(
+                    concat!("LAPACK ", $name, " failed") | concat!("LAPACK ", $name, " workspace query failed") => {
+                        concat!("LAPACK ", $name, " is not available with the pure_rust backend")
+                    }
+                )*
+                _ => "LAPACK function is not available with the pure_rust backend",
+            }
+        };
+    }
+    messages!(
+        "dgesv", "dgesvx", "zgesv", "dposv", "dsysv", "zposv", "dgesvd", "zgesvd", "dgetrf", "zgetrf", "dgetri",
+        "zgetri", "dgeequ", "dlaqge", "dpotrf", "zpotrf", "dgeqrf", "dorgqr", "dormqr", "dgelqf", "dgebrd", "dorgbr",
+        "dggsvd3", "dgelsd", "dgelsy", "dgeev", "zgeev", "dsyev", "dsyevd", "dsyevr", "zheevr"
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{lapack_error, Error};
+    use crate::StrError;
+    use std::error::Error as StdError;
+
+    #[test]
+    fn message_and_display_work() {
+        let err = Error::BadDimensions("arrays have wrong dimensions");
+        assert_eq!(err.message(), "arrays have wrong dimensions");
+        assert_eq!(format!("{}", err), "arrays have wrong dimensions");
+        assert!(err.source().is_none());
+        assert_eq!(err.root(), &err);
+    }
+
+    #[test]
+    fn context_works() {
+        let err = Error::NotConverged("LAPACK dsyev failed")
+            .context("cannot compute the eigenvalues")
+            .context("cannot run the simulation");
+        assert_eq!(err.message(), "cannot run the simulation");
+        let source = err.source().unwrap();
+        assert_eq!(source.to_string(), "cannot compute the eigenvalues");
+        assert_eq!(source.source().unwrap().to_string(), "LAPACK dsyev failed");
+        assert_eq!(err.root(), &Error::NotConverged("LAPACK dsyev failed"));
+    }
+
+    #[test]
+    fn conversions_work() {
+        let err: Error = "some error".into();
+        assert_eq!(err, Error::Other("some error"));
+        let message: StrError = Error::Singular("LAPACK dgetrf failed").into();
+        assert_eq!(message, "LAPACK dgetrf failed");
+        fn propagate() -> Result<(), StrError> {
+            Err(Error::Backend("LAPACK dgesv failed"))?;
+            Ok(())
+        }
+        assert_eq!(propagate(), Err("LAPACK dgesv failed"));
+    }
+
+    #[test]
+    fn lapack_error_works() {
+        assert_eq!(
+            lapack_error(-1, "failed", Error::Singular),
+            Error::InvalidArgument("failed")
+        );
+        assert_eq!(lapack_error(2, "failed", Error::Singular), Error::Singular("failed"));
+        assert_eq!(
+            lapack_error(2, "failed", Error::NotConverged),
+            Error::NotConverged("failed")
+        );
+    }
+
+    #[cfg(backend_pure_rust)]
+    #[test]
+    fn lapack_error_handles_not_available() {
+        use crate::pure_rust::LAPACK_NOT_AVAILABLE;
+        assert_eq!(
+            lapack_error(LAPACK_NOT_AVAILABLE, "LAPACK dgetrf failed", Error::Singular),
+            Error::Backend("LAPACK dgetrf is not available with the pure_rust backend")
+        );
+        assert_eq!(
+            lapack_error(
+                LAPACK_NOT_AVAILABLE,
+                "LAPACK dgeqrf workspace query failed",
+                Error::Backend
+            ),
+            Error::Backend("LAPACK dgeqrf is not available with the pure_rust backend")
+        );
+    }
+}
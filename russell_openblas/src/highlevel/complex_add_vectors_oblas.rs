@@ -22,7 +22,7 @@ pub fn complex_add_vectors_oblas(
     v: &[Complex64],
 ) {
     let n = w.len();
-    let n_i32: i32 = to_i32(n);
+    let n_i32 = to_i32(n);
     // w := v
     zcopy(n_i32, v, 1, w, 1);
     // w := beta * v
@@ -39,22 +39,26 @@ pub type StrError = &'static str;
 mod config;
 mod constants;
 mod conversions;
+mod error;
 mod highlevel;
 mod matrix;
 mod matvec;
+#[cfg(backend_pure_rust)]
+mod pure_rust;
 mod to_i32;
 mod vector;
 pub use crate::config::*;
 use crate::constants::*;
 pub use crate::conversions::*;
+pub use crate::error::*;
 pub use crate::highlevel::*;
 pub use crate::matrix::*;
 pub use crate::matvec::*;
 pub use crate::to_i32::*;
 pub use crate::vector::*;
 
-// run code from README file
-#[cfg(doctest)]
+// run code from README file (the examples require LAPACK)
+#[cfg(all(doctest, not(backend_pure_rust)))]
 mod test_readme {
     macro_rules! external_doc_test {
         ($x:expr) => {
@@ -1,28 +1,51 @@
-use super::{cblas_transpose, cblas_uplo, lapack_job_vlr, lapack_uplo, CBLAS_COL_MAJOR, LAPACK_COL_MAJOR};
-use crate::StrError;
+use super::{
+    cblas_transpose, cblas_uplo, lapack_error, lapack_job_vlr, lapack_side, lapack_transpose, lapack_uplo, to_i32,
+    CBLAS_COL_MAJOR, LAPACK_COL_MAJOR,
+};
+use crate::{BlasInt, Error};
 use num_complex::Complex64;
 
+#[cfg(backend_pure_rust)]
+use crate::pure_rust::*;
+
+#[cfg(not(backend_pure_rust))]
 #[rustfmt::skip]
 extern "C" {
     // from /usr/include/x86_64-linux-gnu/cblas.h
-    fn cblas_dgemm(order: i32, transa: i32, transb: i32, m: i32, n: i32, k: i32, alpha: f64, a: *const f64, lda: i32, b: *const f64, ldb: i32, beta: f64, c: *mut f64, ldc: i32);
-    fn cblas_zgemm(order: i32, transa: i32, transb: i32, m: i32, n: i32, k: i32, alpha: *const Complex64, a: *const Complex64, lda: i32, b: *const Complex64, ldb: i32, beta: *const Complex64, c: *mut Complex64, ldc: i32);
-    fn cblas_dsyrk(order: i32, uplo: i32, trans: i32, n: i32, k: i32, alpha: f64, a: *const f64, lda: i32, beta: f64, c: *mut f64, ldc: i32);
-    fn cblas_zsyrk(order: i32, uplo: i32, trans: i32, n: i32, k: i32, alpha: *const Complex64, a: *const Complex64, lda: i32, beta: *const Complex64, c: *mut Complex64, ldc: i32);
-    fn cblas_zherk(order: i32, uplo: i32, trans: i32, n: i32, k: i32, alpha: f64, a: *const Complex64, lda: i32, beta: f64, c: *const Complex64, ldc: i32);
+    fn cblas_dgemm(order: i32, transa: i32, transb: i32, m: BlasInt, n: BlasInt, k: BlasInt, alpha: f64, a: *const f64, lda: BlasInt, b: *const f64, ldb: BlasInt, beta: f64, c: *mut f64, ldc: BlasInt);
+    fn cblas_sgemm(order: i32, transa: i32, transb: i32, m: BlasInt, n: BlasInt, k: BlasInt, alpha: f32, a: *const f32, lda: BlasInt, b: *const f32, ldb: BlasInt, beta: f32, c: *mut f32, ldc: BlasInt);
+    fn cblas_zgemm(order: i32, transa: i32, transb: i32, m: BlasInt, n: BlasInt, k: BlasInt, alpha: *const Complex64, a: *const Complex64, lda: BlasInt, b: *const Complex64, ldb: BlasInt, beta: *const Complex64, c: *mut Complex64, ldc: BlasInt);
+    fn cblas_dsyrk(order: i32, uplo: i32, trans: i32, n: BlasInt, k: BlasInt, alpha: f64, a: *const f64, lda: BlasInt, beta: f64, c: *mut f64, ldc: BlasInt);
+    fn cblas_zsyrk(order: i32, uplo: i32, trans: i32, n: BlasInt, k: BlasInt, alpha: *const Complex64, a: *const Complex64, lda: BlasInt, beta: *const Complex64, c: *mut Complex64, ldc: BlasInt);
+    fn cblas_zherk(order: i32, uplo: i32, trans: i32, n: BlasInt, k: BlasInt, alpha: f64, a: *const Complex64, lda: BlasInt, beta: f64, c: *const Complex64, ldc: BlasInt);
     // from /usr/include/lapacke.h
-    fn LAPACKE_dlange(matrix_layout: i32, norm: u8, m: i32, n: i32, a: *const f64, lda: i32) -> f64;
-    fn LAPACKE_zlange(matrix_layout: i32, norm: u8, m: i32, n: i32, a: *const Complex64, lda: i32) -> f64;
-    fn LAPACKE_dgesvd(matrix_layout: i32, jobu: u8, jobvt: u8, m: i32, n: i32, a: *mut f64, lda: i32, s: *mut f64, u: *mut f64, ldu: i32, vt: *mut f64, ldvt: i32, superb: *mut f64) -> i32;
-    fn LAPACKE_zgesvd(matrix_layout: i32, jobu: u8, jobvt: u8, m: i32, n: i32, a: *mut Complex64, lda: i32, s: *mut f64, u: *mut Complex64, ldu: i32, vt: *mut Complex64, ldvt: i32, superb: *mut f64) -> i32;
-    fn LAPACKE_dgetrf(matrix_layout: i32, m: i32, n: i32, a: *mut f64, lda: i32, ipiv: *mut i32) -> i32;
-    fn LAPACKE_zgetrf(matrix_layout: i32, m: i32, n: i32, a: *mut Complex64, lda: i32, ipiv: *mut i32) -> i32;
-    fn LAPACKE_dgetri(matrix_layout: i32, n: i32, a: *mut f64, lda: i32, ipiv: *const i32) -> i32;
-    fn LAPACKE_zgetri(matrix_layout: i32, n: i32, a: *mut Complex64, lda: i32, ipiv: *const i32) -> i32;
-    fn LAPACKE_dpotrf(matrix_layout: i32, uplo: u8, n: i32, a: *mut f64, lda: i32) -> i32;
-    fn LAPACKE_zpotrf(matrix_layout: i32, uplo: u8, n: i32, a: *mut Complex64, lda: i32) -> i32;
-    fn LAPACKE_dgeev(matrix_layout: i32, jobvl: u8, jobvr: u8, n: i32, a: *mut f64, lda: i32, wr: *mut f64, wi: *mut f64, vl: *mut f64, ldvl: i32, vr: *mut f64, ldvr: i32) -> i32;
-    fn LAPACKE_dsyev(matrix_layout: i32, jobz: u8, uplo: u8, n: i32, a: *mut f64, lda: i32, w: *mut f64) -> i32;
+    fn LAPACKE_dlange(matrix_layout: i32, norm: u8, m: BlasInt, n: BlasInt, a: *const f64, lda: BlasInt) -> f64;
+    fn LAPACKE_zlange(matrix_layout: i32, norm: u8, m: BlasInt, n: BlasInt, a: *const Complex64, lda: BlasInt) -> f64;
+    fn LAPACKE_dgesvd(matrix_layout: i32, jobu: u8, jobvt: u8, m: BlasInt, n: BlasInt, a: *mut f64, lda: BlasInt, s: *mut f64, u: *mut f64, ldu: BlasInt, vt: *mut f64, ldvt: BlasInt, superb: *mut f64) -> BlasInt;
+    fn LAPACKE_zgesvd(matrix_layout: i32, jobu: u8, jobvt: u8, m: BlasInt, n: BlasInt, a: *mut Complex64, lda: BlasInt, s: *mut f64, u: *mut Complex64, ldu: BlasInt, vt: *mut Complex64, ldvt: BlasInt, superb: *mut f64) -> BlasInt;
+    fn LAPACKE_dgetrf(matrix_layout: i32, m: BlasInt, n: BlasInt, a: *mut f64, lda: BlasInt, ipiv: *mut BlasInt) -> BlasInt;
+    fn LAPACKE_zgetrf(matrix_layout: i32, m: BlasInt, n: BlasInt, a: *mut Complex64, lda: BlasInt, ipiv: *mut BlasInt) -> BlasInt;
+    fn LAPACKE_dgetri(matrix_layout: i32, n: BlasInt, a: *mut f64, lda: BlasInt, ipiv: *const BlasInt) -> BlasInt;
+    fn LAPACKE_zgetri(matrix_layout: i32, n: BlasInt, a: *mut Complex64, lda: BlasInt, ipiv: *const BlasInt) -> BlasInt;
+    fn LAPACKE_dgeequ(matrix_layout: i32, m: BlasInt, n: BlasInt, a: *const f64, lda: BlasInt, r: *mut f64, c: *mut f64, rowcnd: *mut f64, colcnd: *mut f64, amax: *mut f64) -> BlasInt;
+    fn LAPACKE_dlaqge(matrix_layout: i32, m: BlasInt, n: BlasInt, a: *mut f64, lda: BlasInt, r: *const f64, c: *const f64, rowcnd: f64, colcnd: f64, amax: f64, equed: *mut u8) -> BlasInt;
+    fn LAPACKE_dpotrf(matrix_layout: i32, uplo: u8, n: BlasInt, a: *mut f64, lda: BlasInt) -> BlasInt;
+    fn LAPACKE_zpotrf(matrix_layout: i32, uplo: u8, n: BlasInt, a: *mut Complex64, lda: BlasInt) -> BlasInt;
+    fn LAPACKE_dgeev(matrix_layout: i32, jobvl: u8, jobvr: u8, n: BlasInt, a: *mut f64, lda: BlasInt, wr: *mut f64, wi: *mut f64, vl: *mut f64, ldvl: BlasInt, vr: *mut f64, ldvr: BlasInt) -> BlasInt;
+    fn LAPACKE_zgeev(matrix_layout: i32, jobvl: u8, jobvr: u8, n: BlasInt, a: *mut Complex64, lda: BlasInt, w: *mut Complex64, vl: *mut Complex64, ldvl: BlasInt, vr: *mut Complex64, ldvr: BlasInt) -> BlasInt;
+    fn LAPACKE_dsyev(matrix_layout: i32, jobz: u8, uplo: u8, n: BlasInt, a: *mut f64, lda: BlasInt, w: *mut f64) -> BlasInt;
+    fn LAPACKE_dgeqrf_work(matrix_layout: i32, m: BlasInt, n: BlasInt, a: *mut f64, lda: BlasInt, tau: *mut f64, work: *mut f64, lwork: BlasInt) -> BlasInt;
+    fn LAPACKE_dorgqr_work(matrix_layout: i32, m: BlasInt, n: BlasInt, k: BlasInt, a: *mut f64, lda: BlasInt, tau: *const f64, work: *mut f64, lwork: BlasInt) -> BlasInt;
+    fn LAPACKE_dormqr_work(matrix_layout: i32, side: u8, trans: u8, m: BlasInt, n: BlasInt, k: BlasInt, a: *const f64, lda: BlasInt, tau: *const f64, c: *mut f64, ldc: BlasInt, work: *mut f64, lwork: BlasInt) -> BlasInt;
+    fn LAPACKE_dgelqf_work(matrix_layout: i32, m: BlasInt, n: BlasInt, a: *mut f64, lda: BlasInt, tau: *mut f64, work: *mut f64, lwork: BlasInt) -> BlasInt;
+    fn LAPACKE_dgebrd_work(matrix_layout: i32, m: BlasInt, n: BlasInt, a: *mut f64, lda: BlasInt, d: *mut f64, e: *mut f64, tauq: *mut f64, taup: *mut f64, work: *mut f64, lwork: BlasInt) -> BlasInt;
+    fn LAPACKE_dorgbr_work(matrix_layout: i32, vect: u8, m: BlasInt, n: BlasInt, k: BlasInt, a: *mut f64, lda: BlasInt, tau: *const f64, work: *mut f64, lwork: BlasInt) -> BlasInt;
+    fn LAPACKE_dggsvd3(matrix_layout: i32, jobu: u8, jobv: u8, jobq: u8, m: BlasInt, n: BlasInt, p: BlasInt, k: *mut BlasInt, l: *mut BlasInt, a: *mut f64, lda: BlasInt, b: *mut f64, ldb: BlasInt, alpha: *mut f64, beta: *mut f64, u: *mut f64, ldu: BlasInt, v: *mut f64, ldv: BlasInt, q: *mut f64, ldq: BlasInt, iwork: *mut BlasInt) -> BlasInt;
+    fn LAPACKE_dgelsd(matrix_layout: i32, m: BlasInt, n: BlasInt, nrhs: BlasInt, a: *mut f64, lda: BlasInt, b: *mut f64, ldb: BlasInt, s: *mut f64, rcond: f64, rank: *mut BlasInt) -> BlasInt;
+    fn LAPACKE_dgelsy(matrix_layout: i32, m: BlasInt, n: BlasInt, nrhs: BlasInt, a: *mut f64, lda: BlasInt, b: *mut f64, ldb: BlasInt, jpvt: *mut BlasInt, rcond: f64, rank: *mut BlasInt) -> BlasInt;
+    fn LAPACKE_dsyevd(matrix_layout: i32, jobz: u8, uplo: u8, n: BlasInt, a: *mut f64, lda: BlasInt, w: *mut f64) -> BlasInt;
+    fn LAPACKE_dsyevr(matrix_layout: i32, jobz: u8, range: u8, uplo: u8, n: BlasInt, a: *mut f64, lda: BlasInt, vl: f64, vu: f64, il: BlasInt, iu: BlasInt, abstol: f64, m: *mut BlasInt, w: *mut f64, z: *mut f64, ldz: BlasInt, isuppz: *mut BlasInt) -> BlasInt;
+    fn LAPACKE_zheevr(matrix_layout: i32, jobz: u8, range: u8, uplo: u8, n: BlasInt, a: *mut Complex64, lda: BlasInt, vl: f64, vu: f64, il: BlasInt, iu: BlasInt, abstol: f64, m: *mut BlasInt, w: *mut f64, z: *mut Complex64, ldz: BlasInt, isuppz: *mut BlasInt) -> BlasInt;
 }
 
 /// Performs the matrix-matrix multiplication
@@ -72,9 +95,9 @@ extern "C" {
 pub fn dgemm(
     trans_a: bool,
     trans_b: bool,
-    m: i32,
-    n: i32,
-    k: i32,
+    m: BlasInt,
+    n: BlasInt,
+    k: BlasInt,
     alpha: f64,
     a: &[f64],
     b: &[f64],
@@ -103,6 +126,60 @@ pub fn dgemm(
     }
 }
 
+/// Performs the matrix-matrix multiplication (single precision version)
+///
+/// Computes:
+///
+/// ```text
+///   c  := α ⋅ op(a) ⋅ op(b) + β ⋅  c
+/// (m,n)        (m,k)  (k,n)      (m,n)
+/// ```
+///
+/// where `op(x) = xᵀ` if the corresponding `trans` flag is true (see [dgemm])
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <http://www.netlib.org/lapack/explore-html/d4/de2/sgemm_8f.html>
+///
+#[inline]
+pub fn sgemm(
+    trans_a: bool,
+    trans_b: bool,
+    m: BlasInt,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: f32,
+    a: &[f32],
+    b: &[f32],
+    beta: f32,
+    c: &mut [f32],
+) {
+    let lda = if trans_a { k } else { m };
+    let ldb = if trans_b { n } else { k };
+    unsafe {
+        cblas_sgemm(
+            CBLAS_COL_MAJOR,
+            cblas_transpose(trans_a),
+            cblas_transpose(trans_b),
+            m,
+            n,
+            k,
+            alpha,
+            a.as_ptr(),
+            lda,
+            b.as_ptr(),
+            ldb,
+            beta,
+            c.as_mut_ptr(),
+            m,
+        );
+    }
+}
+
 /// Performs the matrix-matrix multiplication (complex version)
 ///
 /// Computes one of:
@@ -150,9 +227,9 @@ pub fn dgemm(
 pub fn zgemm(
     trans_a: bool,
     trans_b: bool,
-    m: i32,
-    n: i32,
-    k: i32,
+    m: BlasInt,
+    n: BlasInt,
+    k: BlasInt,
     alpha: Complex64,
     a: &[Complex64],
     b: &[Complex64],
@@ -204,7 +281,7 @@ pub fn zgemm(
 /// <http://www.netlib.org/lapack/explore-html/dc/d05/dsyrk_8f.html>
 ///
 #[inline]
-pub fn dsyrk(up: bool, trans: bool, n: i32, k: i32, alpha: f64, a: &[f64], beta: f64, c: &mut [f64]) {
+pub fn dsyrk(up: bool, trans: bool, n: BlasInt, k: BlasInt, alpha: f64, a: &[f64], beta: f64, c: &mut [f64]) {
     let lda = if trans { k } else { n };
     unsafe {
         cblas_dsyrk(
@@ -249,8 +326,8 @@ pub fn dsyrk(up: bool, trans: bool, n: i32, k: i32, alpha: f64, a: &[f64], beta:
 pub fn zsyrk(
     up: bool,
     trans: bool,
-    n: i32,
-    k: i32,
+    n: BlasInt,
+    k: BlasInt,
     alpha: Complex64,
     a: &[Complex64],
     beta: Complex64,
@@ -297,7 +374,16 @@ pub fn zsyrk(
 /// <http://www.netlib.org/lapack/explore-html/d1/db1/zherk_8f.html>
 ///
 #[inline]
-pub fn zherk(up: bool, trans: bool, n: i32, k: i32, alpha: f64, a: &[Complex64], beta: f64, c: &mut [Complex64]) {
+pub fn zherk(
+    up: bool,
+    trans: bool,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: f64,
+    a: &[Complex64],
+    beta: f64,
+    c: &mut [Complex64],
+) {
     let lda = if trans { k } else { n };
     unsafe {
         cblas_zherk(
@@ -346,7 +432,7 @@ pub fn zherk(up: bool, trans: bool, n: i32, k: i32, alpha: f64, a: &[Complex64],
 /// <http://www.netlib.org/lapack/explore-html/dc/d09/dlange_8f.html>
 ///
 #[inline]
-pub fn dlange(norm: u8, m: i32, n: i32, a: &[f64]) -> f64 {
+pub fn dlange(norm: u8, m: BlasInt, n: BlasInt, a: &[f64]) -> f64 {
     unsafe { LAPACKE_dlange(LAPACK_COL_MAJOR, norm, m, n, a.as_ptr(), m) }
 }
 
@@ -380,7 +466,7 @@ pub fn dlange(norm: u8, m: i32, n: i32, a: &[f64]) -> f64 {
 /// <http://www.netlib.org/lapack/explore-html/d5/d8f/zlange_8f.html>
 ///
 #[inline]
-pub fn zlange(norm: u8, m: i32, n: i32, a: &[Complex64]) -> f64 {
+pub fn zlange(norm: u8, m: BlasInt, n: BlasInt, a: &[Complex64]) -> f64 {
     unsafe { LAPACKE_zlange(LAPACK_COL_MAJOR, norm, m, n, a.as_ptr(), m) }
 }
 
@@ -407,6 +493,8 @@ pub fn zlange(norm: u8, m: i32, n: i32, a: &[Complex64]) -> f64 {
 /// 3. `jobu` and `jobvt` are c_char and can be passed as b'A'
 ///    (see LAPACK reference for further options)
 /// 4. `superb` is a work area of size min(m,n)-1; e.g., use min(m,n)
+/// 5. With `jobu = b'S'`, `u` is (m,min(m,n)); with `jobvt = b'S'`, `vt` is (min(m,n),n)
+/// 6. With `jobu = b'N'` or `jobvt = b'N'`, `u` or `vt` is not accessed
 ///
 /// # Important
 ///
@@ -420,14 +508,15 @@ pub fn zlange(norm: u8, m: i32, n: i32, a: &[Complex64]) -> f64 {
 pub fn dgesvd(
     jobu: u8,
     jobvt: u8,
-    m: i32,
-    n: i32,
+    m: BlasInt,
+    n: BlasInt,
     a: &mut [f64],
     s: &mut [f64],
     u: &mut [f64],
     vt: &mut [f64],
     superb: &mut [f64],
-) -> Result<(), StrError> {
+) -> Result<(), Error> {
+    let ldvt = if jobvt == b'S' { BlasInt::min(m, n) } else { n };
     unsafe {
         let info = LAPACKE_dgesvd(
             LAPACK_COL_MAJOR,
@@ -441,11 +530,11 @@ pub fn dgesvd(
             u.as_mut_ptr(),
             m,
             vt.as_mut_ptr(),
-            n,
+            BlasInt::max(1, ldvt),
             superb.as_mut_ptr(),
         );
-        if info != 0_i32 {
-            return Err("LAPACK dgesvd failed");
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK dgesvd failed", Error::NotConverged));
         }
     }
     Ok(())
@@ -487,14 +576,14 @@ pub fn dgesvd(
 pub fn zgesvd(
     jobu: u8,
     jobvt: u8,
-    m: i32,
-    n: i32,
+    m: BlasInt,
+    n: BlasInt,
     a: &mut [Complex64],
     s: &mut [f64],
     u: &mut [Complex64],
     vh: &mut [Complex64],
     superb: &mut [f64],
-) -> Result<(), StrError> {
+) -> Result<(), Error> {
     unsafe {
         let info = LAPACKE_zgesvd(
             LAPACK_COL_MAJOR,
@@ -511,8 +600,8 @@ pub fn zgesvd(
             n,
             superb.as_mut_ptr(),
         );
-        if info != 0_i32 {
-            return Err("LAPACK zgesvd failed");
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK zgesvd failed", Error::NotConverged));
         }
     }
     Ok(())
@@ -545,11 +634,11 @@ pub fn zgesvd(
 /// <http://www.netlib.org/lapack/explore-html/d3/d6a/dgetrf_8f.html>
 ///
 #[inline]
-pub fn dgetrf(m: i32, n: i32, a: &mut [f64], ipiv: &mut [i32]) -> Result<(), StrError> {
+pub fn dgetrf(m: BlasInt, n: BlasInt, a: &mut [f64], ipiv: &mut [BlasInt]) -> Result<(), Error> {
     unsafe {
         let info = LAPACKE_dgetrf(LAPACK_COL_MAJOR, m, n, a.as_mut_ptr(), m, ipiv.as_mut_ptr());
-        if info != 0_i32 {
-            return Err("LAPACK dgetrf failed");
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK dgetrf failed", Error::Singular));
         }
     }
     Ok(())
@@ -582,11 +671,11 @@ pub fn dgetrf(m: i32, n: i32, a: &mut [f64], ipiv: &mut [i32]) -> Result<(), Str
 /// <http://www.netlib.org/lapack/explore-html/dd/dd1/zgetrf_8f.html>
 ///
 #[inline]
-pub fn zgetrf(m: i32, n: i32, a: &mut [Complex64], ipiv: &mut [i32]) -> Result<(), StrError> {
+pub fn zgetrf(m: BlasInt, n: BlasInt, a: &mut [Complex64], ipiv: &mut [BlasInt]) -> Result<(), Error> {
     unsafe {
         let info = LAPACKE_zgetrf(LAPACK_COL_MAJOR, m, n, a.as_mut_ptr(), n, ipiv.as_mut_ptr());
-        if info != 0_i32 {
-            return Err("LAPACK zgetrf failed");
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK zgetrf failed", Error::Singular));
         }
     }
     Ok(())
@@ -613,11 +702,11 @@ pub fn zgetrf(m: i32, n: i32, a: &mut [Complex64], ipiv: &mut [i32]) -> Result<(
 /// <http://www.netlib.org/lapack/explore-html/df/da4/dgetri_8f.html>
 ///
 #[inline]
-pub fn dgetri(n: i32, a: &mut [f64], ipiv: &[i32]) -> Result<(), StrError> {
+pub fn dgetri(n: BlasInt, a: &mut [f64], ipiv: &[BlasInt]) -> Result<(), Error> {
     unsafe {
         let info = LAPACKE_dgetri(LAPACK_COL_MAJOR, n, a.as_mut_ptr(), n, ipiv.as_ptr());
-        if info != 0_i32 {
-            return Err("LAPACK dgetri failed");
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK dgetri failed", Error::Singular));
         }
     }
     Ok(())
@@ -644,16 +733,135 @@ pub fn dgetri(n: i32, a: &mut [f64], ipiv: &[i32]) -> Result<(), StrError> {
 /// <http://www.netlib.org/lapack/explore-html/d0/db3/zgetri_8f.html>
 ///
 #[inline]
-pub fn zgetri(n: i32, a: &mut [Complex64], ipiv: &[i32]) -> Result<(), StrError> {
+pub fn zgetri(n: BlasInt, a: &mut [Complex64], ipiv: &[BlasInt]) -> Result<(), Error> {
     unsafe {
         let info = LAPACKE_zgetri(LAPACK_COL_MAJOR, n, a.as_mut_ptr(), n, ipiv.as_ptr());
-        if info != 0_i32 {
-            return Err("LAPACK zgetri failed");
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK zgetri failed", Error::Singular));
         }
     }
     Ok(())
 }
 
+/// Computes row and column scalings intended to equilibrate a general matrix and reduce its condition number
+///
+/// The scale factors are such that
+///
+/// ```text
+/// B = diag(r) ⋅ A ⋅ diag(c)
+/// ```
+///
+/// has its largest element in each row and column with absolute value equal to 1.
+///
+/// # Output
+///
+/// * `r` -- (len = m) the row scale factors
+/// * `c` -- (len = n) the column scale factors
+///
+/// Returns `(rowcnd, colcnd, amax)` where `rowcnd` is the ratio of the smallest to the largest `r[i]`,
+/// `colcnd` is the ratio of the smallest to the largest `c[j]`, and `amax` is the absolute value
+/// of the largest element of A. These values are needed by **dlaqge**.
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/explore-html/d2/de8/dgeequ_8f.html>
+///
+#[inline]
+pub fn dgeequ(m: BlasInt, n: BlasInt, a: &[f64], r: &mut [f64], c: &mut [f64]) -> Result<(f64, f64, f64), Error> {
+    if to_i32(r.len()) != m || to_i32(c.len()) != n {
+        return Err(Error::BadDimensions(
+            "the lengths of r and c must equal m and n, respectively",
+        ));
+    }
+    let mut rowcnd = 0.0;
+    let mut colcnd = 0.0;
+    let mut amax = 0.0;
+    unsafe {
+        let info = LAPACKE_dgeequ(
+            LAPACK_COL_MAJOR,
+            m,
+            n,
+            a.as_ptr(),
+            BlasInt::max(1, m),
+            r.as_mut_ptr(),
+            c.as_mut_ptr(),
+            &mut rowcnd,
+            &mut colcnd,
+            &mut amax,
+        );
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK dgeequ failed", Error::Singular));
+        }
+    }
+    Ok((rowcnd, colcnd, amax))
+}
+
+/// Equilibrates a general matrix using the row and column scaling factors computed by dgeequ
+///
+/// The scaling is only applied when it is worthwhile (i.e., when `rowcnd` or `colcnd` is
+/// small or when `amax` is close to underflow or overflow):
+///
+/// ```text
+/// A := diag(r) ⋅ A ⋅ diag(c)
+/// ```
+///
+/// Returns the form of equilibration that was done:
+///
+/// * `b'N'` -- no equilibration
+/// * `b'R'` -- row equilibration, i.e., A has been premultiplied by diag(r)
+/// * `b'C'` -- column equilibration, i.e., A has been postmultiplied by diag(c)
+/// * `b'B'` -- both row and column equilibration
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/explore-html/d4/d5b/dlaqge_8f.html>
+///
+#[inline]
+pub fn dlaqge(
+    m: BlasInt,
+    n: BlasInt,
+    a: &mut [f64],
+    r: &[f64],
+    c: &[f64],
+    rowcnd: f64,
+    colcnd: f64,
+    amax: f64,
+) -> Result<u8, Error> {
+    if to_i32(r.len()) != m || to_i32(c.len()) != n {
+        return Err(Error::BadDimensions(
+            "the lengths of r and c must equal m and n, respectively",
+        ));
+    }
+    let mut equed = b'N';
+    unsafe {
+        let info = LAPACKE_dlaqge(
+            LAPACK_COL_MAJOR,
+            m,
+            n,
+            a.as_mut_ptr(),
+            BlasInt::max(1, m),
+            r.as_ptr(),
+            c.as_ptr(),
+            rowcnd,
+            colcnd,
+            amax,
+            &mut equed,
+        );
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK dlaqge failed", Error::InvalidArgument));
+        }
+    }
+    Ok(equed)
+}
+
 /// Computes the Cholesky factorization of a real symmetric positive definite matrix
 ///
 /// The factorization has the form
@@ -681,11 +889,11 @@ pub fn zgetri(n: i32, a: &mut [Complex64], ipiv: &[i32]) -> Result<(), StrError>
 /// <http://www.netlib.org/lapack/explore-html/d0/d8a/dpotrf_8f.html>
 ///
 #[inline]
-pub fn dpotrf(up: bool, n: i32, a: &mut [f64]) -> Result<(), StrError> {
+pub fn dpotrf(up: bool, n: BlasInt, a: &mut [f64]) -> Result<(), Error> {
     unsafe {
         let info = LAPACKE_dpotrf(LAPACK_COL_MAJOR, lapack_uplo(up), n, a.as_mut_ptr(), n);
-        if info != 0_i32 {
-            return Err("LAPACK dpotrf failed");
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK dpotrf failed", Error::Singular));
         }
     }
     Ok(())
@@ -718,42 +926,68 @@ pub fn dpotrf(up: bool, n: i32, a: &mut [f64]) -> Result<(), StrError> {
 /// <http://www.netlib.org/lapack/explore-html/d1/db9/zpotrf_8f.html>
 ///
 #[inline]
-pub fn zpotrf(up: bool, n: i32, a: &mut [Complex64]) -> Result<(), StrError> {
+pub fn zpotrf(up: bool, n: BlasInt, a: &mut [Complex64]) -> Result<(), Error> {
     unsafe {
         let info = LAPACKE_zpotrf(LAPACK_COL_MAJOR, lapack_uplo(up), n, a.as_mut_ptr(), n);
-        if info != 0_i32 {
-            return Err("LAPACK zpotrf failed");
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK zpotrf failed", Error::Singular));
         }
     }
     Ok(())
 }
 
-/// Computes the eigenvalues and eigenvectors of a general matrix
+/// Returns the optimal length of the workspace for dgeqrf (workspace query)
 ///
-/// The right eigenvector v(j) of A satisfies
+/// # Reference
 ///
-/// ```text
-/// A ⋅ v(j) = lambda(j) ⋅ v(j)
-/// ```
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/dgeqrf.f.html>
 ///
-/// where lambda(j) is its eigenvalue.
+#[inline]
+pub fn dgeqrf_lwork(m: BlasInt, n: BlasInt) -> Result<usize, Error> {
+    let mut work = [0.0];
+    unsafe {
+        let info = LAPACKE_dgeqrf_work(
+            LAPACK_COL_MAJOR,
+            m,
+            n,
+            [0.0].as_mut_ptr(),
+            BlasInt::max(1, m),
+            [0.0].as_mut_ptr(),
+            work.as_mut_ptr(),
+            -1,
+        );
+        if info != 0 {
+            return Err(lapack_error(
+                info,
+                "LAPACK dgeqrf workspace query failed",
+                Error::Backend,
+            ));
+        }
+    }
+    Ok(usize::max(1, work[0] as usize))
+}
+
+/// Computes the QR factorization of a general matrix
 ///
-/// The left eigenvector u(j) of A satisfies
+/// The factorization has the form
 ///
 /// ```text
-/// u(j)ᴴ ⋅ A = lambda(j) ⋅ u(j)ᴴ
+///   A  =   Q  ⋅  R
+/// (m,n)  (m,m) (m,n)
 /// ```
 ///
-/// where u(j)ᴴ denotes the conjugate-transpose of u(j).
+/// where Q is an orthogonal matrix and R is an upper triangular (trapezoidal if m < n) matrix.
 ///
-/// The computed eigenvectors are normalized to have Euclidean norm
-/// equal to 1 and largest component real.
+/// # Output
+///
+/// * `a` -- on exit, the elements on and above the diagonal contain R; the elements below the
+///   diagonal, together with `tau`, represent Q as a product of min(m,n) elementary reflectors
+/// * `tau` -- (len = min(m,n)) the scalar factors of the elementary reflectors
 ///
 /// # Notes
 ///
-/// 1. The matrix will be modified
-/// 2. If calc_vl==false, you may pass an empty array
-/// 3. If calc_vr==false, you may pass an empty array
+/// * `work` is a work area with len ≥ max(1,n); use [dgeqrf_lwork] to obtain the optimal length
+/// * Use [dorgqr] to generate Q explicitly or [dormqr] to multiply a matrix by Q
 ///
 /// # Important
 ///
@@ -761,59 +995,77 @@ pub fn zpotrf(up: bool, n: i32, a: &mut [Complex64]) -> Result<(), StrError> {
 ///
 /// # Reference
 ///
-/// <http://www.netlib.org/lapack/explore-html/d9/d28/dgeev_8f.html>
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/dgeqrf.f.html>
 ///
 #[inline]
-pub fn dgeev(
-    calc_vl: bool,
-    calc_vr: bool,
-    n: i32,
-    a: &mut [f64],
-    wr: &mut [f64],
-    wi: &mut [f64],
-    vl: &mut [f64],
-    vr: &mut [f64],
-) -> Result<(), StrError> {
-    let ldvl = if calc_vl { n } else { 1 };
-    let ldvr = if calc_vr { n } else { 1 };
+pub fn dgeqrf(m: BlasInt, n: BlasInt, a: &mut [f64], tau: &mut [f64], work: &mut [f64]) -> Result<(), Error> {
     unsafe {
-        let info = LAPACKE_dgeev(
+        let info = LAPACKE_dgeqrf_work(
             LAPACK_COL_MAJOR,
-            lapack_job_vlr(calc_vl),
-            lapack_job_vlr(calc_vr),
+            m,
             n,
             a.as_mut_ptr(),
-            n,
-            wr.as_mut_ptr(),
-            wi.as_mut_ptr(),
-            vl.as_mut_ptr(),
-            ldvl,
-            vr.as_mut_ptr(),
-            ldvr,
+            BlasInt::max(1, m),
+            tau.as_mut_ptr(),
+            work.as_mut_ptr(),
+            work.len() as BlasInt,
         );
-        if info != 0_i32 {
-            return Err("LAPACK dgeev failed");
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK dgeqrf failed", Error::Backend));
         }
     }
     Ok(())
 }
 
-/// Computes the eigenvalues and eigenvectors of a symmetric matrix
+/// Returns the optimal length of the workspace for dorgqr (workspace query)
 ///
-/// The eigenvector v(j) of A satisfies
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/dorgqr.f.html>
+///
+#[inline]
+pub fn dorgqr_lwork(m: BlasInt, n: BlasInt, k: BlasInt) -> Result<usize, Error> {
+    let mut work = [0.0];
+    unsafe {
+        let info = LAPACKE_dorgqr_work(
+            LAPACK_COL_MAJOR,
+            m,
+            n,
+            k,
+            [0.0].as_mut_ptr(),
+            BlasInt::max(1, m),
+            [0.0].as_ptr(),
+            work.as_mut_ptr(),
+            -1,
+        );
+        if info != 0 {
+            return Err(lapack_error(
+                info,
+                "LAPACK dorgqr workspace query failed",
+                Error::Backend,
+            ));
+        }
+    }
+    Ok(usize::max(1, work[0] as usize))
+}
+
+/// Generates the (m × n) matrix Q with orthonormal columns defined by the elementary reflectors computed by dgeqrf
+///
+/// Q is defined as the first n columns of the product of k elementary reflectors:
 ///
 /// ```text
-/// A ⋅ v(j) = lambda(j) ⋅ v(j)
+/// Q = H(1) ⋅ H(2) ⋯ H(k)
 /// ```
 ///
-/// where lambda(j) is its eigenvalue.
+/// # Input
 ///
-/// The computed eigenvectors are normalized to have Euclidean norm
-/// equal to 1 and largest component real.
+/// * `m`, `n`, `k` -- the dimensions with m ≥ n ≥ k ≥ 0
+/// * `a` -- (m × n) the reflectors as returned by dgeqrf in the first k columns; on exit, it contains Q
+/// * `tau` -- (len = k) the scalar factors of the elementary reflectors as returned by dgeqrf
 ///
 /// # Notes
 ///
-/// * The matrix will be modified (it will contain the eigenvectors as columns)
+/// * `work` is a work area with len ≥ max(1,n); use [dorgqr_lwork] to obtain the optimal length
 ///
 /// # Important
 ///
@@ -821,43 +1073,1046 @@ pub fn dgeev(
 ///
 /// # Reference
 ///
-/// <https://netlib.org/lapack/explore-html/dd/d4c/dsyev_8f.html>
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/dorgqr.f.html>
 ///
 #[inline]
-pub fn dsyev(calc_v: bool, up: bool, n: i32, a: &mut [f64], w: &mut [f64]) -> Result<(), StrError> {
+pub fn dorgqr(m: BlasInt, n: BlasInt, k: BlasInt, a: &mut [f64], tau: &[f64], work: &mut [f64]) -> Result<(), Error> {
     unsafe {
-        let info = LAPACKE_dsyev(
+        let info = LAPACKE_dorgqr_work(
             LAPACK_COL_MAJOR,
-            lapack_job_vlr(calc_v),
-            lapack_uplo(up),
+            m,
             n,
+            k,
             a.as_mut_ptr(),
-            n,
-            w.as_mut_ptr(),
+            BlasInt::max(1, m),
+            tau.as_ptr(),
+            work.as_mut_ptr(),
+            work.len() as BlasInt,
         );
-        if info != 0_i32 {
-            return Err("LAPACK dsyev failed");
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK dorgqr failed", Error::Backend));
         }
     }
     Ok(())
 }
 
-////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+/// Returns the optimal length of the workspace for dormqr (workspace query)
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/dormqr.f.html>
+///
+#[inline]
+pub fn dormqr_lwork(left: bool, trans: bool, m: BlasInt, n: BlasInt, k: BlasInt) -> Result<usize, Error> {
+    let lda = if left { m } else { n };
+    let mut work = [0.0];
+    unsafe {
+        let info = LAPACKE_dormqr_work(
+            LAPACK_COL_MAJOR,
+            lapack_side(left),
+            lapack_transpose(trans),
+            m,
+            n,
+            k,
+            [0.0].as_ptr(),
+            BlasInt::max(1, lda),
+            [0.0].as_ptr(),
+            [0.0].as_mut_ptr(),
+            BlasInt::max(1, m),
+            work.as_mut_ptr(),
+            -1,
+        );
+        if info != 0 {
+            return Err(lapack_error(
+                info,
+                "LAPACK dormqr workspace query failed",
+                Error::Backend,
+            ));
+        }
+    }
+    Ok(usize::max(1, work[0] as usize))
+}
 
-#[cfg(test)]
-mod tests {
-    use super::{
-        dgeev, dgemm, dgesvd, dgetrf, dgetri, dlange, dpotrf, dsyev, dsyrk, zgemm, zgesvd, zgetrf, zgetri, zherk,
-        zlange, zpotrf, zsyrk,
-    };
-    use crate::conversions::{col_major, col_major_complex, dgeev_data, dgeev_data_lr};
-    use crate::{to_i32, StrError};
-    use num_complex::{Complex64, ComplexFloat};
-    use russell_chk::{approx_eq, complex_approx_eq, complex_vec_approx_eq, vec_approx_eq};
+/// Multiplies a general matrix by the orthogonal matrix Q defined by the elementary reflectors computed by dgeqrf
+///
+/// Computes one of:
+///
+/// ```text
+/// left = true,  trans = false:  c := Q  ⋅ c
+/// left = true,  trans = true:   c := Qᵀ ⋅ c
+/// left = false, trans = false:  c := c ⋅ Q
+/// left = false, trans = true:   c := c ⋅ Qᵀ
+/// ```
+///
+/// # Input
+///
+/// * `m`, `n` -- the dimensions of the (m × n) matrix c
+/// * `k` -- the number of elementary reflectors (k ≤ m if left; k ≤ n otherwise)
+/// * `a` -- (m × k if left; n × k otherwise) the reflectors as returned by dgeqrf
+/// * `tau` -- (len = k) the scalar factors of the elementary reflectors as returned by dgeqrf
+/// * `c` -- (m × n) the matrix to be overwritten by the product
+///
+/// # Notes
+///
+/// * `work` is a work area with len ≥ max(1,n) if left or len ≥ max(1,m) otherwise;
+///   use [dormqr_lwork] to obtain the optimal length
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/dormqr.f.html>
+///
+#[inline]
+pub fn dormqr(
+    left: bool,
+    trans: bool,
+    m: BlasInt,
+    n: BlasInt,
+    k: BlasInt,
+    a: &[f64],
+    tau: &[f64],
+    c: &mut [f64],
+    work: &mut [f64],
+) -> Result<(), Error> {
+    let lda = if left { m } else { n };
+    unsafe {
+        let info = LAPACKE_dormqr_work(
+            LAPACK_COL_MAJOR,
+            lapack_side(left),
+            lapack_transpose(trans),
+            m,
+            n,
+            k,
+            a.as_ptr(),
+            BlasInt::max(1, lda),
+            tau.as_ptr(),
+            c.as_mut_ptr(),
+            BlasInt::max(1, m),
+            work.as_mut_ptr(),
+            work.len() as BlasInt,
+        );
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK dormqr failed", Error::Backend));
+        }
+    }
+    Ok(())
+}
 
-    #[test]
-    fn dgemm_notrans_notrans_works() {
-        // 0.5⋅a⋅b + 2⋅c
+/// Returns the optimal length of the workspace for dgelqf (workspace query)
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/dgelqf.f.html>
+///
+#[inline]
+pub fn dgelqf_lwork(m: BlasInt, n: BlasInt) -> Result<usize, Error> {
+    let mut work = [0.0];
+    unsafe {
+        let info = LAPACKE_dgelqf_work(
+            LAPACK_COL_MAJOR,
+            m,
+            n,
+            [0.0].as_mut_ptr(),
+            BlasInt::max(1, m),
+            [0.0].as_mut_ptr(),
+            work.as_mut_ptr(),
+            -1,
+        );
+        if info != 0 {
+            return Err(lapack_error(
+                info,
+                "LAPACK dgelqf workspace query failed",
+                Error::Backend,
+            ));
+        }
+    }
+    Ok(usize::max(1, work[0] as usize))
+}
+
+/// Computes the LQ factorization of a general matrix
+///
+/// The factorization has the form
+///
+/// ```text
+///   A  =   L  ⋅  Q
+/// (m,n)  (m,n) (n,n)
+/// ```
+///
+/// where L is a lower triangular (trapezoidal if m > n) matrix and Q is an orthogonal matrix.
+///
+/// # Output
+///
+/// * `a` -- on exit, the elements on and below the diagonal contain L; the elements above the
+///   diagonal, together with `tau`, represent Q as a product of min(m,n) elementary reflectors
+/// * `tau` -- (len = min(m,n)) the scalar factors of the elementary reflectors
+///
+/// # Notes
+///
+/// * `work` is a work area with len ≥ max(1,m); use [dgelqf_lwork] to obtain the optimal length
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/dgelqf.f.html>
+///
+#[inline]
+pub fn dgelqf(m: BlasInt, n: BlasInt, a: &mut [f64], tau: &mut [f64], work: &mut [f64]) -> Result<(), Error> {
+    unsafe {
+        let info = LAPACKE_dgelqf_work(
+            LAPACK_COL_MAJOR,
+            m,
+            n,
+            a.as_mut_ptr(),
+            BlasInt::max(1, m),
+            tau.as_mut_ptr(),
+            work.as_mut_ptr(),
+            work.len() as BlasInt,
+        );
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK dgelqf failed", Error::Backend));
+        }
+    }
+    Ok(())
+}
+
+/// Returns the optimal length of the workspace for dgebrd (workspace query)
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/dgebrd.f.html>
+///
+#[inline]
+pub fn dgebrd_lwork(m: BlasInt, n: BlasInt) -> Result<usize, Error> {
+    let mut work = [0.0];
+    unsafe {
+        let info = LAPACKE_dgebrd_work(
+            LAPACK_COL_MAJOR,
+            m,
+            n,
+            [0.0].as_mut_ptr(),
+            BlasInt::max(1, m),
+            [0.0].as_mut_ptr(),
+            [0.0].as_mut_ptr(),
+            [0.0].as_mut_ptr(),
+            [0.0].as_mut_ptr(),
+            work.as_mut_ptr(),
+            -1,
+        );
+        if info != 0 {
+            return Err(lapack_error(
+                info,
+                "LAPACK dgebrd workspace query failed",
+                Error::Backend,
+            ));
+        }
+    }
+    Ok(usize::max(1, work[0] as usize))
+}
+
+/// Reduces a general matrix to bidiagonal form by an orthogonal transformation
+///
+/// The reduction has the form
+///
+/// ```text
+///   A  =   Q  ⋅  B  ⋅  Pᵀ
+/// (m,n)  (m,m) (m,n) (n,n)
+/// ```
+///
+/// where Q and P are orthogonal matrices and B is upper bidiagonal if m ≥ n or
+/// lower bidiagonal if m < n.
+///
+/// # Output
+///
+/// * `a` -- on exit, the diagonal and the first superdiagonal (if m ≥ n) or the first
+///   subdiagonal (if m < n) contain B; the other elements, together with `tauq` and `taup`,
+///   represent Q and P as products of elementary reflectors
+/// * `d` -- (len = min(m,n)) the diagonal elements of B
+/// * `e` -- (len = min(m,n) - 1) the off-diagonal elements of B
+/// * `tauq` -- (len = min(m,n)) the scalar factors of the elementary reflectors representing Q
+/// * `taup` -- (len = min(m,n)) the scalar factors of the elementary reflectors representing P
+///
+/// # Notes
+///
+/// * `work` is a work area with len ≥ max(1,m,n); use [dgebrd_lwork] to obtain the optimal length
+/// * Use [dorgbr] to generate Q or Pᵀ explicitly
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/dgebrd.f.html>
+///
+#[inline]
+#[allow(clippy::too_many_arguments)]
+pub fn dgebrd(
+    m: BlasInt,
+    n: BlasInt,
+    a: &mut [f64],
+    d: &mut [f64],
+    e: &mut [f64],
+    tauq: &mut [f64],
+    taup: &mut [f64],
+    work: &mut [f64],
+) -> Result<(), Error> {
+    unsafe {
+        let info = LAPACKE_dgebrd_work(
+            LAPACK_COL_MAJOR,
+            m,
+            n,
+            a.as_mut_ptr(),
+            BlasInt::max(1, m),
+            d.as_mut_ptr(),
+            e.as_mut_ptr(),
+            tauq.as_mut_ptr(),
+            taup.as_mut_ptr(),
+            work.as_mut_ptr(),
+            work.len() as BlasInt,
+        );
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK dgebrd failed", Error::Backend));
+        }
+    }
+    Ok(())
+}
+
+/// Returns the optimal length of the workspace for dorgbr (workspace query)
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/dorgbr.f.html>
+///
+#[inline]
+pub fn dorgbr_lwork(want_q: bool, m: BlasInt, n: BlasInt, k: BlasInt) -> Result<usize, Error> {
+    let mut work = [0.0];
+    let vect = if want_q { b'Q' } else { b'P' };
+    unsafe {
+        let info = LAPACKE_dorgbr_work(
+            LAPACK_COL_MAJOR,
+            vect,
+            m,
+            n,
+            k,
+            [0.0].as_mut_ptr(),
+            BlasInt::max(1, m),
+            [0.0].as_ptr(),
+            work.as_mut_ptr(),
+            -1,
+        );
+        if info != 0 {
+            return Err(lapack_error(
+                info,
+                "LAPACK dorgbr workspace query failed",
+                Error::Backend,
+            ));
+        }
+    }
+    Ok(usize::max(1, work[0] as usize))
+}
+
+/// Generates one of the orthogonal matrices Q or Pᵀ determined by dgebrd
+///
+/// If `want_q`, generates the (m × n) matrix with the first n columns of Q, where the
+/// original matrix reduced by dgebrd was (m × k); the dimensions must satisfy m ≥ n ≥ min(m,k).
+///
+/// Otherwise, generates the (m × n) matrix with the first m rows of Pᵀ, where the original
+/// matrix reduced by dgebrd was (k × n); the dimensions must satisfy n ≥ m ≥ min(n,k).
+///
+/// # Input
+///
+/// * `a` -- (m × n) the reflectors as returned by dgebrd (stored with leading dimension m);
+///   on exit, it contains Q or Pᵀ
+/// * `tau` -- (len = min(m,k) if want_q; min(n,k) otherwise) the `tauq` or `taup` factors as returned by dgebrd
+///
+/// # Notes
+///
+/// * `work` is a work area with len ≥ max(1,min(m,n)); use [dorgbr_lwork] to obtain the optimal length
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/dorgbr.f.html>
+///
+#[inline]
+pub fn dorgbr(
+    want_q: bool,
+    m: BlasInt,
+    n: BlasInt,
+    k: BlasInt,
+    a: &mut [f64],
+    tau: &[f64],
+    work: &mut [f64],
+) -> Result<(), Error> {
+    let vect = if want_q { b'Q' } else { b'P' };
+    unsafe {
+        let info = LAPACKE_dorgbr_work(
+            LAPACK_COL_MAJOR,
+            vect,
+            m,
+            n,
+            k,
+            a.as_mut_ptr(),
+            BlasInt::max(1, m),
+            tau.as_ptr(),
+            work.as_mut_ptr(),
+            work.len() as BlasInt,
+        );
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK dorgbr failed", Error::Backend));
+        }
+    }
+    Ok(())
+}
+
+/// Computes the generalized singular value decomposition (GSVD) of a pair of matrices
+///
+/// The GSVD is written as follows:
+///
+/// ```text
+///   A  =   U  ⋅  D1  ⋅ [0 R] ⋅  Qᵀ
+/// (m,n)  (m,m) (m,r)   (r,n)  (n,n)
+///
+///   B  =   V  ⋅  D2  ⋅ [0 R] ⋅  Qᵀ
+/// (p,n)  (p,p) (p,r)   (r,n)  (n,n)
+/// ```
+///
+/// where U, V, and Q are orthogonal matrices, r = k + l is the effective numerical rank of
+/// the (m+p,n) matrix [Aᵀ,Bᵀ]ᵀ, and R is a (r,r) nonsingular upper triangular matrix.
+/// D1 and D2 are "diagonal" matrices defined by `alpha` and `beta`; in particular,
+/// if m - k - l ≥ 0:
+///
+/// ```text
+///                   k  l                        k  l
+///      D1 =   k  [  I  0 ]         D2 =   l  [  0  S ]
+///             l  [  0  C ]                p-l[  0  0 ]
+///         m-k-l  [  0  0 ]
+///
+/// C = diag(alpha[k..k+l])    S = diag(beta[k..k+l])    C² + S² = I
+/// ```
+///
+/// See the LAPACK reference for the case m - k - l < 0.
+///
+/// # Output
+///
+/// * Returns `(k, l)`
+/// * `a` -- on exit, contains the triangular matrix R, or part of R if m - k - l < 0
+/// * `b` -- on exit, contains part of R if m - k - l < 0
+/// * `alpha` -- (len = n) the generalized singular value pairs (alpha, beta)
+/// * `beta` -- (len = n) the generalized singular value pairs (alpha, beta)
+/// * `u` -- (m,m) the orthogonal matrix U
+/// * `v` -- (p,p) the orthogonal matrix V
+/// * `q` -- (n,n) the orthogonal matrix Q
+///
+/// # Notes
+///
+/// 1. `jobu`, `jobv`, and `jobq` are c_char and can be passed as b'U', b'V', and b'Q'
+///    to compute U, V, and Q, respectively, or as b'N' to skip the computation
+/// 2. `iwork` is a work area of size n, which stores the sorting information on exit
+/// 3. The ratios alpha\[i\]/beta\[i\] for i in k..k+l are the generalized singular values
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://github.com/Reference-LAPACK/lapack/blob/master/SRC/dggsvd3.f>
+///
+#[inline]
+#[allow(clippy::too_many_arguments)]
+pub fn dggsvd3(
+    jobu: u8,
+    jobv: u8,
+    jobq: u8,
+    m: BlasInt,
+    n: BlasInt,
+    p: BlasInt,
+    a: &mut [f64],
+    b: &mut [f64],
+    alpha: &mut [f64],
+    beta: &mut [f64],
+    u: &mut [f64],
+    v: &mut [f64],
+    q: &mut [f64],
+    iwork: &mut [BlasInt],
+) -> Result<(BlasInt, BlasInt), Error> {
+    let mut k: BlasInt = 0;
+    let mut l: BlasInt = 0;
+    unsafe {
+        let info = LAPACKE_dggsvd3(
+            LAPACK_COL_MAJOR,
+            jobu,
+            jobv,
+            jobq,
+            m,
+            n,
+            p,
+            &mut k,
+            &mut l,
+            a.as_mut_ptr(),
+            BlasInt::max(1, m),
+            b.as_mut_ptr(),
+            BlasInt::max(1, p),
+            alpha.as_mut_ptr(),
+            beta.as_mut_ptr(),
+            u.as_mut_ptr(),
+            BlasInt::max(1, m),
+            v.as_mut_ptr(),
+            BlasInt::max(1, p),
+            q.as_mut_ptr(),
+            BlasInt::max(1, n),
+            iwork.as_mut_ptr(),
+        );
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK dggsvd3 failed", Error::NotConverged));
+        }
+    }
+    Ok((k, l))
+}
+
+/// Computes the minimum-norm solution to a linear least squares problem using the SVD
+///
+/// Solves:
+///
+/// ```text
+/// minimize ‖ b - A ⋅ x ‖₂
+/// ```
+///
+/// where A is an (m × n) matrix which may be rank-deficient. Several right-hand sides
+/// can be handled in a single call; they are stored as the columns of the (ldb × nrhs) matrix b.
+///
+/// The effective rank of A is determined by treating as zero those singular values
+/// which are less than `rcond` times the largest singular value.
+///
+/// # Input
+///
+/// * `a` -- (m × n) the matrix; it will be modified (destroyed)
+/// * `b` -- (ldb × nrhs) with ldb = max(1,m,n); on exit, the first n rows contain the solution x
+/// * `rcond` -- the relative threshold for the singular values; if rcond < 0, the machine precision is used
+///
+/// # Output
+///
+/// * `s` -- (len = min(m,n)) the singular values of A in decreasing order
+///
+/// Returns the effective rank of A.
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/dgelsd.f.html>
+///
+#[inline]
+pub fn dgelsd(
+    m: BlasInt,
+    n: BlasInt,
+    nrhs: BlasInt,
+    a: &mut [f64],
+    b: &mut [f64],
+    s: &mut [f64],
+    rcond: f64,
+) -> Result<usize, Error> {
+    let mut rank = 0;
+    unsafe {
+        let info = LAPACKE_dgelsd(
+            LAPACK_COL_MAJOR,
+            m,
+            n,
+            nrhs,
+            a.as_mut_ptr(),
+            BlasInt::max(1, m),
+            b.as_mut_ptr(),
+            BlasInt::max(1, BlasInt::max(m, n)),
+            s.as_mut_ptr(),
+            rcond,
+            &mut rank,
+        );
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK dgelsd failed", Error::NotConverged));
+        }
+    }
+    Ok(rank as usize)
+}
+
+/// Computes the minimum-norm solution to a linear least squares problem using a complete orthogonal factorization
+///
+/// Solves:
+///
+/// ```text
+/// minimize ‖ b - A ⋅ x ‖₂
+/// ```
+///
+/// where A is an (m × n) matrix which may be rank-deficient. The routine first computes a QR
+/// factorization with column pivoting; the effective rank is the order of the largest leading
+/// triangular submatrix of R with estimated condition number less than 1/rcond.
+///
+/// # Input
+///
+/// * `a` -- (m × n) the matrix; it will be modified (destroyed)
+/// * `b` -- (ldb × nrhs) with ldb = max(1,m,n); on exit, the first n rows contain the solution x
+/// * `jpvt` -- (len = n) if jpvt\[j\] ≠ 0, the j-th column of A is permuted to the front; otherwise it is
+///   a free column. On exit, jpvt\[j\] = k means that the j-th column of A⋅P was the k-th column of A (one-based)
+/// * `rcond` -- the threshold used to determine the effective rank
+///
+/// Returns the effective rank of A.
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/dgelsy.f.html>
+///
+#[inline]
+pub fn dgelsy(
+    m: BlasInt,
+    n: BlasInt,
+    nrhs: BlasInt,
+    a: &mut [f64],
+    b: &mut [f64],
+    jpvt: &mut [BlasInt],
+    rcond: f64,
+) -> Result<usize, Error> {
+    let mut rank = 0;
+    unsafe {
+        let info = LAPACKE_dgelsy(
+            LAPACK_COL_MAJOR,
+            m,
+            n,
+            nrhs,
+            a.as_mut_ptr(),
+            BlasInt::max(1, m),
+            b.as_mut_ptr(),
+            BlasInt::max(1, BlasInt::max(m, n)),
+            jpvt.as_mut_ptr(),
+            rcond,
+            &mut rank,
+        );
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK dgelsy failed", Error::Backend));
+        }
+    }
+    Ok(rank as usize)
+}
+
+/// Computes the eigenvalues and eigenvectors of a general matrix
+///
+/// The right eigenvector v(j) of A satisfies
+///
+/// ```text
+/// A ⋅ v(j) = lambda(j) ⋅ v(j)
+/// ```
+///
+/// where lambda(j) is its eigenvalue.
+///
+/// The left eigenvector u(j) of A satisfies
+///
+/// ```text
+/// u(j)ᴴ ⋅ A = lambda(j) ⋅ u(j)ᴴ
+/// ```
+///
+/// where u(j)ᴴ denotes the conjugate-transpose of u(j).
+///
+/// The computed eigenvectors are normalized to have Euclidean norm
+/// equal to 1 and largest component real.
+///
+/// # Notes
+///
+/// 1. The matrix will be modified
+/// 2. If calc_vl==false, you may pass an empty array
+/// 3. If calc_vr==false, you may pass an empty array
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <http://www.netlib.org/lapack/explore-html/d9/d28/dgeev_8f.html>
+///
+#[inline]
+pub fn dgeev(
+    calc_vl: bool,
+    calc_vr: bool,
+    n: BlasInt,
+    a: &mut [f64],
+    wr: &mut [f64],
+    wi: &mut [f64],
+    vl: &mut [f64],
+    vr: &mut [f64],
+) -> Result<(), Error> {
+    let ldvl = if calc_vl { n } else { 1 };
+    let ldvr = if calc_vr { n } else { 1 };
+    unsafe {
+        let info = LAPACKE_dgeev(
+            LAPACK_COL_MAJOR,
+            lapack_job_vlr(calc_vl),
+            lapack_job_vlr(calc_vr),
+            n,
+            a.as_mut_ptr(),
+            n,
+            wr.as_mut_ptr(),
+            wi.as_mut_ptr(),
+            vl.as_mut_ptr(),
+            ldvl,
+            vr.as_mut_ptr(),
+            ldvr,
+        );
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK dgeev failed", Error::NotConverged));
+        }
+    }
+    Ok(())
+}
+
+/// Computes the eigenvalues and eigenvectors of a general matrix (complex version)
+///
+/// The right eigenvector v(j) of A satisfies
+///
+/// ```text
+/// A ⋅ v(j) = lambda(j) ⋅ v(j)
+/// ```
+///
+/// where lambda(j) is its eigenvalue.
+///
+/// The left eigenvector u(j) of A satisfies
+///
+/// ```text
+/// u(j)ᴴ ⋅ A = lambda(j) ⋅ u(j)ᴴ
+/// ```
+///
+/// where u(j)ᴴ denotes the conjugate-transpose of u(j).
+///
+/// The computed eigenvectors are normalized to have Euclidean norm
+/// equal to 1 and largest component real.
+///
+/// # Notes
+///
+/// 1. The matrix will be modified
+/// 2. If calc_vl==false, you may pass an empty array
+/// 3. If calc_vr==false, you may pass an empty array
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/zgeev.f.html>
+///
+#[inline]
+pub fn zgeev(
+    calc_vl: bool,
+    calc_vr: bool,
+    n: BlasInt,
+    a: &mut [Complex64],
+    w: &mut [Complex64],
+    vl: &mut [Complex64],
+    vr: &mut [Complex64],
+) -> Result<(), Error> {
+    let ldvl = if calc_vl { n } else { 1 };
+    let ldvr = if calc_vr { n } else { 1 };
+    unsafe {
+        let info = LAPACKE_zgeev(
+            LAPACK_COL_MAJOR,
+            lapack_job_vlr(calc_vl),
+            lapack_job_vlr(calc_vr),
+            n,
+            a.as_mut_ptr(),
+            n,
+            w.as_mut_ptr(),
+            vl.as_mut_ptr(),
+            ldvl,
+            vr.as_mut_ptr(),
+            ldvr,
+        );
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK zgeev failed", Error::NotConverged));
+        }
+    }
+    Ok(())
+}
+
+/// Computes the eigenvalues and eigenvectors of a symmetric matrix
+///
+/// The eigenvector v(j) of A satisfies
+///
+/// ```text
+/// A ⋅ v(j) = lambda(j) ⋅ v(j)
+/// ```
+///
+/// where lambda(j) is its eigenvalue.
+///
+/// The computed eigenvectors are normalized to have Euclidean norm
+/// equal to 1 and largest component real.
+///
+/// # Notes
+///
+/// * The matrix will be modified (it will contain the eigenvectors as columns)
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://netlib.org/lapack/explore-html/dd/d4c/dsyev_8f.html>
+///
+#[inline]
+pub fn dsyev(calc_v: bool, up: bool, n: BlasInt, a: &mut [f64], w: &mut [f64]) -> Result<(), Error> {
+    unsafe {
+        let info = LAPACKE_dsyev(
+            LAPACK_COL_MAJOR,
+            lapack_job_vlr(calc_v),
+            lapack_uplo(up),
+            n,
+            a.as_mut_ptr(),
+            n,
+            w.as_mut_ptr(),
+        );
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK dsyev failed", Error::NotConverged));
+        }
+    }
+    Ok(())
+}
+
+/// Computes the eigenvalues and eigenvectors of a symmetric matrix using the divide and conquer algorithm
+///
+/// The eigenvector v(j) of A satisfies
+///
+/// ```text
+/// A ⋅ v(j) = lambda(j) ⋅ v(j)
+/// ```
+///
+/// where lambda(j) is its eigenvalue. The eigenvalues are returned in ascending order.
+///
+/// The divide and conquer algorithm is much faster than the QR algorithm (dsyev) for large
+/// matrices when the eigenvectors are required; however, it needs more workspace.
+///
+/// # Notes
+///
+/// * The matrix will be modified (it will contain the eigenvectors as columns)
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/dsyevd.f.html>
+///
+#[inline]
+pub fn dsyevd(calc_v: bool, up: bool, n: BlasInt, a: &mut [f64], w: &mut [f64]) -> Result<(), Error> {
+    unsafe {
+        let info = LAPACKE_dsyevd(
+            LAPACK_COL_MAJOR,
+            lapack_job_vlr(calc_v),
+            lapack_uplo(up),
+            n,
+            a.as_mut_ptr(),
+            n,
+            w.as_mut_ptr(),
+        );
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK dsyevd failed", Error::NotConverged));
+        }
+    }
+    Ok(())
+}
+
+/// Specifies the subset of eigenvalues (and eigenvectors) computed by the expert drivers dsyevr and zheevr
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EigenRange {
+    /// All eigenvalues
+    All,
+
+    /// The eigenvalues in the half-open interval (vl, vu]
+    Values(f64, f64),
+
+    /// The il-th through iu-th eigenvalues (one-based and inclusive, i.e., 1 ≤ il ≤ iu ≤ n)
+    Indices(BlasInt, BlasInt),
+}
+
+/// Returns the LAPACK arguments (range, vl, vu, il, iu) corresponding to EigenRange
+fn lapack_range(range: EigenRange) -> (u8, f64, f64, BlasInt, BlasInt) {
+    match range {
+        EigenRange::All => (b'A', 0.0, 0.0, 0, 0),
+        EigenRange::Values(vl, vu) => (b'V', vl, vu, 0, 0),
+        EigenRange::Indices(il, iu) => (b'I', 0.0, 0.0, il, iu),
+    }
+}
+
+/// Computes selected eigenvalues and eigenvectors of a symmetric matrix (expert driver)
+///
+/// The eigenvector v(j) of A satisfies
+///
+/// ```text
+/// A ⋅ v(j) = lambda(j) ⋅ v(j)
+/// ```
+///
+/// where lambda(j) is its eigenvalue. Uses the Relatively Robust Representations (MRRR) algorithm,
+/// which is efficient when only part of the spectrum is required.
+///
+/// # Input
+///
+/// * `calc_v` -- compute the eigenvectors
+/// * `range` -- the subset of eigenvalues to be computed
+/// * `up` -- the upper triangle of `a` is referenced; otherwise the lower triangle is referenced
+/// * `n` -- the dimension of the (n × n) matrix
+/// * `a` -- the matrix; it will be modified (destroyed)
+///
+/// # Output
+///
+/// * `w` -- (len ≥ n) the first m entries will hold the selected eigenvalues in ascending order
+/// * `z` -- (len ≥ n × m_max) the first m columns will hold the eigenvectors if `calc_v`;
+///   m_max is the maximum number of selected eigenvalues (n for All and Values; iu - il + 1 for Indices).
+///   You may pass an empty array if `calc_v == false`
+///
+/// Returns the number m of eigenvalues found.
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/dsyevr.f.html>
+///
+#[inline]
+pub fn dsyevr(
+    calc_v: bool,
+    range: EigenRange,
+    up: bool,
+    n: BlasInt,
+    a: &mut [f64],
+    w: &mut [f64],
+    z: &mut [f64],
+) -> Result<usize, Error> {
+    let (range, vl, vu, il, iu) = lapack_range(range);
+    let ldz = if calc_v { n } else { 1 };
+    let mut m = 0;
+    let mut isuppz = vec![0; if n > 0 { 2 * n as usize } else { 2 }];
+    unsafe {
+        let info = LAPACKE_dsyevr(
+            LAPACK_COL_MAJOR,
+            lapack_job_vlr(calc_v),
+            range,
+            lapack_uplo(up),
+            n,
+            a.as_mut_ptr(),
+            n,
+            vl,
+            vu,
+            il,
+            iu,
+            0.0,
+            &mut m,
+            w.as_mut_ptr(),
+            z.as_mut_ptr(),
+            ldz,
+            isuppz.as_mut_ptr(),
+        );
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK dsyevr failed", Error::NotConverged));
+        }
+    }
+    Ok(m as usize)
+}
+
+/// Computes selected eigenvalues and eigenvectors of a complex Hermitian matrix (expert driver)
+///
+/// The eigenvector v(j) of A satisfies
+///
+/// ```text
+/// A ⋅ v(j) = lambda(j) ⋅ v(j)
+/// ```
+///
+/// where lambda(j) is its (real) eigenvalue. See [dsyevr] for the description of the arguments.
+///
+/// Returns the number m of eigenvalues found.
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/zheevr.f.html>
+///
+#[inline]
+pub fn zheevr(
+    calc_v: bool,
+    range: EigenRange,
+    up: bool,
+    n: BlasInt,
+    a: &mut [Complex64],
+    w: &mut [f64],
+    z: &mut [Complex64],
+) -> Result<usize, Error> {
+    let (range, vl, vu, il, iu) = lapack_range(range);
+    let ldz = if calc_v { n } else { 1 };
+    let mut m = 0;
+    let mut isuppz = vec![0; if n > 0 { 2 * n as usize } else { 2 }];
+    unsafe {
+        let info = LAPACKE_zheevr(
+            LAPACK_COL_MAJOR,
+            lapack_job_vlr(calc_v),
+            range,
+            lapack_uplo(up),
+            n,
+            a.as_mut_ptr(),
+            n,
+            vl,
+            vu,
+            il,
+            iu,
+            0.0,
+            &mut m,
+            w.as_mut_ptr(),
+            z.as_mut_ptr(),
+            ldz,
+            isuppz.as_mut_ptr(),
+        );
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK zheevr failed", Error::NotConverged));
+        }
+    }
+    Ok(m as usize)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+#[cfg_attr(backend_pure_rust, allow(dead_code, unused_imports))]
+mod tests {
+    use super::{
+        dgebrd, dgebrd_lwork, dgeequ, dgeev, dgelqf, dgelqf_lwork, dgelsd, dgelsy, dgemm, dgeqrf, dgeqrf_lwork, dgesvd,
+        dgetrf, dgetri, dggsvd3, dlange, dlaqge, dorgbr, dorgbr_lwork, dorgqr, dorgqr_lwork, dormqr, dormqr_lwork,
+        dpotrf, dsyev, dsyevd, dsyevr, dsyrk, sgemm, zgeev, zgemm, zgesvd, zgetrf, zgetri, zheevr, zherk, zlange,
+        zpotrf, zsyrk, EigenRange,
+    };
+    use crate::conversions::{col_major, col_major_complex, dgeev_data, dgeev_data_lr};
+    use crate::{to_i32, Error, StrError};
+    use num_complex::{Complex64, ComplexFloat};
+    use russell_chk::{approx_eq, complex_approx_eq, complex_vec_approx_eq, vec_approx_eq};
+
+    #[test]
+    fn dgemm_notrans_notrans_works() {
+        // 0.5⋅a⋅b + 2⋅c
 
         // allocate matrices
         #[rustfmt::skip]
@@ -1049,6 +2304,44 @@ mod tests {
         vec_approx_eq(&c, &correct, 1e-15);
     }
 
+    #[test]
+    fn sgemm_works() {
+        // 0.5⋅aᵀ⋅b + 2⋅c (same data and results as in the dgemm tests)
+        #[rustfmt::skip]
+        let a: Vec<f32> = col_major(5, 4, &[ // (k, m) = (5, 4)
+             1.0,  2.0,  1.0, 4.0,
+             2.0,  3.0,  2.0, 0.0,
+             0.0, -1.0,  0.0, 3.0,
+             1.0,  1.0,  4.0, 1.0,
+            -1.0,  1.0, -1.0, 1.0,
+        ]).iter().map(|x| *x as f32).collect();
+        #[rustfmt::skip]
+        let b: Vec<f32> = col_major(5, 3, &[ // (k, n) = (5, 3)
+            1.0, 0.0, 0.0,
+            0.0, 0.0, 3.0,
+            0.0, 0.0, 1.0,
+            1.0, 0.0, 1.0,
+            0.0, 2.0, 0.0,
+        ]).iter().map(|x| *x as f32).collect();
+        #[rustfmt::skip]
+        let mut c: Vec<f32> = col_major(4, 3, &[ // (m, n) = (4, 3)
+             0.50, 0.0,  0.25,
+             0.25, 0.0, -0.25,
+            -0.25, 0.0,  0.00,
+            -0.25, 0.0,  0.00,
+        ]).iter().map(|x| *x as f32).collect();
+        sgemm(true, false, 4, 3, 5, 0.5, &a, &b, 2.0, &mut c);
+        #[rustfmt::skip]
+        let correct = col_major(4, 3, &[
+            2.0, -1.0, 4.0,
+            2.0,  1.0, 4.0,
+            2.0, -1.0, 5.0,
+            2.0,  1.0, 2.0,
+        ]);
+        let c_f64: Vec<f64> = c.iter().map(|x| *x as f64).collect();
+        vec_approx_eq(&c_f64, &correct, 1e-6);
+    }
+
     #[test]
     fn zgemm_notrans_notrans_works() {
         // (0.5-2i)⋅a⋅b + (2-4i)⋅c
@@ -1117,7 +2410,7 @@ mod tests {
         ]);
 
         // n-size
-        let n = 4_i32; // =c.ncol
+        let n = 4; // =c.ncol
 
         // matrix a
         #[rustfmt::skip]
@@ -1129,7 +2422,7 @@ mod tests {
         ]);
 
         // k-size
-        let k = 6_i32; // =a.ncol
+        let k = 6; // =a.ncol
 
         // constants
         let (alpha, beta) = (3.0, -1.0);
@@ -1181,7 +2474,7 @@ mod tests {
         ]);
 
         // n-size
-        let n = 4_i32; // =c.ncol
+        let n = 4; // =c.ncol
 
         // matrix a
         #[rustfmt::skip]
@@ -1193,7 +2486,7 @@ mod tests {
         ]);
 
         // k-size
-        let k = 6_i32; // =a.ncol
+        let k = 6; // =a.ncol
 
         // constants
         let (alpha, beta) = (Complex64::new(3.0, 0.0), Complex64::new(1.0, 0.0));
@@ -1245,7 +2538,7 @@ mod tests {
         ]);
 
         // n-size
-        let n = 4_i32; // =c.ncol
+        let n = 4; // =c.ncol
 
         // matrix a
         #[rustfmt::skip]
@@ -1257,7 +2550,7 @@ mod tests {
         ]);
 
         // k-size
-        let k = 6_i32; // =a.ncol
+        let k = 6; // =a.ncol
 
         // constants
         let (alpha, beta) = (3.0, 1.0);
@@ -1380,6 +2673,7 @@ mod tests {
         approx_eq(norm_max, b[8].abs(), 1e-15);
     }
 
+    #[cfg(not(backend_pure_rust))]
     #[test]
     fn dgesvd_captures_errors() {
         let (m, n) = (2_usize, 3_usize);
@@ -1401,10 +2695,11 @@ mod tests {
                 &mut vt,
                 &mut superb,
             ),
-            Err("LAPACK dgesvd failed")
+            Err(Error::InvalidArgument("LAPACK dgesvd failed"))
         );
     }
 
+    #[cfg(not(backend_pure_rust))]
     #[test]
     fn dgesvd_works() -> Result<(), StrError> {
         // matrix
@@ -1457,6 +2752,7 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(not(backend_pure_rust))]
     #[test]
     fn dgesvd_1_works() -> Result<(), StrError> {
         // matrix
@@ -1480,10 +2776,54 @@ mod tests {
         let mut vt = vec![0.0; (n * n) as usize];
         let mut superb = vec![0.0; min_mn as usize];
 
-        // perform SVD
+        // perform SVD
+        dgesvd(
+            b'A',
+            b'A',
+            to_i32(m),
+            to_i32(n),
+            &mut a,
+            &mut s,
+            &mut u,
+            &mut vt,
+            &mut superb,
+        )?;
+
+        // check
+        let s_correct = &[2.0, 2.0 / f64::sqrt(3.0), 2.0 / f64::sqrt(3.0)];
+        vec_approx_eq(&s, s_correct, 1e-15);
+
+        // check SVD
+        let mut usv = vec![0.0; m * n];
+        for i in 0..m {
+            for j in 0..n {
+                for k in 0..min_mn {
+                    usv[i + j * m] += u[i + k * m] * s[k] * vt[k + j * n];
+                }
+            }
+        }
+        vec_approx_eq(&usv, &a_copy, 1e-15);
+        Ok(())
+    }
+
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn dgesvd_thin_works() -> Result<(), StrError> {
+        // wide matrix: vt is (min_mn,n) with leading dimension min_mn
+        #[rustfmt::skip]
+        let mut a = col_major(2, 3, &[
+            3.0, 2.0,  2.0,
+            2.0, 3.0, -2.0,
+        ]);
+        let a_copy = a.to_vec();
+        let (m, n, min_mn) = (2_usize, 3_usize, 2_usize);
+        let mut s = vec![0.0; min_mn];
+        let mut u = vec![0.0; m * min_mn];
+        let mut vt = vec![0.0; min_mn * n];
+        let mut superb = vec![0.0; min_mn];
         dgesvd(
-            b'A',
-            b'A',
+            b'S',
+            b'S',
             to_i32(m),
             to_i32(n),
             &mut a,
@@ -1492,24 +2832,36 @@ mod tests {
             &mut vt,
             &mut superb,
         )?;
-
-        // check
-        let s_correct = &[2.0, 2.0 / f64::sqrt(3.0), 2.0 / f64::sqrt(3.0)];
-        vec_approx_eq(&s, s_correct, 1e-15);
-
-        // check SVD
+        vec_approx_eq(&s, &[5.0, 3.0], 1e-14);
         let mut usv = vec![0.0; m * n];
         for i in 0..m {
             for j in 0..n {
                 for k in 0..min_mn {
-                    usv[i + j * m] += u[i + k * m] * s[k] * vt[k + j * n];
+                    usv[i + j * m] += u[i + k * m] * s[k] * vt[k + j * min_mn];
                 }
             }
         }
-        vec_approx_eq(&usv, &a_copy, 1e-15);
+        vec_approx_eq(&usv, &a_copy, 1e-14);
+
+        // singular values only: u and vt are not accessed
+        let mut a = a_copy.clone();
+        let mut s = vec![0.0; min_mn];
+        dgesvd(
+            b'N',
+            b'N',
+            to_i32(m),
+            to_i32(n),
+            &mut a,
+            &mut s,
+            &mut [],
+            &mut [],
+            &mut superb,
+        )?;
+        vec_approx_eq(&s, &[5.0, 3.0], 1e-14);
         Ok(())
     }
 
+    #[cfg(not(backend_pure_rust))]
     #[test]
     fn zgesvd_captures_errors() {
         let (m, n) = (2_usize, 3_usize);
@@ -1531,10 +2883,11 @@ mod tests {
                 &mut vh,
                 &mut superb,
             ),
-            Err("LAPACK zgesvd failed")
+            Err(Error::InvalidArgument("LAPACK zgesvd failed"))
         );
     }
 
+    #[cfg(not(backend_pure_rust))]
     #[test]
     fn zgesvd_works_1() -> Result<(), StrError> {
         // matrix
@@ -1586,6 +2939,7 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(not(backend_pure_rust))]
     #[test]
     fn zgesvd_works_2() -> Result<(), StrError> {
         // matrix
@@ -1642,6 +2996,69 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn dgeequ_and_dlaqge_capture_errors() {
+        let a = vec![1.0, 0.0, 2.0, 0.0]; // second row is zero
+        let mut r = vec![0.0; 2];
+        let mut c = vec![0.0; 2];
+        let mut c_wrong = vec![0.0; 3];
+        assert_eq!(
+            dgeequ(2, 2, &a, &mut r, &mut c_wrong),
+            Err(Error::BadDimensions(
+                "the lengths of r and c must equal m and n, respectively"
+            ))
+        );
+        assert_eq!(
+            dgeequ(2, 2, &a, &mut r, &mut c),
+            Err(Error::Singular("LAPACK dgeequ failed"))
+        );
+        let mut a = a.clone();
+        assert_eq!(
+            dlaqge(2, 2, &mut a, &r, &c_wrong, 1.0, 1.0, 1.0),
+            Err(Error::BadDimensions(
+                "the lengths of r and c must equal m and n, respectively"
+            ))
+        );
+    }
+
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn dgeequ_and_dlaqge_work() -> Result<(), StrError> {
+        // badly scaled rows
+        #[rustfmt::skip]
+        let mut a = col_major(2, 2, &[
+            1e6,  2e6,
+            3e-3, 4e-3,
+        ]);
+        let mut r = vec![0.0; 2];
+        let mut c = vec![0.0; 2];
+        let (rowcnd, colcnd, amax) = dgeequ(2, 2, &a, &mut r, &mut c)?;
+        vec_approx_eq(&r, &[0.5e-6, 250.0], 1e-15);
+        vec_approx_eq(&c, &[1.0 / 0.75, 1.0], 1e-15);
+        approx_eq(rowcnd, 2e-9, 1e-20);
+        approx_eq(colcnd, 0.75, 1e-15);
+        assert_eq!(amax, 2e6);
+
+        // only the rows are scaled because colcnd ≥ 0.1
+        let equed = dlaqge(2, 2, &mut a, &r, &c, rowcnd, colcnd, amax)?;
+        assert_eq!(equed, b'R');
+        #[rustfmt::skip]
+        let correct = col_major(2, 2, &[
+            0.50, 1.0,
+            0.75, 1.0,
+        ]);
+        vec_approx_eq(&a, &correct, 1e-15);
+
+        // well scaled matrix: no equilibration
+        let (rowcnd, colcnd, amax) = dgeequ(2, 2, &a, &mut r, &mut c)?;
+        let equed = dlaqge(2, 2, &mut a, &r, &c, rowcnd, colcnd, amax)?;
+        assert_eq!(equed, b'N');
+        vec_approx_eq(&a, &correct, 1e-15);
+        Ok(())
+    }
+
+    #[cfg(not(backend_pure_rust))]
     #[test]
     fn dgetrf_and_dgetri_capture_errors() {
         let (m, n) = (2, 2);
@@ -1649,11 +3066,18 @@ mod tests {
         let m_i32 = to_i32(m);
         let n_i32 = to_i32(n);
         let mut a = vec![0.0; m * n];
-        let mut ipiv = vec![0_i32; min_mn];
-        assert_eq!(dgetrf(m_i32, n_i32, &mut a, &mut ipiv), Err("LAPACK dgetrf failed"));
-        assert_eq!(dgetri(n_i32, &mut a, &ipiv), Err("LAPACK dgetri failed"));
+        let mut ipiv = vec![0; min_mn];
+        assert_eq!(
+            dgetrf(m_i32, n_i32, &mut a, &mut ipiv),
+            Err(Error::Singular("LAPACK dgetrf failed"))
+        );
+        assert_eq!(
+            dgetri(n_i32, &mut a, &ipiv),
+            Err(Error::Singular("LAPACK dgetri failed"))
+        );
     }
 
+    #[cfg(not(backend_pure_rust))]
     #[test]
     fn dgetrf_and_dgetri_work() -> Result<(), StrError> {
         // matrix
@@ -1671,11 +3095,11 @@ mod tests {
         // run dgetrf
         let m_i32 = to_i32(m);
         let n_i32 = to_i32(n);
-        let mut ipiv = vec![0_i32; min_mn];
+        let mut ipiv = vec![0; min_mn];
         dgetrf(m_i32, n_i32, &mut a, &mut ipiv)?;
 
         // check ipiv
-        let ipiv_correct = &[4_i32, 2_i32, 3_i32, 4_i32];
+        let ipiv_correct = &[4, 2, 3, 4];
         assert_eq!(ipiv, ipiv_correct);
 
         // check LU
@@ -1718,6 +3142,7 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(not(backend_pure_rust))]
     #[test]
     fn zgetrf_and_zgetri_capture_errors() {
         let (m, n) = (2, 2);
@@ -1725,11 +3150,18 @@ mod tests {
         let m_i32 = to_i32(m);
         let n_i32 = to_i32(n);
         let mut a = vec![Complex64::new(0.0, 0.0); m * n];
-        let mut ipiv = vec![0_i32; min_mn];
-        assert_eq!(zgetrf(m_i32, n_i32, &mut a, &mut ipiv), Err("LAPACK zgetrf failed"));
-        assert_eq!(zgetri(n_i32, &mut a, &ipiv), Err("LAPACK zgetri failed"));
+        let mut ipiv = vec![0; min_mn];
+        assert_eq!(
+            zgetrf(m_i32, n_i32, &mut a, &mut ipiv),
+            Err(Error::Singular("LAPACK zgetrf failed"))
+        );
+        assert_eq!(
+            zgetri(n_i32, &mut a, &ipiv),
+            Err(Error::Singular("LAPACK zgetri failed"))
+        );
     }
 
+    #[cfg(not(backend_pure_rust))]
     #[test]
     fn zgetrf_and_zgetri_work() -> Result<(), StrError> {
         // matrix
@@ -1747,11 +3179,11 @@ mod tests {
         // run zgetrf
         let m_i32 = to_i32(m);
         let n_i32 = to_i32(n);
-        let mut ipiv = vec![0_i32; min_mn];
+        let mut ipiv = vec![0; min_mn];
         zgetrf(m_i32, n_i32, &mut a, &mut ipiv)?;
 
         // check ipiv
-        let ipiv_correct = &[4_i32, 2_i32, 3_i32, 4_i32];
+        let ipiv_correct = &[4, 2, 3, 4];
         assert_eq!(ipiv, ipiv_correct);
 
         // check LU
@@ -1796,12 +3228,14 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(not(backend_pure_rust))]
     #[test]
     fn dpotrf_captures_errors() {
         let mut a = vec![0.0; 4];
-        assert_eq!(dpotrf(true, 2_i32, &mut a), Err("LAPACK dpotrf failed"));
+        assert_eq!(dpotrf(true, 2, &mut a), Err(Error::Singular("LAPACK dpotrf failed")));
     }
 
+    #[cfg(not(backend_pure_rust))]
     #[test]
     fn dpotrf_works() -> Result<(), StrError> {
         // matrix a
@@ -1821,7 +3255,7 @@ mod tests {
         ]);
 
         // n-size
-        let n = 4_i32; // =a.ncol
+        let n = 4; // =a.ncol
 
         // run dpotrf with up part of matrix a
         dpotrf(true, n, &mut a_up)?;
@@ -1851,12 +3285,14 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(not(backend_pure_rust))]
     #[test]
     fn zpotrf_captures_errors() {
         let mut a = vec![Complex64::new(0.0, 0.0); 4];
-        assert_eq!(zpotrf(true, 2_i32, &mut a), Err("LAPACK zpotrf failed"));
+        assert_eq!(zpotrf(true, 2, &mut a), Err(Error::Singular("LAPACK zpotrf failed")));
     }
 
+    #[cfg(not(backend_pure_rust))]
     #[test]
     fn zpotrf_works() -> Result<(), StrError> {
         // matrix a
@@ -1876,7 +3312,7 @@ mod tests {
         ]);
 
         // n-size
-        let n = 4_i32; // =a.ncol
+        let n = 4; // =a.ncol
 
         // run zpotrf with up part of matrix a
         zpotrf(true, n, &mut a_up)?;
@@ -1906,6 +3342,7 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(not(backend_pure_rust))]
     #[test]
     fn dgeev_captures_errors() {
         let m = 1_usize;
@@ -1914,13 +3351,14 @@ mod tests {
         let mut wi = vec![0.0; m]; // eigenvalues (imaginary part)
         let mut vl = vec![0.0; m * m]; // left eigenvectors
         let mut vr = vec![0.0; m * m]; // right eigenvectors
-        let wrong = -1_i32; // <<< wrong
+        let wrong = -1; // <<< wrong
         assert_eq!(
             dgeev(true, true, wrong, &mut a, &mut wr, &mut wi, &mut vl, &mut vr),
-            Err("LAPACK dgeev failed")
+            Err(Error::InvalidArgument("LAPACK dgeev failed"))
         );
     }
 
+    #[cfg(not(backend_pure_rust))]
     #[test]
     fn dgeev_works() -> Result<(), StrError> {
         // matrix a
@@ -1935,7 +3373,7 @@ mod tests {
         let mut a_copy2 = a.to_vec();
 
         // n-size
-        let n = 4_i32; // =a.nrow=a.ncol
+        let n = 4; // =a.nrow=a.ncol
 
         // eigen-arrays
         let sz = n as usize;
@@ -2049,15 +3487,85 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn zgeev_captures_errors() {
+        let mut a = vec![Complex64::new(0.0, 0.0); 1];
+        let mut w = vec![Complex64::new(0.0, 0.0); 1];
+        let mut vl = vec![Complex64::new(0.0, 0.0); 1];
+        let mut vr = vec![Complex64::new(0.0, 0.0); 1];
+        let wrong = -1; // <<< wrong
+        assert_eq!(
+            zgeev(true, true, wrong, &mut a, &mut w, &mut vl, &mut vr),
+            Err(Error::InvalidArgument("LAPACK zgeev failed"))
+        );
+    }
+
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn zgeev_works() -> Result<(), StrError> {
+        #[rustfmt::skip]
+        let a_copy = col_major_complex(3, 3, &[
+            Complex64::new(1.0, 0.0), Complex64::new(0.0, 2.0), Complex64::new( 0.0,  0.0),
+            Complex64::new(3.0, 0.0), Complex64::new(4.0, 0.0), Complex64::new( 1.0, -1.0),
+            Complex64::new(0.0, 0.0), Complex64::new(0.0, 1.0), Complex64::new(-2.0,  0.0),
+        ]);
+        let n = 3;
+        let zero = Complex64::new(0.0, 0.0);
+        let mut a = a_copy.clone();
+        let mut w = vec![zero; 3];
+        let mut vl = vec![zero; 9];
+        let mut vr = vec![zero; 9];
+        zgeev(true, true, n, &mut a, &mut w, &mut vl, &mut vr)?;
+
+        // check eigenvalues (mpmath.eig)
+        let mut w_sorted = w.clone();
+        w_sorted.sort_by(|a, b| a.re.partial_cmp(&b.re).unwrap());
+        let w_correct = &[
+            Complex64::new(-2.109864105184971, -0.19863960367582006),
+            Complex64::new(0.380770742573713, -1.2973996393562115),
+            Complex64::new(4.729093362611256, 1.4960392430320317),
+        ];
+        complex_vec_approx_eq(&w_sorted, w_correct, 1e-14);
+
+        // check eigenvectors
+        for j in 0..3 {
+            for i in 0..3 {
+                // right: (A ⋅ v)ᵢ = λ vᵢ
+                let mut a_times_v_i = zero;
+                // left: (uᴴ ⋅ A)ᵢ = λ uᵢ*
+                let mut uh_times_a_i = zero;
+                for k in 0..3 {
+                    a_times_v_i += a_copy[i + k * 3] * vr[k + j * 3];
+                    uh_times_a_i += vl[k + j * 3].conj() * a_copy[k + i * 3];
+                }
+                complex_approx_eq(a_times_v_i, w[j] * vr[i + j * 3], 1e-14);
+                complex_approx_eq(uh_times_a_i, w[j] * vl[i + j * 3].conj(), 1e-14);
+            }
+        }
+
+        // eigenvalues only
+        let mut a = a_copy.clone();
+        let mut w_only = vec![zero; 3];
+        zgeev(false, false, n, &mut a, &mut w_only, &mut [], &mut [])?;
+        complex_vec_approx_eq(&w_only, &w, 1e-14);
+        Ok(())
+    }
+
+    #[cfg(not(backend_pure_rust))]
     #[test]
     fn dsyev_captures_errors() {
         let m = 1_usize;
         let mut a = vec![0.0; m * m];
         let mut w = vec![0.0; m]; // eigenvalues (real part)
-        let wrong = -1_i32; // <<< wrong
-        assert_eq!(dsyev(true, true, wrong, &mut a, &mut w), Err("LAPACK dsyev failed"));
+        let wrong = -1; // <<< wrong
+        assert_eq!(
+            dsyev(true, true, wrong, &mut a, &mut w),
+            Err(Error::InvalidArgument("LAPACK dsyev failed"))
+        );
     }
 
+    #[cfg(not(backend_pure_rust))]
     #[test]
     fn dsyev_works() -> Result<(), StrError> {
         // matrix a
@@ -2088,7 +3596,7 @@ mod tests {
         let a_copy = a_full.to_vec();
 
         // n-size
-        let n = 5_i32; // =a.nrow=a.ncol
+        let n = 5; // =a.nrow=a.ncol
 
         // eigen-arrays
         let sz = n as usize;
@@ -2133,6 +3641,583 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn qr_functions_capture_errors() {
+        let mut a = vec![0.0; 4];
+        let mut tau = vec![0.0; 2];
+        let mut c = vec![0.0; 4];
+        let mut work = vec![0.0; 2];
+        let wrong = -1; // <<< wrong
+        assert_eq!(
+            dgeqrf_lwork(wrong, 2),
+            Err(Error::InvalidArgument("LAPACK dgeqrf workspace query failed"))
+        );
+        assert_eq!(
+            dorgqr_lwork(wrong, 2, 2),
+            Err(Error::InvalidArgument("LAPACK dorgqr workspace query failed"))
+        );
+        assert_eq!(
+            dormqr_lwork(true, true, wrong, 2, 2),
+            Err(Error::InvalidArgument("LAPACK dormqr workspace query failed"))
+        );
+        assert_eq!(
+            dgelqf_lwork(wrong, 2),
+            Err(Error::InvalidArgument("LAPACK dgelqf workspace query failed"))
+        );
+        assert_eq!(
+            dgeqrf(wrong, 2, &mut a, &mut tau, &mut work),
+            Err(Error::InvalidArgument("LAPACK dgeqrf failed"))
+        );
+        assert_eq!(
+            dorgqr(wrong, 2, 2, &mut a, &tau, &mut work),
+            Err(Error::InvalidArgument("LAPACK dorgqr failed"))
+        );
+        assert_eq!(
+            dormqr(true, true, wrong, 2, 2, &a, &tau, &mut c, &mut work),
+            Err(Error::InvalidArgument("LAPACK dormqr failed"))
+        );
+        assert_eq!(
+            dgelqf(wrong, 2, &mut a, &mut tau, &mut work),
+            Err(Error::InvalidArgument("LAPACK dgelqf failed"))
+        );
+        // workspace too small
+        assert_eq!(
+            dgeqrf(2, 2, &mut a, &mut tau, &mut []),
+            Err(Error::InvalidArgument("LAPACK dgeqrf failed"))
+        );
+    }
+
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn qr_functions_work() -> Result<(), StrError> {
+        // matrix a
+        #[rustfmt::skip]
+        let a_copy = col_major(4, 3, &[
+            1.0, 2.0,  3.0,
+            4.0, 5.0,  6.0,
+            7.0, 8.0, 10.0,
+            1.0, 0.0,  1.0,
+        ]);
+        let (m, n) = (4, 3);
+
+        // factorize
+        let mut a = a_copy.clone();
+        let mut tau = vec![0.0; 3];
+        let lwork = dgeqrf_lwork(m, n)?;
+        assert!(lwork >= 3);
+        let mut work = vec![0.0; lwork];
+        dgeqrf(m, n, &mut a, &mut tau, &mut work)?;
+
+        // extract R
+        let mut r = vec![0.0; 9];
+        for j in 0..3 {
+            for i in 0..(j + 1) {
+                r[i + j * 3] = a[i + j * 4];
+            }
+        }
+        approx_eq(r[0], -f64::sqrt(67.0), 1e-14);
+
+        // Qᵀ ⋅ a = [R; 0]
+        let mut c = a_copy.clone();
+        let lwork = dormqr_lwork(true, true, m, n, 3)?;
+        let mut work = vec![0.0; lwork];
+        dormqr(true, true, m, n, 3, &a, &tau, &mut c, &mut work)?;
+        for j in 0..3 {
+            for i in 0..4 {
+                let correct = if i <= j { r[i + j * 3] } else { 0.0 };
+                approx_eq(c[i + j * 4], correct, 1e-13);
+            }
+        }
+
+        // generate Q
+        let lwork = dorgqr_lwork(m, n, 3)?;
+        let mut work = vec![0.0; lwork];
+        let mut q = a.clone();
+        dorgqr(m, n, 3, &mut q, &tau, &mut work)?;
+
+        // Q ⋅ R = a
+        let mut qr = vec![0.0; 12];
+        dgemm(false, false, m, n, n, 1.0, &q, &r, 0.0, &mut qr);
+        vec_approx_eq(&qr, &a_copy, 1e-13);
+
+        // Qᵀ ⋅ Q = I
+        let mut qtq = vec![0.0; 9];
+        dgemm(true, false, n, n, m, 1.0, &q, &q, 0.0, &mut qtq);
+        vec_approx_eq(&qtq, &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0], 1e-14);
+
+        // LQ factorization of aᵀ: L = Rᵀ
+        let mut at = vec![0.0; 12];
+        for i in 0..4 {
+            for j in 0..3 {
+                at[j + i * 3] = a_copy[i + j * 4];
+            }
+        }
+        let lwork = dgelqf_lwork(n, m)?;
+        let mut work = vec![0.0; lwork];
+        dgelqf(n, m, &mut at, &mut tau, &mut work)?;
+        for j in 0..3 {
+            for i in j..3 {
+                approx_eq(at[i + j * 3], r[j + i * 3], 1e-13);
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn bidiag_functions_capture_errors() {
+        let mut a = vec![0.0; 4];
+        let mut d = vec![0.0; 2];
+        let mut e = vec![0.0; 1];
+        let mut tauq = vec![0.0; 2];
+        let mut taup = vec![0.0; 2];
+        let mut work = vec![0.0; 2];
+        let wrong = -1; // <<< wrong
+        assert_eq!(
+            dgebrd_lwork(wrong, 2),
+            Err(Error::InvalidArgument("LAPACK dgebrd workspace query failed"))
+        );
+        assert_eq!(
+            dorgbr_lwork(true, wrong, 2, 2),
+            Err(Error::InvalidArgument("LAPACK dorgbr workspace query failed"))
+        );
+        assert_eq!(
+            dgebrd(wrong, 2, &mut a, &mut d, &mut e, &mut tauq, &mut taup, &mut work),
+            Err(Error::InvalidArgument("LAPACK dgebrd failed"))
+        );
+        assert_eq!(
+            dorgbr(false, wrong, 2, 2, &mut a, &taup, &mut work),
+            Err(Error::InvalidArgument("LAPACK dorgbr failed"))
+        );
+    }
+
+    // reduces a (m × n) matrix to bidiagonal form and checks that a = Q ⋅ B ⋅ Pᵀ
+    fn check_bidiag(mm: usize, nn: usize, a_copy: &[f64]) -> Result<(), StrError> {
+        let (m, n) = (to_i32(mm), to_i32(nn));
+        let k = usize::min(mm, nn);
+
+        // reduce
+        let mut a = a_copy.to_vec();
+        let mut d = vec![0.0; k];
+        let mut e = vec![0.0; k - 1];
+        let mut tauq = vec![0.0; k];
+        let mut taup = vec![0.0; k];
+        let mut work = vec![0.0; dgebrd_lwork(m, n)?];
+        dgebrd(m, n, &mut a, &mut d, &mut e, &mut tauq, &mut taup, &mut work)?;
+
+        // bidiagonal matrix (upper if m ≥ n; lower otherwise)
+        let mut b = vec![0.0; mm * nn];
+        for i in 0..k {
+            b[i + i * mm] = d[i];
+            if i < k - 1 {
+                if mm >= nn {
+                    b[i + (i + 1) * mm] = e[i];
+                } else {
+                    b[(i + 1) + i * mm] = e[i];
+                }
+            }
+        }
+
+        // generate Q (m × m)
+        let mut q = vec![0.0; mm * mm];
+        let ncopy = usize::min(mm, nn) * mm;
+        q[..ncopy].copy_from_slice(&a[..ncopy]);
+        let mut work = vec![0.0; dorgbr_lwork(true, m, m, n)?];
+        dorgbr(true, m, m, n, &mut q, &tauq, &mut work)?;
+
+        // generate Pᵀ (n × n)
+        let mut pt = vec![0.0; nn * nn];
+        for j in 0..nn {
+            for i in 0..k {
+                pt[i + j * nn] = a[i + j * mm];
+            }
+        }
+        let mut work = vec![0.0; dorgbr_lwork(false, n, n, m)?];
+        dorgbr(false, n, n, m, &mut pt, &taup, &mut work)?;
+
+        // Q ⋅ B ⋅ Pᵀ = a
+        let mut qb = vec![0.0; mm * nn];
+        let mut qbpt = vec![0.0; mm * nn];
+        dgemm(false, false, m, n, m, 1.0, &q, &b, 0.0, &mut qb);
+        dgemm(false, false, m, n, n, 1.0, &qb, &pt, 0.0, &mut qbpt);
+        vec_approx_eq(&qbpt, a_copy, 1e-13);
+
+        // Qᵀ ⋅ Q = I and P ⋅ Pᵀ = I
+        let mut qtq = vec![0.0; mm * mm];
+        dgemm(true, false, m, m, m, 1.0, &q, &q, 0.0, &mut qtq);
+        let mut ppt = vec![0.0; nn * nn];
+        dgemm(false, true, n, n, n, 1.0, &pt, &pt, 0.0, &mut ppt);
+        vec_approx_eq(&qtq, &identity(mm), 1e-14);
+        vec_approx_eq(&ppt, &identity(nn), 1e-14);
+        Ok(())
+    }
+
+    fn identity(n: usize) -> Vec<f64> {
+        let mut id = vec![0.0; n * n];
+        for i in 0..n {
+            id[i + i * n] = 1.0;
+        }
+        id
+    }
+
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn bidiag_functions_work() -> Result<(), StrError> {
+        // tall matrix: upper bidiagonal
+        #[rustfmt::skip]
+        let a = col_major(4, 3, &[
+            1.0, 2.0,  3.0,
+            4.0, 5.0,  6.0,
+            7.0, 8.0, 10.0,
+            1.0, 0.0,  1.0,
+        ]);
+        check_bidiag(4, 3, &a)?;
+
+        // wide matrix: lower bidiagonal
+        #[rustfmt::skip]
+        let a = col_major(3, 4, &[
+            1.0, 4.0,  7.0, 1.0,
+            2.0, 5.0,  8.0, 0.0,
+            3.0, 6.0, 10.0, 1.0,
+        ]);
+        check_bidiag(3, 4, &a)?;
+
+        // square matrix
+        #[rustfmt::skip]
+        let a = col_major(3, 3, &[
+            2.0, -1.0,  0.0,
+           -1.0,  2.0, -1.0,
+            0.0, -1.0,  2.0,
+        ]);
+        check_bidiag(3, 3, &a)
+    }
+
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn dggsvd3_captures_errors() {
+        let mut a = vec![0.0; 4];
+        let mut b = vec![0.0; 4];
+        let mut alpha = vec![0.0; 2];
+        let mut beta = vec![0.0; 2];
+        let mut u = vec![0.0; 4];
+        let mut v = vec![0.0; 4];
+        let mut q = vec![0.0; 4];
+        let mut iwork = vec![0; 2];
+        let wrong = -1; // <<< wrong
+        assert_eq!(
+            dggsvd3(
+                b'U', b'V', b'Q', wrong, 2, 2, &mut a, &mut b, &mut alpha, &mut beta, &mut u, &mut v, &mut q,
+                &mut iwork
+            ),
+            Err(Error::InvalidArgument("LAPACK dggsvd3 failed"))
+        );
+    }
+
+    // computes the GSVD of a (m × n) and b (p × n) and checks the decomposition (assuming m ≥ k + l)
+    fn check_gsvd(mm: usize, nn: usize, pp: usize, a_copy: &[f64], b_copy: &[f64]) -> (usize, usize) {
+        let (m, n, p) = (to_i32(mm), to_i32(nn), to_i32(pp));
+        let mut a = a_copy.to_vec();
+        let mut b = b_copy.to_vec();
+        let mut alpha = vec![0.0; nn];
+        let mut beta = vec![0.0; nn];
+        let mut u = vec![0.0; mm * mm];
+        let mut v = vec![0.0; pp * pp];
+        let mut q = vec![0.0; nn * nn];
+        let mut iwork = vec![0; nn];
+        let (k, l) = dggsvd3(
+            b'U', b'V', b'Q', m, n, p, &mut a, &mut b, &mut alpha, &mut beta, &mut u, &mut v, &mut q, &mut iwork,
+        )
+        .unwrap();
+        let (k, l) = (k as usize, l as usize);
+        let r = k + l;
+        // [0 R] ⋅ Qᵀ (r × n), with R stored in a[0..r, n-r..n]
+        let mut rqt = vec![0.0; r * nn];
+        for i in 0..r {
+            for j in 0..nn {
+                for c in (nn - r + i)..nn {
+                    rqt[i + j * r] += a[i + c * mm] * q[j + c * nn];
+                }
+            }
+        }
+        // D1 (m × r) and D2 (p × r)
+        let mut d1 = vec![0.0; mm * r];
+        let mut d2 = vec![0.0; pp * r];
+        for i in 0..k {
+            d1[i + i * mm] = 1.0;
+        }
+        for i in 0..l {
+            d1[(k + i) + (k + i) * mm] = alpha[k + i];
+            d2[i + (k + i) * pp] = beta[k + i];
+            approx_eq(alpha[k + i] * alpha[k + i] + beta[k + i] * beta[k + i], 1.0, 1e-14);
+        }
+        // check a = U ⋅ D1 ⋅ [0 R] ⋅ Qᵀ and b = V ⋅ D2 ⋅ [0 R] ⋅ Qᵀ
+        for (x_copy, w, d, nrow) in [(a_copy, &u, &d1, mm), (b_copy, &v, &d2, pp)] {
+            for i in 0..nrow {
+                for j in 0..nn {
+                    let mut sum = 0.0;
+                    for c in 0..nrow {
+                        for e in 0..r {
+                            sum += w[i + c * nrow] * d[c + e * nrow] * rqt[e + j * r];
+                        }
+                    }
+                    approx_eq(sum, x_copy[i + j * nrow], 1e-13);
+                }
+            }
+        }
+        (k, l)
+    }
+
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn dggsvd3_works() {
+        // b has full column rank
+        #[rustfmt::skip]
+        let a = col_major(4, 3, &[
+            1.0, 2.0,  3.0,
+            4.0, 5.0,  6.0,
+            7.0, 8.0, 10.0,
+            1.0, 0.0,  1.0,
+        ]);
+        #[rustfmt::skip]
+        let b = col_major(3, 3, &[
+            2.0, -1.0,  0.0,
+           -1.0,  2.0, -1.0,
+            0.0, -1.0,  2.0,
+        ]);
+        assert_eq!(check_gsvd(4, 3, 3, &a, &b), (0, 3));
+
+        // b has rank one
+        let b = col_major(1, 3, &[1.0, 1.0, 1.0]);
+        assert_eq!(check_gsvd(4, 3, 1, &a, &b), (2, 1));
+    }
+
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn least_squares_functions_capture_errors() {
+        let mut a = vec![0.0; 4];
+        let mut b = vec![0.0; 2];
+        let mut s = vec![0.0; 2];
+        let mut jpvt = vec![0; 2];
+        let wrong = -1; // <<< wrong
+        assert_eq!(
+            dgelsd(wrong, 2, 1, &mut a, &mut b, &mut s, -1.0),
+            Err(Error::InvalidArgument("LAPACK dgelsd failed"))
+        );
+        assert_eq!(
+            dgelsy(wrong, 2, 1, &mut a, &mut b, &mut jpvt, 1e-12),
+            Err(Error::InvalidArgument("LAPACK dgelsy failed"))
+        );
+    }
+
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn least_squares_functions_work() -> Result<(), StrError> {
+        // fit y = c0 + c1 x to (0,1), (1,3), (2,4), (3,8)
+        #[rustfmt::skip]
+        let a_fit = col_major(4, 2, &[
+            1.0, 0.0,
+            1.0, 1.0,
+            1.0, 2.0,
+            1.0, 3.0,
+        ]);
+        let b_fit = vec![1.0, 3.0, 4.0, 8.0];
+        let (mut a, mut b) = (a_fit.clone(), b_fit.clone());
+        let mut s = vec![0.0; 2];
+        let rank = dgelsd(4, 2, 1, &mut a, &mut b, &mut s, -1.0)?;
+        assert_eq!(rank, 2);
+        vec_approx_eq(&b[..2], &[0.7, 2.2], 1e-14);
+        let (mut a, mut b) = (a_fit.clone(), b_fit.clone());
+        let mut jpvt = vec![0; 2];
+        let rank = dgelsy(4, 2, 1, &mut a, &mut b, &mut jpvt, 1e-12)?;
+        assert_eq!(rank, 2);
+        vec_approx_eq(&b[..2], &[0.7, 2.2], 1e-14);
+
+        // rank-deficient matrix (third column = first column + second column)
+        #[rustfmt::skip]
+        let a_def = col_major(4, 3, &[
+            1.0, 2.0,  3.0,
+            4.0, 5.0,  9.0,
+            7.0, 8.0, 15.0,
+            1.0, 0.0,  1.0,
+        ]);
+        let b_def = vec![6.0, 18.0, 30.0, 2.0]; // = a ⋅ [1, 1, 1]
+        let x_min_norm = &[2.0 / 3.0, 2.0 / 3.0, 4.0 / 3.0];
+        let (mut a, mut b) = (a_def.clone(), b_def.clone());
+        let mut s = vec![0.0; 3];
+        let rank = dgelsd(4, 3, 1, &mut a, &mut b, &mut s, 1e-12)?;
+        assert_eq!(rank, 2);
+        vec_approx_eq(&s[..2], &[21.796140004740867, 0.963473348741213], 1e-13);
+        assert!(s[2] < 1e-13);
+        vec_approx_eq(&b[..3], x_min_norm, 1e-13);
+        let (mut a, mut b) = (a_def.clone(), b_def.clone());
+        let mut jpvt = vec![0; 3];
+        let rank = dgelsy(4, 3, 1, &mut a, &mut b, &mut jpvt, 1e-12)?;
+        assert_eq!(rank, 2);
+        vec_approx_eq(&b[..3], x_min_norm, 1e-13);
+        Ok(())
+    }
+
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn dsyevd_captures_errors() {
+        let mut a = vec![0.0; 1];
+        let mut w = vec![0.0; 1];
+        let wrong = -1; // <<< wrong
+        assert_eq!(
+            dsyevd(true, true, wrong, &mut a, &mut w),
+            Err(Error::InvalidArgument("LAPACK dsyevd failed"))
+        );
+    }
+
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn dsyevd_works() -> Result<(), StrError> {
+        #[rustfmt::skip]
+        let mut a = col_major(5, 5, &[
+             1.96, -6.49, -0.47, -7.20, -0.65,
+            -6.49,  3.80, -6.39,  1.50, -6.34,
+            -0.47, -6.39,  4.17, -1.51,  2.67,
+            -7.20,  1.50, -1.51,  5.70,  1.80,
+            -0.65, -6.34,  2.67,  1.80, -7.10,
+        ]);
+        let a_copy = a.to_vec();
+        let mut w = vec![0.0; 5];
+        dsyevd(true, true, 5, &mut a, &mut w)?;
+        #[rustfmt::skip]
+        let w_correct = &[
+            -11.065575263268386,
+             -6.228746932398536,
+              0.864027975272061,
+              8.865457108365517,
+             16.09483711202934,
+        ];
+        vec_approx_eq(&w, w_correct, 1e-14);
+        check_eigen_sym(5, &a_copy, &w, &a, 1e-14);
+        Ok(())
+    }
+
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn dsyevr_captures_errors() {
+        let mut a = vec![1.0, 0.0, 0.0, 1.0];
+        let mut w = vec![0.0; 2];
+        let mut z = vec![0.0; 4];
+        let wrong = -1; // <<< wrong
+        assert_eq!(
+            dsyevr(true, EigenRange::All, true, wrong, &mut a, &mut w, &mut z),
+            Err(Error::InvalidArgument("LAPACK dsyevr failed"))
+        );
+        assert_eq!(
+            dsyevr(true, EigenRange::Indices(0, 1), true, 2, &mut a, &mut w, &mut z),
+            Err(Error::InvalidArgument("LAPACK dsyevr failed"))
+        );
+        assert_eq!(
+            dsyevr(true, EigenRange::Values(1.0, 0.0), true, 2, &mut a, &mut w, &mut z),
+            Err(Error::InvalidArgument("LAPACK dsyevr failed"))
+        );
+    }
+
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn dsyevr_works() -> Result<(), StrError> {
+        #[rustfmt::skip]
+        let a_full = col_major(5, 5, &[
+             1.96, -6.49, -0.47, -7.20, -0.65,
+            -6.49,  3.80, -6.39,  1.50, -6.34,
+            -0.47, -6.39,  4.17, -1.51,  2.67,
+            -7.20,  1.50, -1.51,  5.70,  1.80,
+            -0.65, -6.34,  2.67,  1.80, -7.10,
+        ]);
+        #[rustfmt::skip]
+        let w_correct = &[
+            -11.065575263268386,
+             -6.228746932398536,
+              0.864027975272061,
+              8.865457108365517,
+             16.09483711202934,
+        ];
+        let n = 5;
+
+        // all eigenvalues
+        let mut a = a_full.clone();
+        let mut w = vec![0.0; 5];
+        let mut z = vec![0.0; 25];
+        let m = dsyevr(true, EigenRange::All, true, n, &mut a, &mut w, &mut z)?;
+        assert_eq!(m, 5);
+        vec_approx_eq(&w, w_correct, 1e-14);
+        check_eigen_sym(5, &a_full, &w, &z, 1e-14);
+
+        // eigenvalues in (-7, 9]
+        let mut a = a_full.clone();
+        let mut w = vec![0.0; 5];
+        let mut z = vec![0.0; 25];
+        let m = dsyevr(true, EigenRange::Values(-7.0, 9.0), false, n, &mut a, &mut w, &mut z)?;
+        assert_eq!(m, 3);
+        vec_approx_eq(&w[..m], &w_correct[1..4], 1e-14);
+        check_eigen_sym(5, &a_full, &w, &z, 1e-14);
+
+        // the 4th and 5th eigenvalues, without eigenvectors
+        let mut a = a_full.clone();
+        let mut w = vec![0.0; 5];
+        let m = dsyevr(false, EigenRange::Indices(4, 5), true, n, &mut a, &mut w, &mut [])?;
+        assert_eq!(m, 2);
+        vec_approx_eq(&w[..m], &w_correct[3..], 1e-14);
+        Ok(())
+    }
+
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn zheevr_captures_errors() {
+        let mut a = vec![Complex64::new(1.0, 0.0)];
+        let mut w = vec![0.0; 1];
+        let mut z = vec![Complex64::new(0.0, 0.0); 1];
+        let wrong = -1; // <<< wrong
+        assert_eq!(
+            zheevr(true, EigenRange::All, true, wrong, &mut a, &mut w, &mut z),
+            Err(Error::InvalidArgument("LAPACK zheevr failed"))
+        );
+    }
+
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn zheevr_works() -> Result<(), StrError> {
+        #[rustfmt::skip]
+        let a_full = col_major_complex(3, 3, &[
+            Complex64::new(2.0, 0.0), Complex64::new(1.0, -1.0), Complex64::new(0.0,  0.0),
+            Complex64::new(1.0, 1.0), Complex64::new(3.0,  0.0), Complex64::new(0.0, -2.0),
+            Complex64::new(0.0, 0.0), Complex64::new(0.0,  2.0), Complex64::new(1.0,  0.0),
+        ]);
+        // mpmath.eighe
+        let w_correct = &[-0.4892885718100788, 1.71083145355169, 4.778457118258388];
+
+        // all eigenvalues
+        let mut a = a_full.clone();
+        let mut w = vec![0.0; 3];
+        let mut z = vec![Complex64::new(0.0, 0.0); 9];
+        let m = zheevr(true, EigenRange::All, true, 3, &mut a, &mut w, &mut z)?;
+        assert_eq!(m, 3);
+        vec_approx_eq(&w, w_correct, 1e-14);
+        for j in 0..m {
+            for i in 0..3 {
+                let mut a_times_v_i = Complex64::new(0.0, 0.0);
+                for k in 0..3 {
+                    a_times_v_i += a_full[i + k * 3] * z[k + j * 3];
+                }
+                complex_approx_eq(a_times_v_i, w[j] * z[i + j * 3], 1e-14);
+            }
+        }
+
+        // the smallest eigenvalue (lower triangle)
+        let mut a = a_full.clone();
+        let mut w = vec![0.0; 3];
+        let mut z = vec![Complex64::new(0.0, 0.0); 3];
+        let m = zheevr(true, EigenRange::Indices(1, 1), false, 3, &mut a, &mut w, &mut z)?;
+        assert_eq!(m, 1);
+        approx_eq(w[0], w_correct[0], 1e-14);
+        Ok(())
+    }
+
     // Checks eigenvalues and eigenvectors of a symmetric matrix
     //
     // ```text
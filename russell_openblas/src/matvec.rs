@@ -1,16 +1,27 @@
-use super::{cblas_transpose, to_i32, CBLAS_COL_MAJOR, LAPACK_COL_MAJOR};
-use crate::StrError;
+use super::{cblas_transpose, lapack_error, lapack_transpose, lapack_uplo, to_i32, CBLAS_COL_MAJOR, LAPACK_COL_MAJOR};
+use crate::{BlasInt, Error};
 use num_complex::Complex64;
 
+#[cfg(backend_pure_rust)]
+use crate::pure_rust::*;
+
+#[cfg(not(backend_pure_rust))]
 #[rustfmt::skip]
 extern "C" {
     // from /usr/include/x86_64-linux-gnu/cblas.h
-    fn cblas_dgemv(order: i32, trans: i32, m: i32, n: i32, alpha: f64, a: *const f64, lda: i32, x: *const f64, incx: i32, beta: f64, y: *mut f64, incy: i32);
-    fn cblas_zgemv(order: i32, trans: i32, m: i32, n: i32, alpha: *const Complex64, a: *const Complex64, lda: i32, x: *const Complex64, incx: i32, beta: *const Complex64, y: *mut Complex64, incy: i32);
-    fn cblas_dger(order: i32, m: i32, n: i32, alpha: f64, x: *const f64, incx: i32, y: *const f64, incy: i32, a: *mut f64, lda: i32);
+    fn cblas_dgemv(order: i32, trans: i32, m: BlasInt, n: BlasInt, alpha: f64, a: *const f64, lda: BlasInt, x: *const f64, incx: BlasInt, beta: f64, y: *mut f64, incy: BlasInt);
+    fn cblas_sgemv(order: i32, trans: i32, m: BlasInt, n: BlasInt, alpha: f32, a: *const f32, lda: BlasInt, x: *const f32, incx: BlasInt, beta: f32, y: *mut f32, incy: BlasInt);
+    fn cblas_zgemv(order: i32, trans: i32, m: BlasInt, n: BlasInt, alpha: *const Complex64, a: *const Complex64, lda: BlasInt, x: *const Complex64, incx: BlasInt, beta: *const Complex64, y: *mut Complex64, incy: BlasInt);
+    fn cblas_dger(order: i32, m: BlasInt, n: BlasInt, alpha: f64, x: *const f64, incx: BlasInt, y: *const f64, incy: BlasInt, a: *mut f64, lda: BlasInt);
     // from /usr/include/lapacke.h
-    fn LAPACKE_dgesv(matrix_layout: i32, n: i32, nrhs: i32, a: *mut f64, lda: i32, ipiv: *mut i32, b: *mut f64, ldb: i32) -> i32;
-    fn LAPACKE_zgesv(matrix_layout: i32, n: i32, nrhs: i32, a: *mut Complex64, lda: i32, ipiv: *mut i32, b: *mut Complex64, ldb: i32) -> i32;
+    fn LAPACKE_dgesv(matrix_layout: i32, n: BlasInt, nrhs: BlasInt, a: *mut f64, lda: BlasInt, ipiv: *mut BlasInt, b: *mut f64, ldb: BlasInt) -> BlasInt;
+    fn LAPACKE_dgesvx(matrix_layout: i32, fact: u8, trans: u8, n: BlasInt, nrhs: BlasInt, a: *mut f64, lda: BlasInt, af: *mut f64, ldaf: BlasInt, ipiv: *mut BlasInt, equed: *mut u8, r: *mut f64, c: *mut f64, b: *mut f64, ldb: BlasInt, x: *mut f64, ldx: BlasInt, rcond: *mut f64, ferr: *mut f64, berr: *mut f64, rpivot: *mut f64) -> BlasInt;
+    fn LAPACKE_zgesv(matrix_layout: i32, n: BlasInt, nrhs: BlasInt, a: *mut Complex64, lda: BlasInt, ipiv: *mut BlasInt, b: *mut Complex64, ldb: BlasInt) -> BlasInt;
+    fn LAPACKE_dposv(matrix_layout: i32, uplo: u8, n: BlasInt, nrhs: BlasInt, a: *mut f64, lda: BlasInt, b: *mut f64, ldb: BlasInt) -> BlasInt;
+    fn LAPACKE_dsysv(matrix_layout: i32, uplo: u8, n: BlasInt, nrhs: BlasInt, a: *mut f64, lda: BlasInt, ipiv: *mut BlasInt, b: *mut f64, ldb: BlasInt) -> BlasInt;
+    fn LAPACKE_zposv(matrix_layout: i32, uplo: u8, n: BlasInt, nrhs: BlasInt, a: *mut Complex64, lda: BlasInt, b: *mut Complex64, ldb: BlasInt) -> BlasInt;
+    fn LAPACKE_dgetrs(matrix_layout: i32, trans: u8, n: BlasInt, nrhs: BlasInt, a: *const f64, lda: BlasInt, ipiv: *const BlasInt, b: *mut f64, ldb: BlasInt) -> BlasInt;
+    fn LAPACKE_dpotrs(matrix_layout: i32, uplo: u8, n: BlasInt, nrhs: BlasInt, a: *const f64, lda: BlasInt, b: *mut f64, ldb: BlasInt) -> BlasInt;
 }
 
 /// Performs the rank 1 operation (tensor product)
@@ -29,7 +40,7 @@ extern "C" {
 /// <http://www.netlib.org/lapack/explore-html/dc/da8/dger_8f.html>
 ///
 #[inline]
-pub fn dger(m: i32, n: i32, alpha: f64, x: &[f64], incx: i32, y: &[f64], incy: i32, a: &mut [f64]) {
+pub fn dger(m: BlasInt, n: BlasInt, alpha: f64, x: &[f64], incx: BlasInt, y: &[f64], incy: BlasInt, a: &mut [f64]) {
     unsafe {
         cblas_dger(
             CBLAS_COL_MAJOR,
@@ -69,15 +80,15 @@ pub fn dger(m: i32, n: i32, alpha: f64, x: &[f64], incx: i32, y: &[f64], incy: i
 #[inline]
 pub fn dgemv(
     trans: bool,
-    m: i32,
-    n: i32,
+    m: BlasInt,
+    n: BlasInt,
     alpha: f64,
     a: &[f64],
     x: &[f64],
-    incx: i32,
+    incx: BlasInt,
     beta: f64,
     y: &mut [f64],
-    incy: i32,
+    incy: BlasInt,
 ) {
     unsafe {
         cblas_dgemv(
@@ -97,6 +108,58 @@ pub fn dgemv(
     }
 }
 
+/// Performs one of the matrix-vector multiplication (single precision version)
+///
+/// ```text
+///  y := α ⋅ a  ⋅ x  +  β ⋅ y
+/// (m)     (m,n) (n)       (m)
+///
+/// or
+///
+///  y := α ⋅  aᵀ ⋅ x  +  β ⋅ y
+/// (m)      (m,n) (n)       (m)
+/// ```
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <http://www.netlib.org/lapack/explore-html/d6/d30/sgemv_8f.html>
+///
+#[inline]
+#[allow(clippy::too_many_arguments)]
+pub fn sgemv(
+    trans: bool,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: f32,
+    a: &[f32],
+    x: &[f32],
+    incx: BlasInt,
+    beta: f32,
+    y: &mut [f32],
+    incy: BlasInt,
+) {
+    unsafe {
+        cblas_sgemv(
+            CBLAS_COL_MAJOR,
+            cblas_transpose(trans),
+            m,
+            n,
+            alpha,
+            a.as_ptr(),
+            m,
+            x.as_ptr(),
+            incx,
+            beta,
+            y.as_mut_ptr(),
+            incy,
+        );
+    }
+}
+
 /// Performs one of the matrix-vector multiplication (complex version)
 ///
 /// ```text
@@ -118,17 +181,18 @@ pub fn dgemv(
 /// <http://www.netlib.org/lapack/explore-html/db/d40/zgemv_8f.html>
 ///
 #[inline]
+#[allow(clippy::too_many_arguments)]
 pub fn zgemv(
     trans: bool,
-    m: i32,
-    n: i32,
+    m: BlasInt,
+    n: BlasInt,
     alpha: Complex64,
     a: &[Complex64],
     x: &[Complex64],
-    incx: i32,
+    incx: BlasInt,
     beta: Complex64,
     y: &mut [Complex64],
-    incy: i32,
+    incy: BlasInt,
 ) {
     unsafe {
         cblas_zgemv(
@@ -184,11 +248,11 @@ pub fn zgemv(
 /// <http://www.netlib.org/lapack/explore-html/d8/d72/dgesv_8f.html>
 ///
 #[inline]
-pub fn dgesv(n: i32, nrhs: i32, a: &mut [f64], ipiv: &mut [i32], b: &mut [f64]) -> Result<(), StrError> {
+pub fn dgesv(n: BlasInt, nrhs: BlasInt, a: &mut [f64], ipiv: &mut [BlasInt], b: &mut [f64]) -> Result<(), Error> {
     unsafe {
-        let ipiv_len: i32 = to_i32(ipiv.len());
+        let ipiv_len: BlasInt = to_i32(ipiv.len());
         if ipiv_len != n {
-            return Err("the length of ipiv must equal n");
+            return Err(Error::BadDimensions("the length of ipiv must equal n"));
         }
         let info = LAPACKE_dgesv(
             LAPACK_COL_MAJOR,
@@ -200,13 +264,116 @@ pub fn dgesv(n: i32, nrhs: i32, a: &mut [f64], ipiv: &mut [i32], b: &mut [f64])
             b.as_mut_ptr(),
             n,
         );
-        if info != 0_i32 {
-            return Err("LAPACK dgesv failed");
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK dgesv failed", Error::Singular));
         }
     }
     Ok(())
 }
 
+/// Computes the solution to a real system of linear equations with iterative refinement and error bounds (expert driver)
+///
+/// The system is:
+///
+/// ```text
+///   A  ⋅  X =   B
+/// (n,n)  (n)  (n,nrhs)
+/// ```
+///
+/// The LU decomposition (with partial pivoting) is used to factor the (optionally equilibrated)
+/// matrix A. Then, the solution is improved by iterative refinement and error bounds are computed.
+///
+/// # Input
+///
+/// * `equilibrate` -- equilibrates the system (if worthwhile) before factorizing; in this case, `a` and `b` may be scaled
+/// * `a` -- (n,n) the coefficient matrix
+/// * `af` -- (n,n) workspace for the LU factors
+/// * `ipiv` -- (len = n) workspace for the pivot indices
+/// * `r` -- (len = n) workspace for the row scale factors
+/// * `c` -- (len = n) workspace for the column scale factors
+/// * `b` -- (n,nrhs) the right-hand side
+///
+/// # Output
+///
+/// * `x` -- (n,nrhs) the solution
+/// * `ferr` -- (len = nrhs) the estimated forward error bound of each solution vector
+/// * `berr` -- (len = nrhs) the componentwise relative backward error of each solution vector
+///
+/// Returns `(rcond, equed)` where `rcond` is the estimate of the reciprocal condition number of A
+/// (after equilibration) and `equed` is the form of equilibration that was done (see **dlaqge**).
+///
+/// # Note
+///
+/// If `rcond` is less than the machine precision, the matrix is singular to working precision;
+/// nonetheless, the solution and error bounds are computed and no error is returned.
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/explore-html/d0/d37/dgesvx_8f.html>
+///
+#[inline]
+#[allow(clippy::too_many_arguments)]
+pub fn dgesvx(
+    equilibrate: bool,
+    n: BlasInt,
+    nrhs: BlasInt,
+    a: &mut [f64],
+    af: &mut [f64],
+    ipiv: &mut [BlasInt],
+    r: &mut [f64],
+    c: &mut [f64],
+    b: &mut [f64],
+    x: &mut [f64],
+    ferr: &mut [f64],
+    berr: &mut [f64],
+) -> Result<(f64, u8), Error> {
+    if to_i32(ipiv.len()) != n || to_i32(r.len()) != n || to_i32(c.len()) != n {
+        return Err(Error::BadDimensions("the lengths of ipiv, r, and c must equal n"));
+    }
+    if to_i32(ferr.len()) != nrhs || to_i32(berr.len()) != nrhs {
+        return Err(Error::BadDimensions("the lengths of ferr and berr must equal nrhs"));
+    }
+    let fact = if equilibrate { b'E' } else { b'N' };
+    let ld = BlasInt::max(1, n);
+    let mut equed = b'N';
+    let mut rcond = 0.0;
+    let mut rpivot = 0.0;
+    unsafe {
+        let info = LAPACKE_dgesvx(
+            LAPACK_COL_MAJOR,
+            fact,
+            b'N',
+            n,
+            nrhs,
+            a.as_mut_ptr(),
+            ld,
+            af.as_mut_ptr(),
+            ld,
+            ipiv.as_mut_ptr(),
+            &mut equed,
+            r.as_mut_ptr(),
+            c.as_mut_ptr(),
+            b.as_mut_ptr(),
+            ld,
+            x.as_mut_ptr(),
+            ld,
+            &mut rcond,
+            ferr.as_mut_ptr(),
+            berr.as_mut_ptr(),
+            &mut rpivot,
+        );
+        // info = n + 1 means that rcond < machine precision (the solution has been computed)
+        if info != 0 && info != n + 1 {
+            return Err(lapack_error(info, "LAPACK dgesvx failed", Error::Singular));
+        }
+    }
+    Ok((rcond, equed))
+}
+
 /// Computes the solution to a real system of linear equations (complex version)
 ///
 /// The system is:
@@ -243,11 +410,17 @@ pub fn dgesv(n: i32, nrhs: i32, a: &mut [f64], ipiv: &mut [i32], b: &mut [f64])
 /// <http://www.netlib.org/lapack/explore-html/d1/ddc/zgesv_8f.html>
 ///
 #[inline]
-pub fn zgesv(n: i32, nrhs: i32, a: &mut [Complex64], ipiv: &mut [i32], b: &mut [Complex64]) -> Result<(), StrError> {
+pub fn zgesv(
+    n: BlasInt,
+    nrhs: BlasInt,
+    a: &mut [Complex64],
+    ipiv: &mut [BlasInt],
+    b: &mut [Complex64],
+) -> Result<(), Error> {
     unsafe {
-        let ipiv_len: i32 = to_i32(ipiv.len());
+        let ipiv_len: BlasInt = to_i32(ipiv.len());
         if ipiv_len != n {
-            return Err("the length of ipiv must equal n");
+            return Err(Error::BadDimensions("the length of ipiv must equal n"));
         }
         let info = LAPACKE_zgesv(
             LAPACK_COL_MAJOR,
@@ -259,8 +432,319 @@ pub fn zgesv(n: i32, nrhs: i32, a: &mut [Complex64], ipiv: &mut [i32], b: &mut [
             b.as_mut_ptr(),
             n,
         );
-        if info != 0_i32 {
-            return Err("LAPACK zgesv failed");
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK zgesv failed", Error::Singular));
+        }
+    }
+    Ok(())
+}
+
+/// Computes the solution to a real system of linear equations with a symmetric positive definite matrix
+///
+/// The system is:
+///
+/// ```text
+///   A  ⋅  X =   B
+/// (n,n)  (n)  (n,nrhs)
+/// ```
+///
+/// where A is an N-by-N symmetric positive definite matrix and X and B are N-by-NRHS matrices.
+///
+/// The Cholesky decomposition is used to factor A as
+///
+/// ```text
+/// up = true:
+///
+/// A = Uᵀ ⋅ U
+///
+/// or
+///
+/// up = false:
+///
+/// A = L ⋅ Lᵀ
+/// ```
+///
+/// where U is an upper triangular matrix and L is a lower triangular matrix. The factored
+/// form of A is then used to solve the system of equations A * X = B.
+///
+/// # Note
+///
+/// 1. Only the upper (`up = true`) or lower (`up = false`) triangle of the matrix is accessed
+/// 2. The matrix will be modified
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <http://www.netlib.org/lapack/explore-html/d9/d6f/dposv_8f.html>
+///
+#[inline]
+pub fn dposv(up: bool, n: BlasInt, nrhs: BlasInt, a: &mut [f64], b: &mut [f64]) -> Result<(), Error> {
+    unsafe {
+        let info = LAPACKE_dposv(
+            LAPACK_COL_MAJOR,
+            lapack_uplo(up),
+            n,
+            nrhs,
+            a.as_mut_ptr(),
+            n,
+            b.as_mut_ptr(),
+            n,
+        );
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK dposv failed", Error::Singular));
+        }
+    }
+    Ok(())
+}
+
+/// Solves a real system of linear equations using the LU factorization computed by dgetrf
+///
+/// The system is:
+///
+/// ```text
+/// trans = false:
+///
+///   A  ⋅  X =   B
+/// (n,n)  (n)  (n,nrhs)
+///
+/// or
+///
+/// trans = true:
+///
+///   Aᵀ ⋅  X =   B
+/// ```
+///
+/// where `a` and `ipiv` hold the factors `A = P ⋅ L ⋅ U` as returned by **dgetrf**.
+///
+/// # Note
+///
+/// 1. The length of ipiv must be equal to `n`
+/// 2. On output, `b` holds the solution X
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/explore-html/d6/d49/dgetrs_8f.html>
+///
+#[inline]
+pub fn dgetrs(trans: bool, n: BlasInt, nrhs: BlasInt, a: &[f64], ipiv: &[BlasInt], b: &mut [f64]) -> Result<(), Error> {
+    if to_i32(ipiv.len()) != n {
+        return Err(Error::BadDimensions("the length of ipiv must equal n"));
+    }
+    let ld = BlasInt::max(1, n);
+    unsafe {
+        let info = LAPACKE_dgetrs(
+            LAPACK_COL_MAJOR,
+            lapack_transpose(trans),
+            n,
+            nrhs,
+            a.as_ptr(),
+            ld,
+            ipiv.as_ptr(),
+            b.as_mut_ptr(),
+            ld,
+        );
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK dgetrs failed", Error::InvalidArgument));
+        }
+    }
+    Ok(())
+}
+
+/// Solves a real system of linear equations using the Cholesky factorization computed by dpotrf
+///
+/// The system is:
+///
+/// ```text
+///   A  ⋅  X =   B
+/// (n,n)  (n)  (n,nrhs)
+/// ```
+///
+/// where `a` holds the factor computed by **dpotrf**:
+///
+/// ```text
+/// up = true:
+///
+/// A = Uᵀ ⋅ U
+///
+/// or
+///
+/// up = false:
+///
+/// A = L ⋅ Lᵀ
+/// ```
+///
+/// # Note
+///
+/// 1. Only the upper (`up = true`) or lower (`up = false`) triangle of `a` is accessed
+/// 2. On output, `b` holds the solution X
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/explore-html/d0/d43/dpotrs_8f.html>
+///
+#[inline]
+pub fn dpotrs(up: bool, n: BlasInt, nrhs: BlasInt, a: &[f64], b: &mut [f64]) -> Result<(), Error> {
+    let ld = BlasInt::max(1, n);
+    unsafe {
+        let info = LAPACKE_dpotrs(
+            LAPACK_COL_MAJOR,
+            lapack_uplo(up),
+            n,
+            nrhs,
+            a.as_ptr(),
+            ld,
+            b.as_mut_ptr(),
+            ld,
+        );
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK dpotrs failed", Error::InvalidArgument));
+        }
+    }
+    Ok(())
+}
+
+/// Computes the solution to a real system of linear equations with a symmetric (indefinite) matrix
+///
+/// The system is:
+///
+/// ```text
+///   A  ⋅  X =   B
+/// (n,n)  (n)  (n,nrhs)
+/// ```
+///
+/// where A is an N-by-N symmetric matrix and X and B are N-by-NRHS matrices.
+///
+/// The diagonal pivoting method is used to factor A as
+///
+/// ```text
+/// up = true:
+///
+/// A = U ⋅ D ⋅ Uᵀ
+///
+/// or
+///
+/// up = false:
+///
+/// A = L ⋅ D ⋅ Lᵀ
+/// ```
+///
+/// where U (or L) is a product of permutation and unit upper (lower) triangular matrices,
+/// and D is symmetric and block diagonal with 1-by-1 and 2-by-2 diagonal blocks. The
+/// factored form of A is then used to solve the system of equations A * X = B.
+///
+/// # Note
+///
+/// 1. The length of ipiv must be equal to `n`
+/// 2. Only the upper (`up = true`) or lower (`up = false`) triangle of the matrix is accessed
+/// 3. The matrix will be modified
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <http://www.netlib.org/lapack/explore-html/d6/d0e/dsysv_8f.html>
+///
+#[inline]
+pub fn dsysv(
+    up: bool,
+    n: BlasInt,
+    nrhs: BlasInt,
+    a: &mut [f64],
+    ipiv: &mut [BlasInt],
+    b: &mut [f64],
+) -> Result<(), Error> {
+    unsafe {
+        let ipiv_len: BlasInt = to_i32(ipiv.len());
+        if ipiv_len != n {
+            return Err(Error::BadDimensions("the length of ipiv must equal n"));
+        }
+        let info = LAPACKE_dsysv(
+            LAPACK_COL_MAJOR,
+            lapack_uplo(up),
+            n,
+            nrhs,
+            a.as_mut_ptr(),
+            n,
+            ipiv.as_mut_ptr(),
+            b.as_mut_ptr(),
+            n,
+        );
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK dsysv failed", Error::Singular));
+        }
+    }
+    Ok(())
+}
+
+/// Computes the solution to a complex system of linear equations with a Hermitian positive definite matrix
+///
+/// The system is:
+///
+/// ```text
+///   A  ⋅  X =   B
+/// (n,n)  (n)  (n,nrhs)
+/// ```
+///
+/// where A is an N-by-N Hermitian positive definite matrix and X and B are N-by-NRHS matrices.
+///
+/// The Cholesky decomposition is used to factor A as
+///
+/// ```text
+/// up = true:
+///
+/// A = Uᴴ ⋅ U
+///
+/// or
+///
+/// up = false:
+///
+/// A = L ⋅ Lᴴ
+/// ```
+///
+/// where U is an upper triangular matrix and L is a lower triangular matrix. The factored
+/// form of A is then used to solve the system of equations A * X = B.
+///
+/// # Note
+///
+/// 1. Only the upper (`up = true`) or lower (`up = false`) triangle of the matrix is accessed
+/// 2. The matrix will be modified
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <http://www.netlib.org/lapack/explore-html/d3/d8c/zposv_8f.html>
+///
+#[inline]
+pub fn zposv(up: bool, n: BlasInt, nrhs: BlasInt, a: &mut [Complex64], b: &mut [Complex64]) -> Result<(), Error> {
+    unsafe {
+        let info = LAPACKE_zposv(
+            LAPACK_COL_MAJOR,
+            lapack_uplo(up),
+            n,
+            nrhs,
+            a.as_mut_ptr(),
+            n,
+            b.as_mut_ptr(),
+            n,
+        );
+        if info != 0 {
+            return Err(lapack_error(info, "LAPACK zposv failed", Error::Singular));
         }
     }
     Ok(())
@@ -269,10 +753,11 @@ pub fn zgesv(n: i32, nrhs: i32, a: &mut [Complex64], ipiv: &mut [i32], b: &mut [
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
+#[cfg_attr(backend_pure_rust, allow(dead_code, unused_imports))]
 mod tests {
-    use super::{dgemv, dger, dgesv, zgemv, zgesv};
+    use super::{dgemv, dger, dgesv, dgesvx, dgetrs, dposv, dpotrs, dsysv, sgemv, zgemv, zgesv, zposv};
     use crate::conversions::{col_major, col_major_complex};
-    use crate::{to_i32, StrError};
+    use crate::{to_i32, Error, StrError};
     use num_complex::Complex64;
     use russell_chk::{complex_vec_approx_eq, vec_approx_eq};
 
@@ -328,6 +813,25 @@ mod tests {
         vec_approx_eq(&a, &[0.1, 1.0, 2.0, 3.0, 0.2, 0.2, 0.2, 0.2, 0.3, 0.3, 0.3, 0.3], 1e-15);
     }
 
+    #[test]
+    fn sgemv_works() {
+        #[rustfmt::skip]
+        let a: Vec<f32> = col_major(4, 3, &[
+            0.1, 0.2, 0.3,
+            1.0, 0.2, 0.3,
+            2.0, 0.2, 0.3,
+            3.0, 0.2, 0.3,
+        ]).iter().map(|x| *x as f32).collect();
+        let mut x = [20.0, 10.0, 30.0];
+        let mut y = [3.0, 1.0, 2.0, 4.0];
+        sgemv(false, 4, 3, 0.5, &a, &x, 1, 2.0, &mut y, 1);
+        let y_f64: Vec<f64> = y.iter().map(|v| *v as f64).collect();
+        vec_approx_eq(&y_f64, &[12.5, 17.5, 29.5, 43.5], 1e-5);
+        sgemv(true, 4, 3, 0.5, &a, &y, 1, 2.0, &mut x, 1);
+        let x_f64: Vec<f64> = x.iter().map(|v| *v as f64).collect();
+        vec_approx_eq(&x_f64, &[144.125, 30.3, 75.45], 1e-4);
+    }
+
     #[test]
     fn zgemv_works() {
         // allocate matrix
@@ -375,6 +879,7 @@ mod tests {
         complex_vec_approx_eq(&a, &a_clone, 1e-15);
     }
 
+    #[cfg(not(backend_pure_rust))]
     #[test]
     fn dgesv_captures_errors() {
         let m = 2;
@@ -382,10 +887,10 @@ mod tests {
         let mut b = vec![0.0; m];
         let mut ipiv = vec![0; m];
         let m_i32 = to_i32(m);
-        let nrhs = 1_i32;
+        let nrhs = 1;
         assert_eq!(
             dgesv(m_i32, nrhs, &mut a, &mut ipiv, &mut b),
-            Err("LAPACK dgesv failed")
+            Err(Error::Singular("LAPACK dgesv failed"))
         );
     }
 
@@ -396,13 +901,14 @@ mod tests {
         let mut b = vec![0.0; m];
         let mut ipiv = vec![0; 1]; // << ERROR
         let m_i32 = to_i32(m);
-        let nrhs = 1_i32;
+        let nrhs = 1;
         assert_eq!(
             dgesv(m_i32, nrhs, &mut a, &mut ipiv, &mut b),
-            Err("the length of ipiv must equal n")
+            Err(Error::BadDimensions("the length of ipiv must equal n"))
         );
     }
 
+    #[cfg(not(backend_pure_rust))]
     #[test]
     fn dgesv_works() -> Result<(), StrError> {
         // matrix
@@ -419,7 +925,7 @@ mod tests {
         let mut b = vec![8.0, 45.0, -3.0, 3.0, 19.0];
 
         // solve b := x := A⁻¹ b
-        let (n, nrhs) = (5_i32, 1_i32);
+        let (n, nrhs) = (5, 1);
         let mut ipiv = vec![0; n as usize];
         dgesv(n, nrhs, &mut a, &mut ipiv, &mut b)?;
 
@@ -429,6 +935,100 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn dgesvx_captures_errors() {
+        let n = 2;
+        let mut a = vec![0.0; n * n];
+        let mut af = vec![0.0; n * n];
+        let mut b = vec![0.0; n];
+        let mut x = vec![0.0; n];
+        let mut ipiv = vec![0; n];
+        let mut r = vec![0.0; n];
+        let mut c = vec![0.0; n];
+        let mut c_wrong = vec![0.0; 1];
+        let (mut ferr, mut berr) = (vec![0.0; 1], vec![0.0; 1]);
+        let mut berr_wrong = vec![0.0; 2];
+        let n_i32 = to_i32(n);
+        assert_eq!(
+            dgesvx(
+                false,
+                n_i32,
+                1,
+                &mut a,
+                &mut af,
+                &mut ipiv,
+                &mut r,
+                &mut c_wrong,
+                &mut b,
+                &mut x,
+                &mut ferr,
+                &mut berr
+            ),
+            Err(Error::BadDimensions("the lengths of ipiv, r, and c must equal n"))
+        );
+        assert_eq!(
+            dgesvx(
+                false,
+                n_i32,
+                1,
+                &mut a,
+                &mut af,
+                &mut ipiv,
+                &mut r,
+                &mut c,
+                &mut b,
+                &mut x,
+                &mut ferr,
+                &mut berr_wrong
+            ),
+            Err(Error::BadDimensions("the lengths of ferr and berr must equal nrhs"))
+        );
+        assert_eq!(
+            dgesvx(false, n_i32, 1, &mut a, &mut af, &mut ipiv, &mut r, &mut c, &mut b, &mut x, &mut ferr, &mut berr),
+            Err(Error::Singular("LAPACK dgesvx failed"))
+        );
+    }
+
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn dgesvx_works() -> Result<(), StrError> {
+        // matrix
+        #[rustfmt::skip]
+        let mut a = col_major(5, 5, &[
+            2.0,  3.0,  0.0, 0.0, 0.0,
+            3.0,  0.0,  4.0, 0.0, 6.0,
+            0.0, -1.0, -3.0, 2.0, 0.0,
+            0.0,  0.0,  1.0, 0.0, 0.0,
+            0.0,  4.0,  2.0, 0.0, 1.0,
+        ]);
+
+        // right-hand-side
+        let mut b = vec![8.0, 45.0, -3.0, 3.0, 19.0];
+
+        // solve
+        let (n, nrhs) = (5, 1);
+        let mut af = vec![0.0; 25];
+        let mut ipiv = vec![0; 5];
+        let mut r = vec![0.0; 5];
+        let mut c = vec![0.0; 5];
+        let mut x = vec![0.0; 5];
+        let mut ferr = vec![0.0; 1];
+        let mut berr = vec![0.0; 1];
+        let (rcond, _) = dgesvx(
+            false, n, nrhs, &mut a, &mut af, &mut ipiv, &mut r, &mut c, &mut b, &mut x, &mut ferr, &mut berr,
+        )?;
+
+        // check
+        let correct = &[1.0, 2.0, 3.0, 4.0, 5.0];
+        vec_approx_eq(&x, correct, 1e-14);
+        assert!(rcond > 0.01 && rcond <= 1.0);
+        assert!(ferr[0] < 1e-12);
+        assert!(berr[0] < 1e-15);
+        Ok(())
+    }
+
+    #[cfg(not(backend_pure_rust))]
     #[test]
     fn zgesv_captures_errors() {
         let m = 2;
@@ -436,10 +1036,10 @@ mod tests {
         let mut b = vec![Complex64::new(0.0, 0.0); m];
         let mut ipiv = vec![0; m];
         let m_i32 = to_i32(m);
-        let nrhs = 1_i32;
+        let nrhs = 1;
         assert_eq!(
             zgesv(m_i32, nrhs, &mut a, &mut ipiv, &mut b),
-            Err("LAPACK zgesv failed")
+            Err(Error::Singular("LAPACK zgesv failed"))
         );
     }
 
@@ -455,13 +1055,14 @@ mod tests {
         let mut b = vec![Complex64::new(0.0, 0.0); m];
         let mut ipiv = vec![0; 1]; // << ERROR
         let m_i32 = to_i32(m);
-        let nrhs = 1_i32;
+        let nrhs = 1;
         assert_eq!(
             zgesv(m_i32, nrhs, &mut a, &mut ipiv, &mut b),
-            Err("the length of ipiv must equal n")
+            Err(Error::BadDimensions("the length of ipiv must equal n"))
         );
     }
 
+    #[cfg(not(backend_pure_rust))]
     #[test]
     fn zgesv_works_1() -> Result<(), StrError> {
         // matrix
@@ -484,7 +1085,7 @@ mod tests {
         ];
 
         // solve b := x := A⁻¹ b
-        let (n, nrhs) = (5_i32, 1_i32);
+        let (n, nrhs) = (5, 1);
         let mut ipiv = vec![0; n as usize];
         zgesv(n, nrhs, &mut a, &mut ipiv, &mut b)?;
 
@@ -500,6 +1101,7 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(not(backend_pure_rust))]
     #[test]
     fn zgesv_works_2() -> Result<(), StrError> {
         // NOTE: zgesv performs poorly in this problem.
@@ -535,7 +1137,7 @@ mod tests {
 
         // run test
         // solve b := x := A⁻¹ b
-        let (n, nrhs) = (5_i32, 1_i32);
+        let (n, nrhs) = (5, 1);
         let mut ipiv = vec![0; n as usize];
         zgesv(n, nrhs, &mut a, &mut ipiv, &mut b)?;
         complex_vec_approx_eq(&b, x_correct, 0.00049);
@@ -554,4 +1156,180 @@ mod tests {
         assert_eq!(ipiv, [1, 2, 3, 4, 5]);
         Ok(())
     }
+
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn dposv_captures_errors() {
+        // not positive definite
+        let mut a = col_major(2, 2, &[1.0, 2.0, 2.0, 1.0]);
+        let mut b = vec![1.0, 1.0];
+        assert_eq!(
+            dposv(true, 2, 1, &mut a, &mut b),
+            Err(Error::Singular("LAPACK dposv failed"))
+        );
+    }
+
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn dposv_works() -> Result<(), StrError> {
+        // upper triangle only (the lower part is not accessed)
+        #[rustfmt::skip]
+        let mut a = col_major(3, 3, &[
+            4.0,  2.0, 2.0,
+            -1.0, 5.0, 3.0,
+            -1.0, -1.0, 6.0,
+        ]);
+        // two right-hand sides
+        let mut b = vec![14.0, 21.0, 26.0, 4.0, 2.0, 2.0];
+        dposv(true, 3, 2, &mut a, &mut b)?;
+        vec_approx_eq(&b, &[1.0, 2.0, 3.0, 1.0, 0.0, 0.0], 1e-14);
+
+        // lower triangle only
+        #[rustfmt::skip]
+        let mut a = col_major(3, 3, &[
+            4.0, -1.0, -1.0,
+            2.0,  5.0, -1.0,
+            2.0,  3.0,  6.0,
+        ]);
+        let mut b = vec![14.0, 21.0, 26.0];
+        dposv(false, 3, 1, &mut a, &mut b)?;
+        vec_approx_eq(&b, &[1.0, 2.0, 3.0], 1e-14);
+        Ok(())
+    }
+
+    #[test]
+    fn dgetrs_captures_errors() {
+        let a = vec![1.0, 0.0, 0.0, 1.0];
+        let ipiv = vec![1; 1]; // << ERROR
+        let mut b = vec![1.0, 1.0];
+        assert_eq!(
+            dgetrs(false, 2, 1, &a, &ipiv, &mut b),
+            Err(Error::BadDimensions("the length of ipiv must equal n"))
+        );
+        let ipiv = vec![1; 2];
+        assert_eq!(
+            dgetrs(false, 2, -1, &a, &ipiv, &mut b),
+            Err(Error::InvalidArgument("LAPACK dgetrs failed"))
+        );
+    }
+
+    #[test]
+    fn dgetrs_works() -> Result<(), StrError> {
+        // factors of a = [[1, 2], [3, 4]] computed by dgetrf (rows swapped)
+        // l = [[1, 0], [1/3, 1]] and u = [[3, 4], [0, 2/3]]
+        let a = col_major(2, 2, &[3.0, 4.0, 1.0 / 3.0, 2.0 / 3.0]);
+        let ipiv = vec![2, 2];
+        // two right-hand sides
+        let mut b = vec![3.0, 7.0, 1.0, 3.0];
+        dgetrs(false, 2, 2, &a, &ipiv, &mut b)?;
+        vec_approx_eq(&b, &[1.0, 1.0, 1.0, 0.0], 1e-15);
+        // transposed system
+        let mut b = vec![4.0, 6.0];
+        dgetrs(true, 2, 1, &a, &ipiv, &mut b)?;
+        vec_approx_eq(&b, &[1.0, 1.0], 1e-15);
+        Ok(())
+    }
+
+    #[test]
+    fn dpotrs_captures_errors() {
+        let a = vec![1.0, 0.0, 0.0, 1.0];
+        let mut b = vec![1.0, 1.0];
+        assert_eq!(
+            dpotrs(false, 2, -1, &a, &mut b),
+            Err(Error::InvalidArgument("LAPACK dpotrs failed"))
+        );
+    }
+
+    #[test]
+    fn dpotrs_works() -> Result<(), StrError> {
+        // factor of a = [[4, 2], [2, 5]] computed by dpotrf
+        // l = [[2, 0], [1, 2]] (the -1.0 values are not accessed)
+        let l = col_major(2, 2, &[2.0, -1.0, 1.0, 2.0]);
+        let mut b = vec![6.0, 7.0, 4.0, 2.0];
+        dpotrs(false, 2, 2, &l, &mut b)?;
+        vec_approx_eq(&b, &[1.0, 1.0, 1.0, 0.0], 1e-15);
+        // u = lᵀ
+        let u = col_major(2, 2, &[2.0, 1.0, -1.0, 2.0]);
+        let mut b = vec![6.0, 7.0];
+        dpotrs(true, 2, 1, &u, &mut b)?;
+        vec_approx_eq(&b, &[1.0, 1.0], 1e-15);
+        Ok(())
+    }
+
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn dsysv_captures_errors() {
+        let mut a = vec![0.0; 4];
+        let mut b = vec![1.0, 1.0];
+        let mut ipiv = vec![0; 2];
+        assert_eq!(
+            dsysv(true, 2, 1, &mut a, &mut ipiv, &mut b),
+            Err(Error::Singular("LAPACK dsysv failed"))
+        );
+        let mut ipiv = vec![0; 1]; // << ERROR
+        assert_eq!(
+            dsysv(true, 2, 1, &mut a, &mut ipiv, &mut b),
+            Err(Error::BadDimensions("the length of ipiv must equal n"))
+        );
+    }
+
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn dsysv_works() -> Result<(), StrError> {
+        // symmetric indefinite matrix (upper triangle only)
+        #[rustfmt::skip]
+        let mut a = col_major(3, 3, &[
+            1.0,  2.0, 3.0,
+            -1.0, -4.0, 1.0,
+            -1.0, -1.0, 0.0,
+        ]);
+        let mut b = vec![6.0, -1.0, 4.0];
+        let mut ipiv = vec![0; 3];
+        dsysv(true, 3, 1, &mut a, &mut ipiv, &mut b)?;
+        vec_approx_eq(&b, &[1.0, 1.0, 1.0], 1e-14);
+        Ok(())
+    }
+
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn zposv_captures_errors() {
+        // not positive definite
+        let mut a = vec![Complex64::new(0.0, 0.0); 4];
+        let mut b = vec![Complex64::new(1.0, 0.0); 2];
+        assert_eq!(
+            zposv(false, 2, 1, &mut a, &mut b),
+            Err(Error::Singular("LAPACK zposv failed"))
+        );
+    }
+
+    #[cfg(not(backend_pure_rust))]
+    #[test]
+    fn zposv_works() -> Result<(), StrError> {
+        // Hermitian positive definite matrix (lower triangle only)
+        #[rustfmt::skip]
+        let mut a = col_major_complex(2, 2, &[
+            Complex64::new(2.0, 0.0), Complex64::new(-9.0, 9.0),
+            Complex64::new(1.0, 1.0), Complex64::new( 3.0, 0.0),
+        ]);
+        // x = [1 + i, 2]
+        let mut b = vec![Complex64::new(4.0, 0.0), Complex64::new(6.0, 2.0)];
+        zposv(false, 2, 1, &mut a, &mut b)?;
+        let x_correct = &[Complex64::new(1.0, 1.0), Complex64::new(2.0, 0.0)];
+        complex_vec_approx_eq(&b, x_correct, 1e-14);
+        Ok(())
+    }
+
+    #[cfg(backend_pure_rust)]
+    #[test]
+    fn lapack_functions_return_backend_error() {
+        let mut a = vec![2.0, 0.0, 0.0, 2.0];
+        let mut ipiv = vec![0; 2];
+        let mut b = vec![1.0, 1.0];
+        assert_eq!(
+            dgesv(2, 1, &mut a, &mut ipiv, &mut b),
+            Err(Error::Backend(
+                "LAPACK dgesv is not available with the pure_rust backend"
+            ))
+        );
+    }
 }
@@ -0,0 +1,826 @@
+// Pure-Rust replacements of the BLAS/LAPACK functions (pure_rust backend)
+//
+// The functions have the same names and signatures as the C functions declared in
+// the extern blocks of the other modules; thus, the wrappers are the same for all backends.
+// Only the col-major layout is implemented (the wrappers always use col-major).
+//
+// The BLAS functions, the matrix norms, and the triangular solvers using given factors
+// (dgetrs and dpotrs) are implemented here. The remaining LAPACK functions are not available
+// and return LAPACK_NOT_AVAILABLE as the info code; thus, the corresponding wrappers return an error.
+
+#![allow(non_snake_case)]
+#![allow(clippy::too_many_arguments)]
+
+use crate::constants::{CBLAS_NO_TRANS, CBLAS_UPPER};
+use crate::BlasInt;
+use num_complex::Complex64;
+
+/// Info code returned by the LAPACK functions that are not available with the pure_rust backend
+pub(crate) const LAPACK_NOT_AVAILABLE: BlasInt = -9999;
+
+/// Conjugate transpose (not used by the wrappers, but handled by the complex functions)
+const CBLAS_CONJ_TRANS: i32 = 113;
+
+/// Returns the offset of the i-th element of a strided vector (negative increments start from the end)
+#[inline]
+fn idx(n: BlasInt, inc: BlasInt, i: BlasInt) -> isize {
+    if inc < 0 {
+        ((i - n + 1) * inc) as isize
+    } else {
+        (i * inc) as isize
+    }
+}
+
+/// Returns the offset of the (i,j) element of a col-major matrix
+#[inline]
+fn ij(i: BlasInt, j: BlasInt, ld: BlasInt) -> isize {
+    (i + j * ld) as isize
+}
+
+// BLAS level 1 ////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub(crate) unsafe fn cblas_ddot(n: BlasInt, x: *const f64, incx: BlasInt, y: *const f64, incy: BlasInt) -> f64 {
+    let mut sum = 0.0;
+    for i in 0..n {
+        sum += *x.offset(idx(n, incx, i)) * *y.offset(idx(n, incy, i));
+    }
+    sum
+}
+
+pub(crate) unsafe fn cblas_zdotu_sub(
+    n: BlasInt,
+    x: *const Complex64,
+    incx: BlasInt,
+    y: *const Complex64,
+    incy: BlasInt,
+    dotu: *mut Complex64,
+) {
+    let mut sum = Complex64::new(0.0, 0.0);
+    for i in 0..n {
+        sum += *x.offset(idx(n, incx, i)) * *y.offset(idx(n, incy, i));
+    }
+    *dotu = sum;
+}
+
+pub(crate) unsafe fn cblas_zdotc_sub(
+    n: BlasInt,
+    x: *const Complex64,
+    incx: BlasInt,
+    y: *const Complex64,
+    incy: BlasInt,
+    dotc: *mut Complex64,
+) {
+    let mut sum = Complex64::new(0.0, 0.0);
+    for i in 0..n {
+        sum += (*x.offset(idx(n, incx, i))).conj() * *y.offset(idx(n, incy, i));
+    }
+    *dotc = sum;
+}
+
+pub(crate) unsafe fn cblas_dcopy(n: BlasInt, x: *const f64, incx: BlasInt, y: *mut f64, incy: BlasInt) {
+    for i in 0..n {
+        *y.offset(idx(n, incy, i)) = *x.offset(idx(n, incx, i));
+    }
+}
+
+pub(crate) unsafe fn cblas_zcopy(n: BlasInt, x: *const Complex64, incx: BlasInt, y: *mut Complex64, incy: BlasInt) {
+    for i in 0..n {
+        *y.offset(idx(n, incy, i)) = *x.offset(idx(n, incx, i));
+    }
+}
+
+pub(crate) unsafe fn cblas_dscal(n: BlasInt, alpha: f64, x: *mut f64, incx: BlasInt) {
+    if incx <= 0 {
+        return; // as in the reference BLAS
+    }
+    for i in 0..n {
+        *x.offset(idx(n, incx, i)) *= alpha;
+    }
+}
+
+pub(crate) unsafe fn cblas_zscal(n: BlasInt, alpha: *const Complex64, x: *mut Complex64, incx: BlasInt) {
+    if incx <= 0 {
+        return; // as in the reference BLAS
+    }
+    for i in 0..n {
+        *x.offset(idx(n, incx, i)) *= *alpha;
+    }
+}
+
+pub(crate) unsafe fn cblas_daxpy(n: BlasInt, alpha: f64, x: *const f64, incx: BlasInt, y: *mut f64, incy: BlasInt) {
+    if alpha == 0.0 {
+        return;
+    }
+    for i in 0..n {
+        *y.offset(idx(n, incy, i)) += alpha * *x.offset(idx(n, incx, i));
+    }
+}
+
+pub(crate) unsafe fn cblas_zaxpy(
+    n: BlasInt,
+    alpha: *const Complex64,
+    x: *const Complex64,
+    incx: BlasInt,
+    y: *mut Complex64,
+    incy: BlasInt,
+) {
+    let alpha = *alpha;
+    if alpha == Complex64::new(0.0, 0.0) {
+        return;
+    }
+    for i in 0..n {
+        *y.offset(idx(n, incy, i)) += alpha * *x.offset(idx(n, incx, i));
+    }
+}
+
+pub(crate) unsafe fn cblas_dnrm2(n: BlasInt, x: *const f64, incx: BlasInt) -> f64 {
+    if incx <= 0 {
+        return 0.0; // as in the reference BLAS
+    }
+    // scaled sum of squares to avoid overflow and underflow
+    let mut scale = 0.0;
+    let mut ssq = 1.0;
+    for i in 0..n {
+        let v = *x.offset(idx(n, incx, i));
+        if v != 0.0 {
+            let a = f64::abs(v);
+            if scale < a {
+                ssq = 1.0 + ssq * (scale / a) * (scale / a);
+                scale = a;
+            } else {
+                ssq += (a / scale) * (a / scale);
+            }
+        }
+    }
+    scale * f64::sqrt(ssq)
+}
+
+pub(crate) unsafe fn cblas_dznrm2(n: BlasInt, x: *const Complex64, incx: BlasInt) -> f64 {
+    if incx <= 0 {
+        return 0.0; // as in the reference BLAS
+    }
+    // scaled sum of squares of the real and imaginary parts
+    let mut scale = 0.0;
+    let mut ssq = 1.0;
+    for i in 0..n {
+        let v = *x.offset(idx(n, incx, i));
+        for part in [v.re, v.im] {
+            if part != 0.0 {
+                let a = f64::abs(part);
+                if scale < a {
+                    ssq = 1.0 + ssq * (scale / a) * (scale / a);
+                    scale = a;
+                } else {
+                    ssq += (a / scale) * (a / scale);
+                }
+            }
+        }
+    }
+    scale * f64::sqrt(ssq)
+}
+
+pub(crate) unsafe fn cblas_dasum(n: BlasInt, x: *const f64, incx: BlasInt) -> f64 {
+    if incx <= 0 {
+        return 0.0; // as in the reference BLAS
+    }
+    let mut sum = 0.0;
+    for i in 0..n {
+        sum += f64::abs(*x.offset(idx(n, incx, i)));
+    }
+    sum
+}
+
+pub(crate) unsafe fn cblas_idamax(n: BlasInt, x: *const f64, incx: BlasInt) -> BlasInt {
+    if n < 1 || incx <= 0 {
+        return 0;
+    }
+    let mut imax = 0;
+    let mut vmax = f64::abs(*x);
+    for i in 1..n {
+        let v = f64::abs(*x.offset(idx(n, incx, i)));
+        if v > vmax {
+            imax = i;
+            vmax = v;
+        }
+    }
+    imax
+}
+
+// BLAS level 2 ////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub(crate) unsafe fn cblas_dgemv(
+    _order: i32,
+    trans: i32,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: f64,
+    a: *const f64,
+    lda: BlasInt,
+    x: *const f64,
+    incx: BlasInt,
+    beta: f64,
+    y: *mut f64,
+    incy: BlasInt,
+) {
+    let (len_x, len_y) = if trans == CBLAS_NO_TRANS { (n, m) } else { (m, n) };
+    for i in 0..len_y {
+        let mut sum = 0.0;
+        for j in 0..len_x {
+            let aij = if trans == CBLAS_NO_TRANS {
+                *a.offset(ij(i, j, lda))
+            } else {
+                *a.offset(ij(j, i, lda))
+            };
+            sum += aij * *x.offset(idx(len_x, incx, j));
+        }
+        let yi = y.offset(idx(len_y, incy, i));
+        *yi = if beta == 0.0 {
+            alpha * sum
+        } else {
+            alpha * sum + beta * *yi
+        };
+    }
+}
+
+pub(crate) unsafe fn cblas_sgemv(
+    _order: i32,
+    trans: i32,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: f32,
+    a: *const f32,
+    lda: BlasInt,
+    x: *const f32,
+    incx: BlasInt,
+    beta: f32,
+    y: *mut f32,
+    incy: BlasInt,
+) {
+    let (len_x, len_y) = if trans == CBLAS_NO_TRANS { (n, m) } else { (m, n) };
+    for i in 0..len_y {
+        let mut sum = 0.0;
+        for j in 0..len_x {
+            let aij = if trans == CBLAS_NO_TRANS {
+                *a.offset(ij(i, j, lda))
+            } else {
+                *a.offset(ij(j, i, lda))
+            };
+            sum += aij * *x.offset(idx(len_x, incx, j));
+        }
+        let yi = y.offset(idx(len_y, incy, i));
+        *yi = if beta == 0.0 {
+            alpha * sum
+        } else {
+            alpha * sum + beta * *yi
+        };
+    }
+}
+
+pub(crate) unsafe fn cblas_zgemv(
+    _order: i32,
+    trans: i32,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: *const Complex64,
+    a: *const Complex64,
+    lda: BlasInt,
+    x: *const Complex64,
+    incx: BlasInt,
+    beta: *const Complex64,
+    y: *mut Complex64,
+    incy: BlasInt,
+) {
+    let (alpha, beta) = (*alpha, *beta);
+    let (len_x, len_y) = if trans == CBLAS_NO_TRANS { (n, m) } else { (m, n) };
+    for i in 0..len_y {
+        let mut sum = Complex64::new(0.0, 0.0);
+        for j in 0..len_x {
+            let aij = match trans {
+                CBLAS_NO_TRANS => *a.offset(ij(i, j, lda)),
+                CBLAS_CONJ_TRANS => (*a.offset(ij(j, i, lda))).conj(),
+                _ => *a.offset(ij(j, i, lda)),
+            };
+            sum += aij * *x.offset(idx(len_x, incx, j));
+        }
+        let yi = y.offset(idx(len_y, incy, i));
+        *yi = if beta == Complex64::new(0.0, 0.0) {
+            alpha * sum
+        } else {
+            alpha * sum + beta * *yi
+        };
+    }
+}
+
+pub(crate) unsafe fn cblas_dger(
+    _order: i32,
+    m: BlasInt,
+    n: BlasInt,
+    alpha: f64,
+    x: *const f64,
+    incx: BlasInt,
+    y: *const f64,
+    incy: BlasInt,
+    a: *mut f64,
+    lda: BlasInt,
+) {
+    for j in 0..n {
+        let temp = alpha * *y.offset(idx(n, incy, j));
+        for i in 0..m {
+            *a.offset(ij(i, j, lda)) += *x.offset(idx(m, incx, i)) * temp;
+        }
+    }
+}
+
+// BLAS level 3 ////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub(crate) unsafe fn cblas_dgemm(
+    _order: i32,
+    transa: i32,
+    transb: i32,
+    m: BlasInt,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: f64,
+    a: *const f64,
+    lda: BlasInt,
+    b: *const f64,
+    ldb: BlasInt,
+    beta: f64,
+    c: *mut f64,
+    ldc: BlasInt,
+) {
+    for j in 0..n {
+        for i in 0..m {
+            let mut sum = 0.0;
+            for l in 0..k {
+                let ail = if transa == CBLAS_NO_TRANS {
+                    *a.offset(ij(i, l, lda))
+                } else {
+                    *a.offset(ij(l, i, lda))
+                };
+                let blj = if transb == CBLAS_NO_TRANS {
+                    *b.offset(ij(l, j, ldb))
+                } else {
+                    *b.offset(ij(j, l, ldb))
+                };
+                sum += ail * blj;
+            }
+            let cij = c.offset(ij(i, j, ldc));
+            *cij = if beta == 0.0 {
+                alpha * sum
+            } else {
+                alpha * sum + beta * *cij
+            };
+        }
+    }
+}
+
+pub(crate) unsafe fn cblas_sgemm(
+    _order: i32,
+    transa: i32,
+    transb: i32,
+    m: BlasInt,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: f32,
+    a: *const f32,
+    lda: BlasInt,
+    b: *const f32,
+    ldb: BlasInt,
+    beta: f32,
+    c: *mut f32,
+    ldc: BlasInt,
+) {
+    for j in 0..n {
+        for i in 0..m {
+            let mut sum = 0.0;
+            for l in 0..k {
+                let ail = if transa == CBLAS_NO_TRANS {
+                    *a.offset(ij(i, l, lda))
+                } else {
+                    *a.offset(ij(l, i, lda))
+                };
+                let blj = if transb == CBLAS_NO_TRANS {
+                    *b.offset(ij(l, j, ldb))
+                } else {
+                    *b.offset(ij(j, l, ldb))
+                };
+                sum += ail * blj;
+            }
+            let cij = c.offset(ij(i, j, ldc));
+            *cij = if beta == 0.0 {
+                alpha * sum
+            } else {
+                alpha * sum + beta * *cij
+            };
+        }
+    }
+}
+
+/// Returns op(a)(i,j) of a complex matrix
+#[inline]
+unsafe fn z_op(trans: i32, a: *const Complex64, lda: BlasInt, i: BlasInt, j: BlasInt) -> Complex64 {
+    match trans {
+        CBLAS_NO_TRANS => *a.offset(ij(i, j, lda)),
+        CBLAS_CONJ_TRANS => (*a.offset(ij(j, i, lda))).conj(),
+        _ => *a.offset(ij(j, i, lda)),
+    }
+}
+
+pub(crate) unsafe fn cblas_zgemm(
+    _order: i32,
+    transa: i32,
+    transb: i32,
+    m: BlasInt,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: *const Complex64,
+    a: *const Complex64,
+    lda: BlasInt,
+    b: *const Complex64,
+    ldb: BlasInt,
+    beta: *const Complex64,
+    c: *mut Complex64,
+    ldc: BlasInt,
+) {
+    let (alpha, beta) = (*alpha, *beta);
+    for j in 0..n {
+        for i in 0..m {
+            let mut sum = Complex64::new(0.0, 0.0);
+            for l in 0..k {
+                sum += z_op(transa, a, lda, i, l) * z_op(transb, b, ldb, l, j);
+            }
+            let cij = c.offset(ij(i, j, ldc));
+            *cij = if beta == Complex64::new(0.0, 0.0) {
+                alpha * sum
+            } else {
+                alpha * sum + beta * *cij
+            };
+        }
+    }
+}
+
+/// Returns the range of rows of column j in the upper or lower triangle
+#[inline]
+fn triangle(uplo: i32, n: BlasInt, j: BlasInt) -> std::ops::Range<BlasInt> {
+    if uplo == CBLAS_UPPER {
+        0..(j + 1)
+    } else {
+        j..n
+    }
+}
+
+pub(crate) unsafe fn cblas_dsyrk(
+    _order: i32,
+    uplo: i32,
+    trans: i32,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: f64,
+    a: *const f64,
+    lda: BlasInt,
+    beta: f64,
+    c: *mut f64,
+    ldc: BlasInt,
+) {
+    for j in 0..n {
+        for i in triangle(uplo, n, j) {
+            let mut sum = 0.0;
+            for l in 0..k {
+                sum += if trans == CBLAS_NO_TRANS {
+                    *a.offset(ij(i, l, lda)) * *a.offset(ij(j, l, lda))
+                } else {
+                    *a.offset(ij(l, i, lda)) * *a.offset(ij(l, j, lda))
+                };
+            }
+            let cij = c.offset(ij(i, j, ldc));
+            *cij = if beta == 0.0 {
+                alpha * sum
+            } else {
+                alpha * sum + beta * *cij
+            };
+        }
+    }
+}
+
+pub(crate) unsafe fn cblas_zsyrk(
+    _order: i32,
+    uplo: i32,
+    trans: i32,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: *const Complex64,
+    a: *const Complex64,
+    lda: BlasInt,
+    beta: *const Complex64,
+    c: *mut Complex64,
+    ldc: BlasInt,
+) {
+    let (alpha, beta) = (*alpha, *beta);
+    for j in 0..n {
+        for i in triangle(uplo, n, j) {
+            let mut sum = Complex64::new(0.0, 0.0);
+            for l in 0..k {
+                sum += if trans == CBLAS_NO_TRANS {
+                    *a.offset(ij(i, l, lda)) * *a.offset(ij(j, l, lda))
+                } else {
+                    *a.offset(ij(l, i, lda)) * *a.offset(ij(l, j, lda))
+                };
+            }
+            let cij = c.offset(ij(i, j, ldc));
+            *cij = if beta == Complex64::new(0.0, 0.0) {
+                alpha * sum
+            } else {
+                alpha * sum + beta * *cij
+            };
+        }
+    }
+}
+
+pub(crate) unsafe fn cblas_zherk(
+    _order: i32,
+    uplo: i32,
+    trans: i32,
+    n: BlasInt,
+    k: BlasInt,
+    alpha: f64,
+    a: *const Complex64,
+    lda: BlasInt,
+    beta: f64,
+    c: *mut Complex64,
+    ldc: BlasInt,
+) {
+    for j in 0..n {
+        for i in triangle(uplo, n, j) {
+            let mut sum = Complex64::new(0.0, 0.0);
+            for l in 0..k {
+                sum += if trans == CBLAS_NO_TRANS {
+                    *a.offset(ij(i, l, lda)) * (*a.offset(ij(j, l, lda))).conj()
+                } else {
+                    (*a.offset(ij(l, i, lda))).conj() * *a.offset(ij(l, j, lda))
+                };
+            }
+            let cij = c.offset(ij(i, j, ldc));
+            *cij = if beta == 0.0 {
+                alpha * sum
+            } else {
+                alpha * sum + beta * *cij
+            };
+            if i == j {
+                (*cij).im = 0.0;
+            }
+        }
+    }
+}
+
+// LAPACK //////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Computes the norm of a col-major matrix given the absolute values of its entries
+unsafe fn lange<F>(norm: u8, m: BlasInt, n: BlasInt, lda: BlasInt, abs: F) -> f64
+where
+    F: Fn(isize) -> f64,
+{
+    if m < 1 || n < 1 {
+        return 0.0;
+    }
+    let mut res: f64 = 0.0;
+    match norm {
+        b'M' | b'm' => {
+            for j in 0..n {
+                for i in 0..m {
+                    res = f64::max(res, abs(ij(i, j, lda)));
+                }
+            }
+        }
+        b'O' | b'o' | b'1' => {
+            for j in 0..n {
+                res = f64::max(res, (0..m).map(|i| abs(ij(i, j, lda))).sum());
+            }
+        }
+        b'I' | b'i' => {
+            for i in 0..m {
+                res = f64::max(res, (0..n).map(|j| abs(ij(i, j, lda))).sum());
+            }
+        }
+        b'F' | b'f' | b'E' | b'e' => {
+            let mut sum = 0.0;
+            for j in 0..n {
+                for i in 0..m {
+                    sum += f64::powi(abs(ij(i, j, lda)), 2);
+                }
+            }
+            res = f64::sqrt(sum);
+        }
+        _ => {}
+    }
+    res
+}
+
+pub(crate) unsafe fn LAPACKE_dlange(
+    _matrix_layout: i32,
+    norm: u8,
+    m: BlasInt,
+    n: BlasInt,
+    a: *const f64,
+    lda: BlasInt,
+) -> f64 {
+    lange(norm, m, n, lda, |p| f64::abs(*a.offset(p)))
+}
+
+pub(crate) unsafe fn LAPACKE_zlange(
+    _matrix_layout: i32,
+    norm: u8,
+    m: BlasInt,
+    n: BlasInt,
+    a: *const Complex64,
+    lda: BlasInt,
+) -> f64 {
+    lange(norm, m, n, lda, |p| (*a.offset(p)).norm())
+}
+
+/// Checks the arguments of the triangular solvers (returns the LAPACK info code)
+fn check_trs(n: BlasInt, nrhs: BlasInt, lda: BlasInt, ldb: BlasInt, ldb_pos: BlasInt) -> BlasInt {
+    if n < 0 {
+        -3
+    } else if nrhs < 0 {
+        -4
+    } else if lda < BlasInt::max(1, n) {
+        -6
+    } else if ldb < BlasInt::max(1, n) {
+        -ldb_pos
+    } else {
+        0
+    }
+}
+
+pub(crate) unsafe fn LAPACKE_dgetrs(
+    _matrix_layout: i32,
+    trans: u8,
+    n: BlasInt,
+    nrhs: BlasInt,
+    a: *const f64,
+    lda: BlasInt,
+    ipiv: *const BlasInt,
+    b: *mut f64,
+    ldb: BlasInt,
+) -> BlasInt {
+    let info = check_trs(n, nrhs, lda, ldb, 9);
+    if info != 0 {
+        return info;
+    }
+    let a = |i: BlasInt, j: BlasInt| *a.offset(ij(i, j, lda));
+    // NOTE: ipiv are 1-based indices
+    let p = |k: BlasInt| *ipiv.offset(k as isize) - 1;
+    for r in 0..nrhs {
+        let x = |i: BlasInt| b.offset(ij(i, r, ldb));
+        if trans == b'N' || trans == b'n' {
+            // b := P ⋅ b
+            for k in 0..n {
+                std::ptr::swap(x(k), x(p(k)));
+            }
+            // forward substitution with the unit lower triangular L
+            for i in 0..n {
+                for j in 0..i {
+                    *x(i) -= a(i, j) * *x(j);
+                }
+            }
+            // backward substitution with the upper triangular U
+            for i in (0..n).rev() {
+                for j in (i + 1)..n {
+                    *x(i) -= a(i, j) * *x(j);
+                }
+                *x(i) /= a(i, i);
+            }
+        } else {
+            // forward substitution with Uᵀ
+            for i in 0..n {
+                for j in 0..i {
+                    *x(i) -= a(j, i) * *x(j);
+                }
+                *x(i) /= a(i, i);
+            }
+            // backward substitution with the unit upper triangular Lᵀ
+            for i in (0..n).rev() {
+                for j in (i + 1)..n {
+                    *x(i) -= a(j, i) * *x(j);
+                }
+            }
+            // b := Pᵀ ⋅ b
+            for k in (0..n).rev() {
+                std::ptr::swap(x(k), x(p(k)));
+            }
+        }
+    }
+    0
+}
+
+pub(crate) unsafe fn LAPACKE_dpotrs(
+    _matrix_layout: i32,
+    uplo: u8,
+    n: BlasInt,
+    nrhs: BlasInt,
+    a: *const f64,
+    lda: BlasInt,
+    b: *mut f64,
+    ldb: BlasInt,
+) -> BlasInt {
+    let info = check_trs(n, nrhs, lda, ldb, 8);
+    if info != 0 {
+        return info;
+    }
+    // element (i,j), with j ≤ i, of the lower triangular factor L (or Uᵀ)
+    let up = uplo == b'U' || uplo == b'u';
+    let low = |i: BlasInt, j: BlasInt| {
+        if up {
+            *a.offset(ij(j, i, lda))
+        } else {
+            *a.offset(ij(i, j, lda))
+        }
+    };
+    for r in 0..nrhs {
+        let x = |i: BlasInt| b.offset(ij(i, r, ldb));
+        // forward substitution: L ⋅ y = b
+        for i in 0..n {
+            for j in 0..i {
+                *x(i) -= low(i, j) * *x(j);
+            }
+            *x(i) /= low(i, i);
+        }
+        // backward substitution: Lᵀ ⋅ x = y
+        for i in (0..n).rev() {
+            for j in (i + 1)..n {
+                *x(i) -= low(j, i) * *x(j);
+            }
+            *x(i) /= low(i, i);
+        }
+    }
+    0
+}
+
+/// Declares LAPACK functions that are not available with the pure_rust backend
+macro_rules! not_available {
+    ($(fn $name:ident($($arg:ident: $t:ty),*);)*) => {
+        $(
+            #[allow(unused_variables)]
+            pub(crate) unsafe fn $name($($arg: $t),*) -> BlasInt {
+                LAPACK_NOT_AVAILABLE
+            }
+        )*
+    };
+}
+
+#[rustfmt::skip]
+not_available! {
+    fn LAPACKE_dgesv(matrix_layout: i32, n: BlasInt, nrhs: BlasInt, a: *mut f64, lda: BlasInt, ipiv: *mut BlasInt, b: *mut f64, ldb: BlasInt);
+    fn LAPACKE_dgesvx(matrix_layout: i32, fact: u8, trans: u8, n: BlasInt, nrhs: BlasInt, a: *mut f64, lda: BlasInt, af: *mut f64, ldaf: BlasInt, ipiv: *mut BlasInt, equed: *mut u8, r: *mut f64, c: *mut f64, b: *mut f64, ldb: BlasInt, x: *mut f64, ldx: BlasInt, rcond: *mut f64, ferr: *mut f64, berr: *mut f64, rpivot: *mut f64);
+    fn LAPACKE_zgesv(matrix_layout: i32, n: BlasInt, nrhs: BlasInt, a: *mut Complex64, lda: BlasInt, ipiv: *mut BlasInt, b: *mut Complex64, ldb: BlasInt);
+    fn LAPACKE_dposv(matrix_layout: i32, uplo: u8, n: BlasInt, nrhs: BlasInt, a: *mut f64, lda: BlasInt, b: *mut f64, ldb: BlasInt);
+    fn LAPACKE_dsysv(matrix_layout: i32, uplo: u8, n: BlasInt, nrhs: BlasInt, a: *mut f64, lda: BlasInt, ipiv: *mut BlasInt, b: *mut f64, ldb: BlasInt);
+    fn LAPACKE_zposv(matrix_layout: i32, uplo: u8, n: BlasInt, nrhs: BlasInt, a: *mut Complex64, lda: BlasInt, b: *mut Complex64, ldb: BlasInt);
+    fn LAPACKE_dgesvd(matrix_layout: i32, jobu: u8, jobvt: u8, m: BlasInt, n: BlasInt, a: *mut f64, lda: BlasInt, s: *mut f64, u: *mut f64, ldu: BlasInt, vt: *mut f64, ldvt: BlasInt, superb: *mut f64);
+    fn LAPACKE_zgesvd(matrix_layout: i32, jobu: u8, jobvt: u8, m: BlasInt, n: BlasInt, a: *mut Complex64, lda: BlasInt, s: *mut f64, u: *mut Complex64, ldu: BlasInt, vt: *mut Complex64, ldvt: BlasInt, superb: *mut f64);
+    fn LAPACKE_dgetrf(matrix_layout: i32, m: BlasInt, n: BlasInt, a: *mut f64, lda: BlasInt, ipiv: *mut BlasInt);
+    fn LAPACKE_zgetrf(matrix_layout: i32, m: BlasInt, n: BlasInt, a: *mut Complex64, lda: BlasInt, ipiv: *mut BlasInt);
+    fn LAPACKE_dgetri(matrix_layout: i32, n: BlasInt, a: *mut f64, lda: BlasInt, ipiv: *const BlasInt);
+    fn LAPACKE_zgetri(matrix_layout: i32, n: BlasInt, a: *mut Complex64, lda: BlasInt, ipiv: *const BlasInt);
+    fn LAPACKE_dgeequ(matrix_layout: i32, m: BlasInt, n: BlasInt, a: *const f64, lda: BlasInt, r: *mut f64, c: *mut f64, rowcnd: *mut f64, colcnd: *mut f64, amax: *mut f64);
+    fn LAPACKE_dlaqge(matrix_layout: i32, m: BlasInt, n: BlasInt, a: *mut f64, lda: BlasInt, r: *const f64, c: *const f64, rowcnd: f64, colcnd: f64, amax: f64, equed: *mut u8);
+    fn LAPACKE_dpotrf(matrix_layout: i32, uplo: u8, n: BlasInt, a: *mut f64, lda: BlasInt);
+    fn LAPACKE_zpotrf(matrix_layout: i32, uplo: u8, n: BlasInt, a: *mut Complex64, lda: BlasInt);
+    fn LAPACKE_dgeev(matrix_layout: i32, jobvl: u8, jobvr: u8, n: BlasInt, a: *mut f64, lda: BlasInt, wr: *mut f64, wi: *mut f64, vl: *mut f64, ldvl: BlasInt, vr: *mut f64, ldvr: BlasInt);
+    fn LAPACKE_zgeev(matrix_layout: i32, jobvl: u8, jobvr: u8, n: BlasInt, a: *mut Complex64, lda: BlasInt, w: *mut Complex64, vl: *mut Complex64, ldvl: BlasInt, vr: *mut Complex64, ldvr: BlasInt);
+    fn LAPACKE_dsyev(matrix_layout: i32, jobz: u8, uplo: u8, n: BlasInt, a: *mut f64, lda: BlasInt, w: *mut f64);
+    fn LAPACKE_dgeqrf_work(matrix_layout: i32, m: BlasInt, n: BlasInt, a: *mut f64, lda: BlasInt, tau: *mut f64, work: *mut f64, lwork: BlasInt);
+    fn LAPACKE_dorgqr_work(matrix_layout: i32, m: BlasInt, n: BlasInt, k: BlasInt, a: *mut f64, lda: BlasInt, tau: *const f64, work: *mut f64, lwork: BlasInt);
+    fn LAPACKE_dormqr_work(matrix_layout: i32, side: u8, trans: u8, m: BlasInt, n: BlasInt, k: BlasInt, a: *const f64, lda: BlasInt, tau: *const f64, c: *mut f64, ldc: BlasInt, work: *mut f64, lwork: BlasInt);
+    fn LAPACKE_dgelqf_work(matrix_layout: i32, m: BlasInt, n: BlasInt, a: *mut f64, lda: BlasInt, tau: *mut f64, work: *mut f64, lwork: BlasInt);
+    fn LAPACKE_dgebrd_work(matrix_layout: i32, m: BlasInt, n: BlasInt, a: *mut f64, lda: BlasInt, d: *mut f64, e: *mut f64, tauq: *mut f64, taup: *mut f64, work: *mut f64, lwork: BlasInt);
+    fn LAPACKE_dorgbr_work(matrix_layout: i32, vect: u8, m: BlasInt, n: BlasInt, k: BlasInt, a: *mut f64, lda: BlasInt, tau: *const f64, work: *mut f64, lwork: BlasInt);
+    fn LAPACKE_dggsvd3(matrix_layout: i32, jobu: u8, jobv: u8, jobq: u8, m: BlasInt, n: BlasInt, p: BlasInt, k: *mut BlasInt, l: *mut BlasInt, a: *mut f64, lda: BlasInt, b: *mut f64, ldb: BlasInt, alpha: *mut f64, beta: *mut f64, u: *mut f64, ldu: BlasInt, v: *mut f64, ldv: BlasInt, q: *mut f64, ldq: BlasInt, iwork: *mut BlasInt);
+    fn LAPACKE_dgelsd(matrix_layout: i32, m: BlasInt, n: BlasInt, nrhs: BlasInt, a: *mut f64, lda: BlasInt, b: *mut f64, ldb: BlasInt, s: *mut f64, rcond: f64, rank: *mut BlasInt);
+    fn LAPACKE_dgelsy(matrix_layout: i32, m: BlasInt, n: BlasInt, nrhs: BlasInt, a: *mut f64, lda: BlasInt, b: *mut f64, ldb: BlasInt, jpvt: *mut BlasInt, rcond: f64, rank: *mut BlasInt);
+    fn LAPACKE_dsyevd(matrix_layout: i32, jobz: u8, uplo: u8, n: BlasInt, a: *mut f64, lda: BlasInt, w: *mut f64);
+    fn LAPACKE_dsyevr(matrix_layout: i32, jobz: u8, range: u8, uplo: u8, n: BlasInt, a: *mut f64, lda: BlasInt, vl: f64, vu: f64, il: BlasInt, iu: BlasInt, abstol: f64, m: *mut BlasInt, w: *mut f64, z: *mut f64, ldz: BlasInt, isuppz: *mut BlasInt);
+    fn LAPACKE_zheevr(matrix_layout: i32, jobz: u8, range: u8, uplo: u8, n: BlasInt, a: *mut Complex64, lda: BlasInt, vl: f64, vu: f64, il: BlasInt, iu: BlasInt, abstol: f64, m: *mut BlasInt, w: *mut f64, z: *mut Complex64, ldz: BlasInt, isuppz: *mut BlasInt);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{idx, LAPACKE_dgetrf, LAPACK_NOT_AVAILABLE};
+
+    #[test]
+    fn idx_works() {
+        assert_eq!((0..3).map(|i| idx(3, 2, i)).collect::<Vec<_>>(), &[0, 2, 4]);
+        assert_eq!((0..3).map(|i| idx(3, -2, i)).collect::<Vec<_>>(), &[4, 2, 0]);
+    }
+
+    #[test]
+    fn lapack_functions_are_not_available() {
+        let mut a = [1.0];
+        let mut ipiv = [0];
+        let info = unsafe { LAPACKE_dgetrf(102, 1, 1, a.as_mut_ptr(), 1, ipiv.as_mut_ptr()) };
+        assert_eq!(info, LAPACK_NOT_AVAILABLE);
+    }
+}
@@ -1,40 +1,58 @@
 use std::convert::TryFrom;
 
-/// Converts number to i32
+/// Defines the integer type of the BLAS/LAPACK interface
+///
+/// This type is `i32` (LP64 interface) by default and `i64` (ILP64 interface) if the `ilp64` feature is enabled.
+/// The ILP64 interface is required for matrices with more than 2³¹ - 1 entries.
+#[cfg(not(feature = "ilp64"))]
+pub type BlasInt = i32;
+
+/// Defines the integer type of the BLAS/LAPACK interface
+///
+/// This type is `i32` (LP64 interface) by default and `i64` (ILP64 interface) if the `ilp64` feature is enabled.
+/// The ILP64 interface is required for matrices with more than 2³¹ - 1 entries.
+#[cfg(feature = "ilp64")]
+pub type BlasInt = i64;
+
+/// Converts number to the integer type of the BLAS/LAPACK interface (i32 or i64; see [BlasInt])
+///
+/// # Panics
+///
+/// Panics if the number does not fit into [BlasInt].
 ///
 /// # Example
 ///
 /// ```
 /// use russell_openblas::to_i32;
-/// use std::convert::TryFrom;
 /// let m = 3_usize;
 /// let x = vec![0.0; m];
 /// let m_i32 = to_i32(x.len());
 /// ```
-///
-/// # Note
-///
-/// Remember to import:
-///
-/// ```text
-/// use std::convert::TryFrom;
-/// ```
 #[inline]
-pub fn to_i32(num: usize) -> i32 {
-    i32::try_from(num).unwrap()
+pub fn to_i32(num: usize) -> BlasInt {
+    BlasInt::try_from(num).unwrap()
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::to_i32;
+    use super::{to_i32, BlasInt};
 
     #[test]
     fn usize_to_i32_works() {
         let m = 2_usize;
         let x = vec![0.0; m];
         let m_i32 = to_i32(x.len());
-        assert_eq!(m_i32, 2_i32);
+        assert_eq!(m_i32, 2);
+    }
+
+    #[test]
+    fn blas_int_has_the_right_size() {
+        if cfg!(feature = "ilp64") {
+            assert_eq!(std::mem::size_of::<BlasInt>(), 8);
+        } else {
+            assert_eq!(std::mem::size_of::<BlasInt>(), 4);
+        }
     }
 }
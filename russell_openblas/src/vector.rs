@@ -1,17 +1,46 @@
+use crate::BlasInt;
 use num_complex::Complex64;
 
+#[cfg(backend_pure_rust)]
+use crate::pure_rust::*;
+
+#[cfg(not(backend_pure_rust))]
 extern "C" {
     // from /usr/include/x86_64-linux-gnu/cblas.h
-    fn cblas_ddot(n: i32, x: *const f64, incx: i32, y: *const f64, incy: i32) -> f64;
-    fn cblas_dcopy(n: i32, x: *const f64, incx: i32, y: *mut f64, incy: i32);
-    fn cblas_zcopy(n: i32, x: *const Complex64, incx: i32, y: *mut Complex64, incy: i32);
-    fn cblas_dscal(n: i32, alpha: f64, x: *const f64, incx: i32);
-    fn cblas_zscal(n: i32, alpha: *const Complex64, x: *const Complex64, incx: i32);
-    fn cblas_daxpy(n: i32, alpha: f64, x: *const f64, incx: i32, y: *mut f64, incy: i32);
-    fn cblas_zaxpy(n: i32, alpha: *const Complex64, x: *const Complex64, incx: i32, y: *mut Complex64, incy: i32);
-    fn cblas_dnrm2(n: i32, x: *const f64, incx: i32) -> f64;
-    fn cblas_dasum(n: i32, x: *const f64, incx: i32) -> f64;
-    fn cblas_idamax(n: i32, x: *const f64, incx: i32) -> i32;
+    fn cblas_ddot(n: BlasInt, x: *const f64, incx: BlasInt, y: *const f64, incy: BlasInt) -> f64;
+    fn cblas_zdotu_sub(
+        n: BlasInt,
+        x: *const Complex64,
+        incx: BlasInt,
+        y: *const Complex64,
+        incy: BlasInt,
+        dotu: *mut Complex64,
+    );
+    fn cblas_zdotc_sub(
+        n: BlasInt,
+        x: *const Complex64,
+        incx: BlasInt,
+        y: *const Complex64,
+        incy: BlasInt,
+        dotc: *mut Complex64,
+    );
+    fn cblas_dcopy(n: BlasInt, x: *const f64, incx: BlasInt, y: *mut f64, incy: BlasInt);
+    fn cblas_zcopy(n: BlasInt, x: *const Complex64, incx: BlasInt, y: *mut Complex64, incy: BlasInt);
+    fn cblas_dscal(n: BlasInt, alpha: f64, x: *mut f64, incx: BlasInt);
+    fn cblas_zscal(n: BlasInt, alpha: *const Complex64, x: *mut Complex64, incx: BlasInt);
+    fn cblas_daxpy(n: BlasInt, alpha: f64, x: *const f64, incx: BlasInt, y: *mut f64, incy: BlasInt);
+    fn cblas_zaxpy(
+        n: BlasInt,
+        alpha: *const Complex64,
+        x: *const Complex64,
+        incx: BlasInt,
+        y: *mut Complex64,
+        incy: BlasInt,
+    );
+    fn cblas_dnrm2(n: BlasInt, x: *const f64, incx: BlasInt) -> f64;
+    fn cblas_dznrm2(n: BlasInt, x: *const Complex64, incx: BlasInt) -> f64;
+    fn cblas_dasum(n: BlasInt, x: *const f64, incx: BlasInt) -> f64;
+    fn cblas_idamax(n: BlasInt, x: *const f64, incx: BlasInt) -> BlasInt;
 }
 
 /// Calculates the dot product of two vectors
@@ -29,10 +58,52 @@ extern "C" {
 /// <http://www.netlib.org/lapack/explore-html/d5/df6/ddot_8f.html>
 ///
 #[inline]
-pub fn ddot(n: i32, x: &[f64], incx: i32, y: &[f64], incy: i32) -> f64 {
+pub fn ddot(n: BlasInt, x: &[f64], incx: BlasInt, y: &[f64], incy: BlasInt) -> f64 {
     unsafe { cblas_ddot(n, x.as_ptr(), incx, y.as_ptr(), incy) }
 }
 
+/// Calculates the unconjugated dot product of two vectors (complex version)
+///
+/// ```text
+/// xᵀ ⋅ y
+/// ```
+///
+/// # Reference
+///
+/// <http://www.netlib.org/lapack/explore-html/db/d2d/zdotu_8f.html>
+///
+#[inline]
+pub fn zdotu(n: BlasInt, x: &[Complex64], incx: BlasInt, y: &[Complex64], incy: BlasInt) -> Complex64 {
+    let mut dotu = Complex64::new(0.0, 0.0);
+    unsafe {
+        cblas_zdotu_sub(n, x.as_ptr(), incx, y.as_ptr(), incy, &mut dotu);
+    }
+    dotu
+}
+
+/// Calculates the conjugated dot product of two vectors (complex version)
+///
+/// ```text
+/// xᴴ ⋅ y
+/// ```
+///
+/// # Note
+///
+/// The first vector (x) is conjugated.
+///
+/// # Reference
+///
+/// <http://www.netlib.org/lapack/explore-html/d6/db8/zdotc_8f.html>
+///
+#[inline]
+pub fn zdotc(n: BlasInt, x: &[Complex64], incx: BlasInt, y: &[Complex64], incy: BlasInt) -> Complex64 {
+    let mut dotc = Complex64::new(0.0, 0.0);
+    unsafe {
+        cblas_zdotc_sub(n, x.as_ptr(), incx, y.as_ptr(), incy, &mut dotc);
+    }
+    dotc
+}
+
 /// Copies a vector into another
 ///
 /// ```text
@@ -48,7 +119,7 @@ pub fn ddot(n: i32, x: &[f64], incx: i32, y: &[f64], incy: i32) -> f64 {
 /// <https://www.netlib.org/lapack/explore-html/da/d6c/dcopy_8f.html>
 ///
 #[inline]
-pub fn dcopy(n: i32, x: &[f64], incx: i32, y: &mut [f64], incy: i32) {
+pub fn dcopy(n: BlasInt, x: &[f64], incx: BlasInt, y: &mut [f64], incy: BlasInt) {
     unsafe {
         cblas_dcopy(n, x.as_ptr(), incx, y.as_mut_ptr(), incy);
     }
@@ -65,7 +136,7 @@ pub fn dcopy(n: i32, x: &[f64], incx: i32, y: &mut [f64], incy: i32) {
 /// <https://www.netlib.org/lapack/explore-html/d6/d53/zcopy_8f.html>
 ///
 #[inline]
-pub fn zcopy(n: i32, x: &[Complex64], incx: i32, y: &mut [Complex64], incy: i32) {
+pub fn zcopy(n: BlasInt, x: &[Complex64], incx: BlasInt, y: &mut [Complex64], incy: BlasInt) {
     unsafe {
         cblas_zcopy(n, x.as_ptr(), incx, y.as_mut_ptr(), incy);
     }
@@ -86,9 +157,9 @@ pub fn zcopy(n: i32, x: &[Complex64], incx: i32, y: &mut [Complex64], incy: i32)
 /// <http://www.netlib.org/lapack/explore-html/d4/dd0/dscal_8f.html>
 ///
 #[inline]
-pub fn dscal(n: i32, alpha: f64, x: &mut [f64], incx: i32) {
+pub fn dscal(n: BlasInt, alpha: f64, x: &mut [f64], incx: BlasInt) {
     unsafe {
-        cblas_dscal(n, alpha, x.as_ptr(), incx);
+        cblas_dscal(n, alpha, x.as_mut_ptr(), incx);
     }
 }
 
@@ -103,9 +174,9 @@ pub fn dscal(n: i32, alpha: f64, x: &mut [f64], incx: i32) {
 /// <http://www.netlib.org/lapack/explore-html/d2/d74/zscal_8f.html>
 ///
 #[inline]
-pub fn zscal(n: i32, alpha: Complex64, x: &mut [Complex64], incx: i32) {
+pub fn zscal(n: BlasInt, alpha: Complex64, x: &mut [Complex64], incx: BlasInt) {
     unsafe {
-        cblas_zscal(n, &alpha, x.as_ptr(), incx);
+        cblas_zscal(n, &alpha, x.as_mut_ptr(), incx);
     }
 }
 
@@ -120,7 +191,7 @@ pub fn zscal(n: i32, alpha: Complex64, x: &mut [Complex64], incx: i32) {
 /// <http://www.netlib.org/lapack/explore-html/d9/dcd/daxpy_8f.html>
 ///
 #[inline]
-pub fn daxpy(n: i32, alpha: f64, x: &[f64], incx: i32, y: &mut [f64], incy: i32) {
+pub fn daxpy(n: BlasInt, alpha: f64, x: &[f64], incx: BlasInt, y: &mut [f64], incy: BlasInt) {
     unsafe {
         cblas_daxpy(n, alpha, x.as_ptr(), incx, y.as_mut_ptr(), incy);
     }
@@ -137,7 +208,7 @@ pub fn daxpy(n: i32, alpha: f64, x: &[f64], incx: i32, y: &mut [f64], incy: i32)
 /// <http://www.netlib.org/lapack/explore-html/d7/db2/zaxpy_8f.html>
 ///
 #[inline]
-pub fn zaxpy(n: i32, alpha: Complex64, x: &[Complex64], incx: i32, y: &mut [Complex64], incy: i32) {
+pub fn zaxpy(n: BlasInt, alpha: Complex64, x: &[Complex64], incx: BlasInt, y: &mut [Complex64], incy: BlasInt) {
     unsafe {
         cblas_zaxpy(n, &alpha, x.as_ptr(), incx, y.as_mut_ptr(), incy);
     }
@@ -154,7 +225,7 @@ pub fn zaxpy(n: i32, alpha: Complex64, x: &[Complex64], incx: i32, y: &mut [Comp
 /// <http://www.netlib.org/lapack/explore-html/de/d05/dasum_8f.html>
 ///
 #[inline]
-pub fn dasum(n: i32, x: &[f64], incx: i32) -> f64 {
+pub fn dasum(n: BlasInt, x: &[f64], incx: BlasInt) -> f64 {
     unsafe { cblas_dasum(n, x.as_ptr(), incx) }
 }
 
@@ -169,10 +240,25 @@ pub fn dasum(n: i32, x: &[f64], incx: i32) -> f64 {
 /// <http://www.netlib.org/lapack/explore-html/d6/de0/dnrm2_8f90.html>
 ///
 #[inline]
-pub fn dnrm2(n: i32, x: &[f64], incx: i32) -> f64 {
+pub fn dnrm2(n: BlasInt, x: &[f64], incx: BlasInt) -> f64 {
     unsafe { cblas_dnrm2(n, x.as_ptr(), incx) }
 }
 
+/// Computes the Euclidean norm (complex version)
+///
+/// ```text
+/// ‖x‖₂ := sqrt(xᴴ ⋅ x)
+/// ```
+///
+/// # Reference
+///
+/// <http://www.netlib.org/lapack/explore-html/d9/d19/dznrm2_8f90.html>
+///
+#[inline]
+pub fn dznrm2(n: BlasInt, x: &[Complex64], incx: BlasInt) -> f64 {
+    unsafe { cblas_dznrm2(n, x.as_ptr(), incx) }
+}
+
 /// Finds the index of the maximum absolute value
 ///
 /// # Note
@@ -184,7 +270,7 @@ pub fn dnrm2(n: i32, x: &[f64], incx: i32) -> f64 {
 /// <http://www.netlib.org/lapack/explore-html/dd/de0/idamax_8f.html>
 ///
 #[inline]
-pub fn idamax(n: i32, x: &[f64], incx: i32) -> i32 {
+pub fn idamax(n: BlasInt, x: &[f64], incx: BlasInt) -> BlasInt {
     unsafe { cblas_idamax(n, x.as_ptr(), incx) }
 }
 
@@ -192,7 +278,7 @@ pub fn idamax(n: i32, x: &[f64], incx: i32) -> i32 {
 
 #[cfg(test)]
 mod tests {
-    use super::{dasum, daxpy, dcopy, ddot, dnrm2, dscal, idamax, zaxpy, zcopy, zscal};
+    use super::{dasum, daxpy, dcopy, ddot, dnrm2, dscal, dznrm2, idamax, zaxpy, zcopy, zdotc, zdotu, zscal};
     use crate::to_i32;
     use num_complex::Complex64;
     use russell_chk::{approx_eq, complex_vec_approx_eq, vec_approx_eq};
@@ -334,7 +420,7 @@ mod tests {
             Complex64::new(666.0, -0.5), // 3
             Complex64::new(666.0, 5.0),  // 4
         ];
-        let (n, incx, incy) = (x.len() as i32, 1, 1);
+        let (n, incx, incy) = (to_i32(x.len()), 1, 1);
         zaxpy(n, Complex64::new(1.0, 0.0), &x, incx, &mut y, incy);
         let x_correct = &[
             Complex64::new(20.0, 1.0),   // 0
@@ -367,21 +453,48 @@ mod tests {
     #[test]
     fn dasum_works() {
         let x = [-1.0, 1.0, -1.0, 1.0, 2.0, -2.0];
-        let (n, incx) = (to_i32(x.len()), 1_i32);
+        let (n, incx) = (to_i32(x.len()), 1);
         approx_eq(dasum(n, &x, incx), 8.0, 1e-15);
     }
 
     #[test]
     fn dnrm2_works() {
         let x = [1.0, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0, 3.0];
-        let (n, incx) = (to_i32(x.len()), 1_i32);
+        let (n, incx) = (to_i32(x.len()), 1);
         approx_eq(dnrm2(n, &x, incx), 5.0, 1e-15);
     }
 
+    #[test]
+    fn zdotu_and_zdotc_work() {
+        const IGNORED: Complex64 = Complex64::new(100000.0, 100000.0);
+        let x = [Complex64::new(1.0, 2.0), Complex64::new(3.0, -1.0), IGNORED];
+        let y = [Complex64::new(2.0, 1.0), Complex64::new(-1.0, 4.0), IGNORED];
+        let (n, incx, incy) = (2, 1, 1);
+        // (1+2i)(2+i) + (3-i)(-1+4i) = 5i + (1+13i)
+        let dotu = zdotu(n, &x, incx, &y, incy);
+        assert_eq!(dotu, Complex64::new(1.0, 18.0));
+        // (1-2i)(2+i) + (3+i)(-1+4i) = (4-3i) + (-7+11i)
+        let dotc = zdotc(n, &x, incx, &y, incy);
+        assert_eq!(dotc, Complex64::new(-3.0, 8.0));
+        // with increments
+        let x = [Complex64::new(1.0, 1.0), IGNORED, Complex64::new(0.0, 2.0)];
+        let y = [Complex64::new(1.0, 0.0), Complex64::new(1.0, 1.0)];
+        let (n, incx, incy) = (2, 2, 1);
+        // (1-i)(1) + (-2i)(1+i) = (1-i) + (2-2i)
+        assert_eq!(zdotc(n, &x, incx, &y, incy), Complex64::new(3.0, -3.0));
+    }
+
+    #[test]
+    fn dznrm2_works() {
+        let x = [Complex64::new(1.0, 2.0), Complex64::new(2.0, 4.0)];
+        let (n, incx) = (to_i32(x.len()), 1);
+        approx_eq(dznrm2(n, &x, incx), 5.0, 1e-15);
+    }
+
     #[test]
     fn idamax_works() {
         let x = [1.0, 2.0, 7.0, -8.0, -5.0, -10.0, -9.0, 10.0, 6.0];
-        let (n, incx) = (to_i32(x.len()), 1_i32);
+        let (n, incx) = (to_i32(x.len()), 1);
         let idx = idamax(n, &x, incx);
         assert_eq!(idx, 5);
     }
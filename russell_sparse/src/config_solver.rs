@@ -1,5 +1,4 @@
-use super::{str_enum_ordering, str_enum_scaling, LinSolKind, Ordering, Scaling};
-use russell_openblas::to_i32;
+use super::{str_enum_ordering, str_enum_scaling, to_i32, LinSolKind, Ordering, Scaling};
 use std::fmt;
 
 /// Holds configuration options for the sparse Solver
@@ -0,0 +1,270 @@
+use crate::csr_matrix::{check_compressed, compress};
+use crate::{to_i32, Error, SparseTriplet};
+use russell_lab::Matrix;
+
+/// Holds the arrays needed for a CSC (compressed sparse column) matrix
+///
+/// The non-zero values of column `j` are stored in `values[col_pointers[j]..col_pointers[j+1]]`
+/// and their row indices in `row_indices[col_pointers[j]..col_pointers[j+1]]`. Within each column,
+/// the row indices are sorted in ascending order and there are no duplicates.
+///
+/// # Example
+///
+/// ```text
+///     ┌                ┐
+///     │  2  3  0  0  0 │
+///     │  3  0  4  0  6 │
+/// A = │  0 -1 -3  2  0 │
+///     │  0  0  1  0  0 │
+///     │  0  4  2  0  1 │
+///     └                ┘
+/// col_pointers = [0,    2,        5,             9, 10,  12]
+///  row_indices = [0, 1, 0,  2, 4, 1,  2, 3, 4,   2, 1, 4]
+///       values = [2, 3, 3, -1, 4, 4, -3, 1, 2,   2, 6, 1]
+/// ```
+#[derive(Clone, Debug)]
+pub struct CscMatrix {
+    pub(crate) nrow: usize,            // [i32] number of rows
+    pub(crate) ncol: usize,            // [i32] number of columns
+    pub(crate) col_pointers: Vec<i32>, // [ncol + 1] pointers to the first entry of each column
+    pub(crate) row_indices: Vec<i32>,  // [nnz] row indices
+    pub(crate) values: Vec<f64>,       // [nnz] non-zero values
+}
+
+impl CscMatrix {
+    /// Creates a new CSC matrix from the compressed arrays
+    ///
+    /// # Input
+    ///
+    /// * `nrow` -- number of rows
+    /// * `ncol` -- number of columns
+    /// * `col_pointers` -- (ncol + 1) pointers to the first entry of each column; the last pointer is nnz
+    /// * `row_indices` -- (nnz) row indices, sorted within each column and without duplicates
+    /// * `values` -- (nnz) the non-zero values
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_sparse::{CscMatrix, StrError};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     // ┌       ┐
+    ///     // │ 1 0 2 │
+    ///     // │ 0 0 3 │
+    ///     // └       ┘
+    ///     let csc = CscMatrix::new(2, 3, vec![0, 1, 1, 3], vec![0, 0, 1], vec![1.0, 2.0, 3.0])?;
+    ///     assert_eq!(csc.get(1, 2), 3.0);
+    ///     assert_eq!(csc.get(1, 0), 0.0);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(
+        nrow: usize,
+        ncol: usize,
+        col_pointers: Vec<i32>,
+        row_indices: Vec<i32>,
+        values: Vec<f64>,
+    ) -> Result<Self, Error> {
+        check_compressed(ncol, nrow, &col_pointers, &row_indices, &values)?;
+        Ok(CscMatrix {
+            nrow,
+            ncol,
+            col_pointers,
+            row_indices,
+            values,
+        })
+    }
+
+    /// Creates a new CSC matrix from a triplet (COO format)
+    ///
+    /// The entries of each column are sorted by row index and repeated (i,j) entries are summed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_sparse::{CscMatrix, SparseTriplet, StrError};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     let mut trip = SparseTriplet::new(2, 4)?;
+    ///     trip.put(1, 1, 4.0)?;
+    ///     trip.put(0, 1, 2.0)?;
+    ///     trip.put(0, 0, 0.5)?;
+    ///     trip.put(0, 0, 0.5)?; // repeated
+    ///     let csc = CscMatrix::from_triplet(&trip)?;
+    ///     assert_eq!(csc.nnz(), 3);
+    ///     assert_eq!(csc.col_pointers(), &[0, 1, 3]);
+    ///     assert_eq!(csc.row_indices(), &[0, 0, 1]);
+    ///     assert_eq!(csc.values(), &[1.0, 2.0, 4.0]);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_triplet(trip: &SparseTriplet) -> Result<Self, Error> {
+        let pos = trip.pos;
+        let (col_pointers, row_indices, values) = compress(
+            trip.neq,
+            &trip.indices_j[..pos],
+            &trip.indices_i[..pos],
+            &trip.values_aij[..pos],
+        );
+        Ok(CscMatrix {
+            nrow: trip.neq,
+            ncol: trip.neq,
+            col_pointers,
+            row_indices,
+            values,
+        })
+    }
+
+    /// Returns a triplet (COO format) corresponding to this matrix
+    ///
+    /// **Note:** The matrix must be square because the triplet is square.
+    pub fn to_triplet(&self) -> Result<SparseTriplet, Error> {
+        if self.nrow != self.ncol {
+            return Err(Error::BadDimensions(
+                "the matrix must be square to be converted to a triplet",
+            ));
+        }
+        let mut trip = SparseTriplet::new(self.nrow, usize::max(1, self.nnz()))?;
+        for (i, j, aij) in self.iter() {
+            trip.put(i, j, aij)?;
+        }
+        Ok(trip)
+    }
+
+    /// Returns the number of rows
+    pub fn nrow(&self) -> usize {
+        self.nrow
+    }
+
+    /// Returns the number of columns
+    pub fn ncol(&self) -> usize {
+        self.ncol
+    }
+
+    /// Returns the number of non-zero values (nnz)
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the column pointers array (ncol + 1)
+    pub fn col_pointers(&self) -> &[i32] {
+        &self.col_pointers
+    }
+
+    /// Returns the row indices array (nnz)
+    pub fn row_indices(&self) -> &[i32] {
+        &self.row_indices
+    }
+
+    /// Returns the non-zero values array (nnz)
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Returns the (i,j) component (zero if not stored)
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the indices are out of bounds.
+    pub fn get(&self, i: usize, j: usize) -> f64 {
+        assert!(i < self.nrow);
+        assert!(j < self.ncol);
+        let (start, end) = (self.col_pointers[j] as usize, self.col_pointers[j + 1] as usize);
+        match self.row_indices[start..end].binary_search(&to_i32(i)) {
+            Ok(p) => self.values[start + p],
+            Err(_) => 0.0,
+        }
+    }
+
+    /// Returns an iterator over the stored entries `(i, j, aij)`, column by column
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_sparse::{CscMatrix, StrError};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     let csc = CscMatrix::new(2, 2, vec![0, 1, 2], vec![1, 0], vec![5.0, 6.0])?;
+    ///     let entries: Vec<_> = csc.iter().collect();
+    ///     assert_eq!(entries, &[(1, 0, 5.0), (0, 1, 6.0)]);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, f64)> + '_ {
+        (0..self.ncol).flat_map(move |j| {
+            let (start, end) = (self.col_pointers[j] as usize, self.col_pointers[j + 1] as usize);
+            (start..end).map(move |p| (self.row_indices[p] as usize, j, self.values[p]))
+        })
+    }
+
+    /// Returns the dense Matrix corresponding to this CSC matrix
+    pub fn as_matrix(&self) -> Matrix {
+        let mut a = Matrix::new(self.nrow, self.ncol);
+        for (i, j, aij) in self.iter() {
+            a.set(i, j, aij);
+        }
+        a
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::CscMatrix;
+    use crate::{Error, SparseTriplet};
+
+    #[test]
+    fn new_captures_errors() {
+        assert_eq!(
+            CscMatrix::new(2, 3, vec![0, 1, 1, 1], vec![2], vec![1.0]).err(),
+            Some(Error::InvalidMatrix("an index is out of bounds"))
+        );
+        assert_eq!(
+            CscMatrix::new(2, 2, vec![0, 1], vec![0], vec![1.0]).err(),
+            Some(Error::InvalidMatrix(
+                "the pointers array must have length equal to the number of rows (or columns) + 1"
+            ))
+        );
+    }
+
+    #[test]
+    fn from_triplet_and_to_triplet_work() {
+        let mut trip = SparseTriplet::new(5, 13).unwrap();
+        trip.put(0, 0, 1.0).unwrap(); // << (0, 0, a00/2)
+        trip.put(0, 0, 1.0).unwrap(); // << (0, 0, a00/2)
+        trip.put(1, 0, 3.0).unwrap();
+        trip.put(0, 1, 3.0).unwrap();
+        trip.put(2, 1, -1.0).unwrap();
+        trip.put(4, 1, 4.0).unwrap();
+        trip.put(1, 2, 4.0).unwrap();
+        trip.put(2, 2, -3.0).unwrap();
+        trip.put(3, 2, 1.0).unwrap();
+        trip.put(4, 2, 2.0).unwrap();
+        trip.put(2, 3, 2.0).unwrap();
+        trip.put(1, 4, 6.0).unwrap();
+        trip.put(4, 4, 1.0).unwrap();
+        let csc = CscMatrix::from_triplet(&trip).unwrap();
+        assert_eq!((csc.nrow(), csc.ncol(), csc.nnz()), (5, 5, 12));
+        assert_eq!(csc.col_pointers(), &[0, 2, 5, 9, 10, 12]);
+        assert_eq!(csc.row_indices(), &[0, 1, 0, 2, 4, 1, 2, 3, 4, 2, 1, 4]);
+        assert_eq!(
+            csc.values(),
+            &[2.0, 3.0, 3.0, -1.0, 4.0, 4.0, -3.0, 1.0, 2.0, 2.0, 6.0, 1.0]
+        );
+        assert_eq!(csc.get(0, 0), 2.0);
+        assert_eq!(csc.get(4, 2), 2.0);
+        assert_eq!(csc.get(3, 3), 0.0);
+        assert_eq!(format!("{}", csc.as_matrix()), format!("{}", trip.as_matrix()));
+        let back = csc.to_triplet().unwrap();
+        assert_eq!(back.nnz_current(), 12);
+        assert_eq!(format!("{}", back.as_matrix()), format!("{}", trip.as_matrix()));
+        let rect = CscMatrix::new(1, 2, vec![0, 1, 1], vec![0], vec![1.0]).unwrap();
+        assert_eq!(
+            rect.to_triplet().err(),
+            Some(Error::BadDimensions(
+                "the matrix must be square to be converted to a triplet"
+            ))
+        );
+    }
+}
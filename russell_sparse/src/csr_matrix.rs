@@ -0,0 +1,432 @@
+use crate::{to_i32, Error, SparseTriplet};
+use russell_lab::Matrix;
+
+/// Holds the arrays needed for a CSR (compressed sparse row) matrix
+///
+/// The non-zero values of row `i` are stored in `values[row_pointers[i]..row_pointers[i+1]]`
+/// and their column indices in `col_indices[row_pointers[i]..row_pointers[i+1]]`. Within each row,
+/// the column indices are sorted in ascending order and there are no duplicates.
+///
+/// # Example
+///
+/// ```text
+///     ┌                ┐
+///     │  2  3  0  0  0 │
+///     │  3  0  4  0  6 │
+/// A = │  0 -1 -3  2  0 │
+///     │  0  0  1  0  0 │
+///     │  0  4  2  0  1 │
+///     └                ┘
+/// row_pointers = [0,    2,       5,          8, 9,       12]
+///  col_indices = [0, 1, 0, 2, 4, 1,  2, 3,   2, 1, 2, 4]
+///       values = [2, 3, 3, 4, 6, -1, -3, 2,  1, 4, 2, 1]
+/// ```
+#[derive(Clone, Debug)]
+pub struct CsrMatrix {
+    pub(crate) nrow: usize,            // [i32] number of rows
+    pub(crate) ncol: usize,            // [i32] number of columns
+    pub(crate) row_pointers: Vec<i32>, // [nrow + 1] pointers to the first entry of each row
+    pub(crate) col_indices: Vec<i32>,  // [nnz] column indices
+    pub(crate) values: Vec<f64>,       // [nnz] non-zero values
+}
+
+/// Converts coordinate (triplet) arrays to compressed arrays, sorting the minor indices and summing duplicates
+///
+/// Returns `(pointers, indices, values)` where the major indices are compressed. For CSR, the major
+/// indices are the row indices; for CSC, the major indices are the column indices.
+pub(crate) fn compress(n_major: usize, major: &[i32], minor: &[i32], values: &[f64]) -> (Vec<i32>, Vec<i32>, Vec<f64>) {
+    // count the entries of each major index
+    let mut pointers = vec![0_usize; n_major + 1];
+    for m in major {
+        pointers[*m as usize + 1] += 1;
+    }
+    for k in 0..n_major {
+        pointers[k + 1] += pointers[k];
+    }
+
+    // scatter the entries
+    let nnz = pointers[n_major];
+    let mut next = pointers.clone();
+    let mut work_indices = vec![0_i32; nnz];
+    let mut work_values = vec![0.0; nnz];
+    for p in 0..major.len() {
+        let m = major[p] as usize;
+        work_indices[next[m]] = minor[p];
+        work_values[next[m]] = values[p];
+        next[m] += 1;
+    }
+
+    // sort each segment and sum duplicates
+    let mut out_pointers = vec![0_i32; n_major + 1];
+    let mut out_indices = Vec::with_capacity(nnz);
+    let mut out_values = Vec::with_capacity(nnz);
+    let mut segment: Vec<(i32, f64)> = Vec::new();
+    for k in 0..n_major {
+        segment.clear();
+        for p in pointers[k]..pointers[k + 1] {
+            segment.push((work_indices[p], work_values[p]));
+        }
+        segment.sort_by_key(|(index, _)| *index); // stable: keeps the order of duplicates
+        for (index, value) in &segment {
+            if out_indices.len() > out_pointers[k] as usize && out_indices.last() == Some(index) {
+                *out_values.last_mut().unwrap() += value;
+            } else {
+                out_indices.push(*index);
+                out_values.push(*value);
+            }
+        }
+        out_pointers[k + 1] = to_i32(out_indices.len());
+    }
+    (out_pointers, out_indices, out_values)
+}
+
+/// Checks the compressed arrays
+pub(crate) fn check_compressed(
+    n_major: usize,
+    n_minor: usize,
+    pointers: &[i32],
+    indices: &[i32],
+    values: &[f64],
+) -> Result<(), Error> {
+    if pointers.len() != n_major + 1 {
+        return Err(Error::InvalidMatrix(
+            "the pointers array must have length equal to the number of rows (or columns) + 1",
+        ));
+    }
+    if pointers[0] != 0 {
+        return Err(Error::InvalidMatrix("the first pointer must be zero"));
+    }
+    let nnz = pointers[n_major];
+    if nnz < 0 || indices.len() != nnz as usize || values.len() != nnz as usize {
+        return Err(Error::InvalidMatrix(
+            "the indices and values arrays must have length equal to the last pointer",
+        ));
+    }
+    if pointers.windows(2).any(|w| w[1] < w[0]) {
+        return Err(Error::InvalidMatrix("the pointers must be non-decreasing"));
+    }
+    for k in 0..n_major {
+        let (start, end) = (pointers[k], pointers[k + 1]);
+        for p in start..end {
+            let index = indices[p as usize];
+            if index < 0 || index as usize >= n_minor {
+                return Err(Error::InvalidMatrix("an index is out of bounds"));
+            }
+            if p > start && index <= indices[p as usize - 1] {
+                return Err(Error::InvalidMatrix(
+                    "the indices must be sorted and without duplicates",
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+impl CsrMatrix {
+    /// Creates a new CSR matrix from the compressed arrays
+    ///
+    /// # Input
+    ///
+    /// * `nrow` -- number of rows
+    /// * `ncol` -- number of columns
+    /// * `row_pointers` -- (nrow + 1) pointers to the first entry of each row; the last pointer is nnz
+    /// * `col_indices` -- (nnz) column indices, sorted within each row and without duplicates
+    /// * `values` -- (nnz) the non-zero values
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_sparse::{CsrMatrix, StrError};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     // ┌       ┐
+    ///     // │ 1 0 2 │
+    ///     // │ 0 0 3 │
+    ///     // └       ┘
+    ///     let csr = CsrMatrix::new(2, 3, vec![0, 2, 3], vec![0, 2, 2], vec![1.0, 2.0, 3.0])?;
+    ///     assert_eq!(csr.get(0, 2), 2.0);
+    ///     assert_eq!(csr.get(1, 0), 0.0);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(
+        nrow: usize,
+        ncol: usize,
+        row_pointers: Vec<i32>,
+        col_indices: Vec<i32>,
+        values: Vec<f64>,
+    ) -> Result<Self, Error> {
+        check_compressed(nrow, ncol, &row_pointers, &col_indices, &values)?;
+        Ok(CsrMatrix {
+            nrow,
+            ncol,
+            row_pointers,
+            col_indices,
+            values,
+        })
+    }
+
+    /// Creates a new CSR matrix from a triplet (COO format)
+    ///
+    /// The entries of each row are sorted by column index and repeated (i,j) entries are summed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_sparse::{CsrMatrix, SparseTriplet, StrError};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     let mut trip = SparseTriplet::new(2, 4)?;
+    ///     trip.put(1, 1, 4.0)?;
+    ///     trip.put(0, 1, 2.0)?;
+    ///     trip.put(0, 0, 0.5)?;
+    ///     trip.put(0, 0, 0.5)?; // repeated
+    ///     let csr = CsrMatrix::from_triplet(&trip)?;
+    ///     assert_eq!(csr.nnz(), 3);
+    ///     assert_eq!(csr.row_pointers(), &[0, 2, 3]);
+    ///     assert_eq!(csr.col_indices(), &[0, 1, 1]);
+    ///     assert_eq!(csr.values(), &[1.0, 2.0, 4.0]);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_triplet(trip: &SparseTriplet) -> Result<Self, Error> {
+        let pos = trip.pos;
+        let (row_pointers, col_indices, values) = compress(
+            trip.neq,
+            &trip.indices_i[..pos],
+            &trip.indices_j[..pos],
+            &trip.values_aij[..pos],
+        );
+        Ok(CsrMatrix {
+            nrow: trip.neq,
+            ncol: trip.neq,
+            row_pointers,
+            col_indices,
+            values,
+        })
+    }
+
+    /// Returns a triplet (COO format) corresponding to this matrix
+    ///
+    /// **Note:** The matrix must be square because the triplet is square.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_sparse::{CsrMatrix, StrError};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     let csr = CsrMatrix::new(2, 2, vec![0, 1, 2], vec![1, 0], vec![5.0, 6.0])?;
+    ///     let trip = csr.to_triplet()?;
+    ///     assert_eq!(trip.nnz_current(), 2);
+    ///     let correct = "┌     ┐\n\
+    ///                    │ 0 5 │\n\
+    ///                    │ 6 0 │\n\
+    ///                    └     ┘";
+    ///     assert_eq!(format!("{}", trip.as_matrix()), correct);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_triplet(&self) -> Result<SparseTriplet, Error> {
+        if self.nrow != self.ncol {
+            return Err(Error::BadDimensions(
+                "the matrix must be square to be converted to a triplet",
+            ));
+        }
+        let mut trip = SparseTriplet::new(self.nrow, usize::max(1, self.nnz()))?;
+        for (i, j, aij) in self.iter() {
+            trip.put(i, j, aij)?;
+        }
+        Ok(trip)
+    }
+
+    /// Returns the number of rows
+    pub fn nrow(&self) -> usize {
+        self.nrow
+    }
+
+    /// Returns the number of columns
+    pub fn ncol(&self) -> usize {
+        self.ncol
+    }
+
+    /// Returns the number of non-zero values (nnz)
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the row pointers array (nrow + 1)
+    pub fn row_pointers(&self) -> &[i32] {
+        &self.row_pointers
+    }
+
+    /// Returns the column indices array (nnz)
+    pub fn col_indices(&self) -> &[i32] {
+        &self.col_indices
+    }
+
+    /// Returns the non-zero values array (nnz)
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Returns the (i,j) component (zero if not stored)
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the indices are out of bounds.
+    pub fn get(&self, i: usize, j: usize) -> f64 {
+        assert!(i < self.nrow);
+        assert!(j < self.ncol);
+        let (start, end) = (self.row_pointers[i] as usize, self.row_pointers[i + 1] as usize);
+        match self.col_indices[start..end].binary_search(&to_i32(j)) {
+            Ok(p) => self.values[start + p],
+            Err(_) => 0.0,
+        }
+    }
+
+    /// Returns an iterator over the stored entries `(i, j, aij)`, row by row
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_sparse::{CsrMatrix, StrError};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     let csr = CsrMatrix::new(2, 2, vec![0, 1, 2], vec![1, 0], vec![5.0, 6.0])?;
+    ///     let entries: Vec<_> = csr.iter().collect();
+    ///     assert_eq!(entries, &[(0, 1, 5.0), (1, 0, 6.0)]);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, f64)> + '_ {
+        (0..self.nrow).flat_map(move |i| {
+            let (start, end) = (self.row_pointers[i] as usize, self.row_pointers[i + 1] as usize);
+            (start..end).map(move |p| (i, self.col_indices[p] as usize, self.values[p]))
+        })
+    }
+
+    /// Returns the dense Matrix corresponding to this CSR matrix
+    pub fn as_matrix(&self) -> Matrix {
+        let mut a = Matrix::new(self.nrow, self.ncol);
+        for (i, j, aij) in self.iter() {
+            a.set(i, j, aij);
+        }
+        a
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{compress, CsrMatrix};
+    use crate::{Error, SparseTriplet};
+
+    // returns the 5 x 5 matrix of the UMFPACK example with one repeated entry
+    fn get_triplet() -> SparseTriplet {
+        let mut trip = SparseTriplet::new(5, 13).unwrap();
+        trip.put(2, 3, 2.0).unwrap();
+        trip.put(0, 0, 1.0).unwrap(); // << (0, 0, a00/2)
+        trip.put(1, 0, 3.0).unwrap();
+        trip.put(0, 1, 3.0).unwrap();
+        trip.put(2, 1, -1.0).unwrap();
+        trip.put(4, 1, 4.0).unwrap();
+        trip.put(1, 4, 6.0).unwrap();
+        trip.put(1, 2, 4.0).unwrap();
+        trip.put(2, 2, -3.0).unwrap();
+        trip.put(3, 2, 1.0).unwrap();
+        trip.put(4, 2, 2.0).unwrap();
+        trip.put(0, 0, 1.0).unwrap(); // << (0, 0, a00/2)
+        trip.put(4, 4, 1.0).unwrap();
+        trip
+    }
+
+    #[test]
+    fn compress_works() {
+        let (p, i, x) = compress(3, &[2, 0, 2, 2], &[1, 1, 0, 1], &[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(p, &[0, 1, 1, 3]);
+        assert_eq!(i, &[1, 0, 1]);
+        assert_eq!(x, &[2.0, 3.0, 5.0]);
+        let (p, i, x) = compress(2, &[], &[], &[]);
+        assert_eq!(p, &[0, 0, 0]);
+        assert_eq!(i.len(), 0);
+        assert_eq!(x.len(), 0);
+    }
+
+    #[test]
+    fn new_captures_errors() {
+        assert_eq!(
+            CsrMatrix::new(2, 2, vec![0, 1], vec![0], vec![1.0]).err(),
+            Some(Error::InvalidMatrix(
+                "the pointers array must have length equal to the number of rows (or columns) + 1"
+            ))
+        );
+        assert_eq!(
+            CsrMatrix::new(1, 2, vec![1, 1], vec![0], vec![1.0]).err(),
+            Some(Error::InvalidMatrix("the first pointer must be zero"))
+        );
+        assert_eq!(
+            CsrMatrix::new(1, 2, vec![0, 2], vec![0], vec![1.0]).err(),
+            Some(Error::InvalidMatrix(
+                "the indices and values arrays must have length equal to the last pointer"
+            ))
+        );
+        assert_eq!(
+            CsrMatrix::new(2, 2, vec![0, 2, 1], vec![0], vec![1.0]).err(),
+            Some(Error::InvalidMatrix("the pointers must be non-decreasing"))
+        );
+        assert_eq!(
+            CsrMatrix::new(1, 2, vec![0, 1], vec![2], vec![1.0]).err(),
+            Some(Error::InvalidMatrix("an index is out of bounds"))
+        );
+        assert_eq!(
+            CsrMatrix::new(1, 2, vec![0, 2], vec![1, 1], vec![1.0, 2.0]).err(),
+            Some(Error::InvalidMatrix(
+                "the indices must be sorted and without duplicates"
+            ))
+        );
+    }
+
+    #[test]
+    fn from_triplet_works() {
+        let trip = get_triplet();
+        let csr = CsrMatrix::from_triplet(&trip).unwrap();
+        assert_eq!((csr.nrow(), csr.ncol(), csr.nnz()), (5, 5, 12));
+        assert_eq!(csr.row_pointers(), &[0, 2, 5, 8, 9, 12]);
+        assert_eq!(csr.col_indices(), &[0, 1, 0, 2, 4, 1, 2, 3, 2, 1, 2, 4]);
+        assert_eq!(
+            csr.values(),
+            &[2.0, 3.0, 3.0, 4.0, 6.0, -1.0, -3.0, 2.0, 1.0, 4.0, 2.0, 1.0]
+        );
+        assert_eq!(csr.get(0, 0), 2.0);
+        assert_eq!(csr.get(2, 3), 2.0);
+        assert_eq!(csr.get(3, 3), 0.0);
+        assert_eq!(format!("{}", csr.as_matrix()), format!("{}", trip.as_matrix()));
+    }
+
+    #[test]
+    fn to_triplet_works() {
+        let trip = get_triplet();
+        let csr = CsrMatrix::from_triplet(&trip).unwrap();
+        let back = csr.to_triplet().unwrap();
+        assert_eq!(back.nnz_current(), 12);
+        assert_eq!(format!("{}", back.as_matrix()), format!("{}", trip.as_matrix()));
+        let rect = CsrMatrix::new(1, 2, vec![0, 1], vec![1], vec![1.0]).unwrap();
+        assert_eq!(
+            rect.to_triplet().err(),
+            Some(Error::BadDimensions(
+                "the matrix must be square to be converted to a triplet"
+            ))
+        );
+        let empty = CsrMatrix::new(2, 2, vec![0, 0, 0], vec![], vec![]).unwrap();
+        assert_eq!(empty.to_triplet().unwrap().nnz_current(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_panics_on_wrong_indices() {
+        let csr = CsrMatrix::new(1, 1, vec![0, 0], vec![], vec![]).unwrap();
+        csr.get(1, 0);
+    }
+}
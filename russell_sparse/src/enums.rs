@@ -1,4 +1,4 @@
-use crate::StrError;
+use crate::Error;
 
 /// Matrix symmetry option
 #[derive(Clone, Copy, Debug)]
@@ -122,7 +122,7 @@ pub fn enum_scaling(scaling: &str) -> Scaling {
     }
 }
 
-pub(crate) fn code_symmetry_mmp(option: Option<Symmetry>) -> Result<i32, StrError> {
+pub(crate) fn code_symmetry_mmp(option: Option<Symmetry>) -> Result<i32, Error> {
     match option {
         None => Ok(0),
         Some(v) => match v {
@@ -132,7 +132,7 @@ pub(crate) fn code_symmetry_mmp(option: Option<Symmetry>) -> Result<i32, StrErro
     }
 }
 
-pub(crate) fn code_symmetry_umf(option: Option<Symmetry>) -> Result<i32, StrError> {
+pub(crate) fn code_symmetry_umf(option: Option<Symmetry>) -> Result<i32, Error> {
     match option {
         None => Ok(0),
         Some(v) => match v {
@@ -0,0 +1,155 @@
+use crate::StrError;
+use std::fmt;
+
+/// Defines the errors returned by the sparse matrix tools and solvers
+///
+/// The variants tell apart, for instance, a singular matrix found by the solver from an invalid
+/// Matrix Market file; the errors coming from russell_lab are wrapped by [Error::Lab]. The messages
+/// are kept from the former `StrError` and an error converts into a `StrError`; hence, code returning
+/// `Result<T, StrError>` still works with the `?` operator.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::Vector;
+/// use russell_sparse::{ConfigSolver, Error, Solver, SparseTriplet};
+///
+/// // singular matrix
+/// let mut trip = SparseTriplet::new(2, 4).unwrap();
+/// trip.put(0, 0, 1.0).unwrap();
+/// trip.put(0, 1, 2.0).unwrap();
+/// trip.put(1, 0, 2.0).unwrap();
+/// trip.put(1, 1, 4.0).unwrap();
+/// let rhs = Vector::from(&[1.0, 1.0]);
+/// match Solver::compute(ConfigSolver::new(), &trip, &rhs) {
+///     Err(Error::Singular(message)) => println!("{}", message),
+///     Err(e) => println!("another error: {}", e),
+///     Ok(_) => (),
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// The dimensions of the matrices, vectors, or triplets are incompatible
+    BadDimensions(&'static str),
+
+    /// The structure of a sparse matrix (pointers, indices, or sparsity pattern) is invalid
+    InvalidMatrix(&'static str),
+
+    /// An argument has an invalid value
+    InvalidArgument(&'static str),
+
+    /// The matrix is singular (or a preconditioner found a zero pivot)
+    Singular(&'static str),
+
+    /// The solver has not factorized the matrix yet
+    NotFactorized(&'static str),
+
+    /// The linear solver (MUMPS or UMFPACK) failed for a reason other than a singular matrix
+    Solver(&'static str),
+
+    /// A Matrix Market file cannot be read or written (or has an invalid format)
+    MatrixMarket(&'static str),
+
+    /// An error returned by russell_lab
+    Lab(russell_lab::Error),
+
+    /// Any other error (e.g., converted from a `StrError`)
+    Other(&'static str),
+
+    /// Wraps an error (the source) with a message describing the context
+    Context(&'static str, Box<Error>),
+}
+
+impl Error {
+    /// Returns the message of this error (without the messages of the sources)
+    pub fn message(&self) -> &'static str {
+        match self {
+            Error::BadDimensions(m)
+            | Error::InvalidMatrix(m)
+            | Error::InvalidArgument(m)
+            | Error::Singular(m)
+            | Error::NotFactorized(m)
+            | Error::Solver(m)
+            | Error::MatrixMarket(m)
+            | Error::Other(m)
+            | Error::Context(m, _) => m,
+            Error::Lab(e) => e.message(),
+        }
+    }
+
+    /// Wraps this error with a message describing the context
+    pub fn context(self, message: &'static str) -> Self {
+        Error::Context(message, Box::new(self))
+    }
+
+    /// Returns the innermost error (following the chain of sources)
+    pub fn root(&self) -> &Error {
+        match self {
+            Error::Context(_, source) => source.root(),
+            _ => self,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Context(_, source) => Some(source.as_ref()),
+            Error::Lab(e) => e.source(),
+            _ => None,
+        }
+    }
+}
+
+impl From<StrError> for Error {
+    fn from(message: StrError) -> Self {
+        Error::Other(message)
+    }
+}
+
+impl From<russell_lab::Error> for Error {
+    fn from(error: russell_lab::Error) -> Self {
+        Error::Lab(error)
+    }
+}
+
+impl From<Error> for StrError {
+    fn from(error: Error) -> Self {
+        error.message()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+    use crate::StrError;
+    use std::error::Error as StdError;
+
+    #[test]
+    fn message_and_context_work() {
+        let err = Error::Singular("Error(1): Matrix is singular").context("cannot solve the linear system");
+        assert_eq!(err.message(), "cannot solve the linear system");
+        assert_eq!(format!("{}", err), "cannot solve the linear system");
+        assert_eq!(err.source().unwrap().to_string(), "Error(1): Matrix is singular");
+        assert_eq!(err.root(), &Error::Singular("Error(1): Matrix is singular"));
+    }
+
+    #[test]
+    fn conversions_work() {
+        let err: Error = "some error".into();
+        assert_eq!(err, Error::Other("some error"));
+        let err: Error = russell_lab::Error::BadDimensions("vectors are incompatible").into();
+        assert_eq!(err.message(), "vectors are incompatible");
+        assert!(err.source().is_none());
+        let message: StrError = Error::NotFactorized("factorization must be done before calling solve").into();
+        assert_eq!(message, "factorization must be done before calling solve");
+    }
+}
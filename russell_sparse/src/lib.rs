@@ -64,18 +64,44 @@
 pub type StrError = &'static str;
 
 mod config_solver;
+mod csc_matrix;
+mod csr_matrix;
 mod enums;
+mod error;
+mod lin_sol_trait;
+mod preconditioner;
 pub mod prelude;
 mod read_matrix_market;
 mod solver;
+mod solver_mmp;
+mod solver_umf;
+mod sp_mat_add;
+mod sp_mat_mat_mul;
+mod sp_mat_vec_mul;
+mod sparse_qr;
 mod sparse_triplet;
+mod to_i32;
 mod verify_lin_sys;
+mod write_matrix_market;
 pub use crate::config_solver::*;
+pub use crate::csc_matrix::*;
+pub use crate::csr_matrix::*;
 pub use crate::enums::*;
+pub use crate::error::*;
+pub use crate::lin_sol_trait::*;
+pub use crate::preconditioner::*;
 pub use crate::read_matrix_market::*;
 pub use crate::solver::*;
+use crate::solver_mmp::*;
+use crate::solver_umf::*;
+pub use crate::sp_mat_add::*;
+pub use crate::sp_mat_mat_mul::*;
+pub use crate::sp_mat_vec_mul::*;
+pub use crate::sparse_qr::*;
 pub use crate::sparse_triplet::*;
+use crate::to_i32::*;
 pub use crate::verify_lin_sys::*;
+pub use crate::write_matrix_market::*;
 
 // run code from README file
 #[cfg(doctest)]
@@ -0,0 +1,54 @@
+use crate::{ConfigSolver, Error, SparseTriplet, Symmetry};
+use russell_lab::Vector;
+
+/// Holds information about the strategies used by a linear solver
+#[derive(Clone, Copy, Debug)]
+pub struct LinSolStats {
+    /// Used ordering strategy
+    pub used_ordering: &'static str,
+
+    /// Used scaling strategy
+    pub used_scaling: &'static str,
+}
+
+/// Defines the functionality of a sparse linear solver (backend)
+///
+/// The [crate::Solver] holds an implementation of this trait and performs the common tasks
+/// (checking the dimensions, measuring the elapsed times, etc.). Thus, other backends (e.g.,
+/// a third-party direct solver) can be used by implementing this trait and calling
+/// [crate::Solver::new_custom], without modifying [crate::LinSolKind].
+///
+/// The [crate::Solver] calls the functions in the following order: `initialize` (once), `factorize`
+/// (one or more times), and `solve` (one or more times after each `factorize`). Thus, a backend
+/// does not need to check the order of the calls. For this reason, the built-in backends are
+/// private and can only be used via [crate::Solver].
+pub trait LinSolTrait {
+    /// Initializes the solver (e.g., allocates memory)
+    ///
+    /// # Input
+    ///
+    /// * `config` -- configuration parameters
+    /// * `neq` -- number of equations (number of rows and columns of the square matrix)
+    /// * `nnz` -- (maximum) number of non-zero values, including repeated entries
+    /// * `symmetry` -- tells whether the matrix is symmetric or not
+    fn initialize(
+        &mut self,
+        config: &ConfigSolver,
+        neq: usize,
+        nnz: usize,
+        symmetry: Option<Symmetry>,
+    ) -> Result<(), Error>;
+
+    /// Performs the factorization (and analysis)
+    ///
+    /// **Note:** The number of equations of `trip` has already been checked by the caller.
+    fn factorize(&mut self, trip: &SparseTriplet) -> Result<(), Error>;
+
+    /// Computes the solution of the linear system `a ⋅ x = rhs`
+    ///
+    /// **Note:** The dimensions of `x` and `rhs` have already been checked by the caller.
+    fn solve(&mut self, x: &mut Vector, rhs: &Vector) -> Result<(), Error>;
+
+    /// Returns information about the strategies used by the solver
+    fn stats(&self) -> LinSolStats;
+}
@@ -0,0 +1,376 @@
+use crate::{CsrMatrix, Error};
+use russell_lab::Vector;
+
+/// Defines a preconditioner M ≈ A for iterative (Krylov) solvers
+///
+/// The iterative solvers call [Preconditioner::apply] once (or twice) per iteration to
+/// compute `z = M⁻¹⋅r`. Thus, `M` must approximate `A` while being cheap to invert.
+pub trait Preconditioner {
+    /// Computes `z = M⁻¹⋅r`
+    fn apply(&self, z: &mut Vector, r: &Vector) -> Result<(), Error>;
+}
+
+/// Implements the diagonal (Jacobi) preconditioner `M = diag(A)`
+pub struct PrecondJacobi {
+    inv_diag: Vec<f64>, // the inverse of the diagonal entries
+}
+
+/// Implements the incomplete LU factorization with zero fill-in `M = L⋅U`
+///
+/// The factors L (unit lower triangular) and U have the same sparsity pattern as A.
+pub struct PrecondIlu0 {
+    lu: CsrMatrix,    // the L and U factors stored in the pattern of A (the unit diagonal of L is not stored)
+    diag: Vec<usize>, // positions of the diagonal entries in lu.values
+}
+
+/// Implements the incomplete Cholesky factorization with zero fill-in `M = L⋅Lᵀ`
+///
+/// The factor L has the same sparsity pattern as the lower triangular part of A. This
+/// preconditioner is suitable for symmetric positive-definite matrices (e.g., for the CG method).
+pub struct PrecondIc0 {
+    l: CsrMatrix, // the lower triangular factor with the diagonal entry at the end of each row
+}
+
+/// Returns the positions of the diagonal entries in the values array
+fn find_diagonal(a: &CsrMatrix) -> Result<Vec<usize>, Error> {
+    if a.nrow != a.ncol {
+        return Err(Error::BadDimensions("the matrix must be square"));
+    }
+    let mut diag = Vec::with_capacity(a.nrow);
+    for i in 0..a.nrow {
+        let (start, end) = (a.row_pointers[i] as usize, a.row_pointers[i + 1] as usize);
+        match a.col_indices[start..end].binary_search(&(i as i32)) {
+            Ok(p) => diag.push(start + p),
+            Err(_) => {
+                return Err(Error::InvalidMatrix(
+                    "the diagonal entries must be present in the sparsity pattern",
+                ))
+            }
+        }
+    }
+    Ok(diag)
+}
+
+/// Checks the dimensions of the vectors
+fn check_vectors(n: usize, z: &Vector, r: &Vector) -> Result<(), Error> {
+    if z.dim() != n || r.dim() != n {
+        return Err(Error::BadDimensions("vectors are incompatible with the preconditioner"));
+    }
+    Ok(())
+}
+
+impl PrecondJacobi {
+    /// Allocates a new instance
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_lab::Vector;
+    /// use russell_sparse::{CsrMatrix, PrecondJacobi, Preconditioner, StrError};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     // ┌     ┐
+    ///     // │ 2 1 │
+    ///     // │ 1 4 │
+    ///     // └     ┘
+    ///     let a = CsrMatrix::new(2, 2, vec![0, 2, 4], vec![0, 1, 0, 1], vec![2.0, 1.0, 1.0, 4.0])?;
+    ///     let precond = PrecondJacobi::new(&a)?;
+    ///     let r = Vector::from(&[1.0, 1.0]);
+    ///     let mut z = Vector::new(2);
+    ///     precond.apply(&mut z, &r)?;
+    ///     assert_eq!(z.as_data(), &[0.5, 0.25]);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(a: &CsrMatrix) -> Result<Self, Error> {
+        let diag = find_diagonal(a)?;
+        let mut inv_diag = vec![0.0; a.nrow];
+        for i in 0..a.nrow {
+            let aii = a.values[diag[i]];
+            if aii == 0.0 {
+                return Err(Error::Singular("the diagonal entries must not be zero"));
+            }
+            inv_diag[i] = 1.0 / aii;
+        }
+        Ok(PrecondJacobi { inv_diag })
+    }
+}
+
+impl Preconditioner for PrecondJacobi {
+    fn apply(&self, z: &mut Vector, r: &Vector) -> Result<(), Error> {
+        check_vectors(self.inv_diag.len(), z, r)?;
+        for i in 0..self.inv_diag.len() {
+            z[i] = self.inv_diag[i] * r[i];
+        }
+        Ok(())
+    }
+}
+
+impl PrecondIlu0 {
+    /// Allocates a new instance and computes the ILU(0) factorization
+    ///
+    /// **Note:** The diagonal entries of A must be present in the sparsity pattern.
+    pub fn new(a: &CsrMatrix) -> Result<Self, Error> {
+        let diag = find_diagonal(a)?;
+        let n = a.nrow;
+        let mut lu = a.clone();
+        let mut position = vec![usize::MAX; n]; // position[j] = index of (i,j) in lu.values
+        for i in 0..n {
+            let (start, end) = (lu.row_pointers[i] as usize, lu.row_pointers[i + 1] as usize);
+            for p in start..end {
+                position[lu.col_indices[p] as usize] = p;
+            }
+            for p in start..diag[i] {
+                let k = lu.col_indices[p] as usize;
+                let ukk = lu.values[diag[k]];
+                if ukk == 0.0 {
+                    return Err(Error::Singular("ILU(0) found a zero pivot"));
+                }
+                lu.values[p] /= ukk;
+                let lik = lu.values[p];
+                for q in (diag[k] + 1)..(lu.row_pointers[k + 1] as usize) {
+                    let pij = position[lu.col_indices[q] as usize];
+                    if pij >= start && pij < end {
+                        lu.values[pij] -= lik * lu.values[q];
+                    }
+                }
+            }
+            if lu.values[diag[i]] == 0.0 {
+                return Err(Error::Singular("ILU(0) found a zero pivot"));
+            }
+        }
+        Ok(PrecondIlu0 { lu, diag })
+    }
+}
+
+impl Preconditioner for PrecondIlu0 {
+    fn apply(&self, z: &mut Vector, r: &Vector) -> Result<(), Error> {
+        let n = self.lu.nrow;
+        check_vectors(n, z, r)?;
+        let (pointers, indices, values) = (&self.lu.row_pointers, &self.lu.col_indices, &self.lu.values);
+        // solve L⋅y = r (y is stored in z)
+        for i in 0..n {
+            let mut sum = r[i];
+            for p in (pointers[i] as usize)..self.diag[i] {
+                sum -= values[p] * z[indices[p] as usize];
+            }
+            z[i] = sum;
+        }
+        // solve U⋅z = y
+        for i in (0..n).rev() {
+            let mut sum = z[i];
+            for p in (self.diag[i] + 1)..(pointers[i + 1] as usize) {
+                sum -= values[p] * z[indices[p] as usize];
+            }
+            z[i] = sum / values[self.diag[i]];
+        }
+        Ok(())
+    }
+}
+
+impl PrecondIc0 {
+    /// Allocates a new instance and computes the IC(0) factorization
+    ///
+    /// Only the lower triangular part of A is used; thus, A may be given in full or lower triangular storage.
+    ///
+    /// **Note:** The factorization may fail (with a non-positive pivot) even if A is positive-definite;
+    /// however, it always succeeds for M-matrices and diagonally dominant matrices.
+    pub fn new(a: &CsrMatrix) -> Result<Self, Error> {
+        let diag = find_diagonal(a)?;
+        let n = a.nrow;
+
+        // copy the lower triangular part of A
+        let mut row_pointers = vec![0_i32; n + 1];
+        let mut col_indices = Vec::new();
+        let mut values = Vec::new();
+        for i in 0..n {
+            let start = a.row_pointers[i] as usize;
+            col_indices.extend_from_slice(&a.col_indices[start..=diag[i]]);
+            values.extend_from_slice(&a.values[start..=diag[i]]);
+            row_pointers[i + 1] = col_indices.len() as i32;
+        }
+
+        // factorize row by row: L_ij = (a_ij - Σ_k L_ik L_jk) / L_jj
+        let mut work = vec![0.0; n]; // work[k] = L_ik of the current row i
+        for i in 0..n {
+            let (start, end) = (row_pointers[i] as usize, row_pointers[i + 1] as usize);
+            for p in start..end {
+                let j = col_indices[p] as usize;
+                let mut sum = values[p];
+                let (start_j, end_j) = (row_pointers[j] as usize, row_pointers[j + 1] as usize - 1);
+                for q in start_j..end_j {
+                    sum -= work[col_indices[q] as usize] * values[q];
+                }
+                if j < i {
+                    values[p] = sum / values[end_j];
+                    work[j] = values[p];
+                } else {
+                    if sum <= 0.0 {
+                        return Err(Error::Singular("IC(0) found a non-positive pivot"));
+                    }
+                    values[p] = f64::sqrt(sum);
+                }
+            }
+            for p in start..end {
+                work[col_indices[p] as usize] = 0.0;
+            }
+        }
+        Ok(PrecondIc0 {
+            l: CsrMatrix {
+                nrow: n,
+                ncol: n,
+                row_pointers,
+                col_indices,
+                values,
+            },
+        })
+    }
+}
+
+impl Preconditioner for PrecondIc0 {
+    fn apply(&self, z: &mut Vector, r: &Vector) -> Result<(), Error> {
+        let n = self.l.nrow;
+        check_vectors(n, z, r)?;
+        let (pointers, indices, values) = (&self.l.row_pointers, &self.l.col_indices, &self.l.values);
+        // solve L⋅y = r (y is stored in z)
+        for i in 0..n {
+            let (start, end) = (pointers[i] as usize, pointers[i + 1] as usize - 1);
+            let mut sum = r[i];
+            for p in start..end {
+                sum -= values[p] * z[indices[p] as usize];
+            }
+            z[i] = sum / values[end];
+        }
+        // solve Lᵀ⋅z = y (column-oriented)
+        for i in (0..n).rev() {
+            let (start, end) = (pointers[i] as usize, pointers[i + 1] as usize - 1);
+            z[i] /= values[end];
+            let zi = z[i];
+            for p in start..end {
+                z[indices[p] as usize] -= values[p] * zi;
+            }
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{PrecondIc0, PrecondIlu0, PrecondJacobi, Preconditioner};
+    use crate::{sp_mat_vec_mul, CsrMatrix, Error, SparseTriplet};
+    use russell_chk::vec_approx_eq;
+    use russell_lab::Vector;
+
+    // returns the n x n tridiagonal matrix [-1, 2, -1]
+    fn tridiagonal(n: usize) -> CsrMatrix {
+        let mut trip = SparseTriplet::new(n, 3 * n).unwrap();
+        for i in 0..n {
+            trip.put(i, i, 2.0).unwrap();
+            if i > 0 {
+                trip.put(i, i - 1, -1.0).unwrap();
+                trip.put(i - 1, i, -1.0).unwrap();
+            }
+        }
+        CsrMatrix::from_triplet(&trip).unwrap()
+    }
+
+    #[test]
+    fn new_captures_errors() {
+        let rect = CsrMatrix::new(1, 2, vec![0, 1], vec![0], vec![1.0]).unwrap();
+        let no_diag = CsrMatrix::new(2, 2, vec![0, 1, 2], vec![1, 0], vec![1.0, 1.0]).unwrap();
+        let zero_diag = CsrMatrix::new(2, 2, vec![0, 1, 2], vec![0, 1], vec![1.0, 0.0]).unwrap();
+        assert_eq!(
+            PrecondJacobi::new(&rect).err(),
+            Some(Error::BadDimensions("the matrix must be square"))
+        );
+        assert_eq!(
+            PrecondIlu0::new(&no_diag).err(),
+            Some(Error::InvalidMatrix(
+                "the diagonal entries must be present in the sparsity pattern"
+            ))
+        );
+        assert_eq!(
+            PrecondJacobi::new(&zero_diag).err(),
+            Some(Error::Singular("the diagonal entries must not be zero"))
+        );
+        assert_eq!(
+            PrecondIlu0::new(&zero_diag).err(),
+            Some(Error::Singular("ILU(0) found a zero pivot"))
+        );
+        assert_eq!(
+            PrecondIc0::new(&zero_diag).err(),
+            Some(Error::Singular("IC(0) found a non-positive pivot"))
+        );
+        let precond = PrecondJacobi::new(&tridiagonal(3)).unwrap();
+        let mut z = Vector::new(2);
+        assert_eq!(
+            precond.apply(&mut z, &Vector::new(3)).err(),
+            Some(Error::BadDimensions("vectors are incompatible with the preconditioner"))
+        );
+    }
+
+    #[test]
+    fn exact_for_tridiagonal() {
+        // the LU and Cholesky factors of a tridiagonal matrix have no fill-in
+        let a = tridiagonal(5);
+        let r = Vector::from(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+        let ilu = PrecondIlu0::new(&a).unwrap();
+        let ic = PrecondIc0::new(&a).unwrap();
+        for precond in [&ilu as &dyn Preconditioner, &ic] {
+            let mut z = Vector::new(5);
+            let mut az = Vector::new(5);
+            precond.apply(&mut z, &r).unwrap();
+            sp_mat_vec_mul(&mut az, 1.0, &a, &z).unwrap();
+            vec_approx_eq(az.as_data(), r.as_data(), 1e-14);
+        }
+    }
+
+    #[test]
+    fn incomplete_factors_work() {
+        // ┌             ┐
+        // │  4 -1 -1  0 │
+        // │ -1  4  0 -1 │
+        // │ -1  0  4 -1 │
+        // │  0 -1 -1  4 │
+        // └             ┘
+        // the complete factors would have fill-in at (2,1) and (1,2)
+        let a = CsrMatrix::new(
+            4,
+            4,
+            vec![0, 3, 6, 9, 12],
+            vec![0, 1, 2, 0, 1, 3, 0, 2, 3, 1, 2, 3],
+            vec![4.0, -1.0, -1.0, -1.0, 4.0, -1.0, -1.0, 4.0, -1.0, -1.0, -1.0, 4.0],
+        )
+        .unwrap();
+
+        // IC(0): L has the pattern of the lower part of A
+        let ic = PrecondIc0::new(&a).unwrap();
+        assert_eq!(ic.l.row_pointers, &[0, 1, 3, 5, 8]);
+        assert_eq!(ic.l.col_indices, &[0, 0, 1, 0, 2, 1, 2, 3]);
+        let l10 = -0.5;
+        let l11 = f64::sqrt(4.0 - 0.25);
+        let l33 = f64::sqrt(4.0 - 2.0 / (l11 * l11));
+        vec_approx_eq(
+            &ic.l.values,
+            &[2.0, l10, l11, l10, l11, -1.0 / l11, -1.0 / l11, l33],
+            1e-15,
+        );
+
+        // ILU(0) of a symmetric matrix: U = D⋅Lᵀ with D = diag(L)
+        let ilu = PrecondIlu0::new(&a).unwrap();
+        let mut z_ilu = Vector::new(4);
+        let mut z_ic = Vector::new(4);
+        let r = Vector::from(&[1.0, -1.0, 2.0, 0.5]);
+        ilu.apply(&mut z_ilu, &r).unwrap();
+        ic.apply(&mut z_ic, &r).unwrap();
+        vec_approx_eq(z_ilu.as_data(), z_ic.as_data(), 1e-14);
+
+        // Jacobi
+        let jacobi = PrecondJacobi::new(&a).unwrap();
+        let mut z = Vector::new(4);
+        jacobi.apply(&mut z, &r).unwrap();
+        vec_approx_eq(z.as_data(), &[0.25, -0.25, 0.5, 0.125], 1e-15);
+    }
+}
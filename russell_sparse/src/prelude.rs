@@ -4,5 +4,7 @@
 //! access to commonly used functionality.
 
 pub use crate::config_solver::ConfigSolver;
+pub use crate::csc_matrix::CscMatrix;
+pub use crate::csr_matrix::CsrMatrix;
 pub use crate::solver::Solver;
 pub use crate::sparse_triplet::SparseTriplet;
@@ -1,11 +1,15 @@
 use super::SparseTriplet;
-use crate::StrError;
+use crate::Error;
+#[cfg(feature = "gz")]
+use flate2::read::GzDecoder;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 
 struct MatrixMarketData {
     // header
-    symmetric: bool,
+    symmetric: bool, // symmetric or hermitian (the same for real matrices)
+    skew: bool,      // skew-symmetric
+    pattern: bool,   // only the (i,j) indices are given; aij = 1
 
     // dimensions
     m: i32,   // number of rows
@@ -23,6 +27,8 @@ impl MatrixMarketData {
     fn new() -> Self {
         MatrixMarketData {
             symmetric: false,
+            skew: false,
+            pattern: false,
             m: 0,
             n: 0,
             nnz: 0,
@@ -34,59 +40,74 @@ impl MatrixMarketData {
     }
 
     #[inline]
-    fn parse_header(&mut self, line: &String) -> Result<(), StrError> {
+    fn parse_header(&mut self, line: &String) -> Result<(), Error> {
         let mut data = line.trim_start().trim_end_matches("\n").split_whitespace();
 
         match data.next() {
             Some(v) => {
                 if v != "%%MatrixMarket" {
-                    return Err("the header (first line) must start with %%MatrixMarket");
+                    return Err(Error::MatrixMarket(
+                        "the header (first line) must start with %%MatrixMarket",
+                    ));
                 }
             }
-            None => return Err("cannot find the keyword %%MatrixMarket on the first line"),
+            None => {
+                return Err(Error::MatrixMarket(
+                    "cannot find the keyword %%MatrixMarket on the first line",
+                ))
+            }
         }
 
         match data.next() {
             Some(v) => {
                 if v != "matrix" {
-                    return Err("after %%MatrixMarket, the first option must be \"matrix\"");
+                    return Err(Error::MatrixMarket(
+                        "after %%MatrixMarket, the first option must be \"matrix\"",
+                    ));
                 }
             }
-            None => return Err("cannot find the first option in the header line"),
+            None => return Err(Error::MatrixMarket("cannot find the first option in the header line")),
         }
 
         match data.next() {
             Some(v) => {
                 if v != "coordinate" {
-                    return Err("after %%MatrixMarket, the second option must be \"coordinate\"");
+                    return Err(Error::MatrixMarket(
+                        "after %%MatrixMarket, the second option must be \"coordinate\"",
+                    ));
                 }
             }
-            None => return Err("cannot find the second option in the header line"),
+            None => return Err(Error::MatrixMarket("cannot find the second option in the header line")),
         }
 
         match data.next() {
-            Some(v) => {
-                if v != "real" {
-                    return Err("after %%MatrixMarket, the third option must be \"real\"");
+            Some(v) => match v {
+                "real" | "integer" => self.pattern = false,
+                "pattern" => self.pattern = true,
+                _ => {
+                    return Err(Error::MatrixMarket(
+                        "after %%MatrixMarket, the third option must be \"real\", \"integer\", or \"pattern\"",
+                    ))
                 }
-            }
-            None => return Err("cannot find the third option in the header line"),
+            },
+            None => return Err(Error::MatrixMarket("cannot find the third option in the header line")),
         }
 
         match data.next() {
             Some(v) => match v {
                 "general" => self.symmetric = false,
-                "symmetric" => self.symmetric = true,
-                _ => return Err("after %%MatrixMarket, the fourth option must be either \"general\" or \"symmetric\""),
+                "symmetric" | "hermitian" => self.symmetric = true,
+                "skew-symmetric" => self.skew = true,
+                _ => return Err(Error::MatrixMarket("after %%MatrixMarket, the fourth option must be \"general\", \"symmetric\", \"skew-symmetric\", or \"hermitian\"")),
             },
-            None => return Err("cannot find the fourth option in the header line"),
+            None => return Err(Error::MatrixMarket("cannot find the fourth option in the header line")),
         }
 
         Ok(())
     }
 
     #[inline]
-    fn parse_dimensions(&mut self, line: &String) -> Result<bool, StrError> {
+    fn parse_dimensions(&mut self, line: &String) -> Result<bool, Error> {
         let maybe_data = line.trim_start().trim_end_matches("\n");
         if maybe_data.starts_with("%") || maybe_data == "" {
             return Ok(false); // ignore comments or empty lines; returns false == not parsed
@@ -98,34 +119,42 @@ impl MatrixMarketData {
             .next()
             .unwrap() // must panic because no error expected here
             .parse()
-            .map_err(|_| "cannot parse number of rows")?;
+            .map_err(|_| Error::MatrixMarket("cannot parse number of rows"))?;
 
         match data.next() {
-            Some(v) => self.n = v.parse().map_err(|_| "cannot parse number of columns")?,
-            None => return Err("cannot read number of columns"),
+            Some(v) => {
+                self.n = v
+                    .parse()
+                    .map_err(|_| Error::MatrixMarket("cannot parse number of columns"))?
+            }
+            None => return Err(Error::MatrixMarket("cannot read number of columns")),
         };
 
         match data.next() {
-            Some(v) => self.nnz = v.parse().map_err(|_| "cannot parse number of non-zeros")?,
-            None => return Err("cannot read number of non-zeros"),
+            Some(v) => {
+                self.nnz = v
+                    .parse()
+                    .map_err(|_| Error::MatrixMarket("cannot parse number of non-zeros"))?
+            }
+            None => return Err(Error::MatrixMarket("cannot read number of non-zeros")),
         };
 
         if self.m < 1 || self.n < 1 || self.nnz < 1 {
-            return Err("found invalid (zero or negative) dimensions");
+            return Err(Error::MatrixMarket("found invalid (zero or negative) dimensions"));
         }
 
         Ok(true) // returns true == parsed
     }
 
     #[inline]
-    fn parse_triple(&mut self, line: &String) -> Result<bool, StrError> {
+    fn parse_triple(&mut self, line: &String) -> Result<bool, Error> {
         let maybe_data = line.trim_start().trim_end_matches("\n");
         if maybe_data.starts_with("%") || maybe_data == "" {
             return Ok(false); // ignore comments or empty lines
         }
 
         if self.pos == self.nnz {
-            return Err("there are more (i,j,aij) triples than specified");
+            return Err(Error::MatrixMarket("there are more (i,j,aij) triples than specified"));
         }
 
         let mut data = maybe_data.split_whitespace();
@@ -134,23 +163,27 @@ impl MatrixMarketData {
             .next()
             .unwrap() // must panic because no error expected here
             .parse()
-            .map_err(|_| "cannot parse index i")?;
+            .map_err(|_| Error::MatrixMarket("cannot parse index i"))?;
 
         match data.next() {
-            Some(v) => self.j = v.parse().map_err(|_| "cannot parse index j")?,
-            None => return Err("cannot read index j"),
+            Some(v) => self.j = v.parse().map_err(|_| Error::MatrixMarket("cannot parse index j"))?,
+            None => return Err(Error::MatrixMarket("cannot read index j")),
         };
 
-        match data.next() {
-            Some(v) => self.aij = v.parse().map_err(|_| "cannot parse value aij")?,
-            None => return Err("cannot read value aij"),
-        };
+        if self.pattern {
+            self.aij = 1.0;
+        } else {
+            match data.next() {
+                Some(v) => self.aij = v.parse().map_err(|_| Error::MatrixMarket("cannot parse value aij"))?,
+                None => return Err(Error::MatrixMarket("cannot read value aij")),
+            };
+        }
 
         self.i -= 1; // MatrixMarket is one-based
         self.j -= 1;
 
         if self.i < 0 || self.i >= self.m || self.j < 0 || self.j >= self.n {
-            return Err("found invalid indices");
+            return Err(Error::MatrixMarket("found invalid indices"));
         }
 
         self.pos += 1; // next position
@@ -190,7 +223,7 @@ impl MatrixMarketData {
 /// # Output
 ///
 /// * A SparseTriplet or an error message
-/// * Returns true if the `symmetric` keyword is present in the header
+/// * Returns true if the `symmetric` (or `hermitian`) keyword is present in the header
 ///
 /// # Panics
 ///
@@ -237,8 +270,14 @@ impl MatrixMarketData {
 /// ## Remarks
 ///
 /// * The first line is the **header line**
-/// * The header must contain `%%MatrixMarket matrix coordinate real` followed by `general` or `symmetric` (separated by spaces)
-/// * Thus, this function can only read the `coordinate` and `real` combination for now
+/// * The header must contain `%%MatrixMarket matrix coordinate` followed by `real`, `integer`, or `pattern`
+///   and then `general`, `symmetric`, `skew-symmetric`, or `hermitian` (separated by spaces)
+/// * Thus, this function can only read the `coordinate` format (with real values) for now
+/// * With `pattern`, the data lines contain only the (i,j) indices and the values are set to one
+/// * With `hermitian` (real values), the matrix is symmetric
+/// * With `skew-symmetric`, the off-diagonal entries are always mirrored with a negative sign
+///   (regardless of `sym_mirror`) and the returned symmetric flag is false
+/// * Files ending with `.gz` are decompressed on the fly (only with the `gz` feature)
 /// * After the header line, the percentage character marks a comment line
 /// * After the header line, a line with dimensions `m n nnz` must follow
 /// * `m`, `n`, and `nnz` are the number of columns, rows, and non-zero values
@@ -323,9 +362,67 @@ impl MatrixMarketData {
 ///     Ok(())
 /// }
 /// ```
-pub fn read_matrix_market(filepath: &String, sym_mirror: bool) -> Result<(SparseTriplet, bool), StrError> {
-    let input = File::open(filepath).map_err(|_| "cannot open file")?;
-    let buffered = BufReader::new(input);
+pub fn read_matrix_market(filepath: &String, sym_mirror: bool) -> Result<(SparseTriplet, bool), Error> {
+    let buffered = open_matrix_market(filepath)?;
+    parse_matrix_market(buffered, sym_mirror)
+}
+
+impl SparseTriplet {
+    /// Reads a MatrixMarket file into a new SparseTriplet with all (i,j) entries
+    ///
+    /// This function is similar to [read_matrix_market]; however, the off-diagonal entries of
+    /// `symmetric`, `hermitian`, and `skew-symmetric` matrices are always mirrored. Thus, the
+    /// resulting triplet represents the full matrix, as needed by UMFPACK or by [crate::CsrMatrix].
+    ///
+    /// The supported headers are:
+    ///
+    /// ```text
+    /// %%MatrixMarket matrix coordinate {real,integer,pattern} {general,symmetric,skew-symmetric,hermitian}
+    /// ```
+    ///
+    /// With the `pattern` option, only the (i,j) indices are given and the values are set to one.
+    /// With the `gz` feature, files ending with `.gz` are decompressed on the fly (e.g., as downloaded
+    /// from the SuiteSparse Matrix Collection).
+    ///
+    /// **Note:** This function works only with square matrices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_sparse::{SparseTriplet, StrError};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     let trip = SparseTriplet::from_matrix_market("./data/matrix_market/simple_sym.mtx")?;
+    ///     let correct = "┌       ┐\n\
+    ///                    │ 1 2 0 │\n\
+    ///                    │ 2 3 4 │\n\
+    ///                    │ 0 4 0 │\n\
+    ///                    └       ┘";
+    ///     assert_eq!(format!("{}", trip.as_matrix()), correct);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_matrix_market(filepath: &str) -> Result<Self, Error> {
+        let buffered = open_matrix_market(filepath)?;
+        let (trip, _) = parse_matrix_market(buffered, true)?;
+        Ok(trip)
+    }
+}
+
+/// Opens a MatrixMarket file, decompressing it if the extension is .gz (with the `gz` feature)
+fn open_matrix_market(filepath: &str) -> Result<Box<dyn BufRead>, Error> {
+    let input = File::open(filepath).map_err(|_| Error::MatrixMarket("cannot open file"))?;
+    if filepath.ends_with(".gz") {
+        #[cfg(feature = "gz")]
+        return Ok(Box::new(BufReader::new(GzDecoder::new(input))));
+        #[cfg(not(feature = "gz"))]
+        return Err(Error::MatrixMarket("reading .gz files requires the gz feature"));
+    }
+    Ok(Box::new(BufReader::new(input)))
+}
+
+/// Parses the MatrixMarket data
+fn parse_matrix_market(buffered: Box<dyn BufRead>, sym_mirror: bool) -> Result<(SparseTriplet, bool), Error> {
     let mut lines_iter = buffered.lines();
 
     // auxiliary data structure
@@ -333,8 +430,8 @@ pub fn read_matrix_market(filepath: &String, sym_mirror: bool) -> Result<(Sparse
 
     // read first line
     let header = match lines_iter.next() {
-        Some(v) => v.unwrap(), // must panic because no error expected here
-        None => return Err("file is empty"),
+        Some(v) => v.map_err(|_| Error::MatrixMarket("cannot read file"))?,
+        None => return Err(Error::MatrixMarket("file is empty")),
     };
 
     // parse header
@@ -350,12 +447,12 @@ pub fn read_matrix_market(filepath: &String, sym_mirror: bool) -> Result<(Sparse
 
     // check dimensions
     if data.m != data.n {
-        return Err("cannot read non-square matrix");
+        return Err(Error::MatrixMarket("cannot read non-square matrix"));
     }
 
     // set max number of entries
     let mut max = data.nnz;
-    if data.symmetric && sym_mirror {
+    if (data.symmetric && sym_mirror) || data.skew {
         max = 2 * data.nnz;
     }
 
@@ -366,12 +463,15 @@ pub fn read_matrix_market(filepath: &String, sym_mirror: bool) -> Result<(Sparse
     loop {
         match lines_iter.next() {
             Some(v) => {
-                let line = v.unwrap(); // must panic because no error expected here
+                let line = v.map_err(|_| Error::MatrixMarket("cannot read file"))?;
                 if data.parse_triple(&line)? {
                     trip.put(data.i as usize, data.j as usize, data.aij)?;
                     if data.symmetric && sym_mirror && data.i != data.j {
                         trip.put(data.j as usize, data.i as usize, data.aij)?;
                     }
+                    if data.skew && data.i != data.j {
+                        trip.put(data.j as usize, data.i as usize, -data.aij)?;
+                    }
                 }
             }
             None => break,
@@ -380,7 +480,7 @@ pub fn read_matrix_market(filepath: &String, sym_mirror: bool) -> Result<(Sparse
 
     // check data
     if data.pos != data.nnz {
-        return Err("not all triples (i,j,aij) have been found");
+        return Err(Error::MatrixMarket("not all triples (i,j,aij) have been found"));
     }
 
     Ok((trip, data.symmetric))
@@ -391,6 +491,7 @@ pub fn read_matrix_market(filepath: &String, sym_mirror: bool) -> Result<(Sparse
 #[cfg(test)]
 mod tests {
     use super::{read_matrix_market, MatrixMarketData};
+    use crate::{Error, SparseTriplet};
     use russell_lab::Matrix;
 
     #[test]
@@ -399,88 +500,110 @@ mod tests {
 
         assert_eq!(
             data.parse_header(&String::from("  \n")),
-            Err("cannot find the keyword %%MatrixMarket on the first line")
+            Err(Error::MatrixMarket(
+                "cannot find the keyword %%MatrixMarket on the first line"
+            ))
         );
         assert_eq!(
             data.parse_header(&String::from("MatrixMarket  ")),
-            Err("the header (first line) must start with %%MatrixMarket"),
+            Err(Error::MatrixMarket(
+                "the header (first line) must start with %%MatrixMarket"
+            )),
         );
 
         assert_eq!(
             data.parse_header(&String::from("  %%MatrixMarket")),
-            Err("cannot find the first option in the header line"),
+            Err(Error::MatrixMarket("cannot find the first option in the header line")),
         );
         assert_eq!(
             data.parse_header(&String::from("%%MatrixMarket   wrong")),
-            Err("after %%MatrixMarket, the first option must be \"matrix\""),
+            Err(Error::MatrixMarket(
+                "after %%MatrixMarket, the first option must be \"matrix\""
+            )),
         );
 
         assert_eq!(
             data.parse_header(&String::from("%%MatrixMarket matrix  ")),
-            Err("cannot find the second option in the header line"),
+            Err(Error::MatrixMarket("cannot find the second option in the header line")),
         );
         assert_eq!(
             data.parse_header(&String::from("%%MatrixMarket   matrix wrong")),
-            Err("after %%MatrixMarket, the second option must be \"coordinate\""),
+            Err(Error::MatrixMarket(
+                "after %%MatrixMarket, the second option must be \"coordinate\""
+            )),
         );
 
         assert_eq!(
             data.parse_header(&String::from("%%MatrixMarket matrix  coordinate")),
-            Err("cannot find the third option in the header line"),
+            Err(Error::MatrixMarket("cannot find the third option in the header line")),
         );
         assert_eq!(
             data.parse_header(&String::from("%%MatrixMarket matrix    coordinate  wrong")),
-            Err("after %%MatrixMarket, the third option must be \"real\""),
+            Err(Error::MatrixMarket(
+                "after %%MatrixMarket, the third option must be \"real\", \"integer\", or \"pattern\""
+            )),
         );
 
         assert_eq!(
             data.parse_header(&String::from("%%MatrixMarket  matrix coordinate real")),
-            Err("cannot find the fourth option in the header line"),
+            Err(Error::MatrixMarket("cannot find the fourth option in the header line")),
         );
         assert_eq!(
             data.parse_header(&String::from("  %%MatrixMarket matrix coordinate real wrong")),
-            Err("after %%MatrixMarket, the fourth option must be either \"general\" or \"symmetric\""),
+            Err(Error::MatrixMarket("after %%MatrixMarket, the fourth option must be \"general\", \"symmetric\", \"skew-symmetric\", or \"hermitian\"")),
         );
     }
 
+    #[test]
+    fn parse_header_works() {
+        let mut data = MatrixMarketData::new();
+        data.parse_header(&String::from("%%MatrixMarket matrix coordinate pattern hermitian"))
+            .unwrap();
+        assert_eq!((data.symmetric, data.skew, data.pattern), (true, false, true));
+        let mut data = MatrixMarketData::new();
+        data.parse_header(&String::from("%%MatrixMarket matrix coordinate integer skew-symmetric"))
+            .unwrap();
+        assert_eq!((data.symmetric, data.skew, data.pattern), (false, true, false));
+    }
+
     #[test]
     fn parse_dimensions_captures_errors() {
         let mut data = MatrixMarketData::new();
 
         assert_eq!(
             data.parse_dimensions(&String::from(" wrong \n")).err(),
-            Some("cannot parse number of rows")
+            Some(Error::MatrixMarket("cannot parse number of rows"))
         );
 
         assert_eq!(
             data.parse_dimensions(&String::from(" 1 \n")).err(),
-            Some("cannot read number of columns")
+            Some(Error::MatrixMarket("cannot read number of columns"))
         );
         assert_eq!(
             data.parse_dimensions(&String::from(" 1 wrong")).err(),
-            Some("cannot parse number of columns")
+            Some(Error::MatrixMarket("cannot parse number of columns"))
         );
 
         assert_eq!(
             data.parse_dimensions(&String::from(" 1 1   \n")).err(),
-            Some("cannot read number of non-zeros")
+            Some(Error::MatrixMarket("cannot read number of non-zeros"))
         );
         assert_eq!(
             data.parse_dimensions(&String::from(" 1 1  wrong")).err(),
-            Some("cannot parse number of non-zeros")
+            Some(Error::MatrixMarket("cannot parse number of non-zeros"))
         );
 
         assert_eq!(
             data.parse_dimensions(&String::from(" 0 1  1")).err(),
-            Some("found invalid (zero or negative) dimensions")
+            Some(Error::MatrixMarket("found invalid (zero or negative) dimensions"))
         );
         assert_eq!(
             data.parse_dimensions(&String::from(" 1 0  1")).err(),
-            Some("found invalid (zero or negative) dimensions")
+            Some(Error::MatrixMarket("found invalid (zero or negative) dimensions"))
         );
         assert_eq!(
             data.parse_dimensions(&String::from(" 1 1  0")).err(),
-            Some("found invalid (zero or negative) dimensions")
+            Some(Error::MatrixMarket("found invalid (zero or negative) dimensions"))
         );
     }
 
@@ -493,42 +616,42 @@ mod tests {
 
         assert_eq!(
             data.parse_triple(&String::from(" wrong \n")).err(),
-            Some("cannot parse index i")
+            Some(Error::MatrixMarket("cannot parse index i"))
         );
 
         assert_eq!(
             data.parse_triple(&String::from(" 1 \n")).err(),
-            Some("cannot read index j")
+            Some(Error::MatrixMarket("cannot read index j"))
         );
         assert_eq!(
             data.parse_triple(&String::from(" 1 wrong")).err(),
-            Some("cannot parse index j")
+            Some(Error::MatrixMarket("cannot parse index j"))
         );
 
         assert_eq!(
             data.parse_triple(&String::from(" 1 1   \n")).err(),
-            Some("cannot read value aij")
+            Some(Error::MatrixMarket("cannot read value aij"))
         );
         assert_eq!(
             data.parse_triple(&String::from(" 1 1  wrong")).err(),
-            Some("cannot parse value aij")
+            Some(Error::MatrixMarket("cannot parse value aij"))
         );
 
         assert_eq!(
             data.parse_triple(&String::from(" 0 1  1")).err(),
-            Some("found invalid indices")
+            Some(Error::MatrixMarket("found invalid indices"))
         );
         assert_eq!(
             data.parse_triple(&String::from(" 3 1  1")).err(),
-            Some("found invalid indices")
+            Some(Error::MatrixMarket("found invalid indices"))
         );
         assert_eq!(
             data.parse_triple(&String::from(" 1 0  1")).err(),
-            Some("found invalid indices")
+            Some(Error::MatrixMarket("found invalid indices"))
         );
         assert_eq!(
             data.parse_triple(&String::from(" 1 3  1")).err(),
-            Some("found invalid indices")
+            Some(Error::MatrixMarket("found invalid indices"))
         );
     }
 
@@ -536,31 +659,33 @@ mod tests {
     fn read_matrix_market_handle_wrong_files() {
         assert_eq!(
             read_matrix_market(&String::from("__wrong__"), false).err(),
-            Some("cannot open file")
+            Some(Error::MatrixMarket("cannot open file"))
         );
         assert_eq!(
             read_matrix_market(&String::from("./data/matrix_market/bad_empty_file.mtx"), false).err(),
-            Some("file is empty")
+            Some(Error::MatrixMarket("file is empty"))
         );
         assert_eq!(
             read_matrix_market(&String::from("./data/matrix_market/bad_wrong_header.mtx"), false).err(),
-            Some("after %%MatrixMarket, the first option must be \"matrix\"")
+            Some(Error::MatrixMarket(
+                "after %%MatrixMarket, the first option must be \"matrix\""
+            ))
         );
         assert_eq!(
             read_matrix_market(&String::from("./data/matrix_market/bad_wrong_dims.mtx"), false).err(),
-            Some("found invalid (zero or negative) dimensions")
+            Some(Error::MatrixMarket("found invalid (zero or negative) dimensions"))
         );
         assert_eq!(
             read_matrix_market(&String::from("./data/matrix_market/bad_rectangular.mtx"), false).err(),
-            Some("cannot read non-square matrix")
+            Some(Error::MatrixMarket("cannot read non-square matrix"))
         );
         assert_eq!(
             read_matrix_market(&String::from("./data/matrix_market/bad_missing_data.mtx"), false).err(),
-            Some("not all triples (i,j,aij) have been found")
+            Some(Error::MatrixMarket("not all triples (i,j,aij) have been found"))
         );
         assert_eq!(
             read_matrix_market(&String::from("./data/matrix_market/bad_many_lines.mtx"), false).err(),
-            Some("there are more (i,j,aij) triples than specified")
+            Some(Error::MatrixMarket("there are more (i,j,aij) triples than specified"))
         );
     }
 
@@ -615,4 +740,50 @@ mod tests {
                        └                ┘";
         assert_eq!(format!("{}", a), correct);
     }
+
+    #[test]
+    fn from_matrix_market_works() {
+        assert_eq!(
+            SparseTriplet::from_matrix_market("__wrong__").err(),
+            Some(Error::MatrixMarket("cannot open file"))
+        );
+
+        // symmetric matrix is mirrored
+        let trip = SparseTriplet::from_matrix_market("./data/matrix_market/simple_sym.mtx").unwrap();
+        let correct = "┌       ┐\n\
+                       │ 1 2 0 │\n\
+                       │ 2 3 4 │\n\
+                       │ 0 4 0 │\n\
+                       └       ┘";
+        assert_eq!(format!("{}", trip.as_matrix()), correct);
+
+        // gzip-compressed file
+        let res = SparseTriplet::from_matrix_market("./data/matrix_market/simple_sym.mtx.gz");
+        #[cfg(feature = "gz")]
+        assert_eq!(format!("{}", res.unwrap().as_matrix()), correct);
+        #[cfg(not(feature = "gz"))]
+        assert_eq!(
+            res.err(),
+            Some(Error::MatrixMarket("reading .gz files requires the gz feature"))
+        );
+
+        // integer values and skew-symmetric matrix
+        let trip = SparseTriplet::from_matrix_market("./data/matrix_market/ok_integer_skew.mtx").unwrap();
+        assert_eq!((trip.neq, trip.pos, trip.max), (3, 4, 4));
+        let correct = "┌          ┐\n\
+                       │  0 -2  0 │\n\
+                       │  2  0  5 │\n\
+                       │  0 -5  0 │\n\
+                       └          ┘";
+        assert_eq!(format!("{}", trip.as_matrix()), correct);
+
+        // pattern
+        let trip = SparseTriplet::from_matrix_market("./data/matrix_market/ok_pattern_gen.mtx").unwrap();
+        let correct = "┌       ┐\n\
+                       │ 1 0 1 │\n\
+                       │ 0 1 0 │\n\
+                       │ 1 0 0 │\n\
+                       └       ┘";
+        assert_eq!(format!("{}", trip.as_matrix()), correct);
+    }
 }
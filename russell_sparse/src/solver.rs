@@ -1,68 +1,8 @@
-use super::{
-    code_symmetry_mmp, code_symmetry_umf, str_enum_ordering, str_enum_scaling, str_mmp_ordering, str_mmp_scaling,
-    str_umf_ordering, str_umf_scaling, ConfigSolver, LinSolKind, SparseTriplet,
-};
-use crate::{StrError, Symmetry};
-use russell_lab::{format_nanoseconds, vec_copy, Stopwatch, Vector};
-use russell_openblas::to_i32;
+use super::{ConfigSolver, LinSolKind, LinSolTrait, SolverMmp, SolverUmf, SparseTriplet};
+use crate::{Error, Symmetry};
+use russell_lab::{format_nanoseconds, PerfReport, Stopwatch, Vector};
 use std::fmt;
 
-#[repr(C)]
-pub(crate) struct ExtSolver {
-    data: [u8; 0],
-    marker: core::marker::PhantomData<(*mut u8, core::marker::PhantomPinned)>,
-}
-
-extern "C" {
-    // MMP
-    fn new_solver_mmp() -> *mut ExtSolver;
-    fn drop_solver_mmp(solver: *mut ExtSolver);
-    fn solver_mmp_initialize(
-        solver: *mut ExtSolver,
-        n: i32,
-        nnz: i32,
-        symmetry: i32,
-        ordering: i32,
-        scaling: i32,
-        pct_inc_workspace: i32,
-        max_work_memory: i32,
-        openmp_num_threads: i32,
-    ) -> i32;
-    fn solver_mmp_factorize(
-        solver: *mut ExtSolver,
-        indices_i: *const i32,
-        indices_j: *const i32,
-        values_aij: *const f64,
-        verbose: i32,
-    ) -> i32;
-    fn solver_mmp_solve(solver: *mut ExtSolver, rhs: *mut f64, verbose: i32) -> i32;
-    fn solver_mmp_used_ordering(solver: *const ExtSolver) -> i32;
-    fn solver_mmp_used_scaling(solver: *const ExtSolver) -> i32;
-
-    // UMF
-    fn new_solver_umf() -> *mut ExtSolver;
-    fn drop_solver_umf(solver: *mut ExtSolver);
-    fn solver_umf_initialize(
-        solver: *mut ExtSolver,
-        n: i32,
-        nnz: i32,
-        symmetry: i32,
-        ordering: i32,
-        scaling: i32,
-        verbose: i32,
-    ) -> i32;
-    fn solver_umf_factorize(
-        solver: *mut ExtSolver,
-        indices_i: *const i32,
-        indices_j: *const i32,
-        values_aij: *const f64,
-        verbose: i32,
-    ) -> i32;
-    fn solver_umf_solve(solver: *mut ExtSolver, x: *mut f64, rhs: *const f64, verbose: i32) -> i32;
-    fn solver_umf_used_ordering(solver: *const ExtSolver) -> i32;
-    fn solver_umf_used_scaling(solver: *const ExtSolver) -> i32;
-}
-
 /// Implements a sparse linear solver
 ///
 /// For a general sparse and square matrix `a` (symmetric, non-symmetric)
@@ -72,125 +12,65 @@ extern "C" {
 ///   a   ⋅  x  =  rhs
 /// (m,m)   (m)    (m)
 /// ```
+///
+/// The actual work is performed by an implementation of [LinSolTrait] (e.g., the wrappers to MUMPS or UMFPACK
+/// selected by [LinSolKind]; or a custom backend given to [Solver::new_custom]).
 pub struct Solver {
-    kind: LinSolKind,            // solver kind
-    verbose: i32,                // verbose mode
-    done_factorize: bool,        // factorization completed
-    neq: usize,                  // number of equations == nrow(a) where a*x=rhs
-    solver: *mut ExtSolver,      // data allocated by the c-code
-    stopwatch: Stopwatch,        // stopwatch to measure elapsed time
-    time_fact: u128,             // elapsed time during factorize
-    time_solve: u128,            // elapsed time during solve
-    used_ordering: &'static str, // used ordering strategy
-    used_scaling: &'static str,  // used scaling strategy
+    actual: Box<dyn LinSolTrait>, // actual solver (backend)
+    done_factorize: bool,         // factorization completed
+    neq: usize,                   // number of equations == nrow(a) where a*x=rhs
+    stopwatch: Stopwatch,         // stopwatch to measure elapsed time
+    time_fact: u128,              // elapsed time during factorize
+    time_solve: u128,             // elapsed time during solve
+    perf: PerfReport,             // statistics of the elapsed times of all calls to factorize and solve
 }
 
 impl Solver {
     /// Creates a new solver
-    pub fn new(config: ConfigSolver, neq: usize, nnz: usize, symmetry: Option<Symmetry>) -> Result<Self, StrError> {
-        let n = to_i32(neq);
-        let nnz = to_i32(nnz);
-        unsafe {
-            let solver = match config.lin_sol_kind {
-                LinSolKind::Mmp => new_solver_mmp(),
-                LinSolKind::Umf => new_solver_umf(),
-            };
-            if solver.is_null() {
-                return Err("c-code failed to allocate solver");
-            }
-            match config.lin_sol_kind {
-                LinSolKind::Mmp => {
-                    let res = solver_mmp_initialize(
-                        solver,
-                        n,
-                        nnz,
-                        code_symmetry_mmp(symmetry)?,
-                        config.ordering,
-                        config.scaling,
-                        config.pct_inc_workspace,
-                        config.max_work_memory,
-                        config.openmp_num_threads,
-                    );
-                    if res != 0 {
-                        drop_solver_mmp(solver);
-                        return Err(Solver::handle_mmp_error_code(res));
-                    }
-                }
-                LinSolKind::Umf => {
-                    let res = solver_umf_initialize(
-                        solver,
-                        n,
-                        nnz,
-                        code_symmetry_umf(symmetry)?,
-                        config.ordering,
-                        config.scaling,
-                        config.verbose,
-                    );
-                    if res != 0 {
-                        drop_solver_umf(solver);
-                        return Err(Solver::handle_umf_error_code(res));
-                    }
-                }
-            }
-            Ok(Solver {
-                kind: config.lin_sol_kind,
-                verbose: config.verbose,
-                done_factorize: false,
-                neq,
-                solver,
-                stopwatch: Stopwatch::new(""),
-                time_fact: 0,
-                time_solve: 0,
-                used_ordering: str_enum_ordering(config.ordering),
-                used_scaling: str_enum_scaling(config.scaling),
-            })
-        }
+    ///
+    /// The backend is selected by the `lin_sol_kind` option of the configuration.
+    pub fn new(config: ConfigSolver, neq: usize, nnz: usize, symmetry: Option<Symmetry>) -> Result<Self, Error> {
+        let actual: Box<dyn LinSolTrait> = match config.lin_sol_kind {
+            LinSolKind::Mmp => Box::new(SolverMmp::new()?),
+            LinSolKind::Umf => Box::new(SolverUmf::new()?),
+        };
+        Solver::new_custom(actual, config, neq, nnz, symmetry)
+    }
+
+    /// Creates a new solver with a custom backend
+    ///
+    /// The `lin_sol_kind` option of the configuration is ignored.
+    pub fn new_custom(
+        mut actual: Box<dyn LinSolTrait>,
+        config: ConfigSolver,
+        neq: usize,
+        nnz: usize,
+        symmetry: Option<Symmetry>,
+    ) -> Result<Self, Error> {
+        actual.initialize(&config, neq, nnz, symmetry)?;
+        Ok(Solver {
+            actual,
+            done_factorize: false,
+            neq,
+            stopwatch: Stopwatch::new(""),
+            time_fact: 0,
+            time_solve: 0,
+            perf: PerfReport::new(),
+        })
     }
 
     /// Performs the factorization
-    pub fn factorize(&mut self, trip: &SparseTriplet) -> Result<(), StrError> {
+    pub fn factorize(&mut self, trip: &SparseTriplet) -> Result<(), Error> {
         if trip.neq != self.neq {
-            return Err("cannot factorize because the triplet has incompatible number of equations");
+            return Err(Error::BadDimensions(
+                "cannot factorize because the triplet has incompatible number of equations",
+            ));
         }
         self.stopwatch.reset();
-        unsafe {
-            match self.kind {
-                LinSolKind::Mmp => {
-                    let res = solver_mmp_factorize(
-                        self.solver,
-                        trip.indices_i.as_ptr(),
-                        trip.indices_j.as_ptr(),
-                        trip.values_aij.as_ptr(),
-                        self.verbose,
-                    );
-                    if res != 0 {
-                        return Err(Solver::handle_mmp_error_code(res));
-                    }
-                    let ord = solver_mmp_used_ordering(self.solver);
-                    let sca = solver_mmp_used_scaling(self.solver);
-                    self.used_ordering = str_mmp_ordering(ord);
-                    self.used_scaling = str_mmp_scaling(sca);
-                }
-                LinSolKind::Umf => {
-                    let res = solver_umf_factorize(
-                        self.solver,
-                        trip.indices_i.as_ptr(),
-                        trip.indices_j.as_ptr(),
-                        trip.values_aij.as_ptr(),
-                        self.verbose,
-                    );
-                    if res != 0 {
-                        return Err(Solver::handle_umf_error_code(res));
-                    }
-                    let ord = solver_umf_used_ordering(self.solver);
-                    let sca = solver_umf_used_scaling(self.solver);
-                    self.used_ordering = str_umf_ordering(ord);
-                    self.used_scaling = str_umf_scaling(sca);
-                }
-            }
-        }
+        self.actual.factorize(trip)?;
         self.done_factorize = true;
         self.time_fact = self.stopwatch.stop();
+        self.perf.record("factorize", self.time_fact);
         Ok(())
     }
 
@@ -252,37 +132,19 @@ impl Solver {
     ///     Ok(())
     /// }
     /// ```
-    pub fn solve(&mut self, x: &mut Vector, rhs: &Vector) -> Result<(), StrError> {
+    pub fn solve(&mut self, x: &mut Vector, rhs: &Vector) -> Result<(), Error> {
         if !self.done_factorize {
-            return Err("factorization must be done before calling solve");
+            return Err(Error::NotFactorized("factorization must be done before calling solve"));
         }
         if x.dim() != self.neq || rhs.dim() != self.neq {
-            return Err("x.ndim() and rhs.ndim() must equal the number of equations");
+            return Err(Error::BadDimensions(
+                "x.ndim() and rhs.ndim() must equal the number of equations",
+            ));
         }
         self.stopwatch.reset();
-        unsafe {
-            match self.kind {
-                LinSolKind::Mmp => {
-                    vec_copy(x, rhs)?;
-                    let res = solver_mmp_solve(self.solver, x.as_mut_data().as_mut_ptr(), self.verbose);
-                    if res != 0 {
-                        return Err(Solver::handle_mmp_error_code(res));
-                    }
-                }
-                LinSolKind::Umf => {
-                    let res = solver_umf_solve(
-                        self.solver,
-                        x.as_mut_data().as_mut_ptr(),
-                        rhs.as_data().as_ptr(),
-                        self.verbose,
-                    );
-                    if res != 0 {
-                        return Err(Solver::handle_umf_error_code(res));
-                    }
-                }
-            }
-        }
+        self.actual.solve(x, rhs)?;
         self.time_solve = self.stopwatch.stop();
+        self.perf.record("solve", self.time_solve);
         Ok(())
     }
 
@@ -357,7 +219,7 @@ impl Solver {
     ///     Ok(())
     /// }
     /// ```
-    pub fn compute(config: ConfigSolver, trip: &SparseTriplet, rhs: &Vector) -> Result<(Self, Vector), StrError> {
+    pub fn compute(config: ConfigSolver, trip: &SparseTriplet, rhs: &Vector) -> Result<(Self, Vector), Error> {
         let mut solver = Solver::new(config, trip.neq, trip.pos, None)?;
         let mut x = Vector::new(trip.neq());
         solver.factorize(&trip)?;
@@ -374,127 +236,18 @@ impl Solver {
         (self.time_fact, self.time_solve)
     }
 
-    /// Handles error code
-    fn handle_mmp_error_code(err: i32) -> StrError {
-        match err {
-            -1 => "Error(-1): error on some processor",
-            -2 => "Error(-2): nnz is out of range",
-            -3 => "Error(-3): solver called with an invalid job value",
-            -4 => "Error(-4): error in user-provided permutation array",
-            -5 => "Error(-5): problem with real workspace allocation during analysis",
-            -6 => "Error(-6): matrix is singular in structure",
-            -7 => "Error(-7): problem with integer workspace allocation during analysis",
-            -8 => "Error(-8): internal integer work array is too small for factorization",
-            -9 => "Error(-9): internal real/complex work array is too small",
-            -10 => "Error(-10): numerically singular matrix",
-            -11 => "Error(-11): real/complex work array or lwk user is too small for solution",
-            -12 => "Error(-12): real/complex work array is too small for iterative refinement",
-            -13 => "Error(-13): problem with workspace allocation during factorization or solution",
-            -14 => "Error(-14): integer work array is too small for solution",
-            -15 => "Error(-15): integer work array is too small for iterative refinement and/or error analysis",
-            -16 => "Error(-16): n is out of range",
-            -17 => "Error(-17): internal send buffer is too small.",
-            -18 => "Error(-18): blocking size for multiple rhs is too large",
-            -19 => "Error(-19): maximum allowed size of working memory is too small for the factorization",
-            -20 => "Error(-20): reception buffer is too small",
-            -21 => "Error(-21): value of par=0 is not allowed",
-            -22 => "Error(-22): problem with a pointer array provided by the user",
-            -23 => "Error(-23): mpi was not initialized",
-            -24 => "Error(-24): nelt is out of range",
-            -25 => "Error(-25): problem with the initialization of BLACS",
-            -26 => "Error(-26): lrhs is out of range",
-            -27 => "Error(-27): nz rhs and irhs ptr(nrhs+1) do not match",
-            -28 => "Error(-28): irhs ptr(1) is not equal to 1",
-            -29 => "Error(-29): lsol loc is smaller than required",
-            -30 => "Error(-30): Schur lld is out of range",
-            -31 => "Error(-31): block cyclic symmetric Schur complement is required",
-            -32 => "Error(-32): incompatible values of nrhs",
-            -33 => "Error(-33): ICNTL(26) was asked for during solve/factorization phase",
-            -34 => "Error(-34): lredrhs is out of range",
-            -35 => "Error(-35): problem with the expansion phase",
-            -36 => "Error(-36): incompatible values of ICNTL(25) and INFOG(28)",
-            -37 => "Error(-37): value of ICNTL(25) is invalid",
-            -38 => "Error(-38): parallel analysis requires PT-SCOTCH or ParMetis",
-            -39 => "Error(-39): incompatible values for ICNTL(28), ICNTL(5) and/or ICNTL(19) and/or ICNTL(6)",
-            -40 => "Error(-40): the matrix is not positive definite as assumed",
-            -41 => "Error(-41): incompatible value of lwk user from factorization to solution",
-            -42 => "Error(-42): incompatible ICNTL(32) value",
-            -43 => "Error(-43): Incompatible values of ICNTL(32) and ICNTL(xx)",
-            -44 => "Error(-44): the solve phase (JOB=3) cannot be performed",
-            -45 => "Error(-45): nrhs less than 0",
-            -46 => "Error(-46): nz rhs less than 0",
-            -47 => "Error(-47): problem with entries of A-1",
-            -48 => "Error(-48): A-1 incompatible values of ICNTL(30) and ICNTL(xx)",
-            -49 => "Error(-49): size Schur has an incorrect value",
-            -50 => "Error(-50): problem with fill-reducing ordering during analysis",
-            -51 => "Error(-51): problem with external ordering (Metis/ParMetis, SCOTCH/PT-SCOTCH, PORD)",
-            -52 => "Error(-52): problem with default Fortran integers",
-            -53 => "Error(-53): inconsistent input data between two consecutive calls",
-            -54 => "Error(-54): incompatible ICNTL(35)=0",
-            -55 => "Error(-55): problem with solution and distributed right-hand side",
-            -56 => "Error(-56): problem with solution and distributed right-hand side",
-            -70 => "Error(-70): problem with the file to save the current instance",
-            -71 => "Error(-71): problem with the creation of one of the files",
-            -72 => "Error(-72): error while saving data",
-            -73 => "Error(-73): problem with incompatible parameter of the current instance",
-            -74 => "Error(-74): problem with output file",
-            -75 => "Error(-75): error while restoring data",
-            -76 => "Error(-76): error while deleting the files",
-            -77 => "Error(-77): neither save dir nor the environment variable are defined.",
-            -78 => "Error(-78): problem of workspace allocation during the restore step",
-            -79 => "Error(-79): problem with the file unit used to open the save/restore file",
-            -90 => "Error(-90): error in out-of-core management",
-            -800 => "Error(-800): temporary error associated to the current release",
-            1 => "Error(+1): index (in irn or jcn) is out of range",
-            2 => "Error(+2): during error analysis the max-norm of the computed solution is close to zero",
-            4 => "Error(+4): not used in current version",
-            8 => "Error(+8): problem with the iterative refinement routine",
-            100000 => return "Error: c-code returned null pointer (MMP)",
-            200000 => return "Error: c-code failed to allocate memory (MMP)",
-            _ => return "Error: unknown error returned by c-code (MMP)",
-        }
-    }
-
-    /// Handles UMF error code
-    fn handle_umf_error_code(err: i32) -> StrError {
-        match err {
-            1 => return "Error(1): Matrix is singular",
-            2 => return "Error(2): The determinant is nonzero, but smaller than allowed",
-            3 => return "Error(3): The determinant is larger than allowed",
-            -1 => return "Error(-1): Not enough memory",
-            -3 => return "Error(-3): Invalid numeric object",
-            -4 => return "Error(-4): Invalid symbolic object",
-            -5 => return "Error(-5): Argument missing",
-            -6 => return "Error(-6): Nrow or ncol must be greater than zero",
-            -8 => return "Error(-8): Invalid matrix",
-            -11 => return "Error(-11): Different pattern",
-            -13 => return "Error(-13): Invalid system",
-            -15 => return "Error(-15): Invalid permutation",
-            -17 => return "Error(-17): Failed to save/load file",
-            -18 => return "Error(-18): Ordering method failed",
-            -911 => return "Error(-911): An internal error has occurred",
-            100000 => return "Error: c-code returned null pointer (UMF)",
-            200000 => return "Error: c-code failed to allocate memory (UMF)",
-            _ => return "Error: unknown error returned by c-code (UMF)",
-        }
-    }
-}
-
-impl Drop for Solver {
-    /// Tells the c-code to release memory
-    fn drop(&mut self) {
-        unsafe {
-            match self.kind {
-                LinSolKind::Mmp => drop_solver_mmp(self.solver),
-                LinSolKind::Umf => drop_solver_umf(self.solver),
-            }
-        }
+    /// Returns the statistics of the elapsed times of all calls to factorize and solve
+    ///
+    /// The sections are named "factorize" and "solve".
+    pub fn get_perf_report(&self) -> &PerfReport {
+        &self.perf
     }
 }
 
 impl fmt::Display for Solver {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let time_total = self.time_fact + self.time_solve;
+        let stats = self.actual.stats();
         write!(
             f,
             "\x20\x20\x20\x20\"usedOrdering\": \"{}\",\n\
@@ -507,8 +260,8 @@ impl fmt::Display for Solver {
              \x20\x20\x20\x20\"timeFactStr\": \"{}\",\n\
              \x20\x20\x20\x20\"timeSolveStr\": \"{}\",\n\
              \x20\x20\x20\x20\"timeTotalStr\": \"{}\"",
-            self.used_ordering,
-            self.used_scaling,
+            stats.used_ordering,
+            stats.used_scaling,
             self.done_factorize,
             self.neq,
             self.time_fact,
@@ -527,8 +280,35 @@ impl fmt::Display for Solver {
 #[cfg(test)]
 mod tests {
     use super::{ConfigSolver, LinSolKind, Solver, SparseTriplet};
+    use crate::{Error, LinSolStats, LinSolTrait, Symmetry};
     use russell_chk::vec_approx_eq;
-    use russell_lab::Vector;
+    use russell_lab::{solve_lin_sys, vec_copy, Matrix, Vector};
+
+    // dense solver to test the plugging of custom backends
+    struct DenseSolver {
+        a: Matrix,
+    }
+
+    impl LinSolTrait for DenseSolver {
+        fn initialize(&mut self, _: &ConfigSolver, neq: usize, _: usize, _: Option<Symmetry>) -> Result<(), Error> {
+            self.a = Matrix::new(neq, neq);
+            Ok(())
+        }
+        fn factorize(&mut self, trip: &SparseTriplet) -> Result<(), Error> {
+            trip.to_matrix(&mut self.a)
+        }
+        fn solve(&mut self, x: &mut Vector, rhs: &Vector) -> Result<(), Error> {
+            vec_copy(x, rhs)?;
+            solve_lin_sys(x, &mut self.a.clone())?;
+            Ok(())
+        }
+        fn stats(&self) -> LinSolStats {
+            LinSolStats {
+                used_ordering: "No",
+                used_scaling: "No",
+            }
+        }
+    }
 
     #[test]
     fn new_works() {
@@ -546,7 +326,9 @@ mod tests {
         let trip = SparseTriplet::new(2, 2).unwrap();
         assert_eq!(
             solver.factorize(&trip).err(),
-            Some("cannot factorize because the triplet has incompatible number of equations")
+            Some(Error::BadDimensions(
+                "cannot factorize because the triplet has incompatible number of equations"
+            ))
         );
     }
 
@@ -558,7 +340,10 @@ mod tests {
         let mut trip = SparseTriplet::new(neq, nnz).unwrap();
         trip.put(0, 0, 1.0).unwrap();
         trip.put(1, 1, 0.0).unwrap();
-        assert_eq!(solver.factorize(&trip), Err("Error(1): Matrix is singular"));
+        assert_eq!(
+            solver.factorize(&trip),
+            Err(Error::Singular("Error(1): Matrix is singular"))
+        );
     }
 
     #[test]
@@ -585,7 +370,7 @@ mod tests {
         let rhs = Vector::from(&[1.0, 1.0]);
         assert_eq!(
             solver.solve(&mut x, &rhs),
-            Err("factorization must be done before calling solve")
+            Err(Error::NotFactorized("factorization must be done before calling solve"))
         );
     }
 
@@ -604,11 +389,15 @@ mod tests {
         let rhs_wrong = Vector::from(&[1.0]);
         assert_eq!(
             solver.solve(&mut x_wrong, &rhs),
-            Err("x.ndim() and rhs.ndim() must equal the number of equations")
+            Err(Error::BadDimensions(
+                "x.ndim() and rhs.ndim() must equal the number of equations"
+            ))
         );
         assert_eq!(
             solver.solve(&mut x, &rhs_wrong),
-            Err("x.ndim() and rhs.ndim() must equal the number of equations")
+            Err(Error::BadDimensions(
+                "x.ndim() and rhs.ndim() must equal the number of equations"
+            ))
         );
     }
 
@@ -663,7 +452,9 @@ mod tests {
         trip_wrong.put(0, 0, 1.0).unwrap();
         assert_eq!(
             solver.factorize(&trip_wrong).err(),
-            Some("cannot factorize because the triplet has incompatible number of equations")
+            Some(Error::BadDimensions(
+                "cannot factorize because the triplet has incompatible number of equations"
+            ))
         );
 
         // allocate a square matrix
@@ -690,7 +481,7 @@ mod tests {
         // solve fails on non-factorized system
         assert_eq!(
             solver.solve(&mut x, &rhs),
-            Err("factorization must be done before calling solve")
+            Err(Error::NotFactorized("factorization must be done before calling solve"))
         );
 
         // factorize works
@@ -701,14 +492,18 @@ mod tests {
         let mut x_wrong = Vector::new(3);
         assert_eq!(
             solver.solve(&mut x_wrong, &rhs),
-            Err("x.ndim() and rhs.ndim() must equal the number of equations")
+            Err(Error::BadDimensions(
+                "x.ndim() and rhs.ndim() must equal the number of equations"
+            ))
         );
 
         // solve fails on wrong rhs vector
         let rhs_wrong = Vector::from(&[1.0]);
         assert_eq!(
             solver.solve(&mut x, &rhs_wrong),
-            Err("x.ndim() and rhs.ndim() must equal the number of equations")
+            Err(Error::BadDimensions(
+                "x.ndim() and rhs.ndim() must equal the number of equations"
+            ))
         );
 
         // solve works
@@ -727,7 +522,7 @@ mod tests {
         let mut solver = Solver::new(config, 5, 2, None).unwrap();
         assert_eq!(
             solver.factorize(&trip_singular),
-            Err("Error(-10): numerically singular matrix")
+            Err(Error::Singular("Error(-10): numerically singular matrix"))
         );
     }
 
@@ -757,56 +552,32 @@ mod tests {
         let solver = Solver::new(config, neq, nnz, None).unwrap();
         let times = solver.get_elapsed_times();
         assert_eq!(times, (0, 0));
+        assert_eq!(solver.get_perf_report().sections().len(), 0);
     }
 
     #[test]
-    fn handle_mmp_error_code_works() {
-        let default = "Error: unknown error returned by c-code (MMP)";
-        let mut config = ConfigSolver::new();
-        config.lin_sol_kind(LinSolKind::Mmp);
-        for c in 1..57 {
-            let res = Solver::handle_mmp_error_code(-c);
-            assert!(res.len() > 0);
-            assert_ne!(res, default);
-        }
-        for c in 70..80 {
-            let res = Solver::handle_mmp_error_code(-c);
-            assert!(res.len() > 0);
-            assert_ne!(res, default);
-        }
-        for c in &[-90, -800, 1, 2, 4, 8] {
-            let res = Solver::handle_mmp_error_code(*c);
-            assert!(res.len() > 0);
-            assert_ne!(res, default);
-        }
-        assert_eq!(
-            Solver::handle_mmp_error_code(100000),
-            "Error: c-code returned null pointer (MMP)"
-        );
-        assert_eq!(
-            Solver::handle_mmp_error_code(200000),
-            "Error: c-code failed to allocate memory (MMP)"
-        );
-        assert_eq!(Solver::handle_mmp_error_code(123), default);
-    }
-
-    #[test]
-    fn handle_umf_error_code_works() {
-        let default = "Error: unknown error returned by c-code (UMF)";
-        for c in &[1, 2, 3, -1, -3, -4, -5, -6, -8, -11, -13, -15, -17, -18, -911] {
-            let res = Solver::handle_umf_error_code(*c);
-            assert!(res.len() > 0);
-            assert_ne!(res, default);
-        }
-        assert_eq!(
-            Solver::handle_umf_error_code(100000),
-            "Error: c-code returned null pointer (UMF)"
-        );
+    fn new_custom_works() {
+        let config = ConfigSolver::new();
+        let actual = Box::new(DenseSolver { a: Matrix::new(0, 0) });
+        let mut solver = Solver::new_custom(actual, config, 2, 3, None).unwrap();
+        let mut trip = SparseTriplet::new(2, 3).unwrap();
+        trip.put(0, 0, 2.0).unwrap();
+        trip.put(0, 1, 1.0).unwrap();
+        trip.put(1, 1, 4.0).unwrap();
+        let mut x = Vector::new(2);
+        let rhs = Vector::from(&[3.0, 4.0]);
         assert_eq!(
-            Solver::handle_umf_error_code(200000),
-            "Error: c-code failed to allocate memory (UMF)"
+            solver.solve(&mut x, &rhs),
+            Err(Error::NotFactorized("factorization must be done before calling solve"))
         );
-        assert_eq!(Solver::handle_umf_error_code(123), default);
+        solver.factorize(&trip).unwrap();
+        solver.solve(&mut x, &rhs).unwrap();
+        vec_approx_eq(x.as_data(), &[1.0, 1.0], 1e-15);
+        assert_eq!(solver.get_perf_report().sections().len(), 2);
+        assert!(format!("{}", solver).starts_with(
+            "\x20\x20\x20\x20\"usedOrdering\": \"No\",\n\
+             \x20\x20\x20\x20\"usedScaling\": \"No\",\n"
+        ));
     }
 
     #[test]
@@ -0,0 +1,263 @@
+use super::{code_symmetry_mmp, str_enum_ordering, str_enum_scaling, str_mmp_ordering, str_mmp_scaling, to_i32};
+use crate::{ConfigSolver, Error, LinSolStats, LinSolTrait, SparseTriplet, Symmetry};
+use russell_lab::{vec_copy, Vector};
+
+#[repr(C)]
+pub(crate) struct ExtSolverMmp {
+    data: [u8; 0],
+    marker: core::marker::PhantomData<(*mut u8, core::marker::PhantomPinned)>,
+}
+
+extern "C" {
+    fn new_solver_mmp() -> *mut ExtSolverMmp;
+    fn drop_solver_mmp(solver: *mut ExtSolverMmp);
+    fn solver_mmp_initialize(
+        solver: *mut ExtSolverMmp,
+        n: i32,
+        nnz: i32,
+        symmetry: i32,
+        ordering: i32,
+        scaling: i32,
+        pct_inc_workspace: i32,
+        max_work_memory: i32,
+        openmp_num_threads: i32,
+    ) -> i32;
+    fn solver_mmp_factorize(
+        solver: *mut ExtSolverMmp,
+        indices_i: *const i32,
+        indices_j: *const i32,
+        values_aij: *const f64,
+        verbose: i32,
+    ) -> i32;
+    fn solver_mmp_solve(solver: *mut ExtSolverMmp, rhs: *mut f64, verbose: i32) -> i32;
+    fn solver_mmp_used_ordering(solver: *const ExtSolverMmp) -> i32;
+    fn solver_mmp_used_scaling(solver: *const ExtSolverMmp) -> i32;
+}
+
+/// Wraps the NON-THREAD-SAFE (Mu-M-P) Solver
+pub(crate) struct SolverMmp {
+    solver: *mut ExtSolverMmp,   // data allocated by the c-code
+    verbose: i32,                // verbose mode
+    used_ordering: &'static str, // used ordering strategy
+    used_scaling: &'static str,  // used scaling strategy
+}
+
+impl SolverMmp {
+    /// Allocates a new instance
+    pub(crate) fn new() -> Result<Self, Error> {
+        unsafe {
+            let solver = new_solver_mmp();
+            if solver.is_null() {
+                return Err(Error::Solver("c-code failed to allocate solver"));
+            }
+            Ok(SolverMmp {
+                solver,
+                verbose: 0,
+                used_ordering: "Auto",
+                used_scaling: "Auto",
+            })
+        }
+    }
+
+    /// Handles error code
+    pub(crate) fn handle_error_code(err: i32) -> Error {
+        let message = match err {
+            -1 => "Error(-1): error on some processor",
+            -2 => "Error(-2): nnz is out of range",
+            -3 => "Error(-3): solver called with an invalid job value",
+            -4 => "Error(-4): error in user-provided permutation array",
+            -5 => "Error(-5): problem with real workspace allocation during analysis",
+            -6 => "Error(-6): matrix is singular in structure",
+            -7 => "Error(-7): problem with integer workspace allocation during analysis",
+            -8 => "Error(-8): internal integer work array is too small for factorization",
+            -9 => "Error(-9): internal real/complex work array is too small",
+            -10 => "Error(-10): numerically singular matrix",
+            -11 => "Error(-11): real/complex work array or lwk user is too small for solution",
+            -12 => "Error(-12): real/complex work array is too small for iterative refinement",
+            -13 => "Error(-13): problem with workspace allocation during factorization or solution",
+            -14 => "Error(-14): integer work array is too small for solution",
+            -15 => "Error(-15): integer work array is too small for iterative refinement and/or error analysis",
+            -16 => "Error(-16): n is out of range",
+            -17 => "Error(-17): internal send buffer is too small.",
+            -18 => "Error(-18): blocking size for multiple rhs is too large",
+            -19 => "Error(-19): maximum allowed size of working memory is too small for the factorization",
+            -20 => "Error(-20): reception buffer is too small",
+            -21 => "Error(-21): value of par=0 is not allowed",
+            -22 => "Error(-22): problem with a pointer array provided by the user",
+            -23 => "Error(-23): mpi was not initialized",
+            -24 => "Error(-24): nelt is out of range",
+            -25 => "Error(-25): problem with the initialization of BLACS",
+            -26 => "Error(-26): lrhs is out of range",
+            -27 => "Error(-27): nz rhs and irhs ptr(nrhs+1) do not match",
+            -28 => "Error(-28): irhs ptr(1) is not equal to 1",
+            -29 => "Error(-29): lsol loc is smaller than required",
+            -30 => "Error(-30): Schur lld is out of range",
+            -31 => "Error(-31): block cyclic symmetric Schur complement is required",
+            -32 => "Error(-32): incompatible values of nrhs",
+            -33 => "Error(-33): ICNTL(26) was asked for during solve/factorization phase",
+            -34 => "Error(-34): lredrhs is out of range",
+            -35 => "Error(-35): problem with the expansion phase",
+            -36 => "Error(-36): incompatible values of ICNTL(25) and INFOG(28)",
+            -37 => "Error(-37): value of ICNTL(25) is invalid",
+            -38 => "Error(-38): parallel analysis requires PT-SCOTCH or ParMetis",
+            -39 => "Error(-39): incompatible values for ICNTL(28), ICNTL(5) and/or ICNTL(19) and/or ICNTL(6)",
+            -40 => "Error(-40): the matrix is not positive definite as assumed",
+            -41 => "Error(-41): incompatible value of lwk user from factorization to solution",
+            -42 => "Error(-42): incompatible ICNTL(32) value",
+            -43 => "Error(-43): Incompatible values of ICNTL(32) and ICNTL(xx)",
+            -44 => "Error(-44): the solve phase (JOB=3) cannot be performed",
+            -45 => "Error(-45): nrhs less than 0",
+            -46 => "Error(-46): nz rhs less than 0",
+            -47 => "Error(-47): problem with entries of A-1",
+            -48 => "Error(-48): A-1 incompatible values of ICNTL(30) and ICNTL(xx)",
+            -49 => "Error(-49): size Schur has an incorrect value",
+            -50 => "Error(-50): problem with fill-reducing ordering during analysis",
+            -51 => "Error(-51): problem with external ordering (Metis/ParMetis, SCOTCH/PT-SCOTCH, PORD)",
+            -52 => "Error(-52): problem with default Fortran integers",
+            -53 => "Error(-53): inconsistent input data between two consecutive calls",
+            -54 => "Error(-54): incompatible ICNTL(35)=0",
+            -55 => "Error(-55): problem with solution and distributed right-hand side",
+            -56 => "Error(-56): problem with solution and distributed right-hand side",
+            -70 => "Error(-70): problem with the file to save the current instance",
+            -71 => "Error(-71): problem with the creation of one of the files",
+            -72 => "Error(-72): error while saving data",
+            -73 => "Error(-73): problem with incompatible parameter of the current instance",
+            -74 => "Error(-74): problem with output file",
+            -75 => "Error(-75): error while restoring data",
+            -76 => "Error(-76): error while deleting the files",
+            -77 => "Error(-77): neither save dir nor the environment variable are defined.",
+            -78 => "Error(-78): problem of workspace allocation during the restore step",
+            -79 => "Error(-79): problem with the file unit used to open the save/restore file",
+            -90 => "Error(-90): error in out-of-core management",
+            -800 => "Error(-800): temporary error associated to the current release",
+            1 => "Error(+1): index (in irn or jcn) is out of range",
+            2 => "Error(+2): during error analysis the max-norm of the computed solution is close to zero",
+            4 => "Error(+4): not used in current version",
+            8 => "Error(+8): problem with the iterative refinement routine",
+            100000 => "Error: c-code returned null pointer (MMP)",
+            200000 => "Error: c-code failed to allocate memory (MMP)",
+            _ => "Error: unknown error returned by c-code (MMP)",
+        };
+        match err {
+            -6 | -10 | -40 => Error::Singular(message),
+            _ => Error::Solver(message),
+        }
+    }
+}
+
+impl LinSolTrait for SolverMmp {
+    fn initialize(
+        &mut self,
+        config: &ConfigSolver,
+        neq: usize,
+        nnz: usize,
+        symmetry: Option<Symmetry>,
+    ) -> Result<(), Error> {
+        unsafe {
+            let res = solver_mmp_initialize(
+                self.solver,
+                to_i32(neq),
+                to_i32(nnz),
+                code_symmetry_mmp(symmetry)?,
+                config.ordering,
+                config.scaling,
+                config.pct_inc_workspace,
+                config.max_work_memory,
+                config.openmp_num_threads,
+            );
+            if res != 0 {
+                return Err(SolverMmp::handle_error_code(res));
+            }
+        }
+        self.verbose = config.verbose;
+        self.used_ordering = str_enum_ordering(config.ordering);
+        self.used_scaling = str_enum_scaling(config.scaling);
+        Ok(())
+    }
+
+    fn factorize(&mut self, trip: &SparseTriplet) -> Result<(), Error> {
+        unsafe {
+            let res = solver_mmp_factorize(
+                self.solver,
+                trip.indices_i.as_ptr(),
+                trip.indices_j.as_ptr(),
+                trip.values_aij.as_ptr(),
+                self.verbose,
+            );
+            if res != 0 {
+                return Err(SolverMmp::handle_error_code(res));
+            }
+            self.used_ordering = str_mmp_ordering(solver_mmp_used_ordering(self.solver));
+            self.used_scaling = str_mmp_scaling(solver_mmp_used_scaling(self.solver));
+        }
+        Ok(())
+    }
+
+    fn solve(&mut self, x: &mut Vector, rhs: &Vector) -> Result<(), Error> {
+        vec_copy(x, rhs)?;
+        unsafe {
+            let res = solver_mmp_solve(self.solver, x.as_mut_data().as_mut_ptr(), self.verbose);
+            if res != 0 {
+                return Err(SolverMmp::handle_error_code(res));
+            }
+        }
+        Ok(())
+    }
+
+    fn stats(&self) -> LinSolStats {
+        LinSolStats {
+            used_ordering: self.used_ordering,
+            used_scaling: self.used_scaling,
+        }
+    }
+}
+
+impl Drop for SolverMmp {
+    /// Tells the c-code to release memory
+    fn drop(&mut self) {
+        unsafe {
+            drop_solver_mmp(self.solver);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::SolverMmp;
+    use crate::Error;
+
+    #[test]
+    fn handle_error_code_works() {
+        let default = "Error: unknown error returned by c-code (MMP)";
+        for c in 1..57 {
+            let res = SolverMmp::handle_error_code(-c);
+            assert!(res.message().len() > 0);
+            assert_ne!(res.message(), default);
+        }
+        for c in 70..80 {
+            let res = SolverMmp::handle_error_code(-c);
+            assert!(res.message().len() > 0);
+            assert_ne!(res.message(), default);
+        }
+        for c in &[-90, -800, 1, 2, 4, 8] {
+            let res = SolverMmp::handle_error_code(*c);
+            assert!(res.message().len() > 0);
+            assert_ne!(res.message(), default);
+        }
+        assert_eq!(
+            SolverMmp::handle_error_code(-10),
+            Error::Singular("Error(-10): numerically singular matrix")
+        );
+        assert_eq!(
+            SolverMmp::handle_error_code(100000),
+            Error::Solver("Error: c-code returned null pointer (MMP)")
+        );
+        assert_eq!(
+            SolverMmp::handle_error_code(200000),
+            Error::Solver("Error: c-code failed to allocate memory (MMP)")
+        );
+        assert_eq!(SolverMmp::handle_error_code(123), Error::Solver(default));
+    }
+}
@@ -0,0 +1,196 @@
+use super::{code_symmetry_umf, str_enum_ordering, str_enum_scaling, str_umf_ordering, str_umf_scaling, to_i32};
+use crate::{ConfigSolver, Error, LinSolStats, LinSolTrait, SparseTriplet, Symmetry};
+use russell_lab::Vector;
+
+#[repr(C)]
+pub(crate) struct ExtSolverUmf {
+    data: [u8; 0],
+    marker: core::marker::PhantomData<(*mut u8, core::marker::PhantomPinned)>,
+}
+
+extern "C" {
+    fn new_solver_umf() -> *mut ExtSolverUmf;
+    fn drop_solver_umf(solver: *mut ExtSolverUmf);
+    fn solver_umf_initialize(
+        solver: *mut ExtSolverUmf,
+        n: i32,
+        nnz: i32,
+        symmetry: i32,
+        ordering: i32,
+        scaling: i32,
+        verbose: i32,
+    ) -> i32;
+    fn solver_umf_factorize(
+        solver: *mut ExtSolverUmf,
+        indices_i: *const i32,
+        indices_j: *const i32,
+        values_aij: *const f64,
+        verbose: i32,
+    ) -> i32;
+    fn solver_umf_solve(solver: *mut ExtSolverUmf, x: *mut f64, rhs: *const f64, verbose: i32) -> i32;
+    fn solver_umf_used_ordering(solver: *const ExtSolverUmf) -> i32;
+    fn solver_umf_used_scaling(solver: *const ExtSolverUmf) -> i32;
+}
+
+/// Wraps Tim Davis' UMFPACK Solver
+pub(crate) struct SolverUmf {
+    solver: *mut ExtSolverUmf,   // data allocated by the c-code
+    verbose: i32,                // verbose mode
+    used_ordering: &'static str, // used ordering strategy
+    used_scaling: &'static str,  // used scaling strategy
+}
+
+impl SolverUmf {
+    /// Allocates a new instance
+    pub(crate) fn new() -> Result<Self, Error> {
+        unsafe {
+            let solver = new_solver_umf();
+            if solver.is_null() {
+                return Err(Error::Solver("c-code failed to allocate solver"));
+            }
+            Ok(SolverUmf {
+                solver,
+                verbose: 0,
+                used_ordering: "Auto",
+                used_scaling: "Auto",
+            })
+        }
+    }
+
+    /// Handles error code
+    pub(crate) fn handle_error_code(err: i32) -> Error {
+        let message = match err {
+            1 => "Error(1): Matrix is singular",
+            2 => "Error(2): The determinant is nonzero, but smaller than allowed",
+            3 => "Error(3): The determinant is larger than allowed",
+            -1 => "Error(-1): Not enough memory",
+            -3 => "Error(-3): Invalid numeric object",
+            -4 => "Error(-4): Invalid symbolic object",
+            -5 => "Error(-5): Argument missing",
+            -6 => "Error(-6): Nrow or ncol must be greater than zero",
+            -8 => "Error(-8): Invalid matrix",
+            -11 => "Error(-11): Different pattern",
+            -13 => "Error(-13): Invalid system",
+            -15 => "Error(-15): Invalid permutation",
+            -17 => "Error(-17): Failed to save/load file",
+            -18 => "Error(-18): Ordering method failed",
+            -911 => "Error(-911): An internal error has occurred",
+            100000 => "Error: c-code returned null pointer (UMF)",
+            200000 => "Error: c-code failed to allocate memory (UMF)",
+            _ => "Error: unknown error returned by c-code (UMF)",
+        };
+        match err {
+            1 => Error::Singular(message),
+            _ => Error::Solver(message),
+        }
+    }
+}
+
+impl LinSolTrait for SolverUmf {
+    fn initialize(
+        &mut self,
+        config: &ConfigSolver,
+        neq: usize,
+        nnz: usize,
+        symmetry: Option<Symmetry>,
+    ) -> Result<(), Error> {
+        unsafe {
+            let res = solver_umf_initialize(
+                self.solver,
+                to_i32(neq),
+                to_i32(nnz),
+                code_symmetry_umf(symmetry)?,
+                config.ordering,
+                config.scaling,
+                config.verbose,
+            );
+            if res != 0 {
+                return Err(SolverUmf::handle_error_code(res));
+            }
+        }
+        self.verbose = config.verbose;
+        self.used_ordering = str_enum_ordering(config.ordering);
+        self.used_scaling = str_enum_scaling(config.scaling);
+        Ok(())
+    }
+
+    fn factorize(&mut self, trip: &SparseTriplet) -> Result<(), Error> {
+        unsafe {
+            let res = solver_umf_factorize(
+                self.solver,
+                trip.indices_i.as_ptr(),
+                trip.indices_j.as_ptr(),
+                trip.values_aij.as_ptr(),
+                self.verbose,
+            );
+            if res != 0 {
+                return Err(SolverUmf::handle_error_code(res));
+            }
+            self.used_ordering = str_umf_ordering(solver_umf_used_ordering(self.solver));
+            self.used_scaling = str_umf_scaling(solver_umf_used_scaling(self.solver));
+        }
+        Ok(())
+    }
+
+    fn solve(&mut self, x: &mut Vector, rhs: &Vector) -> Result<(), Error> {
+        unsafe {
+            let res = solver_umf_solve(
+                self.solver,
+                x.as_mut_data().as_mut_ptr(),
+                rhs.as_data().as_ptr(),
+                self.verbose,
+            );
+            if res != 0 {
+                return Err(SolverUmf::handle_error_code(res));
+            }
+        }
+        Ok(())
+    }
+
+    fn stats(&self) -> LinSolStats {
+        LinSolStats {
+            used_ordering: self.used_ordering,
+            used_scaling: self.used_scaling,
+        }
+    }
+}
+
+impl Drop for SolverUmf {
+    /// Tells the c-code to release memory
+    fn drop(&mut self) {
+        unsafe {
+            drop_solver_umf(self.solver);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::SolverUmf;
+    use crate::Error;
+
+    #[test]
+    fn handle_error_code_works() {
+        let default = "Error: unknown error returned by c-code (UMF)";
+        for c in &[1, 2, 3, -1, -3, -4, -5, -6, -8, -11, -13, -15, -17, -18, -911] {
+            let res = SolverUmf::handle_error_code(*c);
+            assert!(res.message().len() > 0);
+            assert_ne!(res.message(), default);
+        }
+        assert_eq!(
+            SolverUmf::handle_error_code(1),
+            Error::Singular("Error(1): Matrix is singular")
+        );
+        assert_eq!(
+            SolverUmf::handle_error_code(100000),
+            Error::Solver("Error: c-code returned null pointer (UMF)")
+        );
+        assert_eq!(
+            SolverUmf::handle_error_code(200000),
+            Error::Solver("Error: c-code failed to allocate memory (UMF)")
+        );
+        assert_eq!(SolverUmf::handle_error_code(123), Error::Solver(default));
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{to_i32, CsrMatrix, Error};
+
+/// Performs the addition of two sparse matrices (CSR format)
+///
+/// ```text
+///   c  :=  α ⋅  a   +  β ⋅  b
+/// (m,n)       (m,n)       (m,n)
+/// ```
+///
+/// The sparsity pattern of `c` is the union of the patterns of `a` and `b`. The arrays of `c` are
+/// replaced; thus, `c` may be created with any dimensions (e.g., by `CsrMatrix::new(0, 0, vec![0], vec![], vec![])`).
+/// Entries that cancel out numerically are kept as explicit zeros so that the pattern does not
+/// depend on the values of `α` and `β` (e.g., in a loop over shifts `K + σ⋅M`).
+///
+/// # Example
+///
+/// ```
+/// use russell_sparse::{sp_mat_add, CsrMatrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // ┌     ┐      ┌     ┐
+///     // │ 2 1 │      │ 1 0 │
+///     // │ 0 3 │      │ 1 1 │
+///     // └     ┘      └     ┘
+///     let kk = CsrMatrix::new(2, 2, vec![0, 2, 3], vec![0, 1, 1], vec![2.0, 1.0, 3.0])?;
+///     let mm = CsrMatrix::new(2, 2, vec![0, 1, 3], vec![0, 0, 1], vec![1.0, 1.0, 1.0])?;
+///     let mut c = CsrMatrix::new(0, 0, vec![0], vec![], vec![])?;
+///     sp_mat_add(&mut c, 1.0, &kk, -2.0, &mm)?;
+///     assert_eq!(c.row_pointers(), &[0, 2, 4]);
+///     assert_eq!(c.col_indices(), &[0, 1, 0, 1]);
+///     assert_eq!(c.values(), &[0.0, 1.0, -2.0, 1.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn sp_mat_add(c: &mut CsrMatrix, alpha: f64, a: &CsrMatrix, beta: f64, b: &CsrMatrix) -> Result<(), Error> {
+    if a.nrow != b.nrow || a.ncol != b.ncol {
+        return Err(Error::BadDimensions("matrices are incompatible"));
+    }
+    let nrow = a.nrow;
+    let mut row_pointers = vec![0_i32; nrow + 1];
+    let mut col_indices = Vec::with_capacity(a.nnz() + b.nnz());
+    let mut values = Vec::with_capacity(a.nnz() + b.nnz());
+    for i in 0..nrow {
+        // merge the sorted rows of a and b
+        let (mut pa, end_a) = (a.row_pointers[i] as usize, a.row_pointers[i + 1] as usize);
+        let (mut pb, end_b) = (b.row_pointers[i] as usize, b.row_pointers[i + 1] as usize);
+        while pa < end_a || pb < end_b {
+            let ja = if pa < end_a { a.col_indices[pa] } else { i32::MAX };
+            let jb = if pb < end_b { b.col_indices[pb] } else { i32::MAX };
+            if ja < jb {
+                col_indices.push(ja);
+                values.push(alpha * a.values[pa]);
+                pa += 1;
+            } else if jb < ja {
+                col_indices.push(jb);
+                values.push(beta * b.values[pb]);
+                pb += 1;
+            } else {
+                col_indices.push(ja);
+                values.push(alpha * a.values[pa] + beta * b.values[pb]);
+                pa += 1;
+                pb += 1;
+            }
+        }
+        row_pointers[i + 1] = to_i32(col_indices.len());
+    }
+    c.nrow = nrow;
+    c.ncol = a.ncol;
+    c.row_pointers = row_pointers;
+    c.col_indices = col_indices;
+    c.values = values;
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::sp_mat_add;
+    use crate::{CsrMatrix, Error};
+    use russell_lab::{mat_add, mat_approx_eq, Matrix};
+
+    #[test]
+    fn sp_mat_add_fails_on_wrong_dims() {
+        let a = CsrMatrix::new(2, 2, vec![0, 0, 0], vec![], vec![]).unwrap();
+        let b_2x3 = CsrMatrix::new(2, 3, vec![0, 0, 0], vec![], vec![]).unwrap();
+        let b_1x2 = CsrMatrix::new(1, 2, vec![0, 0], vec![], vec![]).unwrap();
+        let mut c = CsrMatrix::new(0, 0, vec![0], vec![], vec![]).unwrap();
+        assert_eq!(
+            sp_mat_add(&mut c, 1.0, &a, 1.0, &b_2x3),
+            Err(Error::BadDimensions("matrices are incompatible"))
+        );
+        assert_eq!(
+            sp_mat_add(&mut c, 1.0, &a, 1.0, &b_1x2),
+            Err(Error::BadDimensions("matrices are incompatible"))
+        );
+    }
+
+    #[test]
+    fn sp_mat_add_works() {
+        // ┌         ┐      ┌         ┐
+        // │ 1 0 2 0 │      │ 0 0 1 1 │
+        // │ 0 0 0 3 │      │ 0 0 0 0 │
+        // │ 4 5 0 6 │      │ 1 0 0 1 │
+        // └         ┘      └         ┘
+        let a = CsrMatrix::new(
+            3,
+            4,
+            vec![0, 2, 3, 6],
+            vec![0, 2, 3, 0, 1, 3],
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+        )
+        .unwrap();
+        let b = CsrMatrix::new(3, 4, vec![0, 2, 2, 4], vec![2, 3, 0, 3], vec![1.0, 1.0, 1.0, 1.0]).unwrap();
+        let mut c = CsrMatrix::new(1, 1, vec![0, 1], vec![0], vec![9.0]).unwrap();
+        sp_mat_add(&mut c, 2.0, &a, -3.0, &b).unwrap();
+        assert_eq!((c.nrow(), c.ncol()), (3, 4));
+        assert_eq!(c.row_pointers(), &[0, 3, 4, 7]);
+        assert_eq!(c.col_indices(), &[0, 2, 3, 3, 0, 1, 3]);
+        let mut c_dense = Matrix::new(3, 4);
+        mat_add(&mut c_dense, 2.0, &a.as_matrix(), -3.0, &b.as_matrix()).unwrap();
+        mat_approx_eq(&c.as_matrix(), &c_dense, 1e-15);
+    }
+}
@@ -0,0 +1,206 @@
+use crate::{to_i32, CsrMatrix, Error};
+
+/// Computes the sparsity pattern of the product of two sparse matrices (CSR format)
+///
+/// ```text
+///   c  :=  a   ⋅   b
+/// (m,n)  (m,k)   (k,n)
+/// ```
+///
+/// This is the symbolic phase of Gustavson's algorithm. The arrays of `c` are replaced by the
+/// pattern of the product (with sorted column indices) and the values of `c` are set to zero.
+/// Afterwards, [sp_mat_mat_mul_numeric] may be called many times with matrices `a` and `b` having
+/// the same patterns but different values (e.g., in a nonlinear or time-stepping loop).
+///
+/// **Note:** Numerical cancellations are ignored; i.e., the pattern is structural.
+pub fn sp_mat_mat_mul_symbolic(c: &mut CsrMatrix, a: &CsrMatrix, b: &CsrMatrix) -> Result<(), Error> {
+    if a.ncol != b.nrow {
+        return Err(Error::BadDimensions("matrices are incompatible"));
+    }
+    let (m, n) = (a.nrow, b.ncol);
+    let mut row_pointers = vec![0_i32; m + 1];
+    let mut col_indices = Vec::new();
+    let mut marker = vec![usize::MAX; n]; // marker[j] == i if column j is already in row i
+    for i in 0..m {
+        let start = col_indices.len();
+        for pa in a.row_pointers[i] as usize..a.row_pointers[i + 1] as usize {
+            let l = a.col_indices[pa] as usize;
+            for pb in b.row_pointers[l] as usize..b.row_pointers[l + 1] as usize {
+                let j = b.col_indices[pb];
+                if marker[j as usize] != i {
+                    marker[j as usize] = i;
+                    col_indices.push(j);
+                }
+            }
+        }
+        col_indices[start..].sort_unstable();
+        row_pointers[i + 1] = to_i32(col_indices.len());
+    }
+    c.nrow = m;
+    c.ncol = n;
+    c.values = vec![0.0; col_indices.len()];
+    c.row_pointers = row_pointers;
+    c.col_indices = col_indices;
+    Ok(())
+}
+
+/// Computes the values of the product of two sparse matrices (CSR format)
+///
+/// ```text
+///   c  :=  α ⋅  a   ⋅   b
+/// (m,n)       (m,k)   (k,n)
+/// ```
+///
+/// This is the numeric phase of Gustavson's algorithm. The pattern of `c` must have been computed
+/// by [sp_mat_mat_mul_symbolic] (or must contain the pattern of the product); only the values
+/// of `c` are modified.
+pub fn sp_mat_mat_mul_numeric(c: &mut CsrMatrix, alpha: f64, a: &CsrMatrix, b: &CsrMatrix) -> Result<(), Error> {
+    if a.ncol != b.nrow || c.nrow != a.nrow || c.ncol != b.ncol {
+        return Err(Error::BadDimensions("matrices are incompatible"));
+    }
+    let mut position = vec![usize::MAX; c.ncol]; // position[j] = index of (i,j) in c.values
+    for i in 0..c.nrow {
+        let (start, end) = (c.row_pointers[i] as usize, c.row_pointers[i + 1] as usize);
+        for p in start..end {
+            position[c.col_indices[p] as usize] = p;
+            c.values[p] = 0.0;
+        }
+        for pa in a.row_pointers[i] as usize..a.row_pointers[i + 1] as usize {
+            let l = a.col_indices[pa] as usize;
+            let alpha_ail = alpha * a.values[pa];
+            for pb in b.row_pointers[l] as usize..b.row_pointers[l + 1] as usize {
+                let p = position[b.col_indices[pb] as usize];
+                if p < start || p >= end {
+                    return Err(Error::InvalidMatrix(
+                        "the sparsity pattern of c does not contain the pattern of the product",
+                    ));
+                }
+                c.values[p] += alpha_ail * b.values[pb];
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Performs the multiplication of two sparse matrices (CSR format)
+///
+/// ```text
+///   c  :=  α ⋅  a   ⋅   b
+/// (m,n)       (m,k)   (k,n)
+/// ```
+///
+/// This function calls [sp_mat_mat_mul_symbolic] followed by [sp_mat_mat_mul_numeric] (Gustavson's
+/// algorithm). The arrays of `c` are replaced.
+///
+/// # Example
+///
+/// ```
+/// use russell_sparse::{sp_mat_mat_mul, CsrMatrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // ┌     ┐   ┌     ┐   ┌     ┐
+///     // │ 1 2 │   │ 0 1 │   │ 2 1 │
+///     // │ 0 3 │ ⋅ │ 1 0 │ = │ 3 0 │
+///     // └     ┘   └     ┘   └     ┘
+///     let a = CsrMatrix::new(2, 2, vec![0, 2, 3], vec![0, 1, 1], vec![1.0, 2.0, 3.0])?;
+///     let b = CsrMatrix::new(2, 2, vec![0, 1, 2], vec![1, 0], vec![1.0, 1.0])?;
+///     let mut c = CsrMatrix::new(0, 0, vec![0], vec![], vec![])?;
+///     sp_mat_mat_mul(&mut c, 1.0, &a, &b)?;
+///     assert_eq!(c.row_pointers(), &[0, 2, 3]);
+///     assert_eq!(c.col_indices(), &[0, 1, 0]);
+///     assert_eq!(c.values(), &[2.0, 1.0, 3.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn sp_mat_mat_mul(c: &mut CsrMatrix, alpha: f64, a: &CsrMatrix, b: &CsrMatrix) -> Result<(), Error> {
+    sp_mat_mat_mul_symbolic(c, a, b)?;
+    sp_mat_mat_mul_numeric(c, alpha, a, b)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{sp_mat_mat_mul, sp_mat_mat_mul_numeric, sp_mat_mat_mul_symbolic};
+    use crate::{CsrMatrix, Error};
+    use russell_lab::{mat_approx_eq, mat_mat_mul, Matrix};
+
+    fn empty() -> CsrMatrix {
+        CsrMatrix::new(0, 0, vec![0], vec![], vec![]).unwrap()
+    }
+
+    #[test]
+    fn sp_mat_mat_mul_fails_on_wrong_dims() {
+        let a = CsrMatrix::new(2, 3, vec![0, 0, 0], vec![], vec![]).unwrap();
+        let b = CsrMatrix::new(2, 2, vec![0, 0, 0], vec![], vec![]).unwrap();
+        let mut c = empty();
+        assert_eq!(
+            sp_mat_mat_mul(&mut c, 1.0, &a, &b),
+            Err(Error::BadDimensions("matrices are incompatible"))
+        );
+        assert_eq!(
+            sp_mat_mat_mul_symbolic(&mut c, &a, &b),
+            Err(Error::BadDimensions("matrices are incompatible"))
+        );
+        assert_eq!(
+            sp_mat_mat_mul_numeric(&mut c, 1.0, &b, &b),
+            Err(Error::BadDimensions("matrices are incompatible"))
+        );
+    }
+
+    #[test]
+    fn sp_mat_mat_mul_works() {
+        // ┌         ┐
+        // │ 1 0 2 0 │
+        // │ 0 0 0 3 │
+        // │ 4 5 0 6 │
+        // └         ┘
+        let a = CsrMatrix::new(
+            3,
+            4,
+            vec![0, 2, 3, 6],
+            vec![0, 2, 3, 0, 1, 3],
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+        )
+        .unwrap();
+        // ┌       ┐
+        // │ 1 0 0 │
+        // │ 0 0 1 │
+        // │ 2 1 0 │
+        // │ 0 1 1 │
+        // └       ┘
+        let b = CsrMatrix::new(
+            4,
+            3,
+            vec![0, 1, 2, 4, 6],
+            vec![0, 2, 0, 1, 1, 2],
+            vec![1.0, 1.0, 2.0, 1.0, 1.0, 1.0],
+        )
+        .unwrap();
+        let mut c = empty();
+        sp_mat_mat_mul(&mut c, 2.0, &a, &b).unwrap();
+        assert_eq!((c.nrow(), c.ncol()), (3, 3));
+        assert_eq!(c.row_pointers(), &[0, 2, 4, 7]);
+        assert_eq!(c.col_indices(), &[0, 1, 1, 2, 0, 1, 2]);
+        let mut c_dense = Matrix::new(3, 3);
+        mat_mat_mul(&mut c_dense, 2.0, &a.as_matrix(), &b.as_matrix()).unwrap();
+        mat_approx_eq(&c.as_matrix(), &c_dense, 1e-15);
+
+        // numeric phase only (reusing the pattern)
+        sp_mat_mat_mul_numeric(&mut c, -1.0, &a, &b).unwrap();
+        mat_mat_mul(&mut c_dense, -1.0, &a.as_matrix(), &b.as_matrix()).unwrap();
+        mat_approx_eq(&c.as_matrix(), &c_dense, 1e-15);
+    }
+
+    #[test]
+    fn sp_mat_mat_mul_numeric_captures_wrong_pattern() {
+        let a = CsrMatrix::new(2, 2, vec![0, 2, 3], vec![0, 1, 1], vec![1.0, 2.0, 3.0]).unwrap();
+        let mut c = CsrMatrix::new(2, 2, vec![0, 1, 2], vec![0, 1], vec![0.0, 0.0]).unwrap();
+        assert_eq!(
+            sp_mat_mat_mul_numeric(&mut c, 1.0, &a, &a),
+            Err(Error::InvalidMatrix(
+                "the sparsity pattern of c does not contain the pattern of the product"
+            ))
+        );
+    }
+}
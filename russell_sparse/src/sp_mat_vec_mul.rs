@@ -0,0 +1,273 @@
+use crate::{CscMatrix, CsrMatrix, Error, SparseTriplet};
+use russell_lab::Vector;
+
+/// Defines sparse matrices that can be multiplied by a vector
+///
+/// This trait is implemented by [CsrMatrix], [CscMatrix], and [SparseTriplet] (COO format)
+/// and allows [sp_mat_vec_mul] to accept any of these formats.
+pub trait SpMatVecMul {
+    /// Returns the dimensions (nrow, ncol)
+    fn sp_dims(&self) -> (usize, usize);
+
+    /// Performs `v += α⋅a⋅u` without checking the dimensions
+    fn sp_mat_vec_mul_add(&self, v: &mut Vector, alpha: f64, u: &Vector);
+}
+
+impl SpMatVecMul for CsrMatrix {
+    fn sp_dims(&self) -> (usize, usize) {
+        (self.nrow, self.ncol)
+    }
+
+    fn sp_mat_vec_mul_add(&self, v: &mut Vector, alpha: f64, u: &Vector) {
+        for i in 0..self.nrow {
+            let (start, end) = (self.row_pointers[i] as usize, self.row_pointers[i + 1] as usize);
+            let mut sum = 0.0;
+            for p in start..end {
+                sum += self.values[p] * u[self.col_indices[p] as usize];
+            }
+            v[i] += alpha * sum;
+        }
+    }
+}
+
+impl SpMatVecMul for CscMatrix {
+    fn sp_dims(&self) -> (usize, usize) {
+        (self.nrow, self.ncol)
+    }
+
+    fn sp_mat_vec_mul_add(&self, v: &mut Vector, alpha: f64, u: &Vector) {
+        for j in 0..self.ncol {
+            let (start, end) = (self.col_pointers[j] as usize, self.col_pointers[j + 1] as usize);
+            let alpha_uj = alpha * u[j];
+            for p in start..end {
+                v[self.row_indices[p] as usize] += self.values[p] * alpha_uj;
+            }
+        }
+    }
+}
+
+impl SpMatVecMul for SparseTriplet {
+    fn sp_dims(&self) -> (usize, usize) {
+        (self.neq, self.neq)
+    }
+
+    fn sp_mat_vec_mul_add(&self, v: &mut Vector, alpha: f64, u: &Vector) {
+        for p in 0..self.pos {
+            let i = self.indices_i[p] as usize;
+            let j = self.indices_j[p] as usize;
+            v[i] += alpha * self.values_aij[p] * u[j];
+        }
+    }
+}
+
+/// Performs the sparse matrix-vector multiplication
+///
+/// ```text
+///  v  :=  α ⋅  a   ⋅  u
+/// (m)        (m,n)   (n)
+/// ```
+///
+/// The matrix `a` may be given in the CSR, CSC, or COO (triplet) format. Repeated (i,j) entries
+/// of a triplet are summed. All entries of the triplet are considered; i.e., symmetric matrices
+/// must be stored in full (see [SparseTriplet::mat_vec_mul] for the triangular case).
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::Vector;
+/// use russell_sparse::{sp_mat_vec_mul, CsrMatrix, SparseTriplet, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // ┌       ┐
+///     // │ 2 1 0 │
+///     // │ 1 2 1 │
+///     // │ 0 1 2 │
+///     // └       ┘
+///     let mut trip = SparseTriplet::new(3, 7)?;
+///     trip.put(0, 0, 2.0)?;
+///     trip.put(0, 1, 1.0)?;
+///     trip.put(1, 0, 1.0)?;
+///     trip.put(1, 1, 2.0)?;
+///     trip.put(1, 2, 1.0)?;
+///     trip.put(2, 1, 1.0)?;
+///     trip.put(2, 2, 2.0)?;
+///     let csr = CsrMatrix::from_triplet(&trip)?;
+///
+///     // residual r = b - a⋅x
+///     let x = Vector::from(&[1.0, 1.0, 1.0]);
+///     let b = Vector::from(&[3.0, 4.0, 5.0]);
+///     let mut r = Vector::new(3);
+///     sp_mat_vec_mul(&mut r, -1.0, &csr, &x)?;
+///     for i in 0..3 {
+///         r[i] += b[i];
+///     }
+///     assert_eq!(r.as_data(), &[0.0, 0.0, 2.0]);
+///
+///     // the triplet may be used directly
+///     let mut v = Vector::new(3);
+///     sp_mat_vec_mul(&mut v, 1.0, &trip, &x)?;
+///     assert_eq!(v.as_data(), &[3.0, 4.0, 3.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn sp_mat_vec_mul<M>(v: &mut Vector, alpha: f64, a: &M, u: &Vector) -> Result<(), Error>
+where
+    M: SpMatVecMul,
+{
+    let (m, n) = a.sp_dims();
+    if v.dim() != m || u.dim() != n {
+        return Err(Error::BadDimensions("matrix and vectors are incompatible"));
+    }
+    v.fill(0.0);
+    a.sp_mat_vec_mul_add(v, alpha, u);
+    Ok(())
+}
+
+/// Performs the sparse matrix-vector multiplication in parallel (CSR format)
+///
+/// ```text
+///  v  :=  α ⋅  a   ⋅  u
+/// (m)        (m,n)   (n)
+/// ```
+///
+/// The rows of `a` are split among the threads of the rayon thread pool. Only the CSR format
+/// is supported because each component of `v` is then computed independently.
+///
+/// **Note:** This function is only available with the `rayon` feature.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::Vector;
+/// use russell_sparse::{sp_mat_vec_mul_par, CsrMatrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // ┌     ┐
+///     // │ 1 2 │
+///     // │ 0 3 │
+///     // └     ┘
+///     let a = CsrMatrix::new(2, 2, vec![0, 2, 3], vec![0, 1, 1], vec![1.0, 2.0, 3.0])?;
+///     let u = Vector::from(&[1.0, 1.0]);
+///     let mut v = Vector::new(2);
+///     sp_mat_vec_mul_par(&mut v, 2.0, &a, &u)?;
+///     assert_eq!(v.as_data(), &[6.0, 6.0]);
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "rayon")]
+pub fn sp_mat_vec_mul_par(v: &mut Vector, alpha: f64, a: &CsrMatrix, u: &Vector) -> Result<(), Error> {
+    use rayon::prelude::*;
+    if v.dim() != a.nrow || u.dim() != a.ncol {
+        return Err(Error::BadDimensions("matrix and vectors are incompatible"));
+    }
+    v.as_mut_data().par_iter_mut().enumerate().for_each(|(i, vi)| {
+        let (start, end) = (a.row_pointers[i] as usize, a.row_pointers[i + 1] as usize);
+        let mut sum = 0.0;
+        for p in start..end {
+            sum += a.values[p] * u[a.col_indices[p] as usize];
+        }
+        *vi = alpha * sum;
+    });
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::sp_mat_vec_mul;
+    use crate::{CscMatrix, CsrMatrix, Error, SparseTriplet};
+    use russell_chk::vec_approx_eq;
+    use russell_lab::{mat_vec_mul, Vector};
+
+    // returns a 3 x 4 matrix (as CSR and CSC) and the 4 x 4 triplet with repeated entries
+    fn get_matrices() -> (CsrMatrix, CscMatrix, SparseTriplet) {
+        // ┌           ┐
+        // │ 1 0 2 0   │
+        // │ 0 0 0 3   │
+        // │ 4 5 0 6   │
+        // └           ┘
+        let csr = CsrMatrix::new(
+            3,
+            4,
+            vec![0, 2, 3, 6],
+            vec![0, 2, 3, 0, 1, 3],
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+        )
+        .unwrap();
+        let csc = CscMatrix::new(
+            3,
+            4,
+            vec![0, 2, 3, 4, 6],
+            vec![0, 2, 2, 0, 1, 2],
+            vec![1.0, 4.0, 5.0, 2.0, 3.0, 6.0],
+        )
+        .unwrap();
+        let mut trip = SparseTriplet::new(4, 8).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(0, 2, 2.0).unwrap();
+        trip.put(1, 3, 1.0).unwrap();
+        trip.put(1, 3, 2.0).unwrap(); // repeated
+        trip.put(2, 0, 4.0).unwrap();
+        trip.put(2, 1, 5.0).unwrap();
+        trip.put(2, 3, 6.0).unwrap();
+        trip.put(3, 3, -1.0).unwrap();
+        (csr, csc, trip)
+    }
+
+    #[test]
+    fn sp_mat_vec_mul_fails_on_wrong_dims() {
+        let (csr, csc, trip) = get_matrices();
+        let u = Vector::new(4);
+        let mut v = Vector::new(4);
+        assert_eq!(
+            sp_mat_vec_mul(&mut v, 1.0, &csr, &u).err(),
+            Some(Error::BadDimensions("matrix and vectors are incompatible"))
+        );
+        assert_eq!(
+            sp_mat_vec_mul(&mut v, 1.0, &csc, &u).err(),
+            Some(Error::BadDimensions("matrix and vectors are incompatible"))
+        );
+        let mut v = Vector::new(3);
+        assert_eq!(
+            sp_mat_vec_mul(&mut v, 1.0, &trip, &u).err(),
+            Some(Error::BadDimensions("matrix and vectors are incompatible"))
+        );
+    }
+
+    #[test]
+    fn sp_mat_vec_mul_works() {
+        let (csr, csc, trip) = get_matrices();
+        let u = Vector::from(&[1.0, 2.0, 3.0, 4.0]);
+        let correct = &[14.0, 24.0, 76.0];
+        let mut v = Vector::filled(3, 123.0);
+        sp_mat_vec_mul(&mut v, 2.0, &csr, &u).unwrap();
+        vec_approx_eq(v.as_data(), correct, 1e-15);
+        let mut v = Vector::filled(3, 123.0);
+        sp_mat_vec_mul(&mut v, 2.0, &csc, &u).unwrap();
+        vec_approx_eq(v.as_data(), correct, 1e-15);
+
+        // compare with the dense version
+        let mut v = Vector::new(4);
+        let mut v_dense = Vector::new(4);
+        sp_mat_vec_mul(&mut v, 0.5, &trip, &u).unwrap();
+        mat_vec_mul(&mut v_dense, 0.5, &trip.as_matrix(), &u).unwrap();
+        vec_approx_eq(v.as_data(), v_dense.as_data(), 1e-15);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn sp_mat_vec_mul_par_works() {
+        use super::sp_mat_vec_mul_par;
+        let (csr, _, _) = get_matrices();
+        let u = Vector::from(&[1.0, 2.0, 3.0, 4.0]);
+        let mut v = Vector::filled(3, 123.0);
+        sp_mat_vec_mul_par(&mut v, 2.0, &csr, &u).unwrap();
+        vec_approx_eq(v.as_data(), &[14.0, 24.0, 76.0], 1e-15);
+        let mut v = Vector::new(4);
+        assert_eq!(
+            sp_mat_vec_mul_par(&mut v, 1.0, &csr, &u).err(),
+            Some(Error::BadDimensions("matrix and vectors are incompatible"))
+        );
+    }
+}
@@ -0,0 +1,397 @@
+use crate::{CsrMatrix, Error};
+use russell_lab::Vector;
+use std::collections::BTreeSet;
+
+/// Implements a sparse QR factorization to solve least-squares problems
+///
+/// For a rectangular sparse matrix `a` (m,n), find `x` that minimizes:
+///
+/// ```text
+/// ‖a⋅x - b‖₂
+/// ```
+///
+/// The upper triangular factor R of `a = Q⋅R` is computed row by row with Givens rotations
+/// (George-Heath algorithm); thus, Q is never formed and the fill-in is restricted to R.
+/// The solution is computed by the corrected semi-normal equations `Rᵀ⋅R⋅x = aᵀ⋅b` followed by
+/// one step of iterative refinement, which makes the accuracy comparable to the one obtained
+/// with Q. Therefore, [SparseQr::solve] may be called many times with different right-hand sides.
+///
+/// # Rank deficiency
+///
+/// A column `k` is considered (numerically) dependent on the previous ones if
+/// `|R[k][k]| ≤ tolerance ⋅ max(|R[j][j]|)`. The dependent columns are removed and the
+/// factorization is recomputed. Then, the corresponding components of `x` are set to zero
+/// (basic solution) and the rank equals `n` minus the number of dropped columns.
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::vec_approx_eq;
+/// use russell_lab::Vector;
+/// use russell_sparse::{CsrMatrix, SparseQr, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // fit the line y = c0 + c1⋅t to the points (0,1), (1,2), (2,4)
+///     // ┌     ┐           ┌   ┐
+///     // │ 1 0 │           │ 1 │
+///     // │ 1 1 │ ⋅ [c0 c1] │ 2 │
+///     // │ 1 2 │           │ 4 │
+///     // └     ┘           └   ┘
+///     let a = CsrMatrix::new(3, 2, vec![0, 1, 3, 5], vec![0, 0, 1, 0, 1], vec![1.0, 1.0, 1.0, 1.0, 2.0])?;
+///     let qr = SparseQr::new(&a, 1e-12)?;
+///     assert_eq!(qr.rank(), 2);
+///     let b = Vector::from(&[1.0, 2.0, 4.0]);
+///     let mut x = Vector::new(2);
+///     qr.solve(&mut x, &b)?;
+///     vec_approx_eq(x.as_data(), &[5.0 / 6.0, 1.5], 1e-14);
+///     Ok(())
+/// }
+/// ```
+pub struct SparseQr {
+    a: CsrMatrix,                   // copy of the matrix (needed for the iterative refinement)
+    r_rows: Vec<Vec<(usize, f64)>>, // [n] rows of R with sorted column indices (the diagonal comes first)
+    dropped: Vec<usize>,            // dropped (dependent) columns
+}
+
+impl SparseQr {
+    /// Computes the factorization
+    ///
+    /// # Input
+    ///
+    /// * `a` -- (m,n) matrix (any m and n)
+    /// * `tolerance` -- relative tolerance to detect dependent columns (e.g., 1e-12)
+    pub fn new(a: &CsrMatrix, tolerance: f64) -> Result<Self, Error> {
+        if tolerance < 0.0 {
+            return Err(Error::InvalidArgument("the tolerance must be non-negative"));
+        }
+        let n = a.ncol;
+        let mut is_dropped = vec![false; n];
+        let mut r_rows = factorize(a, &is_dropped);
+
+        // detect the dependent columns
+        let max_diag = r_rows
+            .iter()
+            .filter_map(|row| row.first().map(|(_, rkk)| f64::abs(*rkk)))
+            .fold(0.0, f64::max);
+        let mut dropped = Vec::new();
+        for k in 0..n {
+            let diag = match r_rows[k].first() {
+                Some((j, rkk)) if *j == k => f64::abs(*rkk),
+                _ => 0.0,
+            };
+            if diag == 0.0 || diag <= tolerance * max_diag {
+                is_dropped[k] = true;
+                dropped.push(k);
+            }
+        }
+        if !dropped.is_empty() {
+            r_rows = factorize(a, &is_dropped);
+        }
+        Ok(SparseQr {
+            a: a.clone(),
+            r_rows,
+            dropped,
+        })
+    }
+
+    /// Returns the (numerical) rank of the matrix
+    pub fn rank(&self) -> usize {
+        self.a.ncol - self.dropped.len()
+    }
+
+    /// Returns the indices of the dropped (dependent) columns
+    pub fn dropped_columns(&self) -> &[usize] {
+        &self.dropped
+    }
+
+    /// Computes the least-squares solution
+    ///
+    /// # Input
+    ///
+    /// * `x` -- (n) the solution vector (the components of the dropped columns are set to zero)
+    /// * `b` -- (m) the right-hand side vector
+    pub fn solve(&self, x: &mut Vector, b: &Vector) -> Result<(), Error> {
+        let (m, n) = (self.a.nrow, self.a.ncol);
+        if x.dim() != n || b.dim() != m {
+            return Err(Error::BadDimensions(
+                "x.dim() must equal ncol and b.dim() must equal nrow",
+            ));
+        }
+
+        // semi-normal equations: Rᵀ⋅R⋅x = aᵀ⋅b
+        let mut y = vec![0.0; n];
+        self.tr_mat_vec_mul(&mut y, b.as_data());
+        self.solve_semi_normal(&mut y);
+        x.as_mut_data().copy_from_slice(&y);
+
+        // refinement: Rᵀ⋅R⋅dx = aᵀ⋅(b - a⋅x)
+        let mut r = b.as_data().to_vec();
+        for (i, ri) in r.iter_mut().enumerate() {
+            for p in (self.a.row_pointers[i] as usize)..(self.a.row_pointers[i + 1] as usize) {
+                *ri -= self.a.values[p] * x[self.a.col_indices[p] as usize];
+            }
+        }
+        self.tr_mat_vec_mul(&mut y, &r);
+        self.solve_semi_normal(&mut y);
+        for k in 0..n {
+            x[k] += y[k];
+        }
+        Ok(())
+    }
+
+    /// Computes y = aᵀ⋅u with zero components for the dropped columns
+    fn tr_mat_vec_mul(&self, y: &mut [f64], u: &[f64]) {
+        y.fill(0.0);
+        for (i, ui) in u.iter().enumerate() {
+            for p in (self.a.row_pointers[i] as usize)..(self.a.row_pointers[i + 1] as usize) {
+                y[self.a.col_indices[p] as usize] += self.a.values[p] * ui;
+            }
+        }
+        for k in &self.dropped {
+            y[*k] = 0.0;
+        }
+    }
+
+    /// Solves Rᵀ⋅R⋅x = y in place (the rows of R for the dropped columns are empty)
+    fn solve_semi_normal(&self, y: &mut [f64]) {
+        let n = self.a.ncol;
+        // Rᵀ⋅z = y (column-oriented)
+        for k in 0..n {
+            if let Some((_, rkk)) = self.r_rows[k].first() {
+                y[k] /= rkk;
+                for (j, rkj) in &self.r_rows[k][1..] {
+                    y[*j] -= rkj * y[k];
+                }
+            } else {
+                y[k] = 0.0;
+            }
+        }
+        // R⋅x = z
+        for k in (0..n).rev() {
+            if let Some((_, rkk)) = self.r_rows[k].first() {
+                let mut sum = y[k];
+                for (j, rkj) in &self.r_rows[k][1..] {
+                    sum -= rkj * y[*j];
+                }
+                y[k] = sum / rkk;
+            }
+        }
+    }
+}
+
+/// Computes the rows of R by Givens rotations, ignoring the dropped columns
+fn factorize(a: &CsrMatrix, is_dropped: &[bool]) -> Vec<Vec<(usize, f64)>> {
+    let n = a.ncol;
+    let mut r_rows: Vec<Vec<(usize, f64)>> = vec![Vec::new(); n];
+    let mut w = vec![0.0; n]; // the current row being rotated into R
+    let mut w_pattern = BTreeSet::new(); // the non-zero columns of w
+    let mut in_r_row = vec![false; n]; // marks the pattern of the current row of R
+    for i in 0..a.nrow {
+        // load the row of a
+        for p in (a.row_pointers[i] as usize)..(a.row_pointers[i + 1] as usize) {
+            let j = a.col_indices[p] as usize;
+            if !is_dropped[j] && a.values[p] != 0.0 {
+                w[j] = a.values[p];
+                w_pattern.insert(j);
+            }
+        }
+
+        // eliminate the leading entry of w with the corresponding row of R
+        while let Some(k) = w_pattern.pop_first() {
+            if w[k] == 0.0 {
+                continue;
+            }
+            if r_rows[k].is_empty() {
+                // the row becomes a new row of R
+                r_rows[k].push((k, w[k]));
+                w[k] = 0.0;
+                for j in std::mem::take(&mut w_pattern) {
+                    if w[j] != 0.0 {
+                        r_rows[k].push((j, w[j]));
+                        w[j] = 0.0;
+                    }
+                }
+                break;
+            }
+            let rkk = r_rows[k][0].1;
+            let rho = f64::hypot(rkk, w[k]);
+            let (c, s) = (rkk / rho, w[k] / rho);
+
+            // rotate the entries of the row of R (and the corresponding entries of w)
+            for (j, rkj) in r_rows[k].iter_mut() {
+                in_r_row[*j] = true;
+                let (rj, wj) = (*rkj, w[*j]);
+                *rkj = c * rj + s * wj;
+                w[*j] = c * wj - s * rj;
+                if *j != k && w[*j] != 0.0 {
+                    w_pattern.insert(*j);
+                }
+            }
+            r_rows[k][0].1 = rho;
+            w[k] = 0.0;
+
+            // fill-in: entries of w that are not in the row of R
+            let mut fill = Vec::new();
+            for j in w_pattern.iter().copied() {
+                if !in_r_row[j] && w[j] != 0.0 {
+                    fill.push((j, s * w[j]));
+                    w[j] *= c;
+                }
+            }
+            for (j, _) in r_rows[k].iter() {
+                in_r_row[*j] = false;
+            }
+            if !fill.is_empty() {
+                r_rows[k].extend(fill);
+                r_rows[k].sort_unstable_by_key(|(j, _)| *j);
+            }
+        }
+    }
+    r_rows
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::SparseQr;
+    use crate::{CsrMatrix, Error};
+    use russell_chk::vec_approx_eq;
+    use russell_lab::Vector;
+
+    // returns aᵀ⋅(b - a⋅x), which must vanish at the least-squares solution
+    fn normal_residual(a: &CsrMatrix, x: &Vector, b: &Vector) -> Vec<f64> {
+        let mut r = b.as_data().to_vec();
+        for (i, j, aij) in a.iter() {
+            r[i] -= aij * x[j];
+        }
+        let mut res = vec![0.0; a.ncol()];
+        for (i, j, aij) in a.iter() {
+            res[j] += aij * r[i];
+        }
+        res
+    }
+
+    #[test]
+    fn new_captures_errors() {
+        let a = CsrMatrix::new(1, 1, vec![0, 1], vec![0], vec![1.0]).unwrap();
+        assert_eq!(
+            SparseQr::new(&a, -1.0).err(),
+            Some(Error::InvalidArgument("the tolerance must be non-negative"))
+        );
+    }
+
+    #[test]
+    fn solve_captures_errors() {
+        let a = CsrMatrix::new(2, 1, vec![0, 1, 2], vec![0, 0], vec![1.0, 1.0]).unwrap();
+        let qr = SparseQr::new(&a, 1e-12).unwrap();
+        let mut x = Vector::new(1);
+        let b = Vector::new(1);
+        assert_eq!(
+            qr.solve(&mut x, &b).err(),
+            Some(Error::BadDimensions(
+                "x.dim() must equal ncol and b.dim() must equal nrow"
+            ))
+        );
+        let mut x = Vector::new(2);
+        let b = Vector::new(2);
+        assert_eq!(
+            qr.solve(&mut x, &b).err(),
+            Some(Error::BadDimensions(
+                "x.dim() must equal ncol and b.dim() must equal nrow"
+            ))
+        );
+    }
+
+    #[test]
+    fn solve_works_square() {
+        //  2  3  .  .  .
+        //  3  .  4  .  6
+        //  . -1 -3  2  .
+        //  .  .  1  .  .
+        //  .  4  2  .  1
+        let a = CsrMatrix::new(
+            5,
+            5,
+            vec![0, 2, 5, 8, 9, 12],
+            vec![0, 1, 0, 2, 4, 1, 2, 3, 2, 1, 2, 4],
+            vec![2.0, 3.0, 3.0, 4.0, 6.0, -1.0, -3.0, 2.0, 1.0, 4.0, 2.0, 1.0],
+        )
+        .unwrap();
+        let qr = SparseQr::new(&a, 1e-12).unwrap();
+        assert_eq!(qr.rank(), 5);
+        assert_eq!(qr.dropped_columns().len(), 0);
+        let b = Vector::from(&[8.0, 45.0, -3.0, 3.0, 19.0]);
+        let mut x = Vector::new(5);
+        qr.solve(&mut x, &b).unwrap();
+        vec_approx_eq(x.as_data(), &[1.0, 2.0, 3.0, 4.0, 5.0], 1e-13);
+    }
+
+    #[test]
+    fn solve_works_overdetermined() {
+        //  1  .  2
+        //  .  3  .
+        //  4  .  .
+        //  .  5  6
+        //  7  .  8
+        //  .  .  9
+        let a = CsrMatrix::new(
+            6,
+            3,
+            vec![0, 2, 3, 4, 6, 8, 9],
+            vec![0, 2, 1, 0, 1, 2, 0, 2, 2],
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0],
+        )
+        .unwrap();
+        let qr = SparseQr::new(&a, 1e-12).unwrap();
+        assert_eq!(qr.rank(), 3);
+        let b = Vector::from(&[1.0, -2.0, 3.0, -4.0, 5.0, -6.0]);
+        let mut x = Vector::new(3);
+        qr.solve(&mut x, &b).unwrap();
+        vec_approx_eq(&normal_residual(&a, &x, &b), &[0.0, 0.0, 0.0], 1e-13);
+
+        // consistent system: the residual is zero
+        let b = Vector::from(&[7.0, 6.0, 4.0, 28.0, 31.0, 27.0]);
+        qr.solve(&mut x, &b).unwrap();
+        vec_approx_eq(x.as_data(), &[1.0, 2.0, 3.0], 1e-13);
+    }
+
+    #[test]
+    fn solve_works_rank_deficient() {
+        // the third column equals the first column plus the second column
+        //  1  .  1
+        //  1  1  2
+        //  .  1  1
+        //  2  .  2
+        let a = CsrMatrix::new(
+            4,
+            3,
+            vec![0, 2, 5, 7, 9],
+            vec![0, 2, 0, 1, 2, 1, 2, 0, 2],
+            vec![1.0, 1.0, 1.0, 1.0, 2.0, 1.0, 1.0, 2.0, 2.0],
+        )
+        .unwrap();
+        let qr = SparseQr::new(&a, 1e-12).unwrap();
+        assert_eq!(qr.rank(), 2);
+        assert_eq!(qr.dropped_columns(), &[2]);
+        let b = Vector::from(&[1.0, 2.0, 3.0, 4.0]);
+        let mut x = Vector::new(3);
+        qr.solve(&mut x, &b).unwrap();
+        assert_eq!(x[2], 0.0);
+        vec_approx_eq(&normal_residual(&a, &x, &b), &[0.0, 0.0, 0.0], 1e-13);
+    }
+
+    #[test]
+    fn solve_works_underdetermined() {
+        //  1  2  3
+        //  .  1  1
+        let a = CsrMatrix::new(2, 3, vec![0, 3, 5], vec![0, 1, 2, 1, 2], vec![1.0, 2.0, 3.0, 1.0, 1.0]).unwrap();
+        let qr = SparseQr::new(&a, 1e-12).unwrap();
+        assert_eq!(qr.rank(), 2);
+        assert_eq!(qr.dropped_columns(), &[2]);
+        let b = Vector::from(&[6.0, 2.0]);
+        let mut x = Vector::new(3);
+        qr.solve(&mut x, &b).unwrap();
+        vec_approx_eq(x.as_data(), &[2.0, 2.0, 0.0], 1e-14);
+    }
+}
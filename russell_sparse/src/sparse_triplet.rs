@@ -1,6 +1,5 @@
-use crate::StrError;
+use crate::{to_i32, Error};
 use russell_lab::{Matrix, Vector};
-use russell_openblas::to_i32;
 use std::fmt;
 
 /// Holds triples (i,j,aij) representing a sparse matrix
@@ -49,9 +48,9 @@ impl SparseTriplet {
     ///     Ok(())
     /// }
     /// ```
-    pub fn new(neq: usize, max: usize) -> Result<Self, StrError> {
+    pub fn new(neq: usize, max: usize) -> Result<Self, Error> {
         if neq == 0 || max == 0 {
-            return Err("neq and max must be greater than zero");
+            return Err(Error::InvalidArgument("neq and max must be greater than zero"));
         }
         Ok(SparseTriplet {
             neq,
@@ -80,15 +79,17 @@ impl SparseTriplet {
     ///     Ok(())
     /// }
     /// ```
-    pub fn put(&mut self, i: usize, j: usize, aij: f64) -> Result<(), StrError> {
+    pub fn put(&mut self, i: usize, j: usize, aij: f64) -> Result<(), Error> {
         if i >= self.neq {
-            return Err("sparse matrix row index is out of bounds");
+            return Err(Error::InvalidMatrix("sparse matrix row index is out of bounds"));
         }
         if j >= self.neq {
-            return Err("sparse matrix column index is out of bounds");
+            return Err(Error::InvalidMatrix("sparse matrix column index is out of bounds"));
         }
         if self.pos >= self.max {
-            return Err("current nnz (number of non-zeros) reached maximum limit");
+            return Err(Error::InvalidMatrix(
+                "current nnz (number of non-zeros) reached maximum limit",
+            ));
         }
         let i_i32 = to_i32(i);
         let j_i32 = to_i32(j);
@@ -268,10 +269,10 @@ impl SparseTriplet {
     ///     Ok(())
     /// }
     /// ```
-    pub fn to_matrix(&self, a: &mut Matrix) -> Result<(), StrError> {
+    pub fn to_matrix(&self, a: &mut Matrix) -> Result<(), Error> {
         let (m, n) = a.dims();
         if m > self.neq || n > self.neq {
-            return Err("wrong matrix dimensions");
+            return Err(Error::BadDimensions("wrong matrix dimensions"));
         }
         let m_i32 = to_i32(m);
         let n_i32 = to_i32(n);
@@ -343,9 +344,9 @@ impl SparseTriplet {
     ///     Ok(())
     /// }
     /// ```
-    pub fn mat_vec_mul(&self, u: &Vector, triangular: bool) -> Result<Vector, StrError> {
+    pub fn mat_vec_mul(&self, u: &Vector, triangular: bool) -> Result<Vector, Error> {
         if u.dim() != self.neq {
-            return Err("u.ndim must equal neq");
+            return Err(Error::BadDimensions("u.ndim must equal neq"));
         }
         let mut v = Vector::new(self.neq);
         for p in 0..self.pos {
@@ -380,6 +381,7 @@ impl fmt::Display for SparseTriplet {
 #[cfg(test)]
 mod tests {
     use super::SparseTriplet;
+    use crate::Error;
     use russell_chk::vec_approx_eq;
     use russell_lab::{Matrix, Vector};
 
@@ -387,11 +389,11 @@ mod tests {
     fn new_fails_on_wrong_input() {
         assert_eq!(
             SparseTriplet::new(0, 3).err(),
-            Some("neq and max must be greater than zero")
+            Some(Error::InvalidArgument("neq and max must be greater than zero"))
         );
         assert_eq!(
             SparseTriplet::new(3, 0).err(),
-            Some("neq and max must be greater than zero")
+            Some(Error::InvalidArgument("neq and max must be greater than zero"))
         );
     }
 
@@ -408,16 +410,18 @@ mod tests {
         let mut trip = SparseTriplet::new(1, 1).unwrap();
         assert_eq!(
             trip.put(1, 0, 0.0).err(),
-            Some("sparse matrix row index is out of bounds")
+            Some(Error::InvalidMatrix("sparse matrix row index is out of bounds"))
         );
         assert_eq!(
             trip.put(0, 1, 0.0).err(),
-            Some("sparse matrix column index is out of bounds")
+            Some(Error::InvalidMatrix("sparse matrix column index is out of bounds"))
         );
         assert_eq!(trip.put(0, 0, 0.0).err(), None); // << will tak all spots
         assert_eq!(
             trip.put(0, 0, 0.0).err(),
-            Some("current nnz (number of non-zeros) reached maximum limit")
+            Some(Error::InvalidMatrix(
+                "current nnz (number of non-zeros) reached maximum limit"
+            ))
         );
     }
 
@@ -456,8 +460,14 @@ mod tests {
         let trip = SparseTriplet::new(1, 1).unwrap();
         let mut a_2x1 = Matrix::new(2, 1);
         let mut a_1x2 = Matrix::new(1, 2);
-        assert_eq!(trip.to_matrix(&mut a_2x1), Err("wrong matrix dimensions"));
-        assert_eq!(trip.to_matrix(&mut a_1x2), Err("wrong matrix dimensions"));
+        assert_eq!(
+            trip.to_matrix(&mut a_2x1),
+            Err(Error::BadDimensions("wrong matrix dimensions"))
+        );
+        assert_eq!(
+            trip.to_matrix(&mut a_1x2),
+            Err(Error::BadDimensions("wrong matrix dimensions"))
+        );
     }
 
     #[test]
@@ -521,7 +531,10 @@ mod tests {
     fn mat_vec_mul_fails_on_wrong_input() {
         let trip = SparseTriplet::new(2, 1).unwrap();
         let u = Vector::new(3);
-        assert_eq!(trip.mat_vec_mul(&u, false).err(), Some("u.ndim must equal neq"));
+        assert_eq!(
+            trip.mat_vec_mul(&u, false).err(),
+            Some(Error::BadDimensions("u.ndim must equal neq"))
+        );
     }
 
     #[test]
@@ -0,0 +1,29 @@
+use std::convert::TryFrom;
+
+/// Converts number to i32
+///
+/// MUMPS and UMFPACK use 32-bit integers regardless of the integer type of the
+/// BLAS/LAPACK interface (see `russell_openblas::BlasInt`); thus, this function
+/// is used instead of `russell_openblas::to_i32` when calling the solvers.
+#[inline]
+pub(crate) fn to_i32(num: usize) -> i32 {
+    i32::try_from(num).unwrap()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::to_i32;
+
+    #[test]
+    fn to_i32_works() {
+        assert_eq!(to_i32(2), 2_i32);
+    }
+
+    #[test]
+    #[should_panic]
+    fn to_i32_panics_on_overflow() {
+        to_i32(usize::MAX);
+    }
+}
@@ -1,5 +1,5 @@
 use super::SparseTriplet;
-use crate::StrError;
+use crate::Error;
 use russell_lab::{format_nanoseconds, vec_norm, vec_update, Norm, Stopwatch, Vector};
 use russell_openblas::{idamax, to_i32};
 use std::fmt;
@@ -57,9 +57,9 @@ impl VerifyLinSys {
     ///     Ok(())
     /// }
     /// ```
-    pub fn new(trip: &SparseTriplet, x: &Vector, rhs: &Vector, triangular: bool) -> Result<Self, StrError> {
+    pub fn new(trip: &SparseTriplet, x: &Vector, rhs: &Vector, triangular: bool) -> Result<Self, Error> {
         if x.dim() != trip.neq || rhs.dim() != trip.neq {
-            return Err("vector dimensions are incompatible");
+            return Err(Error::BadDimensions("vector dimensions are incompatible"));
         }
         // start stopwatch
         let mut sw = Stopwatch::new("");
@@ -121,6 +121,7 @@ impl fmt::Display for VerifyLinSys {
 #[cfg(test)]
 mod tests {
     use super::{SparseTriplet, VerifyLinSys};
+    use crate::Error;
     use russell_lab::Vector;
 
     #[test]
@@ -132,11 +133,11 @@ mod tests {
         let rhs_wrong = Vector::new(2);
         assert_eq!(
             VerifyLinSys::new(&trip, &x_wrong, &rhs, false).err(),
-            Some("vector dimensions are incompatible")
+            Some(Error::BadDimensions("vector dimensions are incompatible"))
         );
         assert_eq!(
             VerifyLinSys::new(&trip, &x, &rhs_wrong, false).err(),
-            Some("vector dimensions are incompatible")
+            Some(Error::BadDimensions("vector dimensions are incompatible"))
         );
     }
 
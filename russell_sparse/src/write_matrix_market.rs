@@ -0,0 +1,205 @@
+use crate::{CsrMatrix, Error, SparseTriplet};
+use russell_lab::Vector;
+use std::ffi::OsStr;
+use std::fmt::Write;
+use std::fs::{self, File};
+use std::io::Write as IoWrite;
+use std::path::Path;
+
+/// Creates the directory (if needed) and writes the buffer to a file
+fn write_file<P>(full_path: &P, buffer: &str) -> Result<(), Error>
+where
+    P: AsRef<OsStr> + ?Sized,
+{
+    // create directory
+    let path = Path::new(full_path);
+    if let Some(p) = path.parent() {
+        fs::create_dir_all(p).map_err(|_| Error::MatrixMarket("cannot create directory"))?;
+    }
+
+    // write data to file
+    let mut file = File::create(path).map_err(|_| Error::MatrixMarket("cannot create file"))?;
+    file.write_all(buffer.as_bytes())
+        .map_err(|_| Error::MatrixMarket("cannot write file"))?;
+
+    // force sync
+    file.sync_all().map_err(|_| Error::MatrixMarket("cannot sync file"))?;
+    Ok(())
+}
+
+impl CsrMatrix {
+    /// Writes a MatrixMarket file (coordinate format)
+    ///
+    /// # Input
+    ///
+    /// * `full_path` -- may be a String, &str, or Path (the `.mtx` extension is recommended)
+    /// * `symmetric` -- writes the `symmetric` header and only the lower triangular part (i ≥ j) of the matrix.
+    ///   The upper triangular entries are ignored; thus, the matrix must be symmetric (this is not checked).
+    ///
+    /// The indices are written as one-based and the values are written with all digits needed
+    /// to recover the same f64 numbers. The file can be read by [crate::read_matrix_market] and by
+    /// other packages such as SciPy, MATLAB, or Julia.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_sparse::{CsrMatrix, StrError};
+    /// use std::fs;
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     // ┌     ┐
+    ///     // │ 2 1 │
+    ///     // │ 1 3 │
+    ///     // └     ┘
+    ///     let a = CsrMatrix::new(2, 2, vec![0, 2, 4], vec![0, 1, 0, 1], vec![2.0, 1.0, 1.0, 3.0])?;
+    ///     let path = "/tmp/russell_sparse/doc_csr_to_matrix_market.mtx";
+    ///     a.to_matrix_market(path, true)?;
+    ///     let contents = fs::read_to_string(path).map_err(|_| "cannot open file")?;
+    ///     assert_eq!(
+    ///         contents,
+    ///         "%%MatrixMarket matrix coordinate real symmetric\n\
+    ///          2 2 3\n\
+    ///          1 1 2.0\n\
+    ///          2 1 1.0\n\
+    ///          2 2 3.0\n"
+    ///     );
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_matrix_market<P>(&self, full_path: &P, symmetric: bool) -> Result<(), Error>
+    where
+        P: AsRef<OsStr> + ?Sized,
+    {
+        if symmetric && self.nrow != self.ncol {
+            return Err(Error::BadDimensions(
+                "the matrix must be square to be written as symmetric",
+            ));
+        }
+
+        // prepare content and compute number of non-zero values (nnz)
+        let mut nnz = 0;
+        let mut buffer = String::new();
+        for (i, j, aij) in self.iter() {
+            if symmetric && i < j {
+                continue;
+            }
+            writeln!(&mut buffer, "{} {} {:?}", i + 1, j + 1, aij).unwrap();
+            nnz += 1;
+        }
+
+        // prepare header
+        let mut header = String::new();
+        let option = if symmetric { "symmetric" } else { "general" };
+        writeln!(&mut header, "%%MatrixMarket matrix coordinate real {}", option).unwrap();
+        writeln!(&mut header, "{} {} {}", self.nrow, self.ncol, nnz).unwrap();
+        header.push_str(&buffer);
+        write_file(full_path, &header)
+    }
+}
+
+impl SparseTriplet {
+    /// Writes a MatrixMarket file (coordinate format)
+    ///
+    /// The triplet is first converted to a [CsrMatrix]; thus, repeated (i,j) entries are summed
+    /// and the entries are written row by row. See [CsrMatrix::to_matrix_market] for the input arguments.
+    pub fn to_matrix_market<P>(&self, full_path: &P, symmetric: bool) -> Result<(), Error>
+    where
+        P: AsRef<OsStr> + ?Sized,
+    {
+        let csr = CsrMatrix::from_triplet(self)?;
+        csr.to_matrix_market(full_path, symmetric)
+    }
+}
+
+/// Writes a vector (e.g., the right-hand side of a linear system) to a MatrixMarket file (array format)
+///
+/// The vector is written as a dense (n,1) matrix.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::Vector;
+/// use russell_sparse::{write_matrix_market_vector, StrError};
+/// use std::fs;
+///
+/// fn main() -> Result<(), StrError> {
+///     let b = Vector::from(&[1.0, -2.5]);
+///     let path = "/tmp/russell_sparse/doc_write_matrix_market_vector.mtx";
+///     write_matrix_market_vector(path, &b)?;
+///     let contents = fs::read_to_string(path).map_err(|_| "cannot open file")?;
+///     assert_eq!(
+///         contents,
+///         "%%MatrixMarket matrix array real general\n\
+///          2 1\n\
+///          1.0\n\
+///          -2.5\n"
+///     );
+///     Ok(())
+/// }
+/// ```
+pub fn write_matrix_market_vector<P>(full_path: &P, v: &Vector) -> Result<(), Error>
+where
+    P: AsRef<OsStr> + ?Sized,
+{
+    let mut buffer = String::new();
+    writeln!(&mut buffer, "%%MatrixMarket matrix array real general\n{} 1", v.dim()).unwrap();
+    for value in v.as_data() {
+        writeln!(&mut buffer, "{:?}", value).unwrap();
+    }
+    write_file(full_path, &buffer)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::{read_matrix_market, CsrMatrix, Error, SparseTriplet};
+    use std::fs;
+
+    #[test]
+    fn to_matrix_market_captures_errors() {
+        let a = CsrMatrix::new(1, 2, vec![0, 1], vec![1], vec![1.0]).unwrap();
+        assert_eq!(
+            a.to_matrix_market("/tmp/russell_sparse/test_to_matrix_market_err.mtx", true)
+                .err(),
+            Some(Error::BadDimensions(
+                "the matrix must be square to be written as symmetric"
+            ))
+        );
+    }
+
+    #[test]
+    fn to_matrix_market_works() {
+        let mut trip = SparseTriplet::new(3, 6).unwrap();
+        trip.put(2, 0, 0.5).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(0, 0, 1.0).unwrap(); // repeated
+        trip.put(1, 2, -1.0 / 3.0).unwrap();
+        trip.put(2, 1, -1.0 / 3.0).unwrap();
+        trip.put(0, 2, 0.5).unwrap();
+        let path = "/tmp/russell_sparse/test_to_matrix_market_gen.mtx";
+        trip.to_matrix_market(path, false).unwrap();
+        let contents = fs::read_to_string(path).unwrap();
+        assert_eq!(
+            contents,
+            "%%MatrixMarket matrix coordinate real general\n\
+             3 3 5\n\
+             1 1 2.0\n\
+             1 3 0.5\n\
+             2 3 -0.3333333333333333\n\
+             3 1 0.5\n\
+             3 2 -0.3333333333333333\n"
+        );
+        let (back, symmetric) = read_matrix_market(&path.to_string(), false).unwrap();
+        assert!(!symmetric);
+        assert_eq!(format!("{}", back.as_matrix()), format!("{}", trip.as_matrix()));
+
+        // symmetric storage
+        let path = "/tmp/russell_sparse/test_to_matrix_market_sym.mtx";
+        trip.to_matrix_market(path, true).unwrap();
+        let (back, symmetric) = read_matrix_market(&path.to_string(), true).unwrap();
+        assert!(symmetric);
+        assert_eq!(back.nnz_current(), 5);
+        assert_eq!(back.as_matrix().as_data(), trip.as_matrix().as_data());
+    }
+}
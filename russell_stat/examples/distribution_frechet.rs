@@ -1,8 +1,8 @@
-use russell_stat::{statistics, DistributionFrechet, Histogram, ProbabilityDistribution, StrError};
+use russell_stat::{statistics, DistributionFrechet, Generator, Histogram, ProbabilityDistribution, StrError};
 
 fn main() -> Result<(), StrError> {
     // generate samples
-    let mut rng = rand::thread_rng();
+    let mut rng = Generator::new_from_entropy();
     let dist = DistributionFrechet::new(0.0, 1.0, 1.0)?;
     let nsamples = 10_000;
     let mut data = vec![0.0; nsamples];
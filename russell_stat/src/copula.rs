@@ -0,0 +1,29 @@
+use crate::Error;
+use rand::Rng;
+
+/// Defines the Copula trait
+///
+/// A copula is a multivariate distribution function with uniform marginals on [0,1].
+/// It describes the dependence structure of a random vector independently of its marginals.
+///
+/// Reference: <https://en.wikipedia.org/wiki/Copula_(probability_theory)>
+pub trait Copula {
+    /// Returns the number of dimensions
+    fn ndim(&self) -> usize;
+
+    /// Implements the copula density function c(u)
+    ///
+    /// # Input
+    ///
+    /// * `u` -- point in the unit hypercube (0,1)ᵈ; must have length equal to `ndim`
+    fn pdf(&self, u: &[f64]) -> Result<f64, Error>;
+
+    /// Generates a pseudo-random point in the unit hypercube following this copula
+    ///
+    /// # Output
+    ///
+    /// * `u` -- the point in (0,1)ᵈ; must have length equal to `ndim`
+    fn sample<R: Rng + ?Sized>(&self, u: &mut [f64], rng: &mut R) -> Result<(), Error>
+    where
+        Self: Sized;
+}
@@ -0,0 +1,166 @@
+use crate::{Copula, Error};
+use rand::Rng;
+use rand_distr::{Distribution, Exp1, Gamma};
+
+/// Implements the Clayton copula (Archimedean)
+///
+/// ```text
+/// C(u) = (Σᵢ uᵢ⁻ᶿ - d + 1)^(-1/θ)
+///
+/// c(u) = Πₖ (1 + k θ) ⋅ Πᵢ uᵢ^(-1-θ) ⋅ (Σᵢ uᵢ⁻ᶿ - d + 1)^(-d-1/θ)   (k = 0, ..., d-1)
+/// ```
+///
+/// where θ > 0 controls the (lower-tail) dependence. Kendall's tau is τ = θ / (θ + 2).
+///
+/// The samples are generated by the Marshall-Olkin algorithm.
+pub struct CopulaClayton {
+    ndim: usize, // number of dimensions
+    theta: f64,  // dependence parameter θ > 0
+
+    sampler: Gamma<f64>, // sampler of the frailty variable V ~ Γ(1/θ, 1)
+}
+
+impl CopulaClayton {
+    /// Creates a new Clayton copula
+    ///
+    /// # Input
+    ///
+    /// * `ndim` -- the number of dimensions (≥ 2)
+    /// * `theta` -- the dependence parameter (θ > 0)
+    pub fn new(ndim: usize, theta: f64) -> Result<Self, Error> {
+        if ndim < 2 {
+            return Err(Error::InvalidParameters("ndim must be at least 2"));
+        }
+        if theta <= 0.0 || !theta.is_finite() {
+            return Err(Error::InvalidParameters("invalid parameters"));
+        }
+        Ok(CopulaClayton {
+            ndim,
+            theta,
+            sampler: Gamma::new(1.0 / theta, 1.0).map_err(|_| Error::InvalidParameters("invalid parameters"))?,
+        })
+    }
+}
+
+impl Copula for CopulaClayton {
+    /// Returns the number of dimensions
+    fn ndim(&self) -> usize {
+        self.ndim
+    }
+
+    /// Implements the copula density function c(u)
+    fn pdf(&self, u: &[f64]) -> Result<f64, Error> {
+        if u.len() != self.ndim {
+            return Err(Error::BadDimensions("u.len() must equal ndim"));
+        }
+        if u.iter().any(|v| *v <= 0.0 || *v >= 1.0) {
+            return Ok(0.0);
+        }
+        let d = self.ndim as f64;
+        let mut log_c = 0.0;
+        let mut sum = 0.0;
+        for (k, v) in u.iter().enumerate() {
+            log_c += f64::ln(1.0 + (k as f64) * self.theta) - (1.0 + self.theta) * f64::ln(*v);
+            sum += f64::powf(*v, -self.theta);
+        }
+        log_c -= (d + 1.0 / self.theta) * f64::ln(sum - d + 1.0);
+        Ok(f64::exp(log_c))
+    }
+
+    /// Generates a pseudo-random point in the unit hypercube following this copula
+    fn sample<R: Rng + ?Sized>(&self, u: &mut [f64], rng: &mut R) -> Result<(), Error> {
+        if u.len() != self.ndim {
+            return Err(Error::BadDimensions("u.len() must equal ndim"));
+        }
+        let v = self.sampler.sample(rng);
+        for ui in u.iter_mut() {
+            let e: f64 = Exp1.sample(rng);
+            *ui = f64::powf(1.0 + e / v, -1.0 / self.theta);
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::CopulaClayton;
+    use crate::{Copula, Error};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use russell_chk::approx_eq;
+
+    // Calculates Kendall's tau of a bivariate sample
+    fn kendall_tau(x: &[f64], y: &[f64]) -> f64 {
+        let n = x.len();
+        let mut s = 0.0;
+        for i in 0..n {
+            for j in (i + 1)..n {
+                s += f64::signum((x[i] - x[j]) * (y[i] - y[j]));
+            }
+        }
+        2.0 * s / ((n * (n - 1)) as f64)
+    }
+
+    #[test]
+    fn new_captures_errors() {
+        assert_eq!(
+            CopulaClayton::new(1, 2.0).err(),
+            Some(Error::InvalidParameters("ndim must be at least 2"))
+        );
+        assert_eq!(
+            CopulaClayton::new(2, 0.0).err(),
+            Some(Error::InvalidParameters("invalid parameters"))
+        );
+        assert_eq!(
+            CopulaClayton::new(2, f64::NAN).err(),
+            Some(Error::InvalidParameters("invalid parameters"))
+        );
+        let copula = CopulaClayton::new(2, 2.0).unwrap();
+        assert_eq!(
+            copula.pdf(&[0.5]).err(),
+            Some(Error::BadDimensions("u.len() must equal ndim"))
+        );
+    }
+
+    #[test]
+    fn pdf_works() {
+        // compare with the mixed derivative of the CDF
+        let theta = 1.5;
+        let copula = CopulaClayton::new(2, theta).unwrap();
+        assert_eq!(copula.ndim(), 2);
+        let cdf = |u: f64, v: f64| f64::powf(u.powf(-theta) + v.powf(-theta) - 1.0, -1.0 / theta);
+        let h = 1e-4;
+        for (u, v) in [(0.5, 0.5), (0.1, 0.3), (0.9, 0.2), (0.75, 0.95)] {
+            let num = (cdf(u + h, v + h) - cdf(u + h, v - h) - cdf(u - h, v + h) + cdf(u - h, v - h)) / (4.0 * h * h);
+            approx_eq(copula.pdf(&[u, v]).unwrap(), num, 1e-6);
+        }
+        assert_eq!(copula.pdf(&[0.5, 1.0]).unwrap(), 0.0);
+
+        // trivariate closed-form
+        let copula = CopulaClayton::new(3, theta).unwrap();
+        let (u, v, w): (f64, f64, f64) = (0.2, 0.4, 0.7);
+        let s = u.powf(-theta) + v.powf(-theta) + w.powf(-theta) - 2.0;
+        let c = (1.0 + theta) * (1.0 + 2.0 * theta) * (u * v * w).powf(-1.0 - theta) * s.powf(-3.0 - 1.0 / theta);
+        approx_eq(copula.pdf(&[u, v, w]).unwrap(), c, 1e-13);
+    }
+
+    #[test]
+    fn sample_works() {
+        let theta = 2.0;
+        let copula = CopulaClayton::new(2, theta).unwrap();
+        let mut rng = StdRng::seed_from_u64(1234);
+        let n = 2000;
+        let mut u = vec![0.0; 2];
+        let mut x = vec![0.0; n];
+        let mut y = vec![0.0; n];
+        for i in 0..n {
+            copula.sample(&mut u, &mut rng).unwrap();
+            assert!(u[0] > 0.0 && u[0] < 1.0 && u[1] > 0.0 && u[1] < 1.0);
+            x[i] = u[0];
+            y[i] = u[1];
+        }
+        approx_eq(kendall_tau(&x, &y), theta / (theta + 2.0), 0.03);
+    }
+}
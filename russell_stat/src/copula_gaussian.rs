@@ -0,0 +1,210 @@
+use crate::distribution_normal::{std_normal_cdf, std_normal_inverse_cdf};
+use crate::{Copula, Error};
+use rand::Rng;
+use rand_distr::{Distribution, StandardNormal};
+use russell_lab::{mat_cholesky, Matrix};
+
+/// Implements the Gaussian copula
+///
+/// ```text
+/// c(u) = |R|^(-1/2) exp(-½ zᵀ (R⁻¹ - I) z)   with   zᵢ = Φ⁻¹(uᵢ)
+/// ```
+///
+/// where R is the correlation matrix and Φ is the CDF of the standard normal distribution.
+pub struct CopulaGaussian {
+    l: Matrix,    // lower-triangular Cholesky factor of the correlation matrix: R = L⋅Lᵀ
+    log_det: f64, // log(|R|)
+}
+
+impl CopulaGaussian {
+    /// Creates a new Gaussian copula
+    ///
+    /// # Input
+    ///
+    /// * `rho` -- the (ndim × ndim) correlation matrix (symmetric positive-definite with unit diagonal)
+    pub fn new(rho: &Matrix) -> Result<Self, Error> {
+        let m = check_correlation_matrix(rho)?;
+        let mut l = Matrix::new(m, m);
+        mat_cholesky(&mut l, rho)?;
+        let mut log_det = 0.0;
+        for i in 0..m {
+            log_det += 2.0 * f64::ln(l.get(i, i));
+        }
+        Ok(CopulaGaussian { l, log_det })
+    }
+
+    /// Returns the lower-triangular Cholesky factor L of the correlation matrix (R = L⋅Lᵀ)
+    pub fn get_cholesky_factor(&self) -> &Matrix {
+        &self.l
+    }
+}
+
+impl Copula for CopulaGaussian {
+    /// Returns the number of dimensions
+    fn ndim(&self) -> usize {
+        self.l.nrow()
+    }
+
+    /// Implements the copula density function c(u)
+    fn pdf(&self, u: &[f64]) -> Result<f64, Error> {
+        let n = self.l.nrow();
+        if u.len() != n {
+            return Err(Error::BadDimensions("u.len() must equal ndim"));
+        }
+        if u.iter().any(|v| *v <= 0.0 || *v >= 1.0) {
+            return Ok(0.0);
+        }
+        // solve L⋅w = z by forward substitution such that zᵀ⋅R⁻¹⋅z = wᵀ⋅w
+        let z: Vec<f64> = u.iter().map(|v| std_normal_inverse_cdf(*v)).collect();
+        let mut w = vec![0.0; n];
+        let mut quad = 0.0;
+        for i in 0..n {
+            let mut sum = z[i];
+            for (k, wk) in w.iter().enumerate().take(i) {
+                sum -= self.l.get(i, k) * wk;
+            }
+            w[i] = sum / self.l.get(i, i);
+            quad += w[i] * w[i] - z[i] * z[i];
+        }
+        Ok(f64::exp(-0.5 * (self.log_det + quad)))
+    }
+
+    /// Generates a pseudo-random point in the unit hypercube following this copula
+    fn sample<R: Rng + ?Sized>(&self, u: &mut [f64], rng: &mut R) -> Result<(), Error> {
+        let n = self.l.nrow();
+        if u.len() != n {
+            return Err(Error::BadDimensions("u.len() must equal ndim"));
+        }
+        let eps: Vec<f64> = (0..n).map(|_| StandardNormal.sample(rng)).collect();
+        for (i, ui) in u.iter_mut().enumerate() {
+            let mut z = 0.0;
+            for (k, ek) in eps.iter().enumerate().take(i + 1) {
+                z += self.l.get(i, k) * ek;
+            }
+            *ui = std_normal_cdf(z);
+        }
+        Ok(())
+    }
+}
+
+/// Checks whether a matrix is a valid correlation matrix and returns its dimension
+///
+/// The matrix must be square, non-empty, symmetric, with unit diagonal and entries in [-1, 1].
+/// Positive-definiteness is not checked here (it is verified by the Cholesky factorization).
+pub(crate) fn check_correlation_matrix(rho: &Matrix) -> Result<usize, Error> {
+    let (m, n) = rho.dims();
+    if m != n || m < 1 {
+        return Err(Error::InvalidCorrelation(
+            "the correlation matrix must be square and non-empty",
+        ));
+    }
+    for i in 0..m {
+        if f64::abs(rho.get(i, i) - 1.0) > 1e-14 {
+            return Err(Error::InvalidCorrelation(
+                "the diagonal of the correlation matrix must be equal to one",
+            ));
+        }
+        for j in (i + 1)..m {
+            if f64::abs(rho.get(i, j) - rho.get(j, i)) > 1e-14 || f64::abs(rho.get(i, j)) > 1.0 {
+                return Err(Error::InvalidCorrelation(
+                    "the correlation matrix must be symmetric with entries in [-1, 1]",
+                ));
+            }
+        }
+    }
+    Ok(m)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(all(test, not(feature = "pure_rust")))]
+mod tests {
+    use super::CopulaGaussian;
+    use crate::distribution_normal::std_normal_inverse_cdf;
+    use crate::{statistics, Copula, Error};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use russell_chk::approx_eq;
+    use russell_lab::math::PI;
+    use russell_lab::Matrix;
+
+    #[test]
+    fn new_captures_errors() {
+        let rho = Matrix::new(2, 3);
+        assert_eq!(
+            CopulaGaussian::new(&rho).err(),
+            Some(Error::InvalidCorrelation(
+                "the correlation matrix must be square and non-empty"
+            ))
+        );
+        let rho = Matrix::from(&[[1.0, 0.5], [0.5, 2.0]]);
+        assert_eq!(
+            CopulaGaussian::new(&rho).err(),
+            Some(Error::InvalidCorrelation(
+                "the diagonal of the correlation matrix must be equal to one"
+            ))
+        );
+        let rho = Matrix::from(&[[1.0, 0.5], [0.4, 1.0]]);
+        assert_eq!(
+            CopulaGaussian::new(&rho).err(),
+            Some(Error::InvalidCorrelation(
+                "the correlation matrix must be symmetric with entries in [-1, 1]"
+            ))
+        );
+        let copula = CopulaGaussian::new(&Matrix::identity(2)).unwrap();
+        assert_eq!(
+            copula.pdf(&[0.5]).err(),
+            Some(Error::BadDimensions("u.len() must equal ndim"))
+        );
+    }
+
+    #[test]
+    fn pdf_works() {
+        // bivariate: c(u,v) = φ₂(x,y;ρ) / (φ(x) φ(y))
+        let r = 0.6;
+        let rho = Matrix::from(&[[1.0, r], [r, 1.0]]);
+        let copula = CopulaGaussian::new(&rho).unwrap();
+        assert_eq!(copula.ndim(), 2);
+        for (u, v) in [(0.5, 0.5), (0.1, 0.3), (0.9, 0.2), (0.75, 0.95)] {
+            let x = std_normal_inverse_cdf(u);
+            let y = std_normal_inverse_cdf(v);
+            let ss = 1.0 - r * r;
+            let phi2 = f64::exp(-(x * x - 2.0 * r * x * y + y * y) / (2.0 * ss)) / (2.0 * PI * f64::sqrt(ss));
+            let phi_x = f64::exp(-x * x / 2.0) / f64::sqrt(2.0 * PI);
+            let phi_y = f64::exp(-y * y / 2.0) / f64::sqrt(2.0 * PI);
+            approx_eq(copula.pdf(&[u, v]).unwrap(), phi2 / (phi_x * phi_y), 1e-13);
+        }
+        assert_eq!(copula.pdf(&[0.0, 0.5]).unwrap(), 0.0);
+
+        // independence copula
+        let copula = CopulaGaussian::new(&Matrix::identity(3)).unwrap();
+        approx_eq(copula.pdf(&[0.2, 0.5, 0.7]).unwrap(), 1.0, 1e-15);
+    }
+
+    #[test]
+    fn sample_works() {
+        let r = 0.8;
+        let rho = Matrix::from(&[[1.0, r], [r, 1.0]]);
+        let copula = CopulaGaussian::new(&rho).unwrap();
+        let mut rng = StdRng::seed_from_u64(1234);
+        let n = 10_000;
+        let mut u = vec![0.0; 2];
+        let mut z0 = vec![0.0; n];
+        let mut z1 = vec![0.0; n];
+        for i in 0..n {
+            copula.sample(&mut u, &mut rng).unwrap();
+            assert!(u[0] > 0.0 && u[0] < 1.0 && u[1] > 0.0 && u[1] < 1.0);
+            z0[i] = std_normal_inverse_cdf(u[0]);
+            z1[i] = std_normal_inverse_cdf(u[1]);
+        }
+        // the sample correlation of the normal scores approximates ρ
+        let s0 = statistics(&z0);
+        let s1 = statistics(&z1);
+        let mut cov = 0.0;
+        for i in 0..n {
+            cov += (z0[i] - s0.mean) * (z1[i] - s1.mean);
+        }
+        cov /= (n - 1) as f64;
+        approx_eq(cov / (s0.std_dev * s1.std_dev), r, 0.02);
+    }
+}
@@ -1,7 +1,7 @@
-use crate::{ProbabilityDistribution, StrError};
+use crate::{Error, ProbabilityDistribution};
 use rand::Rng;
 use rand_distr::{Distribution, Frechet};
-use russell_lab::math::gamma;
+use russell_lab::math::{gamma, EULER};
 
 const FRECHET_MIN_DELTA_X: f64 = 1e-15;
 
@@ -21,12 +21,13 @@ impl DistributionFrechet {
     ///
     /// * `location` -- location parameter
     /// * `shape` -- shape parameter
-    pub fn new(location: f64, scale: f64, shape: f64) -> Result<Self, StrError> {
+    pub fn new(location: f64, scale: f64, shape: f64) -> Result<Self, Error> {
         Ok(DistributionFrechet {
             location,
             scale,
             shape,
-            sampler: Frechet::new(location, scale, shape).map_err(|_| "invalid parameters")?,
+            sampler: Frechet::new(location, scale, shape)
+                .map_err(|_| Error::InvalidParameters("invalid parameters"))?,
         })
     }
 }
@@ -72,14 +73,53 @@ impl ProbabilityDistribution for DistributionFrechet {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
         self.sampler.sample(rng)
     }
+
+    /// Implements the inverse of the Cumulative Density Function (quantile function)
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return self.location;
+        }
+        if p >= 1.0 {
+            return f64::INFINITY;
+        }
+        self.location + self.scale * f64::powf(-f64::ln(p), -1.0 / self.shape)
+    }
+
+    /// Returns the Median
+    fn median(&self) -> f64 {
+        self.location + self.scale / f64::powf(f64::ln(2.0), 1.0 / self.shape)
+    }
+
+    /// Returns the Mode
+    fn mode(&self) -> f64 {
+        self.location + self.scale * f64::powf(self.shape / (1.0 + self.shape), 1.0 / self.shape)
+    }
+
+    /// Returns the Skewness
+    ///
+    /// The skewness is infinite for shape ≤ 3
+    fn skewness(&self) -> f64 {
+        if self.shape > 3.0 {
+            let g1 = gamma(1.0 - 1.0 / self.shape);
+            let g2 = gamma(1.0 - 2.0 / self.shape);
+            let g3 = gamma(1.0 - 3.0 / self.shape);
+            return (g3 - 3.0 * g2 * g1 + 2.0 * g1 * g1 * g1) / f64::powf(g2 - g1 * g1, 1.5);
+        }
+        f64::INFINITY
+    }
+
+    /// Returns the (differential) Entropy in nats
+    fn entropy(&self) -> f64 {
+        1.0 + EULER / self.shape + EULER + f64::ln(self.scale / self.shape)
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use crate::{DistributionFrechet, ProbabilityDistribution};
-    use russell_chk::approx_eq;
+    use crate::{DistributionFrechet, Error, ProbabilityDistribution};
+    use russell_chk::{approx_eq, approx_eq_slices};
 
     // Data from the following R-code (run with Rscript frechet.R):
     /*
@@ -115,7 +155,7 @@ mod tests {
     fn frechet_handles_errors() {
         assert_eq!(
             DistributionFrechet::new(2.0, 3.0, f64::INFINITY).err(),
-            Some("invalid parameters")
+            Some(Error::InvalidParameters("invalid parameters"))
         );
     }
 
@@ -233,12 +273,15 @@ mod tests {
             [3.50000000000000e+00, 5.00000000000000e-01, 2.00000000000000e+00, 3.00000000000000e+00, 2.20316171616565e-01, 7.43567079205906e-01],
             [4.00000000000000e+00, 5.00000000000000e-01, 2.00000000000000e+00, 3.00000000000000e+00, 1.32710267758176e-01, 8.29784773144222e-01],
         ];
-        for row in data {
-            let [x, location, scale, shape, pdf, cdf] = row;
-            let d = DistributionFrechet::new(location, scale, shape).unwrap();
-            approx_eq(d.pdf(x), pdf, 1e-14);
-            approx_eq(d.cdf(x), cdf, 1e-14);
-        }
+        let computed: Vec<_> = data
+            .iter()
+            .map(|[x, location, scale, shape, _, _]| {
+                let d = DistributionFrechet::new(*location, *scale, *shape).unwrap();
+                (d.pdf(*x), d.cdf(*x))
+            })
+            .collect();
+        let reference: Vec<_> = data.iter().map(|row| (row[4], row[5])).collect();
+        approx_eq_slices(&computed, &reference, 1e-14);
     }
 
     #[test]
@@ -255,6 +298,28 @@ mod tests {
         assert_eq!(d.variance(), f64::INFINITY);
     }
 
+    #[test]
+    fn median_mode_skewness_and_entropy_work() {
+        // Data from mpmath
+        let d = DistributionFrechet::new(1.0, 2.0, 4.0).unwrap();
+        approx_eq(d.median(), 3.1919146048935846, 1e-15);
+        approx_eq(d.mode(), 2.8914832180063516, 1e-15);
+        approx_eq(d.skewness(), 5.605138216895865, 1e-12);
+        approx_eq(d.entropy(), 1.0283724005669708, 1e-15);
+        let d = DistributionFrechet::new(1.0, 2.0, 3.0).unwrap();
+        assert_eq!(d.skewness(), f64::INFINITY);
+    }
+
+    #[test]
+    fn inverse_cdf_works() {
+        let d = DistributionFrechet::new(0.5, 2.0, 3.0).unwrap();
+        assert_eq!(d.inverse_cdf(0.0), 0.5);
+        assert_eq!(d.inverse_cdf(1.0), f64::INFINITY);
+        for p in [0.001, 0.1, 0.5, 0.9, 0.999] {
+            approx_eq(d.cdf(d.inverse_cdf(p)), p, 1e-15);
+        }
+    }
+
     #[test]
     fn sample_works() {
         let d = DistributionFrechet::new(1.0, 2.0, 3.0).unwrap();
@@ -1,8 +1,11 @@
-use crate::{ProbabilityDistribution, StrError};
+use crate::{Error, ProbabilityDistribution};
 use rand::Rng;
 use rand_distr::{Distribution, Gumbel};
 use russell_lab::math::{EULER, PI, SQRT_6};
 
+/// Holds the skewness of the Gumbel distribution: 12 √6 ζ(3) / π³
+const GUMBEL_SKEWNESS: f64 = 1.1395470994046487;
+
 /// Defines the Gumbel / Type I Extreme Value Distribution (largest value)
 pub struct DistributionGumbel {
     location: f64, // location: characteristic largest value
@@ -18,11 +21,11 @@ impl DistributionGumbel {
     ///
     /// * `location` -- characteristic largest value
     /// * `scale` -- measure of dispersion of the largest value
-    pub fn new(location: f64, scale: f64) -> Result<Self, StrError> {
+    pub fn new(location: f64, scale: f64) -> Result<Self, Error> {
         Ok(DistributionGumbel {
             location,
             scale,
-            sampler: Gumbel::new(location, scale).map_err(|_| "invalid parameters")?,
+            sampler: Gumbel::new(location, scale).map_err(|_| Error::InvalidParameters("invalid parameters"))?,
         })
     }
 
@@ -32,13 +35,13 @@ impl DistributionGumbel {
     ///
     /// * `mu` -- mean μ
     /// * `sig` -- standard deviation σ
-    pub fn new_from_mu_sig(mu: f64, sig: f64) -> Result<Self, StrError> {
+    pub fn new_from_mu_sig(mu: f64, sig: f64) -> Result<Self, Error> {
         let scale = sig * SQRT_6 / PI;
         let location = mu - EULER * scale;
         Ok(DistributionGumbel {
             location,
             scale,
-            sampler: Gumbel::new(location, scale).map_err(|_| "invalid parameters")?,
+            sampler: Gumbel::new(location, scale).map_err(|_| Error::InvalidParameters("invalid parameters"))?,
         })
     }
 }
@@ -70,13 +73,44 @@ impl ProbabilityDistribution for DistributionGumbel {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
         self.sampler.sample(rng)
     }
+
+    /// Implements the inverse of the Cumulative Density Function (quantile function)
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        if p >= 1.0 {
+            return f64::INFINITY;
+        }
+        self.location - self.scale * f64::ln(-f64::ln(p))
+    }
+
+    /// Returns the Median
+    fn median(&self) -> f64 {
+        self.location - self.scale * f64::ln(f64::ln(2.0))
+    }
+
+    /// Returns the Mode
+    fn mode(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the Skewness
+    fn skewness(&self) -> f64 {
+        GUMBEL_SKEWNESS
+    }
+
+    /// Returns the (differential) Entropy in nats
+    fn entropy(&self) -> f64 {
+        f64::ln(self.scale) + EULER + 1.0
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use crate::{DistributionGumbel, ProbabilityDistribution};
+    use crate::{DistributionGumbel, Error, ProbabilityDistribution};
     use russell_chk::approx_eq;
 
     // Data from the following R-code (run with Rscript gumbel.R):
@@ -110,11 +144,11 @@ mod tests {
     fn gumbel_handles_errors() {
         assert_eq!(
             DistributionGumbel::new(2.0, f64::INFINITY).err(),
-            Some("invalid parameters")
+            Some(Error::InvalidParameters("invalid parameters"))
         );
         assert_eq!(
             DistributionGumbel::new_from_mu_sig(2.0, f64::INFINITY).err(),
-            Some("invalid parameters")
+            Some(Error::InvalidParameters("invalid parameters"))
         );
     }
 
@@ -265,6 +299,26 @@ mod tests {
         approx_eq(d.variance(), sig * sig, 1e-14);
     }
 
+    #[test]
+    fn median_mode_skewness_and_entropy_work() {
+        // Data from mpmath
+        let d = DistributionGumbel::new(0.5, 2.0).unwrap();
+        approx_eq(d.median(), 1.2330258411633287, 1e-15);
+        assert_eq!(d.mode(), 0.5);
+        approx_eq(d.skewness(), 1.1395470994046487, 1e-15);
+        approx_eq(d.entropy(), 2.270362845461478, 1e-15);
+    }
+
+    #[test]
+    fn inverse_cdf_works() {
+        let d = DistributionGumbel::new(0.5, 2.0).unwrap();
+        assert_eq!(d.inverse_cdf(0.0), f64::NEG_INFINITY);
+        assert_eq!(d.inverse_cdf(1.0), f64::INFINITY);
+        for p in [0.001, 0.1, 0.5, 0.9, 0.999] {
+            approx_eq(d.cdf(d.inverse_cdf(p)), p, 1e-15);
+        }
+    }
+
     #[test]
     fn sample_works() {
         let d = DistributionGumbel::new(1.0, 2.0).unwrap();
@@ -1,7 +1,8 @@
-use crate::{ProbabilityDistribution, StrError};
+use crate::distribution_normal::std_normal_inverse_cdf;
+use crate::{Error, ProbabilityDistribution};
 use rand::Rng;
 use rand_distr::{Distribution, LogNormal};
-use russell_lab::math::{erf, SQRT_2, SQRT_PI};
+use russell_lab::math::{erf, PI, SQRT_2, SQRT_PI};
 
 const LOGNORMAL_MIN_X: f64 = 1e-15;
 
@@ -22,13 +23,13 @@ impl DistributionLognormal {
     ///
     /// * `mu_logx` -- mean (μ) of log(x)
     /// * `sig_logx` -- standard deviation (σ) of log(x)
-    pub fn new(mu_logx: f64, sig_logx: f64) -> Result<Self, StrError> {
+    pub fn new(mu_logx: f64, sig_logx: f64) -> Result<Self, Error> {
         Ok(DistributionLognormal {
             mu_logx,
             sig_logx,
             a: 1.0 / (sig_logx * SQRT_2 * SQRT_PI),
             b: -1.0 / (2.0 * sig_logx * sig_logx),
-            sampler: LogNormal::new(mu_logx, sig_logx).map_err(|_| "invalid parameters")?,
+            sampler: LogNormal::new(mu_logx, sig_logx).map_err(|_| Error::InvalidParameters("invalid parameters"))?,
         })
     }
 
@@ -40,7 +41,7 @@ impl DistributionLognormal {
     /// * `sig` -- standard deviation σ
     ///
     /// This function will hence calculate `mu_logx` (mean of log(x)) and `sig_logx` (std-dev of log(x))
-    pub fn new_from_mu_sig(mu: f64, sig: f64) -> Result<Self, StrError> {
+    pub fn new_from_mu_sig(mu: f64, sig: f64) -> Result<Self, Error> {
         let d = sig / mu;
         let v = f64::ln(1.0 + d * d);
         let sig_logx = f64::sqrt(v);
@@ -50,7 +51,7 @@ impl DistributionLognormal {
             sig_logx,
             a: 1.0 / (sig_logx * SQRT_2 * SQRT_PI),
             b: -1.0 / (2.0 * sig_logx * sig_logx),
-            sampler: LogNormal::new(mu_logx, sig_logx).map_err(|_| "invalid parameters")?,
+            sampler: LogNormal::new(mu_logx, sig_logx).map_err(|_| Error::InvalidParameters("invalid parameters"))?,
         })
     }
 }
@@ -87,13 +88,42 @@ impl ProbabilityDistribution for DistributionLognormal {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
         self.sampler.sample(rng)
     }
+
+    /// Implements the inverse of the Cumulative Density Function (quantile function)
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return 0.0;
+        }
+        f64::exp(self.mu_logx + self.sig_logx * std_normal_inverse_cdf(p))
+    }
+
+    /// Returns the Median
+    fn median(&self) -> f64 {
+        f64::exp(self.mu_logx)
+    }
+
+    /// Returns the Mode
+    fn mode(&self) -> f64 {
+        f64::exp(self.mu_logx - self.sig_logx * self.sig_logx)
+    }
+
+    /// Returns the Skewness
+    fn skewness(&self) -> f64 {
+        let ess = f64::exp(self.sig_logx * self.sig_logx);
+        (ess + 2.0) * f64::sqrt(ess - 1.0)
+    }
+
+    /// Returns the (differential) Entropy in nats
+    fn entropy(&self) -> f64 {
+        self.mu_logx + 0.5 + f64::ln(self.sig_logx) + 0.5 * f64::ln(2.0 * PI)
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use crate::{DistributionLognormal, ProbabilityDistribution};
+    use crate::{DistributionLognormal, Error, ProbabilityDistribution};
     use russell_chk::approx_eq;
 
     // Data from the following R-code (run with Rscript lognormal.R):
@@ -125,11 +155,11 @@ mod tests {
     fn lognormal_handles_errors() {
         assert_eq!(
             DistributionLognormal::new(2.0, f64::INFINITY).err(),
-            Some("invalid parameters")
+            Some(Error::InvalidParameters("invalid parameters"))
         );
         assert_eq!(
             DistributionLognormal::new_from_mu_sig(2.0, f64::INFINITY).err(),
-            Some("invalid parameters")
+            Some(Error::InvalidParameters("invalid parameters"))
         );
     }
 
@@ -283,6 +313,27 @@ mod tests {
         approx_eq(d.variance(), sig * sig, 1e-14);
     }
 
+    #[test]
+    fn median_mode_skewness_and_entropy_work() {
+        // Data from mpmath
+        let d = DistributionLognormal::new(0.5, 0.4).unwrap();
+        approx_eq(d.median(), 1.6487212707001282, 1e-15);
+        approx_eq(d.mode(), 1.4049475905635938, 1e-15);
+        approx_eq(d.skewness(), 1.321914405398763, 1e-15);
+        approx_eq(d.entropy(), 1.0026478013305177, 1e-15);
+    }
+
+    #[test]
+    fn inverse_cdf_works() {
+        let d = DistributionLognormal::new(0.5, 0.25).unwrap();
+        assert_eq!(d.inverse_cdf(0.0), 0.0);
+        assert_eq!(d.inverse_cdf(1.0), f64::INFINITY);
+        for p in [0.001, 0.1, 0.5, 0.9, 0.999] {
+            approx_eq(d.cdf(d.inverse_cdf(p)), p, 1e-15);
+        }
+        approx_eq(d.inverse_cdf(0.5), f64::exp(0.5), 1e-15);
+    }
+
     #[test]
     fn sample_works() {
         let d = DistributionLognormal::new(1.0, 2.0).unwrap();
@@ -1,7 +1,43 @@
-use crate::{ProbabilityDistribution, StrError};
+use crate::{Error, ProbabilityDistribution};
 use rand::Rng;
 use rand_distr::{Distribution, Normal};
-use russell_lab::math::{erf, SQRT_2, SQRT_PI};
+use russell_lab::math::{erf, erfc, PI, SQRT_2, SQRT_PI};
+
+// Coefficients of the rational approximations by P. J. Acklam (inverse of the standard normal CDF)
+#[allow(clippy::excessive_precision)]
+const ACKLAM_A: [f64; 6] = [
+    -3.969683028665376e+01,
+    2.209460984245205e+02,
+    -2.759285104469687e+02,
+    1.383577518672690e+02,
+    -3.066479806614716e+01,
+    2.506628277459239e+00,
+];
+#[allow(clippy::excessive_precision)]
+const ACKLAM_B: [f64; 5] = [
+    -5.447609879822406e+01,
+    1.615858368580409e+02,
+    -1.556989798598866e+02,
+    6.680131188771972e+01,
+    -1.328068155288572e+01,
+];
+#[allow(clippy::excessive_precision)]
+const ACKLAM_C: [f64; 6] = [
+    -7.784894002430293e-03,
+    -3.223964580411365e-01,
+    -2.400758277161838e+00,
+    -2.549732539343734e+00,
+    4.374664141464968e+00,
+    2.938163982698783e+00,
+];
+#[allow(clippy::excessive_precision)]
+const ACKLAM_D: [f64; 4] = [
+    7.784695709041462e-03,
+    3.224671290700398e-01,
+    2.445134137142996e+00,
+    3.754408661907416e+00,
+];
+const ACKLAM_P_LOW: f64 = 0.02425;
 
 /// Defines the Normal distribution
 pub struct DistributionNormal {
@@ -20,13 +56,13 @@ impl DistributionNormal {
     ///
     /// * `mu` -- mean μ
     /// * `sig` -- standard deviation σ
-    pub fn new(mu: f64, sig: f64) -> Result<Self, StrError> {
+    pub fn new(mu: f64, sig: f64) -> Result<Self, Error> {
         Ok(DistributionNormal {
             mu,
             sig,
             a: 1.0 / (sig * SQRT_2 * SQRT_PI),
             b: -1.0 / (2.0 * sig * sig),
-            sampler: Normal::new(mu, sig).map_err(|_| "invalid parameters")?,
+            sampler: Normal::new(mu, sig).map_err(|_| Error::InvalidParameters("invalid parameters"))?,
         })
     }
 }
@@ -56,13 +92,87 @@ impl ProbabilityDistribution for DistributionNormal {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
         self.sampler.sample(rng)
     }
+
+    /// Implements the inverse of the Cumulative Density Function (quantile function)
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        self.mu + self.sig * std_normal_inverse_cdf(p)
+    }
+
+    /// Returns the Median
+    fn median(&self) -> f64 {
+        self.mu
+    }
+
+    /// Returns the Mode
+    fn mode(&self) -> f64 {
+        self.mu
+    }
+
+    /// Returns the Skewness
+    fn skewness(&self) -> f64 {
+        0.0
+    }
+
+    /// Returns the (differential) Entropy in nats
+    fn entropy(&self) -> f64 {
+        0.5 * f64::ln(2.0 * PI * f64::exp(1.0) * self.sig * self.sig)
+    }
+}
+
+/// Computes the PDF of the standard normal distribution φ(z)
+pub(crate) fn std_normal_pdf(z: f64) -> f64 {
+    f64::exp(-z * z / 2.0) / (SQRT_2 * SQRT_PI)
+}
+
+/// Computes the CDF of the standard normal distribution Φ(z)
+pub(crate) fn std_normal_cdf(z: f64) -> f64 {
+    erfc(-z / SQRT_2) / 2.0
+}
+
+/// Computes the inverse of the CDF of the standard normal distribution Φ⁻¹(p)
+///
+/// Uses the rational approximation by P. J. Acklam followed by one step of Halley's method.
+///
+/// Reference: <https://web.archive.org/web/20151030215612/http://home.online.no/~pjacklam/notes/invnorm/>
+pub(crate) fn std_normal_inverse_cdf(p: f64) -> f64 {
+    if p <= 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if p >= 1.0 {
+        return f64::INFINITY;
+    }
+    let (a, b, c, d) = (&ACKLAM_A, &ACKLAM_B, &ACKLAM_C, &ACKLAM_D);
+    let x = if p < ACKLAM_P_LOW {
+        let q = f64::sqrt(-2.0 * f64::ln(p));
+        (((((c[0] * q + c[1]) * q + c[2]) * q + c[3]) * q + c[4]) * q + c[5])
+            / ((((d[0] * q + d[1]) * q + d[2]) * q + d[3]) * q + 1.0)
+    } else if p <= 1.0 - ACKLAM_P_LOW {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((a[0] * r + a[1]) * r + a[2]) * r + a[3]) * r + a[4]) * r + a[5]) * q
+            / (((((b[0] * r + b[1]) * r + b[2]) * r + b[3]) * r + b[4]) * r + 1.0)
+    } else {
+        let q = f64::sqrt(-2.0 * f64::ln(1.0 - p));
+        -(((((c[0] * q + c[1]) * q + c[2]) * q + c[3]) * q + c[4]) * q + c[5])
+            / ((((d[0] * q + d[1]) * q + d[2]) * q + d[3]) * q + 1.0)
+    };
+
+    // refinement using Halley's rational method
+    let e = std_normal_cdf(x) - p;
+    let u = e * SQRT_2 * SQRT_PI * f64::exp(x * x / 2.0);
+    let refined = x - u / (1.0 + x * u / 2.0);
+    if refined.is_finite() {
+        refined
+    } else {
+        x
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use crate::{DistributionNormal, ProbabilityDistribution};
+    use crate::{DistributionNormal, Error, ProbabilityDistribution};
     use russell_chk::approx_eq;
 
     // Data from the following R-code (run with Rscript normal.R):
@@ -94,7 +204,7 @@ mod tests {
     fn normal_handles_errors() {
         assert_eq!(
             DistributionNormal::new(2.0, f64::INFINITY).err(),
-            Some("invalid parameters")
+            Some(Error::InvalidParameters("invalid parameters"))
         );
     }
 
@@ -273,6 +383,42 @@ mod tests {
         approx_eq(d.variance(), sig * sig, 1e-14);
     }
 
+    #[test]
+    fn median_mode_skewness_and_entropy_work() {
+        let d = DistributionNormal::new(1.0, 2.0).unwrap();
+        assert_eq!(d.median(), 1.0);
+        assert_eq!(d.mode(), 1.0);
+        assert_eq!(d.skewness(), 0.0);
+        approx_eq(d.entropy(), 2.112085713764618, 1e-15);
+    }
+
+    #[test]
+    fn inverse_cdf_works() {
+        // Data from Python: NormalDist(mu, sig).inv_cdf(p)
+        #[rustfmt::skip]
+        // p, mu, sig, x
+        let data = [
+            [1e-10, 0.0, 1.0, -6.361340902404056],
+            [0.001, 0.0, 1.0, -3.090232306167813],
+            [0.025, 0.0, 1.0, -1.9599639845400538],
+            [0.3,   0.0, 1.0, -0.5244005127080407],
+            [0.5,   0.0, 1.0,  0.0],
+            [0.975, 0.0, 1.0,  1.9599639845400536],
+            [0.999, 0.0, 1.0,  3.090232306167813],
+            [0.1,   1.0, 0.5,  0.3592242172276996],
+            [0.9,  -0.5, 2.0,  2.0631031310892016],
+        ];
+        for row in data {
+            let [p, mu, sig, x] = row;
+            let d = DistributionNormal::new(mu, sig).unwrap();
+            approx_eq(d.inverse_cdf(p), x, 1e-14);
+            approx_eq(d.cdf(d.inverse_cdf(p)), p, 1e-15);
+        }
+        let d = DistributionNormal::new(0.0, 1.0).unwrap();
+        assert_eq!(d.inverse_cdf(0.0), f64::NEG_INFINITY);
+        assert_eq!(d.inverse_cdf(1.0), f64::INFINITY);
+    }
+
     #[test]
     fn sample_works() {
         let d = DistributionNormal::new(1.0, 2.0).unwrap();
@@ -0,0 +1,288 @@
+use crate::{Error, ProbabilityDistribution};
+use rand::Rng;
+use rand_distr::{Distribution, StudentT};
+use russell_lab::math::{ln_gamma, PI};
+
+/// Defines the maximum number of iterations of the continued fraction of the incomplete beta function
+const BETA_CF_MAX_ITERATIONS: usize = 300;
+
+/// Defines the tolerance of the continued fraction of the incomplete beta function
+const BETA_CF_TOLERANCE: f64 = 1e-15;
+
+/// Defines the Student's t Distribution
+///
+/// ```text
+///         Γ((ν+1)/2)       x²  -(ν+1)/2
+/// f(x) = ——————————— (1 + ——)
+///        √(νπ) Γ(ν/2)      ν
+/// ```
+///
+/// where ν > 0 is the number of degrees of freedom.
+pub struct DistributionStudentT {
+    nu: f64,                // degrees of freedom
+    ln_coef: f64,           // log of the normalization coefficient of the PDF
+    sampler: StudentT<f64>, // sampler
+}
+
+impl DistributionStudentT {
+    /// Creates a new Student's t distribution
+    ///
+    /// # Input
+    ///
+    /// * `nu` -- degrees of freedom (ν > 0)
+    pub fn new(nu: f64) -> Result<Self, Error> {
+        if nu <= 0.0 || !nu.is_finite() {
+            return Err(Error::InvalidParameters("invalid parameters"));
+        }
+        Ok(DistributionStudentT {
+            nu,
+            ln_coef: ln_gamma((nu + 1.0) / 2.0) - ln_gamma(nu / 2.0) - 0.5 * f64::ln(nu * PI),
+            sampler: StudentT::new(nu).map_err(|_| Error::InvalidParameters("invalid parameters"))?,
+        })
+    }
+}
+
+impl ProbabilityDistribution for DistributionStudentT {
+    /// Implements the Probability Density Function (CDF)
+    fn pdf(&self, x: f64) -> f64 {
+        f64::exp(self.ln_coef - (self.nu + 1.0) / 2.0 * f64::ln(1.0 + x * x / self.nu))
+    }
+
+    /// Implements the Cumulative Density Function (CDF)
+    fn cdf(&self, x: f64) -> f64 {
+        if x == f64::INFINITY {
+            return 1.0;
+        }
+        if x == f64::NEG_INFINITY {
+            return 0.0;
+        }
+        let ib = incomplete_beta(self.nu / 2.0, 0.5, self.nu / (self.nu + x * x));
+        if x > 0.0 {
+            1.0 - ib / 2.0
+        } else {
+            ib / 2.0
+        }
+    }
+
+    /// Returns the Mean
+    ///
+    /// The mean is undefined (NaN) for ν ≤ 1
+    fn mean(&self) -> f64 {
+        if self.nu > 1.0 {
+            0.0
+        } else {
+            f64::NAN
+        }
+    }
+
+    /// Returns the Variance
+    ///
+    /// The variance is infinite for 1 < ν ≤ 2 and undefined (NaN) for ν ≤ 1
+    fn variance(&self) -> f64 {
+        if self.nu > 2.0 {
+            self.nu / (self.nu - 2.0)
+        } else if self.nu > 1.0 {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        }
+    }
+
+    /// Generates a pseudo-random number belonging to this probability distribution
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.sampler.sample(rng)
+    }
+
+    /// Returns the Median
+    fn median(&self) -> f64 {
+        0.0
+    }
+
+    /// Returns the Mode
+    fn mode(&self) -> f64 {
+        0.0
+    }
+
+    /// Returns the Skewness
+    ///
+    /// The skewness is undefined (NaN) for ν ≤ 3
+    fn skewness(&self) -> f64 {
+        if self.nu > 3.0 {
+            0.0
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+/// Computes the regularized incomplete beta function Iₓ(a, b)
+///
+/// Uses the continued fraction representation evaluated by the modified Lentz's method.
+///
+/// Reference: Press WH et al. (2007) Numerical Recipes, 3rd edition, Section 6.4
+fn incomplete_beta(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    let ln_bt = ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * f64::ln(x) + b * f64::ln(1.0 - x);
+    if x < (a + 1.0) / (a + b + 2.0) {
+        f64::exp(ln_bt) * beta_continued_fraction(a, b, x) / a
+    } else {
+        1.0 - f64::exp(ln_bt) * beta_continued_fraction(b, a, 1.0 - x) / b
+    }
+}
+
+/// Evaluates the continued fraction of the incomplete beta function
+fn beta_continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    let tiny = f64::MIN_POSITIVE / f64::EPSILON;
+    let not_tiny = |v: f64| if f64::abs(v) < tiny { tiny } else { v };
+    let (qab, qap, qam) = (a + b, a + 1.0, a - 1.0);
+    let mut c = 1.0;
+    let mut d = 1.0 / not_tiny(1.0 - qab * x / qap);
+    let mut h = d;
+    for i in 1..BETA_CF_MAX_ITERATIONS {
+        let m = i as f64;
+        let m2 = 2.0 * m;
+        // even step
+        let aa = m * (b - m) * x / ((qam + m2) * (a + m2));
+        d = 1.0 / not_tiny(1.0 + aa * d);
+        c = not_tiny(1.0 + aa / c);
+        h *= d * c;
+        // odd step
+        let aa = -(a + m) * (qab + m) * x / ((a + m2) * (qap + m2));
+        d = 1.0 / not_tiny(1.0 + aa * d);
+        c = not_tiny(1.0 + aa / c);
+        let del = d * c;
+        h *= del;
+        if f64::abs(del - 1.0) < BETA_CF_TOLERANCE {
+            break;
+        }
+    }
+    h
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::incomplete_beta;
+    use crate::{statistics, DistributionStudentT, Error, ProbabilityDistribution};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use russell_chk::approx_eq;
+    use russell_lab::math::PI;
+
+    #[test]
+    fn incomplete_beta_works() {
+        assert_eq!(incomplete_beta(2.0, 3.0, 0.0), 0.0);
+        assert_eq!(incomplete_beta(2.0, 3.0, 1.0), 1.0);
+        // I_x(1, 1) = x and I_x(2, 3) = 6x² - 8x³ + 3x⁴
+        approx_eq(incomplete_beta(1.0, 1.0, 0.3), 0.3, 1e-15);
+        for x in [0.1, 0.5, 0.9] {
+            approx_eq(
+                incomplete_beta(2.0, 3.0, x),
+                6.0 * x * x - 8.0 * x * x * x + 3.0 * x * x * x * x,
+                1e-14,
+            );
+        }
+    }
+
+    #[test]
+    fn student_t_handles_errors() {
+        assert_eq!(
+            DistributionStudentT::new(0.0).err(),
+            Some(Error::InvalidParameters("invalid parameters"))
+        );
+        assert_eq!(
+            DistributionStudentT::new(f64::INFINITY).err(),
+            Some(Error::InvalidParameters("invalid parameters"))
+        );
+    }
+
+    #[test]
+    fn student_t_works() {
+        // Data from mpmath (regularized incomplete beta function)
+        #[rustfmt::skip]
+        // nu x pdf cdf
+        let data = [
+            [ 1.0, -3.0, 0.03183098861837907, 0.10241638234956673],
+            [ 1.0, -1.0,  0.15915494309189534,                0.25],
+            [ 1.0,  0.0,            1.0 / PI,                 0.5],
+            [ 1.0,  0.5,  0.25464790894703254, 0.6475836176504333],
+            [ 1.0,  2.0, 0.06366197723675814, 0.8524163823495667],
+            [ 2.5, -3.0,   0.0250410669313931, 0.03628804777451592],
+            [ 2.5, -1.0,  0.20079596067092013, 0.20203051363913673],
+            [ 2.5,  0.0,  0.36180872402956503,                 0.5],
+            [ 2.5,  0.5,  0.3062258405858296, 0.6711510400651427],
+            [ 2.5,  2.0, 0.06796349050979465, 0.921304252121017],
+            [ 5.0, -3.0, 0.01729257880022296, 0.015049623948731287],
+            [ 5.0, -1.0,  0.21967979735098057, 0.1816087338245613],
+            [ 5.0,  0.0,  0.3796066898224944,                 0.5],
+            [ 5.0,  0.5,  0.3279185313227465,  0.6808505641795355],
+            [ 5.0,  2.0, 0.06509031032621647, 0.9490302605850708],
+            [30.0, -3.0, 0.0067790627460931, 0.002694982032825973],
+            [30.0, -1.0,  0.23799334232287983, 0.16265430771301495],
+            [30.0,  0.0,  0.39563218489409776,                 0.5],
+            [30.0,  0.5,  0.34787857969720454, 0.6896384975574363],
+            [30.0,  2.0, 0.05685227504719796, 0.9726874775185085],
+        ];
+        for row in data {
+            let [nu, x, pdf, cdf] = row;
+            let d = DistributionStudentT::new(nu).unwrap();
+            approx_eq(d.pdf(x), pdf, 1e-14);
+            approx_eq(d.cdf(x), cdf, 1e-14);
+        }
+        let d = DistributionStudentT::new(3.0).unwrap();
+        assert_eq!(d.cdf(f64::NEG_INFINITY), 0.0);
+        assert_eq!(d.cdf(f64::INFINITY), 1.0);
+    }
+
+    #[test]
+    fn mean_and_variance_work() {
+        assert!(DistributionStudentT::new(1.0).unwrap().mean().is_nan());
+        assert!(DistributionStudentT::new(1.0).unwrap().variance().is_nan());
+        assert_eq!(DistributionStudentT::new(2.0).unwrap().mean(), 0.0);
+        assert_eq!(DistributionStudentT::new(2.0).unwrap().variance(), f64::INFINITY);
+        assert_eq!(DistributionStudentT::new(4.0).unwrap().variance(), 2.0);
+    }
+
+    #[test]
+    fn median_mode_skewness_and_entropy_work() {
+        // Data from mpmath (digamma and beta functions)
+        let d = DistributionStudentT::new(5.0).unwrap();
+        assert_eq!(d.median(), 0.0);
+        assert_eq!(d.mode(), 0.0);
+        assert_eq!(d.skewness(), 0.0);
+        approx_eq(d.entropy(), 1.627502672414396, 1e-10);
+        let d = DistributionStudentT::new(2.5).unwrap();
+        assert!(d.skewness().is_nan());
+        approx_eq(d.entropy(), 1.8477600705759215, 1e-8);
+    }
+
+    #[test]
+    fn inverse_cdf_works() {
+        // Data from mpmath
+        for (nu, p, x) in [
+            (3.0, 0.975, 3.182446305283708),
+            (10.0, 0.95, 1.8124611228116759),
+            (1.0, 0.9, 3.077683537175254),
+            (25.0, 0.025, -2.0595385527532977),
+        ] {
+            let d = DistributionStudentT::new(nu).unwrap();
+            approx_eq(d.inverse_cdf(p), x, 1e-12);
+        }
+    }
+
+    #[test]
+    fn sample_works() {
+        let d = DistributionStudentT::new(10.0).unwrap();
+        let mut rng = StdRng::seed_from_u64(1234);
+        let data: Vec<f64> = (0..10000).map(|_| d.sample(&mut rng)).collect();
+        let stat = statistics(&data);
+        approx_eq(stat.mean, 0.0, 0.05);
+        approx_eq(stat.std_dev, f64::sqrt(d.variance()), 0.05);
+    }
+}
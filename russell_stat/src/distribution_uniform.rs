@@ -1,4 +1,4 @@
-use crate::{ProbabilityDistribution, StrError};
+use crate::{Error, ProbabilityDistribution};
 use rand::Rng;
 use rand_distr::{Distribution, Uniform};
 
@@ -17,9 +17,9 @@ impl DistributionUniform {
     ///
     /// * `xmin` -- min x value
     /// * `xmax` -- max x value
-    pub fn new(xmin: f64, xmax: f64) -> Result<Self, StrError> {
+    pub fn new(xmin: f64, xmax: f64) -> Result<Self, Error> {
         if xmax < xmin {
-            return Err("invalid parameters");
+            return Err(Error::InvalidParameters("invalid parameters"));
         }
         Ok(DistributionUniform {
             xmin,
@@ -66,13 +66,46 @@ impl ProbabilityDistribution for DistributionUniform {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
         self.sampler.sample(rng)
     }
+
+    /// Implements the inverse of the Cumulative Density Function (quantile function)
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return self.xmin;
+        }
+        if p >= 1.0 {
+            return self.xmax;
+        }
+        self.xmin + p * (self.xmax - self.xmin)
+    }
+
+    /// Returns the Median
+    fn median(&self) -> f64 {
+        (self.xmin + self.xmax) / 2.0
+    }
+
+    /// Returns the Mode
+    ///
+    /// Any value in [xmin, xmax] is a mode; this function returns the midpoint.
+    fn mode(&self) -> f64 {
+        (self.xmin + self.xmax) / 2.0
+    }
+
+    /// Returns the Skewness
+    fn skewness(&self) -> f64 {
+        0.0
+    }
+
+    /// Returns the (differential) Entropy in nats
+    fn entropy(&self) -> f64 {
+        f64::ln(self.xmax - self.xmin)
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use crate::{DistributionUniform, ProbabilityDistribution};
+    use crate::{DistributionUniform, Error, ProbabilityDistribution};
     use rand::prelude::StdRng;
     use rand::SeedableRng;
     use russell_chk::approx_eq;
@@ -100,7 +133,10 @@ mod tests {
 
     #[test]
     fn uniform_handles_errors() {
-        assert_eq!(DistributionUniform::new(2.0, 1.0).err(), Some("invalid parameters"));
+        assert_eq!(
+            DistributionUniform::new(2.0, 1.0).err(),
+            Some(Error::InvalidParameters("invalid parameters"))
+        );
     }
 
     #[test]
@@ -130,6 +166,26 @@ mod tests {
         approx_eq(d.variance(), 1.0 / 3.0, 1e-14);
     }
 
+    #[test]
+    fn median_mode_skewness_and_entropy_work() {
+        let d = DistributionUniform::new(-1.0, 3.0).unwrap();
+        assert_eq!(d.median(), 1.0);
+        assert_eq!(d.mode(), 1.0);
+        assert_eq!(d.skewness(), 0.0);
+        assert_eq!(d.entropy(), f64::ln(4.0));
+    }
+
+    #[test]
+    fn inverse_cdf_works() {
+        let d = DistributionUniform::new(1.5, 2.5).unwrap();
+        assert_eq!(d.inverse_cdf(-1.0), 1.5);
+        assert_eq!(d.inverse_cdf(2.0), 2.5);
+        approx_eq(d.inverse_cdf(0.0), 1.5, 1e-15);
+        approx_eq(d.inverse_cdf(0.25), 1.75, 1e-15);
+        approx_eq(d.inverse_cdf(0.5), 2.0, 1e-15);
+        approx_eq(d.inverse_cdf(1.0), 2.5, 1e-15);
+    }
+
     #[test]
     fn sample_works() {
         let mut rng = StdRng::seed_from_u64(1234);
@@ -0,0 +1,139 @@
+use crate::StrError;
+use std::fmt;
+
+/// Defines the errors returned by the distributions, copulas, sequences, and regressions
+///
+/// For instance, wrong parameters of a distribution give [Error::InvalidParameters] whereas a
+/// correlation matrix that is not valid (or cannot be attained by the Nataf transformation) gives
+/// [Error::InvalidCorrelation]. The errors coming from russell_lab (e.g., a failed Cholesky
+/// factorization) are wrapped by [Error::Lab]. An error converts into a `StrError` with the same
+/// message as before; hence, `?` still works in functions returning `Result<T, StrError>`.
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{DistributionNormal, Error};
+///
+/// match DistributionNormal::new(0.0, -1.0) {
+///     Err(Error::InvalidParameters(message)) => println!("{}", message),
+///     Err(e) => println!("another error: {}", e),
+///     Ok(_) => (),
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// The dimensions of the arrays (or the number of marginals) are incompatible
+    BadDimensions(&'static str),
+
+    /// The parameters of a distribution, copula, sequence, or model are invalid
+    InvalidParameters(&'static str),
+
+    /// The correlation matrix is invalid or the correlation cannot be attained
+    InvalidCorrelation(&'static str),
+
+    /// The design matrix of the regression is rank deficient
+    RankDeficient(&'static str),
+
+    /// A quasi-random sequence cannot generate more points
+    SequenceExhausted(&'static str),
+
+    /// An error returned by russell_lab
+    Lab(russell_lab::Error),
+
+    /// Any other error (e.g., converted from a `StrError`)
+    Other(&'static str),
+
+    /// Wraps an error (the source) with a message describing the context
+    Context(&'static str, Box<Error>),
+}
+
+impl Error {
+    /// Returns the message of this error (without the messages of the sources)
+    pub fn message(&self) -> &'static str {
+        match self {
+            Error::BadDimensions(m)
+            | Error::InvalidParameters(m)
+            | Error::InvalidCorrelation(m)
+            | Error::RankDeficient(m)
+            | Error::SequenceExhausted(m)
+            | Error::Other(m)
+            | Error::Context(m, _) => m,
+            Error::Lab(e) => e.message(),
+        }
+    }
+
+    /// Wraps this error with a message describing the context
+    pub fn context(self, message: &'static str) -> Self {
+        Error::Context(message, Box::new(self))
+    }
+
+    /// Returns the innermost error (following the chain of sources)
+    pub fn root(&self) -> &Error {
+        match self {
+            Error::Context(_, source) => source.root(),
+            _ => self,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Context(_, source) => Some(source.as_ref()),
+            Error::Lab(e) => e.source(),
+            _ => None,
+        }
+    }
+}
+
+impl From<StrError> for Error {
+    fn from(message: StrError) -> Self {
+        Error::Other(message)
+    }
+}
+
+impl From<russell_lab::Error> for Error {
+    fn from(error: russell_lab::Error) -> Self {
+        Error::Lab(error)
+    }
+}
+
+impl From<Error> for StrError {
+    fn from(error: Error) -> Self {
+        error.message()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+    use crate::StrError;
+    use std::error::Error as StdError;
+
+    #[test]
+    fn message_and_context_work() {
+        let err = Error::RankDeficient("the design matrix is rank deficient").context("cannot fit the model");
+        assert_eq!(err.message(), "cannot fit the model");
+        assert_eq!(format!("{}", err), "cannot fit the model");
+        assert_eq!(err.source().unwrap().to_string(), "the design matrix is rank deficient");
+        assert_eq!(err.root(), &Error::RankDeficient("the design matrix is rank deficient"));
+    }
+
+    #[test]
+    fn conversions_work() {
+        let err: Error = "some error".into();
+        assert_eq!(err, Error::Other("some error"));
+        let err: Error = russell_lab::Error::Singular("LAPACK dpotrf failed").into();
+        assert_eq!(err.message(), "LAPACK dpotrf failed");
+        let message: StrError = Error::InvalidParameters("invalid parameters").into();
+        assert_eq!(message, "invalid parameters");
+    }
+}
@@ -0,0 +1,195 @@
+use rand::rngs::StdRng;
+use rand::{Error, RngCore, SeedableRng};
+
+/// Implements a seedable pseudo-random number generator with support for independent streams
+///
+/// The generator wraps [rand::rngs::StdRng] and implements [rand::RngCore]; thus, `&mut Generator`
+/// can be passed to all sampling functions of this crate (e.g., [crate::ProbabilityDistribution::sample]).
+///
+/// Independent streams are derived from the seed by a SplitMix64 hash of the stream index.
+/// Therefore, simulations running in several threads (one stream per thread or per task) are
+/// exactly reproducible regardless of the scheduling.
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{DistributionNormal, Generator, ProbabilityDistribution, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let dist = DistributionNormal::new(0.0, 1.0)?;
+///
+///     // the same seed yields the same numbers
+///     let mut a = Generator::new(1234);
+///     let mut b = Generator::new(1234);
+///     assert_eq!(dist.sample(&mut a), dist.sample(&mut b));
+///
+///     // independent streams (e.g., one per thread)
+///     let streams: Vec<_> = (0..4).map(|i| a.stream(i)).collect();
+///     assert_eq!(streams[2].get_seed(), b.stream(2).get_seed());
+///     Ok(())
+/// }
+/// ```
+pub struct Generator {
+    seed: u64,   // seed
+    rng: StdRng, // pseudo-random number generator
+}
+
+impl Generator {
+    /// Creates a new generator with the given seed
+    pub fn new(seed: u64) -> Self {
+        Generator {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Creates a new generator with a seed drawn from the operating system
+    ///
+    /// The seed can be retrieved by [Generator::get_seed] to reproduce the run afterwards.
+    pub fn new_from_entropy() -> Self {
+        Generator::new(rand::random::<u64>())
+    }
+
+    /// Returns the seed
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Restarts the sequence of numbers from the seed
+    pub fn reset(&mut self) {
+        self.rng = StdRng::seed_from_u64(self.seed);
+    }
+
+    /// Returns a new generator corresponding to the independent stream with the given index
+    ///
+    /// The result depends only on the seed and the index (not on the state of this generator).
+    pub fn stream(&self, index: u64) -> Generator {
+        Generator::new(splitmix64(self.seed ^ splitmix64(index)))
+    }
+
+    /// Returns a new generator seeded by the next number of this generator
+    ///
+    /// This function advances the state of this generator; hence, successive calls
+    /// return different (and reproducible) generators.
+    pub fn split(&mut self) -> Generator {
+        Generator::new(splitmix64(self.rng.next_u64()))
+    }
+}
+
+impl RngCore for Generator {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+/// Computes the SplitMix64 hash (finalizer) of a 64-bit integer
+///
+/// Reference: Steele GL, Lea D, Flood CH (2014) Fast splittable pseudorandom number generators, OOPSLA'14
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{splitmix64, Generator};
+    use crate::{DistributionUniform, ProbabilityDistribution};
+    use rand::{Rng, RngCore};
+
+    #[test]
+    fn splitmix64_works() {
+        // reference values of the SplitMix64 generator seeded with 0 (state increments by the golden gamma)
+        assert_eq!(splitmix64(0), 0xe220_a839_7b1d_cdaf);
+        assert_eq!(splitmix64(0x9e37_79b9_7f4a_7c15), 0x6e78_9e6a_a1b9_65f4);
+    }
+
+    #[test]
+    fn generator_is_reproducible() {
+        let mut a = Generator::new(123);
+        let mut b = Generator::new(123);
+        let mut c = Generator::new(321);
+        assert_eq!(a.get_seed(), 123);
+        let xa: Vec<f64> = (0..10).map(|_| a.gen()).collect();
+        let xb: Vec<f64> = (0..10).map(|_| b.gen()).collect();
+        let xc: Vec<f64> = (0..10).map(|_| c.gen()).collect();
+        assert_eq!(xa, xb);
+        assert_ne!(xa, xc);
+        a.reset();
+        let xr: Vec<f64> = (0..10).map(|_| a.gen()).collect();
+        assert_eq!(xa, xr);
+        let mut bytes_a = [0_u8; 16];
+        let mut bytes_b = [0_u8; 16];
+        a.fill_bytes(&mut bytes_a);
+        b.try_fill_bytes(&mut bytes_b).unwrap();
+        assert_eq!(bytes_a, bytes_b);
+        let g = Generator::new_from_entropy();
+        let mut h = Generator::new(g.get_seed());
+        let mut g = g;
+        assert_eq!(g.next_u32(), h.next_u32());
+    }
+
+    #[test]
+    fn streams_are_reproducible_and_distinct() {
+        let mut a = Generator::new(7);
+        let b = Generator::new(7);
+        let _: f64 = a.gen(); // the streams do not depend on the state
+        let mut seeds: Vec<u64> = (0..100).map(|i| a.stream(i).get_seed()).collect();
+        for (i, seed) in seeds.iter().enumerate() {
+            assert_eq!(*seed, b.stream(i as u64).get_seed());
+        }
+        seeds.sort();
+        seeds.dedup();
+        assert_eq!(seeds.len(), 100);
+        assert_ne!(a.stream(0).get_seed(), Generator::new(8).stream(0).get_seed());
+    }
+
+    #[test]
+    fn split_works() {
+        let mut a = Generator::new(7);
+        let mut b = Generator::new(7);
+        let (mut a1, mut a2) = (a.split(), a.split());
+        let (mut b1, mut b2) = (b.split(), b.split());
+        assert_ne!(a1.get_seed(), a2.get_seed());
+        assert_eq!(a1.next_u64(), b1.next_u64());
+        assert_eq!(a2.next_u64(), b2.next_u64());
+    }
+
+    #[test]
+    fn generator_works_with_threads() {
+        let dist = DistributionUniform::new(0.0, 1.0).unwrap();
+        let root = Generator::new(2023);
+        let sums_sequential: Vec<f64> = (0..4)
+            .map(|i| {
+                let mut rng = root.stream(i);
+                (0..100).map(|_| dist.sample(&mut rng)).sum()
+            })
+            .collect();
+        let sums_parallel: Vec<f64> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..4)
+                .map(|i| {
+                    let mut rng = root.stream(i);
+                    let dist = &dist;
+                    scope.spawn(move || (0..100).map(|_| dist.sample(&mut rng)).sum::<f64>())
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+        assert_eq!(sums_sequential, sums_parallel);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::StrError;
+use crate::Error;
 use num_traits::Num;
 use std::cmp;
 use std::fmt::{self, Write};
@@ -75,9 +75,9 @@ where
     T: Num + Copy + PartialOrd,
 {
     /// Creates a new Histogram
-    pub fn new(stations: &[T]) -> Result<Self, StrError> {
+    pub fn new(stations: &[T]) -> Result<Self, Error> {
         if stations.len() < 2 {
-            return Err("histogram must have at least 2 stations");
+            return Err(Error::InvalidParameters("histogram must have at least 2 stations"));
         }
         let nbins = stations.len() - 1;
         Ok(Histogram {
@@ -216,12 +216,13 @@ where
 #[cfg(test)]
 mod tests {
     use super::Histogram;
+    use crate::Error;
 
     #[test]
     fn new_fails_on_wrong_input() {
         assert_eq!(
             Histogram::<i32>::new(&[]).err(),
-            Some("histogram must have at least 2 stations")
+            Some(Error::InvalidParameters("histogram must have at least 2 stations"))
         );
     }
 
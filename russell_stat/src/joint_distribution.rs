@@ -0,0 +1,210 @@
+use crate::{Copula, Error, ProbabilityDistribution};
+use rand::Rng;
+
+/// Implements a joint (multivariate) distribution built from marginals and a copula
+///
+/// The joint CDF and PDF are (Sklar's theorem):
+///
+/// ```text
+/// F(x) = C(F₁(x₁), ..., F_d(x_d))
+///
+/// f(x) = c(F₁(x₁), ..., F_d(x_d)) ⋅ Πᵢ fᵢ(xᵢ)
+/// ```
+///
+/// where Fᵢ and fᵢ are the CDF and PDF of the i-th marginal and C and c are the copula
+/// distribution and density functions.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::Matrix;
+/// use russell_stat::*;
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     // dependent load and resistance
+///     let load = DistributionGumbel::new(10.0, 2.0)?;
+///     let resistance = DistributionLognormal::new_from_mu_sig(20.0, 2.0)?;
+///     let rho = Matrix::from(&[[1.0, 0.5], [0.5, 1.0]]);
+///     let copula = CopulaGaussian::new(&rho)?;
+///     let joint = JointDistribution::new(vec![Box::new(load), Box::new(resistance)], copula)?;
+///     assert_eq!(joint.ndim(), 2);
+///     assert!(joint.pdf(&[10.0, 20.0])? > 0.0);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+pub struct JointDistribution<C: Copula> {
+    marginals: Vec<Box<dyn ProbabilityDistribution>>, // marginal distributions
+    copula: C,                                        // dependence structure
+}
+
+impl<C: Copula> JointDistribution<C> {
+    /// Creates a new joint distribution
+    ///
+    /// # Input
+    ///
+    /// * `marginals` -- the marginal distributions; must have length equal to `copula.ndim()`
+    /// * `copula` -- the copula describing the dependence structure
+    pub fn new(marginals: Vec<Box<dyn ProbabilityDistribution>>, copula: C) -> Result<Self, Error> {
+        if marginals.len() != copula.ndim() {
+            return Err(Error::BadDimensions(
+                "the number of marginals must equal the number of dimensions of the copula",
+            ));
+        }
+        Ok(JointDistribution { marginals, copula })
+    }
+
+    /// Returns the number of dimensions
+    pub fn ndim(&self) -> usize {
+        self.marginals.len()
+    }
+
+    /// Returns the marginal distributions
+    pub fn get_marginals(&self) -> &Vec<Box<dyn ProbabilityDistribution>> {
+        &self.marginals
+    }
+
+    /// Returns the copula
+    pub fn get_copula(&self) -> &C {
+        &self.copula
+    }
+
+    /// Implements the joint Probability Density Function
+    ///
+    /// # Input
+    ///
+    /// * `x` -- the values of the random variables; must have length equal to `ndim`
+    pub fn pdf(&self, x: &[f64]) -> Result<f64, Error> {
+        if x.len() != self.marginals.len() {
+            return Err(Error::BadDimensions("x.len() must equal ndim"));
+        }
+        let mut u = vec![0.0; x.len()];
+        let mut prod = 1.0;
+        for ((ui, xi), marginal) in u.iter_mut().zip(x).zip(&self.marginals) {
+            *ui = marginal.cdf(*xi);
+            prod *= marginal.pdf(*xi);
+        }
+        if prod == 0.0 {
+            return Ok(0.0);
+        }
+        Ok(self.copula.pdf(&u)? * prod)
+    }
+
+    /// Generates a pseudo-random point belonging to this joint distribution
+    ///
+    /// # Output
+    ///
+    /// * `x` -- the values of the random variables; must have length equal to `ndim`
+    pub fn sample<R: Rng + ?Sized>(&self, x: &mut [f64], rng: &mut R) -> Result<(), Error> {
+        if x.len() != self.marginals.len() {
+            return Err(Error::BadDimensions("x.len() must equal ndim"));
+        }
+        self.copula.sample(x, rng)?;
+        for (xi, marginal) in x.iter_mut().zip(&self.marginals) {
+            *xi = marginal.inverse_cdf(*xi);
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(unused_imports))]
+mod tests {
+    use super::JointDistribution;
+    use crate::{statistics, CopulaClayton, CopulaGaussian, DistributionNormal, DistributionUniform, Error};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use russell_chk::approx_eq;
+    use russell_lab::math::PI;
+    use russell_lab::Matrix;
+
+    #[test]
+    fn new_captures_errors() {
+        let copula = CopulaClayton::new(2, 1.0).unwrap();
+        let d = DistributionUniform::new(0.0, 1.0).unwrap();
+        assert_eq!(
+            JointDistribution::new(vec![Box::new(d)], copula).err(),
+            Some(Error::BadDimensions(
+                "the number of marginals must equal the number of dimensions of the copula"
+            ))
+        );
+        let copula = CopulaClayton::new(2, 1.0).unwrap();
+        let d1 = DistributionUniform::new(0.0, 1.0).unwrap();
+        let d2 = DistributionUniform::new(0.0, 1.0).unwrap();
+        let joint = JointDistribution::new(vec![Box::new(d1), Box::new(d2)], copula).unwrap();
+        let mut x = vec![0.0; 3];
+        let mut rng = StdRng::seed_from_u64(1234);
+        assert_eq!(
+            joint.pdf(&x).err(),
+            Some(Error::BadDimensions("x.len() must equal ndim"))
+        );
+        assert_eq!(
+            joint.sample(&mut x, &mut rng).err(),
+            Some(Error::BadDimensions("x.len() must equal ndim"))
+        );
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn pdf_works() {
+        // Gaussian copula with normal marginals = bivariate normal distribution
+        let (mu1, sig1, mu2, sig2, r) = (1.0, 2.0, -1.0, 0.5, 0.6);
+        let rho = Matrix::from(&[[1.0, r], [r, 1.0]]);
+        let copula = CopulaGaussian::new(&rho).unwrap();
+        let d1 = DistributionNormal::new(mu1, sig1).unwrap();
+        let d2 = DistributionNormal::new(mu2, sig2).unwrap();
+        let joint = JointDistribution::new(vec![Box::new(d1), Box::new(d2)], copula).unwrap();
+        assert_eq!(joint.ndim(), 2);
+        assert_eq!(joint.get_marginals().len(), 2);
+        assert_eq!(joint.get_copula().get_cholesky_factor().dims(), (2, 2));
+        for (x, y) in [(1.0, -1.0), (0.0, -0.5), (3.0, -1.2), (-2.0, 0.1)] {
+            let (zx, zy) = ((x - mu1) / sig1, (y - mu2) / sig2);
+            let q = (zx * zx - 2.0 * r * zx * zy + zy * zy) / (1.0 - r * r);
+            let f = f64::exp(-q / 2.0) / (2.0 * PI * sig1 * sig2 * f64::sqrt(1.0 - r * r));
+            approx_eq(joint.pdf(&[x, y]).unwrap(), f, 1e-12);
+        }
+
+        // outside the support
+        let copula = CopulaClayton::new(2, 1.0).unwrap();
+        let d1 = DistributionUniform::new(0.0, 1.0).unwrap();
+        let d2 = DistributionUniform::new(0.0, 1.0).unwrap();
+        let joint = JointDistribution::new(vec![Box::new(d1), Box::new(d2)], copula).unwrap();
+        assert_eq!(joint.pdf(&[2.0, 0.5]).unwrap(), 0.0);
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn sample_works() {
+        let r = 0.7;
+        let rho = Matrix::from(&[[1.0, r], [r, 1.0]]);
+        let copula = CopulaGaussian::new(&rho).unwrap();
+        let d1 = DistributionNormal::new(10.0, 2.0).unwrap();
+        let d2 = DistributionUniform::new(-1.0, 1.0).unwrap();
+        let joint = JointDistribution::new(vec![Box::new(d1), Box::new(d2)], copula).unwrap();
+        let mut rng = StdRng::seed_from_u64(1234);
+        let n = 10000;
+        let mut x = vec![0.0; 2];
+        let mut xx = vec![0.0; n];
+        let mut yy = vec![0.0; n];
+        for i in 0..n {
+            joint.sample(&mut x, &mut rng).unwrap();
+            assert!(x[1] >= -1.0 && x[1] <= 1.0);
+            xx[i] = x[0];
+            yy[i] = x[1];
+        }
+        let sx = statistics(&xx);
+        let sy = statistics(&yy);
+        approx_eq(sx.mean, 10.0, 0.05);
+        approx_eq(sx.std_dev, 2.0, 0.05);
+        approx_eq(sy.mean, 0.0, 0.02);
+        let mut cov = 0.0;
+        for i in 0..n {
+            cov += (xx[i] - sx.mean) * (yy[i] - sy.mean);
+        }
+        cov /= (n - 1) as f64;
+        assert!(cov / (sx.std_dev * sy.std_dev) > 0.6);
+    }
+}
@@ -5,21 +5,51 @@
 /// Defines a type alias for the error type as a static string
 pub type StrError = &'static str;
 
+mod copula;
+mod copula_clayton;
+mod copula_gaussian;
+mod copula_gumbel;
 mod distribution_frechet;
 mod distribution_gumbel;
 mod distribution_lognormal;
 mod distribution_normal;
+mod distribution_student_t;
 mod distribution_uniform;
+mod error;
+mod generator;
 mod histogram;
+mod joint_distribution;
+mod linear_regression;
+mod nataf;
 mod probability_distribution;
+mod probability_plot;
+mod quasi_random;
+mod sampling;
+mod sequence_halton;
+mod sequence_sobol;
 mod statistics;
+pub use crate::copula::*;
+pub use crate::copula_clayton::*;
+pub use crate::copula_gaussian::*;
+pub use crate::copula_gumbel::*;
 pub use crate::distribution_frechet::*;
 pub use crate::distribution_gumbel::*;
 pub use crate::distribution_lognormal::*;
 pub use crate::distribution_normal::*;
+pub use crate::distribution_student_t::*;
 pub use crate::distribution_uniform::*;
+pub use crate::error::*;
+pub use crate::generator::*;
 pub use crate::histogram::*;
+pub use crate::joint_distribution::*;
+pub use crate::linear_regression::*;
+pub use crate::nataf::*;
 pub use crate::probability_distribution::*;
+pub use crate::probability_plot::*;
+pub use crate::quasi_random::*;
+pub use crate::sampling::*;
+pub use crate::sequence_halton::*;
+pub use crate::sequence_sobol::*;
 pub use crate::statistics::*;
 
 // run code from README file
@@ -0,0 +1,560 @@
+use crate::{DistributionStudentT, Error, ProbabilityDistribution};
+use russell_lab::{mat_qr, Matrix};
+
+/// Holds the results of an ordinary least-squares (OLS) linear regression
+///
+/// The model is `y = X⋅β + ε` where X is the (n × p) design matrix, β are the p coefficients,
+/// and ε are independent errors with zero mean and constant variance σ².
+///
+/// The coefficients are computed by the QR decomposition of the design matrix (see [russell_lab::mat_qr]),
+/// thus the normal equations (XᵀX)⋅β = Xᵀy are never formed.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::Matrix;
+/// use russell_stat::{LinearRegression, StrError};
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     // fit y = a + b⋅x
+///     let x = Matrix::from(&[[1.0], [2.0], [3.0], [4.0], [5.0]]);
+///     let y = [2.1, 3.9, 6.2, 7.8, 10.1];
+///     let reg = LinearRegression::new(&x, &y, true)?;
+///     assert_eq!(format!("{:.2?}", reg.coefficients), "[0.05, 1.99]");
+///     assert!(reg.r_squared > 0.99);
+///
+///     // prediction with 95% prediction interval
+///     let y_new = reg.predict(&[6.0])?;
+///     let (lower, upper) = reg.prediction_interval(&[6.0], 0.95)?;
+///     assert!(lower < y_new && y_new < upper);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+pub struct LinearRegression {
+    /// Estimated coefficients β (the first one is the intercept, if any)
+    pub coefficients: Vec<f64>,
+
+    /// Standard errors of the coefficients
+    pub std_errors: Vec<f64>,
+
+    /// t-statistics of the coefficients (βⱼ / se(βⱼ))
+    pub t_statistics: Vec<f64>,
+
+    /// Two-sided p-values of the t-statistics (null hypothesis βⱼ = 0)
+    pub p_values: Vec<f64>,
+
+    /// Coefficient of determination R²
+    ///
+    /// Without intercept, R² is computed with respect to zero (uncentered total sum of squares).
+    pub r_squared: f64,
+
+    /// Adjusted coefficient of determination
+    pub adjusted_r_squared: f64,
+
+    /// Residual standard error σ̂ = √(SSE / dof)
+    pub residual_std_error: f64,
+
+    /// Degrees of freedom of the residuals (n - p)
+    pub dof: usize,
+
+    /// Fitted values ŷ = X⋅β
+    pub fitted: Vec<f64>,
+
+    /// Residuals e = y - ŷ
+    pub residuals: Vec<f64>,
+
+    /// Leverages (diagonal of the hat matrix H = X⋅(XᵀX)⁻¹⋅Xᵀ)
+    pub leverages: Vec<f64>,
+
+    /// Standardized (internally studentized) residuals eᵢ / (σ̂ √(1 - hᵢ))
+    pub standardized_residuals: Vec<f64>,
+
+    /// Cook's distances
+    pub cooks_distances: Vec<f64>,
+
+    /// Durbin-Watson statistic (values near 2 indicate no autocorrelation of the residuals)
+    pub durbin_watson: f64,
+
+    intercept: bool,              // the model includes an intercept
+    cov_unscaled: Matrix,         // (XᵀX)⁻¹
+    t_dist: DistributionStudentT, // distribution of the t-statistics
+}
+
+impl LinearRegression {
+    /// Performs the linear regression
+    ///
+    /// # Input
+    ///
+    /// * `x` -- the (n × k) matrix of regressors (one row per observation)
+    /// * `y` -- the n observed responses
+    /// * `intercept` -- adds a column of ones to the design matrix; i.e., p = k + 1
+    pub fn new(x: &Matrix, y: &[f64], intercept: bool) -> Result<Self, Error> {
+        let (n, k) = x.dims();
+        if y.len() != n {
+            return Err(Error::BadDimensions("y.len() must equal the number of rows of x"));
+        }
+        let offset = if intercept { 1 } else { 0 };
+        let p = k + offset;
+        if p == 0 {
+            return Err(Error::InvalidParameters("the model must have at least one coefficient"));
+        }
+        if n <= p {
+            return Err(Error::InvalidParameters(
+                "the number of observations must be greater than the number of coefficients",
+            ));
+        }
+
+        // design matrix
+        let mut design = Matrix::new(n, p);
+        for i in 0..n {
+            if intercept {
+                design.set(i, 0, 1.0);
+            }
+            for j in 0..k {
+                design.set(i, j + offset, x.get(i, j));
+            }
+        }
+
+        // economy QR decomposition: X = Q⋅R with Q (n × p) and R (p × p)
+        let mut q = Matrix::new(n, p);
+        let mut r = Matrix::new(p, p);
+        mat_qr(&mut q, &mut r, &design, true)?;
+
+        // check the rank
+        let max_diag = (0..p).fold(0.0, |acc, j| f64::max(acc, f64::abs(r.get(j, j))));
+        let tol = (n as f64) * f64::EPSILON * max_diag;
+        if (0..p).any(|j| f64::abs(r.get(j, j)) <= tol) {
+            return Err(Error::RankDeficient("the design matrix is rank deficient"));
+        }
+
+        // Qᵀ⋅y
+        let mut qty = vec![0.0; p];
+        for (j, v) in qty.iter_mut().enumerate() {
+            for (i, yi) in y.iter().enumerate() {
+                *v += q.get(i, j) * yi;
+            }
+        }
+
+        // coefficients: solve R⋅β = Qᵀ⋅y
+        let mut coefficients = vec![0.0; p];
+        for i in (0..p).rev() {
+            let mut sum = qty[i];
+            for (j, c) in coefficients.iter().enumerate().skip(i + 1) {
+                sum -= r.get(i, j) * c;
+            }
+            coefficients[i] = sum / r.get(i, i);
+        }
+
+        // R⁻¹ (upper triangular) and (XᵀX)⁻¹ = R⁻¹⋅R⁻ᵀ
+        let mut r_inv = Matrix::new(p, p);
+        for j in 0..p {
+            r_inv.set(j, j, 1.0 / r.get(j, j));
+            for i in (0..j).rev() {
+                let mut sum = 0.0;
+                for l in (i + 1)..(j + 1) {
+                    sum += r.get(i, l) * r_inv.get(l, j);
+                }
+                r_inv.set(i, j, -sum / r.get(i, i));
+            }
+        }
+        let mut cov_unscaled = Matrix::new(p, p);
+        for i in 0..p {
+            for j in 0..p {
+                let mut sum = 0.0;
+                for l in usize::max(i, j)..p {
+                    sum += r_inv.get(i, l) * r_inv.get(j, l);
+                }
+                cov_unscaled.set(i, j, sum);
+            }
+        }
+
+        // fitted values, residuals, and leverages (hᵢ = ‖R⁻ᵀ⋅xᵢ‖²)
+        let mut fitted = vec![0.0; n];
+        let mut residuals = vec![0.0; n];
+        let mut leverages = vec![0.0; n];
+        for i in 0..n {
+            let mut yi = 0.0;
+            for (j, c) in coefficients.iter().enumerate() {
+                yi += design.get(i, j) * c;
+            }
+            fitted[i] = yi;
+            residuals[i] = y[i] - yi;
+            let mut hi = 0.0;
+            for j in 0..p {
+                let mut sum = 0.0;
+                for l in 0..(j + 1) {
+                    sum += design.get(i, l) * r_inv.get(l, j);
+                }
+                hi += sum * sum;
+            }
+            leverages[i] = hi;
+        }
+
+        // goodness of fit
+        let dof = n - p;
+        let sse: f64 = residuals.iter().map(|e| e * e).sum();
+        let y_mean = y.iter().sum::<f64>() / (n as f64);
+        let sst: f64 = if intercept {
+            y.iter().map(|v| (v - y_mean) * (v - y_mean)).sum()
+        } else {
+            y.iter().map(|v| v * v).sum()
+        };
+        let r_squared = 1.0 - sse / sst;
+        let adjusted_r_squared = 1.0 - (1.0 - r_squared) * ((n - offset) as f64) / (dof as f64);
+        let variance = sse / (dof as f64);
+        let residual_std_error = f64::sqrt(variance);
+
+        // inference on the coefficients
+        let t_dist = DistributionStudentT::new(dof as f64)?;
+        let mut std_errors = vec![0.0; p];
+        let mut t_statistics = vec![0.0; p];
+        let mut p_values = vec![0.0; p];
+        for j in 0..p {
+            std_errors[j] = residual_std_error * f64::sqrt(cov_unscaled.get(j, j));
+            t_statistics[j] = coefficients[j] / std_errors[j];
+            p_values[j] = 2.0 * t_dist.cdf(-f64::abs(t_statistics[j]));
+        }
+
+        // residual diagnostics
+        let mut standardized_residuals = vec![0.0; n];
+        let mut cooks_distances = vec![0.0; n];
+        for i in 0..n {
+            let ri = residuals[i] / (residual_std_error * f64::sqrt(1.0 - leverages[i]));
+            standardized_residuals[i] = ri;
+            cooks_distances[i] = ri * ri * leverages[i] / ((p as f64) * (1.0 - leverages[i]));
+        }
+        let mut dw_num = 0.0;
+        for i in 1..n {
+            dw_num += (residuals[i] - residuals[i - 1]) * (residuals[i] - residuals[i - 1]);
+        }
+
+        Ok(LinearRegression {
+            coefficients,
+            std_errors,
+            t_statistics,
+            p_values,
+            r_squared,
+            adjusted_r_squared,
+            residual_std_error,
+            dof,
+            fitted,
+            residuals,
+            leverages,
+            standardized_residuals,
+            cooks_distances,
+            durbin_watson: dw_num / sse,
+            intercept,
+            cov_unscaled,
+            t_dist,
+        })
+    }
+
+    /// Returns the estimated covariance matrix of the coefficients σ̂²⋅(XᵀX)⁻¹
+    pub fn get_covariance(&self) -> Matrix {
+        let (p, _) = self.cov_unscaled.dims();
+        let variance = self.residual_std_error * self.residual_std_error;
+        let mut cov = Matrix::new(p, p);
+        for i in 0..p {
+            for j in 0..p {
+                cov.set(i, j, variance * self.cov_unscaled.get(i, j));
+            }
+        }
+        cov
+    }
+
+    /// Predicts the (mean) response
+    ///
+    /// # Input
+    ///
+    /// * `x0` -- the k regressors (without the leading one corresponding to the intercept)
+    pub fn predict(&self, x0: &[f64]) -> Result<f64, Error> {
+        let d = self.design_row(x0)?;
+        Ok(d.iter().zip(&self.coefficients).map(|(a, b)| a * b).sum())
+    }
+
+    /// Computes the confidence intervals of the coefficients
+    ///
+    /// # Input
+    ///
+    /// * `level` -- the confidence level (e.g., 0.95)
+    ///
+    /// # Output
+    ///
+    /// Returns the (lower, upper) bounds of each coefficient
+    pub fn coefficient_intervals(&self, level: f64) -> Result<Vec<(f64, f64)>, Error> {
+        let t = self.critical_value(level)?;
+        Ok(self
+            .coefficients
+            .iter()
+            .zip(&self.std_errors)
+            .map(|(b, se)| (b - t * se, b + t * se))
+            .collect())
+    }
+
+    /// Computes the confidence interval of the mean response at x0
+    ///
+    /// # Input
+    ///
+    /// * `x0` -- the k regressors (without the leading one corresponding to the intercept)
+    /// * `level` -- the confidence level (e.g., 0.95)
+    pub fn confidence_interval(&self, x0: &[f64], level: f64) -> Result<(f64, f64), Error> {
+        self.interval(x0, level, 0.0)
+    }
+
+    /// Computes the prediction interval of a new observation at x0
+    ///
+    /// # Input
+    ///
+    /// * `x0` -- the k regressors (without the leading one corresponding to the intercept)
+    /// * `level` -- the confidence level (e.g., 0.95)
+    pub fn prediction_interval(&self, x0: &[f64], level: f64) -> Result<(f64, f64), Error> {
+        self.interval(x0, level, 1.0)
+    }
+
+    /// Computes the confidence (extra = 0) or prediction (extra = 1) interval
+    fn interval(&self, x0: &[f64], level: f64, extra: f64) -> Result<(f64, f64), Error> {
+        let t = self.critical_value(level)?;
+        let d = self.design_row(x0)?;
+        let y0: f64 = d.iter().zip(&self.coefficients).map(|(a, b)| a * b).sum();
+        let mut quad = 0.0;
+        for (i, di) in d.iter().enumerate() {
+            for (j, dj) in d.iter().enumerate() {
+                quad += di * self.cov_unscaled.get(i, j) * dj;
+            }
+        }
+        let half = t * self.residual_std_error * f64::sqrt(extra + quad);
+        Ok((y0 - half, y0 + half))
+    }
+
+    /// Returns the quantile t(1 - α/2, dof) with α = 1 - level
+    fn critical_value(&self, level: f64) -> Result<f64, Error> {
+        if level <= 0.0 || level >= 1.0 {
+            return Err(Error::InvalidParameters("level must be in (0, 1)"));
+        }
+        Ok(self.t_dist.inverse_cdf(0.5 + level / 2.0))
+    }
+
+    /// Returns the row of the design matrix corresponding to x0
+    fn design_row(&self, x0: &[f64]) -> Result<Vec<f64>, Error> {
+        let offset = if self.intercept { 1 } else { 0 };
+        if x0.len() + offset != self.coefficients.len() {
+            return Err(Error::BadDimensions("x0.len() must equal the number of regressors"));
+        }
+        let mut d = vec![1.0; offset];
+        d.extend_from_slice(x0);
+        Ok(d)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(dead_code, unused_imports))]
+mod tests {
+    use super::LinearRegression;
+    use crate::Error;
+    use russell_chk::{approx_eq, vec_approx_eq};
+    use russell_lab::Matrix;
+
+    // Data: y ≈ b₀ + b₁ x₁ + b₂ x₂
+    fn data() -> (Matrix, Vec<f64>) {
+        #[rustfmt::skip]
+        let x = Matrix::from(&[
+            [1.0, 2.0],
+            [2.0, 1.0],
+            [3.0, 4.0],
+            [4.0, 3.0],
+            [5.0, 6.0],
+            [6.0, 5.0],
+            [7.0, 8.0],
+            [8.0, 9.0],
+        ]);
+        let y = vec![3.1, 3.9, 7.2, 7.8, 11.5, 11.9, 15.2, 17.8];
+        (x, y)
+    }
+
+    #[test]
+    fn new_captures_errors() {
+        let (x, y) = data();
+        assert_eq!(
+            LinearRegression::new(&x, &y[1..], true).err(),
+            Some(Error::BadDimensions("y.len() must equal the number of rows of x"))
+        );
+        let x0 = Matrix::new(3, 0);
+        assert_eq!(
+            LinearRegression::new(&x0, &[1.0, 2.0, 3.0], false).err(),
+            Some(Error::InvalidParameters("the model must have at least one coefficient"))
+        );
+        let x2 = Matrix::from(&[[1.0, 2.0], [2.0, 1.0], [3.0, 4.0]]);
+        assert_eq!(
+            LinearRegression::new(&x2, &[1.0, 2.0, 3.0], true).err(),
+            Some(Error::InvalidParameters(
+                "the number of observations must be greater than the number of coefficients"
+            ))
+        );
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn methods_capture_errors() {
+        let (x, y) = data();
+        let x3 = Matrix::from(&[[1.0, 2.0], [2.0, 4.0], [3.0, 6.0], [4.0, 8.0]]);
+        assert_eq!(
+            LinearRegression::new(&x3, &[1.0, 2.0, 3.0, 4.0], false).err(),
+            Some(Error::RankDeficient("the design matrix is rank deficient"))
+        );
+        let reg = LinearRegression::new(&x, &y, true).unwrap();
+        assert_eq!(
+            reg.predict(&[1.0]).err(),
+            Some(Error::BadDimensions("x0.len() must equal the number of regressors"))
+        );
+        assert_eq!(
+            reg.coefficient_intervals(1.0).err(),
+            Some(Error::InvalidParameters("level must be in (0, 1)"))
+        );
+        assert_eq!(
+            reg.confidence_interval(&[1.0, 2.0], 0.0).err(),
+            Some(Error::InvalidParameters("level must be in (0, 1)"))
+        );
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn linear_regression_works() {
+        // Reference values computed with mpmath (normal equations in high precision)
+        let (x, y) = data();
+        let reg = LinearRegression::new(&x, &y, true).unwrap();
+        vec_approx_eq(
+            &reg.coefficients,
+            &[0.35882352941176465, 1.334313725490196, 0.7235294117647059],
+            1e-13,
+        );
+        vec_approx_eq(
+            &reg.std_errors,
+            &[0.15676712772044362, 0.08563376144708995, 0.07449432243705323],
+            1e-14,
+        );
+        vec_approx_eq(
+            &reg.t_statistics,
+            &[2.288895220761076, 15.581631624515535, 9.71254436707011],
+            1e-11,
+        );
+        vec_approx_eq(
+            &reg.p_values,
+            &[0.07074695562740409, 1.9781634166963386e-5, 0.00019657823165363137],
+            1e-13,
+        );
+        assert_eq!(reg.dof, 5);
+        approx_eq(reg.r_squared, 0.9989411436059863, 1e-14);
+        approx_eq(reg.adjusted_r_squared, 0.9985176010483808, 1e-14);
+        approx_eq(reg.residual_std_error, 0.20107553940999324, 1e-14);
+        vec_approx_eq(
+            &reg.residuals,
+            &[
+                -0.04019607843137243,
+                0.14901960784313718,
+                -0.05588235294117631,
+                -0.06666666666666687,
+                0.12843137254901955,
+                -0.08235294117647031,
+                -0.2872549019607851,
+                0.2549019607843143,
+            ],
+            1e-13,
+        );
+        for ((f, e), yi) in reg.fitted.iter().zip(&reg.residuals).zip(&y) {
+            approx_eq(f + e, *yi, 1e-14);
+        }
+        vec_approx_eq(
+            &reg.leverages,
+            &[
+                0.553921568627451,
+                0.43137254901960784,
+                0.27941176470588235,
+                0.3333333333333333,
+                0.20098039215686275,
+                0.43137254901960784,
+                0.31862745098039216,
+                0.45098039215686275,
+            ],
+            1e-14,
+        );
+        vec_approx_eq(
+            &reg.standardized_residuals,
+            &[
+                -0.29930834812555656,
+                0.9828115753261486,
+                -0.32739486074393985,
+                -0.40606459807271295,
+                0.7145508875150042,
+                -0.5431327126802384,
+                -1.7306764725247723,
+                1.7108817990773948,
+            ],
+            1e-12,
+        );
+        vec_approx_eq(
+            &reg.cooks_distances,
+            &[
+                0.03708117238137135,
+                0.24425527628840746,
+                0.013854153074757358,
+                0.027481409634658985,
+                0.042809615142715104,
+                0.07459596734292733,
+                0.46688409692038674,
+                0.8014723833277259,
+            ],
+            1e-12,
+        );
+        approx_eq(reg.durbin_watson, 2.455102603602062, 1e-12);
+
+        // covariance
+        let cov = reg.get_covariance();
+        approx_eq(f64::sqrt(cov.get(1, 1)), reg.std_errors[1], 1e-15);
+        approx_eq(cov.get(1, 2), cov.get(2, 1), 1e-15);
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn intervals_work() {
+        let (x, y) = data();
+        let reg = LinearRegression::new(&x, &y, true).unwrap();
+        let x0 = [4.5, 5.0];
+        approx_eq(reg.predict(&x0).unwrap(), 9.980882352941176, 1e-13);
+        let (lower, upper) = reg.confidence_interval(&x0, 0.95).unwrap();
+        approx_eq(lower, 9.791970657210541, 1e-12);
+        approx_eq(upper, 10.169794048671812, 1e-12);
+        let (lower, upper) = reg.prediction_interval(&x0, 0.95).unwrap();
+        approx_eq(lower, 9.43056087370842, 1e-12);
+        approx_eq(upper, 10.531203832173934, 1e-12);
+        let bounds = reg.coefficient_intervals(0.95).unwrap();
+        let correct = [
+            (-0.044159201531286048, 0.7618062603548154),
+            (1.1141851337970932, 1.554442317183299),
+            (0.532035659649982, 0.9150231638794297),
+        ];
+        for (b, c) in bounds.iter().zip(&correct) {
+            approx_eq(b.0, c.0, 1e-12);
+            approx_eq(b.1, c.1, 1e-12);
+        }
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn regression_without_intercept_works() {
+        let (x, y) = data();
+        let mut x1 = Matrix::new(8, 1);
+        for i in 0..8 {
+            x1.set(i, 0, x.get(i, 0));
+        }
+        let reg = LinearRegression::new(&x1, &y, false).unwrap();
+        assert_eq!(reg.dof, 7);
+        approx_eq(reg.coefficients[0], 2.1637254901960784, 1e-14);
+        approx_eq(reg.r_squared, 0.9956511731918488, 1e-14);
+        approx_eq(reg.std_errors[0], 0.05404874231500693, 1e-14);
+        approx_eq(reg.predict(&[2.0]).unwrap(), 2.0 * 2.1637254901960784, 1e-14);
+    }
+}
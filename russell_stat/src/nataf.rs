@@ -0,0 +1,503 @@
+use crate::copula_gaussian::check_correlation_matrix;
+use crate::distribution_normal::{std_normal_cdf, std_normal_inverse_cdf, std_normal_pdf};
+use crate::{Error, ProbabilityDistribution};
+use rand::Rng;
+use rand_distr::{Distribution, StandardNormal};
+use russell_lab::math::{PI, SQRT_2};
+use russell_lab::{mat_cholesky, Matrix};
+
+/// Defines the number of Gauss-Hermite points used to compute the correlation distortion
+///
+/// The largest node maps to z ≈ 7.6, thus Φ(z) remains distinguishable from one.
+const NATAF_NPOINT: usize = 20;
+
+/// Defines the maximum number of iterations used to compute the correlation distortion
+const NATAF_MAX_ITERATIONS: usize = 100;
+
+/// Defines the tolerance used to compute the correlation distortion
+const NATAF_TOLERANCE: f64 = 1e-12;
+
+/// Implements the Nataf transformation
+///
+/// Maps correlated (non-Gaussian) random variables `x` to independent standard normal variables `u`:
+///
+/// ```text
+/// zᵢ = Φ⁻¹(Fᵢ(xᵢ))     (correlated standard normal variables)
+/// u  = L⁻¹ ⋅ z         (independent standard normal variables)
+/// ```
+///
+/// where Fᵢ is the CDF of the i-th marginal, Φ is the CDF of the standard normal distribution, and
+/// L is the lower-triangular Cholesky factor of the correlation matrix in the z-space (ρz = L⋅Lᵀ).
+///
+/// The correlation matrix ρz differs from the correlation matrix ρx of the original variables
+/// (correlation distortion). Each coefficient of ρz is computed such that
+///
+/// ```text
+///         ∞  ∞  (xᵢ - μᵢ) (xⱼ - μⱼ)
+/// ρxᵢⱼ =  ∫  ∫  ————————————————— φ₂(zᵢ, zⱼ, ρzᵢⱼ) dzᵢ dzⱼ
+///        -∞ -∞        σᵢ σⱼ
+/// ```
+///
+/// where φ₂ is the bivariate standard normal PDF. The integral is computed by Gauss-Hermite
+/// quadrature and the equation is solved by bisection.
+///
+/// # Reference
+///
+/// * Liu PL and Der Kiureghian A (1986) Multivariate distribution models with prescribed marginals
+///   and covariances, Probabilistic Engineering Mechanics, 1(2):105-112
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::Matrix;
+/// use russell_stat::*;
+///
+/// # #[cfg(not(feature = "pure_rust"))]
+/// fn main() -> Result<(), StrError> {
+///     let d1 = DistributionNormal::new(1.0, 2.0)?;
+///     let d2 = DistributionLognormal::new(0.0, 0.5)?;
+///     let corr_x = Matrix::from(&[[1.0, 0.5], [0.5, 1.0]]);
+///     let nataf = Nataf::new(vec![Box::new(d1), Box::new(d2)], &corr_x)?;
+///
+///     // forward and backward transformations
+///     let x = [2.0, 1.5];
+///     let mut u = vec![0.0; 2];
+///     let mut xx = vec![0.0; 2];
+///     nataf.x_to_u(&mut u, &x)?;
+///     nataf.u_to_x(&mut xx, &u)?;
+///     assert!(f64::abs(xx[0] - x[0]) < 1e-12);
+///     assert!(f64::abs(xx[1] - x[1]) < 1e-12);
+///
+///     // the correlation in the z-space is larger than 0.5
+///     assert!(nataf.get_corr_z().get(0, 1) > 0.5);
+///     Ok(())
+/// }
+/// # #[cfg(feature = "pure_rust")] fn main() {}
+/// ```
+pub struct Nataf {
+    marginals: Vec<Box<dyn ProbabilityDistribution>>, // marginal distributions
+    corr_z: Matrix,                                   // correlation matrix in the z-space
+    l: Matrix,                                        // lower-triangular Cholesky factor of corr_z
+}
+
+impl Nataf {
+    /// Creates a new Nataf transformation
+    ///
+    /// # Input
+    ///
+    /// * `marginals` -- the marginal distributions
+    /// * `corr_x` -- the (ndim × ndim) correlation matrix of the original variables
+    pub fn new(marginals: Vec<Box<dyn ProbabilityDistribution>>, corr_x: &Matrix) -> Result<Self, Error> {
+        let ndim = check_correlation_matrix(corr_x)?;
+        if marginals.len() != ndim {
+            return Err(Error::BadDimensions(
+                "the number of marginals must equal the dimension of the correlation matrix",
+            ));
+        }
+
+        // quantile functions evaluated at the quadrature points
+        let (zz, ww) = gauss_hermite(NATAF_NPOINT);
+        let mut hh = Vec::with_capacity(ndim); // normalized values (xᵢ - μᵢ) / σᵢ
+        let mut moments = Vec::with_capacity(ndim); // (μᵢ, σᵢ) computed by quadrature
+        for marginal in &marginals {
+            let h: Vec<f64> = zz.iter().map(|z| marginal.inverse_cdf(std_normal_cdf(*z))).collect();
+            let mean: f64 = h.iter().zip(&ww).map(|(v, w)| w * v).sum();
+            let var: f64 = h.iter().zip(&ww).map(|(v, w)| w * (v - mean) * (v - mean)).sum();
+            if !var.is_finite() || var <= 0.0 {
+                return Err(Error::InvalidParameters(
+                    "the marginal distributions must have finite and positive variances",
+                ));
+            }
+            let std = f64::sqrt(var);
+            hh.push(h.iter().map(|v| (v - mean) / std).collect::<Vec<_>>());
+            moments.push((mean, std));
+        }
+
+        // correlation distortion
+        let (z_min, z_max) = (zz[0], zz[NATAF_NPOINT - 1]);
+        let mut corr_z = Matrix::identity(ndim);
+        for (i, hi) in hh.iter().enumerate() {
+            for j in (i + 1)..ndim {
+                let target = corr_x.get(i, j);
+                if target == 0.0 {
+                    continue;
+                }
+                let (mean, std) = moments[j];
+                let marginal = &marginals[j];
+                let corr = |r: f64| {
+                    let s = f64::sqrt(f64::max(0.0, 1.0 - r * r));
+                    let mut sum = 0.0;
+                    for (a, (za, wa)) in zz.iter().zip(&ww).enumerate() {
+                        for (zb, wb) in zz.iter().zip(&ww) {
+                            // clamp z to the range of the nodes (such that Φ(z) < 1); the weight is negligible beyond it
+                            let z = f64::min(f64::max(r * za + s * zb, z_min), z_max);
+                            let xj = marginal.inverse_cdf(std_normal_cdf(z));
+                            sum += wa * wb * hi[a] * (xj - mean) / std;
+                        }
+                    }
+                    sum
+                };
+                let (mut lo, mut up) = (-1.0, 1.0);
+                if target < corr(lo) - NATAF_TOLERANCE || target > corr(up) + NATAF_TOLERANCE {
+                    return Err(Error::InvalidCorrelation(
+                        "the correlation cannot be attained with the given marginals",
+                    ));
+                }
+                for _ in 0..NATAF_MAX_ITERATIONS {
+                    let r = (lo + up) / 2.0;
+                    if corr(r) < target {
+                        lo = r;
+                    } else {
+                        up = r;
+                    }
+                    if up - lo < NATAF_TOLERANCE {
+                        break;
+                    }
+                }
+                let r = (lo + up) / 2.0;
+                corr_z.set(i, j, r);
+                corr_z.set(j, i, r);
+            }
+        }
+
+        // Cholesky factorization
+        let mut l = Matrix::new(ndim, ndim);
+        mat_cholesky(&mut l, &corr_z)?;
+        Ok(Nataf { marginals, corr_z, l })
+    }
+
+    /// Returns the number of dimensions
+    pub fn ndim(&self) -> usize {
+        self.marginals.len()
+    }
+
+    /// Returns the correlation matrix in the z-space (correlated standard normal variables)
+    pub fn get_corr_z(&self) -> &Matrix {
+        &self.corr_z
+    }
+
+    /// Returns the lower-triangular Cholesky factor L of the correlation matrix in the z-space
+    pub fn get_cholesky_factor(&self) -> &Matrix {
+        &self.l
+    }
+
+    /// Maps the original variables to independent standard normal variables
+    ///
+    /// # Output
+    ///
+    /// * `u` -- the independent standard normal variables
+    ///
+    /// # Input
+    ///
+    /// * `x` -- the original (correlated) variables
+    pub fn x_to_u(&self, u: &mut [f64], x: &[f64]) -> Result<(), Error> {
+        let n = self.marginals.len();
+        if u.len() != n || x.len() != n {
+            return Err(Error::BadDimensions("u.len() and x.len() must equal ndim"));
+        }
+        // solve L⋅u = z by forward substitution
+        let mut w = vec![0.0; n];
+        for (i, (marginal, xi)) in self.marginals.iter().zip(x).enumerate() {
+            let mut sum = std_normal_inverse_cdf(marginal.cdf(*xi));
+            for (k, wk) in w.iter().enumerate().take(i) {
+                sum -= self.l.get(i, k) * wk;
+            }
+            w[i] = sum / self.l.get(i, i);
+        }
+        u.copy_from_slice(&w);
+        Ok(())
+    }
+
+    /// Maps independent standard normal variables to the original variables
+    ///
+    /// # Output
+    ///
+    /// * `x` -- the original (correlated) variables
+    ///
+    /// # Input
+    ///
+    /// * `u` -- the independent standard normal variables
+    pub fn u_to_x(&self, x: &mut [f64], u: &[f64]) -> Result<(), Error> {
+        let n = self.marginals.len();
+        if u.len() != n || x.len() != n {
+            return Err(Error::BadDimensions("u.len() and x.len() must equal ndim"));
+        }
+        for (i, xi) in x.iter_mut().enumerate() {
+            let mut z = 0.0;
+            for (k, uk) in u.iter().enumerate().take(i + 1) {
+                z += self.l.get(i, k) * uk;
+            }
+            *xi = self.marginals[i].inverse_cdf(std_normal_cdf(z));
+        }
+        Ok(())
+    }
+
+    /// Computes the Jacobian matrix of the mapping from u to x
+    ///
+    /// ```text
+    ///        ∂xᵢ    φ(zᵢ)
+    /// Jᵢⱼ = ——— = ——————— Lᵢⱼ
+    ///        ∂uⱼ   fᵢ(xᵢ)
+    /// ```
+    ///
+    /// # Output
+    ///
+    /// * `jac` -- the (ndim × ndim) Jacobian matrix ∂x/∂u
+    ///
+    /// # Input
+    ///
+    /// * `u` -- the independent standard normal variables
+    pub fn jacobian(&self, jac: &mut Matrix, u: &[f64]) -> Result<(), Error> {
+        let n = self.marginals.len();
+        if u.len() != n {
+            return Err(Error::BadDimensions("u.len() must equal ndim"));
+        }
+        if jac.dims() != (n, n) {
+            return Err(Error::BadDimensions("the Jacobian matrix must be (ndim × ndim)"));
+        }
+        for i in 0..n {
+            let mut z = 0.0;
+            for (k, uk) in u.iter().enumerate().take(i + 1) {
+                z += self.l.get(i, k) * uk;
+            }
+            let xi = self.marginals[i].inverse_cdf(std_normal_cdf(z));
+            let factor = std_normal_pdf(z) / self.marginals[i].pdf(xi);
+            for j in 0..n {
+                jac.set(i, j, if j <= i { factor * self.l.get(i, j) } else { 0.0 });
+            }
+        }
+        Ok(())
+    }
+
+    /// Generates a pseudo-random point belonging to the joint distribution
+    ///
+    /// # Output
+    ///
+    /// * `x` -- the original (correlated) variables
+    pub fn sample<R: Rng + ?Sized>(&self, x: &mut [f64], rng: &mut R) -> Result<(), Error> {
+        let u: Vec<f64> = (0..self.marginals.len()).map(|_| StandardNormal.sample(rng)).collect();
+        self.u_to_x(x, &u)
+    }
+}
+
+/// Computes the Gauss-Hermite nodes and weights for the expectation with respect to the standard normal distribution
+///
+/// Returns `(z, w)` such that `E[g(Z)] ≈ Σₖ wₖ g(zₖ)` with `Σₖ wₖ = 1`.
+///
+/// The nodes of the (physicists') Hermite polynomial are computed by Newton's method
+/// as in Numerical Recipes (gauher).
+fn gauss_hermite(n: usize) -> (Vec<f64>, Vec<f64>) {
+    let pim4 = 1.0 / f64::powf(PI, 0.25);
+    let mut x = vec![0.0; n];
+    let mut w = vec![0.0; n];
+    let m = n.div_ceil(2);
+    let nf = n as f64;
+    let mut z = 0.0;
+    for i in 0..m {
+        z = match i {
+            0 => f64::sqrt(2.0 * nf + 1.0) - 1.85575 * f64::powf(2.0 * nf + 1.0, -0.16667),
+            1 => z - 1.14 * f64::powf(nf, 0.426) / z,
+            2 => 1.86 * z - 0.86 * x[0],
+            3 => 1.91 * z - 0.91 * x[1],
+            _ => 2.0 * z - x[i - 2],
+        };
+        let mut pp = 0.0;
+        for _ in 0..NATAF_MAX_ITERATIONS {
+            let (mut p1, mut p2) = (pim4, 0.0);
+            for j in 1..(n + 1) {
+                let jf = j as f64;
+                let p3 = p2;
+                p2 = p1;
+                p1 = z * f64::sqrt(2.0 / jf) * p2 - f64::sqrt((jf - 1.0) / jf) * p3;
+            }
+            pp = f64::sqrt(2.0 * nf) * p2;
+            let z_old = z;
+            z = z_old - p1 / pp;
+            if f64::abs(z - z_old) <= 1e-15 * (1.0 + f64::abs(z)) {
+                break;
+            }
+        }
+        x[i] = z;
+        x[n - 1 - i] = -z;
+        w[i] = 2.0 / (pp * pp);
+        w[n - 1 - i] = w[i];
+    }
+    let sqrt_pi = f64::sqrt(PI);
+    let zz = x.iter().rev().map(|v| SQRT_2 * v).collect();
+    let ww = w.iter().rev().map(|v| v / sqrt_pi).collect();
+    (zz, ww)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+#[cfg_attr(feature = "pure_rust", allow(unused_imports))]
+mod tests {
+    use super::{gauss_hermite, Nataf};
+    use crate::{
+        statistics, DistributionGumbel, DistributionLognormal, DistributionNormal, DistributionUniform, Error,
+    };
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use russell_chk::{approx_eq, vec_approx_eq};
+    use russell_lab::Matrix;
+
+    #[test]
+    fn gauss_hermite_works() {
+        let (z, w) = gauss_hermite(20);
+        approx_eq(w.iter().sum::<f64>(), 1.0, 1e-14);
+        // moments of the standard normal distribution: E[Z²] = 1, E[Z⁴] = 3, E[Z⁶] = 15
+        let m = |p: i32| z.iter().zip(&w).map(|(z, w)| w * f64::powi(*z, p)).sum::<f64>();
+        approx_eq(m(1), 0.0, 1e-14);
+        approx_eq(m(2), 1.0, 1e-13);
+        approx_eq(m(4), 3.0, 1e-12);
+        approx_eq(m(6), 15.0, 1e-11);
+        assert!(z[0] < z[19]);
+        let (z, w) = gauss_hermite(3);
+        vec_approx_eq(&z, &[-f64::sqrt(3.0), 0.0, f64::sqrt(3.0)], 1e-14);
+        vec_approx_eq(&w, &[1.0 / 6.0, 2.0 / 3.0, 1.0 / 6.0], 1e-14);
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn new_captures_errors() {
+        let corr_x = Matrix::from(&[[1.0, 0.5], [0.5, 1.0]]);
+        let d = DistributionNormal::new(0.0, 1.0).unwrap();
+        assert_eq!(
+            Nataf::new(vec![Box::new(d)], &corr_x).err(),
+            Some(Error::BadDimensions(
+                "the number of marginals must equal the dimension of the correlation matrix"
+            ))
+        );
+        let corr_x = Matrix::from(&[[1.0, 0.5], [0.4, 1.0]]);
+        let d1 = DistributionNormal::new(0.0, 1.0).unwrap();
+        let d2 = DistributionNormal::new(0.0, 1.0).unwrap();
+        assert_eq!(
+            Nataf::new(vec![Box::new(d1), Box::new(d2)], &corr_x).err(),
+            Some(Error::InvalidCorrelation(
+                "the correlation matrix must be symmetric with entries in [-1, 1]"
+            ))
+        );
+        // lognormal variables with σ = 1 cannot have a correlation of -0.9
+        let corr_x = Matrix::from(&[[1.0, -0.9], [-0.9, 1.0]]);
+        let d1 = DistributionLognormal::new(0.0, 1.0).unwrap();
+        let d2 = DistributionLognormal::new(0.0, 1.0).unwrap();
+        assert_eq!(
+            Nataf::new(vec![Box::new(d1), Box::new(d2)], &corr_x).err(),
+            Some(Error::InvalidCorrelation(
+                "the correlation cannot be attained with the given marginals"
+            ))
+        );
+        let corr_x = Matrix::from(&[[1.0, 0.5], [0.5, 1.0]]);
+        let d1 = DistributionNormal::new(0.0, 1.0).unwrap();
+        let d2 = DistributionNormal::new(0.0, 1.0).unwrap();
+        let nataf = Nataf::new(vec![Box::new(d1), Box::new(d2)], &corr_x).unwrap();
+        let mut u = vec![0.0; 3];
+        let mut jac = Matrix::new(2, 3);
+        assert_eq!(
+            nataf.x_to_u(&mut u, &[0.0, 0.0]).err(),
+            Some(Error::BadDimensions("u.len() and x.len() must equal ndim"))
+        );
+        assert_eq!(
+            nataf.u_to_x(&mut u, &[0.0, 0.0]).err(),
+            Some(Error::BadDimensions("u.len() and x.len() must equal ndim"))
+        );
+        assert_eq!(
+            nataf.jacobian(&mut jac, &u).err(),
+            Some(Error::BadDimensions("u.len() must equal ndim"))
+        );
+        assert_eq!(
+            nataf.jacobian(&mut jac, &[0.0, 0.0]).err(),
+            Some(Error::BadDimensions("the Jacobian matrix must be (ndim × ndim)"))
+        );
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn normal_marginals_have_no_distortion() {
+        let corr_x = Matrix::from(&[[1.0, 0.3, -0.2], [0.3, 1.0, 0.6], [-0.2, 0.6, 1.0]]);
+        let d1 = DistributionNormal::new(1.0, 2.0).unwrap();
+        let d2 = DistributionNormal::new(-1.0, 0.5).unwrap();
+        let d3 = DistributionNormal::new(0.0, 3.0).unwrap();
+        let nataf = Nataf::new(vec![Box::new(d1), Box::new(d2), Box::new(d3)], &corr_x).unwrap();
+        assert_eq!(nataf.ndim(), 3);
+        vec_approx_eq(nataf.get_corr_z().as_data(), corr_x.as_data(), 1e-10);
+        assert_eq!(nataf.get_cholesky_factor().dims(), (3, 3));
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn lognormal_distortion_works() {
+        // closed-form: ρx = (exp(ρz σ₁ σ₂) - 1) / √((exp(σ₁²) - 1) (exp(σ₂²) - 1))
+        let (s1, s2) = (0.3, 0.6);
+        for rz in [-0.7, 0.2, 0.5, 0.9] {
+            let rx = (f64::exp(rz * s1 * s2) - 1.0) / f64::sqrt((f64::exp(s1 * s1) - 1.0) * (f64::exp(s2 * s2) - 1.0));
+            let corr_x = Matrix::from(&[[1.0, rx], [rx, 1.0]]);
+            let d1 = DistributionLognormal::new(1.0, s1).unwrap();
+            let d2 = DistributionLognormal::new(-0.5, s2).unwrap();
+            let nataf = Nataf::new(vec![Box::new(d1), Box::new(d2)], &corr_x).unwrap();
+            approx_eq(nataf.get_corr_z().get(0, 1), rz, 1e-8);
+        }
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn transformations_work() {
+        let corr_x = Matrix::from(&[[1.0, 0.4, 0.1], [0.4, 1.0, -0.3], [0.1, -0.3, 1.0]]);
+        let d1 = DistributionGumbel::new(10.0, 2.0).unwrap();
+        let d2 = DistributionUniform::new(-1.0, 3.0).unwrap();
+        let d3 = DistributionLognormal::new(0.0, 0.25).unwrap();
+        let nataf = Nataf::new(vec![Box::new(d1), Box::new(d2), Box::new(d3)], &corr_x).unwrap();
+        let x = [11.0, 0.5, 1.2];
+        let mut u = vec![0.0; 3];
+        let mut xx = vec![0.0; 3];
+        nataf.x_to_u(&mut u, &x).unwrap();
+        nataf.u_to_x(&mut xx, &u).unwrap();
+        vec_approx_eq(&xx, &x, 1e-10);
+
+        // compare the Jacobian with central differences
+        let mut jac = Matrix::new(3, 3);
+        nataf.jacobian(&mut jac, &u).unwrap();
+        let h = 1e-6;
+        let (mut xp, mut xm) = (vec![0.0; 3], vec![0.0; 3]);
+        for j in 0..3 {
+            let mut up = u.clone();
+            let mut um = u.clone();
+            up[j] += h;
+            um[j] -= h;
+            nataf.u_to_x(&mut xp, &up).unwrap();
+            nataf.u_to_x(&mut xm, &um).unwrap();
+            for i in 0..3 {
+                approx_eq(jac.get(i, j), (xp[i] - xm[i]) / (2.0 * h), 1e-7);
+            }
+        }
+    }
+
+    #[cfg(not(feature = "pure_rust"))]
+    #[test]
+    fn sample_works() {
+        let r = 0.6;
+        let corr_x = Matrix::from(&[[1.0, r], [r, 1.0]]);
+        let d1 = DistributionGumbel::new(10.0, 2.0).unwrap();
+        let d2 = DistributionLognormal::new(0.0, 0.5).unwrap();
+        let nataf = Nataf::new(vec![Box::new(d1), Box::new(d2)], &corr_x).unwrap();
+        let mut rng = StdRng::seed_from_u64(1234);
+        let n = 20000;
+        let mut x = vec![0.0; 2];
+        let mut xx = vec![0.0; n];
+        let mut yy = vec![0.0; n];
+        for i in 0..n {
+            nataf.sample(&mut x, &mut rng).unwrap();
+            xx[i] = x[0];
+            yy[i] = x[1];
+        }
+        let sx = statistics(&xx);
+        let sy = statistics(&yy);
+        let mut cov = 0.0;
+        for i in 0..n {
+            cov += (xx[i] - sx.mean) * (yy[i] - sy.mean);
+        }
+        cov /= (n - 1) as f64;
+        approx_eq(cov / (sx.std_dev * sy.std_dev), r, 0.02);
+    }
+}
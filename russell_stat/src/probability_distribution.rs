@@ -1,5 +1,26 @@
+use crate::distribution_normal::{std_normal_cdf, std_normal_pdf};
 use rand::Rng;
 
+/// Defines the maximum number of iterations used in the numerical inversion of the CDF
+const INV_CDF_MAX_ITERATIONS: usize = 200;
+
+/// Defines the tolerance used in the numerical inversion of the CDF
+const INV_CDF_TOLERANCE: f64 = 1e-14;
+
+/// Defines the number of intervals of the Simpson rule used in the numerical computation of expectations
+const EXPECTATION_NDIV: usize = 2000;
+
+/// Defines the half-width of the range of standard normal variables z ∈ [-Z, Z] used in the numerical computations
+///
+/// Note that Φ(8) < 1 in double precision.
+const EXPECTATION_ZMAX: f64 = 8.0;
+
+/// Defines the number of grid points used to search for the mode
+const MODE_NGRID: usize = 161;
+
+/// Defines the maximum number of iterations of the golden-section search for the mode
+const MODE_MAX_ITERATIONS: usize = 200;
+
 /// Defines the Probability Distribution trait
 pub trait ProbabilityDistribution {
     /// Implements the Probability Density Function (CDF)
@@ -15,5 +36,288 @@ pub trait ProbabilityDistribution {
     fn variance(&self) -> f64;
 
     /// Generates a pseudo-random number belonging to this probability distribution
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64;
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64
+    where
+        Self: Sized;
+
+    /// Implements the inverse of the Cumulative Density Function (quantile function)
+    ///
+    /// Returns `x` such that `cdf(x) = p`.
+    ///
+    /// # Note
+    ///
+    /// The default implementation brackets the solution starting from the mean and then
+    /// performs a bisection. Distributions with a closed-form expression should override it.
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        if p >= 1.0 {
+            return f64::INFINITY;
+        }
+
+        // starting point and step
+        let mean = self.mean();
+        let variance = self.variance();
+        let x0 = if mean.is_finite() { mean } else { 0.0 };
+        let mut step = if variance.is_finite() && variance > 0.0 {
+            f64::sqrt(variance)
+        } else {
+            1.0
+        };
+
+        // bracket the solution
+        let (mut a, mut b) = (x0, x0);
+        for _ in 0..INV_CDF_MAX_ITERATIONS {
+            if self.cdf(a) <= p {
+                break;
+            }
+            a -= step;
+            step *= 2.0;
+        }
+        for _ in 0..INV_CDF_MAX_ITERATIONS {
+            if self.cdf(b) >= p {
+                break;
+            }
+            b += step;
+            step *= 2.0;
+        }
+
+        // bisection
+        for _ in 0..INV_CDF_MAX_ITERATIONS {
+            let x = (a + b) / 2.0;
+            if self.cdf(x) < p {
+                a = x;
+            } else {
+                b = x;
+            }
+            if b - a <= INV_CDF_TOLERANCE * (1.0 + f64::abs(x)) {
+                break;
+            }
+        }
+        (a + b) / 2.0
+    }
+
+    /// Returns the Median
+    ///
+    /// The default implementation returns `inverse_cdf(0.5)`.
+    fn median(&self) -> f64 {
+        self.inverse_cdf(0.5)
+    }
+
+    /// Returns the Mode (the value corresponding to the maximum of the PDF)
+    ///
+    /// # Note
+    ///
+    /// The default implementation assumes a unimodal distribution. It searches for the maximum of the PDF
+    /// on a grid of quantiles and then refines the result by the golden-section search.
+    fn mode(&self) -> f64 {
+        // grid search
+        let dz = 2.0 * EXPECTATION_ZMAX / ((MODE_NGRID - 1) as f64);
+        let xx: Vec<f64> = (0..MODE_NGRID)
+            .map(|i| self.inverse_cdf(std_normal_cdf(-EXPECTATION_ZMAX + (i as f64) * dz)))
+            .collect();
+        let mut k = 0;
+        let mut f_max = f64::NEG_INFINITY;
+        for (i, x) in xx.iter().enumerate() {
+            let f = self.pdf(*x);
+            if f > f_max {
+                f_max = f;
+                k = i;
+            }
+        }
+
+        // golden-section search
+        let ratio = (f64::sqrt(5.0) - 1.0) / 2.0;
+        let mut a = xx[if k > 0 { k - 1 } else { k }];
+        let mut b = xx[if k + 1 < MODE_NGRID { k + 1 } else { k }];
+        let mut c = b - ratio * (b - a);
+        let mut d = a + ratio * (b - a);
+        for _ in 0..MODE_MAX_ITERATIONS {
+            if b - a <= INV_CDF_TOLERANCE * (1.0 + f64::abs(a) + f64::abs(b)) {
+                break;
+            }
+            if self.pdf(c) > self.pdf(d) {
+                b = d;
+            } else {
+                a = c;
+            }
+            c = b - ratio * (b - a);
+            d = a + ratio * (b - a);
+        }
+        (a + b) / 2.0
+    }
+
+    /// Returns the Skewness E[(X - μ)³] / σ³
+    ///
+    /// # Note
+    ///
+    /// The default implementation computes the expectation numerically and returns NaN if
+    /// the mean or the variance is not finite.
+    fn skewness(&self) -> f64 {
+        let mean = self.mean();
+        let sig = f64::sqrt(self.variance());
+        if !mean.is_finite() || !sig.is_finite() || sig <= 0.0 {
+            return f64::NAN;
+        }
+        numerical_expectation(self, |x| f64::powi((x - mean) / sig, 3))
+    }
+
+    /// Returns the (differential) Entropy -E[ln f(X)] in nats
+    ///
+    /// # Note
+    ///
+    /// The default implementation computes the expectation numerically.
+    fn entropy(&self) -> f64 {
+        -numerical_expectation(self, |x| {
+            let f = self.pdf(x);
+            if f > 0.0 {
+                f64::ln(f)
+            } else {
+                0.0
+            }
+        })
+    }
+}
+
+/// Computes the expectation E[g(X)] numerically
+///
+/// Uses the change of variables x = F⁻¹(Φ(z)), where Φ is the CDF of the standard normal distribution:
+///
+/// ```text
+///           ∞
+/// E[g(X)] = ∫ g(F⁻¹(Φ(z))) φ(z) dz
+///          -∞
+/// ```
+///
+/// The integral is computed by the composite Simpson rule over z ∈ [-Z, Z].
+fn numerical_expectation<D, G>(dist: &D, g: G) -> f64
+where
+    D: ProbabilityDistribution + ?Sized,
+    G: Fn(f64) -> f64,
+{
+    let h = 2.0 * EXPECTATION_ZMAX / (EXPECTATION_NDIV as f64);
+    let mut sum = 0.0;
+    for i in 0..(EXPECTATION_NDIV + 1) {
+        let z = -EXPECTATION_ZMAX + (i as f64) * h;
+        let weight = if i == 0 || i == EXPECTATION_NDIV {
+            1.0
+        } else if i % 2 == 1 {
+            4.0
+        } else {
+            2.0
+        };
+        sum += weight * g(dist.inverse_cdf(std_normal_cdf(z))) * std_normal_pdf(z);
+    }
+    sum * h / 3.0
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::ProbabilityDistribution;
+    use crate::{DistributionFrechet, DistributionGumbel, DistributionLognormal, DistributionNormal};
+    use rand::Rng;
+    use russell_chk::approx_eq;
+
+    // Exponential distribution with rate λ = 2 (uses the default inverse_cdf)
+    struct Exponential {}
+
+    impl ProbabilityDistribution for Exponential {
+        fn pdf(&self, x: f64) -> f64 {
+            if x < 0.0 {
+                0.0
+            } else {
+                2.0 * f64::exp(-2.0 * x)
+            }
+        }
+        fn cdf(&self, x: f64) -> f64 {
+            if x < 0.0 {
+                0.0
+            } else {
+                1.0 - f64::exp(-2.0 * x)
+            }
+        }
+        fn mean(&self) -> f64 {
+            0.5
+        }
+        fn variance(&self) -> f64 {
+            0.25
+        }
+        fn sample<R: Rng + ?Sized>(&self, _rng: &mut R) -> f64 {
+            0.0
+        }
+    }
+
+    // Forwards the required methods only, thus the default implementations are used
+    struct Numerical<'a, D: ProbabilityDistribution>(&'a D);
+
+    impl<'a, D: ProbabilityDistribution> ProbabilityDistribution for Numerical<'a, D> {
+        fn pdf(&self, x: f64) -> f64 {
+            self.0.pdf(x)
+        }
+        fn cdf(&self, x: f64) -> f64 {
+            self.0.cdf(x)
+        }
+        fn mean(&self) -> f64 {
+            self.0.mean()
+        }
+        fn variance(&self) -> f64 {
+            self.0.variance()
+        }
+        fn sample<R: Rng + ?Sized>(&self, _rng: &mut R) -> f64 {
+            0.0
+        }
+        fn inverse_cdf(&self, p: f64) -> f64 {
+            self.0.inverse_cdf(p)
+        }
+    }
+
+    // Compares the default implementations with the closed-form expressions
+    fn check_defaults<D: ProbabilityDistribution>(d: &D, tol: f64) {
+        let n = Numerical(d);
+        approx_eq(n.median(), d.median(), tol);
+        approx_eq(n.mode(), d.mode(), 1e3 * tol);
+        approx_eq(n.skewness(), d.skewness(), tol);
+        approx_eq(n.entropy(), d.entropy(), tol);
+    }
+
+    #[test]
+    fn default_implementations_match_closed_forms() {
+        check_defaults(&DistributionNormal::new(1.0, 2.0).unwrap(), 1e-9);
+        check_defaults(&DistributionLognormal::new(0.5, 0.4).unwrap(), 1e-9);
+        check_defaults(&DistributionGumbel::new(0.5, 2.0).unwrap(), 1e-9);
+        check_defaults(&DistributionFrechet::new(1.0, 2.0, 8.0).unwrap(), 1e-6);
+    }
+
+    #[test]
+    fn inverse_cdf_handles_limits() {
+        let d = Exponential {};
+        assert_eq!(d.inverse_cdf(0.0), f64::NEG_INFINITY);
+        assert_eq!(d.inverse_cdf(1.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn median_and_mode_work() {
+        let d = Exponential {};
+        approx_eq(d.median(), f64::ln(2.0) / 2.0, 1e-13);
+        approx_eq(d.mode(), 0.0, 1e-12);
+    }
+
+    #[test]
+    fn skewness_and_entropy_work() {
+        let d = Exponential {};
+        approx_eq(d.skewness(), 2.0, 1e-9);
+        approx_eq(d.entropy(), 1.0 - f64::ln(2.0), 1e-10);
+    }
+
+    #[test]
+    fn inverse_cdf_works() {
+        let d = Exponential {};
+        for p in [1e-6, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 0.999] {
+            let x = d.inverse_cdf(p);
+            approx_eq(x, -f64::ln(1.0 - p) / 2.0, 1e-12);
+        }
+    }
 }
@@ -0,0 +1,222 @@
+use crate::ProbabilityDistribution;
+
+/// Specifies the formula to compute plotting positions (empirical cumulative probabilities)
+///
+/// The plotting position of the i-th smallest value (i = 1, ..., n) in a sample of size n is:
+///
+/// ```text
+///         i - a
+/// pᵢ = ———————————
+///      n + 1 - 2 a
+/// ```
+///
+/// where `a` is a constant depending on the formula.
+///
+/// Reference: <https://en.wikipedia.org/wiki/Q%E2%80%93Q_plot#Heuristics>
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PlottingPosition {
+    /// Weibull formula (a = 0): i / (n + 1)
+    Weibull,
+
+    /// Benard's median rank approximation (a = 0.3): (i - 0.3) / (n + 0.4)
+    Benard,
+
+    /// Blom formula (a = 0.375): (i - 3/8) / (n + 1/4)
+    Blom,
+
+    /// Cunnane formula (a = 0.4): (i - 0.4) / (n + 0.2)
+    Cunnane,
+
+    /// Gringorten formula (a = 0.44): (i - 0.44) / (n + 0.12)
+    Gringorten,
+
+    /// Hazen formula (a = 0.5): (i - 0.5) / n
+    Hazen,
+}
+
+impl PlottingPosition {
+    /// Returns the constant `a` of the plotting position formula
+    pub fn constant(&self) -> f64 {
+        match self {
+            PlottingPosition::Weibull => 0.0,
+            PlottingPosition::Benard => 0.3,
+            PlottingPosition::Blom => 0.375,
+            PlottingPosition::Cunnane => 0.4,
+            PlottingPosition::Gringorten => 0.44,
+            PlottingPosition::Hazen => 0.5,
+        }
+    }
+}
+
+/// Calculates the plotting positions (empirical cumulative probabilities) of a sample of size n
+///
+/// Returns the probabilities `pᵢ` corresponding to the sorted values (ascending order).
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{plotting_positions, PlottingPosition};
+///
+/// let p = plotting_positions(4, PlottingPosition::Weibull);
+/// assert_eq!(p, &[0.2, 0.4, 0.6, 0.8]);
+///
+/// let p = plotting_positions(4, PlottingPosition::Hazen);
+/// assert_eq!(p, &[0.125, 0.375, 0.625, 0.875]);
+/// ```
+pub fn plotting_positions(n: usize, kind: PlottingPosition) -> Vec<f64> {
+    let a = kind.constant();
+    let den = (n as f64) + 1.0 - 2.0 * a;
+    (1..(n + 1)).map(|i| ((i as f64) - a) / den).collect()
+}
+
+/// Calculates the coordinates of a Q-Q (quantile-quantile) plot
+///
+/// Returns `(x, y)` where `x` are the theoretical quantiles of the distribution and
+/// `y` are the sorted data (ascending order). If the data follows the distribution,
+/// the points lie approximately on the straight line `y = x`.
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{qq_plot_data, DistributionUniform, PlottingPosition, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let dist = DistributionUniform::new(0.0, 10.0)?;
+///     let data = [8.0, 2.0, 4.0, 6.0];
+///     let (x, y) = qq_plot_data(&data, &dist, PlottingPosition::Weibull);
+///     assert_eq!(x, &[2.0, 4.0, 6.0, 8.0]);
+///     assert_eq!(y, &[2.0, 4.0, 6.0, 8.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn qq_plot_data<T, D>(data: &[T], dist: &D, kind: PlottingPosition) -> (Vec<f64>, Vec<f64>)
+where
+    T: Into<f64> + Copy,
+    D: ProbabilityDistribution,
+{
+    let y = sorted_data(data);
+    let x = plotting_positions(y.len(), kind)
+        .iter()
+        .map(|p| dist.inverse_cdf(*p))
+        .collect();
+    (x, y)
+}
+
+/// Calculates the coordinates of a P-P (probability-probability) plot
+///
+/// Returns `(x, y)` where `x` are the plotting positions (empirical cumulative probabilities)
+/// and `y` are the values of the theoretical CDF evaluated at the sorted data (ascending order).
+/// If the data follows the distribution, the points lie approximately on the straight line `y = x`.
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{pp_plot_data, DistributionUniform, PlottingPosition, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let dist = DistributionUniform::new(0.0, 10.0)?;
+///     let data = [8.0, 2.0, 4.0, 6.0];
+///     let (x, y) = pp_plot_data(&data, &dist, PlottingPosition::Weibull);
+///     assert_eq!(x, &[0.2, 0.4, 0.6, 0.8]);
+///     assert_eq!(y, &[0.2, 0.4, 0.6, 0.8]);
+///     Ok(())
+/// }
+/// ```
+pub fn pp_plot_data<T, D>(data: &[T], dist: &D, kind: PlottingPosition) -> (Vec<f64>, Vec<f64>)
+where
+    T: Into<f64> + Copy,
+    D: ProbabilityDistribution,
+{
+    let sorted = sorted_data(data);
+    let x = plotting_positions(sorted.len(), kind);
+    let y = sorted.iter().map(|v| dist.cdf(*v)).collect();
+    (x, y)
+}
+
+/// Returns a sorted copy (ascending order) of the data
+fn sorted_data<T>(data: &[T]) -> Vec<f64>
+where
+    T: Into<f64> + Copy,
+{
+    let mut sorted: Vec<f64> = data.iter().map(|v| (*v).into()).collect();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    sorted
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{plotting_positions, pp_plot_data, qq_plot_data, PlottingPosition};
+    use crate::{DistributionGumbel, DistributionNormal, ProbabilityDistribution};
+    use russell_chk::{approx_eq, vec_approx_eq};
+
+    #[test]
+    fn plotting_positions_handle_small_samples() {
+        let p = plotting_positions(0, PlottingPosition::Weibull);
+        assert_eq!(p.len(), 0);
+        let p = plotting_positions(1, PlottingPosition::Gringorten);
+        assert_eq!(p, &[0.5]);
+    }
+
+    #[test]
+    fn plotting_positions_work() {
+        let n = 5;
+        let p = plotting_positions(n, PlottingPosition::Weibull);
+        vec_approx_eq(&p, &[1.0 / 6.0, 2.0 / 6.0, 3.0 / 6.0, 4.0 / 6.0, 5.0 / 6.0], 1e-15);
+        let p = plotting_positions(n, PlottingPosition::Benard);
+        vec_approx_eq(&p, &[0.7 / 5.4, 1.7 / 5.4, 2.7 / 5.4, 3.7 / 5.4, 4.7 / 5.4], 1e-15);
+        let p = plotting_positions(n, PlottingPosition::Blom);
+        vec_approx_eq(
+            &p,
+            &[0.625 / 5.25, 1.625 / 5.25, 2.625 / 5.25, 3.625 / 5.25, 4.625 / 5.25],
+            1e-15,
+        );
+        let p = plotting_positions(n, PlottingPosition::Cunnane);
+        vec_approx_eq(&p, &[0.6 / 5.2, 1.6 / 5.2, 2.6 / 5.2, 3.6 / 5.2, 4.6 / 5.2], 1e-15);
+        let p = plotting_positions(n, PlottingPosition::Gringorten);
+        vec_approx_eq(
+            &p,
+            &[0.56 / 5.12, 1.56 / 5.12, 2.56 / 5.12, 3.56 / 5.12, 4.56 / 5.12],
+            1e-15,
+        );
+        let p = plotting_positions(n, PlottingPosition::Hazen);
+        vec_approx_eq(&p, &[0.1, 0.3, 0.5, 0.7, 0.9], 1e-15);
+
+        // positions are symmetric about 1/2
+        for kind in [
+            PlottingPosition::Weibull,
+            PlottingPosition::Benard,
+            PlottingPosition::Blom,
+            PlottingPosition::Cunnane,
+            PlottingPosition::Gringorten,
+            PlottingPosition::Hazen,
+        ] {
+            let p = plotting_positions(n, kind);
+            for i in 0..n {
+                approx_eq(p[i] + p[n - 1 - i], 1.0, 1e-15);
+            }
+        }
+    }
+
+    #[test]
+    fn qq_plot_data_works() {
+        let dist = DistributionNormal::new(0.0, 1.0).unwrap();
+        let data = [0.5, -1.0, 2.0, 0.0];
+        let (x, y) = qq_plot_data(&data, &dist, PlottingPosition::Hazen);
+        assert_eq!(y, &[-1.0, 0.0, 0.5, 2.0]);
+        approx_eq(x[0], -1.1503493803760079, 1e-14);
+        approx_eq(x[1], -0.31863936396437514, 1e-14);
+        approx_eq(x[2], 0.31863936396437514, 1e-14);
+        approx_eq(x[3], 1.1503493803760079, 1e-14);
+    }
+
+    #[test]
+    fn pp_plot_data_works() {
+        let dist = DistributionGumbel::new(0.5, 2.0).unwrap();
+        let data = [3, 1, 2];
+        let (x, y) = pp_plot_data(&data, &dist, PlottingPosition::Weibull);
+        vec_approx_eq(&x, &[0.25, 0.5, 0.75], 1e-15);
+        vec_approx_eq(&y, &[dist.cdf(1.0), dist.cdf(2.0), dist.cdf(3.0)], 1e-15);
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{Error, ProbabilityDistribution};
+use russell_lab::Matrix;
+
+/// Defines a quasi-random (low-discrepancy) sequence of points in the unit hypercube [0,1)ᵈ
+pub trait QuasiRandomSequence {
+    /// Returns the dimension d of the points
+    fn ndim(&self) -> usize;
+
+    /// Computes the next point of the sequence
+    ///
+    /// # Output
+    ///
+    /// * `u` -- the point in [0,1)ᵈ; must have length equal to `ndim`
+    fn next_point(&mut self, u: &mut [f64]) -> Result<(), Error>;
+
+    /// Generates the next `npoint` points of the sequence
+    ///
+    /// Returns a matrix (npoint × ndim) where each row is a point in [0,1)ᵈ
+    fn generate(&mut self, npoint: usize) -> Result<Matrix, Error> {
+        let ndim = self.ndim();
+        let mut points = Matrix::new(npoint, ndim);
+        let mut u = vec![0.0; ndim];
+        for i in 0..npoint {
+            self.next_point(&mut u)?;
+            for (j, uj) in u.iter().enumerate() {
+                points.set(i, j, *uj);
+            }
+        }
+        Ok(points)
+    }
+}
+
+/// Maps points in the unit hypercube to the space of random variables using quantile functions
+///
+/// Computes `xᵢⱼ = Fⱼ⁻¹(uᵢⱼ)` where `Fⱼ` is the CDF of the j-th marginal distribution.
+///
+/// # Input
+///
+/// * `u` -- matrix (npoint × ndim) with points in the unit hypercube (e.g., from a [QuasiRandomSequence])
+/// * `marginals` -- the ndim marginal distributions
+///
+/// # Output
+///
+/// Returns a matrix (npoint × ndim) with the corresponding values of the random variables
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{quantile_map, DistributionUniform, Halton, QuasiRandomSequence, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut halton = Halton::new(2)?;
+///     let u = halton.generate(3)?;
+///     let dx = DistributionUniform::new(0.0, 2.0)?;
+///     let dy = DistributionUniform::new(-3.0, 0.0)?;
+///     let x = quantile_map(&u, &[&dx, &dy])?;
+///     assert_eq!(
+///         format!("{:.4}", x),
+///         "┌                 ┐\n\
+///          │  1.0000 -2.0000 │\n\
+///          │  0.5000 -1.0000 │\n\
+///          │  1.5000 -2.6667 │\n\
+///          └                 ┘"
+///     );
+///     Ok(())
+/// }
+/// ```
+pub fn quantile_map(u: &Matrix, marginals: &[&dyn ProbabilityDistribution]) -> Result<Matrix, Error> {
+    let (npoint, ndim) = u.dims();
+    if marginals.len() != ndim {
+        return Err(Error::BadDimensions(
+            "the number of marginals must equal the number of columns of u",
+        ));
+    }
+    let mut x = Matrix::new(npoint, ndim);
+    for i in 0..npoint {
+        for (j, marginal) in marginals.iter().enumerate() {
+            x.set(i, j, marginal.inverse_cdf(u.get(i, j)));
+        }
+    }
+    Ok(x)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{quantile_map, QuasiRandomSequence};
+    use crate::{DistributionNormal, DistributionUniform, Error};
+    use russell_chk::approx_eq;
+    use russell_lab::Matrix;
+
+    struct Counter {
+        count: f64,
+    }
+
+    impl QuasiRandomSequence for Counter {
+        fn ndim(&self) -> usize {
+            2
+        }
+        fn next_point(&mut self, u: &mut [f64]) -> Result<(), Error> {
+            self.count += 1.0;
+            u[0] = self.count / 10.0;
+            u[1] = 1.0 - self.count / 10.0;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn generate_works() {
+        let mut seq = Counter { count: 0.0 };
+        let points = seq.generate(3).unwrap();
+        assert_eq!(points.dims(), (3, 2));
+        assert_eq!(points.as_data(), &[0.1, 0.2, 0.3, 0.9, 0.8, 0.7]);
+    }
+
+    #[test]
+    fn quantile_map_captures_errors() {
+        let u = Matrix::new(2, 2);
+        let d = DistributionUniform::new(0.0, 1.0).unwrap();
+        assert_eq!(
+            quantile_map(&u, &[&d]).err(),
+            Some(Error::BadDimensions(
+                "the number of marginals must equal the number of columns of u"
+            ))
+        );
+    }
+
+    #[test]
+    fn quantile_map_works() {
+        let u = Matrix::from(&[[0.5, 0.25], [0.975, 0.5]]);
+        let dx = DistributionNormal::new(1.0, 2.0).unwrap();
+        let dy = DistributionUniform::new(0.0, 4.0).unwrap();
+        let x = quantile_map(&u, &[&dx, &dy]).unwrap();
+        approx_eq(x.get(0, 0), 1.0, 1e-15);
+        approx_eq(x.get(0, 1), 1.0, 1e-15);
+        approx_eq(x.get(1, 0), 1.0 + 2.0 * 1.9599639845400536, 1e-14);
+        approx_eq(x.get(1, 1), 2.0, 1e-15);
+    }
+}
@@ -0,0 +1,327 @@
+use crate::Error;
+use rand::Rng;
+use russell_lab::Matrix;
+use std::collections::BTreeMap;
+
+/// Shuffles a slice in place (random permutation)
+///
+/// Uses the Fisher-Yates algorithm; thus, all permutations are equally likely.
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{shuffle, Generator};
+///
+/// let mut rng = Generator::new(1234);
+/// let mut data = [1, 2, 3, 4, 5];
+/// shuffle(&mut data, &mut rng);
+/// data.sort();
+/// assert_eq!(data, [1, 2, 3, 4, 5]);
+/// ```
+pub fn shuffle<T, R: Rng + ?Sized>(data: &mut [T], rng: &mut R) {
+    for i in (1..data.len()).rev() {
+        let j = rng.gen_range(0..(i + 1));
+        data.swap(i, j);
+    }
+}
+
+/// Returns a random permutation of the indices {0, 1, ..., n-1}
+pub fn random_permutation<R: Rng + ?Sized>(n: usize, rng: &mut R) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..n).collect();
+    shuffle(&mut indices, rng);
+    indices
+}
+
+/// Samples k distinct indices from {0, 1, ..., n-1} (sampling without replacement)
+///
+/// Uses a partial Fisher-Yates shuffle. The indices are returned in the order they have been drawn.
+///
+/// # Input
+///
+/// * `k` -- the number of indices to draw (k ≤ n)
+/// * `n` -- the size of the population
+/// * `rng` -- the random number generator
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{sample_without_replacement, Generator, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut rng = Generator::new(1234);
+///     let mut indices = sample_without_replacement(3, 10, &mut rng)?;
+///     assert_eq!(indices.len(), 3);
+///     indices.sort();
+///     indices.dedup();
+///     assert_eq!(indices.len(), 3);
+///     Ok(())
+/// }
+/// ```
+pub fn sample_without_replacement<R: Rng + ?Sized>(k: usize, n: usize, rng: &mut R) -> Result<Vec<usize>, Error> {
+    if k > n {
+        return Err(Error::InvalidParameters("k must not be greater than n"));
+    }
+    let mut indices: Vec<usize> = (0..n).collect();
+    for i in 0..k {
+        let j = rng.gen_range(i..n);
+        indices.swap(i, j);
+    }
+    indices.truncate(k);
+    Ok(indices)
+}
+
+/// Samples a fraction of the indices of each stratum (stratified sampling without replacement)
+///
+/// The number of indices drawn from a stratum with m members is `round(fraction · m)`; thus,
+/// the proportions of the strata in the sample are (approximately) equal to the proportions in the population.
+///
+/// # Input
+///
+/// * `labels` -- the stratum (e.g., class) of each member of the population
+/// * `fraction` -- the fraction of each stratum to be sampled (0 ≤ fraction ≤ 1)
+/// * `rng` -- the random number generator
+///
+/// # Output
+///
+/// Returns the indices (into `labels`) of the sampled members, grouped by stratum in ascending label order
+pub fn stratified_sample<R: Rng + ?Sized>(labels: &[usize], fraction: f64, rng: &mut R) -> Result<Vec<usize>, Error> {
+    if !(0.0..=1.0).contains(&fraction) {
+        return Err(Error::InvalidParameters("fraction must be in [0, 1]"));
+    }
+    let mut sample = Vec::new();
+    for members in group_by_label(labels).values() {
+        let k = f64::round(fraction * (members.len() as f64)) as usize;
+        let selected = sample_without_replacement(k, members.len(), rng)?;
+        sample.extend(selected.iter().map(|i| members[*i]));
+    }
+    Ok(sample)
+}
+
+/// Splits the indices of a population into folds with (approximately) the same proportions of each stratum
+///
+/// This function is used in stratified k-fold cross-validation. The members of each stratum are shuffled
+/// and then dealt to the folds in turn; thus, the sizes of the folds differ by at most one.
+///
+/// # Input
+///
+/// * `labels` -- the stratum (e.g., class) of each member of the population
+/// * `nfold` -- the number of folds (1 ≤ nfold ≤ labels.len())
+/// * `rng` -- the random number generator
+///
+/// # Output
+///
+/// Returns `nfold` disjoint lists of indices (into `labels`) whose union is {0, 1, ..., labels.len()-1}
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{stratified_folds, Generator, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut rng = Generator::new(1234);
+///     let labels = [0, 0, 0, 0, 1, 1, 1, 1];
+///     let folds = stratified_folds(&labels, 2, &mut rng)?;
+///     for fold in &folds {
+///         assert_eq!(fold.len(), 4);
+///         assert_eq!(fold.iter().filter(|i| labels[**i] == 1).count(), 2);
+///     }
+///     Ok(())
+/// }
+/// ```
+pub fn stratified_folds<R: Rng + ?Sized>(
+    labels: &[usize],
+    nfold: usize,
+    rng: &mut R,
+) -> Result<Vec<Vec<usize>>, Error> {
+    if nfold < 1 || nfold > labels.len() {
+        return Err(Error::InvalidParameters("nfold must be in [1, labels.len()]"));
+    }
+    let mut folds = vec![Vec::new(); nfold];
+    let mut f = 0;
+    for members in group_by_label(labels).values_mut() {
+        shuffle(members, rng);
+        for i in members.iter() {
+            folds[f].push(*i);
+            f = (f + 1) % nfold;
+        }
+    }
+    Ok(folds)
+}
+
+/// Generates a Latin hypercube sample in the unit hypercube [0,1)ᵈ
+///
+/// Each coordinate axis is divided into `npoint` strata of equal width and each stratum
+/// contains exactly one point. The points are randomly located within the strata.
+///
+/// Reference: McKay MD, Beckman RJ, Conover WJ (1979) A comparison of three methods for selecting values
+/// of input variables in the analysis of output from a computer code, Technometrics, 21(2):239-245
+///
+/// # Output
+///
+/// Returns a matrix (npoint × ndim) where each row is a point in [0,1)ᵈ. The result can be
+/// mapped to the space of random variables by [crate::quantile_map].
+pub fn latin_hypercube<R: Rng + ?Sized>(npoint: usize, ndim: usize, rng: &mut R) -> Matrix {
+    let mut points = Matrix::new(npoint, ndim);
+    let width = 1.0 / (npoint as f64);
+    for j in 0..ndim {
+        for (i, stratum) in random_permutation(npoint, rng).into_iter().enumerate() {
+            let u: f64 = rng.gen();
+            points.set(i, j, ((stratum as f64) + u) * width);
+        }
+    }
+    points
+}
+
+/// Groups the indices of the members of a population by their labels
+fn group_by_label(labels: &[usize]) -> BTreeMap<usize, Vec<usize>> {
+    let mut groups: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for (i, label) in labels.iter().enumerate() {
+        groups.entry(*label).or_default().push(i);
+    }
+    groups
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        group_by_label, latin_hypercube, random_permutation, sample_without_replacement, shuffle, stratified_folds,
+        stratified_sample,
+    };
+    use crate::Error;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn captures_errors() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        assert_eq!(
+            sample_without_replacement(4, 3, &mut rng).err(),
+            Some(Error::InvalidParameters("k must not be greater than n"))
+        );
+        assert_eq!(
+            stratified_sample(&[0, 1], 1.5, &mut rng).err(),
+            Some(Error::InvalidParameters("fraction must be in [0, 1]"))
+        );
+        assert_eq!(
+            stratified_folds(&[0, 1], 0, &mut rng).err(),
+            Some(Error::InvalidParameters("nfold must be in [1, labels.len()]"))
+        );
+        assert_eq!(
+            stratified_folds(&[0, 1], 3, &mut rng).err(),
+            Some(Error::InvalidParameters("nfold must be in [1, labels.len()]"))
+        );
+    }
+
+    #[test]
+    fn shuffle_works() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let mut empty: [f64; 0] = [];
+        shuffle(&mut empty, &mut rng);
+        let mut data: Vec<usize> = (0..20).collect();
+        shuffle(&mut data, &mut rng);
+        assert_ne!(data, (0..20).collect::<Vec<_>>());
+        data.sort();
+        assert_eq!(data, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn shuffle_is_uniform() {
+        // each of the 3! = 6 permutations must be (approximately) equally likely
+        let mut rng = StdRng::seed_from_u64(1234);
+        let n_sample = 60_000;
+        let mut counts = [0_usize; 6];
+        for _ in 0..n_sample {
+            let p = random_permutation(3, &mut rng);
+            counts[p[0] * 2 + usize::from(p[1] > p[2])] += 1;
+        }
+        for count in counts {
+            let freq = (count as f64) / (n_sample as f64);
+            assert!(f64::abs(freq - 1.0 / 6.0) < 0.01);
+        }
+    }
+
+    #[test]
+    fn sample_without_replacement_works() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        assert_eq!(sample_without_replacement(0, 5, &mut rng).unwrap(), &[] as &[usize]);
+        let mut all = sample_without_replacement(5, 5, &mut rng).unwrap();
+        all.sort();
+        assert_eq!(all, &[0, 1, 2, 3, 4]);
+        // each index must be drawn with probability k/n
+        let (k, n, n_sample) = (3, 10, 20_000);
+        let mut counts = vec![0_usize; n];
+        for _ in 0..n_sample {
+            let mut indices = sample_without_replacement(k, n, &mut rng).unwrap();
+            indices.sort();
+            indices.dedup();
+            assert_eq!(indices.len(), k);
+            for i in indices {
+                counts[i] += 1;
+            }
+        }
+        for count in counts {
+            let freq = (count as f64) / (n_sample as f64);
+            assert!(f64::abs(freq - 0.3) < 0.015);
+        }
+    }
+
+    #[test]
+    fn group_by_label_works() {
+        let groups = group_by_label(&[2, 0, 2, 1, 0]);
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[&0], &[1, 4]);
+        assert_eq!(groups[&1], &[3]);
+        assert_eq!(groups[&2], &[0, 2]);
+    }
+
+    #[test]
+    fn stratified_sample_works() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let labels = [0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2];
+        let sample = stratified_sample(&labels, 0.5, &mut rng).unwrap();
+        assert_eq!(sample.len(), 10);
+        let count = |label: usize| sample.iter().filter(|i| labels[**i] == label).count();
+        assert_eq!(count(0), 3);
+        assert_eq!(count(1), 5);
+        assert_eq!(count(2), 2);
+        let mut unique = sample.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len(), 10);
+        assert_eq!(stratified_sample(&labels, 0.0, &mut rng).unwrap().len(), 0);
+        assert_eq!(stratified_sample(&labels, 1.0, &mut rng).unwrap().len(), 20);
+    }
+
+    #[test]
+    fn stratified_folds_works() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let labels = [1, 0, 1, 1, 0, 1, 1, 0, 1, 1, 1];
+        let folds = stratified_folds(&labels, 3, &mut rng).unwrap();
+        assert_eq!(folds.len(), 3);
+        let sizes: Vec<_> = folds.iter().map(|f| f.len()).collect();
+        assert_eq!(sizes, &[4, 4, 3]);
+        for fold in &folds {
+            assert_eq!(fold.iter().filter(|i| labels[**i] == 0).count(), 1);
+        }
+        let mut all: Vec<usize> = folds.into_iter().flatten().collect();
+        all.sort();
+        assert_eq!(all, (0..labels.len()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn latin_hypercube_works() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let npoint = 10;
+        let points = latin_hypercube(npoint, 3, &mut rng);
+        assert_eq!(points.dims(), (npoint, 3));
+        for j in 0..3 {
+            let mut strata: Vec<usize> = (0..npoint)
+                .map(|i| (points.get(i, j) * (npoint as f64)) as usize)
+                .collect();
+            strata.sort();
+            assert_eq!(strata, (0..npoint).collect::<Vec<_>>());
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use crate::{Error, QuasiRandomSequence};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Implements the Halton low-discrepancy sequence
+///
+/// The j-th coordinate of the i-th point is the radical inverse of i in base bⱼ,
+/// where bⱼ is the j-th prime number. The sequence starts at i = 1 (the origin is skipped).
+///
+/// The scrambled version applies a random permutation to the (non-zero) digits of each base,
+/// which breaks the correlation between coordinates observed with large bases.
+///
+/// Reference: <https://en.wikipedia.org/wiki/Halton_sequence>
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{Halton, QuasiRandomSequence, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut halton = Halton::new(2)?;
+///     let points = halton.generate(4)?;
+///     assert_eq!(
+///         format!("{:.4}", points),
+///         "┌               ┐\n\
+///          │ 0.5000 0.3333 │\n\
+///          │ 0.2500 0.6667 │\n\
+///          │ 0.7500 0.1111 │\n\
+///          │ 0.1250 0.4444 │\n\
+///          └               ┘"
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct Halton {
+    index: u64,                  // index of the next point
+    bases: Vec<u64>,             // prime bases (one per dimension)
+    permutations: Vec<Vec<u64>>, // digit permutations (one per dimension)
+}
+
+impl Halton {
+    /// Creates a new Halton sequence
+    ///
+    /// # Input
+    ///
+    /// * `ndim` -- the dimension of the points (≥ 1)
+    pub fn new(ndim: usize) -> Result<Self, Error> {
+        if ndim < 1 {
+            return Err(Error::InvalidParameters("ndim must be at least 1"));
+        }
+        let bases = first_primes(ndim);
+        let permutations = bases.iter().map(|b| (0..*b).collect()).collect();
+        Ok(Halton {
+            index: 1,
+            bases,
+            permutations,
+        })
+    }
+
+    /// Creates a new scrambled Halton sequence
+    ///
+    /// # Input
+    ///
+    /// * `ndim` -- the dimension of the points (≥ 1)
+    /// * `rng` -- the random number generator used to compute the digit permutations
+    pub fn new_scrambled<R: Rng + ?Sized>(ndim: usize, rng: &mut R) -> Result<Self, Error> {
+        let mut halton = Halton::new(ndim)?;
+        for perm in &mut halton.permutations {
+            // keep the zero digit fixed such that the trailing zeros remain zero
+            perm[1..].shuffle(rng);
+        }
+        Ok(halton)
+    }
+
+    /// Resets the sequence to the first point
+    pub fn reset(&mut self) {
+        self.index = 1;
+    }
+
+    /// Returns the prime bases
+    pub fn get_bases(&self) -> &Vec<u64> {
+        &self.bases
+    }
+}
+
+impl QuasiRandomSequence for Halton {
+    /// Returns the dimension d of the points
+    fn ndim(&self) -> usize {
+        self.bases.len()
+    }
+
+    /// Computes the next point of the sequence
+    fn next_point(&mut self, u: &mut [f64]) -> Result<(), Error> {
+        if u.len() != self.bases.len() {
+            return Err(Error::BadDimensions("u.len() must equal ndim"));
+        }
+        for (j, uj) in u.iter_mut().enumerate() {
+            let base = self.bases[j];
+            let perm = &self.permutations[j];
+            let inv_base = 1.0 / (base as f64);
+            let mut factor = inv_base;
+            let mut i = self.index;
+            let mut value = 0.0;
+            while i > 0 {
+                value += factor * (perm[(i % base) as usize] as f64);
+                i /= base;
+                factor *= inv_base;
+            }
+            *uj = value;
+        }
+        self.index += 1;
+        Ok(())
+    }
+}
+
+/// Returns the first n prime numbers
+fn first_primes(n: usize) -> Vec<u64> {
+    let mut primes: Vec<u64> = Vec::with_capacity(n);
+    let mut candidate = 2;
+    while primes.len() < n {
+        if primes
+            .iter()
+            .take_while(|p| *p * *p <= candidate)
+            .all(|p| candidate % p != 0)
+        {
+            primes.push(candidate);
+        }
+        candidate += 1;
+    }
+    primes
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{first_primes, Halton};
+    use crate::{Error, QuasiRandomSequence};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use russell_chk::vec_approx_eq;
+
+    #[test]
+    fn first_primes_works() {
+        assert_eq!(first_primes(0), &[] as &[u64]);
+        assert_eq!(first_primes(10), &[2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    }
+
+    #[test]
+    fn new_captures_errors() {
+        assert_eq!(
+            Halton::new(0).err(),
+            Some(Error::InvalidParameters("ndim must be at least 1"))
+        );
+        let mut halton = Halton::new(2).unwrap();
+        let mut u = vec![0.0; 3];
+        assert_eq!(
+            halton.next_point(&mut u).err(),
+            Some(Error::BadDimensions("u.len() must equal ndim"))
+        );
+    }
+
+    #[test]
+    fn halton_works() {
+        let mut halton = Halton::new(3).unwrap();
+        assert_eq!(halton.ndim(), 3);
+        assert_eq!(halton.get_bases(), &[2, 3, 5]);
+        let mut u = vec![0.0; 3];
+        #[rustfmt::skip]
+        let correct = [
+            [1.0 / 2.0, 1.0 / 3.0, 1.0 / 5.0],
+            [1.0 / 4.0, 2.0 / 3.0, 2.0 / 5.0],
+            [3.0 / 4.0, 1.0 / 9.0, 3.0 / 5.0],
+            [1.0 / 8.0, 4.0 / 9.0, 4.0 / 5.0],
+            [5.0 / 8.0, 7.0 / 9.0, 1.0 / 25.0],
+            [3.0 / 8.0, 2.0 / 9.0, 6.0 / 25.0],
+        ];
+        for row in &correct {
+            halton.next_point(&mut u).unwrap();
+            vec_approx_eq(&u, row, 1e-15);
+        }
+        halton.reset();
+        halton.next_point(&mut u).unwrap();
+        vec_approx_eq(&u, &correct[0], 1e-15);
+    }
+
+    #[test]
+    fn scrambled_halton_works() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let mut halton = Halton::new_scrambled(4, &mut rng).unwrap();
+        let points = halton.generate(7 * 7).unwrap();
+        for j in 0..4 {
+            for i in 0..points.nrow() {
+                let v = points.get(i, j);
+                assert!(v > 0.0 && v < 1.0);
+            }
+        }
+        // the first b-1 points of each base-b coordinate are a permutation of {1/b, ..., (b-1)/b}
+        let bases = [2, 3, 5, 7];
+        for (j, b) in bases.into_iter().enumerate() {
+            let mut digits: Vec<usize> = (0..(b - 1))
+                .map(|i| f64::round(points.get(i, j) * (b as f64)) as usize)
+                .collect();
+            digits.sort();
+            assert_eq!(digits, (1..b).collect::<Vec<_>>());
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{Error, QuasiRandomSequence};
+
+/// Defines the number of bits used in the computation of the Sobol points
+const SOBOL_NBIT: usize = 32;
+
+/// Defines the maximum number of dimensions supported by the Sobol sequence
+pub const SOBOL_MAX_NDIM: usize = 21;
+
+/// Holds the primitive polynomials and initial direction numbers by Joe and Kuo (new-joe-kuo-6.21201)
+///
+/// Each row corresponds to (s, a, m) where s is the degree of the primitive polynomial,
+/// a encodes the polynomial coefficients, and m are the initial direction numbers.
+/// The first dimension (van der Corput sequence in base 2) is not included.
+#[rustfmt::skip]
+const SOBOL_JOE_KUO: [(usize, u32, &[u32]); SOBOL_MAX_NDIM - 1] = [
+    (1,  0, &[1]),
+    (2,  1, &[1, 3]),
+    (3,  1, &[1, 3, 1]),
+    (3,  2, &[1, 1, 1]),
+    (4,  1, &[1, 1, 3, 3]),
+    (4,  4, &[1, 3, 5, 13]),
+    (5,  2, &[1, 1, 5, 5, 17]),
+    (5,  4, &[1, 1, 5, 5, 5]),
+    (5,  7, &[1, 1, 7, 11, 19]),
+    (5, 11, &[1, 1, 5, 1, 1]),
+    (5, 13, &[1, 1, 1, 3, 11]),
+    (5, 14, &[1, 3, 5, 5, 31]),
+    (6,  1, &[1, 3, 3, 9, 7, 49]),
+    (6, 13, &[1, 1, 1, 15, 21, 21]),
+    (6, 16, &[1, 3, 1, 13, 27, 49]),
+    (6, 19, &[1, 1, 1, 15, 7, 5]),
+    (6, 22, &[1, 3, 1, 15, 13, 25]),
+    (6, 25, &[1, 1, 5, 5, 19, 61]),
+    (7,  1, &[1, 3, 7, 11, 23, 15, 103]),
+    (7,  4, &[1, 3, 7, 13, 13, 15, 69]),
+];
+
+/// Implements the Sobol low-discrepancy sequence
+///
+/// The points are generated using the Gray code ordering (Antonov and Saleev) with the
+/// direction numbers by Joe and Kuo. The sequence starts at the origin and supports up to
+/// [SOBOL_MAX_NDIM] dimensions and 2³² points.
+///
+/// # References
+///
+/// * Joe S and Kuo FY (2008) Constructing Sobol sequences with better two-dimensional projections,
+///   SIAM J. Sci. Comput. 30, 2635-2654
+/// * <https://web.maths.unsw.edu.au/~fkuo/sobol/>
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{QuasiRandomSequence, Sobol, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut sobol = Sobol::new(2)?;
+///     let points = sobol.generate(4)?;
+///     assert_eq!(
+///         format!("{}", points),
+///         "┌           ┐\n\
+///          │    0    0 │\n\
+///          │  0.5  0.5 │\n\
+///          │ 0.75 0.25 │\n\
+///          │ 0.25 0.75 │\n\
+///          └           ┘"
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct Sobol {
+    index: u64,                // index of the next point
+    directions: Vec<Vec<u32>>, // direction numbers (ndim × nbit)
+    state: Vec<u32>,           // current integer state (one per dimension)
+}
+
+impl Sobol {
+    /// Creates a new Sobol sequence
+    ///
+    /// # Input
+    ///
+    /// * `ndim` -- the dimension of the points (1 ≤ ndim ≤ [SOBOL_MAX_NDIM])
+    pub fn new(ndim: usize) -> Result<Self, Error> {
+        if !(1..=SOBOL_MAX_NDIM).contains(&ndim) {
+            return Err(Error::InvalidParameters("ndim must be in [1, SOBOL_MAX_NDIM]"));
+        }
+        let mut directions = vec![vec![0_u32; SOBOL_NBIT]; ndim];
+        for (k, v) in directions[0].iter_mut().enumerate() {
+            *v = 1 << (SOBOL_NBIT - 1 - k);
+        }
+        for j in 1..ndim {
+            let (s, a, m) = SOBOL_JOE_KUO[j - 1];
+            let v = &mut directions[j];
+            for k in 0..s {
+                v[k] = m[k] << (SOBOL_NBIT - 1 - k);
+            }
+            for k in s..SOBOL_NBIT {
+                v[k] = v[k - s] ^ (v[k - s] >> s);
+                for l in 1..s {
+                    if (a >> (s - 1 - l)) & 1 == 1 {
+                        v[k] ^= v[k - l];
+                    }
+                }
+            }
+        }
+        Ok(Sobol {
+            index: 0,
+            directions,
+            state: vec![0; ndim],
+        })
+    }
+
+    /// Resets the sequence to the first point (the origin)
+    pub fn reset(&mut self) {
+        self.index = 0;
+        self.state.fill(0);
+    }
+}
+
+impl QuasiRandomSequence for Sobol {
+    /// Returns the dimension d of the points
+    fn ndim(&self) -> usize {
+        self.state.len()
+    }
+
+    /// Computes the next point of the sequence
+    fn next_point(&mut self, u: &mut [f64]) -> Result<(), Error> {
+        if u.len() != self.state.len() {
+            return Err(Error::BadDimensions("u.len() must equal ndim"));
+        }
+        if self.index >= (1 << SOBOL_NBIT) {
+            return Err(Error::SequenceExhausted(
+                "the maximum number of Sobol points has been reached",
+            ));
+        }
+        if self.index > 0 {
+            // position of the rightmost zero bit of (index - 1)
+            let c = (!(self.index - 1)).trailing_zeros() as usize;
+            for j in 0..self.state.len() {
+                self.state[j] ^= self.directions[j][c];
+            }
+        }
+        let scale = 1.0 / ((1_u64 << SOBOL_NBIT) as f64);
+        for (uj, sj) in u.iter_mut().zip(&self.state) {
+            *uj = (*sj as f64) * scale;
+        }
+        self.index += 1;
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{Sobol, SOBOL_MAX_NDIM};
+    use crate::{Error, QuasiRandomSequence};
+    use russell_chk::vec_approx_eq;
+
+    #[test]
+    fn new_captures_errors() {
+        assert_eq!(
+            Sobol::new(0).err(),
+            Some(Error::InvalidParameters("ndim must be in [1, SOBOL_MAX_NDIM]"))
+        );
+        assert_eq!(
+            Sobol::new(SOBOL_MAX_NDIM + 1).err(),
+            Some(Error::InvalidParameters("ndim must be in [1, SOBOL_MAX_NDIM]"))
+        );
+        let mut sobol = Sobol::new(2).unwrap();
+        let mut u = vec![0.0; 3];
+        assert_eq!(
+            sobol.next_point(&mut u).err(),
+            Some(Error::BadDimensions("u.len() must equal ndim"))
+        );
+    }
+
+    #[test]
+    fn sobol_works() {
+        let mut sobol = Sobol::new(3).unwrap();
+        assert_eq!(sobol.ndim(), 3);
+        let mut u = vec![0.0; 3];
+        #[rustfmt::skip]
+        let correct = [
+            [0.0,   0.0,   0.0  ],
+            [0.5,   0.5,   0.5  ],
+            [0.75,  0.25,  0.25 ],
+            [0.25,  0.75,  0.75 ],
+            [0.375, 0.375, 0.625],
+            [0.875, 0.875, 0.125],
+            [0.625, 0.125, 0.875],
+            [0.125, 0.625, 0.375],
+        ];
+        for row in &correct {
+            sobol.next_point(&mut u).unwrap();
+            vec_approx_eq(&u, row, 1e-15);
+        }
+        sobol.reset();
+        sobol.next_point(&mut u).unwrap();
+        vec_approx_eq(&u, &correct[0], 1e-15);
+    }
+
+    #[test]
+    fn sobol_points_are_stratified() {
+        // the first 2ᵏ points of each coordinate are a permutation of {0, 1/2ᵏ, ..., (2ᵏ-1)/2ᵏ}
+        let npoint = 1024;
+        let mut sobol = Sobol::new(SOBOL_MAX_NDIM).unwrap();
+        let points = sobol.generate(npoint).unwrap();
+        for j in 0..SOBOL_MAX_NDIM {
+            let mut cells: Vec<usize> = (0..npoint)
+                .map(|i| (points.get(i, j) * (npoint as f64)) as usize)
+                .collect();
+            cells.sort();
+            assert_eq!(cells, (0..npoint).collect::<Vec<_>>());
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use crate::StrError;
+use std::fmt;
+
+/// Defines the errors returned by the tensor structures and operations
+///
+/// A tensor with incompatible dimensions (e.g., a 2D tensor given to a 3D operation) gives
+/// [Error::BadDimensions]; the components that break the requested symmetry give
+/// [Error::NotSymmetric]; and the components that cannot be represented in 2D give [Error::NotTwoDim].
+/// The messages are the same as the ones of the former `StrError`, into which an error still
+/// converts; thus, `?` keeps working in functions returning `Result<T, StrError>`.
+///
+/// # Example
+///
+/// ```
+/// use russell_tensor::{Error, Tensor2};
+///
+/// let non_symmetric = [[1.0, 2.0, 0.0], [3.0, 4.0, 0.0], [0.0, 0.0, 5.0]];
+/// match Tensor2::from_matrix(&non_symmetric, true, false) {
+///     Err(Error::NotSymmetric(message)) => println!("{}", message),
+///     Err(e) => println!("another error: {}", e),
+///     Ok(_) => (),
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// The tensors (or vectors) have incompatible dimensions or symmetry types
+    BadDimensions(&'static str),
+
+    /// The components do not pass the (minor) symmetry check
+    NotSymmetric(&'static str),
+
+    /// The components cannot define a 2D tensor (the out-of-plane values are not zero)
+    NotTwoDim(&'static str),
+
+    /// The operation does not apply to the current configuration (e.g., plane-strain instead of plane-stress)
+    InvalidArgument(&'static str),
+
+    /// An error returned by russell_lab
+    Lab(russell_lab::Error),
+
+    /// Any other error (e.g., converted from a `StrError`)
+    Other(&'static str),
+
+    /// Wraps an error (the source) with a message describing the context
+    Context(&'static str, Box<Error>),
+}
+
+impl Error {
+    /// Returns the message of this error (without the messages of the sources)
+    pub fn message(&self) -> &'static str {
+        match self {
+            Error::BadDimensions(m)
+            | Error::NotSymmetric(m)
+            | Error::NotTwoDim(m)
+            | Error::InvalidArgument(m)
+            | Error::Other(m)
+            | Error::Context(m, _) => m,
+            Error::Lab(e) => e.message(),
+        }
+    }
+
+    /// Wraps this error with a message describing the context
+    pub fn context(self, message: &'static str) -> Self {
+        Error::Context(message, Box::new(self))
+    }
+
+    /// Returns the innermost error (following the chain of sources)
+    pub fn root(&self) -> &Error {
+        match self {
+            Error::Context(_, source) => source.root(),
+            _ => self,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Context(_, source) => Some(source.as_ref()),
+            Error::Lab(e) => e.source(),
+            _ => None,
+        }
+    }
+}
+
+impl From<StrError> for Error {
+    fn from(message: StrError) -> Self {
+        Error::Other(message)
+    }
+}
+
+impl From<russell_lab::Error> for Error {
+    fn from(error: russell_lab::Error) -> Self {
+        Error::Lab(error)
+    }
+}
+
+impl From<Error> for StrError {
+    fn from(error: Error) -> Self {
+        error.message()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+    use crate::StrError;
+    use std::error::Error as StdError;
+
+    #[test]
+    fn message_and_context_work() {
+        let err = Error::NotTwoDim("cannot define 2D Tensor2 due to non-zero off-diagonal values")
+            .context("cannot compute the strain");
+        assert_eq!(err.message(), "cannot compute the strain");
+        assert_eq!(format!("{}", err), "cannot compute the strain");
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            "cannot define 2D Tensor2 due to non-zero off-diagonal values"
+        );
+        assert_eq!(
+            err.root(),
+            &Error::NotTwoDim("cannot define 2D Tensor2 due to non-zero off-diagonal values")
+        );
+    }
+
+    #[test]
+    fn conversions_work() {
+        let err: Error = "some error".into();
+        assert_eq!(err, Error::Other("some error"));
+        let err: Error = russell_lab::Error::BadDimensions("matrices are incompatible").into();
+        assert_eq!(err.message(), "matrices are incompatible");
+        let message: StrError = Error::NotSymmetric("symmetric Tensor2 does not pass symmetry check").into();
+        assert_eq!(message, "symmetric Tensor2 does not pass symmetry check");
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{t4_ddot_t2, StrError, Tensor2, Tensor4};
+use crate::{t4_ddot_t2, Error, Tensor2, Tensor4};
 
 /// Implements the linear elasticity equations for small-strain problems
 pub struct LinElasticity {
@@ -252,7 +252,7 @@ impl LinElasticity {
     ///     Ok(())
     /// }
     /// ```
-    pub fn calc_stress(&self, stress: &mut Tensor2, strain: &Tensor2) -> Result<(), StrError> {
+    pub fn calc_stress(&self, stress: &mut Tensor2, strain: &Tensor2) -> Result<(), Error> {
         t4_ddot_t2(stress, 1.0, &self.dd, strain)
     }
 
@@ -288,9 +288,11 @@ impl LinElasticity {
     ///     Ok(())
     /// }
     /// ```
-    pub fn out_of_plane_strain(&self, stress: &Tensor2) -> Result<f64, StrError> {
+    pub fn out_of_plane_strain(&self, stress: &Tensor2) -> Result<f64, Error> {
         if !self.plane_stress {
-            return Err("out-of-plane strain works with plane-stress only");
+            return Err(Error::InvalidArgument(
+                "out-of-plane strain works with plane-stress only",
+            ));
         }
         let eps_zz = -(stress.vec[0] + stress.vec[1]) * self.poisson / self.young;
         Ok(eps_zz)
@@ -330,7 +332,7 @@ impl LinElasticity {
 #[cfg(test)]
 mod tests {
     use super::LinElasticity;
-    use crate::{StrError, Tensor2};
+    use crate::{Error, Tensor2};
     use russell_chk::approx_eq;
 
     #[test]
@@ -389,7 +391,7 @@ mod tests {
     }
 
     #[test]
-    fn calc_stress_works() -> Result<(), StrError> {
+    fn calc_stress_works() -> Result<(), Error> {
         // plane-stress
         // from Bhatti page 514 (Young divided by 1000)
         let ela = LinElasticity::new(3000.0, 0.2, false, true);
@@ -480,7 +482,7 @@ mod tests {
     }
 
     #[test]
-    fn out_of_plane_strain_fails_on_wrong_input() -> Result<(), StrError> {
+    fn out_of_plane_strain_fails_on_wrong_input() -> Result<(), Error> {
         let ela = LinElasticity::new(900.0, 0.25, true, false);
         #[rustfmt::skip]
         let stress = Tensor2::from_matrix(
@@ -493,12 +495,17 @@ mod tests {
             true,
         )?;
         let res = ela.out_of_plane_strain(&stress);
-        assert_eq!(res.err(), Some("out-of-plane strain works with plane-stress only"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidArgument(
+                "out-of-plane strain works with plane-stress only"
+            ))
+        );
         Ok(())
     }
 
     #[test]
-    fn out_of_plane_strain_works() -> Result<(), StrError> {
+    fn out_of_plane_strain_works() -> Result<(), Error> {
         let ela = LinElasticity::new(3000.0, 0.2, false, true);
         #[rustfmt::skip]
         let stress = Tensor2::from_matrix(
@@ -1,5 +1,5 @@
 use super::{Tensor2, Tensor4};
-use crate::StrError;
+use crate::Error;
 use russell_lab::{mat_copy, mat_mat_mul, mat_vec_mul, vec_inner, vec_mat_mul, vec_outer, Vector};
 
 /// Copies Tensor2
@@ -35,10 +35,10 @@ use russell_lab::{mat_copy, mat_mat_mul, mat_vec_mul, vec_inner, vec_mat_mul, ve
 ///     Ok(())
 /// }
 /// ```
-pub fn copy_tensor2(b: &mut Tensor2, a: &Tensor2) -> Result<(), StrError> {
+pub fn copy_tensor2(b: &mut Tensor2, a: &Tensor2) -> Result<(), Error> {
     let n = a.vec.dim();
     if b.vec.dim() != n {
-        return Err("second-order tensors are incompatible");
+        return Err(Error::BadDimensions("second-order tensors are incompatible"));
     }
     b.vec.as_mut_data().clone_from_slice(&a.vec.as_data());
     Ok(())
@@ -77,8 +77,8 @@ pub fn copy_tensor2(b: &mut Tensor2, a: &Tensor2) -> Result<(), StrError> {
 /// }
 /// ```
 #[inline]
-pub fn copy_tensor4(ee: &mut Tensor4, dd: &Tensor4) -> Result<(), StrError> {
-    mat_copy(&mut ee.mat, &dd.mat)
+pub fn copy_tensor4(ee: &mut Tensor4, dd: &Tensor4) -> Result<(), Error> {
+    mat_copy(&mut ee.mat, &dd.mat).map_err(Error::BadDimensions)
 }
 
 /// Performs the double-dot (ddot) operation between two Tensor2 (inner product)
@@ -169,7 +169,7 @@ pub fn t2_ddot_t2(a: &Tensor2, b: &Tensor2) -> f64 {
 ///     Ok(())
 /// }
 /// ```
-pub fn t2_dot_t2(a: &Tensor2, b: &Tensor2) -> Result<Tensor2, StrError> {
+pub fn t2_dot_t2(a: &Tensor2, b: &Tensor2) -> Result<Tensor2, Error> {
     let ta = a.to_matrix();
     let tb = b.to_matrix();
     let mut tc = [[0.0; 3]; 3];
@@ -217,16 +217,16 @@ pub fn t2_dot_t2(a: &Tensor2, b: &Tensor2) -> Result<Tensor2, StrError> {
 ///     Ok(())
 /// }
 /// ```
-pub fn t2_dot_vec(v: &mut Vector, alpha: f64, a: &Tensor2, u: &Vector) -> Result<(), StrError> {
+pub fn t2_dot_vec(v: &mut Vector, alpha: f64, a: &Tensor2, u: &Vector) -> Result<(), Error> {
     if a.vec.dim() == 4 {
         if v.dim() != 2 || u.dim() != 2 {
-            return Err("vectors must have dim = 2");
+            return Err(Error::BadDimensions("vectors must have dim = 2"));
         }
         v[0] = alpha * (a.get(0, 0) * u[0] + a.get(0, 1) * u[1]);
         v[1] = alpha * (a.get(1, 0) * u[0] + a.get(1, 1) * u[1]);
     } else {
         if v.dim() != 3 || u.dim() != 3 {
-            return Err("vectors must have dim = 3");
+            return Err(Error::BadDimensions("vectors must have dim = 3"));
         }
         v[0] = alpha * (a.get(0, 0) * u[0] + a.get(0, 1) * u[1] + a.get(0, 2) * u[2]);
         v[1] = alpha * (a.get(1, 0) * u[0] + a.get(1, 1) * u[1] + a.get(1, 2) * u[2]);
@@ -269,16 +269,16 @@ pub fn t2_dot_vec(v: &mut Vector, alpha: f64, a: &Tensor2, u: &Vector) -> Result
 ///     Ok(())
 /// }
 /// ```
-pub fn vec_dot_t2(v: &mut Vector, alpha: f64, u: &Vector, a: &Tensor2) -> Result<(), StrError> {
+pub fn vec_dot_t2(v: &mut Vector, alpha: f64, u: &Vector, a: &Tensor2) -> Result<(), Error> {
     if a.vec.dim() == 4 {
         if v.dim() != 2 || u.dim() != 2 {
-            return Err("vectors must have dim = 2");
+            return Err(Error::BadDimensions("vectors must have dim = 2"));
         }
         v[0] = alpha * (u[0] * a.get(0, 0) + u[1] * a.get(1, 0));
         v[1] = alpha * (u[0] * a.get(0, 1) + u[1] * a.get(1, 1));
     } else {
         if v.dim() != 3 || u.dim() != 3 {
-            return Err("vectors must have dim = 3");
+            return Err(Error::BadDimensions("vectors must have dim = 3"));
         }
         v[0] = alpha * (u[0] * a.get(0, 0) + u[1] * a.get(1, 0) + u[2] * a.get(2, 0));
         v[1] = alpha * (u[0] * a.get(0, 1) + u[1] * a.get(1, 1) + u[2] * a.get(2, 1));
@@ -336,8 +336,8 @@ pub fn vec_dot_t2(v: &mut Vector, alpha: f64, u: &Vector, a: &Tensor2) -> Result
 /// }
 /// ```
 #[inline]
-pub fn t2_dyad_t2(dd: &mut Tensor4, alpha: f64, a: &Tensor2, b: &Tensor2) -> Result<(), StrError> {
-    vec_outer(&mut dd.mat, alpha, &a.vec, &b.vec)
+pub fn t2_dyad_t2(dd: &mut Tensor4, alpha: f64, a: &Tensor2, b: &Tensor2) -> Result<(), Error> {
+    vec_outer(&mut dd.mat, alpha, &a.vec, &b.vec).map_err(Error::BadDimensions)
 }
 
 /// Performs the double-dot (ddot) operation between a Tensor4 and a Tensor2
@@ -389,8 +389,8 @@ pub fn t2_dyad_t2(dd: &mut Tensor4, alpha: f64, a: &Tensor2, b: &Tensor2) -> Res
 /// }
 /// ```
 #[inline]
-pub fn t4_ddot_t2(b: &mut Tensor2, alpha: f64, dd: &Tensor4, a: &Tensor2) -> Result<(), StrError> {
-    mat_vec_mul(&mut b.vec, alpha, &dd.mat, &a.vec)
+pub fn t4_ddot_t2(b: &mut Tensor2, alpha: f64, dd: &Tensor4, a: &Tensor2) -> Result<(), Error> {
+    mat_vec_mul(&mut b.vec, alpha, &dd.mat, &a.vec).map_err(Error::BadDimensions)
 }
 
 /// Performs the double-dot (ddot) operation between a Tensor2 and a Tensor4
@@ -442,8 +442,8 @@ pub fn t4_ddot_t2(b: &mut Tensor2, alpha: f64, dd: &Tensor4, a: &Tensor2) -> Res
 /// }
 /// ```
 #[inline]
-pub fn t2_ddot_t4(b: &mut Tensor2, alpha: f64, a: &Tensor2, dd: &Tensor4) -> Result<(), StrError> {
-    vec_mat_mul(&mut b.vec, alpha, &a.vec, &dd.mat)
+pub fn t2_ddot_t4(b: &mut Tensor2, alpha: f64, a: &Tensor2, dd: &Tensor4) -> Result<(), Error> {
+    vec_mat_mul(&mut b.vec, alpha, &a.vec, &dd.mat).map_err(Error::BadDimensions)
 }
 
 /// Performs the double-dot (ddot) operation between two Tensor4
@@ -511,8 +511,8 @@ pub fn t2_ddot_t4(b: &mut Tensor2, alpha: f64, a: &Tensor2, dd: &Tensor4) -> Res
 /// }
 /// ```
 #[inline]
-pub fn t4_ddot_t4(ee: &mut Tensor4, alpha: f64, cc: &Tensor4, dd: &Tensor4) -> Result<(), StrError> {
-    mat_mat_mul(&mut ee.mat, alpha, &cc.mat, &dd.mat)
+pub fn t4_ddot_t4(ee: &mut Tensor4, alpha: f64, cc: &Tensor4, dd: &Tensor4) -> Result<(), Error> {
+    mat_mat_mul(&mut ee.mat, alpha, &cc.mat, &dd.mat).map_err(Error::BadDimensions)
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -523,7 +523,7 @@ mod tests {
         copy_tensor2, copy_tensor4, t2_ddot_t2, t2_ddot_t4, t2_dot_t2, t2_dot_vec, t2_dyad_t2, t4_ddot_t2, t4_ddot_t4,
         vec_dot_t2, Tensor2, Tensor4,
     };
-    use crate::Samples;
+    use crate::{Error, Samples};
     use russell_chk::{approx_eq, vec_approx_eq};
     use russell_lab::Vector;
 
@@ -538,7 +538,7 @@ mod tests {
         let mut b = Tensor2::new(true, true);
         assert_eq!(
             copy_tensor2(&mut b, &a).err(),
-            Some("second-order tensors are incompatible")
+            Some(Error::BadDimensions("second-order tensors are incompatible"))
         );
     }
 
@@ -566,7 +566,10 @@ mod tests {
     fn copy_tensor4_fails_on_wrong_input() {
         let dd = Tensor4::new(true, false);
         let mut ee = Tensor4::new(false, false);
-        assert_eq!(copy_tensor4(&mut ee, &dd).err(), Some("matrices are incompatible"));
+        assert_eq!(
+            copy_tensor4(&mut ee, &dd).err(),
+            Some(Error::BadDimensions("matrices are incompatible"))
+        );
     }
 
     #[test]
@@ -772,11 +775,11 @@ mod tests {
         let a = Tensor2::new(false, false);
         let u = Vector::new(4);
         let res = t2_dot_vec(&mut v, 1.0, &a, &u);
-        assert_eq!(res.err(), Some("vectors must have dim = 3"));
+        assert_eq!(res.err(), Some(Error::BadDimensions("vectors must have dim = 3")));
 
         let a = Tensor2::new(true, true);
         let res = t2_dot_vec(&mut v, 1.0, &a, &u);
-        assert_eq!(res.err(), Some("vectors must have dim = 2"));
+        assert_eq!(res.err(), Some(Error::BadDimensions("vectors must have dim = 2")));
     }
 
     #[test]
@@ -824,11 +827,11 @@ mod tests {
         let a = Tensor2::new(false, false);
         let u = Vector::new(4);
         let res = vec_dot_t2(&mut v, 1.0, &u, &a);
-        assert_eq!(res.err(), Some("vectors must have dim = 3"));
+        assert_eq!(res.err(), Some(Error::BadDimensions("vectors must have dim = 3")));
 
         let a = Tensor2::new(true, true);
         let res = vec_dot_t2(&mut v, 1.0, &u, &a);
-        assert_eq!(res.err(), Some("vectors must have dim = 2"));
+        assert_eq!(res.err(), Some(Error::BadDimensions("vectors must have dim = 2")));
     }
 
     #[test]
@@ -1,5 +1,5 @@
 use super::{mandel_dim, IJ_TO_M, IJ_TO_M_SYM, M_TO_IJ, SQRT_2};
-use crate::StrError;
+use crate::Error;
 use russell_lab::{vec_copy, vec_norm, vec_update, Matrix, Norm, Vector};
 use serde::{Deserialize, Serialize};
 
@@ -174,15 +174,17 @@ impl Tensor2 {
     ///     Ok(())
     /// }
     /// ```
-    pub fn from_matrix(tt: &[[f64; 3]; 3], symmetric: bool, two_dim: bool) -> Result<Self, StrError> {
+    pub fn from_matrix(tt: &[[f64; 3]; 3], symmetric: bool, two_dim: bool) -> Result<Self, Error> {
         if symmetric {
             if tt[1][0] != tt[0][1] || tt[2][1] != tt[1][2] || tt[2][0] != tt[0][2] {
-                return Err("symmetric Tensor2 does not pass symmetry check");
+                return Err(Error::NotSymmetric("symmetric Tensor2 does not pass symmetry check"));
             }
         }
         if two_dim {
             if tt[1][2] != 0.0 || tt[0][2] != 0.0 {
-                return Err("cannot define 2D Tensor2 due to non-zero off-diagonal values");
+                return Err(Error::NotTwoDim(
+                    "cannot define 2D Tensor2 due to non-zero off-diagonal values",
+                ));
             }
         }
         let dim = mandel_dim(symmetric, two_dim);
@@ -401,14 +403,14 @@ impl Tensor2 {
 
     /// Sets this tensor equal to another one
     #[inline]
-    pub fn set(&mut self, other: &Tensor2) -> Result<(), StrError> {
-        vec_copy(&mut self.vec, &other.vec)
+    pub fn set(&mut self, other: &Tensor2) -> Result<(), Error> {
+        vec_copy(&mut self.vec, &other.vec).map_err(Error::BadDimensions)
     }
 
     /// Adds another tensor to this one
     #[inline]
-    pub fn add(&mut self, alpha: f64, other: &Tensor2) -> Result<(), StrError> {
-        vec_update(&mut self.vec, alpha, &other.vec)
+    pub fn add(&mut self, alpha: f64, other: &Tensor2) -> Result<(), Error> {
+        vec_update(&mut self.vec, alpha, &other.vec).map_err(Error::BadDimensions)
     }
 
     /// Calculates the determinant
@@ -540,8 +542,8 @@ impl Tensor2 {
     ///     Ok(())
     /// }
     /// ```
-    pub fn deviator(&self, dev: &mut Tensor2) -> Result<(), StrError> {
-        vec_copy(&mut dev.vec, &self.vec)?;
+    pub fn deviator(&self, dev: &mut Tensor2) -> Result<(), Error> {
+        vec_copy(&mut dev.vec, &self.vec).map_err(Error::BadDimensions)?;
         let m = (self.vec[0] + self.vec[1] + self.vec[2]) / 3.0;
         dev.vec[0] -= m;
         dev.vec[1] -= m;
@@ -555,6 +557,7 @@ impl Tensor2 {
 #[cfg(test)]
 mod tests {
     use super::{Tensor2, SQRT_2};
+    use crate::Error;
     use russell_chk::{approx_eq, vec_approx_eq};
     use serde::{Deserialize, Serialize};
 
@@ -646,15 +649,15 @@ mod tests {
         ];
         assert_eq!(
             Tensor2::from_matrix(comps_std_10, true, false).err(),
-            Some("symmetric Tensor2 does not pass symmetry check")
+            Some(Error::NotSymmetric("symmetric Tensor2 does not pass symmetry check"))
         );
         assert_eq!(
             Tensor2::from_matrix(comps_std_20, true, false).err(),
-            Some("symmetric Tensor2 does not pass symmetry check")
+            Some(Error::NotSymmetric("symmetric Tensor2 does not pass symmetry check"))
         );
         assert_eq!(
             Tensor2::from_matrix(comps_std_21, true, false).err(),
-            Some("symmetric Tensor2 does not pass symmetry check")
+            Some(Error::NotSymmetric("symmetric Tensor2 does not pass symmetry check"))
         );
 
         // symmetric 2D
@@ -673,11 +676,15 @@ mod tests {
         ];
         assert_eq!(
             Tensor2::from_matrix(comps_std_12, true, true).err(),
-            Some("cannot define 2D Tensor2 due to non-zero off-diagonal values")
+            Some(Error::NotTwoDim(
+                "cannot define 2D Tensor2 due to non-zero off-diagonal values"
+            ))
         );
         assert_eq!(
             Tensor2::from_matrix(comps_std_02, true, true).err(),
-            Some("cannot define 2D Tensor2 due to non-zero off-diagonal values")
+            Some(Error::NotTwoDim(
+                "cannot define 2D Tensor2 due to non-zero off-diagonal values"
+            ))
         );
     }
 
@@ -1079,6 +1086,9 @@ mod tests {
 
         // catch error
         let mut dev = Tensor2::new(true, false);
-        assert_eq!(tt.deviator(&mut dev).err(), Some("vectors are incompatible"));
+        assert_eq!(
+            tt.deviator(&mut dev).err(),
+            Some(Error::BadDimensions("vectors are incompatible"))
+        );
     }
 }
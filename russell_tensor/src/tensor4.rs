@@ -1,5 +1,5 @@
 use super::{mandel_dim, IJKL_TO_MN, IJKL_TO_MN_SYM, MN_TO_IJKL, SQRT_2};
-use crate::StrError;
+use crate::Error;
 use russell_lab::Matrix;
 use serde::{Deserialize, Serialize};
 
@@ -196,7 +196,7 @@ impl Tensor4 {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn from_array(inp: &[[[[f64; 3]; 3]; 3]; 3], minor_symmetric: bool, two_dim: bool) -> Result<Self, StrError> {
+    pub fn from_array(inp: &[[[[f64; 3]; 3]; 3]; 3], minor_symmetric: bool, two_dim: bool) -> Result<Self, Error> {
         let dim = mandel_dim(minor_symmetric, two_dim);
         let mut mat = Matrix::new(dim, dim);
         if minor_symmetric {
@@ -211,13 +211,17 @@ impl Tensor4 {
                                     || inp[i][j][k][l] != inp[i][j][l][k]
                                     || inp[i][j][k][l] != inp[j][i][l][k]
                                 {
-                                    return Err("minor-symmetric Tensor4 does not pass symmetry check");
+                                    return Err(Error::NotSymmetric(
+                                        "minor-symmetric Tensor4 does not pass symmetry check",
+                                    ));
                                 }
                             } else {
                                 let (m, n) = IJKL_TO_MN[i][j][k][l];
                                 if m > max || n > max {
                                     if inp[i][j][k][l] != 0.0 {
-                                        return Err("cannot define 2D Tensor4 due to non-zero values");
+                                        return Err(Error::NotTwoDim(
+                                            "cannot define 2D Tensor4 due to non-zero values",
+                                        ));
                                     }
                                     continue;
                                 } else if m < 3 && n < 3 {
@@ -339,7 +343,7 @@ impl Tensor4 {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn from_matrix(inp: &[[f64; 9]; 9], minor_symmetric: bool, two_dim: bool) -> Result<Self, StrError> {
+    pub fn from_matrix(inp: &[[f64; 9]; 9], minor_symmetric: bool, two_dim: bool) -> Result<Self, Error> {
         let dim = mandel_dim(minor_symmetric, two_dim);
         let mut mat = Matrix::new(dim, dim);
         if minor_symmetric {
@@ -355,12 +359,16 @@ impl Tensor4 {
                             // check minor-symmetry
                             if i > j || k > l {
                                 if inp[m][n] != inp[p][q] || inp[m][n] != inp[r][s] || inp[m][n] != inp[u][v] {
-                                    return Err("minor-symmetric Tensor4 does not pass symmetry check");
+                                    return Err(Error::NotSymmetric(
+                                        "minor-symmetric Tensor4 does not pass symmetry check",
+                                    ));
                                 }
                             } else {
                                 if m > max || n > max {
                                     if inp[m][n] != 0.0 {
-                                        return Err("cannot define 2D Tensor4 due to non-zero values");
+                                        return Err(Error::NotTwoDim(
+                                            "cannot define 2D Tensor4 due to non-zero values",
+                                        ));
                                     }
                                     continue;
                                 } else if m < 3 && n < 3 {
@@ -723,7 +731,7 @@ impl Tensor4 {
 #[cfg(test)]
 mod tests {
     use super::{Tensor4, MN_TO_IJKL};
-    use crate::Samples;
+    use crate::{Error, Samples};
     use russell_chk::approx_eq;
     use serde::{Deserialize, Serialize};
 
@@ -736,10 +744,18 @@ mod tests {
     #[test]
     fn from_array_fails_on_wrong_input() {
         let res = Tensor4::from_array(&Samples::TENSOR4_SAMPLE1, true, false);
-        assert_eq!(res.err(), Some("minor-symmetric Tensor4 does not pass symmetry check"));
+        assert_eq!(
+            res.err(),
+            Some(Error::NotSymmetric(
+                "minor-symmetric Tensor4 does not pass symmetry check"
+            ))
+        );
 
         let res = Tensor4::from_array(&Samples::TENSOR4_SYM_SAMPLE1, true, true);
-        assert_eq!(res.err(), Some("cannot define 2D Tensor4 due to non-zero values"));
+        assert_eq!(
+            res.err(),
+            Some(Error::NotTwoDim("cannot define 2D Tensor4 due to non-zero values"))
+        );
     }
 
     #[test]
@@ -774,13 +790,21 @@ mod tests {
         let mut inp = [[0.0; 9]; 9];
         inp[0][3] = 1e-15;
         let res = Tensor4::from_matrix(&inp, true, false);
-        assert_eq!(res.err(), Some("minor-symmetric Tensor4 does not pass symmetry check"));
+        assert_eq!(
+            res.err(),
+            Some(Error::NotSymmetric(
+                "minor-symmetric Tensor4 does not pass symmetry check"
+            ))
+        );
 
         inp[0][3] = 0.0;
         inp[0][4] = 1.0;
         inp[0][7] = 1.0;
         let res = Tensor4::from_matrix(&inp, true, true);
-        assert_eq!(res.err(), Some("cannot define 2D Tensor4 due to non-zero values"));
+        assert_eq!(
+            res.err(),
+            Some(Error::NotTwoDim("cannot define 2D Tensor4 due to non-zero values"))
+        );
     }
 
     #[test]